@@ -15,4 +15,5 @@ sed -i 's/version = "*.*.*" # DO NOT CHANGE THIS LINE! This will be automaticall
 sed -i 's/path = "[^"]*"/version = "'${NEW_VERSION}'"/g' Cargo.toml
 
 cargo publish -p proof-of-sql-parser --token ${CRATES_TOKEN}
+cargo publish -p proof-of-sql-derive --token ${CRATES_TOKEN}
 cargo publish -p proof-of-sql --token ${CRATES_TOKEN}
\ No newline at end of file
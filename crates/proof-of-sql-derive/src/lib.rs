@@ -0,0 +1,59 @@
+//! Derive macros for the Proof of SQL execution engine.
+//!
+//! These are re-exported by `proof-of-sql`, which should be depended on instead of this crate.
+use proc_macro::TokenStream;
+use quote::quote;
+use syn::{ext::IdentExt, parse_macro_input, Data, DeriveInput, Fields};
+
+/// Derives `FromQueryRow` for a struct with named fields.
+///
+/// Each field is read from the result column of the same name, and must be of a type that the
+/// column can be read as.
+#[proc_macro_derive(FromQueryRow)]
+pub fn derive_from_query_row(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    let name = &input.ident;
+    if !input.generics.params.is_empty() {
+        return syn::Error::new_spanned(
+            &input.generics,
+            "FromQueryRow cannot be derived for generic structs",
+        )
+        .to_compile_error()
+        .into();
+    }
+    let fields = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => &fields.named,
+            _ => {
+                return syn::Error::new_spanned(
+                    name,
+                    "FromQueryRow can only be derived for structs with named fields",
+                )
+                .to_compile_error()
+                .into()
+            }
+        },
+        _ => {
+            return syn::Error::new_spanned(name, "FromQueryRow can only be derived for structs")
+                .to_compile_error()
+                .into()
+        }
+    };
+    let field_values = fields.iter().map(|field| {
+        let ident = field.ident.as_ref().expect("named fields have identifiers");
+        let column = ident.unraw().to_string();
+        quote! { #ident: row.get(#column)? }
+    });
+    quote! {
+        impl<__S: ::proof_of_sql::base::scalar::Scalar> ::proof_of_sql::sql::proof::FromQueryRow<__S>
+            for #name
+        {
+            fn from_query_row(
+                row: &::proof_of_sql::sql::proof::QueryRow<'_, __S>,
+            ) -> ::core::result::Result<Self, ::proof_of_sql::sql::proof::FromQueryRowError> {
+                ::core::result::Result::Ok(Self { #(#field_values),* })
+            }
+        }
+    }
+    .into()
+}
@@ -3,6 +3,9 @@ use arrayvec::ArrayString;
 use std::{cmp::Ordering, fmt, str::FromStr};
 
 /// Top-level unique identifier.
+///
+/// The name is stored inline, so identifiers are cheap to copy and never allocate, and equality
+/// and hashing compare at most 64 bytes.
 #[derive(Debug, PartialEq, Eq, Clone, Hash, Ord, PartialOrd, Copy)]
 pub struct Identifier {
     name: ArrayString<64>,
@@ -248,6 +251,39 @@ mod tests {
         assert_eq!(lower_case.name(), "sxt");
     }
 
+    #[test]
+    fn identically_named_identifiers_compare_and_hash_equal() {
+        use std::collections::hash_map::DefaultHasher;
+        use std::hash::{Hash, Hasher};
+        let hash = |identifier: &Identifier| {
+            let mut hasher = DefaultHasher::new();
+            identifier.hash(&mut hasher);
+            hasher.finish()
+        };
+
+        let parsed = Identifier::from_str("shared_name").unwrap();
+        let constructed = Identifier::new(String::from("SHARED_NAME"));
+        assert_eq!(parsed, constructed);
+        assert_eq!(hash(&parsed), hash(&constructed));
+        assert_eq!(
+            std::mem::size_of::<Identifier>(),
+            std::mem::size_of::<ArrayString<64>>()
+        );
+
+        let other = Identifier::new("other_name");
+        assert_ne!(parsed, other);
+    }
+
+    #[test]
+    fn identifiers_are_ordered_by_name() {
+        let mut identifiers = ["c", "a", "b", "a"].map(Identifier::new);
+        identifiers.sort();
+        assert_eq!(
+            identifiers.iter().map(Identifier::name).collect::<Vec<_>>(),
+            ["a", "a", "b", "c"]
+        );
+    }
+
     #[test]
     #[should_panic]
     fn long_names_panic() {
@@ -2,8 +2,8 @@ use indexmap::IndexMap;
 use proof_of_sql::base::{
     commitment::Commitment,
     database::{
-        Column, ColumnRef, ColumnType, CommitmentAccessor, DataAccessor, MetadataAccessor,
-        SchemaAccessor, TableRef,
+        Column, ColumnField, ColumnRef, ColumnType, CommitmentAccessor, DataAccessor,
+        MetadataAccessor, SchemaAccessor, TableRef,
     },
 };
 use proof_of_sql_parser::Identifier;
@@ -74,6 +74,20 @@ impl<C: Commitment> MetadataAccessor for BenchmarkAccessor<'_, C> {
     fn get_offset(&self, _table_ref: TableRef) -> usize {
         0
     }
+    fn schema(&self) -> Vec<(TableRef, Vec<ColumnField>)> {
+        self.table_schemas
+            .iter()
+            .map(|(&table_ref, columns)| {
+                (
+                    table_ref,
+                    columns
+                        .iter()
+                        .map(|&(id, column_type)| ColumnField::new(id, column_type))
+                        .collect(),
+                )
+            })
+            .collect()
+    }
 }
 impl<C: Commitment> CommitmentAccessor<C> for BenchmarkAccessor<'_, C> {
     fn get_commitment(&self, column: ColumnRef) -> C {
@@ -48,6 +48,15 @@ impl<C: Commitment> MetadataAccessor for CommitAccessor<C> {
     fn get_offset(&self, table_ref: proof_of_sql::base::database::TableRef) -> usize {
         self.inner.get_offset(table_ref)
     }
+
+    fn schema(
+        &self,
+    ) -> Vec<(
+        proof_of_sql::base::database::TableRef,
+        Vec<proof_of_sql::base::database::ColumnField>,
+    )> {
+        self.inner.schema()
+    }
 }
 impl<C: Commitment> SchemaAccessor for CommitAccessor<C> {
     fn lookup_column(
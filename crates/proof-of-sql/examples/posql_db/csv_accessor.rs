@@ -91,6 +91,9 @@ impl MetadataAccessor for CsvDataAccessor {
     fn get_offset(&self, table_ref: TableRef) -> usize {
         self.inner.get_offset(table_ref)
     }
+    fn schema(&self) -> Vec<(TableRef, Vec<proof_of_sql::base::database::ColumnField>)> {
+        self.inner.schema()
+    }
 }
 impl SchemaAccessor for CsvDataAccessor {
     fn lookup_column(
@@ -3,8 +3,8 @@ use bumpalo::Bump;
 use indexmap::IndexMap;
 use proof_of_sql::base::{
     database::{
-        ArrayRefExt, Column, ColumnRef, ColumnType, DataAccessor, MetadataAccessor, SchemaAccessor,
-        TableRef,
+        ArrayRefExt, Column, ColumnField, ColumnRef, ColumnType, DataAccessor, MetadataAccessor,
+        SchemaAccessor, TableRef,
     },
     scalar::Scalar,
 };
@@ -56,6 +56,21 @@ impl MetadataAccessor for RecordBatchAccessor {
         assert!(self.tables.contains_key(&table_ref), "Table not found.");
         0
     }
+
+    fn schema(&self) -> Vec<(TableRef, Vec<ColumnField>)> {
+        self.tables
+            .keys()
+            .map(|&table_ref| {
+                (
+                    table_ref,
+                    self.lookup_schema(table_ref)
+                        .into_iter()
+                        .map(|(id, column_type)| ColumnField::new(id, column_type))
+                        .collect(),
+                )
+            })
+            .collect()
+    }
 }
 impl SchemaAccessor for RecordBatchAccessor {
     fn lookup_column(&self, table_ref: TableRef, column_id: Identifier) -> Option<ColumnType> {
@@ -37,6 +37,16 @@ impl BitDistribution {
         Self { or_all, vary_mask }
     }
 
+    /// The distribution with the most varying bits that is still within the range accepted by
+    /// the verifier: every absolute bit up to and including bit 128 varies, as does the sign.
+    pub fn widest_acceptable() -> Self {
+        let mask = [u64::MAX, u64::MAX, 1, 1 << 63];
+        Self {
+            or_all: mask,
+            vary_mask: mask,
+        }
+    }
+
     pub fn num_varying_bits(&self) -> usize {
         let mut res = 0_usize;
         for xi in self.vary_mask.iter() {
@@ -113,6 +123,7 @@ impl BitDistribution {
 
     /// Iterate over each varying bit for the absolute values and the sign bit
     /// if it varies
+    #[cfg(feature = "prover")]
     pub fn for_each_varying_bit<F>(&self, mut f: F)
     where
         F: FnMut(usize, usize),
@@ -301,3 +301,13 @@ fn we_can_detect_invalid_bit_distributions() {
     };
     assert!(!dist.is_valid());
 }
+
+#[test]
+fn the_widest_acceptable_bit_distribution_is_valid_and_has_a_varying_sign() {
+    let dist = BitDistribution::widest_acceptable();
+    assert!(dist.is_valid());
+    assert_eq!(dist.num_varying_bits(), 130);
+    assert!(dist.has_varying_sign_bit());
+    assert_eq!(dist.most_significant_abs_bit(), 128);
+    assert_eq!(dist.constant_part(), [0; 4]);
+}
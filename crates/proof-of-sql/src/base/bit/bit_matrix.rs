@@ -1,8 +1,8 @@
 use crate::base::{
     bit::{make_abs_bit_mask, BitDistribution},
     scalar::Scalar,
+    MleAllocator,
 };
-use bumpalo::Bump;
 
 /// Let x1, ..., xn denote the values of a data column. Let
 /// b1, ..., bk denote the bit positions of abs(x1), ..., abs(xn)
@@ -12,7 +12,7 @@ use bumpalo::Bump;
 ///   M_ij = abs(xi) & (1 << bj) == 1
 /// The last column of M corresponds to the sign bit if it varies.
 pub fn compute_varying_bit_matrix<'a, S: Scalar>(
-    alloc: &'a Bump,
+    alloc: &'a dyn MleAllocator,
     vals: &[S],
     dist: &BitDistribution,
 ) -> Vec<&'a [bool]> {
@@ -3,10 +3,12 @@ pub use abs_bit_mask::*;
 
 mod bit_distribution;
 pub use bit_distribution::*;
-#[cfg(test)]
+#[cfg(all(test, feature = "prover"))]
 mod bit_distribution_test;
 
+#[cfg(feature = "prover")]
 mod bit_matrix;
+#[cfg(feature = "prover")]
 pub use bit_matrix::*;
-#[cfg(test)]
+#[cfg(all(test, feature = "prover"))]
 mod bit_matrix_test;
@@ -0,0 +1,122 @@
+//! A battery of checks that any [`Commitment`] implementation must pass.
+//!
+//! Proofs rely on commitments being linear in the committed data and consistent across column
+//! types and generator offsets. To check a new scheme, call [`test_commitment_conformance`] from a
+//! test with a public setup that supports vectors of at least 16 elements:
+//!
+//! ```ignore
+//! #[test]
+//! fn my_commitment_conforms_to_the_commitment_trait() {
+//!     test_commitment_conformance::<MyCommitment>(&my_public_setup);
+//! }
+//! ```
+use super::Commitment;
+use crate::base::scalar::Scalar;
+
+fn commit<C: Commitment>(values: &[C::Scalar], offset: usize, setup: &C::PublicSetup<'_>) -> C {
+    let mut commitments = [C::default()];
+    C::compute_commitments(&mut commitments, &[values.into()], offset, setup);
+    commitments[0]
+}
+
+fn scalars<S: Scalar>(values: &[i64]) -> Vec<S> {
+    values.iter().copied().map(S::from).collect()
+}
+
+/// Runs every check in this module against `C`.
+pub fn test_commitment_conformance<C: Commitment>(setup: &C::PublicSetup<'_>) {
+    test_commitments_are_additive::<C>(setup);
+    test_commitments_respect_scalar_multiplication::<C>(setup);
+    test_commitments_of_known_vectors::<C>(setup);
+    test_commitments_are_consistent_across_offsets::<C>(setup);
+}
+
+/// Checks that the commitment to a sum is the sum of the commitments, and likewise for
+/// differences and negation.
+pub fn test_commitments_are_additive<C: Commitment>(setup: &C::PublicSetup<'_>) {
+    let a = scalars::<C::Scalar>(&[1, -2, 3, 400, 0, 6, -7, 8]);
+    let b = scalars::<C::Scalar>(&[9, 10, -11, 0, 13, 14, 15, 1_000_000]);
+    let sum: Vec<_> = a.iter().zip(&b).map(|(&x, &y)| x + y).collect();
+    let difference: Vec<_> = a.iter().zip(&b).map(|(&x, &y)| x - y).collect();
+    let negation: Vec<_> = a.iter().map(|&x| -x).collect();
+
+    let commitment_a = commit::<C>(&a, 0, setup);
+    let commitment_b = commit::<C>(&b, 0, setup);
+
+    let mut expected_sum = commitment_a;
+    expected_sum += commitment_b;
+    assert_eq!(commit::<C>(&sum, 0, setup), expected_sum);
+
+    let mut expected_difference = commitment_a;
+    expected_difference -= commitment_b;
+    assert_eq!(commit::<C>(&difference, 0, setup), expected_difference);
+    assert_eq!(commitment_a - commitment_b, expected_difference);
+
+    assert_eq!(commit::<C>(&negation, 0, setup), -commitment_a);
+}
+
+/// Checks that the commitment to a scaled vector is the scaled commitment.
+pub fn test_commitments_respect_scalar_multiplication<C: Commitment>(setup: &C::PublicSetup<'_>) {
+    let a = scalars::<C::Scalar>(&[1, -2, 3, 400, 0, 6, -7, 8]);
+    let factor = C::Scalar::from(-12345_i64);
+    let scaled: Vec<_> = a.iter().map(|&x| factor * x).collect();
+
+    let commitment_a = commit::<C>(&a, 0, setup);
+    assert_eq!(commit::<C>(&scaled, 0, setup), factor * commitment_a);
+    assert_eq!(commit::<C>(&scaled, 0, setup), factor * &commitment_a);
+    assert_eq!(C::Scalar::ZERO * commitment_a, C::default());
+    assert_eq!(C::Scalar::ONE * commitment_a, commitment_a);
+}
+
+/// Checks the commitments to vectors whose commitments are known in terms of other vectors.
+pub fn test_commitments_of_known_vectors<C: Commitment>(setup: &C::PublicSetup<'_>) {
+    // the zero vector commits to the identity
+    assert_eq!(commit::<C>(&[C::Scalar::ZERO; 8], 3, setup), C::default());
+
+    // a column commits to the same value whichever type it is stored as
+    let values = [1_i64, -2, 3, 400, 0, 6, -7, 8];
+    let expected = commit::<C>(&scalars::<C::Scalar>(&values), 0, setup);
+    let mut commitments = [C::default()];
+    C::compute_commitments(&mut commitments, &[(&values[..]).into()], 0, setup);
+    assert_eq!(commitments[0], expected);
+
+    let flags = [true, false, false, true, true];
+    let expected = commit::<C>(&scalars::<C::Scalar>(&[1, 0, 0, 1, 1]), 0, setup);
+    C::compute_commitments(&mut commitments, &[(&flags[..]).into()], 0, setup);
+    assert_eq!(commitments[0], expected);
+
+    // a unit vector commits to a single generator, so distinct units give distinct commitments
+    let unit = commit::<C>(&[C::Scalar::ONE], 0, setup);
+    assert_ne!(unit, C::default());
+    assert_ne!(
+        unit,
+        commit::<C>(&[C::Scalar::ZERO, C::Scalar::ONE], 0, setup)
+    );
+}
+
+/// Checks that committing at an offset is the same as committing after that many zeros, and that
+/// a commitment can be extended with the rows that follow it.
+pub fn test_commitments_are_consistent_across_offsets<C: Commitment>(setup: &C::PublicSetup<'_>) {
+    let a = scalars::<C::Scalar>(&[1, -2, 3, 400, 0, 6, -7, 8, 9, 10]);
+    let offset = 5;
+    let mut padded = vec![C::Scalar::ZERO; offset];
+    padded.extend_from_slice(&a);
+    assert_eq!(
+        commit::<C>(&a, offset, setup),
+        commit::<C>(&padded, 0, setup)
+    );
+
+    let split = 4;
+    let mut appended = commit::<C>(&a[..split], offset, setup);
+    appended += commit::<C>(&a[split..], offset + split, setup);
+    assert_eq!(appended, commit::<C>(&a, offset, setup));
+    assert_eq!(
+        C::update_commitment(
+            &commit::<C>(&a[..split], offset, setup),
+            &a[split..],
+            offset + split,
+            setup
+        ),
+        appended
+    );
+}
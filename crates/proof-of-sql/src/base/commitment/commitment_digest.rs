@@ -0,0 +1,98 @@
+use super::Commitment;
+use crate::base::{
+    database::{
+        ColumnField, ColumnRef, CommitmentAccessor, DigestAccessor, MetadataAccessor, TableRef,
+    },
+    proof::ProofError,
+};
+use indexmap::IndexMap;
+use serde::{Deserialize, Serialize};
+
+/// A 32-byte digest of a column commitment.
+///
+/// This lets a verifier store only a digest per column rather than the full commitment. The
+/// digest is the blake3 hash of the postcard serialization of the commitment.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct CommitmentDigest(pub [u8; 32]);
+
+impl CommitmentDigest {
+    /// Computes the digest of a commitment.
+    pub fn of<C: Commitment + Serialize>(commitment: &C) -> Self {
+        let bytes = postcard::to_allocvec(commitment).expect("commitments should serialize");
+        Self(*blake3::hash(&bytes).as_bytes())
+    }
+}
+
+/// A [`CommitmentAccessor`] for verifiers that only hold commitment digests.
+///
+/// The prover supplies the full commitments of the columns a query references alongside the
+/// proof. On construction, each of these is checked for inclusion against the digest provided
+/// by the [`DigestAccessor`], after which the proof can be verified against them as usual.
+///
+/// Note: verifying against digests adds the assumption that the digest is collision resistant.
+/// If a prover could find a second commitment with the same digest, they could prove a query
+/// against data other than what was committed to.
+pub struct DigestCommitmentAccessor<'a, C: Commitment> {
+    digests: &'a dyn DigestAccessor,
+    commitments: IndexMap<ColumnRef, C>,
+}
+
+impl<'a, C: Commitment + Serialize> DigestCommitmentAccessor<'a, C> {
+    /// Checks the supplied commitments against their digests.
+    ///
+    /// Fails if any of `column_refs` is missing a commitment, or if any supplied commitment
+    /// does not match its digest.
+    pub fn try_new(
+        digests: &'a dyn DigestAccessor,
+        commitments: IndexMap<ColumnRef, C>,
+        column_refs: impl IntoIterator<Item = ColumnRef>,
+    ) -> Result<Self, ProofError> {
+        if column_refs
+            .into_iter()
+            .any(|column_ref| !commitments.contains_key(&column_ref))
+        {
+            return Err(ProofError::VerificationError(
+                "missing commitment for a referenced column",
+            ));
+        }
+        if commitments.iter().any(|(column_ref, commitment)| {
+            CommitmentDigest::of(commitment) != digests.get_digest(*column_ref)
+        }) {
+            return Err(ProofError::VerificationError(
+                "commitment does not match its digest",
+            ));
+        }
+        Ok(Self {
+            digests,
+            commitments,
+        })
+    }
+}
+
+impl<C: Commitment> MetadataAccessor for DigestCommitmentAccessor<'_, C> {
+    fn get_length(&self, table_ref: TableRef) -> usize {
+        self.digests.get_length(table_ref)
+    }
+
+    fn get_offset(&self, table_ref: TableRef) -> usize {
+        self.digests.get_offset(table_ref)
+    }
+
+    fn schema(&self) -> Vec<(TableRef, Vec<ColumnField>)> {
+        self.digests.schema()
+    }
+
+    fn column_bounds(&self, column: ColumnRef) -> Option<(i64, i64)> {
+        self.digests.column_bounds(column)
+    }
+
+    fn column_epoch(&self, column: ColumnRef) -> Option<u64> {
+        self.digests.column_epoch(column)
+    }
+}
+
+impl<C: Commitment> CommitmentAccessor<C> for DigestCommitmentAccessor<'_, C> {
+    fn get_commitment(&self, column: ColumnRef) -> C {
+        self.commitments[&column]
+    }
+}
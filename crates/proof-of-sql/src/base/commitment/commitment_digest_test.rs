@@ -0,0 +1,143 @@
+use super::{CommitmentDigest, DigestCommitmentAccessor, InnerProductProof};
+use crate::{
+    base::{
+        database::{
+            owned_table_utility::*, ColumnField, ColumnRef, CommitmentAccessor, DigestAccessor,
+            MetadataAccessor, OwnedTableTestAccessor, TableRef,
+        },
+        proof::ProofError,
+    },
+    sql::{
+        ast::test_utility::*,
+        proof::{ProofExecutionPlan, VerifiableQueryResult},
+    },
+};
+use curve25519_dalek::RistrettoPoint;
+use indexmap::IndexMap;
+
+/// An accessor that holds only the digests of column commitments
+struct DigestOnlyAccessor<'a> {
+    metadata: &'a dyn MetadataAccessor,
+    digests: IndexMap<ColumnRef, CommitmentDigest>,
+}
+
+impl MetadataAccessor for DigestOnlyAccessor<'_> {
+    fn get_length(&self, table_ref: TableRef) -> usize {
+        self.metadata.get_length(table_ref)
+    }
+
+    fn get_offset(&self, table_ref: TableRef) -> usize {
+        self.metadata.get_offset(table_ref)
+    }
+
+    fn schema(&self) -> Vec<(TableRef, Vec<ColumnField>)> {
+        self.metadata.schema()
+    }
+}
+
+impl DigestAccessor for DigestOnlyAccessor<'_> {
+    fn get_digest(&self, column: ColumnRef) -> CommitmentDigest {
+        self.digests[&column]
+    }
+}
+
+// select a * a as square from sxt.t
+#[test]
+fn we_can_verify_a_square_query_against_a_digest_only_accessor() {
+    let data = owned_table([bigint("a", [1_i64, -2, 3, 4])]);
+    let t = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(t, data, 0, ());
+    let ast = projection(
+        vec![aliased_plan(
+            multiply(column(t, "a", &accessor), column(t, "a", &accessor)),
+            "square",
+        )],
+        tab(t),
+    );
+    let column_refs = ast.get_column_references();
+    let commitments: IndexMap<ColumnRef, RistrettoPoint> = column_refs
+        .iter()
+        .map(|&column_ref| (column_ref, accessor.get_commitment(column_ref)))
+        .collect();
+    let digest_accessor = DigestOnlyAccessor {
+        metadata: &accessor,
+        digests: commitments
+            .iter()
+            .map(|(&column_ref, commitment)| (column_ref, CommitmentDigest::of(commitment)))
+            .collect(),
+    };
+
+    let verifiable_res = VerifiableQueryResult::new(&ast, &accessor, &());
+    let verifier_accessor =
+        DigestCommitmentAccessor::try_new(&digest_accessor, commitments, column_refs).unwrap();
+    let res = verifiable_res
+        .verify(&ast, &verifier_accessor, &())
+        .unwrap()
+        .table;
+    let expected_res = owned_table([bigint("square", [1_i64, 4, 9, 16])]);
+    assert_eq!(res, expected_res);
+}
+
+#[test]
+fn we_cannot_verify_against_a_wrong_digest() {
+    let data = owned_table([
+        bigint("a", [1_i64, -2, 3, 4]),
+        bigint("b", [5_i64, 6, 7, 8]),
+    ]);
+    let t = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(t, data, 0, ());
+    let ast = projection(
+        vec![aliased_plan(
+            multiply(column(t, "a", &accessor), column(t, "a", &accessor)),
+            "square",
+        )],
+        tab(t),
+    );
+    let column_refs = ast.get_column_references();
+    let a_ref = column_refs[0];
+    let b_commitment = accessor.get_commitment(ColumnRef::new(
+        t,
+        "b".parse().unwrap(),
+        *a_ref.column_type(),
+    ));
+
+    // The digest of `b` is stored for `a`, so the commitment of `a` doesn't match it
+    let digest_accessor = DigestOnlyAccessor {
+        metadata: &accessor,
+        digests: IndexMap::from([(a_ref, CommitmentDigest::of(&b_commitment))]),
+    };
+    let commitments = IndexMap::from([(a_ref, accessor.get_commitment(a_ref))]);
+    assert!(matches!(
+        DigestCommitmentAccessor::try_new(&digest_accessor, commitments, column_refs.clone()),
+        Err(ProofError::VerificationError(_))
+    ));
+
+    // Supplying `b`'s commitment in place of `a`'s matches the digest, but the proof fails
+    let commitments = IndexMap::from([(a_ref, b_commitment)]);
+    let verifier_accessor =
+        DigestCommitmentAccessor::try_new(&digest_accessor, commitments, column_refs).unwrap();
+    let verifiable_res = VerifiableQueryResult::new(&ast, &accessor, &());
+    assert!(verifiable_res
+        .verify(&ast, &verifier_accessor, &())
+        .is_err());
+}
+
+#[test]
+fn we_cannot_verify_without_a_commitment_for_every_referenced_column() {
+    let data = owned_table([bigint("a", [1_i64, -2, 3, 4])]);
+    let t = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(t, data, 0, ());
+    let ast = projection(cols_expr_plan(t, &["a"], &accessor), tab(t));
+    let digest_accessor = DigestOnlyAccessor {
+        metadata: &accessor,
+        digests: IndexMap::new(),
+    };
+    assert!(matches!(
+        DigestCommitmentAccessor::<RistrettoPoint>::try_new(
+            &digest_accessor,
+            IndexMap::new(),
+            ast.get_column_references(),
+        ),
+        Err(ProofError::VerificationError(_))
+    ));
+}
@@ -0,0 +1,205 @@
+use super::{Commitment, CommitmentDigest};
+use crate::base::{
+    database::{
+        ColumnField, ColumnRef, CommitmentAccessor, MerkleRootAccessor, MetadataAccessor, TableRef,
+    },
+    proof::ProofError,
+};
+use indexmap::IndexMap;
+use serde::{Deserialize, Serialize};
+
+/// The hash of an empty slot, used to pad the leaves of a [`CommitmentMerkleTree`] to a power
+/// of two.
+const EMPTY_LEAF: [u8; 32] = [0; 32];
+
+/// The root of a [`CommitmentMerkleTree`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct CommitmentMerkleRoot(pub [u8; 32]);
+
+/// A Merkle tree over the commitments of a set of columns.
+///
+/// Each leaf binds a column reference to the [`CommitmentDigest`] of the column's commitment,
+/// so a verifier holding only the root can check that a supplied commitment belongs to a
+/// particular column. Leaves and internal nodes are hashed with blake3 under distinct prefixes.
+pub struct CommitmentMerkleTree {
+    positions: IndexMap<ColumnRef, usize>,
+    /// The levels of the tree, from the padded leaves up to the root.
+    levels: Vec<Vec<[u8; 32]>>,
+}
+
+impl CommitmentMerkleTree {
+    /// Builds the tree over the given column commitments.
+    pub fn new<'c, C: Commitment + Serialize + 'c>(
+        commitments: impl IntoIterator<Item = (ColumnRef, &'c C)>,
+    ) -> Self {
+        let mut positions = IndexMap::new();
+        let mut leaves = Vec::new();
+        for (column_ref, commitment) in commitments {
+            positions.insert(column_ref, leaves.len());
+            leaves.push(leaf_hash(column_ref, CommitmentDigest::of(commitment)));
+        }
+        leaves.resize(leaves.len().next_power_of_two(), EMPTY_LEAF);
+        let mut levels = vec![leaves];
+        while levels.last().expect("there is always a level").len() > 1 {
+            let level = levels
+                .last()
+                .expect("there is always a level")
+                .chunks(2)
+                .map(|pair| node_hash(&pair[0], &pair[1]))
+                .collect();
+            levels.push(level);
+        }
+        Self { positions, levels }
+    }
+
+    /// The root of the tree.
+    pub fn root(&self) -> CommitmentMerkleRoot {
+        CommitmentMerkleRoot(self.levels.last().expect("there is always a level")[0])
+    }
+
+    /// The inclusion proof of a column's commitment, if the column is in the tree.
+    pub fn inclusion_proof(&self, column: ColumnRef) -> Option<MerkleInclusionProof> {
+        let index = *self.positions.get(&column)?;
+        let siblings = self
+            .levels
+            .iter()
+            .take(self.levels.len() - 1)
+            .enumerate()
+            .map(|(height, level)| level[(index >> height) ^ 1])
+            .collect();
+        Some(MerkleInclusionProof {
+            index: index as u64,
+            siblings,
+        })
+    }
+}
+
+/// A proof that a column's commitment is a leaf of a [`CommitmentMerkleTree`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MerkleInclusionProof {
+    /// The position of the leaf in the tree
+    pub index: u64,
+    /// The sibling of each node on the path from the leaf to the root
+    pub siblings: Vec<[u8; 32]>,
+}
+
+impl MerkleInclusionProof {
+    /// Checks that `commitment` is the commitment of `column` in the tree with the given root.
+    pub fn verify<C: Commitment + Serialize>(
+        &self,
+        root: &CommitmentMerkleRoot,
+        column: ColumnRef,
+        commitment: &C,
+    ) -> bool {
+        if self.siblings.len() >= 64 || self.index >> self.siblings.len() != 0 {
+            return false;
+        }
+        let node = self.siblings.iter().enumerate().fold(
+            leaf_hash(column, CommitmentDigest::of(commitment)),
+            |node, (height, sibling)| {
+                if (self.index >> height) & 1 == 0 {
+                    node_hash(&node, sibling)
+                } else {
+                    node_hash(sibling, &node)
+                }
+            },
+        );
+        node == root.0
+    }
+}
+
+fn leaf_hash(column: ColumnRef, digest: CommitmentDigest) -> [u8; 32] {
+    let column_bytes = postcard::to_allocvec(&column).expect("column refs should serialize");
+    let mut hasher = blake3::Hasher::new();
+    hasher.update(&[0]);
+    hasher.update(&column_bytes);
+    hasher.update(&digest.0);
+    *hasher.finalize().as_bytes()
+}
+
+fn node_hash(left: &[u8; 32], right: &[u8; 32]) -> [u8; 32] {
+    let mut hasher = blake3::Hasher::new();
+    hasher.update(&[1]);
+    hasher.update(left);
+    hasher.update(right);
+    *hasher.finalize().as_bytes()
+}
+
+/// A [`CommitmentAccessor`] for verifiers that only hold the Merkle root of the commitments.
+///
+/// The prover supplies the full commitments of the columns a query references, each with an
+/// inclusion proof, alongside the proof. On construction, every inclusion proof is checked
+/// against the root provided by the [`MerkleRootAccessor`], after which the proof can be
+/// verified against the commitments as usual.
+///
+/// Note: as with [`DigestCommitmentAccessor`](super::DigestCommitmentAccessor), this adds the
+/// assumption that the hash is collision resistant.
+pub struct MerkleCommitmentAccessor<'a, C: Commitment> {
+    roots: &'a dyn MerkleRootAccessor,
+    commitments: IndexMap<ColumnRef, C>,
+}
+
+impl<'a, C: Commitment + Serialize> MerkleCommitmentAccessor<'a, C> {
+    /// Checks the supplied commitments against the Merkle root.
+    ///
+    /// Fails if any of `column_refs` is missing a commitment, or if the inclusion proof of any
+    /// supplied commitment is invalid.
+    pub fn try_new(
+        roots: &'a dyn MerkleRootAccessor,
+        commitments: IndexMap<ColumnRef, (C, MerkleInclusionProof)>,
+        column_refs: impl IntoIterator<Item = ColumnRef>,
+    ) -> Result<Self, ProofError> {
+        if column_refs
+            .into_iter()
+            .any(|column_ref| !commitments.contains_key(&column_ref))
+        {
+            return Err(ProofError::VerificationError(
+                "missing commitment for a referenced column",
+            ));
+        }
+        let root = roots.get_commitment_root();
+        if commitments
+            .iter()
+            .any(|(&column_ref, (commitment, proof))| !proof.verify(&root, column_ref, commitment))
+        {
+            return Err(ProofError::VerificationError(
+                "invalid commitment inclusion proof",
+            ));
+        }
+        Ok(Self {
+            roots,
+            commitments: commitments
+                .into_iter()
+                .map(|(column_ref, (commitment, _))| (column_ref, commitment))
+                .collect(),
+        })
+    }
+}
+
+impl<C: Commitment> MetadataAccessor for MerkleCommitmentAccessor<'_, C> {
+    fn get_length(&self, table_ref: TableRef) -> usize {
+        self.roots.get_length(table_ref)
+    }
+
+    fn get_offset(&self, table_ref: TableRef) -> usize {
+        self.roots.get_offset(table_ref)
+    }
+
+    fn schema(&self) -> Vec<(TableRef, Vec<ColumnField>)> {
+        self.roots.schema()
+    }
+
+    fn column_bounds(&self, column: ColumnRef) -> Option<(i64, i64)> {
+        self.roots.column_bounds(column)
+    }
+
+    fn column_epoch(&self, column: ColumnRef) -> Option<u64> {
+        self.roots.column_epoch(column)
+    }
+}
+
+impl<C: Commitment> CommitmentAccessor<C> for MerkleCommitmentAccessor<'_, C> {
+    fn get_commitment(&self, column: ColumnRef) -> C {
+        self.commitments[&column]
+    }
+}
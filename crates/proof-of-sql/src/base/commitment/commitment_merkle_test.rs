@@ -0,0 +1,186 @@
+use super::{
+    CommitmentMerkleRoot, CommitmentMerkleTree, InnerProductProof, MerkleCommitmentAccessor,
+    MerkleInclusionProof,
+};
+use crate::{
+    base::{
+        database::{
+            owned_table_utility::*, ColumnField, ColumnRef, ColumnType, CommitmentAccessor,
+            MerkleRootAccessor, MetadataAccessor, OwnedTableTestAccessor, TableRef,
+        },
+        proof::ProofError,
+    },
+    sql::{
+        ast::test_utility::*,
+        proof::{ProofExecutionPlan, VerifiableQueryResult},
+    },
+};
+use curve25519_dalek::RistrettoPoint;
+use indexmap::IndexMap;
+
+/// An accessor that holds only the Merkle root of the column commitments
+struct RootOnlyAccessor<'a> {
+    metadata: &'a dyn MetadataAccessor,
+    root: CommitmentMerkleRoot,
+}
+
+impl MetadataAccessor for RootOnlyAccessor<'_> {
+    fn get_length(&self, table_ref: TableRef) -> usize {
+        self.metadata.get_length(table_ref)
+    }
+
+    fn get_offset(&self, table_ref: TableRef) -> usize {
+        self.metadata.get_offset(table_ref)
+    }
+
+    fn schema(&self) -> Vec<(TableRef, Vec<ColumnField>)> {
+        self.metadata.schema()
+    }
+}
+
+impl MerkleRootAccessor for RootOnlyAccessor<'_> {
+    fn get_commitment_root(&self) -> CommitmentMerkleRoot {
+        self.root
+    }
+}
+
+fn column_refs(t: TableRef, names: &[&str]) -> Vec<ColumnRef> {
+    names
+        .iter()
+        .map(|name| ColumnRef::new(t, name.parse().unwrap(), ColumnType::BigInt))
+        .collect()
+}
+
+#[test]
+fn we_can_verify_inclusion_proofs_of_every_leaf() {
+    let data = owned_table([
+        bigint("a", [1_i64, 2]),
+        bigint("b", [3_i64, 4]),
+        bigint("c", [5_i64, 6]),
+    ]);
+    let t = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(t, data, 0, ());
+    let refs = column_refs(t, &["a", "b", "c"]);
+    let commitments: Vec<RistrettoPoint> = refs
+        .iter()
+        .map(|&column_ref| accessor.get_commitment(column_ref))
+        .collect();
+    let tree = CommitmentMerkleTree::new(refs.iter().copied().zip(&commitments));
+    let root = tree.root();
+    for (&column_ref, commitment) in refs.iter().zip(&commitments) {
+        let proof = tree.inclusion_proof(column_ref).unwrap();
+        assert!(proof.verify(&root, column_ref, commitment));
+        // the same commitment is not included under a different column
+        for &other_ref in refs.iter().filter(|&&other_ref| other_ref != column_ref) {
+            assert!(!proof.verify(&root, other_ref, commitment));
+        }
+    }
+}
+
+// select a * a as square from sxt.t
+#[test]
+fn we_can_verify_a_square_query_against_a_merkle_root() {
+    let data = owned_table([
+        bigint("a", [1_i64, -2, 3, 4]),
+        bigint("b", [5_i64, 6, 7, 8]),
+    ]);
+    let t = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(t, data, 0, ());
+    let ast = projection(
+        vec![aliased_plan(
+            multiply(column(t, "a", &accessor), column(t, "a", &accessor)),
+            "square",
+        )],
+        tab(t),
+    );
+    let all_commitments: IndexMap<ColumnRef, RistrettoPoint> = column_refs(t, &["a", "b"])
+        .into_iter()
+        .map(|column_ref| (column_ref, accessor.get_commitment(column_ref)))
+        .collect();
+    let tree = CommitmentMerkleTree::new(all_commitments.iter().map(|(&c, v)| (c, v)));
+    let root_accessor = RootOnlyAccessor {
+        metadata: &accessor,
+        root: tree.root(),
+    };
+
+    let column_refs = ast.get_column_references();
+    let commitments = column_refs
+        .iter()
+        .map(|&column_ref| {
+            (
+                column_ref,
+                (
+                    all_commitments[&column_ref],
+                    tree.inclusion_proof(column_ref).unwrap(),
+                ),
+            )
+        })
+        .collect();
+    let verifiable_res = VerifiableQueryResult::new(&ast, &accessor, &());
+    let verifier_accessor =
+        MerkleCommitmentAccessor::try_new(&root_accessor, commitments, column_refs).unwrap();
+    let res = verifiable_res
+        .verify(&ast, &verifier_accessor, &())
+        .unwrap()
+        .table;
+    let expected_res = owned_table([bigint("square", [1_i64, 4, 9, 16])]);
+    assert_eq!(res, expected_res);
+}
+
+#[test]
+fn we_cannot_verify_a_commitment_with_an_invalid_inclusion_proof() {
+    let data = owned_table([
+        bigint("a", [1_i64, -2, 3, 4]),
+        bigint("b", [5_i64, 6, 7, 8]),
+    ]);
+    let t = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(t, data, 0, ());
+    let ast = projection(cols_expr_plan(t, &["a"], &accessor), tab(t));
+    let refs = column_refs(t, &["a", "b"]);
+    let (a_ref, b_ref) = (refs[0], refs[1]);
+    let a_commitment: RistrettoPoint = accessor.get_commitment(a_ref);
+    let b_commitment: RistrettoPoint = accessor.get_commitment(b_ref);
+    let tree = CommitmentMerkleTree::new([(a_ref, &a_commitment), (b_ref, &b_commitment)]);
+    let root_accessor = RootOnlyAccessor {
+        metadata: &accessor,
+        root: tree.root(),
+    };
+    let a_proof = tree.inclusion_proof(a_ref).unwrap();
+
+    // `b`'s commitment supplied for `a`
+    let commitments = IndexMap::from([(a_ref, (b_commitment, a_proof.clone()))]);
+    assert!(matches!(
+        MerkleCommitmentAccessor::try_new(&root_accessor, commitments, ast.get_column_references()),
+        Err(ProofError::VerificationError(_))
+    ));
+
+    // a tampered sibling
+    let mut bad_proof = a_proof.clone();
+    bad_proof.siblings[0][0] ^= 1;
+    let commitments = IndexMap::from([(a_ref, (a_commitment, bad_proof))]);
+    assert!(matches!(
+        MerkleCommitmentAccessor::try_new(&root_accessor, commitments, ast.get_column_references()),
+        Err(ProofError::VerificationError(_))
+    ));
+
+    // an index outside of the tree
+    let bad_proof = MerkleInclusionProof {
+        index: 2,
+        ..a_proof
+    };
+    let commitments = IndexMap::from([(a_ref, (a_commitment, bad_proof))]);
+    assert!(matches!(
+        MerkleCommitmentAccessor::try_new(&root_accessor, commitments, ast.get_column_references()),
+        Err(ProofError::VerificationError(_))
+    ));
+
+    // a missing commitment
+    assert!(matches!(
+        MerkleCommitmentAccessor::<RistrettoPoint>::try_new(
+            &root_accessor,
+            IndexMap::new(),
+            ast.get_column_references(),
+        ),
+        Err(ProofError::VerificationError(_))
+    ));
+}
@@ -0,0 +1,120 @@
+use super::Commitment;
+use crate::base::database::{
+    ColumnField, ColumnRef, CommitmentAccessor, MetadataAccessor, TableRef,
+};
+use indexmap::IndexMap;
+use serde::{Deserialize, Serialize};
+
+/// The length and generator offset of a table in a [`CommitmentSnapshot`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TableSnapshotMetadata {
+    /// The number of rows in the table
+    pub length: usize,
+    /// The generator offset of the table
+    pub offset: usize,
+}
+
+/// A precomputed set of column commitments that can be used in place of a live [`CommitmentAccessor`].
+///
+/// This is simply a map from column references to their commitments, along with the length and
+/// offset of each table, and the bounds and epochs the accessor advertised for the columns.
+/// Since it is serializable, a verifier can receive it ahead of time and verify proofs without
+/// access to the underlying data store.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CommitmentSnapshot<C> {
+    tables: IndexMap<TableRef, TableSnapshotMetadata>,
+    commitments: IndexMap<ColumnRef, C>,
+    column_bounds: IndexMap<ColumnRef, (i64, i64)>,
+    column_epochs: IndexMap<ColumnRef, u64>,
+}
+
+impl<C> Default for CommitmentSnapshot<C> {
+    fn default() -> Self {
+        Self {
+            tables: IndexMap::new(),
+            commitments: IndexMap::new(),
+            column_bounds: IndexMap::new(),
+            column_epochs: IndexMap::new(),
+        }
+    }
+}
+
+impl<C: Commitment> CommitmentSnapshot<C> {
+    /// Create a new `CommitmentSnapshot` from a collection of columns and an accessor.
+    pub fn from_accessor(
+        columns: impl IntoIterator<Item = ColumnRef>,
+        accessor: &impl CommitmentAccessor<C>,
+    ) -> Self {
+        let mut snapshot = Self::default();
+        for column in columns {
+            let table_ref = column.table_ref();
+            snapshot
+                .tables
+                .entry(table_ref)
+                .or_insert_with(|| TableSnapshotMetadata {
+                    length: accessor.get_length(table_ref),
+                    offset: accessor.get_offset(table_ref),
+                });
+            snapshot
+                .commitments
+                .insert(column, accessor.get_commitment(column));
+            if let Some(bounds) = accessor.column_bounds(column) {
+                snapshot.column_bounds.insert(column, bounds);
+            }
+            if let Some(epoch) = accessor.column_epoch(column) {
+                snapshot.column_epochs.insert(column, epoch);
+            }
+        }
+        snapshot
+    }
+
+    /// Returns the metadata of the table, if it is in the snapshot.
+    pub fn table_metadata(&self, table_ref: TableRef) -> Option<&TableSnapshotMetadata> {
+        self.tables.get(&table_ref)
+    }
+
+    /// Returns the commitment of the column, if it is in the snapshot.
+    pub fn commitment(&self, column: &ColumnRef) -> Option<&C> {
+        self.commitments.get(column)
+    }
+}
+
+impl<C: Commitment> MetadataAccessor for CommitmentSnapshot<C> {
+    fn get_length(&self, table_ref: TableRef) -> usize {
+        self.tables.get(&table_ref).unwrap().length
+    }
+
+    fn get_offset(&self, table_ref: TableRef) -> usize {
+        self.tables.get(&table_ref).unwrap().offset
+    }
+
+    fn schema(&self) -> Vec<(TableRef, Vec<ColumnField>)> {
+        self.tables
+            .keys()
+            .map(|&table_ref| {
+                (
+                    table_ref,
+                    self.commitments
+                        .keys()
+                        .filter(|column| column.table_ref() == table_ref)
+                        .map(|column| ColumnField::new(column.column_id(), *column.column_type()))
+                        .collect(),
+                )
+            })
+            .collect()
+    }
+
+    fn column_bounds(&self, column: ColumnRef) -> Option<(i64, i64)> {
+        self.column_bounds.get(&column).copied()
+    }
+
+    fn column_epoch(&self, column: ColumnRef) -> Option<u64> {
+        self.column_epochs.get(&column).copied()
+    }
+}
+
+impl<C: Commitment> CommitmentAccessor<C> for CommitmentSnapshot<C> {
+    fn get_commitment(&self, column: ColumnRef) -> C {
+        *self.commitments.get(&column).unwrap()
+    }
+}
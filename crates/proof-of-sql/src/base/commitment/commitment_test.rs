@@ -0,0 +1,41 @@
+use super::{commitment_conformance_test::test_commitment_conformance, Commitment};
+use crate::base::scalar::Curve25519Scalar;
+use curve25519_dalek::RistrettoPoint;
+
+#[test]
+fn we_can_update_a_commitment_with_appended_rows() {
+    let rows: Vec<Curve25519Scalar> = (0..100_i64)
+        .map(|i| Curve25519Scalar::from(i * i - 7))
+        .collect();
+
+    let mut full_commitment = [RistrettoPoint::default()];
+    RistrettoPoint::compute_commitments(&mut full_commitment, &[rows[..].into()], 0, &());
+
+    let mut partial_commitment = [RistrettoPoint::default()];
+    RistrettoPoint::compute_commitments(&mut partial_commitment, &[rows[..90].into()], 0, &());
+    let updated_commitment =
+        RistrettoPoint::update_commitment(&partial_commitment[0], &rows[90..], 90, &());
+
+    assert_eq!(updated_commitment, full_commitment[0]);
+}
+
+#[test]
+fn we_can_update_a_commitment_with_a_non_zero_offset() {
+    let rows: Vec<Curve25519Scalar> = (0..10_i64).map(Curve25519Scalar::from).collect();
+
+    let mut full_commitment = [RistrettoPoint::default()];
+    RistrettoPoint::compute_commitments(&mut full_commitment, &[rows[..].into()], 5, &());
+
+    let updated_commitment =
+        RistrettoPoint::update_commitment(&RistrettoPoint::default(), &rows[..], 5, &());
+    assert_eq!(updated_commitment, full_commitment[0]);
+
+    let updated_commitment =
+        RistrettoPoint::update_commitment(&RistrettoPoint::default(), &rows[..], 6, &());
+    assert_ne!(updated_commitment, full_commitment[0]);
+}
+
+#[test]
+fn ristretto_commitments_used_by_the_inner_product_proof_pass_the_conformance_suite() {
+    test_commitment_conformance::<RistrettoPoint>(&());
+}
@@ -33,13 +33,32 @@ pub use column_commitments::{
 
 mod table_commitment;
 pub use table_commitment::{
-    AppendTableCommitmentError, MixedLengthColumns, NegativeRange, TableCommitment,
-    TableCommitmentArithmeticError, TableCommitmentFromColumnsError,
+    commit_table, AppendTableCommitmentError, MixedLengthColumns, NegativeRange,
+    PrependTableCommitmentError, TableCommitment, TableCommitmentArithmeticError,
+    TableCommitmentFromColumnsError,
 };
 
 mod query_commitments;
 pub use query_commitments::{QueryCommitments, QueryCommitmentsExt};
 
+mod commitment_snapshot;
+pub use commitment_snapshot::{CommitmentSnapshot, TableSnapshotMetadata};
+
+mod static_commitment_accessor;
+pub use static_commitment_accessor::StaticCommitmentAccessor;
+
+mod commitment_digest;
+pub use commitment_digest::{CommitmentDigest, DigestCommitmentAccessor};
+#[cfg(all(test, feature = "blitzar", feature = "prover"))]
+mod commitment_digest_test;
+
+mod commitment_merkle;
+pub use commitment_merkle::{
+    CommitmentMerkleRoot, CommitmentMerkleTree, MerkleCommitmentAccessor, MerkleInclusionProof,
+};
+#[cfg(all(test, feature = "blitzar", feature = "prover"))]
+mod commitment_merkle_test;
+
 /// A trait for using commitment schemes generically.
 pub trait Commitment:
     AddAssign
@@ -72,6 +91,29 @@ pub trait Commitment:
         offset: usize,
         setup: &Self::PublicSetup<'_>,
     );
+
+    /// Update an existing commitment to a column with rows appended to the column.
+    ///
+    /// `start_offset` is the generator offset of the first appended row, which is typically the
+    /// generator offset of the column plus its length. Because the commitment is linear, only the
+    /// appended rows need to be committed to.
+    fn update_commitment(
+        existing: &Self,
+        appended_rows: &[Self::Scalar],
+        start_offset: usize,
+        setup: &Self::PublicSetup<'_>,
+    ) -> Self {
+        let mut partial_commitment = [Self::default()];
+        Self::compute_commitments(
+            &mut partial_commitment,
+            &[appended_rows.into()],
+            start_offset,
+            setup,
+        );
+        let mut commitment = *existing;
+        commitment += partial_commitment[0];
+        commitment
+    }
 }
 
 impl Commitment for RistrettoPoint {
@@ -115,3 +157,9 @@ mod commitment_evaluation_proof;
 pub use commitment_evaluation_proof::CommitmentEvaluationProof;
 #[cfg(test)]
 pub(crate) mod commitment_evaluation_proof_test;
+
+#[cfg(test)]
+pub(crate) mod commitment_conformance_test;
+
+#[cfg(all(test, feature = "blitzar"))]
+mod commitment_test;
@@ -1,4 +1,4 @@
-use super::{Commitment, TableCommitment};
+use super::{Bounds, ColumnBounds, Commitment, TableCommitment};
 use crate::base::database::{
     ColumnField, ColumnRef, ColumnType, CommitmentAccessor, MetadataAccessor, SchemaAccessor,
     TableRef,
@@ -12,6 +12,9 @@ use proof_of_sql_parser::Identifier;
 /// - [`MetadataAccessor`]
 /// - [`CommitmentAccessor`]
 /// - [`SchemaAccessor`]
+///
+/// The bounds of a column are the ones recorded in its commitment metadata. Table commitments are
+/// not versioned, so no column epochs are advertised.
 pub type QueryCommitments<C> = IndexMap<TableRef, TableCommitment<C>>;
 
 /// A trait for extending the functionality of the [`QueryCommitments`] alias.
@@ -72,6 +75,43 @@ impl<C: Commitment> MetadataAccessor for QueryCommitments<C> {
 
         table_commitment.range().start
     }
+
+    fn schema(&self) -> Vec<(TableRef, Vec<ColumnField>)> {
+        self.iter()
+            .map(|(&table_ref, table_commitment)| {
+                (
+                    table_ref,
+                    table_commitment
+                        .column_commitments()
+                        .column_metadata()
+                        .iter()
+                        .map(|(&identifier, column_metadata)| {
+                            ColumnField::new(identifier, *column_metadata.column_type())
+                        })
+                        .collect(),
+                )
+            })
+            .collect()
+    }
+
+    fn column_bounds(&self, column: ColumnRef) -> Option<(i64, i64)> {
+        let column_metadata = self
+            .get(&column.table_ref())?
+            .column_commitments()
+            .get_metadata(&column.column_id())?;
+        match column_metadata.bounds() {
+            ColumnBounds::SmallInt(Bounds::Sharp(bounds) | Bounds::Bounded(bounds)) => {
+                Some(((*bounds.min()).into(), (*bounds.max()).into()))
+            }
+            ColumnBounds::Int(Bounds::Sharp(bounds) | Bounds::Bounded(bounds)) => {
+                Some(((*bounds.min()).into(), (*bounds.max()).into()))
+            }
+            ColumnBounds::BigInt(Bounds::Sharp(bounds) | Bounds::Bounded(bounds)) => {
+                Some((*bounds.min(), *bounds.max()))
+            }
+            _ => None,
+        }
+    }
 }
 
 impl<C: Commitment> CommitmentAccessor<C> for QueryCommitments<C> {
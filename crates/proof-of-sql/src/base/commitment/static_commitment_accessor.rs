@@ -0,0 +1,95 @@
+use super::{Commitment, TableSnapshotMetadata};
+use crate::base::database::{
+    ColumnField, ColumnRef, CommitmentAccessor, MetadataAccessor, TableRef,
+};
+use indexmap::IndexMap;
+
+/// A [`CommitmentAccessor`] built from the public commitments and schema of a set of columns.
+///
+/// Thin verifiers, which never see the data, can use this to verify proofs from only the
+/// commitments of the columns a query references, along with the length and offset of
+/// their tables.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct StaticCommitmentAccessor<C> {
+    tables: IndexMap<TableRef, TableSnapshotMetadata>,
+    commitments: IndexMap<ColumnRef, C>,
+    column_bounds: IndexMap<ColumnRef, (i64, i64)>,
+    column_epochs: IndexMap<ColumnRef, u64>,
+}
+
+impl<C: Commitment> StaticCommitmentAccessor<C> {
+    /// Create a new `StaticCommitmentAccessor` from each column's commitment, along with the
+    /// length and offset of its table.
+    ///
+    /// # Panics
+    ///
+    /// Panics if two columns of the same table disagree on the length or offset of the table.
+    pub fn from_columns(columns: Vec<(ColumnRef, C, TableSnapshotMetadata)>) -> Self {
+        let mut tables = IndexMap::new();
+        let mut commitments = IndexMap::new();
+        for (column, commitment, metadata) in columns {
+            let table_metadata = *tables.entry(column.table_ref()).or_insert(metadata);
+            assert_eq!(
+                table_metadata, metadata,
+                "columns of a table must agree on its length and offset"
+            );
+            commitments.insert(column, commitment);
+        }
+        Self {
+            tables,
+            commitments,
+            column_bounds: IndexMap::new(),
+            column_epochs: IndexMap::new(),
+        }
+    }
+
+    /// Declare `(min, max)` bounds on the values of a column.
+    pub fn set_column_bounds(&mut self, column: ColumnRef, bounds: (i64, i64)) {
+        self.column_bounds.insert(column, bounds);
+    }
+
+    /// Declare the epoch of the data of a column.
+    pub fn set_column_epoch(&mut self, column: ColumnRef, epoch: u64) {
+        self.column_epochs.insert(column, epoch);
+    }
+}
+
+impl<C: Commitment> MetadataAccessor for StaticCommitmentAccessor<C> {
+    fn get_length(&self, table_ref: TableRef) -> usize {
+        self.tables.get(&table_ref).unwrap().length
+    }
+
+    fn get_offset(&self, table_ref: TableRef) -> usize {
+        self.tables.get(&table_ref).unwrap().offset
+    }
+
+    fn schema(&self) -> Vec<(TableRef, Vec<ColumnField>)> {
+        self.tables
+            .keys()
+            .map(|&table_ref| {
+                (
+                    table_ref,
+                    self.commitments
+                        .keys()
+                        .filter(|column| column.table_ref() == table_ref)
+                        .map(|column| ColumnField::new(column.column_id(), *column.column_type()))
+                        .collect(),
+                )
+            })
+            .collect()
+    }
+
+    fn column_bounds(&self, column: ColumnRef) -> Option<(i64, i64)> {
+        self.column_bounds.get(&column).copied()
+    }
+
+    fn column_epoch(&self, column: ColumnRef) -> Option<u64> {
+        self.column_epochs.get(&column).copied()
+    }
+}
+
+impl<C: Commitment> CommitmentAccessor<C> for StaticCommitmentAccessor<C> {
+    fn get_commitment(&self, column: ColumnRef) -> C {
+        *self.commitments.get(&column).unwrap()
+    }
+}
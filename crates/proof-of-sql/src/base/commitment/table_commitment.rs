@@ -1,12 +1,13 @@
 use super::{
     committable_column::CommittableColumn, AppendColumnCommitmentsError, ColumnCommitments,
-    ColumnCommitmentsMismatch, Commitment, DuplicateIdentifiers,
+    ColumnCommitmentsMismatch, Commitment, DuplicateIdentifiers, VecCommitmentExt,
 };
 #[cfg(feature = "arrow")]
 use crate::base::database::{ArrayRefExt, ArrowArrayToColumnConversionError};
 use crate::base::{
     database::{Column, ColumnField, CommitmentAccessor, OwnedTable, TableRef},
     scalar::Scalar,
+    MleAllocator,
 };
 #[cfg(feature = "arrow")]
 use arrow::record_batch::RecordBatch;
@@ -48,6 +49,20 @@ pub enum AppendTableCommitmentError {
     AppendColumnCommitments(#[from] AppendColumnCommitmentsError),
 }
 
+/// Errors that can occur when attempting to prepend rows to a [`TableCommitment`].
+#[derive(Debug, Error)]
+pub enum PrependTableCommitmentError {
+    /// Cannot prepend columns of mixed length to existing [`TableCommitment`].
+    #[error(transparent)]
+    MixedLengthColumns(#[from] MixedLengthColumns),
+    /// Encountered error when appending internal [`ColumnCommitments`].
+    #[error(transparent)]
+    AppendColumnCommitments(#[from] AppendColumnCommitmentsError),
+    /// Cannot prepend more rows than there are generators before the [`TableCommitment`].
+    #[error(transparent)]
+    NegativeRange(#[from] NegativeRange),
+}
+
 /// Errors that can occur when performing arithmetic on [`TableCommitment`]s.
 #[derive(Debug, Error)]
 pub enum TableCommitmentArithmeticError {
@@ -231,6 +246,45 @@ impl<C: Commitment> TableCommitment<C> {
         Ok(())
     }
 
+    /// Prepend rows of data from the provided columns to the existing [`TableCommitment`].
+    ///
+    /// The new rows end where the [`TableCommitment`]'s current range starts, so they are
+    /// committed with the generators before it. See
+    /// [`SignedOffset`](crate::base::database::SignedOffset) for leaving room for such rows.
+    ///
+    /// Will error on a variety of mismatches, if the provided columns have mixed length, or if
+    /// there are fewer generators before the current range than new rows.
+    pub fn try_prepend_rows<'a, COL>(
+        &mut self,
+        columns: impl IntoIterator<Item = (&'a Identifier, COL)>,
+        setup: &C::PublicSetup<'_>,
+    ) -> Result<(), PrependTableCommitmentError>
+    where
+        COL: Into<CommittableColumn<'a>>,
+    {
+        let (identifiers, committable_columns): (Vec<&Identifier>, Vec<CommittableColumn>) =
+            columns
+                .into_iter()
+                .map(|(identifier, column)| (identifier, column.into()))
+                .unzip();
+
+        let num_rows = num_rows_of_columns(&committable_columns)?;
+        let start = self
+            .range
+            .start
+            .checked_sub(num_rows)
+            .ok_or(NegativeRange)?;
+
+        self.column_commitments.try_append_rows_with_offset(
+            identifiers.into_iter().zip(committable_columns.into_iter()),
+            start,
+            setup,
+        )?;
+        self.range.start = start;
+
+        Ok(())
+    }
+
     /// Append data of the provided table to the exiting [`TableCommitment`].
     ///
     /// Will error on a variety of mismatches.
@@ -419,7 +473,7 @@ impl<C: Commitment> TableCommitment<C> {
 #[cfg(feature = "arrow")]
 fn batch_to_columns<'a, S: Scalar + 'a>(
     batch: &'a RecordBatch,
-    alloc: &'a Bump,
+    alloc: &'a dyn MleAllocator,
 ) -> Result<Vec<(Identifier, Column<'a, S>)>, RecordBatchToColumnsError> {
     batch
         .schema()
@@ -434,6 +488,25 @@ fn batch_to_columns<'a, S: Scalar + 'a>(
         .collect()
 }
 
+/// Computes the commitment to each column of `table`, with the table's first row at `offset`.
+///
+/// These are the commitments a verifier expects for the table's columns, so they can be published
+/// before any query against the table is proven.
+pub fn commit_table<C: Commitment, S: Scalar>(
+    table: &OwnedTable<S>,
+    offset: usize,
+    setup: &C::PublicSetup<'_>,
+) -> Vec<(ColumnField, C)> {
+    let commitments =
+        Vec::<C>::from_columns_with_offset(table.inner_table().values(), offset, setup);
+    table
+        .inner_table()
+        .iter()
+        .map(|(identifier, column)| ColumnField::new(*identifier, column.column_type()))
+        .zip(commitments)
+        .collect()
+}
+
 /// Return the number of rows for the provided columns, erroring if they have mixed length.
 fn num_rows_of_columns<'a>(
     committable_columns: impl IntoIterator<Item = &'a CommittableColumn<'a>>,
@@ -457,7 +530,10 @@ mod tests {
     use super::*;
     use crate::{
         base::{
-            database::{owned_table_utility::*, OwnedColumn},
+            commitment::InnerProductProof,
+            database::{
+                owned_table_utility::*, ColumnRef, ColumnType, OwnedColumn, OwnedTableTestAccessor,
+            },
             scalar::Curve25519Scalar,
         },
         record_batch,
@@ -465,6 +541,75 @@ mod tests {
     use curve25519_dalek::RistrettoPoint;
     use indexmap::IndexMap;
 
+    #[test]
+    fn we_can_commit_to_a_table_as_the_prover_does() {
+        let table_ref = "sxt.test".parse().unwrap();
+        let table = owned_table::<Curve25519Scalar>([
+            bigint("a", [1, 2, 3]),
+            varchar("b", ["x", "y", "z"]),
+            boolean("c", [true, false, true]),
+        ]);
+        let offset = 5;
+        let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(
+            table_ref,
+            table.clone(),
+            offset,
+            (),
+        );
+
+        let commitments = commit_table::<RistrettoPoint, _>(&table, offset, &());
+        assert_eq!(
+            commitments
+                .iter()
+                .map(|(field, _)| *field)
+                .collect::<Vec<_>>(),
+            [
+                ColumnField::new("a".parse().unwrap(), ColumnType::BigInt),
+                ColumnField::new("b".parse().unwrap(), ColumnType::VarChar),
+                ColumnField::new("c".parse().unwrap(), ColumnType::Boolean),
+            ]
+        );
+        for (field, commitment) in commitments {
+            let column_ref = ColumnRef::new(table_ref, field.name(), field.data_type());
+            assert_eq!(accessor.get_commitment(column_ref), commitment);
+        }
+    }
+
+    #[test]
+    fn we_can_prepend_rows_to_a_table_commitment() {
+        let bigint_id: Identifier = "bigint_column".parse().unwrap();
+        let varchar_id: Identifier = "varchar_column".parse().unwrap();
+        let table = owned_table::<Curve25519Scalar>([
+            bigint(bigint_id, [1, 5, -5, 0, 10]),
+            varchar(varchar_id, ["Lorem", "ipsum", "dolor", "sit", "amet"]),
+        ]);
+        let earlier_rows = owned_table::<Curve25519Scalar>([
+            bigint(bigint_id, [1, 5]),
+            varchar(varchar_id, ["Lorem", "ipsum"]),
+        ]);
+        let later_rows = owned_table::<Curve25519Scalar>([
+            bigint(bigint_id, [-5, 0, 10]),
+            varchar(varchar_id, ["dolor", "sit", "amet"]),
+        ]);
+
+        let mut table_commitment =
+            TableCommitment::<RistrettoPoint>::from_owned_table_with_offset(&later_rows, 4, &());
+        table_commitment
+            .try_prepend_rows(earlier_rows.inner_table(), &())
+            .unwrap();
+        assert_eq!(table_commitment.range(), &(2..7));
+        assert_eq!(
+            table_commitment,
+            TableCommitment::from_owned_table_with_offset(&table, 2, &())
+        );
+
+        // there are only two generators before the range
+        assert!(matches!(
+            table_commitment.try_prepend_rows(later_rows.inner_table(), &()),
+            Err(PrependTableCommitmentError::NegativeRange(_))
+        ));
+    }
+
     #[test]
     #[allow(clippy::reversed_empty_ranges)]
     fn we_cannot_construct_table_commitment_with_negative_range() {
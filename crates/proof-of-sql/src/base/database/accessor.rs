@@ -1,6 +1,6 @@
 use crate::base::{
-    commitment::Commitment,
-    database::{Column, ColumnRef, ColumnType, TableRef},
+    commitment::{Commitment, CommitmentDigest, CommitmentMerkleRoot},
+    database::{Column, ColumnField, ColumnRef, ColumnType, TableRef},
     scalar::Scalar,
 };
 use proof_of_sql_parser::Identifier;
@@ -20,6 +20,30 @@ pub trait MetadataAccessor {
     /// If the data span has its first row starting at the ith table row,
     /// this `get_offset` should then return `i`.
     fn get_offset(&self, table_ref: TableRef) -> usize;
+
+    /// Return all the tables known to this accessor along with their columns
+    ///
+    /// This allows a query to be validated against the available tables
+    /// before any plan is built.
+    fn schema(&self) -> Vec<(TableRef, Vec<ColumnField>)>;
+
+    /// Return the known `(min, max)` bounds of an integer column, if any
+    ///
+    /// Every value of the column must lie within the bounds, since a proof may rely on them
+    /// in place of checking the values themselves. By default, no bounds are known.
+    fn column_bounds(&self, _column: ColumnRef) -> Option<(i64, i64)> {
+        None
+    }
+
+    /// Return the epoch of the data of a column, if it is versioned
+    ///
+    /// The epochs of the columns a query references are absorbed into the transcript, so a
+    /// proof only verifies against an accessor advertising the epochs it was created at. This
+    /// prevents proofs of stale data from verifying once a column is updated. By default,
+    /// columns are not versioned.
+    fn column_epoch(&self, _column: ColumnRef) -> Option<u64> {
+        None
+    }
 }
 
 /// Access commitments of database columns.
@@ -52,6 +76,26 @@ pub trait CommitmentAccessor<C: Commitment>: MetadataAccessor {
     fn get_commitment(&self, column: ColumnRef) -> C;
 }
 
+/// Access digests of the commitments of database columns.
+///
+/// Verifiers that only store a digest of each column commitment use this, together with
+/// the commitments supplied by the prover, to construct a
+/// [`DigestCommitmentAccessor`](crate::base::commitment::DigestCommitmentAccessor).
+pub trait DigestAccessor: MetadataAccessor {
+    /// Return the digest of the full table column commitment
+    fn get_digest(&self, column: ColumnRef) -> CommitmentDigest;
+}
+
+/// Access the Merkle root of the commitments of database columns.
+///
+/// Light clients that only store this root use it, together with the commitments and
+/// inclusion proofs supplied by the prover, to construct a
+/// [`MerkleCommitmentAccessor`](crate::base::commitment::MerkleCommitmentAccessor).
+pub trait MerkleRootAccessor: MetadataAccessor {
+    /// Return the root of the Merkle tree over the column commitments
+    fn get_commitment_root(&self) -> CommitmentMerkleRoot;
+}
+
 /// Access database columns of an in-memory table span.
 ///
 /// Prover uses this information to process a query.
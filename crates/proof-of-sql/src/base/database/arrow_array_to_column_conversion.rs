@@ -1,5 +1,5 @@
 use super::scalar_and_i256_conversions::convert_i256_to_scalar;
-use crate::base::{database::Column, math::decimal::Precision, scalar::Scalar};
+use crate::base::{database::Column, math::decimal::Precision, scalar::Scalar, MleAllocator};
 use arrow::{
     array::{
         Array, ArrayRef, BooleanArray, Decimal128Array, Decimal256Array, Int16Array, Int32Array,
@@ -63,7 +63,7 @@ pub trait ArrayRefExt {
     /// Note: this function must not be called from unsupported or nullable arrays as it will panic.
     fn to_column<'a, S: Scalar>(
         &'a self,
-        alloc: &'a Bump,
+        alloc: &'a dyn MleAllocator,
         range: &Range<usize>,
         scals: Option<&'a [S]>,
     ) -> Result<Column<'a, S>, ArrowArrayToColumnConversionError>;
@@ -161,7 +161,7 @@ impl ArrayRefExt for ArrayRef {
     /// empty `Column` for any empty tange if it is in-bounds.
     ///
     /// # Parameters
-    /// - `alloc`: Reference to a `Bump` allocator used for memory allocation during the conversion.
+    /// - `alloc`: Reference to the allocator used for memory allocation during the conversion.
     /// - `range`: Reference to a `Range<usize>` specifying the slice of the array to convert.
     /// - `precomputed_scals`: Optional reference to a slice of `Curve25519Scalar` values.
     ///    VarChar columns store hashes to their values as scalars, which can be provided here.
@@ -177,7 +177,7 @@ impl ArrayRefExt for ArrayRef {
     /// - When any range is OOB, i.e. indexing 3..6 or 5..5 on array of size 2.
     fn to_column<'a, S: Scalar>(
         &'a self,
-        alloc: &'a Bump,
+        alloc: &'a dyn MleAllocator,
         range: &Range<usize>,
         precomputed_scals: Option<&'a [S]>,
     ) -> Result<Column<'a, S>, ArrowArrayToColumnConversionError> {
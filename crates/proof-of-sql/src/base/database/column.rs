@@ -1,8 +1,7 @@
-use super::{LiteralValue, OwnedColumn, TableRef};
-use crate::base::{
-    math::decimal::{scale_scalar, Precision},
-    scalar::Scalar,
-};
+use super::{IdentifierError, LiteralValue, MetadataAccessor, OwnedColumn, TableRef};
+#[cfg(feature = "prover")]
+use crate::base::math::decimal::scale_scalar;
+use crate::base::{math::decimal::Precision, proof::ProofError, scalar::Scalar, MleAllocator};
 #[cfg(feature = "arrow")]
 use arrow::datatypes::{DataType, Field, TimeUnit as ArrowTimeUnit};
 use bumpalo::Bump;
@@ -10,7 +9,9 @@ use proof_of_sql_parser::{
     posql_time::{PoSQLTimeUnit, PoSQLTimeZone},
     Identifier,
 };
-use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
+#[cfg(feature = "prover")]
+use rayon::iter::IntoParallelRefIterator;
+use rayon::iter::ParallelIterator;
 use serde::{Deserialize, Serialize};
 use std::sync::Arc;
 
@@ -92,7 +93,7 @@ impl<'a, S: Scalar> Column<'a, S> {
     pub fn from_literal_with_length(
         literal: &LiteralValue<S>,
         length: usize,
-        alloc: &'a Bump,
+        alloc: &'a dyn MleAllocator,
     ) -> Self {
         match literal {
             LiteralValue::Boolean(value) => {
@@ -127,7 +128,10 @@ impl<'a, S: Scalar> Column<'a, S> {
     }
 
     /// Convert an `OwnedColumn` to a `Column`
-    pub fn from_owned_column(owned_column: &'a OwnedColumn<S>, alloc: &'a Bump) -> Self {
+    pub fn from_owned_column(
+        owned_column: &'a OwnedColumn<S>,
+        alloc: &'a dyn MleAllocator,
+    ) -> Self {
         match owned_column {
             OwnedColumn::Boolean(col) => Column::Boolean(col.as_slice()),
             OwnedColumn::SmallInt(col) => Column::SmallInt(col.as_slice()),
@@ -154,6 +158,7 @@ impl<'a, S: Scalar> Column<'a, S> {
     }
 
     /// Returns the column as a slice of booleans if it is a boolean column. Otherwise, returns None.
+    #[cfg(feature = "prover")]
     pub(crate) fn as_boolean(&self) -> Option<&'a [bool]> {
         match self {
             Self::Boolean(col) => Some(col),
@@ -164,6 +169,7 @@ impl<'a, S: Scalar> Column<'a, S> {
     /// Returns element at index as scalar
     ///
     /// Note that if index is out of bounds, this function will return None
+    #[cfg(feature = "prover")]
     pub(crate) fn scalar_at(&self, index: usize) -> Option<S> {
         (index < self.len()).then_some(match self {
             Self::Boolean(col) => S::from(col[index]),
@@ -179,6 +185,7 @@ impl<'a, S: Scalar> Column<'a, S> {
     }
 
     /// Convert a column to a vector of Scalar values with scaling
+    #[cfg(feature = "prover")]
     pub(crate) fn to_scalar_with_scaling(&self, scale: i8) -> Vec<S> {
         let scale_factor = scale_scalar(S::ONE, scale).expect("Invalid scale factor");
         match self {
@@ -472,6 +479,27 @@ impl ColumnRef {
         }
     }
 
+    /// Create a new `ColumnRef`, looking up the column's type in the schema of `accessor`
+    ///
+    /// Returns an error if the table has no column named `column_id`.
+    pub fn resolve(
+        table_ref: TableRef,
+        column_id: Identifier,
+        accessor: &dyn MetadataAccessor,
+    ) -> Result<Self, ProofError> {
+        accessor
+            .schema()
+            .into_iter()
+            .filter(|(table, _)| *table == table_ref)
+            .flat_map(|(_, fields)| fields)
+            .find(|field| field.name() == column_id)
+            .map(|field| Self::new(table_ref, column_id, field.data_type()))
+            .ok_or(ProofError::ColumnNotFound {
+                table: table_ref,
+                column: column_id,
+            })
+    }
+
     /// Returns the table reference of this column
     pub fn table_ref(&self) -> TableRef {
         self.table_ref
@@ -504,6 +532,15 @@ impl ColumnField {
         ColumnField { name, data_type }
     }
 
+    /// Create a new `ColumnField` from a string name and a type
+    ///
+    /// Returns an error if `name` is not a valid identifier.
+    pub fn try_new(name: &str, data_type: ColumnType) -> Result<ColumnField, IdentifierError> {
+        let name = Identifier::try_new(name)
+            .map_err(|_| IdentifierError::InvalidIdentifier(name.to_string()))?;
+        Ok(ColumnField::new(name, data_type))
+    }
+
     /// Returns the name of the column
     pub fn name(&self) -> Identifier {
         self.name
@@ -992,4 +1029,31 @@ mod tests {
         assert_eq!(column.column_type().byte_size(), 8);
         assert_eq!(column.column_type().bit_size(), 64);
     }
+
+    #[test]
+    fn we_can_create_a_column_field_from_a_valid_name() {
+        let field = ColumnField::try_new("a1", ColumnType::BigInt).unwrap();
+        assert_eq!(field.name(), Identifier::try_new("a1").unwrap());
+        assert_eq!(field.data_type(), ColumnType::BigInt);
+    }
+
+    #[test]
+    fn we_cannot_create_a_column_field_from_an_invalid_name() {
+        assert!(matches!(
+            ColumnField::try_new("a b", ColumnType::BigInt),
+            Err(IdentifierError::InvalidIdentifier(_))
+        ));
+        assert!(matches!(
+            ColumnField::try_new("1a", ColumnType::BigInt),
+            Err(IdentifierError::InvalidIdentifier(_))
+        ));
+        assert!(matches!(
+            ColumnField::try_new("", ColumnType::BigInt),
+            Err(IdentifierError::InvalidIdentifier(_))
+        ));
+        assert!(matches!(
+            ColumnField::try_new(&"a".repeat(65), ColumnType::BigInt),
+            Err(IdentifierError::InvalidIdentifier(_))
+        ));
+    }
 }
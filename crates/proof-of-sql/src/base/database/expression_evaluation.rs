@@ -1,9 +1,10 @@
 use super::{ExpressionEvaluationError, ExpressionEvaluationResult};
 use crate::base::{
-    database::{OwnedColumn, OwnedTable},
+    database::{filter_util::filter_columns, Column, OwnedColumn, OwnedTable},
     math::decimal::{try_into_to_scalar, Precision},
     scalar::Scalar,
 };
+use bumpalo::Bump;
 use proof_of_sql_parser::{
     intermediate_ast::{BinaryOperator, Expression, Literal, UnaryOperator},
     Identifier,
@@ -24,6 +25,35 @@ impl<S: Scalar> OwnedTable<S> {
         }
     }
 
+    /// Filter the table by a boolean expression, keeping the rows where it is true.
+    ///
+    /// This is a non-proving reference implementation of `SELECT * FROM <table> WHERE <predicate>`,
+    /// which is useful for checking the results of proofs. Rows are selected in the same way
+    /// as by the prover.
+    pub fn filter(&self, predicate: &Expression) -> ExpressionEvaluationResult<Self> {
+        let selection = match self.evaluate(predicate)? {
+            OwnedColumn::Boolean(selection) => selection,
+            column => Err(ExpressionEvaluationError::NonBooleanPredicate(
+                column.column_type(),
+            ))?,
+        };
+        let alloc = Bump::new();
+        let columns: Vec<_> = self
+            .inner_table()
+            .values()
+            .map(|column| Column::from_owned_column(column, &alloc))
+            .collect();
+        let (filtered_columns, _) = filter_columns(&alloc, &columns, &selection);
+        Ok(Self::try_new(
+            self.inner_table()
+                .keys()
+                .copied()
+                .zip(filtered_columns.iter().map(OwnedColumn::from))
+                .collect(),
+        )
+        .expect("filtered columns have the same length"))
+    }
+
     fn evaluate_column(
         &self,
         identifier: &Identifier,
@@ -1,4 +1,7 @@
-use crate::base::{database::ColumnOperationError, math::decimal::DecimalError};
+use crate::base::{
+    database::{ColumnOperationError, ColumnType},
+    math::decimal::DecimalError,
+};
 use thiserror::Error;
 
 /// Errors from evaluation of `Expression`s.
@@ -16,6 +19,9 @@ pub enum ExpressionEvaluationError {
     /// Error in decimal conversion
     #[error(transparent)]
     DecimalConversionError(#[from] DecimalError),
+    /// A filter predicate is not boolean
+    #[error("Filter predicate must be boolean but has type {0}")]
+    NonBooleanPredicate(ColumnType),
 }
 
 /// Result type for expression evaluation
@@ -1,7 +1,7 @@
 use crate::base::{
     database::{
-        owned_table_utility::*, ColumnOperationError, ExpressionEvaluationError, OwnedColumn,
-        OwnedTable,
+        owned_table_utility::*, ColumnOperationError, ColumnType, ExpressionEvaluationError,
+        OwnedColumn, OwnedTable,
     },
     math::decimal::Precision,
     scalar::Curve25519Scalar,
@@ -248,3 +248,38 @@ fn we_cannot_evaluate_expressions_if_column_operation_errors_out() {
         ))
     ));
 }
+
+#[test]
+fn we_can_filter_a_table_by_a_predicate() {
+    let table: OwnedTable<Curve25519Scalar> = owned_table([
+        bigint("a", [1_i64, 2, 3, 4, 5]),
+        varchar("b", ["x", "y", "x", "z", "x"]),
+    ]);
+    let expr = equal(col("b"), lit("x".to_string()));
+    let expected_table = owned_table([bigint("a", [1_i64, 3, 5]), varchar("b", ["x", "x", "x"])]);
+    assert_eq!(table.filter(&expr).unwrap(), expected_table);
+
+    let expr = and(
+        le(col("a"), lit(2)),
+        not(equal(col("b"), lit("y".to_string()))),
+    );
+    let expected_table = owned_table([bigint("a", [1_i64]), varchar("b", ["x"])]);
+    assert_eq!(table.filter(&expr).unwrap(), expected_table);
+
+    // no rows selected
+    let expr = lit(false);
+    let expected_table = owned_table([bigint("a", [0_i64; 0]), varchar("b", [""; 0])]);
+    assert_eq!(table.filter(&expr).unwrap(), expected_table);
+}
+
+#[test]
+fn we_cannot_filter_a_table_by_a_non_boolean_predicate() {
+    let table: OwnedTable<Curve25519Scalar> = owned_table([bigint("a", [1_i64, 2, 3])]);
+    let expr = add(col("a"), lit(1));
+    assert_eq!(
+        table.filter(&expr),
+        Err(ExpressionEvaluationError::NonBooleanPredicate(
+            ColumnType::BigInt
+        ))
+    );
+}
@@ -1,5 +1,4 @@
-use crate::base::{database::Column, scalar::Scalar};
-use bumpalo::Bump;
+use crate::base::{database::Column, scalar::Scalar, MleAllocator};
 
 /// This function takes a selection vector and a set of columns and returns a
 /// new set of columns that only contains the selected rows. The function
@@ -8,7 +7,7 @@ use bumpalo::Bump;
 /// The function returns a tuple of the filtered columns and the number of
 /// rows in the filtered columns.
 pub fn filter_columns<'a, S: Scalar>(
-    alloc: &'a Bump,
+    alloc: &'a dyn MleAllocator,
     columns: &[Column<'a, S>],
     selection: &[bool],
 ) -> (Vec<Column<'a, S>>, usize) {
@@ -33,7 +32,7 @@ pub fn filter_columns<'a, S: Scalar>(
 /// new set of columns that only contains the selected indexes. It is assumed that
 /// the indexes are valid.
 pub fn filter_column_by_index<'a, S: Scalar>(
-    alloc: &'a Bump,
+    alloc: &'a dyn MleAllocator,
     column: &Column<'a, S>,
     indexes: &[usize],
 ) -> Column<'a, S> {
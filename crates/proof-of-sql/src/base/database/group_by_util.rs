@@ -3,6 +3,7 @@
 use crate::base::{
     database::{filter_util::filter_column_by_index, Column, OwnedColumn},
     scalar::Scalar,
+    MleAllocator,
 };
 use bumpalo::Bump;
 use core::cmp::Ordering;
@@ -43,7 +44,7 @@ pub enum AggregateColumnsError {
 /// This function takes a selection vector and a set of group_by and sum columns and returns
 /// the given columns aggregated by the group_by columns only for the selected rows.
 pub fn aggregate_columns<'a, S: Scalar>(
-    alloc: &'a Bump,
+    alloc: &'a dyn MleAllocator,
     group_by_columns_in: &[Column<'a, S>],
     sum_columns_in: &[Column<S>],
     max_columns_in: &[Column<S>],
@@ -121,7 +122,7 @@ pub fn aggregate_columns<'a, S: Scalar>(
 ///
 /// See [`sum_aggregate_slice_by_index_counts`] for an example. This is a helper wrapper around that function.
 pub(crate) fn sum_aggregate_column_by_index_counts<'a, S: Scalar>(
-    alloc: &'a Bump,
+    alloc: &'a dyn MleAllocator,
     column: &Column<S>,
     counts: &[usize],
     indexes: &[usize],
@@ -148,7 +149,7 @@ pub(crate) fn sum_aggregate_column_by_index_counts<'a, S: Scalar>(
 ///
 /// See [`max_aggregate_slice_by_index_counts`] for an example. This is a helper wrapper around that function.
 pub(crate) fn max_aggregate_column_by_index_counts<'a, S: Scalar>(
-    alloc: &'a Bump,
+    alloc: &'a dyn MleAllocator,
     column: &Column<S>,
     counts: &[usize],
     indexes: &[usize],
@@ -179,7 +180,7 @@ pub(crate) fn max_aggregate_column_by_index_counts<'a, S: Scalar>(
 ///
 /// See [`min_aggregate_slice_by_index_counts`] for an example. This is a helper wrapper around that function.
 pub(crate) fn min_aggregate_column_by_index_counts<'a, S: Scalar>(
-    alloc: &'a Bump,
+    alloc: &'a dyn MleAllocator,
     column: &Column<S>,
     counts: &[usize],
     indexes: &[usize],
@@ -225,7 +226,7 @@ pub(crate) fn min_aggregate_column_by_index_counts<'a, S: Scalar>(
 /// assert_eq!(result, expected);
 /// ```
 pub(crate) fn sum_aggregate_slice_by_index_counts<'a, S, T>(
-    alloc: &'a Bump,
+    alloc: &'a dyn MleAllocator,
     slice: &[T],
     counts: &[usize],
     indexes: &[usize],
@@ -266,7 +267,7 @@ where
 /// assert_eq!(result, expected);
 /// ```
 pub(crate) fn max_aggregate_slice_by_index_counts<'a, S, T>(
-    alloc: &'a Bump,
+    alloc: &'a dyn MleAllocator,
     slice: &[T],
     counts: &[usize],
     indexes: &[usize],
@@ -309,7 +310,7 @@ where
 /// assert_eq!(result, expected);
 /// ```
 pub(crate) fn min_aggregate_slice_by_index_counts<'a, S, T>(
-    alloc: &'a Bump,
+    alloc: &'a dyn MleAllocator,
     slice: &[T],
     counts: &[usize],
     indexes: &[usize],
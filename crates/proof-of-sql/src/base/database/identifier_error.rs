@@ -0,0 +1,9 @@
+use thiserror::Error;
+
+/// Errors from validating the name of a column or table.
+#[derive(Error, Debug, PartialEq, Eq)]
+pub enum IdentifierError {
+    /// The name is empty, too long, or contains characters not allowed in an identifier.
+    #[error("Invalid identifier: {0:?}")]
+    InvalidIdentifier(String),
+}
@@ -2,7 +2,10 @@
 //! accessor traits and the `OwnedTable` type along with some utility functions to convert
 //! between Arrow and `OwnedTable`.
 mod accessor;
-pub use accessor::{CommitmentAccessor, DataAccessor, MetadataAccessor, SchemaAccessor};
+pub use accessor::{
+    CommitmentAccessor, DataAccessor, DigestAccessor, MerkleRootAccessor, MetadataAccessor,
+    SchemaAccessor,
+};
 
 mod column;
 pub use column::{Column, ColumnField, ColumnRef, ColumnType};
@@ -21,11 +24,31 @@ pub use literal_value::LiteralValue;
 mod table_ref;
 pub use table_ref::TableRef;
 
+mod signed_offset;
+pub use signed_offset::{OffsetBeforeOrigin, SignedOffset};
+#[cfg(test)]
+mod table_ref_test;
+
+mod recording_accessor;
+pub use recording_accessor::{ColumnAccess, RecordingAccessor};
+
+mod range_source;
+pub use range_source::RangeSource;
+#[cfg(all(test, feature = "blitzar", feature = "prover"))]
+mod range_source_test;
+
 #[cfg(feature = "arrow")]
 mod arrow_array_to_column_conversion;
 #[cfg(feature = "arrow")]
 pub use arrow_array_to_column_conversion::{ArrayRefExt, ArrowArrayToColumnConversionError};
 
+#[cfg(feature = "parquet")]
+mod parquet_accessor;
+#[cfg(feature = "parquet")]
+pub use parquet_accessor::{ParquetAccessor, ParquetAccessorError};
+#[cfg(all(test, feature = "parquet", feature = "blitzar", feature = "prover"))]
+mod parquet_accessor_test;
+
 #[cfg(feature = "arrow")]
 mod record_batch_utility;
 #[cfg(feature = "arrow")]
@@ -40,14 +63,17 @@ mod owned_column;
 pub(crate) use owned_column::compare_indexes_by_owned_columns_with_direction;
 pub use owned_column::OwnedColumn;
 
+mod identifier_error;
+pub use identifier_error::IdentifierError;
+
 mod owned_column_error;
 pub use owned_column_error::{OwnedColumnError, OwnedColumnResult};
 
 pub(crate) mod owned_column_operation;
 
 mod owned_table;
-pub use owned_table::OwnedTable;
 pub(crate) use owned_table::OwnedTableError;
+pub use owned_table::{OwnedTable, OWNED_TABLE_SERIALIZATION_VERSION};
 #[cfg(test)]
 mod owned_table_test;
 pub mod owned_table_utility;
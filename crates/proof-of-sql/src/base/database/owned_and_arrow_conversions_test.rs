@@ -1,14 +1,15 @@
 use super::{OwnedColumn, OwnedTable};
 use crate::{
     base::{
-        database::{owned_table_utility::*, OwnedArrowConversionError},
+        database::{owned_table_utility::*, ColumnType, OwnedArrowConversionError},
         scalar::Curve25519Scalar,
     },
     record_batch,
+    sql::proof::QueryError,
 };
 use arrow::{
     array::{ArrayRef, BooleanArray, Decimal128Array, Float32Array, Int64Array, StringArray},
-    datatypes::Schema,
+    datatypes::{DataType, Field, Schema},
     record_batch::RecordBatch,
 };
 use indexmap::IndexMap;
@@ -147,3 +148,62 @@ fn we_panic_when_converting_an_owned_table_with_a_scalar_column() {
     let owned_table = owned_table::<Curve25519Scalar>([scalar("a", [0; 0])]);
     let _ = RecordBatch::try_from(owned_table);
 }
+
+#[test]
+fn we_can_build_an_owned_table_from_a_mixed_type_record_batch() {
+    let record_batch = record_batch!(
+        "int64" => [-1_i64, 0, 1],
+        "boolean" => [true, false, true],
+        "string" => ["a", "b", "c"],
+    );
+    let table = OwnedTable::<Curve25519Scalar>::try_from_record_batch(&record_batch).unwrap();
+    assert_eq!(
+        table
+            .inner_table()
+            .values()
+            .map(OwnedColumn::column_type)
+            .collect::<Vec<_>>(),
+        vec![ColumnType::BigInt, ColumnType::Boolean, ColumnType::VarChar]
+    );
+    assert_eq!(
+        table,
+        owned_table([
+            bigint("int64", [-1, 0, 1]),
+            boolean("boolean", [true, false, true]),
+            varchar("string", ["a", "b", "c"]),
+        ])
+    );
+}
+
+#[test]
+fn we_cannot_build_an_owned_table_from_a_record_batch_with_nulls_or_unsupported_types() {
+    let schema = Arc::new(Schema::new(vec![Field::new("a", DataType::Int64, true)]));
+    let array: ArrayRef = Arc::new(Int64Array::from(vec![Some(1), None]));
+    let record_batch = RecordBatch::try_new(schema, vec![array]).unwrap();
+    assert!(matches!(
+        OwnedTable::<Curve25519Scalar>::try_from_record_batch(&record_batch),
+        Err(QueryError::ArrowConversionError(
+            OwnedArrowConversionError::NullNotSupportedYet
+        ))
+    ));
+
+    let schema = Arc::new(Schema::new(vec![Field::new("s", DataType::Utf8, true)]));
+    let array: ArrayRef = Arc::new(StringArray::from(vec![Some("x"), None]));
+    let record_batch = RecordBatch::try_new(schema, vec![array]).unwrap();
+    assert!(matches!(
+        OwnedTable::<Curve25519Scalar>::try_from_record_batch(&record_batch),
+        Err(QueryError::ArrowConversionError(
+            OwnedArrowConversionError::NullNotSupportedYet
+        ))
+    ));
+
+    let schema = Arc::new(Schema::new(vec![Field::new("f", DataType::Float32, false)]));
+    let array: ArrayRef = Arc::new(Float32Array::from(vec![0.0]));
+    let record_batch = RecordBatch::try_new(schema, vec![array]).unwrap();
+    assert!(matches!(
+        OwnedTable::<Curve25519Scalar>::try_from_record_batch(&record_batch),
+        Err(QueryError::ArrowConversionError(
+            OwnedArrowConversionError::UnsupportedType(DataType::Float32)
+        ))
+    ));
+}
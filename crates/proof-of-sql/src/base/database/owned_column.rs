@@ -15,8 +15,9 @@ use proof_of_sql_parser::{
     intermediate_ast::OrderByDirection,
     posql_time::{PoSQLTimeUnit, PoSQLTimeZone},
 };
+use serde::{Deserialize, Serialize};
 
-#[derive(Debug, PartialEq, Clone, Eq)]
+#[derive(Debug, PartialEq, Clone, Eq, Serialize, Deserialize)]
 #[non_exhaustive]
 /// Supported types for OwnedColumn
 pub enum OwnedColumn<S: Scalar> {
@@ -131,9 +132,9 @@ impl<S: Scalar> OwnedColumn<S> {
             ColumnType::Boolean => Ok(OwnedColumn::Boolean(
                 scalars
                     .iter()
-                    .map(|s| -> Result<bool, _> { TryInto::<bool>::try_into(*s) })
-                    .collect::<Result<Vec<_>, _>>()
-                    .map_err(|_| {
+                    .map(|s| s.checked_into::<bool>())
+                    .collect::<Option<Vec<_>>>()
+                    .ok_or_else(|| {
                         OwnedColumnError::ScalarConversionError(
                             "Overflow in scalar conversions".to_string(),
                         )
@@ -142,9 +143,9 @@ impl<S: Scalar> OwnedColumn<S> {
             ColumnType::SmallInt => Ok(OwnedColumn::SmallInt(
                 scalars
                     .iter()
-                    .map(|s| -> Result<i16, _> { TryInto::<i16>::try_into(*s) })
-                    .collect::<Result<Vec<_>, _>>()
-                    .map_err(|_| {
+                    .map(|s| s.checked_into::<i16>())
+                    .collect::<Option<Vec<_>>>()
+                    .ok_or_else(|| {
                         OwnedColumnError::ScalarConversionError(
                             "Overflow in scalar conversions".to_string(),
                         )
@@ -153,9 +154,9 @@ impl<S: Scalar> OwnedColumn<S> {
             ColumnType::Int => Ok(OwnedColumn::Int(
                 scalars
                     .iter()
-                    .map(|s| -> Result<i32, _> { TryInto::<i32>::try_into(*s) })
-                    .collect::<Result<Vec<_>, _>>()
-                    .map_err(|_| {
+                    .map(|s| s.checked_into::<i32>())
+                    .collect::<Option<Vec<_>>>()
+                    .ok_or_else(|| {
                         OwnedColumnError::ScalarConversionError(
                             "Overflow in scalar conversions".to_string(),
                         )
@@ -164,9 +165,9 @@ impl<S: Scalar> OwnedColumn<S> {
             ColumnType::BigInt => Ok(OwnedColumn::BigInt(
                 scalars
                     .iter()
-                    .map(|s| -> Result<i64, _> { TryInto::<i64>::try_into(*s) })
-                    .collect::<Result<Vec<_>, _>>()
-                    .map_err(|_| {
+                    .map(|s| s.checked_into::<i64>())
+                    .collect::<Option<Vec<_>>>()
+                    .ok_or_else(|| {
                         OwnedColumnError::ScalarConversionError(
                             "Overflow in scalar conversions".to_string(),
                         )
@@ -175,9 +176,9 @@ impl<S: Scalar> OwnedColumn<S> {
             ColumnType::Int128 => Ok(OwnedColumn::Int128(
                 scalars
                     .iter()
-                    .map(|s| -> Result<i128, _> { TryInto::<i128>::try_into(*s) })
-                    .collect::<Result<Vec<_>, _>>()
-                    .map_err(|_| {
+                    .map(|s| s.checked_into::<i128>())
+                    .collect::<Option<Vec<_>>>()
+                    .ok_or_else(|| {
                         OwnedColumnError::ScalarConversionError(
                             "Overflow in scalar conversions".to_string(),
                         )
@@ -190,9 +191,9 @@ impl<S: Scalar> OwnedColumn<S> {
             ColumnType::TimestampTZ(tu, tz) => {
                 let raw_values: Vec<i64> = scalars
                     .iter()
-                    .map(|s| -> Result<i64, _> { TryInto::<i64>::try_into(*s) })
-                    .collect::<Result<Vec<_>, _>>()
-                    .map_err(|_| {
+                    .map(|s| s.checked_into::<i64>())
+                    .collect::<Option<Vec<_>>>()
+                    .ok_or_else(|| {
                         OwnedColumnError::ScalarConversionError(
                             "Overflow in scalar conversions".to_string(),
                         )
@@ -2,8 +2,15 @@ use super::OwnedColumn;
 use crate::base::scalar::Scalar;
 use indexmap::IndexMap;
 use proof_of_sql_parser::Identifier;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use thiserror::Error;
 
+/// The version of the wire format used when serializing an [`OwnedTable`].
+///
+/// This must be bumped whenever the serialized layout of [`OwnedTable`], [`OwnedColumn`],
+/// or [`super::ColumnType`] changes, e.g. if a variant is added or reordered.
+pub const OWNED_TABLE_SERIALIZATION_VERSION: u32 = 1;
+
 /// An error that occurs when working with tables.
 #[derive(Error, Debug, PartialEq, Eq)]
 pub enum OwnedTableError {
@@ -82,6 +89,41 @@ impl<S: Scalar> PartialEq for OwnedTable<S> {
     }
 }
 
+#[derive(Serialize)]
+struct SerializableOwnedTable<'a, S: Scalar> {
+    version: u32,
+    table: &'a IndexMap<Identifier, OwnedColumn<S>>,
+}
+
+#[derive(Deserialize)]
+struct DeserializableOwnedTable<S: Scalar> {
+    version: u32,
+    table: IndexMap<Identifier, OwnedColumn<S>>,
+}
+
+impl<S: Scalar + Serialize> Serialize for OwnedTable<S> {
+    fn serialize<Ser: Serializer>(&self, serializer: Ser) -> Result<Ser::Ok, Ser::Error> {
+        SerializableOwnedTable {
+            version: OWNED_TABLE_SERIALIZATION_VERSION,
+            table: &self.table,
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de, S: Scalar + Deserialize<'de>> Deserialize<'de> for OwnedTable<S> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let DeserializableOwnedTable { version, table } =
+            DeserializableOwnedTable::deserialize(deserializer)?;
+        if version != OWNED_TABLE_SERIALIZATION_VERSION {
+            return Err(serde::de::Error::custom(format!(
+                "unsupported OwnedTable serialization version {version}, expected {OWNED_TABLE_SERIALIZATION_VERSION}"
+            )));
+        }
+        Self::try_new(table).map_err(serde::de::Error::custom)
+    }
+}
+
 #[cfg(test)]
 impl<S: Scalar> core::ops::Index<&str> for OwnedTable<S> {
     type Output = OwnedColumn<S>;
@@ -1,6 +1,9 @@
 use crate::{
     base::{
-        database::{owned_table_utility::*, OwnedColumn, OwnedTable, OwnedTableError},
+        database::{
+            owned_table_utility::*, OwnedColumn, OwnedTable, OwnedTableError,
+            OWNED_TABLE_SERIALIZATION_VERSION,
+        },
         scalar::Curve25519Scalar,
     },
     proof_primitive::dory::DoryScalar,
@@ -185,3 +188,45 @@ fn we_cannot_create_an_owned_table_with_differing_column_lengths() {
         Err(OwnedTableError::ColumnLengthMismatch)
     ));
 }
+
+#[test]
+fn we_can_round_trip_an_owned_table_with_all_column_types_through_serialization() {
+    let owned_table = owned_table::<Curve25519Scalar>([
+        boolean("boolean", [true, false, true]),
+        smallint("smallint", [i16::MIN, 0, i16::MAX]),
+        int("int", [i32::MIN, 0, i32::MAX]),
+        bigint("bigint", [i64::MIN, 0, i64::MAX]),
+        int128("int128", [i128::MIN, 0, i128::MAX]),
+        varchar("varchar", ["", "ab ĉ", "🦀 with \"quotes\""]),
+        scalar("scalar", [-1, 0, 1]),
+        decimal75("decimal", 12, -3, [-123, 0, 456]),
+        timestamptz(
+            "timestamp",
+            PoSQLTimeUnit::Millisecond,
+            PoSQLTimeZone::Utc,
+            [-1, 0, 1625072400000],
+        ),
+    ]);
+    let bytes = postcard::to_allocvec(&owned_table).unwrap();
+    let deserialized: OwnedTable<Curve25519Scalar> = postcard::from_bytes(&bytes).unwrap();
+    assert_eq!(deserialized, owned_table);
+
+    let json = serde_json::to_string(&owned_table).unwrap();
+    let deserialized: OwnedTable<Curve25519Scalar> = serde_json::from_str(&json).unwrap();
+    assert_eq!(deserialized, owned_table);
+}
+
+#[test]
+fn we_cannot_deserialize_an_owned_table_with_an_unsupported_version() {
+    let owned_table = owned_table::<Curve25519Scalar>([bigint("a", [1, 2, 3])]);
+    let mut json: serde_json::Value = serde_json::to_value(&owned_table).unwrap();
+    assert_eq!(json["version"], OWNED_TABLE_SERIALIZATION_VERSION);
+    json["version"] = (OWNED_TABLE_SERIALIZATION_VERSION + 1).into();
+    assert!(serde_json::from_value::<OwnedTable<Curve25519Scalar>>(json).is_err());
+}
+
+#[test]
+fn we_cannot_deserialize_an_owned_table_with_mismatched_column_lengths() {
+    let json = r#"{"version":1,"table":{"a":{"BigInt":[1,2]},"b":{"BigInt":[1]}}}"#;
+    assert!(serde_json::from_str::<OwnedTable<Curve25519Scalar>>(json).is_err());
+}
@@ -1,6 +1,6 @@
 use super::{
-    Column, ColumnRef, ColumnType, CommitmentAccessor, DataAccessor, MetadataAccessor, OwnedColumn,
-    OwnedTable, SchemaAccessor, TableRef, TestAccessor,
+    Column, ColumnField, ColumnRef, ColumnType, CommitmentAccessor, DataAccessor, MetadataAccessor,
+    OwnedColumn, OwnedTable, SchemaAccessor, TableRef, TestAccessor,
 };
 use crate::base::commitment::{CommitmentEvaluationProof, VecCommitmentExt};
 use bumpalo::Bump;
@@ -11,6 +11,8 @@ use proof_of_sql_parser::Identifier;
 /// Note: this is not optimized for performance, so should not be used for benchmarks.
 pub struct OwnedTableTestAccessor<'a, CP: CommitmentEvaluationProof> {
     tables: IndexMap<TableRef, (OwnedTable<CP::Scalar>, usize)>,
+    column_bounds: IndexMap<ColumnRef, (i64, i64)>,
+    column_epochs: IndexMap<ColumnRef, u64>,
     alloc: Bump,
     setup: Option<CP::ProverPublicSetup<'a>>,
 }
@@ -19,6 +21,8 @@ impl<CP: CommitmentEvaluationProof> Default for OwnedTableTestAccessor<'_, CP> {
     fn default() -> Self {
         Self {
             tables: Default::default(),
+            column_bounds: Default::default(),
+            column_epochs: Default::default(),
             alloc: Bump::new(),
             setup: None,
         }
@@ -29,6 +33,8 @@ impl<CP: CommitmentEvaluationProof> Clone for OwnedTableTestAccessor<'_, CP> {
     fn clone(&self) -> Self {
         Self {
             tables: self.tables.clone(),
+            column_bounds: self.column_bounds.clone(),
+            column_epochs: self.column_epochs.clone(),
             setup: self.setup,
             ..Default::default()
         }
@@ -112,6 +118,30 @@ impl<CP: CommitmentEvaluationProof> MetadataAccessor for OwnedTableTestAccessor<
     fn get_offset(&self, table_ref: TableRef) -> usize {
         self.tables.get(&table_ref).unwrap().1
     }
+
+    fn schema(&self) -> Vec<(TableRef, Vec<ColumnField>)> {
+        self.tables
+            .iter()
+            .map(|(&table_ref, (table, _))| {
+                (
+                    table_ref,
+                    table
+                        .inner_table()
+                        .iter()
+                        .map(|(&id, col)| ColumnField::new(id, col.column_type()))
+                        .collect(),
+                )
+            })
+            .collect()
+    }
+
+    fn column_bounds(&self, column: ColumnRef) -> Option<(i64, i64)> {
+        self.column_bounds.get(&column).copied()
+    }
+
+    fn column_epoch(&self, column: ColumnRef) -> Option<u64> {
+        self.column_epochs.get(&column).copied()
+    }
 }
 impl<CP: CommitmentEvaluationProof> SchemaAccessor for OwnedTableTestAccessor<'_, CP> {
     fn lookup_column(&self, table_ref: TableRef, column_id: Identifier) -> Option<ColumnType> {
@@ -156,4 +186,14 @@ impl<'a, CP: CommitmentEvaluationProof> OwnedTableTestAccessor<'a, CP> {
         res.add_table(table_ref, owned_table, offset);
         res
     }
+
+    /// Declare `(min, max)` bounds on the values of a column.
+    pub fn set_column_bounds(&mut self, column: ColumnRef, bounds: (i64, i64)) {
+        self.column_bounds.insert(column, bounds);
+    }
+
+    /// Declare the epoch of the data of a column.
+    pub fn set_column_epoch(&mut self, column: ColumnRef, epoch: u64) {
+        self.column_epochs.insert(column, epoch);
+    }
 }
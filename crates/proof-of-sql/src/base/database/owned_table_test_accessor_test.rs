@@ -1,13 +1,17 @@
 use super::{
-    Column, ColumnRef, ColumnType, CommitmentAccessor, DataAccessor, MetadataAccessor,
+    Column, ColumnField, ColumnRef, ColumnType, CommitmentAccessor, DataAccessor, MetadataAccessor,
     OwnedTableTestAccessor, SchemaAccessor, TestAccessor,
 };
 use crate::base::{
-    database::owned_table_utility::*,
+    database::{owned_table_utility::*, TableRef},
+    proof::ProofError,
     scalar::{compute_commitment_for_testing, Curve25519Scalar},
 };
 use blitzar::proof::InnerProductProof;
-use proof_of_sql_parser::posql_time::{PoSQLTimeUnit, PoSQLTimeZone};
+use proof_of_sql_parser::{
+    posql_time::{PoSQLTimeUnit, PoSQLTimeZone},
+    Identifier,
+};
 
 #[test]
 fn we_can_query_the_length_of_a_table() {
@@ -208,6 +212,38 @@ fn we_can_access_schema_and_column_names() {
     assert_eq!(accessor.get_column_names(table_ref_1), vec!["a", "b"]);
 }
 
+#[test]
+fn we_can_list_all_tables_and_columns_in_the_schema() {
+    let mut accessor = OwnedTableTestAccessor::<InnerProductProof>::new_empty_with_setup(());
+    let table_ref_1 = "sxt.test".parse().unwrap();
+    let table_ref_2 = "sxt.test2".parse().unwrap();
+
+    let data1 = owned_table([bigint("a", [1, 2, 3]), varchar("b", ["x", "y", "z"])]);
+    accessor.add_table(table_ref_1, data1, 0_usize);
+    let data2 = owned_table([boolean("c", [true, false]), int128("d", [4, 5])]);
+    accessor.add_table(table_ref_2, data2, 3_usize);
+
+    assert_eq!(
+        accessor.schema(),
+        vec![
+            (
+                table_ref_1,
+                vec![
+                    ColumnField::new("a".parse().unwrap(), ColumnType::BigInt),
+                    ColumnField::new("b".parse().unwrap(), ColumnType::VarChar)
+                ]
+            ),
+            (
+                table_ref_2,
+                vec![
+                    ColumnField::new("c".parse().unwrap(), ColumnType::Boolean),
+                    ColumnField::new("d".parse().unwrap(), ColumnType::Int128)
+                ]
+            )
+        ]
+    );
+}
+
 #[test]
 fn we_can_correctly_update_offsets() {
     let mut accessor1 = OwnedTableTestAccessor::<InnerProductProof>::new_empty_with_setup(());
@@ -250,3 +286,43 @@ fn we_can_correctly_update_offsets() {
     assert_eq!(accessor1.get_offset(table_ref), offset);
     assert_eq!(accessor2.get_offset(table_ref), offset);
 }
+
+#[test]
+fn we_can_resolve_a_column_ref_from_the_accessor() {
+    let table_ref: TableRef = "sxt.test".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(
+        table_ref,
+        owned_table([bigint("a", [1, 2]), varchar("b", ["x", "y"])]),
+        0,
+        (),
+    );
+    let column_ref = ColumnRef::resolve(table_ref, "b".parse().unwrap(), &accessor).unwrap();
+    assert_eq!(
+        column_ref,
+        ColumnRef::new(table_ref, "b".parse().unwrap(), ColumnType::VarChar)
+    );
+}
+
+#[test]
+fn we_cannot_resolve_a_column_ref_for_a_missing_column() {
+    let table_ref: TableRef = "sxt.test".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(
+        table_ref,
+        owned_table([bigint("a", [1, 2])]),
+        0,
+        (),
+    );
+    let missing: Identifier = "c".parse().unwrap();
+    assert!(matches!(
+        ColumnRef::resolve(table_ref, missing, &accessor),
+        Err(ProofError::ColumnNotFound { table, column }) if table == table_ref && column == missing
+    ));
+    assert!(matches!(
+        ColumnRef::resolve(
+            "sxt.other".parse().unwrap(),
+            "a".parse().unwrap(),
+            &accessor
+        ),
+        Err(ProofError::ColumnNotFound { .. })
+    ));
+}
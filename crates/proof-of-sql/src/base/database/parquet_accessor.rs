@@ -0,0 +1,295 @@
+use super::{
+    Column, ColumnField, ColumnRef, ColumnType, CommitmentAccessor, DataAccessor, IdentifierError,
+    MetadataAccessor, OwnedArrowConversionError, OwnedColumn, SchemaAccessor, TableRef,
+};
+use crate::base::{
+    commitment::{CommitmentEvaluationProof, VecCommitmentExt},
+    scalar::Scalar,
+    MleAllocator,
+};
+use arrow::{
+    array::{new_empty_array, Array, ArrayRef},
+    compute::concat,
+    datatypes::DataType,
+    error::ArrowError,
+};
+use bumpalo::Bump;
+use indexmap::IndexMap;
+use parquet::{
+    arrow::{arrow_reader::ParquetRecordBatchReaderBuilder, ProjectionMask},
+    errors::ParquetError,
+};
+use proof_of_sql_parser::Identifier;
+use std::{
+    fs::File,
+    path::{Path, PathBuf},
+};
+use thiserror::Error;
+
+/// Errors from reading a Parquet file with a [`ParquetAccessor`].
+#[derive(Error, Debug)]
+pub enum ParquetAccessorError {
+    /// This error occurs when the file can't be opened.
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+    /// This error occurs when the file is not a valid Parquet file.
+    #[error(transparent)]
+    Parquet(#[from] ParquetError),
+    /// This error occurs when the data of the file can't be decoded.
+    #[error(transparent)]
+    Arrow(#[from] ArrowError),
+    /// This error occurs when a column name is not a valid identifier.
+    #[error(transparent)]
+    InvalidColumnName(#[from] IdentifierError),
+    /// This error occurs when a column has a type that no `ColumnType` corresponds to.
+    #[error("column {column} has the unsupported type {data_type}")]
+    UnsupportedType {
+        /// The name of the column
+        column: String,
+        /// The type of the column
+        data_type: DataType,
+    },
+    /// This error occurs when the data of a column can't be converted, e.g. because it has nulls.
+    #[error(transparent)]
+    Conversion(#[from] OwnedArrowConversionError),
+}
+
+/// A Parquet file along with the metadata needed to read its columns.
+struct ParquetTable {
+    path: PathBuf,
+    offset: usize,
+    row_group_lengths: Vec<usize>,
+    /// The index of each column in the file's schema, along with its types
+    columns: IndexMap<Identifier, (usize, DataType, ColumnType)>,
+}
+
+impl ParquetTable {
+    fn open(path: PathBuf, offset: usize) -> Result<Self, ParquetAccessorError> {
+        let builder = ParquetRecordBatchReaderBuilder::try_new(File::open(&path)?)?;
+        let row_group_lengths = builder
+            .metadata()
+            .row_groups()
+            .iter()
+            .map(|row_group| row_group.num_rows() as usize)
+            .collect();
+        let columns = builder
+            .schema()
+            .fields()
+            .iter()
+            .enumerate()
+            .map(|(index, field)| {
+                let column_type =
+                    ColumnType::try_from(field.data_type().clone()).map_err(|_| {
+                        ParquetAccessorError::UnsupportedType {
+                            column: field.name().clone(),
+                            data_type: field.data_type().clone(),
+                        }
+                    })?;
+                let column_field = ColumnField::try_new(field.name(), column_type)?;
+                Ok((
+                    column_field.name(),
+                    (index, field.data_type().clone(), column_type),
+                ))
+            })
+            .collect::<Result<_, ParquetAccessorError>>()?;
+        Ok(Self {
+            path,
+            offset,
+            row_group_lengths,
+            columns,
+        })
+    }
+
+    fn num_rows(&self) -> usize {
+        self.row_group_lengths.iter().sum()
+    }
+
+    /// Read a column from the given row groups of the file, without reading any other column.
+    fn read_column<S: Scalar>(
+        &self,
+        column_id: Identifier,
+        row_groups: Vec<usize>,
+    ) -> Result<OwnedColumn<S>, ParquetAccessorError> {
+        let (index, data_type, _) = &self.columns[&column_id];
+        let builder = ParquetRecordBatchReaderBuilder::try_new(File::open(&self.path)?)?;
+        let projection = ProjectionMask::roots(builder.parquet_schema(), [*index]);
+        let arrays = builder
+            .with_projection(projection)
+            .with_row_groups(row_groups)
+            .build()?
+            .map(|batch| Ok(batch?.column(0).clone()))
+            .collect::<Result<Vec<ArrayRef>, ArrowError>>()?;
+        let array = if arrays.is_empty() {
+            new_empty_array(data_type)
+        } else {
+            concat(
+                &arrays
+                    .iter()
+                    .map(AsRef::as_ref)
+                    .collect::<Vec<&dyn Array>>(),
+            )?
+        };
+        Ok(OwnedColumn::try_from(&array)?)
+    }
+}
+
+/// An accessor over Parquet files, such as those of a data lake.
+///
+/// Only the metadata of a file is read when it is added. The data of a column is read from the
+/// file when the column is accessed, so columns that a query doesn't reference are never loaded.
+/// Commitments are computed for each row group of the file and combined.
+///
+/// Since the accessor traits can't fail, accessing a column panics if the file can no longer be
+/// read or the column's data can't be converted, e.g. because it contains nulls.
+pub struct ParquetAccessor<'a, CP: CommitmentEvaluationProof> {
+    tables: IndexMap<TableRef, ParquetTable>,
+    column_bounds: IndexMap<ColumnRef, (i64, i64)>,
+    column_epochs: IndexMap<ColumnRef, u64>,
+    alloc: Bump,
+    setup: CP::ProverPublicSetup<'a>,
+}
+
+impl<'a, CP: CommitmentEvaluationProof> ParquetAccessor<'a, CP> {
+    /// Create a new accessor without any tables.
+    pub fn new(setup: CP::ProverPublicSetup<'a>) -> Self {
+        Self {
+            tables: IndexMap::new(),
+            column_bounds: IndexMap::new(),
+            column_epochs: IndexMap::new(),
+            alloc: Bump::new(),
+            setup,
+        }
+    }
+
+    /// Add the Parquet file at `path` as the table `table_ref`, starting at row `offset`.
+    ///
+    /// Fails if the file can't be read or has a column whose type isn't supported.
+    pub fn add_table(
+        &mut self,
+        table_ref: TableRef,
+        path: impl AsRef<Path>,
+        offset: usize,
+    ) -> Result<(), ParquetAccessorError> {
+        let table = ParquetTable::open(path.as_ref().to_path_buf(), offset)?;
+        self.tables.insert(table_ref, table);
+        Ok(())
+    }
+
+    /// Declare `(min, max)` bounds on the values of a column.
+    pub fn set_column_bounds(&mut self, column: ColumnRef, bounds: (i64, i64)) {
+        self.column_bounds.insert(column, bounds);
+    }
+
+    /// Declare the epoch of the data of a column.
+    pub fn set_column_epoch(&mut self, column: ColumnRef, epoch: u64) {
+        self.column_epochs.insert(column, epoch);
+    }
+}
+
+/// Copy an owned column into `alloc`.
+fn alloc_column<'a, S: Scalar>(
+    alloc: &'a dyn MleAllocator,
+    column: &OwnedColumn<S>,
+) -> Column<'a, S> {
+    match column {
+        OwnedColumn::Boolean(col) => Column::Boolean(alloc.alloc_slice_copy(col)),
+        OwnedColumn::SmallInt(col) => Column::SmallInt(alloc.alloc_slice_copy(col)),
+        OwnedColumn::Int(col) => Column::Int(alloc.alloc_slice_copy(col)),
+        OwnedColumn::BigInt(col) => Column::BigInt(alloc.alloc_slice_copy(col)),
+        OwnedColumn::Int128(col) => Column::Int128(alloc.alloc_slice_copy(col)),
+        OwnedColumn::Decimal75(precision, scale, col) => {
+            Column::Decimal75(*precision, *scale, alloc.alloc_slice_copy(col))
+        }
+        OwnedColumn::Scalar(col) => Column::Scalar(alloc.alloc_slice_copy(col)),
+        OwnedColumn::VarChar(col) => {
+            let strs: &[&str] =
+                alloc.alloc_slice_fill_iter(col.iter().map(|s| alloc.alloc_str(s) as &str));
+            let scalars: &[S] = alloc.alloc_slice_fill_iter(col.iter().map(S::from));
+            Column::VarChar((strs, scalars))
+        }
+        OwnedColumn::TimestampTZ(tu, tz, col) => {
+            Column::TimestampTZ(*tu, *tz, alloc.alloc_slice_copy(col))
+        }
+    }
+}
+
+impl<CP: CommitmentEvaluationProof> DataAccessor<CP::Scalar> for ParquetAccessor<'_, CP> {
+    fn get_column(&self, column: ColumnRef) -> Column<CP::Scalar> {
+        let table = &self.tables[&column.table_ref()];
+        let owned_column = table
+            .read_column(
+                column.column_id(),
+                (0..table.row_group_lengths.len()).collect(),
+            )
+            .expect("failed to read a column from a parquet file");
+        alloc_column(&self.alloc, &owned_column)
+    }
+}
+
+impl<CP: CommitmentEvaluationProof> CommitmentAccessor<CP::Commitment> for ParquetAccessor<'_, CP> {
+    fn get_commitment(&self, column: ColumnRef) -> CP::Commitment {
+        let table = &self.tables[&column.table_ref()];
+        let mut commitment = CP::Commitment::default();
+        let mut offset = table.offset;
+        for (row_group, length) in table.row_group_lengths.iter().enumerate() {
+            let owned_column = table
+                .read_column::<CP::Scalar>(column.column_id(), vec![row_group])
+                .expect("failed to read a column from a parquet file");
+            commitment += Vec::<CP::Commitment>::from_columns_with_offset(
+                [&owned_column],
+                offset,
+                &self.setup,
+            )[0];
+            offset += length;
+        }
+        commitment
+    }
+}
+
+impl<CP: CommitmentEvaluationProof> MetadataAccessor for ParquetAccessor<'_, CP> {
+    fn get_length(&self, table_ref: TableRef) -> usize {
+        self.tables[&table_ref].num_rows()
+    }
+
+    fn get_offset(&self, table_ref: TableRef) -> usize {
+        self.tables[&table_ref].offset
+    }
+
+    fn schema(&self) -> Vec<(TableRef, Vec<ColumnField>)> {
+        self.tables
+            .iter()
+            .map(|(&table_ref, table)| {
+                (
+                    table_ref,
+                    table
+                        .columns
+                        .iter()
+                        .map(|(&id, (_, _, column_type))| ColumnField::new(id, *column_type))
+                        .collect(),
+                )
+            })
+            .collect()
+    }
+
+    fn column_bounds(&self, column: ColumnRef) -> Option<(i64, i64)> {
+        self.column_bounds.get(&column).copied()
+    }
+
+    fn column_epoch(&self, column: ColumnRef) -> Option<u64> {
+        self.column_epochs.get(&column).copied()
+    }
+}
+
+impl<CP: CommitmentEvaluationProof> SchemaAccessor for ParquetAccessor<'_, CP> {
+    fn lookup_column(&self, table_ref: TableRef, column_id: Identifier) -> Option<ColumnType> {
+        Some(self.tables.get(&table_ref)?.columns.get(&column_id)?.2)
+    }
+
+    fn lookup_schema(&self, table_ref: TableRef) -> Vec<(Identifier, ColumnType)> {
+        self.tables[&table_ref]
+            .columns
+            .iter()
+            .map(|(&id, (_, _, column_type))| (id, *column_type))
+            .collect()
+    }
+}
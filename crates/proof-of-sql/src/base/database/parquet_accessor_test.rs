@@ -0,0 +1,141 @@
+use super::{
+    ColumnRef, ColumnType, CommitmentAccessor, IdentifierError, MetadataAccessor, OwnedTable,
+    OwnedTableTestAccessor, ParquetAccessor, ParquetAccessorError, SchemaAccessor, TableRef,
+};
+use crate::{
+    base::{database::owned_table_utility::*, scalar::Curve25519Scalar},
+    sql::{ast::test_utility::*, proof::VerifiableQueryResult},
+};
+use arrow::{
+    array::{ArrayRef, Float64Array, Int64Array},
+    datatypes::{DataType, Field, Schema},
+    record_batch::RecordBatch,
+};
+use blitzar::proof::InnerProductProof;
+use parquet::{arrow::ArrowWriter, file::properties::WriterProperties};
+use std::{fs::File, path::PathBuf, sync::Arc};
+
+/// Write `batch` to a temporary Parquet file with row groups of at most `row_group_size` rows.
+fn write_parquet_file(name: &str, batch: &RecordBatch, row_group_size: usize) -> PathBuf {
+    let path = std::env::temp_dir().join(format!(
+        "proof_of_sql_{}_{}.parquet",
+        name,
+        std::process::id()
+    ));
+    let properties = WriterProperties::builder()
+        .set_max_row_group_size(row_group_size)
+        .build();
+    let mut writer = ArrowWriter::try_new(
+        File::create(&path).unwrap(),
+        batch.schema(),
+        Some(properties),
+    )
+    .unwrap();
+    writer.write(batch).unwrap();
+    writer.close().unwrap();
+    path
+}
+
+fn sample_table() -> OwnedTable<Curve25519Scalar> {
+    owned_table([
+        bigint("a", [1, 4, 5, 2, 5]),
+        varchar("b", ["1", "2", "3", "4", "5"]),
+        int("c", [10, 20, 30, 40, 50]),
+    ])
+}
+
+#[test]
+fn we_can_read_the_schema_and_columns_of_a_parquet_file() {
+    let path = write_parquet_file("schema", &RecordBatch::try_from(sample_table()).unwrap(), 2);
+    let t: TableRef = "sxt.t".parse().unwrap();
+    let mut accessor = ParquetAccessor::<InnerProductProof>::new(());
+    accessor.add_table(t, &path, 3).unwrap();
+
+    assert_eq!(accessor.get_length(t), 5);
+    assert_eq!(accessor.get_offset(t), 3);
+    assert_eq!(
+        accessor.lookup_schema(t),
+        vec![
+            ("a".parse().unwrap(), ColumnType::BigInt),
+            ("b".parse().unwrap(), ColumnType::VarChar),
+            ("c".parse().unwrap(), ColumnType::Int),
+        ]
+    );
+    assert_eq!(accessor.lookup_column(t, "d".parse().unwrap()), None);
+
+    // the commitments combined from the row groups match those of the whole table
+    let owned_accessor =
+        OwnedTableTestAccessor::<InnerProductProof>::new_from_table(t, sample_table(), 3, ());
+    for (name, column_type) in [
+        ("a", ColumnType::BigInt),
+        ("b", ColumnType::VarChar),
+        ("c", ColumnType::Int),
+    ] {
+        let column = ColumnRef::new(t, name.parse().unwrap(), column_type);
+        assert_eq!(
+            accessor.get_commitment(column),
+            owned_accessor.get_commitment(column)
+        );
+    }
+    std::fs::remove_file(path).unwrap();
+}
+
+/// select a, b from sxt.t where c >= 30
+#[test]
+fn we_can_prove_a_filter_over_a_parquet_file() {
+    let path = write_parquet_file("filter", &RecordBatch::try_from(sample_table()).unwrap(), 2);
+    let t = "sxt.t".parse().unwrap();
+    let mut accessor = ParquetAccessor::<InnerProductProof>::new(());
+    accessor.add_table(t, &path, 0).unwrap();
+
+    let ast = filter(
+        cols_result(t, &["a", "b"], &accessor),
+        tab(t),
+        gte(column(t, "c", &accessor), const_int(30)),
+    );
+    let verifiable_res = VerifiableQueryResult::new(&ast, &accessor, &());
+    let res = verifiable_res.verify(&ast, &accessor, &()).unwrap().table;
+    let expected = owned_table([bigint("a", [5, 2, 5]), varchar("b", ["3", "4", "5"])]);
+    assert_eq!(res, expected);
+    std::fs::remove_file(path).unwrap();
+}
+
+#[test]
+fn we_cannot_add_a_parquet_file_with_an_unsupported_column_type() {
+    let schema = Arc::new(Schema::new(vec![Field::new("f", DataType::Float64, false)]));
+    let array: ArrayRef = Arc::new(Float64Array::from(vec![1.0, 2.0]));
+    let path = write_parquet_file(
+        "unsupported",
+        &RecordBatch::try_new(schema, vec![array]).unwrap(),
+        2,
+    );
+    let mut accessor = ParquetAccessor::<InnerProductProof>::new(());
+    let res = accessor.add_table("sxt.t".parse().unwrap(), &path, 0);
+    assert!(matches!(
+        res,
+        Err(ParquetAccessorError::UnsupportedType {
+            column,
+            data_type: DataType::Float64,
+        }) if column == "f"
+    ));
+    std::fs::remove_file(path).unwrap();
+}
+
+#[test]
+fn we_cannot_add_a_parquet_file_with_an_invalid_column_name() {
+    let schema = Arc::new(Schema::new(vec![Field::new("a b", DataType::Int64, false)]));
+    let array: ArrayRef = Arc::new(Int64Array::from(vec![1, 2]));
+    let path = write_parquet_file(
+        "invalid_name",
+        &RecordBatch::try_new(schema, vec![array]).unwrap(),
+        2,
+    );
+    let mut accessor = ParquetAccessor::<InnerProductProof>::new(());
+    let res = accessor.add_table("sxt.t".parse().unwrap(), &path, 0);
+    assert!(matches!(
+        res,
+        Err(ParquetAccessorError::InvalidColumnName(IdentifierError::InvalidIdentifier(name)))
+            if name == "a b"
+    ));
+    std::fs::remove_file(path).unwrap();
+}
@@ -0,0 +1,178 @@
+use super::{
+    Column, ColumnField, ColumnRef, ColumnType, CommitmentAccessor, DataAccessor, MetadataAccessor,
+    SchemaAccessor, TableRef,
+};
+use crate::base::commitment::{Commitment, CommittableColumn};
+use core::ops::Range;
+use indexmap::IndexMap;
+use proof_of_sql_parser::Identifier;
+
+/// A table holding a single generated `BigInt` column of consecutive integers
+struct GeneratedRange<C> {
+    column_id: Identifier,
+    range: Range<i64>,
+    offset: usize,
+    values: Vec<i64>,
+    commitment: C,
+}
+
+/// An accessor that adds tables of generated integer sequences to another accessor.
+///
+/// Each generated table has a single `BigInt` column holding `start..end`, which can be queried
+/// like any other column, e.g. as an index column or as the inner table of a join, without the
+/// sequence being stored anywhere. Every other table is read from the wrapped accessor.
+///
+/// The commitment of a generated column is not read from the wrapped accessor: it is computed
+/// from the range itself when the range is added. Since it only depends on the range and the
+/// generator offset, a verifier that adds the same range recomputes the expected commitment
+/// rather than trusting one, so a proof over a generated table only verifies if the prover used
+/// exactly that sequence. The column's bounds are also known to be those of the range.
+pub struct RangeSource<'a, C: Commitment, A: ?Sized> {
+    inner: &'a A,
+    setup: &'a C::PublicSetup<'a>,
+    ranges: IndexMap<TableRef, GeneratedRange<C>>,
+}
+
+impl<'a, C: Commitment, A: MetadataAccessor + ?Sized> RangeSource<'a, C, A> {
+    /// Create an accessor that reads every table from `inner` until ranges are added.
+    ///
+    /// `setup` is used to compute the commitments of the generated columns.
+    pub fn new(inner: &'a A, setup: &'a C::PublicSetup<'a>) -> Self {
+        Self {
+            inner,
+            setup,
+            ranges: IndexMap::new(),
+        }
+    }
+
+    /// Add the table `table_ref`, whose only column `column_id` holds `range`.
+    ///
+    /// The table is committed with the generator offset `offset`. It hides any table of the same
+    /// name in the wrapped accessor.
+    ///
+    /// # Panics
+    /// Panics if `range` is empty.
+    pub fn add_range(
+        &mut self,
+        table_ref: TableRef,
+        column_id: Identifier,
+        range: Range<i64>,
+        offset: usize,
+    ) {
+        assert!(!range.is_empty(), "a generated range must not be empty");
+        let values: Vec<i64> = range.clone().collect();
+        let mut commitment = [C::default()];
+        C::compute_commitments(
+            &mut commitment,
+            &[CommittableColumn::BigInt(&values)],
+            offset,
+            self.setup,
+        );
+        self.ranges.insert(
+            table_ref,
+            GeneratedRange {
+                column_id,
+                range,
+                offset,
+                values,
+                commitment: commitment[0],
+            },
+        );
+    }
+
+    /// The generated table of `column`, if `column` is a generated column
+    fn generated_column(&self, column: ColumnRef) -> Option<&GeneratedRange<C>> {
+        self.ranges.get(&column.table_ref()).map(|generated| {
+            assert_eq!(
+                generated.column_id,
+                column.column_id(),
+                "a generated table only has its generated column"
+            );
+            generated
+        })
+    }
+}
+
+impl<C: Commitment, A: MetadataAccessor + ?Sized> MetadataAccessor for RangeSource<'_, C, A> {
+    fn get_length(&self, table_ref: TableRef) -> usize {
+        match self.ranges.get(&table_ref) {
+            Some(generated) => generated.values.len(),
+            None => self.inner.get_length(table_ref),
+        }
+    }
+
+    fn get_offset(&self, table_ref: TableRef) -> usize {
+        match self.ranges.get(&table_ref) {
+            Some(generated) => generated.offset,
+            None => self.inner.get_offset(table_ref),
+        }
+    }
+
+    fn schema(&self) -> Vec<(TableRef, Vec<ColumnField>)> {
+        let mut schema: Vec<_> = self
+            .inner
+            .schema()
+            .into_iter()
+            .filter(|(table_ref, _)| !self.ranges.contains_key(table_ref))
+            .collect();
+        schema.extend(self.ranges.iter().map(|(&table_ref, generated)| {
+            (
+                table_ref,
+                vec![ColumnField::new(generated.column_id, ColumnType::BigInt)],
+            )
+        }));
+        schema
+    }
+
+    fn column_bounds(&self, column: ColumnRef) -> Option<(i64, i64)> {
+        match self.generated_column(column) {
+            Some(generated) => Some((generated.range.start, generated.range.end - 1)),
+            None => self.inner.column_bounds(column),
+        }
+    }
+
+    fn column_epoch(&self, column: ColumnRef) -> Option<u64> {
+        match self.generated_column(column) {
+            Some(_) => None,
+            None => self.inner.column_epoch(column),
+        }
+    }
+}
+
+impl<C: Commitment, A: DataAccessor<C::Scalar> + ?Sized> DataAccessor<C::Scalar>
+    for RangeSource<'_, C, A>
+{
+    fn get_column(&self, column: ColumnRef) -> Column<C::Scalar> {
+        match self.generated_column(column) {
+            Some(generated) => Column::BigInt(&generated.values),
+            None => self.inner.get_column(column),
+        }
+    }
+}
+
+impl<C: Commitment, A: CommitmentAccessor<C> + ?Sized> CommitmentAccessor<C>
+    for RangeSource<'_, C, A>
+{
+    fn get_commitment(&self, column: ColumnRef) -> C {
+        match self.generated_column(column) {
+            Some(generated) => generated.commitment,
+            None => self.inner.get_commitment(column),
+        }
+    }
+}
+
+impl<C: Commitment, A: SchemaAccessor + ?Sized> SchemaAccessor for RangeSource<'_, C, A> {
+    fn lookup_column(&self, table_ref: TableRef, column_id: Identifier) -> Option<ColumnType> {
+        match self.ranges.get(&table_ref) {
+            Some(generated) => (generated.column_id == column_id).then_some(ColumnType::BigInt),
+            None => self.inner.lookup_column(table_ref, column_id),
+        }
+    }
+
+    fn lookup_schema(&self, table_ref: TableRef) -> Vec<(Identifier, ColumnType)> {
+        match self.ranges.get(&table_ref) {
+            Some(generated) => vec![(generated.column_id, ColumnType::BigInt)],
+            None => self.inner.lookup_schema(table_ref),
+        }
+    }
+}
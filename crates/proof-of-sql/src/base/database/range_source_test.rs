@@ -0,0 +1,107 @@
+use super::{
+    owned_table_utility::*, Column, ColumnField, ColumnRef, ColumnType, CommitmentAccessor,
+    DataAccessor, MetadataAccessor, OwnedTableTestAccessor, RangeSource, SchemaAccessor,
+    TestAccessor,
+};
+use crate::{
+    base::commitment::InnerProductProof,
+    sql::{ast::test_utility::*, proof::VerifiableQueryResult},
+};
+use curve25519_dalek::RistrettoPoint;
+
+fn accessor_with_table() -> OwnedTableTestAccessor<'static, InnerProductProof> {
+    let mut accessor = OwnedTableTestAccessor::<InnerProductProof>::new_empty_with_setup(());
+    accessor.add_table(
+        "sxt.t".parse().unwrap(),
+        owned_table([
+            bigint("a", [1, 2, 3, 4, 5, 4, 9]),
+            bigint("b", [10, 20, 30, 40, 50, 60, 70]),
+        ]),
+        0,
+    );
+    accessor
+}
+
+#[test]
+fn a_generated_range_is_read_like_a_committed_column() {
+    let inner = accessor_with_table();
+    let r = "sxt.r".parse().unwrap();
+    let mut source = RangeSource::<RistrettoPoint, _>::new(&inner, &());
+    source.add_range(r, "i".parse().unwrap(), -2..3, 4);
+    let i = ColumnRef::new(r, "i".parse().unwrap(), ColumnType::BigInt);
+
+    assert_eq!(source.get_length(r), 5);
+    assert_eq!(source.get_offset(r), 4);
+    assert!(matches!(
+        source.get_column(i),
+        Column::BigInt(values) if values == [-2, -1, 0, 1, 2]
+    ));
+    assert_eq!(source.column_bounds(i), Some((-2, 2)));
+    assert_eq!(
+        source.lookup_column(r, "i".parse().unwrap()),
+        Some(ColumnType::BigInt)
+    );
+    assert_eq!(source.lookup_column(r, "j".parse().unwrap()), None);
+    assert!(source.schema().contains(&(
+        r,
+        vec![ColumnField::new("i".parse().unwrap(), ColumnType::BigInt)]
+    )));
+
+    // the commitment is that of the same values stored in a table
+    let mut stored = OwnedTableTestAccessor::<InnerProductProof>::new_empty_with_setup(());
+    stored.add_table(r, owned_table([bigint("i", [-2, -1, 0, 1, 2])]), 4);
+    assert_eq!(source.get_commitment(i), stored.get_commitment(i));
+
+    // other tables are read from the wrapped accessor
+    let a = ColumnRef::new(
+        "sxt.t".parse().unwrap(),
+        "a".parse().unwrap(),
+        ColumnType::BigInt,
+    );
+    assert_eq!(source.get_length("sxt.t".parse().unwrap()), 7);
+    assert_eq!(source.get_commitment(a), inner.get_commitment(a));
+}
+
+/// select b from sxt.t where a in (select i from sxt.r)
+#[test]
+fn we_can_prove_a_semi_join_against_a_generated_range() {
+    let inner = accessor_with_table();
+    let t = "sxt.t".parse().unwrap();
+    let r = "sxt.r".parse().unwrap();
+    let mut source = RangeSource::<RistrettoPoint, _>::new(&inner, &());
+    source.add_range(r, "i".parse().unwrap(), 2..5, 0);
+    let expr = semi_join(
+        cols_expr(t, &["b"], &source),
+        tab(t),
+        col_expr(t, "a", &source),
+        col_expr(r, "i", &source),
+    );
+    let res = VerifiableQueryResult::<InnerProductProof>::new(&expr, &source, &());
+
+    // the verifier generates the same range, and so recomputes its commitment
+    let mut verifier_source = RangeSource::<RistrettoPoint, _>::new(&inner, &());
+    verifier_source.add_range(r, "i".parse().unwrap(), 2..5, 0);
+    let res = res.verify(&expr, &verifier_source, &()).unwrap().table;
+    assert_eq!(res, owned_table([bigint("b", [20, 30, 40, 60])]));
+}
+
+/// select b from sxt.t where a in (select i from sxt.r)
+#[test]
+fn a_proof_over_a_different_range_does_not_verify() {
+    let inner = accessor_with_table();
+    let t = "sxt.t".parse().unwrap();
+    let r = "sxt.r".parse().unwrap();
+    let mut source = RangeSource::<RistrettoPoint, _>::new(&inner, &());
+    source.add_range(r, "i".parse().unwrap(), 2..5, 0);
+    let expr = semi_join(
+        cols_expr(t, &["b"], &source),
+        tab(t),
+        col_expr(t, "a", &source),
+        col_expr(r, "i", &source),
+    );
+    let res = VerifiableQueryResult::<InnerProductProof>::new(&expr, &source, &());
+
+    let mut verifier_source = RangeSource::<RistrettoPoint, _>::new(&inner, &());
+    verifier_source.add_range(r, "i".parse().unwrap(), 3..6, 0);
+    assert!(res.verify(&expr, &verifier_source, &()).is_err());
+}
@@ -0,0 +1,94 @@
+use super::{
+    Column, ColumnField, ColumnRef, CommitmentAccessor, DataAccessor, MetadataAccessor, TableRef,
+};
+use crate::base::{commitment::Commitment, scalar::Scalar};
+use indexmap::IndexSet;
+use std::cell::RefCell;
+
+/// A read of a column, or of its commitment, through a [`RecordingAccessor`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ColumnAccess {
+    /// The column that was read
+    pub column: ColumnRef,
+    /// The offset of the data span of the column's table when it was read
+    pub offset: usize,
+}
+
+/// An accessor that records every column, and every column commitment, read through it.
+///
+/// This wraps any other accessor, so that after proving or verifying a query a caller can see
+/// exactly which columns were read. This can be used to check that a plan's
+/// `get_column_references` matches what it accesses, or to only share the data a query needs.
+pub struct RecordingAccessor<'a, A: ?Sized> {
+    inner: &'a A,
+    accesses: RefCell<Vec<ColumnAccess>>,
+}
+
+impl<'a, A: MetadataAccessor + ?Sized> RecordingAccessor<'a, A> {
+    /// Create an accessor that records the reads made through it from `inner`.
+    pub fn new(inner: &'a A) -> Self {
+        Self {
+            inner,
+            accesses: RefCell::new(Vec::new()),
+        }
+    }
+
+    /// Every read made so far, in the order they were made
+    pub fn accesses(&self) -> Vec<ColumnAccess> {
+        self.accesses.borrow().clone()
+    }
+
+    /// The distinct columns read so far, in the order they were first read
+    pub fn accessed_columns(&self) -> IndexSet<ColumnRef> {
+        self.accesses
+            .borrow()
+            .iter()
+            .map(|access| access.column)
+            .collect()
+    }
+
+    fn record(&self, column: ColumnRef) {
+        let offset = self.inner.get_offset(column.table_ref());
+        self.accesses
+            .borrow_mut()
+            .push(ColumnAccess { column, offset });
+    }
+}
+
+impl<A: MetadataAccessor + ?Sized> MetadataAccessor for RecordingAccessor<'_, A> {
+    fn get_length(&self, table_ref: TableRef) -> usize {
+        self.inner.get_length(table_ref)
+    }
+
+    fn get_offset(&self, table_ref: TableRef) -> usize {
+        self.inner.get_offset(table_ref)
+    }
+
+    fn schema(&self) -> Vec<(TableRef, Vec<ColumnField>)> {
+        self.inner.schema()
+    }
+
+    fn column_bounds(&self, column: ColumnRef) -> Option<(i64, i64)> {
+        self.inner.column_bounds(column)
+    }
+
+    fn column_epoch(&self, column: ColumnRef) -> Option<u64> {
+        self.inner.column_epoch(column)
+    }
+}
+
+impl<S: Scalar, A: DataAccessor<S> + ?Sized> DataAccessor<S> for RecordingAccessor<'_, A> {
+    fn get_column(&self, column: ColumnRef) -> Column<S> {
+        self.record(column);
+        self.inner.get_column(column)
+    }
+}
+
+impl<C: Commitment, A: CommitmentAccessor<C> + ?Sized> CommitmentAccessor<C>
+    for RecordingAccessor<'_, A>
+{
+    fn get_commitment(&self, column: ColumnRef) -> C {
+        self.record(column);
+        self.inner.get_commitment(column)
+    }
+}
@@ -0,0 +1,132 @@
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+/// Cannot move a [`SignedOffset`] before its generator origin.
+#[derive(Debug, Error, PartialEq, Eq)]
+#[error("offset {offset} is before the generator origin at {origin}")]
+pub struct OffsetBeforeOrigin {
+    /// The offset that was requested
+    pub offset: i64,
+    /// The generator origin of the offset
+    pub origin: usize,
+}
+
+/// The row offset of a table that can grow at its start as well as at its end.
+///
+/// Commitment generators are indexed from zero, so the generator offset of a table can never be
+/// negative. A table that may have rows prepended to it is instead committed with its logical row
+/// `0` at the generator `origin`. Prepending `n` rows then moves the table to the logical offset
+/// `-n`, whose rows are committed starting at the generator `origin - n`.
+///
+/// Every party must agree on the origin, since it determines the commitments of the table. Up to
+/// `origin` rows can be prepended.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SignedOffset {
+    origin: usize,
+    offset: i64,
+}
+
+impl SignedOffset {
+    /// Create a `SignedOffset` at logical offset `0` with the given generator origin.
+    pub fn new(origin: usize) -> Self {
+        Self { origin, offset: 0 }
+    }
+
+    /// Create a `SignedOffset` at the given logical offset.
+    ///
+    /// Errors if the offset would be before the first generator.
+    pub fn try_new(origin: usize, offset: i64) -> Result<Self, OffsetBeforeOrigin> {
+        let signed_offset = Self { origin, offset };
+        signed_offset.try_generator_offset()?;
+        Ok(signed_offset)
+    }
+
+    /// Returns the generator index of logical row `0`.
+    pub fn origin(&self) -> usize {
+        self.origin
+    }
+
+    /// Returns the logical offset of the table's first row, which is negative once rows have been
+    /// prepended.
+    pub fn offset(&self) -> i64 {
+        self.offset
+    }
+
+    /// Returns the generator offset of the table's first row.
+    ///
+    /// This is the offset that a [`MetadataAccessor`](super::MetadataAccessor) should report.
+    pub fn generator_offset(&self) -> usize {
+        self.try_generator_offset()
+            .expect("signed offsets are never before their origin")
+    }
+
+    fn try_generator_offset(&self) -> Result<usize, OffsetBeforeOrigin> {
+        i64::try_from(self.origin)
+            .ok()
+            .and_then(|origin| origin.checked_add(self.offset))
+            .and_then(|generator_offset| usize::try_from(generator_offset).ok())
+            .ok_or(OffsetBeforeOrigin {
+                offset: self.offset,
+                origin: self.origin,
+            })
+    }
+
+    /// Moves the offset back by `num_rows` rows, which are being prepended to the table.
+    ///
+    /// Errors if there are not enough generators before the table for the new rows.
+    pub fn try_prepend_rows(&mut self, num_rows: usize) -> Result<(), OffsetBeforeOrigin> {
+        let offset = i64::try_from(num_rows)
+            .ok()
+            .and_then(|num_rows| self.offset.checked_sub(num_rows))
+            .ok_or(OffsetBeforeOrigin {
+                offset: i64::MIN,
+                origin: self.origin,
+            })?;
+        *self = Self::try_new(self.origin, offset)?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn we_can_map_signed_offsets_to_generator_offsets() {
+        let offset = SignedOffset::new(100);
+        assert_eq!(offset.offset(), 0);
+        assert_eq!(offset.generator_offset(), 100);
+
+        let offset = SignedOffset::try_new(100, -40).unwrap();
+        assert_eq!(offset.generator_offset(), 60);
+        let offset = SignedOffset::try_new(100, 25).unwrap();
+        assert_eq!(offset.generator_offset(), 125);
+        let offset = SignedOffset::try_new(100, -100).unwrap();
+        assert_eq!(offset.generator_offset(), 0);
+    }
+
+    #[test]
+    fn we_cannot_create_a_signed_offset_before_its_origin() {
+        assert_eq!(
+            SignedOffset::try_new(100, -101),
+            Err(OffsetBeforeOrigin {
+                offset: -101,
+                origin: 100
+            })
+        );
+        assert!(SignedOffset::try_new(0, -1).is_err());
+    }
+
+    #[test]
+    fn we_can_prepend_rows_until_the_origin() {
+        let mut offset = SignedOffset::new(10);
+        offset.try_prepend_rows(4).unwrap();
+        assert_eq!(offset.offset(), -4);
+        assert_eq!(offset.generator_offset(), 6);
+        offset.try_prepend_rows(6).unwrap();
+        assert_eq!(offset.generator_offset(), 0);
+        assert!(offset.try_prepend_rows(1).is_err());
+        assert_eq!(offset.offset(), -10);
+        assert!(offset.try_prepend_rows(usize::MAX).is_err());
+    }
+}
@@ -1,7 +1,11 @@
-use proof_of_sql_parser::{impl_serde_from_str, Identifier, ResourceId};
+use proof_of_sql_parser::{impl_serde_from_str, Identifier, ParseError, ResourceId};
 use std::str::FromStr;
 
 /// Expression for an SQL table
+///
+/// A table reference is always fully qualified, so that it can be used to reliably key tables.
+/// Unqualified names are resolved against a default schema when the reference is constructed,
+/// see [`TableRef::parse_with_default_schema`].
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Copy)]
 pub struct TableRef {
     resource_id: ResourceId,
@@ -13,6 +17,36 @@ impl TableRef {
         Self { resource_id }
     }
 
+    /// Creates a new table reference from a schema and a table identifier
+    pub fn from_names(schema: Identifier, table: Identifier) -> Self {
+        Self::new(ResourceId::new(schema, table))
+    }
+
+    /// Creates a new table reference from a schema and a table name
+    ///
+    /// # Errors
+    /// Fails if either name is not a valid identifier.
+    pub fn try_new(schema: &str, table: &str) -> Result<Self, ParseError> {
+        Ok(Self::new(ResourceId::try_new(schema, table)?))
+    }
+
+    /// Parses either a qualified `schema.table` or an unqualified `table` name.
+    ///
+    /// Unqualified names are placed in `default_schema`.
+    ///
+    /// # Errors
+    /// Fails if the name is not a valid identifier or qualified identifier.
+    pub fn parse_with_default_schema(
+        s: &str,
+        default_schema: Identifier,
+    ) -> Result<Self, ParseError> {
+        if s.contains('.') {
+            s.parse()
+        } else {
+            Ok(Self::from_names(default_schema, Identifier::try_new(s)?))
+        }
+    }
+
     /// Returns the identifier of the schema
     pub fn schema_id(&self) -> Identifier {
         self.resource_id.schema()
@@ -30,7 +64,7 @@ impl TableRef {
 }
 
 impl FromStr for TableRef {
-    type Err = proof_of_sql_parser::ParseError;
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         Ok(Self::new(s.parse()?))
@@ -0,0 +1,57 @@
+use super::TableRef;
+use proof_of_sql_parser::{Identifier, ResourceId};
+
+#[test]
+fn we_can_create_table_refs_from_names() {
+    let table_ref = TableRef::try_new("sxt", "t").unwrap();
+    assert_eq!(table_ref.schema_id(), Identifier::try_new("sxt").unwrap());
+    assert_eq!(table_ref.table_id(), Identifier::try_new("t").unwrap());
+    assert_eq!(table_ref, "sxt.t".parse().unwrap());
+    assert_eq!(
+        table_ref,
+        TableRef::new(ResourceId::try_new("sxt", "t").unwrap())
+    );
+    assert_eq!(
+        table_ref,
+        TableRef::from_names(
+            Identifier::try_new("sxt").unwrap(),
+            Identifier::try_new("t").unwrap()
+        )
+    );
+}
+
+#[test]
+fn we_cannot_create_table_refs_from_invalid_names() {
+    assert!(TableRef::try_new("sxt", "1t").is_err());
+    assert!(TableRef::try_new("s x t", "t").is_err());
+    assert!("sxt".parse::<TableRef>().is_err());
+    assert!("sxt.t.u".parse::<TableRef>().is_err());
+}
+
+#[test]
+fn qualified_and_unqualified_names_resolve_to_the_same_table_ref() {
+    let default_schema = Identifier::try_new("sxt").unwrap();
+    let qualified = TableRef::parse_with_default_schema("sxt.t", default_schema).unwrap();
+    let unqualified = TableRef::parse_with_default_schema("t", default_schema).unwrap();
+    assert_eq!(qualified, unqualified);
+    assert_eq!(qualified, TableRef::try_new("sxt", "t").unwrap());
+}
+
+#[test]
+fn qualified_names_ignore_the_default_schema() {
+    let default_schema = Identifier::try_new("sxt").unwrap();
+    let table_ref = TableRef::parse_with_default_schema("other.t", default_schema).unwrap();
+    assert_eq!(table_ref, TableRef::try_new("other", "t").unwrap());
+    assert_ne!(
+        table_ref,
+        TableRef::parse_with_default_schema("t", default_schema).unwrap()
+    );
+}
+
+#[test]
+fn we_cannot_parse_invalid_names_with_a_default_schema() {
+    let default_schema = Identifier::try_new("sxt").unwrap();
+    assert!(TableRef::parse_with_default_schema("1t", default_schema).is_err());
+    assert!(TableRef::parse_with_default_schema("sxt.", default_schema).is_err());
+    assert!(TableRef::parse_with_default_schema("", default_schema).is_err());
+}
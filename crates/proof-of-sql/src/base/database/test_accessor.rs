@@ -1,5 +1,5 @@
 use super::{
-    Column, ColumnRef, ColumnType, CommitmentAccessor, DataAccessor, MetadataAccessor,
+    Column, ColumnField, ColumnRef, ColumnType, CommitmentAccessor, DataAccessor, MetadataAccessor,
     SchemaAccessor, TableRef,
 };
 use crate::base::{commitment::Commitment, scalar::Curve25519Scalar};
@@ -71,6 +71,10 @@ impl MetadataAccessor for UnimplementedTestAccessor {
     fn get_offset(&self, _table_ref: TableRef) -> usize {
         unimplemented!()
     }
+
+    fn schema(&self) -> Vec<(TableRef, Vec<ColumnField>)> {
+        unimplemented!()
+    }
 }
 impl SchemaAccessor for UnimplementedTestAccessor {
     fn lookup_column(&self, _table_ref: TableRef, _column_id: Identifier) -> Option<ColumnType> {
@@ -1,4 +1,7 @@
-use crate::base::database::ColumnType;
+use crate::base::{
+    database::{ColumnType, OwnedColumn, OwnedTable},
+    scalar::Scalar,
+};
 use arrow::{
     array::{
         Array, BooleanArray, Decimal128Array, Decimal256Array, Int16Array, Int32Array, Int64Array,
@@ -10,7 +13,7 @@ use arrow::{
 };
 use proof_of_sql_parser::posql_time::PoSQLTimeUnit;
 use rand::{
-    distributions::{Distribution, Uniform},
+    distributions::{Alphanumeric, Distribution, Uniform},
     rngs::StdRng,
 };
 use std::sync::Arc;
@@ -25,6 +28,10 @@ pub struct RandomTestAccessorDescriptor {
     pub min_value: i64,
     /// The maximum value of the generated data
     pub max_value: i64,
+    /// The minimum length of generated strings
+    pub min_string_length: usize,
+    /// The maximum length of generated strings
+    pub max_string_length: usize,
 }
 
 impl Default for RandomTestAccessorDescriptor {
@@ -34,6 +41,8 @@ impl Default for RandomTestAccessorDescriptor {
             max_rows: 100,
             min_value: -5,
             max_value: 5,
+            min_string_length: 0,
+            max_string_length: 10,
         }
     }
 }
@@ -65,7 +74,7 @@ pub fn make_random_test_accessor_data(
                 column_fields.push(Field::new(*col_name, DataType::Int16, false));
                 let values: Vec<i16> = values
                     .iter()
-                    .map(|x| ((*x >> 48) as i16)) // Shift right to align the lower 16 bits
+                    .map(|x| (*x).clamp(i16::MIN.into(), i16::MAX.into()) as i16)
                     .collect();
                 columns.push(Arc::new(Int16Array::from(values)));
             }
@@ -73,7 +82,7 @@ pub fn make_random_test_accessor_data(
                 column_fields.push(Field::new(*col_name, DataType::Int32, false));
                 let values: Vec<i32> = values
                     .iter()
-                    .map(|x| ((*x >> 32) as i32)) // Shift right to align the lower 32 bits
+                    .map(|x| (*x).clamp(i32::MIN.into(), i32::MAX.into()) as i32)
                     .collect();
                 columns.push(Arc::new(Int32Array::from(values)));
             }
@@ -154,10 +163,105 @@ pub fn make_random_test_accessor_data(
     RecordBatch::try_new(schema, columns).unwrap()
 }
 
+/// Generate an OwnedTable with random data of the given column types
+///
+/// Integer values are drawn from the descriptor's value range intersected with the range of the
+/// column type, and strings are random alphanumeric strings within the descriptor's length bounds.
+#[allow(dead_code)]
+pub fn make_random_test_accessor_owned_table<S: Scalar>(
+    rng: &mut StdRng,
+    cols: &[(&str, ColumnType)],
+    descriptor: &RandomTestAccessorDescriptor,
+) -> OwnedTable<S> {
+    let n = Uniform::new(descriptor.min_rows, descriptor.max_rows + 1).sample(rng);
+    let sample_values = |rng: &mut StdRng, min: i64, max: i64| -> Vec<i64> {
+        Uniform::new_inclusive(descriptor.min_value.max(min), descriptor.max_value.min(max))
+            .sample_iter(rng)
+            .take(n)
+            .collect()
+    };
+    let columns: Vec<_> = cols
+        .iter()
+        .map(|(col_name, col_type)| {
+            let column = match col_type {
+                ColumnType::Boolean => OwnedColumn::Boolean(
+                    Uniform::new_inclusive(0, 1)
+                        .sample_iter(&mut *rng)
+                        .take(n)
+                        .map(|x| x != 0)
+                        .collect(),
+                ),
+                ColumnType::SmallInt => OwnedColumn::SmallInt(
+                    sample_values(rng, i16::MIN.into(), i16::MAX.into())
+                        .iter()
+                        .map(|x| *x as i16)
+                        .collect(),
+                ),
+                ColumnType::Int => OwnedColumn::Int(
+                    sample_values(rng, i32::MIN.into(), i32::MAX.into())
+                        .iter()
+                        .map(|x| *x as i32)
+                        .collect(),
+                ),
+                ColumnType::BigInt => OwnedColumn::BigInt(sample_values(rng, i64::MIN, i64::MAX)),
+                ColumnType::Int128 => OwnedColumn::Int128(
+                    sample_values(rng, i64::MIN, i64::MAX)
+                        .iter()
+                        .map(|x| *x as i128)
+                        .collect(),
+                ),
+                ColumnType::Decimal75(precision, scale) => OwnedColumn::Decimal75(
+                    *precision,
+                    *scale,
+                    sample_values(rng, i64::MIN, i64::MAX)
+                        .iter()
+                        .map(|x| S::from(*x))
+                        .collect(),
+                ),
+                ColumnType::Scalar => OwnedColumn::Scalar(
+                    sample_values(rng, i64::MIN, i64::MAX)
+                        .iter()
+                        .map(|x| S::from(*x))
+                        .collect(),
+                ),
+                ColumnType::VarChar => {
+                    let length_dist = Uniform::new_inclusive(
+                        descriptor.min_string_length,
+                        descriptor.max_string_length,
+                    );
+                    OwnedColumn::VarChar(
+                        (0..n)
+                            .map(|_| {
+                                let length = length_dist.sample(&mut *rng);
+                                Alphanumeric
+                                    .sample_iter(&mut *rng)
+                                    .take(length)
+                                    .map(char::from)
+                                    .collect()
+                            })
+                            .collect(),
+                    )
+                }
+                ColumnType::TimestampTZ(tu, tz) => {
+                    OwnedColumn::TimestampTZ(*tu, *tz, sample_values(rng, i64::MIN, i64::MAX))
+                }
+            };
+            (col_name.parse().unwrap(), column)
+        })
+        .collect();
+    OwnedTable::try_from_iter(columns).unwrap()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::record_batch;
+    use crate::{base::scalar::Curve25519Scalar, record_batch};
+    #[cfg(all(feature = "blitzar", feature = "prover"))]
+    use crate::{
+        base::{commitment::InnerProductProof, database::OwnedTableTestAccessor},
+        sql::{ast::test_utility::*, proof::VerifiableQueryResult},
+    };
+    use proof_of_sql_parser::Identifier;
     use rand_core::SeedableRng;
 
     #[test]
@@ -184,6 +288,7 @@ mod tests {
             max_rows: 1,
             min_value: -2,
             max_value: -2,
+            ..Default::default()
         };
         let mut rng = StdRng::from_seed([0u8; 32]);
         let cols = [
@@ -198,4 +303,109 @@ mod tests {
             record_batch!("b" => [-2_i64], "a" => ["s-2"], "c" => [-2_i128])
         );
     }
+
+    #[test]
+    fn we_can_construct_a_random_owned_table_with_bounded_values() {
+        let descriptor = RandomTestAccessorDescriptor {
+            min_rows: 10,
+            max_rows: 10,
+            min_value: -100_000,
+            max_value: 100_000,
+            min_string_length: 2,
+            max_string_length: 4,
+        };
+        let mut rng = StdRng::from_seed([0u8; 32]);
+        let cols = [
+            ("a", ColumnType::SmallInt),
+            ("b", ColumnType::VarChar),
+            ("c", ColumnType::Boolean),
+        ];
+        let data: OwnedTable<Curve25519Scalar> =
+            make_random_test_accessor_owned_table(&mut rng, &cols, &descriptor);
+        assert_eq!(data.num_rows(), 10);
+        let a: Identifier = "a".parse().unwrap();
+        let b: Identifier = "b".parse().unwrap();
+        assert_eq!(data.inner_table()[&a].column_type(), ColumnType::SmallInt);
+        match &data.inner_table()[&b] {
+            OwnedColumn::VarChar(col) => {
+                assert!(col.iter().all(|s| (2..=4).contains(&s.len())));
+            }
+            _ => panic!("expected a varchar column"),
+        }
+    }
+
+    #[cfg(all(feature = "blitzar", feature = "prover"))]
+    #[test]
+    fn we_can_filter_on_each_column_type_of_a_random_typed_table() {
+        let descriptor = RandomTestAccessorDescriptor {
+            min_rows: 1,
+            max_rows: 20,
+            min_value: -3,
+            max_value: 3,
+            min_string_length: 0,
+            max_string_length: 1,
+        };
+        let mut rng = StdRng::from_seed([0u8; 32]);
+        let cols = [
+            ("a", ColumnType::BigInt),
+            ("b", ColumnType::Int),
+            ("c", ColumnType::SmallInt),
+            ("d", ColumnType::Int128),
+            ("e", ColumnType::Boolean),
+            ("f", ColumnType::VarChar),
+        ];
+        let t = "sxt.t".parse().unwrap();
+        for _ in 0..5 {
+            let data = make_random_test_accessor_owned_table(&mut rng, &cols, &descriptor);
+            let accessor =
+                OwnedTableTestAccessor::<InnerProductProof>::new_from_table(t, data.clone(), 0, ());
+            for (name, _) in cols {
+                let id: Identifier = name.parse().unwrap();
+                // filter on the value in the first row, so that the result is never empty
+                let (literal, expected) = match &data.inner_table()[&id] {
+                    OwnedColumn::BigInt(col) => (
+                        const_bigint(col[0]),
+                        OwnedColumn::BigInt(col.iter().copied().filter(|v| *v == col[0]).collect()),
+                    ),
+                    OwnedColumn::Int(col) => (
+                        const_int(col[0]),
+                        OwnedColumn::Int(col.iter().copied().filter(|v| *v == col[0]).collect()),
+                    ),
+                    OwnedColumn::SmallInt(col) => (
+                        const_smallint(col[0]),
+                        OwnedColumn::SmallInt(
+                            col.iter().copied().filter(|v| *v == col[0]).collect(),
+                        ),
+                    ),
+                    OwnedColumn::Int128(col) => (
+                        const_int128(col[0]),
+                        OwnedColumn::Int128(col.iter().copied().filter(|v| *v == col[0]).collect()),
+                    ),
+                    OwnedColumn::Boolean(col) => (
+                        const_bool(col[0]),
+                        OwnedColumn::Boolean(
+                            col.iter().copied().filter(|v| *v == col[0]).collect(),
+                        ),
+                    ),
+                    OwnedColumn::VarChar(col) => (
+                        const_varchar(&col[0]),
+                        OwnedColumn::VarChar(
+                            col.iter().filter(|v| **v == col[0]).cloned().collect(),
+                        ),
+                    ),
+                    _ => unreachable!(),
+                };
+                let ast = dense_filter(
+                    cols_expr_plan(t, &["a", "b", "c", "d", "e", "f"], &accessor),
+                    tab(t),
+                    equal(column(t, name, &accessor), literal),
+                );
+                let res = VerifiableQueryResult::new(&ast, &accessor, &())
+                    .verify(&ast, &accessor, &())
+                    .unwrap()
+                    .table;
+                assert_eq!(res.inner_table()[&id], expected);
+            }
+        }
+    }
 }
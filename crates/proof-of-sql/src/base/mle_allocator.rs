@@ -0,0 +1,85 @@
+use bumpalo::Bump;
+use core::{alloc::Layout, ptr::NonNull, slice};
+
+/// An arena allocator for the intermediate MLEs and other values allocated while proving.
+///
+/// Values are never dropped individually: everything allocated lives as long as the borrow of the
+/// allocator, and is released in bulk once the proof is formed. [`Bump`] is the default, but a
+/// pooled allocator tuned to a workload, or a counting allocator for profiling, can be used instead.
+///
+/// The slice helpers used by the prover are provided on `dyn MleAllocator`, so an implementation
+/// only needs to supply raw memory.
+///
+/// # Safety
+///
+/// [`MleAllocator::alloc_layout`] must return a pointer aligned to `layout.align()` that is valid
+/// for reads and writes of `layout.size()` bytes, does not overlap any other allocation, and stays
+/// valid for as long as the allocator is borrowed.
+pub unsafe trait MleAllocator {
+    /// Allocate uninitialized memory for `layout`.
+    ///
+    /// Implementations should panic or abort if the memory cannot be allocated.
+    fn alloc_layout(&self, layout: Layout) -> NonNull<u8>;
+}
+
+// SAFETY: `Bump` never frees or reuses its chunks while it is borrowed.
+unsafe impl MleAllocator for Bump {
+    fn alloc_layout(&self, layout: Layout) -> NonNull<u8> {
+        Bump::alloc_layout(self, layout)
+    }
+}
+
+impl dyn MleAllocator + '_ {
+    /// Allocate a slice of length `len`, whose `i`-th element is `f(i)`.
+    pub fn alloc_slice_fill_with<T, F: FnMut(usize) -> T>(&self, len: usize, mut f: F) -> &mut [T] {
+        let layout = Layout::array::<T>(len).expect("the slice is too large to allocate");
+        let ptr = self.alloc_layout(layout).cast::<T>().as_ptr();
+        // SAFETY: `ptr` is aligned and valid for writes of `len` elements, which are all
+        // initialized before the slice is formed.
+        unsafe {
+            for i in 0..len {
+                ptr.add(i).write(f(i));
+            }
+            slice::from_raw_parts_mut(ptr, len)
+        }
+    }
+
+    /// Allocate a slice holding the elements of `iter`.
+    pub fn alloc_slice_fill_iter<T, I>(&self, iter: I) -> &mut [T]
+    where
+        I: IntoIterator<Item = T>,
+        I::IntoIter: ExactSizeIterator,
+    {
+        let mut iter = iter.into_iter();
+        self.alloc_slice_fill_with(iter.len(), |_| {
+            iter.next().expect("the iterator supplied too few elements")
+        })
+    }
+
+    /// Allocate a slice of length `len` filled with `value`.
+    pub fn alloc_slice_fill_copy<T: Copy>(&self, len: usize, value: T) -> &mut [T] {
+        self.alloc_slice_fill_with(len, |_| value)
+    }
+
+    /// Allocate a slice of length `len` filled with the default value of `T`.
+    pub fn alloc_slice_fill_default<T: Default>(&self, len: usize) -> &mut [T] {
+        self.alloc_slice_fill_with(len, |_| T::default())
+    }
+
+    /// Allocate a copy of `src`.
+    pub fn alloc_slice_copy<T: Copy>(&self, src: &[T]) -> &mut [T] {
+        self.alloc_slice_fill_with(src.len(), |i| src[i])
+    }
+
+    /// Allocate a clone of each element of `src`.
+    pub fn alloc_slice_clone<T: Clone>(&self, src: &[T]) -> &mut [T] {
+        self.alloc_slice_fill_with(src.len(), |i| src[i].clone())
+    }
+
+    /// Allocate a copy of `src`.
+    pub fn alloc_str(&self, src: &str) -> &mut str {
+        let bytes = self.alloc_slice_copy(src.as_bytes());
+        // SAFETY: the bytes are a copy of a valid `str`.
+        unsafe { core::str::from_utf8_unchecked_mut(bytes) }
+    }
+}
@@ -0,0 +1,36 @@
+use super::MleAllocator;
+use bumpalo::Bump;
+
+#[test]
+fn we_can_allocate_slices_with_a_dyn_allocator() {
+    let bump = Bump::new();
+    let alloc: &dyn MleAllocator = &bump;
+    assert_eq!(alloc.alloc_slice_fill_with(3, |i| i * i), [0, 1, 4]);
+    assert_eq!(alloc.alloc_slice_fill_iter([5_i64, -2]), [5, -2]);
+    assert_eq!(alloc.alloc_slice_fill_copy(2, true), [true, true]);
+    assert_eq!(alloc.alloc_slice_fill_default::<u8>(2), [0, 0]);
+    assert_eq!(alloc.alloc_slice_copy(&[1_u16, 2]), [1, 2]);
+    assert_eq!(
+        alloc.alloc_slice_clone(&["a".to_string()]),
+        ["a".to_string()]
+    );
+    assert_eq!(alloc.alloc_str("abc"), "abc");
+}
+
+#[test]
+fn we_can_allocate_empty_slices_with_a_dyn_allocator() {
+    let bump = Bump::new();
+    let alloc: &dyn MleAllocator = &bump;
+    assert!(alloc.alloc_slice_copy::<u64>(&[]).is_empty());
+    assert!(alloc.alloc_slice_fill_with(0, |_| ()).is_empty());
+    assert_eq!(alloc.alloc_str(""), "");
+}
+
+#[test]
+fn allocated_slices_are_aligned() {
+    let bump = Bump::new();
+    let alloc: &dyn MleAllocator = &bump;
+    alloc.alloc_slice_copy(&[1_u8]);
+    let slice = alloc.alloc_slice_fill_copy(3, 1_u128);
+    assert_eq!(slice.as_ptr() as usize % core::mem::align_of::<u128>(), 0);
+}
@@ -4,6 +4,10 @@ pub mod commitment;
 pub mod database;
 pub(crate) mod encode;
 pub mod math;
+mod mle_allocator;
+pub use mle_allocator::MleAllocator;
+#[cfg(test)]
+mod mle_allocator_test;
 pub(crate) mod polynomial;
 pub(crate) mod proof;
 pub(crate) mod ref_into;
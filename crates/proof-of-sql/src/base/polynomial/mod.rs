@@ -1,6 +1,6 @@
 mod composite_polynomial;
 pub use composite_polynomial::{CompositePolynomial, CompositePolynomialInfo};
-#[cfg(test)]
+#[cfg(all(test, feature = "prover"))]
 mod composite_polynomial_test;
 
 mod interpolate;
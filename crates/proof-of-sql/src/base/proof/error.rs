@@ -1,3 +1,5 @@
+use crate::base::database::{ColumnRef, ColumnType, TableRef};
+use proof_of_sql_parser::Identifier;
 use thiserror::Error;
 
 #[derive(Error, Debug)]
@@ -6,4 +8,87 @@ pub enum ProofError {
     #[error("Verification error: {0}")]
     /// This error occurs when a proof failed to verify.
     VerificationError(&'static str),
+    #[error("Proof too large: {0}")]
+    /// This error occurs when a proof contains more components than the query could require.
+    ProofTooLarge(&'static str),
+    #[error("Proof header mismatch: {0}")]
+    /// This error occurs when the header of a proof does not describe a proof of the query.
+    HeaderMismatch(&'static str),
+    #[error(
+        "Commitment mismatch for column {} in table {}",
+        column.column_id(),
+        column.table_ref()
+    )]
+    /// This error occurs when the commitment of a column does not match the data the proof was
+    /// created from.
+    CommitmentMismatch {
+        /// The column whose commitment does not match
+        column: ColumnRef,
+    },
+    #[error("Sumcheck degree {degree} exceeds the maximum of {max}")]
+    /// This error occurs when the sumcheck polynomial of a query has a degree greater than
+    /// [`MAX_SUMCHECK_DEGREE`](crate::sql::proof::MAX_SUMCHECK_DEGREE).
+    DegreeTooHigh {
+        /// The degree of the sumcheck polynomial
+        degree: usize,
+        /// The maximum supported degree
+        max: usize,
+    },
+    #[error("Division by zero")]
+    /// This error occurs when an expression divides by a constant zero.
+    DivisionByZero,
+    #[error("Unsupported cast from {from} to {to}")]
+    /// This error occurs when a cast could lose information or is between incompatible types.
+    UnsupportedCast {
+        /// The type being cast from
+        from: ColumnType,
+        /// The type being cast to
+        to: ColumnType,
+    },
+    #[error("Invalid result indexes")]
+    /// This error occurs when sparse result indexes are not strictly increasing or are outside
+    /// of the table.
+    InvalidIndexes,
+    #[error("The result has {rows} rows, more than the maximum of {max}")]
+    /// This error occurs when a plan selects more result rows than the prover allows.
+    ResultTooLarge {
+        /// The number of rows selected by the plan
+        rows: usize,
+        /// The maximum number of rows allowed
+        max: usize,
+    },
+    #[error("Unsupported proof format version {major}.{minor}")]
+    /// This error occurs when a serialized proof was written with a format version outside of
+    /// the range this crate can read.
+    UnsupportedVersion {
+        /// The major version of the proof format
+        major: u16,
+        /// The minor version of the proof format
+        minor: u16,
+    },
+    #[error(
+        "Column {} in table {} has {actual} rows, more than the {expected} rows of the proof",
+        column.column_id(),
+        column.table_ref()
+    )]
+    /// This error occurs when a column referenced by a proof is longer than the table length
+    /// declared by the plan, so that its MLE would be evaluated over the wrong domain.
+    ///
+    /// Shorter columns are allowed, as plans over several tables pad them with zeros.
+    LengthMismatch {
+        /// The column whose length does not match
+        column: ColumnRef,
+        /// The table length declared by the plan
+        expected: usize,
+        /// The length of the column's table
+        actual: usize,
+    },
+    #[error("Column {column} not found in table {table}")]
+    /// This error occurs when a column is not in the schema of its table.
+    ColumnNotFound {
+        /// The table that was searched
+        table: TableRef,
+        /// The column that was not found
+        column: Identifier,
+    },
 }
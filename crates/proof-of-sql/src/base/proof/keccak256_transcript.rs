@@ -0,0 +1,47 @@
+use super::TranscriptCore;
+use tiny_keccak::{Hasher, Keccak};
+
+/// A Fiat-Shamir transcript built on Keccak256, so that challenges can be recomputed cheaply on
+/// the EVM.
+///
+/// The transcript keeps a single 32-byte state. Every message is absorbed by hashing it together
+/// with the previous state, its label, and the lengths of both, and challenge bytes are squeezed
+/// by repeatedly hashing the state.
+#[derive(Clone, Debug)]
+pub struct Keccak256Transcript {
+    state: [u8; 32],
+}
+
+impl Keccak256Transcript {
+    fn absorb(&mut self, label: &[u8], message: &[u8]) {
+        let mut hasher = Keccak::v256();
+        hasher.update(&self.state);
+        hasher.update(&(label.len() as u64).to_le_bytes());
+        hasher.update(label);
+        hasher.update(&(message.len() as u64).to_le_bytes());
+        hasher.update(message);
+        hasher.finalize(&mut self.state);
+    }
+}
+
+impl TranscriptCore for Keccak256Transcript {
+    fn new(label: &'static [u8]) -> Self {
+        let mut transcript = Self { state: [0; 32] };
+        transcript.absorb(b"dom-sep", label);
+        transcript
+    }
+
+    fn append_message(&mut self, label: &'static [u8], message: &[u8]) {
+        self.absorb(label, message);
+    }
+
+    fn challenge_bytes(&mut self, label: &'static [u8], dest: &mut [u8]) {
+        self.absorb(label, &(dest.len() as u64).to_le_bytes());
+        for chunk in dest.chunks_mut(32) {
+            let mut hasher = Keccak::v256();
+            hasher.update(&self.state);
+            hasher.finalize(&mut self.state);
+            chunk.copy_from_slice(&self.state[..chunk.len()]);
+        }
+    }
+}
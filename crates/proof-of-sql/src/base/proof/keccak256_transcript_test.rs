@@ -0,0 +1,47 @@
+use super::{Keccak256Transcript, MessageLabel, TranscriptCore, TranscriptProtocol};
+use crate::base::scalar::Curve25519Scalar;
+use merlin::Transcript;
+
+#[test]
+fn we_get_equivalent_challenges_from_equivalent_keccak_transcripts() {
+    let mut transcript = <Keccak256Transcript as TranscriptCore>::new(b"same");
+    let mut transcript2 = <Keccak256Transcript as TranscriptCore>::new(b"same");
+    transcript.append_auto(MessageLabel::TableLength, &5usize);
+    transcript2.append_auto(MessageLabel::TableLength, &5usize);
+    let mut v = [Curve25519Scalar::from(0u64); 3];
+    let mut w = [Curve25519Scalar::from(0u64); 3];
+    transcript.challenge_scalars(&mut v, MessageLabel::SumcheckChallenge);
+    transcript2.challenge_scalars(&mut w, MessageLabel::SumcheckChallenge);
+    assert_eq!(v, w);
+    assert_ne!(v[0], v[1]);
+    assert_ne!(v[1], v[2]);
+}
+
+#[test]
+fn we_get_different_challenges_from_different_keccak_transcripts() {
+    let mut transcript = <Keccak256Transcript as TranscriptCore>::new(b"same");
+    let mut transcript2 = <Keccak256Transcript as TranscriptCore>::new(b"same");
+    transcript.append_auto(MessageLabel::TableLength, &5usize);
+    transcript2.append_auto(MessageLabel::TableLength, &6usize);
+    let v: Curve25519Scalar = transcript.challenge_scalar_single(MessageLabel::SumcheckChallenge);
+    let w: Curve25519Scalar = transcript2.challenge_scalar_single(MessageLabel::SumcheckChallenge);
+    assert_ne!(v, w);
+}
+
+#[test]
+fn keccak_and_merlin_transcripts_give_different_challenges() {
+    let mut keccak = <Keccak256Transcript as TranscriptCore>::new(b"same");
+    let mut merlin = Transcript::new(b"same");
+    let v: Curve25519Scalar = keccak.challenge_scalar_single(MessageLabel::SumcheckChallenge);
+    let w: Curve25519Scalar = merlin.challenge_scalar_single(MessageLabel::SumcheckChallenge);
+    assert_ne!(v, w);
+}
+
+#[test]
+fn we_can_squeeze_more_than_one_block_of_challenge_bytes() {
+    let mut transcript = <Keccak256Transcript as TranscriptCore>::new(b"test");
+    let mut bytes = [0u8; 80];
+    transcript.challenge_bytes(b"challenge", &mut bytes);
+    assert_ne!(bytes[..32], bytes[32..64]);
+    assert_ne!(bytes[64..], [0u8; 16]);
+}
@@ -7,4 +7,18 @@ pub use error::ProofError;
 mod transcript_protocol;
 #[cfg(test)]
 mod transcript_protocol_test;
-pub use transcript_protocol::{MessageLabel, TranscriptProtocol};
+pub use transcript_protocol::{MessageLabel, TranscriptCore, TranscriptProtocol};
+
+/// Contains a transcript built on Keccak256.
+mod keccak256_transcript;
+#[cfg(test)]
+mod keccak256_transcript_test;
+pub use keccak256_transcript::Keccak256Transcript;
+
+/// Contains hooks for inspecting the transcript operations performed while proving and verifying.
+#[cfg(feature = "debug-transcript")]
+mod transcript_observer;
+#[cfg(feature = "debug-transcript")]
+pub use transcript_observer::{
+    first_divergence, with_transcript_observer, TranscriptEvent, TranscriptObserver,
+};
@@ -0,0 +1,84 @@
+use super::MessageLabel;
+use std::cell::RefCell;
+
+/// A single operation performed on a transcript through [`super::TranscriptProtocol`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TranscriptEvent {
+    /// A message was absorbed into the transcript.
+    Message {
+        /// The label the message was appended with.
+        label: MessageLabel,
+        /// The serialized message.
+        message: Vec<u8>,
+    },
+    /// Challenges were drawn from the transcript.
+    Challenge {
+        /// The label the challenges were drawn with.
+        label: MessageLabel,
+        /// The raw bytes drawn from the transcript to derive the challenges.
+        challenge: Vec<u8>,
+    },
+}
+
+impl TranscriptEvent {
+    /// The label of the event.
+    pub fn label(&self) -> MessageLabel {
+        match self {
+            TranscriptEvent::Message { label, .. } | TranscriptEvent::Challenge { label, .. } => {
+                *label
+            }
+        }
+    }
+}
+
+/// A callback which is notified of every message absorbed into and every challenge drawn from
+/// a transcript on the current thread.
+///
+/// Recording the events seen while proving and while verifying the same query, and diffing the
+/// two logs, shows exactly where the prover and verifier transcripts diverge.
+pub trait TranscriptObserver {
+    /// Called for every transcript operation, in order.
+    fn observe(&mut self, event: &TranscriptEvent);
+}
+
+impl<F: FnMut(&TranscriptEvent)> TranscriptObserver for F {
+    fn observe(&mut self, event: &TranscriptEvent) {
+        self(event)
+    }
+}
+
+thread_local! {
+    static OBSERVER: RefCell<Option<Box<dyn TranscriptObserver>>> = RefCell::new(None);
+}
+
+/// Runs `f` with `observer` notified of every transcript operation performed on this thread.
+///
+/// Any previously installed observer is restored afterwards.
+pub fn with_transcript_observer<R>(
+    observer: impl TranscriptObserver + 'static,
+    f: impl FnOnce() -> R,
+) -> R {
+    let previous = OBSERVER.with(|cell| cell.borrow_mut().replace(Box::new(observer)));
+    let res = f();
+    OBSERVER.with(|cell| *cell.borrow_mut() = previous);
+    res
+}
+
+/// Notifies the installed observer, if any, of `event`.
+pub(super) fn notify(event: impl FnOnce() -> TranscriptEvent) {
+    OBSERVER.with(|cell| {
+        if let Some(observer) = cell.borrow_mut().as_mut() {
+            observer.observe(&event());
+        }
+    });
+}
+
+/// Returns the index of the first event at which two transcript logs differ, if any.
+///
+/// If one log is a prefix of the other, the index is the length of the shorter log.
+pub fn first_divergence(lhs: &[TranscriptEvent], rhs: &[TranscriptEvent]) -> Option<usize> {
+    lhs.iter()
+        .zip(rhs)
+        .position(|(a, b)| a != b)
+        .or_else(|| (lhs.len() != rhs.len()).then(|| lhs.len().min(rhs.len())))
+}
@@ -1,6 +1,58 @@
+#[cfg(feature = "debug-transcript")]
+use super::transcript_observer::{notify, TranscriptEvent};
 use ark_serialize::CanonicalSerialize;
 use merlin::Transcript;
 
+/// The hash underlying a Fiat-Shamir transcript.
+///
+/// This has the same shape as the primitive operations of `merlin::Transcript`. Every
+/// implementation gets [`TranscriptProtocol`], so the protocols can be run over any hash.
+pub trait TranscriptCore: Sized {
+    /// Create a transcript whose state is initialized from `label`.
+    fn new(label: &'static [u8]) -> Self;
+
+    /// Absorb `message` into the transcript under `label`.
+    fn append_message(&mut self, label: &'static [u8], message: &[u8]);
+
+    /// Fill `dest` with challenge bytes derived from everything absorbed so far.
+    fn challenge_bytes(&mut self, label: &'static [u8], dest: &mut [u8]);
+
+    /// Run `f` on a `merlin::Transcript` that is bound to the state of this transcript.
+    ///
+    /// The commitment evaluation proofs are defined over merlin transcripts. By default, a fresh
+    /// merlin transcript is seeded with a challenge drawn from this transcript, and a challenge
+    /// drawn from the merlin transcript afterwards is absorbed back, so that anything drawn from
+    /// this transcript later still depends on the evaluation proof.
+    fn with_merlin_transcript<R>(&mut self, f: impl FnOnce(&mut Transcript) -> R) -> R {
+        let mut seed = [0u8; 32];
+        self.challenge_bytes(MessageLabel::MerlinSeed.as_bytes(), &mut seed);
+        let mut transcript = Transcript::new(MessageLabel::MerlinSeed.as_bytes());
+        transcript.append_message(MessageLabel::MerlinSeed.as_bytes(), &seed);
+        let res = f(&mut transcript);
+        transcript.challenge_bytes(MessageLabel::MerlinDigest.as_bytes(), &mut seed);
+        self.append_message(MessageLabel::MerlinDigest.as_bytes(), &seed);
+        res
+    }
+}
+
+impl TranscriptCore for Transcript {
+    fn new(label: &'static [u8]) -> Self {
+        Transcript::new(label)
+    }
+
+    fn append_message(&mut self, label: &'static [u8], message: &[u8]) {
+        Transcript::append_message(self, label, message);
+    }
+
+    fn challenge_bytes(&mut self, label: &'static [u8], dest: &mut [u8]) {
+        Transcript::challenge_bytes(self, label, dest);
+    }
+
+    fn with_merlin_transcript<R>(&mut self, f: impl FnOnce(&mut Transcript) -> R) -> R {
+        f(self)
+    }
+}
+
 /// Merlin Transcripts, for non-interactive proofs.
 ///
 /// Think of this as a transaction log.
@@ -63,9 +115,15 @@ pub trait TranscriptProtocol {
     }
 }
 
-impl TranscriptProtocol for Transcript {
+impl<T: TranscriptCore> TranscriptProtocol for T {
     fn append_auto(&mut self, label: MessageLabel, message: &(impl serde::Serialize + ?Sized)) {
-        self.append_message(label.as_bytes(), &postcard::to_allocvec(message).unwrap());
+        let message = postcard::to_allocvec(message).unwrap();
+        #[cfg(feature = "debug-transcript")]
+        notify(|| TranscriptEvent::Message {
+            label,
+            message: message.clone(),
+        });
+        self.append_message(label.as_bytes(), &message);
     }
 
     fn append_canonical_serialize(
@@ -75,6 +133,11 @@ impl TranscriptProtocol for Transcript {
     ) {
         let mut buf = vec![Default::default(); message.compressed_size()];
         message.serialize_compressed(&mut buf).unwrap();
+        #[cfg(feature = "debug-transcript")]
+        notify(|| TranscriptEvent::Message {
+            label,
+            message: buf.clone(),
+        });
         self.append_message(label.as_bytes(), &buf);
     }
 
@@ -84,8 +147,11 @@ impl TranscriptProtocol for Transcript {
         label: MessageLabel,
     ) {
         self.append_message(label.as_bytes(), &[]);
-        struct TranscriptProtocolRng<'a>(&'a mut Transcript);
-        impl<'a> ark_std::rand::RngCore for TranscriptProtocolRng<'a> {
+        struct TranscriptProtocolRng<'a, T: TranscriptCore>(
+            &'a mut T,
+            #[cfg(feature = "debug-transcript")] Vec<u8>,
+        );
+        impl<'a, T: TranscriptCore> ark_std::rand::RngCore for TranscriptProtocolRng<'a, T> {
             fn next_u32(&mut self) -> u32 {
                 let mut buf = [0u8; 4];
                 self.fill_bytes(&mut buf);
@@ -98,20 +164,31 @@ impl TranscriptProtocol for Transcript {
             }
             fn fill_bytes(&mut self, dest: &mut [u8]) {
                 self.0.challenge_bytes(&[], dest);
+                #[cfg(feature = "debug-transcript")]
+                self.1.extend_from_slice(dest);
             }
             fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), ark_std::rand::Error> {
                 self.fill_bytes(dest);
                 Ok(())
             }
         }
-        let rng = &mut TranscriptProtocolRng(self);
+        let rng = &mut TranscriptProtocolRng(
+            self,
+            #[cfg(feature = "debug-transcript")]
+            Vec::new(),
+        );
         for val in buf {
             *val = ark_ff::UniformRand::rand(rng);
         }
+        #[cfg(feature = "debug-transcript")]
+        notify(|| TranscriptEvent::Challenge {
+            label,
+            challenge: core::mem::take(&mut rng.1),
+        });
     }
 }
 
-/// Labels for items in a merlin transcript.
+/// Labels for items in a transcript.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum MessageLabel {
     /// Represents an inner product computation or its result.
@@ -130,6 +207,8 @@ pub enum MessageLabel {
     QueryProof,
     /// Represents a commitment to a query.
     QueryCommit,
+    /// Represents the intermediate MLEs of a query that share the commitment of another.
+    IntermediateMleAliases,
     /// Represents evaluations in an MLE context.
     QueryMleEvaluations,
     /// Represents a challenge in the context of MLE evaluations.
@@ -154,6 +233,18 @@ pub enum MessageLabel {
     TableLength,
     /// Represents an offset for a generator.
     GeneratorOffset,
+    /// Represents an application-supplied nonce that binds a proof to a context.
+    QueryNonce,
+    /// Represents the seed of a merlin transcript derived from another transcript.
+    MerlinSeed,
+    /// Represents a challenge from a derived merlin transcript absorbed back into its parent.
+    MerlinDigest,
+    /// Represents the columns referenced by a query, in canonical order.
+    ColumnReferences,
+    /// Represents the epochs of the data of the columns referenced by a query.
+    ColumnEpochs,
+    /// Represents a seed, shared by every party, that a query transcript starts from.
+    TranscriptSeed,
 }
 
 impl MessageLabel {
@@ -170,6 +261,7 @@ impl MessageLabel {
             MessageLabel::SumcheckRoundEvaluation => b"sumcheckroundevaluationscalars v1",
             MessageLabel::QueryProof => b"queryproof v1",
             MessageLabel::QueryCommit => b"querycommit v1",
+            MessageLabel::IntermediateMleAliases => b"intermediatemlealiases v1",
             MessageLabel::QueryResultData => b"queryresultdata v1",
             MessageLabel::QueryBitDistributions => b"querybitdistributions v1",
             MessageLabel::QueryMleEvaluations => b"querymleevaluations v1",
@@ -182,6 +274,12 @@ impl MessageLabel {
             MessageLabel::ProofExecutionPlan => b"proofexec v1",
             MessageLabel::TableLength => b"tablelength v1",
             MessageLabel::GeneratorOffset => b"generatoroffset v1",
+            MessageLabel::QueryNonce => b"querynonce v1",
+            MessageLabel::MerlinSeed => b"merlinseed v1",
+            MessageLabel::MerlinDigest => b"merlindigest v1",
+            MessageLabel::ColumnReferences => b"columnreferences v1",
+            MessageLabel::ColumnEpochs => b"columnepochs v1",
+            MessageLabel::TranscriptSeed => b"transcriptseed v1",
         }
     }
 }
@@ -29,6 +29,7 @@ pub trait Scalar:
     + for<'a> From<&'a str>
     + Sync
     + Send
+    + 'static
     + num_traits::One
     + core::iter::Sum
     + core::iter::Product
@@ -88,6 +89,28 @@ pub trait Scalar:
             _ => Ordering::Greater,
         }
     }
+    /// Replace every element of `values` with its inverse, using Montgomery's trick so that only
+    /// a single field inversion is needed.
+    ///
+    /// Zeros have no inverse and are left as zero.
+    fn batch_invert(values: &mut [Self]) {
+        super::slice_ops::batch_inversion(values);
+    }
+    /// Embed `value` into the field, returning `None` if its magnitude exceeds
+    /// [`Scalar::MAX_SIGNED`], where it would wrap around and read back as a different value.
+    fn try_from_i128(value: i128) -> Option<Self> {
+        let scalar = Self::try_from(BigInt::from(value)).ok()?;
+        let reads_back_negative = scalar > Self::MAX_SIGNED;
+        (reads_back_negative == (value < 0)).then_some(scalar)
+    }
+    /// Convert to the integer type `T`, returning `None` rather than truncating if the signed
+    /// value of the scalar lies outside the range of `T`.
+    fn checked_into<T>(self) -> Option<T>
+    where
+        Self: TryInto<T>,
+    {
+        self.try_into().ok()
+    }
 }
 
 macro_rules! scalar_conversion_to_int {
@@ -290,3 +290,54 @@ fn test_curve25519_scalar_from_bigint() {
         -Curve25519Scalar::ONE
     );
 }
+
+#[test]
+fn we_can_batch_invert_scalars() {
+    let values: Vec<Curve25519Scalar> = [1_i64, 2, -3, 0, 7, 0, 12345, -1]
+        .into_iter()
+        .map(Curve25519Scalar::from)
+        .collect();
+    let mut inverted = values.clone();
+    Curve25519Scalar::batch_invert(&mut inverted);
+    let expected: Vec<Curve25519Scalar> = values
+        .iter()
+        .copied()
+        .map(|v| v.inv().unwrap_or(Curve25519Scalar::ZERO))
+        .collect();
+    assert_eq!(inverted, expected);
+    assert_eq!(inverted[3], Curve25519Scalar::ZERO);
+    assert_eq!(inverted[5], Curve25519Scalar::ZERO);
+    assert_eq!(inverted[2] * values[2], Curve25519Scalar::ONE);
+}
+
+#[test]
+fn we_can_batch_invert_an_empty_or_all_zero_slice() {
+    let mut empty: [Curve25519Scalar; 0] = [];
+    Curve25519Scalar::batch_invert(&mut empty);
+    let mut zeros = [Curve25519Scalar::ZERO; 3];
+    Curve25519Scalar::batch_invert(&mut zeros);
+    assert_eq!(zeros, [Curve25519Scalar::ZERO; 3]);
+}
+
+#[test]
+fn we_can_embed_any_i128_without_wrapping() {
+    for value in [0, 1, -1, i64::MAX as i128 + 1, i128::MAX, i128::MIN] {
+        let scalar = Curve25519Scalar::try_from_i128(value).unwrap();
+        assert_eq!(scalar, Curve25519Scalar::from(value));
+        assert_eq!(scalar.checked_into::<i128>(), Some(value));
+    }
+}
+
+#[test]
+fn checked_conversions_reject_scalars_outside_the_target_range() {
+    let too_big = Curve25519Scalar::from(i64::MAX as i128 + 1);
+    let too_small = Curve25519Scalar::from(i64::MIN as i128 - 1);
+    assert_eq!(too_big.checked_into::<i64>(), None);
+    assert_eq!(too_small.checked_into::<i64>(), None);
+    assert_eq!(too_big.checked_into::<i128>(), Some(i64::MAX as i128 + 1));
+    assert_eq!(
+        Curve25519Scalar::from(i64::MIN).checked_into::<i64>(),
+        Some(i64::MIN)
+    );
+    assert_eq!(Curve25519Scalar::MAX_SIGNED.checked_into::<i128>(), None);
+}
@@ -82,6 +82,7 @@ fn test_slice_cast_mut_with_random() {
 }
 
 /// random test for slice_cast_mut_with
+#[cfg(feature = "prover")]
 #[test]
 fn test_slice_cast_mut_random() {
     use rand::Rng;
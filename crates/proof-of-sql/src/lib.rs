@@ -1,5 +1,8 @@
 #![doc = include_str!("../README.md")]
 
+// Lets the code generated by `proof-of-sql-derive` refer to this crate from within it.
+extern crate self as proof_of_sql;
+
 pub mod base;
 pub mod proof_primitive;
 pub mod sql;
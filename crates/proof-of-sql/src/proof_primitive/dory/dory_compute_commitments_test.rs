@@ -1,7 +1,11 @@
 use crate::{
-    base::{commitment::CommittableColumn, math::decimal::Precision},
+    base::{
+        commitment::{commitment_conformance_test::test_commitment_conformance, CommittableColumn},
+        math::decimal::Precision,
+    },
     proof_primitive::dory::{
-        compute_dory_commitments, DoryProverPublicSetup, ProverSetup, PublicParameters, F, GT,
+        compute_dory_commitments, DoryCommitment, DoryProverPublicSetup, ProverSetup,
+        PublicParameters, F, GT,
     },
 };
 use ark_ec::pairing::Pairing;
@@ -691,3 +695,13 @@ fn we_can_compute_a_dory_commitment_with_mixed_committable_columns_with_an_offse
         + Pairing::pairing(Gamma_1[3], Gamma_2[1]) * F::from(18);
     assert_eq!(res[9].0, expected);
 }
+
+#[test]
+fn dory_commitments_pass_the_conformance_suite() {
+    let public_parameters = PublicParameters::rand(5, &mut test_rng());
+    let prover_setup = ProverSetup::from(&public_parameters);
+    for sigma in [1, 3] {
+        let setup = DoryProverPublicSetup::new(&prover_setup, sigma);
+        test_commitment_conformance::<DoryCommitment>(&setup);
+    }
+}
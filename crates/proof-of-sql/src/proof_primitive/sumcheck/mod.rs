@@ -1,7 +1,7 @@
 mod proof;
-#[cfg(test)]
+#[cfg(all(test, feature = "prover"))]
 mod proof_test;
-pub use proof::SumcheckProof;
+pub use proof::{SumcheckProof, SumcheckRoundVerifier};
 
 mod prover_state;
 use prover_state::ProverState;
@@ -10,4 +10,14 @@ mod subclaim;
 pub use subclaim::Subclaim;
 
 mod prover_round;
-use prover_round::prove_round;
+use prover_round::{prove_multi_variable_round, prove_round};
+
+mod strategy;
+pub use strategy::SumcheckStrategy;
+
+/// The largest degree that the sumcheck polynomial of a query's proof may have.
+///
+/// Each round sends `degree + 1` evaluations for each variable it binds, which the verifier
+/// interpolates, so the degree is bounded to keep the size of a round and the cost of verifying
+/// it in check.
+pub const MAX_SUMCHECK_DEGREE: usize = 32;
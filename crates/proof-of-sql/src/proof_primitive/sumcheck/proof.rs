@@ -1,12 +1,13 @@
 use crate::{
     base::{
-        polynomial::{CompositePolynomial, CompositePolynomialInfo},
+        polynomial::{interpolate_uni_poly, CompositePolynomial, CompositePolynomialInfo},
         proof::{MessageLabel, ProofError, TranscriptProtocol},
         scalar::Scalar,
     },
-    proof_primitive::sumcheck::{prove_round, ProverState, Subclaim},
+    proof_primitive::sumcheck::{
+        prove_multi_variable_round, prove_round, ProverState, Subclaim, SumcheckStrategy,
+    },
 };
-use merlin::Transcript;
 use serde::{Deserialize, Serialize};
 /**
  * Adopted from arkworks
@@ -18,35 +19,94 @@ use std::vec::Vec;
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct SumcheckProof<S: Scalar> {
     pub(super) evaluations: Vec<Vec<S>>,
+    pub(super) strategy: SumcheckStrategy,
 }
 
 impl<S: Scalar> SumcheckProof<S> {
-    #[tracing::instrument(name = "SumcheckProof::create", level = "debug", skip_all)]
     pub fn create(
-        transcript: &mut Transcript,
+        transcript: &mut impl TranscriptProtocol,
+        evaluation_point: &mut [S],
+        polynomial: &CompositePolynomial<S>,
+    ) -> Self {
+        Self::create_with_strategy(
+            transcript,
+            evaluation_point,
+            polynomial,
+            SumcheckStrategy::default(),
+        )
+    }
+
+    /// Create a proof whose rounds each bind the number of variables chosen by `strategy`.
+    #[tracing::instrument(name = "SumcheckProof::create", level = "debug", skip_all)]
+    pub fn create_with_strategy(
+        transcript: &mut impl TranscriptProtocol,
         evaluation_point: &mut [S],
         polynomial: &CompositePolynomial<S>,
+        strategy: SumcheckStrategy,
     ) -> Self {
         assert_eq!(evaluation_point.len(), polynomial.num_variables);
         transcript.append_auto(
             MessageLabel::Sumcheck,
-            &(polynomial.max_multiplicands, polynomial.num_variables),
+            &(
+                polynomial.max_multiplicands,
+                polynomial.num_variables,
+                strategy,
+            ),
         );
-        let mut r = None;
         let mut state = ProverState::create(polynomial);
-        let mut evaluations = Vec::with_capacity(polynomial.num_variables);
-        for scalar in evaluation_point.iter_mut().take(polynomial.num_variables) {
-            let round_evaluations = prove_round(&mut state, &r);
+        let mut evaluations = Vec::with_capacity(strategy.num_rounds(polynomial.num_variables));
+        let mut bound = 0;
+        let mut previous_round_variables = 0;
+        for round_variables in strategy.round_variables(polynomial.num_variables) {
+            let previous_challenges = &evaluation_point[bound - previous_round_variables..bound];
+            let round_evaluations = match strategy {
+                SumcheckStrategy::SingleVariable => {
+                    prove_round(&mut state, &previous_challenges.first().copied())
+                }
+                SumcheckStrategy::PairedVariables => {
+                    prove_multi_variable_round(&mut state, previous_challenges, round_variables)
+                }
+            };
             transcript.append_canonical_serialize(
                 MessageLabel::SumcheckRoundEvaluation,
                 &round_evaluations,
             );
             evaluations.push(round_evaluations);
-            *scalar = transcript.challenge_scalar_single(MessageLabel::SumcheckChallenge);
-            r = Some(*scalar);
+            for scalar in &mut evaluation_point[bound..bound + round_variables] {
+                *scalar = transcript.challenge_scalar_single(MessageLabel::SumcheckChallenge);
+            }
+            bound += round_variables;
+            previous_round_variables = round_variables;
+        }
+
+        SumcheckProof {
+            evaluations,
+            strategy,
         }
+    }
+
+    /// The evaluations sent by the prover in each round.
+    pub fn round_evaluations(&self) -> &[Vec<S>] {
+        &self.evaluations
+    }
 
-        SumcheckProof { evaluations }
+    /// The strategy the proof was created with.
+    pub fn strategy(&self) -> SumcheckStrategy {
+        self.strategy
+    }
+
+    /// Returns true if the proof has no more rounds and no more evaluations per round
+    /// than a polynomial described by `polynomial_info` could require.
+    pub fn is_within_bounds(&self, polynomial_info: &CompositePolynomialInfo) -> bool {
+        let max_round_length = SumcheckStrategy::round_length(
+            polynomial_info.max_multiplicands,
+            self.strategy.variables_per_round(),
+        );
+        self.evaluations.len() <= polynomial_info.num_variables
+            && self
+                .evaluations
+                .iter()
+                .all(|round| round.len() <= max_round_length)
     }
 
     #[tracing::instrument(
@@ -56,36 +116,124 @@ impl<S: Scalar> SumcheckProof<S> {
     )]
     pub fn verify_without_evaluation(
         &self,
-        transcript: &mut Transcript,
+        transcript: &mut impl TranscriptProtocol,
         polynomial_info: CompositePolynomialInfo,
         claimed_sum: &S,
     ) -> Result<Subclaim<S>, ProofError> {
+        if self.evaluations.len() != self.strategy.num_rounds(polynomial_info.num_variables) {
+            return Err(ProofError::VerificationError(
+                "invalid number of evaluations",
+            ));
+        }
+        let mut verifier =
+            SumcheckRoundVerifier::new(transcript, polynomial_info, claimed_sum, self.strategy);
+        for round_evaluation in self.evaluations.iter() {
+            verifier.verify_round(transcript, round_evaluation)?;
+        }
+        verifier.finish()
+    }
+}
+
+/// Verifies a sumcheck proof one round at a time, so that the rounds can be consumed as they
+/// arrive rather than held in memory all at once.
+pub struct SumcheckRoundVerifier<S: Scalar> {
+    polynomial_info: CompositePolynomialInfo,
+    strategy: SumcheckStrategy,
+    evaluation_point: Vec<S>,
+    expected_sum: S,
+}
+
+impl<S: Scalar> SumcheckRoundVerifier<S> {
+    /// Start verifying a sumcheck proof that the polynomial described by `polynomial_info`
+    /// sums to `claimed_sum`, where the proof was created with `strategy`.
+    pub fn new(
+        transcript: &mut impl TranscriptProtocol,
+        polynomial_info: CompositePolynomialInfo,
+        claimed_sum: &S,
+        strategy: SumcheckStrategy,
+    ) -> Self {
+        assert!(polynomial_info.max_multiplicands > 0);
         transcript.append_auto(
             MessageLabel::Sumcheck,
             &(
                 polynomial_info.max_multiplicands,
                 polynomial_info.num_variables,
+                strategy,
             ),
         );
-        if self.evaluations.len() != polynomial_info.num_variables {
+        Self {
+            polynomial_info,
+            strategy,
+            evaluation_point: Vec::with_capacity(polynomial_info.num_variables),
+            expected_sum: *claimed_sum,
+        }
+    }
+
+    /// Verify the evaluations of the next round and draw its challenges.
+    ///
+    /// A round that binds `k` variables sends the evaluations over the grid `{0, ..., d}^k`, where
+    /// `d` is the max multiplicands, with the first variable of the round varying fastest.
+    pub fn verify_round(
+        &mut self,
+        transcript: &mut impl TranscriptProtocol,
+        round_evaluation: &[S],
+    ) -> Result<(), ProofError> {
+        let remaining_variables = self.polynomial_info.num_variables - self.evaluation_point.len();
+        if remaining_variables == 0 {
             return Err(ProofError::VerificationError(
                 "invalid number of evaluations",
             ));
         }
-        let mut evaluation_point = Vec::with_capacity(polynomial_info.num_variables);
-        for round_index in 0..polynomial_info.num_variables {
-            transcript.append_canonical_serialize(
-                MessageLabel::SumcheckRoundEvaluation,
-                &self.evaluations[round_index],
-            );
-            evaluation_point
-                .push(transcript.challenge_scalar_single(MessageLabel::SumcheckChallenge));
+        let round_variables = self.strategy.variables_per_round().min(remaining_variables);
+        transcript
+            .append_canonical_serialize(MessageLabel::SumcheckRoundEvaluation, round_evaluation);
+        let challenges: Vec<S> = (0..round_variables)
+            .map(|_| transcript.challenge_scalar_single(MessageLabel::SumcheckChallenge))
+            .collect();
+        let line_length = self.polynomial_info.max_multiplicands + 1;
+        if round_evaluation.len()
+            != SumcheckStrategy::round_length(line_length - 1, round_variables)
+        {
+            return Err(ProofError::VerificationError(
+                "round evaluation length does not match max multiplicands",
+            ));
         }
-        Subclaim::create(
-            evaluation_point,
-            &self.evaluations,
-            polynomial_info.max_multiplicands,
-            claimed_sum,
-        )
+        let corner_sum: S = (0..1usize << round_variables)
+            .map(|corner| {
+                let index: usize = (0..round_variables)
+                    .filter(|i| (corner >> i) & 1 == 1)
+                    .map(|i| line_length.pow(i as u32))
+                    .sum();
+                round_evaluation[index]
+            })
+            .sum();
+        if self.expected_sum != corner_sum {
+            return Err(ProofError::VerificationError(
+                "round evaluation does not match claimed sum",
+            ));
+        }
+        let mut grid = round_evaluation.to_vec();
+        for &challenge in &challenges {
+            grid = grid
+                .chunks(line_length)
+                .map(|line| interpolate_uni_poly(line, challenge))
+                .collect();
+        }
+        self.expected_sum = grid[0];
+        self.evaluation_point.extend(challenges);
+        Ok(())
+    }
+
+    /// Finish verification once every round has been verified.
+    pub fn finish(self) -> Result<Subclaim<S>, ProofError> {
+        if self.evaluation_point.len() != self.polynomial_info.num_variables {
+            return Err(ProofError::VerificationError(
+                "invalid number of evaluations",
+            ));
+        }
+        Ok(Subclaim {
+            evaluation_point: self.evaluation_point,
+            expected_evaluation: self.expected_sum,
+        })
     }
 }
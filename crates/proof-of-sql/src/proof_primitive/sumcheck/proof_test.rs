@@ -8,7 +8,7 @@ use crate::base::{
  *
  * See third_party/license/arkworks.LICENSE
  */
-use crate::proof_primitive::sumcheck::proof::*;
+use crate::proof_primitive::sumcheck::{proof::*, SumcheckStrategy};
 use ark_std::UniformRand;
 use merlin::Transcript;
 use num_traits::{One, Zero};
@@ -121,6 +121,20 @@ fn random_polynomial(
 }
 
 fn test_polynomial(nv: usize, num_multiplicands_range: (usize, usize), num_products: usize) {
+    test_polynomial_with_strategy(
+        nv,
+        num_multiplicands_range,
+        num_products,
+        SumcheckStrategy::SingleVariable,
+    );
+}
+
+fn test_polynomial_with_strategy(
+    nv: usize,
+    num_multiplicands_range: (usize, usize),
+    num_products: usize,
+    strategy: SumcheckStrategy,
+) -> SumcheckProof<Curve25519Scalar> {
     let mut rng = <ark_std::rand::rngs::StdRng as ark_std::rand::SeedableRng>::from_seed([0u8; 32]);
     let (poly, asserted_sum) =
         random_polynomial(nv, num_multiplicands_range, num_products, &mut rng);
@@ -129,7 +143,14 @@ fn test_polynomial(nv: usize, num_multiplicands_range: (usize, usize), num_produ
     // create a proof
     let mut transcript = Transcript::new(b"sumchecktest");
     let mut evaluation_point = vec![Curve25519Scalar::zero(); poly_info.num_variables];
-    let proof = SumcheckProof::create(&mut transcript, &mut evaluation_point, &poly);
+    let proof = SumcheckProof::create_with_strategy(
+        &mut transcript,
+        &mut evaluation_point,
+        &poly,
+        strategy,
+    );
+    assert_eq!(proof.strategy(), strategy);
+    assert!(proof.is_within_bounds(&poly_info));
 
     // verify proof
     let mut transcript = Transcript::new(b"sumchecktest");
@@ -141,6 +162,7 @@ fn test_polynomial(nv: usize, num_multiplicands_range: (usize, usize), num_produ
         poly.evaluate(&evaluation_point),
         subclaim.expected_evaluation
     );
+    proof
 }
 
 #[test]
@@ -160,3 +182,41 @@ fn test_normal_polynomial() {
 
     test_polynomial(nv, num_multiplicands_range, num_products);
 }
+
+#[test]
+fn we_can_prove_and_verify_when_binding_two_variables_per_round() {
+    for nv in 1..=6 {
+        let proof = test_polynomial_with_strategy(nv, (1, 5), 4, SumcheckStrategy::PairedVariables);
+        assert_eq!(proof.round_evaluations().len(), (nv + 1) / 2);
+    }
+}
+
+#[test]
+fn binding_two_variables_per_round_gives_fewer_rounds_and_larger_proofs() {
+    let single = test_polynomial_with_strategy(7, (4, 9), 5, SumcheckStrategy::SingleVariable);
+    let paired = test_polynomial_with_strategy(7, (4, 9), 5, SumcheckStrategy::PairedVariables);
+    assert_eq!(single.round_evaluations().len(), 7);
+    assert_eq!(paired.round_evaluations().len(), 4);
+    let single_size = postcard::to_allocvec(&single).unwrap().len();
+    let paired_size = postcard::to_allocvec(&paired).unwrap().len();
+    assert!(paired_size > single_size);
+}
+
+#[test]
+fn we_cannot_verify_a_proof_under_a_different_strategy() {
+    let mut rng = <ark_std::rand::rngs::StdRng as ark_std::rand::SeedableRng>::from_seed([0u8; 32]);
+    let (poly, asserted_sum) = random_polynomial(4, (2, 4), 3, &mut rng);
+    let mut transcript = Transcript::new(b"sumchecktest");
+    let mut evaluation_point = vec![Curve25519Scalar::zero(); 4];
+    let mut proof = SumcheckProof::create_with_strategy(
+        &mut transcript,
+        &mut evaluation_point,
+        &poly,
+        SumcheckStrategy::PairedVariables,
+    );
+    proof.strategy = SumcheckStrategy::SingleVariable;
+    let mut transcript = Transcript::new(b"sumchecktest");
+    assert!(proof
+        .verify_without_evaluation(&mut transcript, poly.info(), &asserted_sum)
+        .is_err());
+}
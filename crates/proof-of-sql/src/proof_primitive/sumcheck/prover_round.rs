@@ -92,6 +92,85 @@ pub fn prove_round<S: Scalar>(prover_state: &mut ProverState<S>, r_maybe: &Optio
     result
 }
 
+/// Fixes the variables bound by `previous_challenges`, then computes the evaluations sent in a
+/// round that binds the next `round_variables` variables at once.
+///
+/// The evaluations are over the grid `{0, ..., degree}^round_variables`, where the first variable
+/// of the round varies fastest.
+#[tracing::instrument(level = "debug", skip_all)]
+pub fn prove_multi_variable_round<S: Scalar>(
+    prover_state: &mut ProverState<S>,
+    previous_challenges: &[S],
+    round_variables: usize,
+) -> Vec<S> {
+    for &r in previous_challenges {
+        let num_vars = prover_state.num_vars - prover_state.randomness.len() - 1;
+        prover_state.randomness.push(r);
+        prover_state
+            .flattened_ml_extensions
+            .par_iter_mut()
+            .for_each(|multiplicand| {
+                in_place_fix_variable(multiplicand, r, num_vars);
+            });
+    }
+    prover_state.round += 1;
+
+    let bound = prover_state.randomness.len();
+    if round_variables == 0 || bound + round_variables > prover_state.num_vars {
+        panic!("Prover is not active");
+    }
+
+    let degree = prover_state.max_multiplicands;
+    let corners = 1usize << round_variables;
+    let grid_length = (degree + 1).pow(round_variables as u32);
+    let round_length = 1usize << (prover_state.num_vars - bound - round_variables);
+
+    // This is the same computation as in `prove_round`, except that each row covers the
+    // `corners` entries that agree on every variable that is not bound in this round, and the
+    // products are evaluated over the whole grid rather than a single line.
+    prover_state
+        .list_of_products
+        .par_iter()
+        .map(|(coefficient, multiplicand_indices)| {
+            (0..round_length)
+                .into_par_iter()
+                .map(|b| {
+                    let mut products = vec![*coefficient; grid_length];
+                    for &multiplicand_index in multiplicand_indices {
+                        let table = &prover_state.flattened_ml_extensions[multiplicand_index];
+                        let grid = extend_to_grid(&table[b * corners..(b + 1) * corners], degree);
+                        products
+                            .iter_mut()
+                            .zip(grid)
+                            .for_each(|(product, value)| *product *= value);
+                    }
+                    products
+                })
+                .reduce(|| vec![S::zero(); grid_length], vec_elementwise_add)
+        })
+        .reduce(|| vec![S::zero(); grid_length], vec_elementwise_add)
+}
+
+/// Extends the values of a multilinear polynomial at the corners `{0, 1}^k` to its values on the
+/// grid `{0, ..., degree}^k`. In both, the first variable varies fastest.
+fn extend_to_grid<S: Scalar>(corners: &[S], degree: usize) -> Vec<S> {
+    if corners.len() == 1 {
+        return corners.to_vec();
+    }
+    let (low, high) = corners.split_at(corners.len() / 2);
+    let low = extend_to_grid(low, degree);
+    let high = extend_to_grid(high, degree);
+    let mut grid = Vec::with_capacity(low.len() * (degree + 1));
+    let mut line = low.clone();
+    for _ in 0..=degree {
+        grid.extend_from_slice(&line);
+        for ((value, low), high) in line.iter_mut().zip(&low).zip(&high) {
+            *value += *high - *low;
+        }
+    }
+    grid
+}
+
 /// This is equivalent to
 /// *multiplicand = Vec<S> {
 ///                    ark_impl: multiplicand.ark_impl.fix_variables(&[r_as_field]),
@@ -0,0 +1,46 @@
+use serde::{Deserialize, Serialize};
+
+/// How many variables the sumcheck protocol binds in each round.
+///
+/// Binding more variables per round means fewer rounds, and so fewer challenges for the verifier
+/// to draw, at the cost of larger proofs: a round over `k` variables of a polynomial of degree
+/// `d` sends `(d + 1)^k` evaluations, where `k` single-variable rounds send `k * (d + 1)`.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SumcheckStrategy {
+    /// Bind one variable per round, which gives the smallest proofs.
+    #[default]
+    SingleVariable,
+    /// Bind two variables per round, which halves the number of rounds.
+    PairedVariables,
+}
+
+impl SumcheckStrategy {
+    /// The largest number of variables bound in a single round.
+    pub fn variables_per_round(&self) -> usize {
+        match self {
+            SumcheckStrategy::SingleVariable => 1,
+            SumcheckStrategy::PairedVariables => 2,
+        }
+    }
+
+    /// The number of variables bound in each round of a sumcheck over `num_variables` variables.
+    ///
+    /// Every round binds [`Self::variables_per_round`] variables, except possibly the last.
+    pub fn round_variables(&self, num_variables: usize) -> impl Iterator<Item = usize> {
+        let per_round = self.variables_per_round();
+        (0..num_variables)
+            .step_by(per_round)
+            .map(move |bound| per_round.min(num_variables - bound))
+    }
+
+    /// The number of rounds in a sumcheck over `num_variables` variables.
+    pub fn num_rounds(&self, num_variables: usize) -> usize {
+        num_variables.div_ceil(self.variables_per_round())
+    }
+
+    /// The number of evaluations sent in a round that binds `round_variables` variables of a
+    /// polynomial with `max_multiplicands` multiplicands per product.
+    pub fn round_length(max_multiplicands: usize, round_variables: usize) -> usize {
+        (max_multiplicands + 1).pow(round_variables as u32)
+    }
+}
@@ -1,16 +1,25 @@
-use super::{add_subtract_columns, scale_and_add_subtract_eval, ProvableExpr, ProvableExprPlan};
+#[cfg(feature = "prover")]
+use super::{add_subtract_columns, prover_evaluate_range_check};
+use super::{
+    count_range_check, integer_type_bounds, range_bit_width, scale_and_add_subtract_eval,
+    verifier_evaluate_range_check, ProvableExpr, ProvableExprPlan,
+};
 use crate::{
     base::{
         commitment::Commitment,
-        database::{
-            try_add_subtract_column_types, Column, ColumnRef, ColumnType, CommitmentAccessor,
-            DataAccessor,
-        },
+        database::{try_add_subtract_column_types, ColumnRef, ColumnType, CommitmentAccessor},
         proof::ProofError,
     },
-    sql::proof::{CountBuilder, ProofBuilder, VerificationBuilder},
+    sql::proof::{CountBuilder, VerificationBuilder},
+};
+#[cfg(feature = "prover")]
+use crate::{
+    base::{
+        database::{Column, DataAccessor},
+        MleAllocator,
+    },
+    sql::proof::ProofBuilder,
 };
-use bumpalo::Bump;
 use indexmap::IndexSet;
 use proof_of_sql_parser::intermediate_ast::BinaryOperator;
 use serde::{Deserialize, Serialize};
@@ -21,6 +30,8 @@ pub struct AddSubtractExpr<C: Commitment> {
     lhs: Box<ProvableExprPlan<C>>,
     rhs: Box<ProvableExprPlan<C>>,
     is_subtract: bool,
+    #[serde(default)]
+    checked_arithmetic: bool,
 }
 
 impl<C: Commitment> AddSubtractExpr<C> {
@@ -34,6 +45,24 @@ impl<C: Commitment> AddSubtractExpr<C> {
             lhs,
             rhs,
             is_subtract,
+            checked_arithmetic: false,
+        }
+    }
+
+    /// Prove that the result fits in its integer type rather than letting it exceed the range
+    pub fn with_checked_arithmetic(self) -> Self {
+        Self {
+            checked_arithmetic: true,
+            ..self
+        }
+    }
+
+    /// The bounds that the result is range checked against, if any
+    fn checked_bounds(&self) -> Option<(i128, i128)> {
+        if self.checked_arithmetic {
+            integer_type_bounds(self.data_type())
+        } else {
+            None
         }
     }
 }
@@ -42,6 +71,9 @@ impl<C: Commitment> ProvableExpr<C> for AddSubtractExpr<C> {
     fn count(&self, builder: &mut CountBuilder) -> Result<(), ProofError> {
         self.lhs.count(builder)?;
         self.rhs.count(builder)?;
+        if let Some(bounds) = self.checked_bounds() {
+            count_range_check(builder, Some(range_bit_width(bounds)))?;
+        }
         Ok(())
     }
 
@@ -55,10 +87,11 @@ impl<C: Commitment> ProvableExpr<C> for AddSubtractExpr<C> {
             .expect("Failed to add/subtract column types")
     }
 
+    #[cfg(feature = "prover")]
     fn result_evaluate<'a>(
         &self,
         table_length: usize,
-        alloc: &'a Bump,
+        alloc: &'a dyn MleAllocator,
         accessor: &'a dyn DataAccessor<C::Scalar>,
     ) -> Column<'a, C::Scalar> {
         let lhs_column: Column<'a, C::Scalar> =
@@ -75,6 +108,7 @@ impl<C: Commitment> ProvableExpr<C> for AddSubtractExpr<C> {
         ))
     }
 
+    #[cfg(feature = "prover")]
     #[tracing::instrument(
         name = "proofs.sql.ast.add_subtract_expr.prover_evaluate",
         level = "info",
@@ -83,19 +117,23 @@ impl<C: Commitment> ProvableExpr<C> for AddSubtractExpr<C> {
     fn prover_evaluate<'a>(
         &self,
         builder: &mut ProofBuilder<'a, C::Scalar>,
-        alloc: &'a Bump,
+        alloc: &'a dyn MleAllocator,
         accessor: &'a dyn DataAccessor<C::Scalar>,
     ) -> Column<'a, C::Scalar> {
         let lhs_column: Column<'a, C::Scalar> = self.lhs.prover_evaluate(builder, alloc, accessor);
         let rhs_column: Column<'a, C::Scalar> = self.rhs.prover_evaluate(builder, alloc, accessor);
-        Column::Scalar(add_subtract_columns(
+        let res = add_subtract_columns(
             lhs_column,
             rhs_column,
             self.lhs.data_type().scale().unwrap_or(0),
             self.rhs.data_type().scale().unwrap_or(0),
             alloc,
             self.is_subtract,
-        ))
+        );
+        if let Some(bounds) = self.checked_bounds() {
+            prover_evaluate_range_check(builder, alloc, res, bounds);
+        }
+        Column::Scalar(res)
     }
 
     fn verifier_evaluate(
@@ -109,6 +147,9 @@ impl<C: Commitment> ProvableExpr<C> for AddSubtractExpr<C> {
         let rhs_scale = self.rhs.data_type().scale().unwrap_or(0);
         let res =
             scale_and_add_subtract_eval(lhs_eval, rhs_eval, lhs_scale, rhs_scale, self.is_subtract);
+        if let Some(bounds) = self.checked_bounds() {
+            verifier_evaluate_range_check(builder, res, bounds)?;
+        }
         Ok(res)
     }
 
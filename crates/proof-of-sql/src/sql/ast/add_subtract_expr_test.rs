@@ -324,3 +324,60 @@ fn we_can_compute_the_correct_output_of_an_add_subtract_expr_using_result_evalua
     let expected_res = Column::Scalar(&expected_res_scalar);
     assert_eq!(res, expected_res);
 }
+
+// select c from sxt.t where a + b >= 0, with the sum range checked
+#[test]
+fn we_can_catch_an_overflowing_bigint_sum_with_checked_arithmetic() {
+    let data = owned_table([
+        bigint("a", [i64::MAX - 1, i64::MAX, 1]),
+        bigint("b", [1_i64, i64::MAX - 2, 2]),
+        bigint("c", [1_i64, 2, 3]),
+    ]);
+    let t = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(t, data, 0, ());
+    let sum = || add(column(t, "a", &accessor), column(t, "b", &accessor));
+
+    // Without the check, the sum silently exceeds the range of a bigint.
+    let ast = dense_filter(
+        vec![col_expr_plan(t, "c", &accessor)],
+        tab(t),
+        not(lte(sum(), const_bigint(0))),
+    );
+    let verifiable_res = VerifiableQueryResult::new(&ast, &accessor, &());
+    let res = verifiable_res.verify(&ast, &accessor, &()).unwrap().table;
+    assert_eq!(res, owned_table([bigint("c", [1_i64, 2, 3])]));
+
+    // With the check, the overflow causes verification to fail.
+    let ast = dense_filter(
+        vec![col_expr_plan(t, "c", &accessor)],
+        tab(t),
+        not(lte(sum().with_checked_arithmetic(), const_bigint(0))),
+    );
+    let verifiable_res = VerifiableQueryResult::new(&ast, &accessor, &());
+    assert!(matches!(
+        verifiable_res.verify(&ast, &accessor, &()),
+        Err(QueryError::ProofError(_))
+    ));
+}
+
+// select a + b as res from sxt.t, with the sum range checked
+#[test]
+fn we_can_prove_a_checked_bigint_sum_that_does_not_overflow() {
+    let data = owned_table([
+        bigint("a", [i64::MAX - 1, i64::MIN, 1]),
+        bigint("b", [1_i64, 0, -2]),
+    ]);
+    let t = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(t, data, 0, ());
+    let ast = projection(
+        vec![aliased_plan(
+            add(column(t, "a", &accessor), column(t, "b", &accessor)).with_checked_arithmetic(),
+            "res",
+        )],
+        tab(t),
+    );
+    let verifiable_res = VerifiableQueryResult::new(&ast, &accessor, &());
+    exercise_verification(&verifiable_res, &ast, &accessor, t);
+    let res = verifiable_res.verify(&ast, &accessor, &()).unwrap().table;
+    assert_eq!(res, owned_table([bigint("res", [i64::MAX, i64::MIN, -1])]));
+}
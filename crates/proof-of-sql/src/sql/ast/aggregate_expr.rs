@@ -2,12 +2,19 @@ use super::{ProvableExpr, ProvableExprPlan};
 use crate::{
     base::{
         commitment::Commitment,
-        database::{Column, ColumnRef, ColumnType, CommitmentAccessor, DataAccessor},
+        database::{ColumnRef, ColumnType, CommitmentAccessor},
         proof::ProofError,
     },
-    sql::proof::{CountBuilder, ProofBuilder, VerificationBuilder},
+    sql::proof::{CountBuilder, VerificationBuilder},
+};
+#[cfg(feature = "prover")]
+use crate::{
+    base::{
+        database::{Column, DataAccessor},
+        MleAllocator,
+    },
+    sql::proof::ProofBuilder,
 };
-use bumpalo::Bump;
 use indexmap::IndexSet;
 use proof_of_sql_parser::intermediate_ast::AggregationOperator;
 use serde::{Deserialize, Serialize};
@@ -41,21 +48,23 @@ impl<C: Commitment> ProvableExpr<C> for AggregateExpr<C> {
         }
     }
 
+    #[cfg(feature = "prover")]
     #[tracing::instrument(name = "AggregateExpr::result_evaluate", level = "debug", skip_all)]
     fn result_evaluate<'a>(
         &self,
         table_length: usize,
-        alloc: &'a Bump,
+        alloc: &'a dyn MleAllocator,
         accessor: &'a dyn DataAccessor<C::Scalar>,
     ) -> Column<'a, C::Scalar> {
         self.expr.result_evaluate(table_length, alloc, accessor)
     }
 
+    #[cfg(feature = "prover")]
     #[tracing::instrument(name = "AggregateExpr::prover_evaluate", level = "debug", skip_all)]
     fn prover_evaluate<'a>(
         &self,
         builder: &mut ProofBuilder<'a, C::Scalar>,
-        alloc: &'a Bump,
+        alloc: &'a dyn MleAllocator,
         accessor: &'a dyn DataAccessor<C::Scalar>,
     ) -> Column<'a, C::Scalar> {
         self.expr.prover_evaluate(builder, alloc, accessor)
@@ -1,14 +1,22 @@
-use super::{ProvableExpr, ProvableExprPlan};
+use super::{ProvableBooleanExpr, ProvableExpr, ProvableExprPlan};
 use crate::{
     base::{
         commitment::Commitment,
-        database::{Column, ColumnRef, ColumnType, CommitmentAccessor, DataAccessor},
+        database::{ColumnRef, ColumnType, CommitmentAccessor},
         proof::ProofError,
     },
-    sql::proof::{CountBuilder, ProofBuilder, SumcheckSubpolynomialType, VerificationBuilder},
+    sql::proof::{CountBuilder, VerificationBuilder},
+};
+#[cfg(feature = "prover")]
+use crate::{
+    base::{
+        database::{Column, DataAccessor},
+        MleAllocator,
+    },
+    sql::proof::{ProofBuilder, SumcheckSubpolynomialType},
 };
-use bumpalo::Bump;
 use indexmap::IndexSet;
+#[cfg(feature = "prover")]
 use num_traits::One;
 use serde::{Deserialize, Serialize};
 
@@ -40,33 +48,68 @@ impl<C: Commitment> ProvableExpr<C> for AndExpr<C> {
         ColumnType::Boolean
     }
 
+    #[cfg(feature = "prover")]
     #[tracing::instrument(name = "AndExpr::result_evaluate", level = "debug", skip_all)]
     fn result_evaluate<'a>(
         &self,
         table_length: usize,
-        alloc: &'a Bump,
+        alloc: &'a dyn MleAllocator,
         accessor: &'a dyn DataAccessor<C::Scalar>,
     ) -> Column<'a, C::Scalar> {
-        let lhs_column: Column<'a, C::Scalar> =
-            self.lhs.result_evaluate(table_length, alloc, accessor);
-        let rhs_column: Column<'a, C::Scalar> =
-            self.rhs.result_evaluate(table_length, alloc, accessor);
-        let lhs = lhs_column.as_boolean().expect("lhs is not boolean");
-        let rhs = rhs_column.as_boolean().expect("rhs is not boolean");
-        Column::Boolean(alloc.alloc_slice_fill_with(table_length, |i| lhs[i] && rhs[i]))
+        Column::Boolean(self.result_evaluate_selection(table_length, alloc, accessor))
     }
 
+    #[cfg(feature = "prover")]
     #[tracing::instrument(name = "AndExpr::prover_evaluate", level = "debug", skip_all)]
     fn prover_evaluate<'a>(
         &self,
         builder: &mut ProofBuilder<'a, C::Scalar>,
-        alloc: &'a Bump,
+        alloc: &'a dyn MleAllocator,
         accessor: &'a dyn DataAccessor<C::Scalar>,
     ) -> Column<'a, C::Scalar> {
-        let lhs_column: Column<'a, C::Scalar> = self.lhs.prover_evaluate(builder, alloc, accessor);
-        let rhs_column: Column<'a, C::Scalar> = self.rhs.prover_evaluate(builder, alloc, accessor);
-        let lhs = lhs_column.as_boolean().expect("lhs is not boolean");
-        let rhs = rhs_column.as_boolean().expect("rhs is not boolean");
+        Column::Boolean(self.prover_evaluate_selection(builder, alloc, accessor))
+    }
+
+    fn verifier_evaluate(
+        &self,
+        builder: &mut VerificationBuilder<C>,
+        accessor: &dyn CommitmentAccessor<C>,
+    ) -> Result<C::Scalar, ProofError> {
+        self.verifier_evaluate_selection(builder, accessor)
+    }
+
+    fn get_column_references(&self, columns: &mut IndexSet<ColumnRef>) {
+        self.lhs.get_column_references(columns);
+        self.rhs.get_column_references(columns);
+    }
+}
+
+impl<C: Commitment> ProvableBooleanExpr<C> for AndExpr<C> {
+    #[cfg(feature = "prover")]
+    fn result_evaluate_selection<'a>(
+        &self,
+        table_length: usize,
+        alloc: &'a dyn MleAllocator,
+        accessor: &'a dyn DataAccessor<C::Scalar>,
+    ) -> &'a [bool] {
+        let lhs = self
+            .lhs
+            .result_evaluate_selection(table_length, alloc, accessor);
+        let rhs = self
+            .rhs
+            .result_evaluate_selection(table_length, alloc, accessor);
+        alloc.alloc_slice_fill_with(table_length, |i| lhs[i] && rhs[i])
+    }
+
+    #[cfg(feature = "prover")]
+    fn prover_evaluate_selection<'a>(
+        &self,
+        builder: &mut ProofBuilder<'a, C::Scalar>,
+        alloc: &'a dyn MleAllocator,
+        accessor: &'a dyn DataAccessor<C::Scalar>,
+    ) -> &'a [bool] {
+        let lhs = self.lhs.prover_evaluate_selection(builder, alloc, accessor);
+        let rhs = self.rhs.prover_evaluate_selection(builder, alloc, accessor);
         let n = lhs.len();
         assert_eq!(n, rhs.len());
 
@@ -82,16 +125,16 @@ impl<C: Commitment> ProvableExpr<C> for AndExpr<C> {
                 (-C::Scalar::one(), vec![Box::new(lhs), Box::new(rhs)]),
             ],
         );
-        Column::Boolean(lhs_and_rhs)
+        lhs_and_rhs
     }
 
-    fn verifier_evaluate(
+    fn verifier_evaluate_selection(
         &self,
         builder: &mut VerificationBuilder<C>,
         accessor: &dyn CommitmentAccessor<C>,
     ) -> Result<C::Scalar, ProofError> {
-        let lhs = self.lhs.verifier_evaluate(builder, accessor)?;
-        let rhs = self.rhs.verifier_evaluate(builder, accessor)?;
+        let lhs = self.lhs.verifier_evaluate_selection(builder, accessor)?;
+        let rhs = self.rhs.verifier_evaluate_selection(builder, accessor)?;
 
         // lhs_and_rhs
         let lhs_and_rhs = builder.consume_intermediate_mle();
@@ -103,9 +146,4 @@ impl<C: Commitment> ProvableExpr<C> for AndExpr<C> {
         // selection
         Ok(lhs_and_rhs)
     }
-
-    fn get_column_references(&self, columns: &mut IndexSet<ColumnRef>) {
-        self.lhs.get_column_references(columns);
-        self.rhs.get_column_references(columns);
-    }
 }
@@ -4,8 +4,8 @@ use crate::{
         database::{owned_table_utility::*, Column, OwnedTableTestAccessor},
     },
     sql::{
-        ast::{test_utility::*, ProvableExpr, ProvableExprPlan},
-        proof::{exercise_verification, VerifiableQueryResult},
+        ast::{test_utility::*, ProvableBooleanExpr, ProvableExpr, ProvableExprPlan},
+        proof::{exercise_verification, ProofBuilder, VerifiableQueryResult},
     },
 };
 use bumpalo::Bump;
@@ -163,3 +163,66 @@ fn we_can_compute_the_correct_output_of_an_and_expr_using_result_evaluate() {
     let expected_res = Column::Boolean(&[false, true, false, false]);
     assert_eq!(res, expected_res);
 }
+
+#[test]
+fn we_can_compose_an_and_expr_from_the_selections_of_its_children() {
+    let data = owned_table([
+        bigint("a", [1, 2, 3, 4, 2]),
+        bigint("b", [0, 1, 0, 1, 1]),
+        varchar("d", ["ab", "t", "efg", "g", "t"]),
+    ]);
+    let t = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(t, data, 0, ());
+    let lhs: ProvableExprPlan<RistrettoPoint> = equal(column(t, "b", &accessor), const_bigint(1));
+    let rhs: ProvableExprPlan<RistrettoPoint> =
+        equal(column(t, "d", &accessor), const_varchar("t"));
+    let and_expr = and(lhs.clone(), rhs.clone());
+    let alloc = Bump::new();
+    let lhs_selection = lhs.result_evaluate_selection(5, &alloc, &accessor);
+    let rhs_selection = rhs.result_evaluate_selection(5, &alloc, &accessor);
+    let expected: Vec<_> = lhs_selection
+        .iter()
+        .zip(rhs_selection)
+        .map(|(l, r)| l & r)
+        .collect();
+    assert_eq!(
+        and_expr.result_evaluate_selection(5, &alloc, &accessor),
+        &expected[..]
+    );
+    assert_eq!(expected, [false, true, false, false, true]);
+}
+
+#[test]
+fn a_plan_proves_its_selection_through_the_specialised_and_expr() {
+    let data = owned_table([
+        bigint("b", [0, 1, 0, 1, 1]),
+        varchar("d", ["ab", "t", "efg", "g", "t"]),
+    ]);
+    let t = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(t, data, 0, ());
+    let plan: ProvableExprPlan<RistrettoPoint> = and(
+        equal(column(t, "b", &accessor), const_bigint(1)),
+        equal(column(t, "d", &accessor), const_varchar("t")),
+    );
+    let and_expr = match &plan {
+        ProvableExprPlan::And(and_expr) => and_expr,
+        _ => unreachable!(),
+    };
+    let alloc = Bump::new();
+
+    let mut plan_builder = ProofBuilder::new(5, 3, Vec::new());
+    let plan_selection = plan.prover_evaluate_selection(&mut plan_builder, &alloc, &accessor);
+    let mut and_builder = ProofBuilder::new(5, 3, Vec::new());
+    let and_selection = and_expr.prover_evaluate_selection(&mut and_builder, &alloc, &accessor);
+
+    assert_eq!(plan_selection, [false, true, false, false, true]);
+    assert_eq!(plan_selection, and_selection);
+    assert_eq!(
+        plan_builder.num_intermediate_mles(),
+        and_builder.num_intermediate_mles()
+    );
+    assert_eq!(
+        plan_builder.num_sumcheck_subpolynomials(),
+        and_builder.num_sumcheck_subpolynomials()
+    );
+}
@@ -0,0 +1,289 @@
+#[cfg(feature = "prover")]
+use super::{div_expr::prove_remainder, semi_join_expr::integer_values};
+use super::{
+    div_expr::{count_remainder, verifier_evaluate_remainder},
+    ProvableBooleanExpr, ProvableExpr, ProvableExprPlan, TableExpr,
+};
+use crate::{
+    base::{
+        commitment::Commitment,
+        database::{
+            ColumnField, ColumnRef, ColumnType, CommitmentAccessor, MetadataAccessor, OwnedColumn,
+            OwnedTable,
+        },
+        proof::ProofError,
+    },
+    sql::{
+        parse::{ConversionError, ConversionResult},
+        proof::{CountBuilder, ProofExecutionPlan, VerificationBuilder},
+    },
+};
+#[cfg(feature = "prover")]
+use crate::{
+    base::{database::DataAccessor, MleAllocator},
+    sql::proof::{Indexes, ProofBuilder, ProverEvaluate, ResultBuilder, SumcheckSubpolynomialType},
+};
+use indexmap::IndexSet;
+use num_traits::{One, Zero};
+use proof_of_sql_parser::Identifier;
+use serde::{Deserialize, Serialize};
+
+/// Provable expressions for queries of the form
+/// ```ignore
+///     SELECT AVG(<expr>) AS <alias> FROM <table> WHERE <where_clause>
+/// ```
+///
+/// `expr` must be an integer expression. The result has a single `BigInt` row holding the
+/// integer average, or no rows if the where clause selects nothing.
+///
+/// The average of the selected values is the Euclidean quotient of their sum `S` by their count
+/// `N`, so it rounds towards negative infinity, e.g. the average of `-1` and `-2` is `-2`. As with
+/// [`DivExpr`](super::DivExpr), the prover commits to `N` and the remainder `r` in the first row
+/// of two columns, and shows that `S = q * N + r` with `0 <= r < N`, where `q` is the result,
+/// using the same remainder argument.
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct AvgExpr<C: Commitment> {
+    pub(super) expr: ProvableExprPlan<C>,
+    pub(super) alias: Identifier,
+    pub(super) table: TableExpr,
+    pub(super) where_clause: ProvableExprPlan<C>,
+}
+
+impl<C: Commitment> AvgExpr<C> {
+    /// Creates a new average expression.
+    ///
+    /// `expr` must be a `SmallInt`, `Int` or `BigInt` expression.
+    pub fn try_new(
+        expr: ProvableExprPlan<C>,
+        alias: Identifier,
+        table: TableExpr,
+        where_clause: ProvableExprPlan<C>,
+    ) -> ConversionResult<Self> {
+        match expr.data_type() {
+            ColumnType::SmallInt | ColumnType::Int | ColumnType::BigInt => Ok(Self {
+                expr,
+                alias,
+                table,
+                where_clause,
+            }),
+            data_type => Err(ConversionError::InvalidDataType {
+                expected: ColumnType::BigInt,
+                actual: data_type,
+            }),
+        }
+    }
+}
+
+impl<C: Commitment> ProofExecutionPlan<C> for AvgExpr<C> {
+    fn count(
+        &self,
+        builder: &mut CountBuilder,
+        _accessor: &dyn MetadataAccessor,
+    ) -> Result<(), ProofError> {
+        self.where_clause.count(builder)?;
+        self.expr.count(builder)?;
+        builder.count_result_columns(1);
+        builder.count_intermediate_mles(2);
+        builder.count_subpolynomials(5);
+        builder.count_degree(3);
+        count_remainder(builder)?;
+        Ok(())
+    }
+
+    fn get_length(&self, accessor: &dyn MetadataAccessor) -> usize {
+        accessor.get_length(self.table.table_ref)
+    }
+
+    fn get_offset(&self, accessor: &dyn MetadataAccessor) -> usize {
+        accessor.get_offset(self.table.table_ref)
+    }
+
+    fn verifier_evaluate(
+        &self,
+        builder: &mut VerificationBuilder<C>,
+        accessor: &dyn CommitmentAccessor<C>,
+        result: Option<&OwnedTable<C::Scalar>>,
+    ) -> Result<(), ProofError> {
+        let rand_eval = builder.mle_evaluations.random_evaluation;
+        let first_row_eval = builder.mle_evaluations.truncated_one_evaluation(1);
+
+        // 1. selection
+        let s = self
+            .where_clause
+            .verifier_evaluate_selection(builder, accessor)?;
+        // 2. column
+        let a = self.expr.verifier_evaluate(builder, accessor)?;
+        // 3. indexes
+        builder
+            .mle_evaluations
+            .result_indexes_evaluation
+            .ok_or(ProofError::VerificationError("invalid indexes"))?;
+        // 4. result
+        // The average is read directly from the result table below, so its MLE isn't needed.
+        let _ = builder.consume_result_mle();
+        let average: Option<C::Scalar> = match result {
+            Some(table) => match table.inner_table().get(&self.alias) {
+                Some(OwnedColumn::BigInt(values)) if values.len() <= 1 => {
+                    values.first().map(|&value| value.into())
+                }
+                _ => Err(ProofError::VerificationError(
+                    "Result of avg must have at most one bigint row.",
+                ))?,
+            },
+            None => Err(ProofError::VerificationError(
+                "AvgExpr is only supported at the top level of a query plan.",
+            ))?,
+        };
+        let has_average = C::Scalar::from(average.is_some());
+        let average = average.unwrap_or_else(Zero::zero);
+
+        // 5. count and remainder
+        let n = builder.consume_intermediate_mle();
+        let r = builder.consume_intermediate_mle();
+
+        // n - n * chi_0 = 0
+        builder.produce_sumcheck_subpolynomial_evaluation(&(rand_eval * (n - n * first_row_eval)));
+        // r - r * chi_0 = 0
+        builder.produce_sumcheck_subpolynomial_evaluation(&(rand_eval * (r - r * first_row_eval)));
+        // sum s - n = 0
+        builder.produce_sumcheck_subpolynomial_evaluation(&(s - n));
+        // sum has_average * s * a - average * n - has_average * r = 0
+        builder.produce_sumcheck_subpolynomial_evaluation(
+            &(has_average * s * a - average * n - has_average * r),
+        );
+        // (1 - has_average) * n = 0
+        builder.produce_sumcheck_subpolynomial_evaluation(
+            &(rand_eval * (C::Scalar::one() - has_average) * n),
+        );
+
+        // 0 <= r <= has_average * (n - chi_0)
+        verifier_evaluate_remainder(builder, r, has_average * (n - first_row_eval))?;
+        Ok(())
+    }
+
+    fn get_column_result_fields(&self) -> Vec<ColumnField> {
+        vec![ColumnField::new(self.alias, ColumnType::BigInt)]
+    }
+
+    fn get_column_references(&self) -> IndexSet<ColumnRef> {
+        let mut columns = IndexSet::new();
+        self.expr.get_column_references(&mut columns);
+        self.where_clause.get_column_references(&mut columns);
+        columns
+    }
+}
+
+#[cfg(feature = "prover")]
+impl<C: Commitment> ProverEvaluate<C::Scalar> for AvgExpr<C> {
+    #[tracing::instrument(name = "AvgExpr::result_evaluate", level = "debug", skip_all)]
+    fn result_evaluate<'a>(
+        &self,
+        builder: &mut ResultBuilder<'a>,
+        alloc: &'a dyn MleAllocator,
+        accessor: &'a dyn DataAccessor<C::Scalar>,
+    ) {
+        let selection =
+            self.where_clause
+                .result_evaluate_selection(builder.table_length(), alloc, accessor);
+        let column = self
+            .expr
+            .result_evaluate(builder.table_length(), alloc, accessor);
+        let average = compute_average(&integer_values(&column), selection);
+        let result = alloc.alloc_slice_fill_iter(average.map(|(average, _, _)| average));
+        builder.set_result_indexes(Indexes::Dense(0..(result.len() as u64)));
+        builder.produce_result_column(result as &[_]);
+    }
+
+    #[tracing::instrument(name = "AvgExpr::prover_evaluate", level = "debug", skip_all)]
+    fn prover_evaluate<'a>(
+        &self,
+        builder: &mut ProofBuilder<'a, C::Scalar>,
+        alloc: &'a dyn MleAllocator,
+        accessor: &'a dyn DataAccessor<C::Scalar>,
+    ) {
+        let table_length = builder.table_length();
+        let s = self
+            .where_clause
+            .prover_evaluate_selection(builder, alloc, accessor);
+        let a_column = self.expr.prover_evaluate(builder, alloc, accessor);
+        let (has_average, average, count, remainder) =
+            match compute_average(&integer_values(&a_column), s) {
+                Some((average, count, remainder)) => (true, average, count, remainder),
+                None => (false, 0, 0, 0),
+            };
+        let one = C::Scalar::one();
+        let has_average = C::Scalar::from(has_average);
+        let average = C::Scalar::from(average);
+
+        // count and remainder
+        let n = alloc.alloc_slice_fill_with(table_length, |i| if i == 0 { count } else { 0 });
+        let r = alloc.alloc_slice_fill_with(table_length, |i| if i == 0 { remainder } else { 0 });
+        let (n, r) = (&*n, &*r);
+        builder.produce_intermediate_mle(n);
+        builder.produce_intermediate_mle(r);
+        let chi_first_row = alloc.alloc_slice_fill_copy(1, true) as &[_];
+
+        // n - n * chi_0 = 0
+        builder.produce_sumcheck_subpolynomial(
+            SumcheckSubpolynomialType::Identity,
+            vec![
+                (one, vec![Box::new(n)]),
+                (-one, vec![Box::new(n), Box::new(chi_first_row)]),
+            ],
+        );
+        // r - r * chi_0 = 0
+        builder.produce_sumcheck_subpolynomial(
+            SumcheckSubpolynomialType::Identity,
+            vec![
+                (one, vec![Box::new(r)]),
+                (-one, vec![Box::new(r), Box::new(chi_first_row)]),
+            ],
+        );
+        // sum s - n = 0
+        builder.produce_sumcheck_subpolynomial(
+            SumcheckSubpolynomialType::ZeroSum,
+            vec![(one, vec![Box::new(s)]), (-one, vec![Box::new(n)])],
+        );
+        // sum has_average * s * a - average * n - has_average * r = 0
+        builder.produce_sumcheck_subpolynomial(
+            SumcheckSubpolynomialType::ZeroSum,
+            vec![
+                (has_average, vec![Box::new(s), Box::new(a_column)]),
+                (-average, vec![Box::new(n)]),
+                (-has_average, vec![Box::new(r)]),
+            ],
+        );
+        // (1 - has_average) * n = 0
+        builder.produce_sumcheck_subpolynomial(
+            SumcheckSubpolynomialType::Identity,
+            vec![(one - has_average, vec![Box::new(n)])],
+        );
+
+        // 0 <= r <= has_average * (n - chi_0)
+        let r_scalars = alloc.alloc_slice_fill_with(table_length, |i| C::Scalar::from(r[i]));
+        let max_remainder = alloc.alloc_slice_fill_with(table_length, |i| {
+            has_average * (C::Scalar::from(n[i]) - C::Scalar::from(i == 0))
+        });
+        prove_remainder(builder, alloc, r_scalars, max_remainder);
+    }
+}
+
+/// Returns the average of the selected values, rounded towards negative infinity, along with
+/// their count and the remainder of their sum, or `None` if no values are selected.
+#[cfg(feature = "prover")]
+fn compute_average(values: &[i64], selection: &[bool]) -> Option<(i64, i64, i64)> {
+    let (sum, count) = values
+        .iter()
+        .zip(selection)
+        .filter(|(_, s)| **s)
+        .fold((0i128, 0i128), |(sum, count), (&value, _)| {
+            (sum + i128::from(value), count + 1)
+        });
+    (count != 0).then(|| {
+        (
+            sum.div_euclid(count) as i64,
+            count as i64,
+            sum.rem_euclid(count) as i64,
+        )
+    })
+}
@@ -0,0 +1,161 @@
+use super::{test_utility::*, AvgExpr};
+use crate::{
+    base::{
+        commitment::InnerProductProof,
+        database::{owned_table_utility::*, ColumnType, OwnedTableTestAccessor, TestAccessor},
+    },
+    sql::{
+        parse::ConversionError,
+        proof::{exercise_verification, VerifiableQueryResult},
+    },
+};
+use curve25519_dalek::ristretto::RistrettoPoint;
+use rand::{
+    distributions::{Distribution, Uniform},
+    rngs::StdRng,
+};
+use rand_core::SeedableRng;
+
+/// select avg(a) as m from sxt.t where b = 99
+#[test]
+fn we_can_prove_a_simple_avg_query() {
+    let data = owned_table([
+        bigint("a", [5, -2, 5, -7, 1, 4]),
+        bigint("b", [99, 99, 99, 0, 99, 99]),
+    ]);
+    let t = "sxt.t".parse().unwrap();
+    let mut accessor = OwnedTableTestAccessor::<InnerProductProof>::new_empty_with_setup(());
+    accessor.add_table(t, data, 0);
+    let expr = avg(
+        column(t, "a", &accessor),
+        "m",
+        tab(t),
+        equal(column(t, "b", &accessor), const_bigint(99)),
+    );
+    let res = VerifiableQueryResult::new(&expr, &accessor, &());
+    exercise_verification(&res, &expr, &accessor, t);
+    let res = res.verify(&expr, &accessor, &()).unwrap().table;
+    // 13 / 5 rounds down to 2
+    assert_eq!(res, owned_table([bigint("m", [2])]));
+}
+
+/// select avg(a) as m from sxt.t where true
+#[test]
+fn the_avg_of_negative_values_rounds_towards_negative_infinity() {
+    let data = owned_table([int("a", [-1, -2])]);
+    let t = "sxt.t".parse().unwrap();
+    let mut accessor = OwnedTableTestAccessor::<InnerProductProof>::new_empty_with_setup(());
+    accessor.add_table(t, data, 0);
+    let expr = avg(column(t, "a", &accessor), "m", tab(t), const_bool(true));
+    let res = VerifiableQueryResult::new(&expr, &accessor, &());
+    exercise_verification(&res, &expr, &accessor, t);
+    let res = res.verify(&expr, &accessor, &()).unwrap().table;
+    assert_eq!(res, owned_table([bigint("m", [-2])]));
+}
+
+/// select avg(a) as m from sxt.t where true
+#[test]
+fn the_avg_of_extreme_values_does_not_overflow() {
+    let data = owned_table([bigint("a", [i64::MAX, i64::MAX, i64::MIN, i64::MAX])]);
+    let t = "sxt.t".parse().unwrap();
+    let mut accessor = OwnedTableTestAccessor::<InnerProductProof>::new_empty_with_setup(());
+    accessor.add_table(t, data, 0);
+    let expr = avg(column(t, "a", &accessor), "m", tab(t), const_bool(true));
+    let res = VerifiableQueryResult::new(&expr, &accessor, &());
+    exercise_verification(&res, &expr, &accessor, t);
+    let res = res.verify(&expr, &accessor, &()).unwrap().table;
+    let expected = (3 * i128::from(i64::MAX) + i128::from(i64::MIN)).div_euclid(4) as i64;
+    assert_eq!(res, owned_table([bigint("m", [expected])]));
+}
+
+/// select avg(a) as m from sxt.t where b = 1
+#[test]
+fn the_avg_of_an_empty_selection_has_no_rows() {
+    let data = owned_table([smallint("a", [1_i16, 2, 2]), bigint("b", [0, 0, 0])]);
+    let t = "sxt.t".parse().unwrap();
+    let mut accessor = OwnedTableTestAccessor::<InnerProductProof>::new_empty_with_setup(());
+    accessor.add_table(t, data, 0);
+    let expr = avg(
+        column(t, "a", &accessor),
+        "m",
+        tab(t),
+        equal(column(t, "b", &accessor), const_bigint(1)),
+    );
+    let res = VerifiableQueryResult::new(&expr, &accessor, &());
+    exercise_verification(&res, &expr, &accessor, t);
+    let res = res.verify(&expr, &accessor, &()).unwrap().table;
+    assert_eq!(res, owned_table([bigint("m", [0; 0])]));
+}
+
+#[test]
+fn we_cannot_average_a_non_integer_column() {
+    let data = owned_table([varchar("a", ["x", "y"])]);
+    let t = "sxt.t".parse().unwrap();
+    let mut accessor = OwnedTableTestAccessor::<InnerProductProof>::new_empty_with_setup(());
+    accessor.add_table(t, data, 0);
+    let res = AvgExpr::<RistrettoPoint>::try_new(
+        column(t, "a", &accessor),
+        "m".parse().unwrap(),
+        tab(t),
+        const_bool(true),
+    );
+    assert!(matches!(
+        res,
+        Err(ConversionError::InvalidDataType {
+            actual: ColumnType::VarChar,
+            ..
+        })
+    ));
+}
+
+fn test_random_tables_with_given_offset(offset: usize) {
+    let dist = Uniform::new(-30, 40);
+    let mut rng = StdRng::from_seed([0u8; 32]);
+    for _ in 0..20 {
+        let n = Uniform::new(1, 21).sample(&mut rng);
+        let a: Vec<i64> = dist.sample_iter(&mut rng).take(n).collect();
+        let b: Vec<i64> = dist.sample_iter(&mut rng).take(n).collect();
+        let filter_val = dist.sample(&mut rng);
+
+        let t = "sxt.t".parse().unwrap();
+        let mut accessor = OwnedTableTestAccessor::<InnerProductProof>::new_empty_with_setup(());
+        accessor.add_table(
+            t,
+            owned_table([bigint("a", a.clone()), bigint("b", b.clone())]),
+            offset,
+        );
+        let expr = avg(
+            column(t, "a", &accessor),
+            "m",
+            tab(t),
+            lte(column(t, "b", &accessor), const_bigint(filter_val)),
+        );
+        let res = VerifiableQueryResult::new(&expr, &accessor, &());
+        exercise_verification(&res, &expr, &accessor, t);
+        let res = res.verify(&expr, &accessor, &()).unwrap().table;
+
+        let selected: Vec<i64> = a
+            .iter()
+            .zip(&b)
+            .filter(|(_, b)| **b <= filter_val)
+            .map(|(a, _)| *a)
+            .collect();
+        let expected: Vec<i64> = if selected.is_empty() {
+            vec![]
+        } else {
+            let sum: i64 = selected.iter().sum();
+            vec![sum.div_euclid(selected.len() as i64)]
+        };
+        assert_eq!(res, owned_table([bigint("m", expected)]));
+    }
+}
+
+#[test]
+fn we_can_query_random_tables_using_a_zero_offset() {
+    test_random_tables_with_given_offset(0);
+}
+
+#[test]
+fn we_can_query_random_tables_using_a_non_zero_offset() {
+    test_random_tables_with_given_offset(121);
+}
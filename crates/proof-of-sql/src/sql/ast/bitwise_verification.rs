@@ -18,6 +18,20 @@ pub fn is_within_acceptable_range(dist: &BitDistribution) -> bool {
     dist.most_significant_abs_bit() <= 128
 }
 
+/// Check that every value described by the bit distribution has an absolute value of less than
+/// `2^bit_width`, i.e. that no bit of the absolute values at position `bit_width` or above is set
+/// or varies.
+pub fn is_within_bit_width(dist: &BitDistribution, bit_width: u8) -> bool {
+    (0..4).all(|i| {
+        let mut bits = dist.or_all[i] | dist.vary_mask[i];
+        if i == 3 {
+            bits &= !(1 << 63);
+        }
+        let allowed = usize::from(bit_width).saturating_sub(64 * i);
+        allowed >= 64 || bits >> allowed == 0
+    })
+}
+
 /// Given a bit distribution for a column of data with a constant sign, the evaluation of a column
 /// of ones, the constant column's evaluation, and the evaluation of varying absolute bits, verify
 /// that the bit distribution is correct.
@@ -1,5 +1,5 @@
 use super::{
-    is_within_acceptable_range, verify_constant_abs_decomposition,
+    is_within_acceptable_range, is_within_bit_width, verify_constant_abs_decomposition,
     verify_constant_sign_decomposition,
 };
 use crate::base::{
@@ -43,6 +43,30 @@ fn we_reject_distributions_that_are_outside_of_maximum_range() {
     assert!(!is_within_acceptable_range(&dist));
 }
 
+#[test]
+fn we_can_check_that_a_distribution_fits_in_a_bit_width() {
+    let data: Vec<Curve25519Scalar> = vec![
+        Curve25519Scalar::from(-255),
+        Curve25519Scalar::from(0),
+        Curve25519Scalar::from(128),
+    ];
+    let dist = BitDistribution::new::<Curve25519Scalar, _>(&data);
+    assert!(is_within_bit_width(&dist, 8));
+    assert!(is_within_bit_width(&dist, 200));
+    assert!(!is_within_bit_width(&dist, 7));
+
+    let data: Vec<Curve25519Scalar> = vec![Curve25519Scalar::from(256)];
+    let dist = BitDistribution::new::<Curve25519Scalar, _>(&data);
+    assert!(!is_within_bit_width(&dist, 8));
+    assert!(is_within_bit_width(&dist, 9));
+
+    let data: Vec<Curve25519Scalar> =
+        vec![Curve25519Scalar::from(u128::MAX) + Curve25519Scalar::from(1)];
+    let dist = BitDistribution::new::<Curve25519Scalar, _>(&data);
+    assert!(!is_within_bit_width(&dist, 128));
+    assert!(is_within_bit_width(&dist, 129));
+}
+
 #[test]
 fn we_can_verify_the_decomposition_of_a_constant_column() {
     let data: Vec<Curve25519Scalar> =
@@ -0,0 +1,231 @@
+use super::{ProvableBooleanExpr, ProvableExpr, ProvableExprPlan};
+use crate::{
+    base::{
+        commitment::Commitment,
+        database::{ColumnRef, ColumnType, CommitmentAccessor},
+        proof::ProofError,
+        scalar::Scalar,
+    },
+    sql::{
+        parse::{ConversionError, ConversionResult},
+        proof::{CountBuilder, VerificationBuilder},
+    },
+};
+#[cfg(feature = "prover")]
+use crate::{
+    base::{
+        database::{Column, DataAccessor},
+        MleAllocator,
+    },
+    sql::proof::{ProofBuilder, SumcheckSubpolynomialType},
+};
+use indexmap::IndexSet;
+#[cfg(feature = "prover")]
+use num_traits::One;
+use serde::{Deserialize, Serialize};
+
+/// Provable `CASE WHEN <predicate> THEN <then_expr> ELSE <else_expr> END` expression
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(try_from = "UncheckedCaseExpr<C>")]
+pub struct CaseExpr<C: Commitment> {
+    predicate: Box<ProvableExprPlan<C>>,
+    then_expr: Box<ProvableExprPlan<C>>,
+    else_expr: Box<ProvableExprPlan<C>>,
+}
+
+/// The serialized form of a [`CaseExpr`], which is only accepted if its predicate is boolean and
+/// its branches have the same type.
+#[derive(Deserialize)]
+struct UncheckedCaseExpr<C: Commitment> {
+    predicate: Box<ProvableExprPlan<C>>,
+    then_expr: Box<ProvableExprPlan<C>>,
+    else_expr: Box<ProvableExprPlan<C>>,
+}
+
+impl<C: Commitment> TryFrom<UncheckedCaseExpr<C>> for CaseExpr<C> {
+    type Error = ConversionError;
+
+    fn try_from(value: UncheckedCaseExpr<C>) -> Result<Self, Self::Error> {
+        Self::try_new(value.predicate, value.then_expr, value.else_expr)
+    }
+}
+
+impl<C: Commitment> CaseExpr<C> {
+    /// Create a new `CASE WHEN` expression
+    ///
+    /// `predicate` must be a `Boolean` expression, and `else_expr` must have the same type as
+    /// `then_expr`, including the precision and scale of decimals.
+    pub fn try_new(
+        predicate: Box<ProvableExprPlan<C>>,
+        then_expr: Box<ProvableExprPlan<C>>,
+        else_expr: Box<ProvableExprPlan<C>>,
+    ) -> ConversionResult<Self> {
+        if predicate.data_type() != ColumnType::Boolean {
+            return Err(ConversionError::InvalidDataType {
+                expected: ColumnType::Boolean,
+                actual: predicate.data_type(),
+            });
+        }
+        if else_expr.data_type() != then_expr.data_type() {
+            return Err(ConversionError::InvalidDataType {
+                expected: then_expr.data_type(),
+                actual: else_expr.data_type(),
+            });
+        }
+        Ok(Self {
+            predicate,
+            then_expr,
+            else_expr,
+        })
+    }
+}
+
+impl<C: Commitment> ProvableExpr<C> for CaseExpr<C> {
+    fn count(&self, builder: &mut CountBuilder) -> Result<(), ProofError> {
+        self.predicate.count(builder)?;
+        self.then_expr.count(builder)?;
+        self.else_expr.count(builder)?;
+        builder.count_subpolynomials(1);
+        builder.count_intermediate_mles(1);
+        builder.count_degree(3);
+        Ok(())
+    }
+
+    fn data_type(&self) -> ColumnType {
+        self.then_expr.data_type()
+    }
+
+    #[cfg(feature = "prover")]
+    fn result_evaluate<'a>(
+        &self,
+        table_length: usize,
+        alloc: &'a dyn MleAllocator,
+        accessor: &'a dyn DataAccessor<C::Scalar>,
+    ) -> Column<'a, C::Scalar> {
+        let predicate = self
+            .predicate
+            .result_evaluate_selection(table_length, alloc, accessor);
+        let then_column = self
+            .then_expr
+            .result_evaluate(table_length, alloc, accessor);
+        let else_column = self
+            .else_expr
+            .result_evaluate(table_length, alloc, accessor);
+        select_columns(alloc, predicate, then_column, else_column)
+    }
+
+    #[cfg(feature = "prover")]
+    #[tracing::instrument(
+        name = "proofs.sql.ast.case_expr.prover_evaluate",
+        level = "info",
+        skip_all
+    )]
+    fn prover_evaluate<'a>(
+        &self,
+        builder: &mut ProofBuilder<'a, C::Scalar>,
+        alloc: &'a dyn MleAllocator,
+        accessor: &'a dyn DataAccessor<C::Scalar>,
+    ) -> Column<'a, C::Scalar> {
+        let predicate = self
+            .predicate
+            .prover_evaluate_selection(builder, alloc, accessor);
+        let then_column = self.then_expr.prover_evaluate(builder, alloc, accessor);
+        let else_column = self.else_expr.prover_evaluate(builder, alloc, accessor);
+
+        // result
+        let result = select_columns(alloc, predicate, then_column.clone(), else_column.clone());
+        let result_scalars: &'a [C::Scalar] =
+            alloc.alloc_slice_copy(&result.to_scalar_with_scaling(0));
+        builder.produce_intermediate_mle(result_scalars);
+
+        // subpolynomial: result - predicate * then - else + predicate * else
+        builder.produce_sumcheck_subpolynomial(
+            SumcheckSubpolynomialType::Identity,
+            vec![
+                (C::Scalar::one(), vec![Box::new(result_scalars)]),
+                (
+                    -C::Scalar::one(),
+                    vec![Box::new(predicate), Box::new(then_column)],
+                ),
+                (-C::Scalar::one(), vec![Box::new(else_column.clone())]),
+                (
+                    C::Scalar::one(),
+                    vec![Box::new(predicate), Box::new(else_column)],
+                ),
+            ],
+        );
+        result
+    }
+
+    fn verifier_evaluate(
+        &self,
+        builder: &mut VerificationBuilder<C>,
+        accessor: &dyn CommitmentAccessor<C>,
+    ) -> Result<C::Scalar, ProofError> {
+        let predicate = self
+            .predicate
+            .verifier_evaluate_selection(builder, accessor)?;
+        let then_eval = self.then_expr.verifier_evaluate(builder, accessor)?;
+        let else_eval = self.else_expr.verifier_evaluate(builder, accessor)?;
+
+        // result
+        let result = builder.consume_intermediate_mle();
+
+        // subpolynomial: result - predicate * then - else + predicate * else
+        let eval = builder.mle_evaluations.random_evaluation
+            * (result - predicate * then_eval - else_eval + predicate * else_eval);
+        builder.produce_sumcheck_subpolynomial_evaluation(&eval);
+
+        Ok(result)
+    }
+
+    fn get_column_references(&self, columns: &mut IndexSet<ColumnRef>) {
+        self.predicate.get_column_references(columns);
+        self.then_expr.get_column_references(columns);
+        self.else_expr.get_column_references(columns);
+    }
+}
+
+/// Picks each row from `then_column` where `predicate` is true and from `else_column` otherwise.
+///
+/// # Panics
+/// Panics if the two columns are not of the same type.
+#[cfg(feature = "prover")]
+fn select_columns<'a, S: Scalar>(
+    alloc: &'a dyn MleAllocator,
+    predicate: &[bool],
+    then_column: Column<'a, S>,
+    else_column: Column<'a, S>,
+) -> Column<'a, S> {
+    fn select<'a, T: Copy>(
+        alloc: &'a dyn MleAllocator,
+        predicate: &[bool],
+        a: &[T],
+        b: &[T],
+    ) -> &'a [T] {
+        alloc.alloc_slice_fill_with(predicate.len(), |i| if predicate[i] { a[i] } else { b[i] })
+    }
+    match (then_column, else_column) {
+        (Column::Boolean(a), Column::Boolean(b)) => Column::Boolean(select(alloc, predicate, a, b)),
+        (Column::SmallInt(a), Column::SmallInt(b)) => {
+            Column::SmallInt(select(alloc, predicate, a, b))
+        }
+        (Column::Int(a), Column::Int(b)) => Column::Int(select(alloc, predicate, a, b)),
+        (Column::BigInt(a), Column::BigInt(b)) => Column::BigInt(select(alloc, predicate, a, b)),
+        (Column::Int128(a), Column::Int128(b)) => Column::Int128(select(alloc, predicate, a, b)),
+        (Column::Decimal75(precision, scale, a), Column::Decimal75(_, _, b)) => {
+            Column::Decimal75(precision, scale, select(alloc, predicate, a, b))
+        }
+        (Column::Scalar(a), Column::Scalar(b)) => Column::Scalar(select(alloc, predicate, a, b)),
+        (Column::VarChar((a_strs, a_scalars)), Column::VarChar((b_strs, b_scalars))) => {
+            Column::VarChar((
+                select(alloc, predicate, a_strs, b_strs),
+                select(alloc, predicate, a_scalars, b_scalars),
+            ))
+        }
+        (Column::TimestampTZ(time_unit, timezone, a), Column::TimestampTZ(_, _, b)) => {
+            Column::TimestampTZ(time_unit, timezone, select(alloc, predicate, a, b))
+        }
+        _ => panic!("CASE branches must have the same type"),
+    }
+}
@@ -0,0 +1,204 @@
+use crate::{
+    base::{
+        commitment::InnerProductProof,
+        database::{owned_table_utility::*, OwnedTableTestAccessor},
+    },
+    sql::{
+        ast::{test_utility::*, ProvableExprPlan},
+        parse::ConversionError,
+        proof::{exercise_verification, VerifiableQueryResult},
+    },
+};
+use curve25519_dalek::ristretto::RistrettoPoint;
+use itertools::{multizip, MultiUnzip};
+use rand::{
+    distributions::{Distribution, Uniform},
+    rngs::StdRng,
+};
+use rand_core::SeedableRng;
+
+// select case when b = 1 then a else c end as x, case when b = 1 then 'yes' else d end as y from sxt.t where c <= 5
+#[test]
+fn we_can_prove_a_typical_case_when_query() {
+    let data = owned_table([
+        bigint("a", [1_i64, 2, 3, 4, 5]),
+        bigint("b", [1_i64, 0, 1, 0, 1]),
+        bigint("c", [10_i64, 3, 4, 5, 6]),
+        varchar("d", ["ab", "t", "efg", "g", "h"]),
+    ]);
+    let t = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(t, data, 0, ());
+    let ast = dense_filter(
+        vec![
+            aliased_plan(
+                case_when(
+                    equal(column(t, "b", &accessor), const_bigint(1)),
+                    column(t, "a", &accessor),
+                    column(t, "c", &accessor),
+                ),
+                "x",
+            ),
+            aliased_plan(
+                case_when(
+                    equal(column(t, "b", &accessor), const_bigint(1)),
+                    const_varchar("yes"),
+                    column(t, "d", &accessor),
+                ),
+                "y",
+            ),
+        ],
+        tab(t),
+        lte(column(t, "c", &accessor), const_bigint(5)),
+    );
+    let verifiable_res = VerifiableQueryResult::new(&ast, &accessor, &());
+    exercise_verification(&verifiable_res, &ast, &accessor, t);
+    let res = verifiable_res.verify(&ast, &accessor, &()).unwrap().table;
+    let expected_res = owned_table([bigint("x", [3_i64, 3, 5]), varchar("y", ["t", "yes", "g"])]);
+    assert_eq!(res, expected_res);
+}
+
+// select case when b = 1 then 7 else -7 end as x from sxt.t
+#[test]
+fn we_can_prove_a_case_when_query_with_literal_branches() {
+    let data = owned_table([bigint("b", [1_i64, 0, 1])]);
+    let t = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(t, data, 0, ());
+    let ast = dense_filter(
+        vec![aliased_plan(
+            case_when(
+                equal(column(t, "b", &accessor), const_bigint(1)),
+                const_bigint(7),
+                const_bigint(-7),
+            ),
+            "x",
+        )],
+        tab(t),
+        const_bool(true),
+    );
+    let verifiable_res = VerifiableQueryResult::new(&ast, &accessor, &());
+    exercise_verification(&verifiable_res, &ast, &accessor, t);
+    let res = verifiable_res.verify(&ast, &accessor, &()).unwrap().table;
+    let expected_res = owned_table([bigint("x", [7_i64, -7, 7])]);
+    assert_eq!(res, expected_res);
+}
+
+#[test]
+fn we_cannot_build_a_case_when_expression_with_mismatched_types() {
+    let data = owned_table([bigint("a", [1_i64]), varchar("d", ["ab"])]);
+    let t = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(t, data, 0, ());
+    assert!(matches!(
+        ProvableExprPlan::<RistrettoPoint>::try_new_case(
+            const_bool(true),
+            column(t, "a", &accessor),
+            column(t, "d", &accessor),
+        ),
+        Err(ConversionError::InvalidDataType { .. })
+    ));
+    assert!(matches!(
+        ProvableExprPlan::<RistrettoPoint>::try_new_case(
+            column(t, "a", &accessor),
+            column(t, "a", &accessor),
+            column(t, "a", &accessor),
+        ),
+        Err(ConversionError::InvalidDataType { .. })
+    ));
+}
+
+#[test]
+fn we_cannot_build_a_case_when_expression_with_decimal_branches_of_different_scales() {
+    assert!(matches!(
+        ProvableExprPlan::<RistrettoPoint>::try_new_case(
+            const_bool(true),
+            const_decimal75(10, 2, 1),
+            const_decimal75(10, 3, 1),
+        ),
+        Err(ConversionError::InvalidDataType { .. })
+    ));
+    assert!(matches!(
+        ProvableExprPlan::<RistrettoPoint>::try_new_case(
+            const_bool(true),
+            const_decimal75(10, 2, 1),
+            const_decimal75(11, 2, 1),
+        ),
+        Err(ConversionError::InvalidDataType { .. })
+    ));
+}
+
+fn test_random_tables_with_given_offset(offset: usize) {
+    let dist = Uniform::new(-3, 4);
+    let mut rng = StdRng::from_seed([0u8; 32]);
+    for _ in 0..20 {
+        // Generate random table
+        let n = Uniform::new(1, 21).sample(&mut rng);
+        let data = owned_table([
+            bigint("a", dist.sample_iter(&mut rng).take(n)),
+            bigint("b", dist.sample_iter(&mut rng).take(n)),
+            bigint("c", dist.sample_iter(&mut rng).take(n)),
+        ]);
+
+        // Generate random values to compare with
+        let filter_val = dist.sample(&mut rng);
+        let else_val = dist.sample(&mut rng);
+
+        // Create and verify proof
+        let t = "sxt.t".parse().unwrap();
+        let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(
+            t,
+            data.clone(),
+            offset,
+            (),
+        );
+        let ast = dense_filter(
+            vec![
+                aliased_plan(
+                    case_when(
+                        lte(column(t, "a", &accessor), const_bigint(filter_val)),
+                        column(t, "b", &accessor),
+                        column(t, "c", &accessor),
+                    ),
+                    "x",
+                ),
+                aliased_plan(
+                    case_when(
+                        equal(column(t, "b", &accessor), column(t, "c", &accessor)),
+                        column(t, "a", &accessor),
+                        const_bigint(else_val),
+                    ),
+                    "y",
+                ),
+            ],
+            tab(t),
+            const_bool(true),
+        );
+        let verifiable_res = VerifiableQueryResult::new(&ast, &accessor, &());
+        exercise_verification(&verifiable_res, &ast, &accessor, t);
+        let res = verifiable_res.verify(&ast, &accessor, &()).unwrap().table;
+
+        // Calculate/compare expected result
+        let (expected_x, expected_y): (Vec<_>, Vec<_>) = multizip((
+            data["a"].i64_iter(),
+            data["b"].i64_iter(),
+            data["c"].i64_iter(),
+        ))
+        .map(|(a, b, c)| {
+            (
+                if *a <= filter_val { *b } else { *c },
+                if b == c { *a } else { else_val },
+            )
+        })
+        .multiunzip();
+        let expected_result = owned_table([bigint("x", expected_x), bigint("y", expected_y)]);
+        assert_eq!(expected_result, res)
+    }
+}
+
+#[test]
+fn we_can_query_random_tables_using_a_zero_offset() {
+    test_random_tables_with_given_offset(0);
+}
+
+#[test]
+fn we_can_query_random_tables_using_a_non_zero_offset() {
+    test_random_tables_with_given_offset(75);
+}
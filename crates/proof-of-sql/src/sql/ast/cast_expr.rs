@@ -0,0 +1,242 @@
+use super::{ProvableExpr, ProvableExprPlan};
+use crate::{
+    base::{
+        commitment::Commitment,
+        database::{ColumnRef, ColumnType, CommitmentAccessor},
+        math::decimal::scale_scalar,
+        proof::ProofError,
+        scalar::Scalar,
+    },
+    sql::proof::{CountBuilder, VerificationBuilder},
+};
+#[cfg(feature = "prover")]
+use crate::{
+    base::{
+        database::{Column, DataAccessor},
+        MleAllocator,
+    },
+    sql::proof::{ProofBuilder, SumcheckSubpolynomialType},
+};
+use indexmap::IndexSet;
+use num_traits::One;
+use serde::{Deserialize, Serialize};
+
+/// Provable conversion of a numeric expression to a wider numeric type, `CAST(expr AS to_type)`
+///
+/// Integers can be widened to larger integers, and integers and decimals can be converted to
+/// decimals that keep all of their digits. The result is committed and shown to equal the
+/// source scaled by `10^(to_scale - from_scale)`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(try_from = "UncheckedCastExpr<C>")]
+pub struct CastExpr<C: Commitment> {
+    from_expr: Box<ProvableExprPlan<C>>,
+    to_type: ColumnType,
+}
+
+/// The serialized form of a [`CastExpr`], which is only accepted if the cast is supported.
+#[derive(Deserialize)]
+struct UncheckedCastExpr<C: Commitment> {
+    from_expr: Box<ProvableExprPlan<C>>,
+    to_type: ColumnType,
+}
+
+impl<C: Commitment> TryFrom<UncheckedCastExpr<C>> for CastExpr<C> {
+    type Error = ProofError;
+
+    fn try_from(value: UncheckedCastExpr<C>) -> Result<Self, Self::Error> {
+        Self::try_new(value.from_expr, value.to_type)
+    }
+}
+
+impl<C: Commitment> CastExpr<C> {
+    /// Create a new cast expression
+    ///
+    /// Returns [`ProofError::UnsupportedCast`] if the cast could lose information.
+    pub fn try_new(
+        from_expr: Box<ProvableExprPlan<C>>,
+        to_type: ColumnType,
+    ) -> Result<Self, ProofError> {
+        let from_type = from_expr.data_type();
+        match scale_difference(from_type, to_type) {
+            Some(_) => Ok(Self { from_expr, to_type }),
+            None => Err(ProofError::UnsupportedCast {
+                from: from_type,
+                to: to_type,
+            }),
+        }
+    }
+
+    /// The factor that the source is multiplied by, `10^(to_scale - from_scale)`
+    fn scale_factor(&self) -> C::Scalar {
+        let scale = scale_difference(self.from_expr.data_type(), self.to_type)
+            .expect("cast should have been validated on creation");
+        scale_scalar(C::Scalar::one(), scale).expect("scale difference is non-negative")
+    }
+}
+
+impl<C: Commitment> ProvableExpr<C> for CastExpr<C> {
+    fn count(&self, builder: &mut CountBuilder) -> Result<(), ProofError> {
+        self.from_expr.count(builder)?;
+        builder.count_intermediate_mles(1);
+        builder.count_subpolynomials(1);
+        builder.count_degree(2);
+        Ok(())
+    }
+
+    fn data_type(&self) -> ColumnType {
+        self.to_type
+    }
+
+    #[cfg(feature = "prover")]
+    fn result_evaluate<'a>(
+        &self,
+        table_length: usize,
+        alloc: &'a dyn MleAllocator,
+        accessor: &'a dyn DataAccessor<C::Scalar>,
+    ) -> Column<'a, C::Scalar> {
+        let from_column: Column<'a, C::Scalar> =
+            self.from_expr
+                .result_evaluate(table_length, alloc, accessor);
+        cast_column(&from_column, self.to_type, self.scale_factor(), alloc)
+    }
+
+    #[cfg(feature = "prover")]
+    #[tracing::instrument(
+        name = "proofs.sql.ast.cast_expr.prover_evaluate",
+        level = "info",
+        skip_all
+    )]
+    fn prover_evaluate<'a>(
+        &self,
+        builder: &mut ProofBuilder<'a, C::Scalar>,
+        alloc: &'a dyn MleAllocator,
+        accessor: &'a dyn DataAccessor<C::Scalar>,
+    ) -> Column<'a, C::Scalar> {
+        let from_column: Column<'a, C::Scalar> =
+            self.from_expr.prover_evaluate(builder, alloc, accessor);
+        let scale_factor = self.scale_factor();
+        let to_column = cast_column(&from_column, self.to_type, scale_factor, alloc);
+        match to_column {
+            Column::SmallInt(col) => builder.produce_intermediate_mle(col),
+            Column::Int(col) => builder.produce_intermediate_mle(col),
+            Column::BigInt(col) => builder.produce_intermediate_mle(col),
+            Column::Int128(col) => builder.produce_intermediate_mle(col),
+            Column::Decimal75(_, _, col) => builder.produce_intermediate_mle(col),
+            _ => unreachable!("casts only produce integers and decimals"),
+        }
+
+        // subpolynomial: to - scale_factor * from
+        builder.produce_sumcheck_subpolynomial(
+            SumcheckSubpolynomialType::Identity,
+            vec![
+                (C::Scalar::one(), vec![Box::new(to_column.clone())]),
+                (-scale_factor, vec![Box::new(from_column)]),
+            ],
+        );
+        to_column
+    }
+
+    fn verifier_evaluate(
+        &self,
+        builder: &mut VerificationBuilder<C>,
+        accessor: &dyn CommitmentAccessor<C>,
+    ) -> Result<C::Scalar, ProofError> {
+        let rand_eval = builder.mle_evaluations.random_evaluation;
+        let from_eval = self.from_expr.verifier_evaluate(builder, accessor)?;
+        let to_eval = builder.consume_intermediate_mle();
+
+        // subpolynomial: to - scale_factor * from
+        let eval = rand_eval * (to_eval - self.scale_factor() * from_eval);
+        builder.produce_sumcheck_subpolynomial_evaluation(&eval);
+
+        Ok(to_eval)
+    }
+
+    fn get_column_references(&self, columns: &mut IndexSet<ColumnRef>) {
+        self.from_expr.get_column_references(columns);
+    }
+}
+
+/// Returns `to_scale - from_scale` if every value of `from_type` can be represented exactly in
+/// `to_type`, and `None` otherwise.
+fn scale_difference(from_type: ColumnType, to_type: ColumnType) -> Option<i8> {
+    match (from_type, to_type) {
+        (from, to) if from.is_integer() && to.is_integer() => {
+            (to.byte_size() >= from.byte_size()).then_some(0)
+        }
+        (
+            ColumnType::SmallInt
+            | ColumnType::Int
+            | ColumnType::BigInt
+            | ColumnType::Int128
+            | ColumnType::Decimal75(_, _),
+            ColumnType::Decimal75(to_precision, to_scale),
+        ) => {
+            let from_precision = i16::from(from_type.precision_value()?);
+            let from_scale = i16::from(from_type.scale()?);
+            let to_precision = i16::from(to_precision.value());
+            let to_scale = i16::from(to_scale);
+            let keeps_digits =
+                to_scale >= from_scale && to_precision - to_scale >= from_precision - from_scale;
+            keeps_digits
+                .then(|| i8::try_from(to_scale - from_scale).ok())
+                .flatten()
+        }
+        _ => None,
+    }
+}
+
+/// Converts an integer or decimal column to `to_type`, scaling it by `scale_factor`.
+///
+/// # Panics
+/// Panics if the column is not an integer or decimal column, or if the cast is unsupported.
+#[cfg(feature = "prover")]
+fn cast_column<'a, S: Scalar>(
+    column: &Column<'a, S>,
+    to_type: ColumnType,
+    scale_factor: S,
+    alloc: &'a dyn MleAllocator,
+) -> Column<'a, S> {
+    if let ColumnType::Decimal75(precision, scale) = to_type {
+        let values = alloc.alloc_slice_fill_iter(
+            column
+                .to_scalar_with_scaling(0)
+                .into_iter()
+                .map(|value| value * scale_factor),
+        );
+        return Column::Decimal75(precision, scale, values);
+    }
+    let values: Vec<i128> = match column {
+        Column::SmallInt(col) => col.iter().map(|&v| v.into()).collect(),
+        Column::Int(col) => col.iter().map(|&v| v.into()).collect(),
+        Column::BigInt(col) => col.iter().map(|&v| v.into()).collect(),
+        Column::Int128(col) => col.to_vec(),
+        _ => panic!("Only integer columns can be cast to integers"),
+    };
+    let expect_widening = "cast should be widening";
+    match to_type {
+        ColumnType::SmallInt => Column::SmallInt(
+            alloc.alloc_slice_fill_iter(
+                values
+                    .iter()
+                    .map(|&v| i16::try_from(v).expect(expect_widening)),
+            ),
+        ),
+        ColumnType::Int => Column::Int(
+            alloc.alloc_slice_fill_iter(
+                values
+                    .iter()
+                    .map(|&v| i32::try_from(v).expect(expect_widening)),
+            ),
+        ),
+        ColumnType::BigInt => Column::BigInt(
+            alloc.alloc_slice_fill_iter(
+                values
+                    .iter()
+                    .map(|&v| i64::try_from(v).expect(expect_widening)),
+            ),
+        ),
+        ColumnType::Int128 => Column::Int128(alloc.alloc_slice_copy(&values)),
+        _ => panic!("Unsupported cast to {to_type}"),
+    }
+}
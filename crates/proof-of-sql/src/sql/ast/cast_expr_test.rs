@@ -0,0 +1,114 @@
+use super::CastExpr;
+use crate::{
+    base::{
+        commitment::InnerProductProof,
+        database::{owned_table_utility::*, ColumnType, OwnedTableTestAccessor},
+        math::decimal::Precision,
+        proof::ProofError,
+        scalar::Curve25519Scalar,
+    },
+    sql::{
+        ast::test_utility::*,
+        proof::{exercise_verification, VerifiableQueryResult},
+    },
+};
+use curve25519_dalek::ristretto::RistrettoPoint;
+
+// select cast(a as bigint) as a, b from sxt.t where b <= 5
+#[test]
+fn we_can_prove_a_widening_cast_from_int_to_bigint() {
+    let data = owned_table([
+        int("a", [i32::MIN, -1, 0, 1, i32::MAX, 7]),
+        bigint("b", [1_i64, 2, 3, 4, 5, 6]),
+    ]);
+    let t = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(t, data, 0, ());
+    let ast = dense_filter(
+        vec![
+            aliased_plan(cast(column(t, "a", &accessor), ColumnType::BigInt), "a"),
+            col_expr_plan(t, "b", &accessor),
+        ],
+        tab(t),
+        lte(column(t, "b", &accessor), const_bigint(5)),
+    );
+    let verifiable_res = VerifiableQueryResult::new(&ast, &accessor, &());
+    exercise_verification(&verifiable_res, &ast, &accessor, t);
+    let res = verifiable_res.verify(&ast, &accessor, &()).unwrap().table;
+    let expected_res = owned_table([
+        bigint("a", [i32::MIN as i64, -1, 0, 1, i32::MAX as i64]),
+        bigint("b", [1_i64, 2, 3, 4, 5]),
+    ]);
+    assert_eq!(res, expected_res);
+}
+
+// select cast(a as decimal(10, 2)) as a from sxt.t
+#[test]
+fn we_can_prove_a_scaling_cast_from_smallint_to_decimal() {
+    let data = owned_table([smallint("a", [-3_i16, 0, 12])]);
+    let t = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(t, data, 0, ());
+    let ast = projection(
+        vec![aliased_plan(
+            cast(
+                column(t, "a", &accessor),
+                ColumnType::Decimal75(Precision::new(10).unwrap(), 2),
+            ),
+            "a",
+        )],
+        tab(t),
+    );
+    let verifiable_res = VerifiableQueryResult::new(&ast, &accessor, &());
+    exercise_verification(&verifiable_res, &ast, &accessor, t);
+    let res = verifiable_res.verify(&ast, &accessor, &()).unwrap().table;
+    let expected_res = owned_table([decimal75(
+        "a",
+        10,
+        2,
+        [
+            Curve25519Scalar::from(-300),
+            Curve25519Scalar::from(0),
+            Curve25519Scalar::from(1200),
+        ],
+    )]);
+    assert_eq!(res, expected_res);
+}
+
+#[test]
+fn we_cannot_cast_to_a_narrower_type() {
+    let data = owned_table([
+        bigint("a", [1_i64]),
+        decimal75("d", 10, 2, [Curve25519Scalar::from(1)]),
+    ]);
+    let t = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(t, data, 0, ());
+    assert!(matches!(
+        CastExpr::<RistrettoPoint>::try_new(Box::new(column(t, "a", &accessor)), ColumnType::Int),
+        Err(ProofError::UnsupportedCast {
+            from: ColumnType::BigInt,
+            to: ColumnType::Int,
+        })
+    ));
+    // dropping digits after the decimal point
+    assert!(matches!(
+        CastExpr::<RistrettoPoint>::try_new(
+            Box::new(column(t, "d", &accessor)),
+            ColumnType::Decimal75(Precision::new(10).unwrap(), 1)
+        ),
+        Err(ProofError::UnsupportedCast { .. })
+    ));
+    // a decimal that is too small to hold every bigint
+    assert!(matches!(
+        CastExpr::<RistrettoPoint>::try_new(
+            Box::new(column(t, "a", &accessor)),
+            ColumnType::Decimal75(Precision::new(20).unwrap(), 2)
+        ),
+        Err(ProofError::UnsupportedCast { .. })
+    ));
+    assert!(matches!(
+        CastExpr::<RistrettoPoint>::try_new(
+            Box::new(column(t, "a", &accessor)),
+            ColumnType::VarChar
+        ),
+        Err(ProofError::UnsupportedCast { .. })
+    ));
+}
@@ -2,12 +2,19 @@ use super::ProvableExpr;
 use crate::{
     base::{
         commitment::Commitment,
-        database::{Column, ColumnField, ColumnRef, ColumnType, CommitmentAccessor, DataAccessor},
+        database::{ColumnField, ColumnRef, ColumnType, CommitmentAccessor},
         proof::ProofError,
     },
-    sql::proof::{CountBuilder, ProofBuilder, VerificationBuilder},
+    sql::proof::{CountBuilder, VerificationBuilder},
+};
+#[cfg(feature = "prover")]
+use crate::{
+    base::{
+        database::{Column, DataAccessor},
+        MleAllocator,
+    },
+    sql::proof::ProofBuilder,
 };
-use bumpalo::Bump;
 use core::marker::PhantomData;
 use indexmap::IndexSet;
 use proof_of_sql_parser::Identifier;
@@ -49,7 +56,7 @@ impl<C: Commitment> ColumnExpr<C> {
 impl<C: Commitment> ProvableExpr<C> for ColumnExpr<C> {
     /// Count the number of proof terms needed by this expression
     fn count(&self, builder: &mut CountBuilder) -> Result<(), ProofError> {
-        builder.count_anchored_mles(1);
+        builder.count_column_mle(self.column_ref);
         Ok(())
     }
 
@@ -60,10 +67,11 @@ impl<C: Commitment> ProvableExpr<C> for ColumnExpr<C> {
 
     /// Evaluate the column expression and
     /// add the result to the ResultBuilder
+    #[cfg(feature = "prover")]
     fn result_evaluate<'a>(
         &self,
         table_length: usize,
-        _alloc: &'a Bump,
+        _alloc: &'a dyn MleAllocator,
         accessor: &'a dyn DataAccessor<C::Scalar>,
     ) -> Column<'a, C::Scalar> {
         let column = accessor.get_column(self.column_ref);
@@ -73,14 +81,15 @@ impl<C: Commitment> ProvableExpr<C> for ColumnExpr<C> {
 
     /// Given the selected rows (as a slice of booleans), evaluate the column expression and
     /// add the components needed to prove the result
+    #[cfg(feature = "prover")]
     fn prover_evaluate<'a>(
         &self,
         builder: &mut ProofBuilder<'a, C::Scalar>,
-        _alloc: &'a Bump,
+        _alloc: &'a dyn MleAllocator,
         accessor: &'a dyn DataAccessor<C::Scalar>,
     ) -> Column<'a, C::Scalar> {
         let column = accessor.get_column(self.column_ref);
-        builder.produce_anchored_mle(column.clone());
+        builder.produce_column_mle(self.column_ref, column.clone());
         column
     }
 
@@ -92,7 +101,7 @@ impl<C: Commitment> ProvableExpr<C> for ColumnExpr<C> {
         accessor: &dyn CommitmentAccessor<C>,
     ) -> Result<C::Scalar, ProofError> {
         let col_commit = accessor.get_commitment(self.column_ref);
-        Ok(builder.consume_anchored_mle(col_commit))
+        Ok(builder.consume_column_mle(self.column_ref, col_commit))
     }
 
     /// Insert in the IndexSet `columns` all the column
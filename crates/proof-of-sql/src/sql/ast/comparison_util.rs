@@ -3,6 +3,7 @@ use crate::{
         database::Column,
         math::decimal::{DecimalError, Precision},
         scalar::Scalar,
+        MleAllocator,
     },
     sql::{
         ast::comparison_util::DecimalError::InvalidPrecision,
@@ -12,14 +13,13 @@ use crate::{
         },
     },
 };
-use bumpalo::Bump;
 use proof_of_sql_parser::intermediate_ast::BinaryOperator;
 use rayon::iter::{
     IndexedParallelIterator, IntoParallelRefIterator, IntoParallelRefMutIterator, ParallelIterator,
 };
 
 fn unchecked_subtract_impl<'a, S: Scalar>(
-    alloc: &'a Bump,
+    alloc: &'a dyn MleAllocator,
     lhs: &[S],
     rhs: &[S],
     table_length: usize,
@@ -36,7 +36,7 @@ fn unchecked_subtract_impl<'a, S: Scalar>(
 /// Scale LHS and RHS to the same scale if at least one of them is decimal
 /// and take the difference. This function is used for comparisons.
 pub(crate) fn scale_and_subtract<'a, S: Scalar>(
-    alloc: &'a Bump,
+    alloc: &'a dyn MleAllocator,
     lhs: Column<'a, S>,
     rhs: Column<'a, S>,
     lhs_scale: i8,
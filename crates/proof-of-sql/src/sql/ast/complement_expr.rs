@@ -0,0 +1,193 @@
+use super::{ProvableBooleanExpr, ProvableExpr, ProvableExprPlan};
+use crate::{
+    base::{
+        commitment::Commitment,
+        database::{ColumnRef, ColumnType, CommitmentAccessor},
+        proof::ProofError,
+        scalar::Scalar,
+    },
+    sql::proof::{CountBuilder, VerificationBuilder},
+};
+#[cfg(feature = "prover")]
+use crate::{
+    base::{
+        database::{Column, DataAccessor},
+        MleAllocator,
+    },
+    sql::proof::{ProofBuilder, SumcheckSubpolynomialType},
+};
+use indexmap::IndexSet;
+use serde::{Deserialize, Serialize};
+
+/// Provable complement of a selection: the rows of the table that a predicate does not select
+///
+/// Unlike `NotExpr`, the complement is committed to, and the verifier checks
+/// that it is disjoint from the selection and that together they select exactly as many rows as
+/// the table has, i.e. `|selected| + |complement| = table_length`.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ComplementExpr<C: Commitment> {
+    expr: Box<ProvableExprPlan<C>>,
+}
+
+impl<C: Commitment> ComplementExpr<C> {
+    /// Create the complement of a boolean expression
+    pub fn new(expr: Box<ProvableExprPlan<C>>) -> Self {
+        Self { expr }
+    }
+}
+
+impl<C: Commitment> ProvableExpr<C> for ComplementExpr<C> {
+    fn count(&self, builder: &mut CountBuilder) -> Result<(), ProofError> {
+        self.expr.count(builder)?;
+        count_complement(builder);
+        Ok(())
+    }
+
+    fn data_type(&self) -> ColumnType {
+        ColumnType::Boolean
+    }
+
+    #[cfg(feature = "prover")]
+    #[tracing::instrument(name = "ComplementExpr::result_evaluate", level = "debug", skip_all)]
+    fn result_evaluate<'a>(
+        &self,
+        table_length: usize,
+        alloc: &'a dyn MleAllocator,
+        accessor: &'a dyn DataAccessor<C::Scalar>,
+    ) -> Column<'a, C::Scalar> {
+        Column::Boolean(self.result_evaluate_selection(table_length, alloc, accessor))
+    }
+
+    #[cfg(feature = "prover")]
+    #[tracing::instrument(name = "ComplementExpr::prover_evaluate", level = "debug", skip_all)]
+    fn prover_evaluate<'a>(
+        &self,
+        builder: &mut ProofBuilder<'a, C::Scalar>,
+        alloc: &'a dyn MleAllocator,
+        accessor: &'a dyn DataAccessor<C::Scalar>,
+    ) -> Column<'a, C::Scalar> {
+        Column::Boolean(self.prover_evaluate_selection(builder, alloc, accessor))
+    }
+
+    fn verifier_evaluate(
+        &self,
+        builder: &mut VerificationBuilder<C>,
+        accessor: &dyn CommitmentAccessor<C>,
+    ) -> Result<C::Scalar, ProofError> {
+        self.verifier_evaluate_selection(builder, accessor)
+    }
+
+    fn get_column_references(&self, columns: &mut IndexSet<ColumnRef>) {
+        self.expr.get_column_references(columns);
+    }
+}
+
+impl<C: Commitment> ProvableBooleanExpr<C> for ComplementExpr<C> {
+    #[cfg(feature = "prover")]
+    fn result_evaluate_selection<'a>(
+        &self,
+        table_length: usize,
+        alloc: &'a dyn MleAllocator,
+        accessor: &'a dyn DataAccessor<C::Scalar>,
+    ) -> &'a [bool] {
+        let selection = self
+            .expr
+            .result_evaluate_selection(table_length, alloc, accessor);
+        assert_eq!(table_length, selection.len());
+        alloc.alloc_slice_fill_with(table_length, |i| !selection[i])
+    }
+
+    #[cfg(feature = "prover")]
+    fn prover_evaluate_selection<'a>(
+        &self,
+        builder: &mut ProofBuilder<'a, C::Scalar>,
+        alloc: &'a dyn MleAllocator,
+        accessor: &'a dyn DataAccessor<C::Scalar>,
+    ) -> &'a [bool] {
+        let selection = self
+            .expr
+            .prover_evaluate_selection(builder, alloc, accessor);
+        prover_evaluate_complement(builder, alloc, selection)
+    }
+
+    fn verifier_evaluate_selection(
+        &self,
+        builder: &mut VerificationBuilder<C>,
+        accessor: &dyn CommitmentAccessor<C>,
+    ) -> Result<C::Scalar, ProofError> {
+        let selection = self.expr.verifier_evaluate_selection(builder, accessor)?;
+        verifier_evaluate_complement(builder, selection)
+    }
+}
+
+#[cfg(feature = "prover")]
+fn prover_evaluate_complement<'a, S: Scalar>(
+    builder: &mut ProofBuilder<'a, S>,
+    alloc: &'a dyn MleAllocator,
+    selection: &'a [bool],
+) -> &'a [bool] {
+    let n = builder.table_length();
+    assert_eq!(n, selection.len());
+
+    // complement
+    let complement: &[_] = alloc.alloc_slice_fill_with(n, |i| !selection[i]);
+    builder.produce_intermediate_mle(complement);
+
+    // subpolynomial: complement - complement * complement
+    builder.produce_sumcheck_subpolynomial(
+        SumcheckSubpolynomialType::Identity,
+        vec![
+            (S::one(), vec![Box::new(complement)]),
+            (-S::one(), vec![Box::new(complement), Box::new(complement)]),
+        ],
+    );
+
+    // subpolynomial: selection * complement
+    builder.produce_sumcheck_subpolynomial(
+        SumcheckSubpolynomialType::Identity,
+        vec![(S::one(), vec![Box::new(selection), Box::new(complement)])],
+    );
+
+    // sum selection + complement - 1 = 0
+    let ones: &[_] = alloc.alloc_slice_fill_copy(n, true);
+    builder.produce_sumcheck_subpolynomial(
+        SumcheckSubpolynomialType::ZeroSum,
+        vec![
+            (S::one(), vec![Box::new(selection)]),
+            (S::one(), vec![Box::new(complement)]),
+            (-S::one(), vec![Box::new(ones)]),
+        ],
+    );
+
+    complement
+}
+
+fn verifier_evaluate_complement<C: Commitment>(
+    builder: &mut VerificationBuilder<C>,
+    selection: C::Scalar,
+) -> Result<C::Scalar, ProofError> {
+    let one_eval = builder.mle_evaluations.one_evaluation;
+    let rand_eval = builder.mle_evaluations.random_evaluation;
+
+    // complement
+    let complement = builder.consume_intermediate_mle();
+
+    // subpolynomial: complement - complement * complement
+    builder.produce_sumcheck_subpolynomial_evaluation(
+        &(rand_eval * (complement - complement * complement)),
+    );
+
+    // subpolynomial: selection * complement
+    builder.produce_sumcheck_subpolynomial_evaluation(&(rand_eval * selection * complement));
+
+    // sum selection + complement - 1 = 0
+    builder.produce_sumcheck_subpolynomial_evaluation(&(selection + complement - one_eval));
+
+    Ok(complement)
+}
+
+fn count_complement(builder: &mut CountBuilder) {
+    builder.count_subpolynomials(3);
+    builder.count_intermediate_mles(1);
+    builder.count_degree(3);
+}
@@ -0,0 +1,96 @@
+use crate::{
+    base::{
+        commitment::InnerProductProof,
+        database::{owned_table_utility::*, Column, OwnedTableTestAccessor},
+    },
+    sql::{
+        ast::{test_utility::*, ProvableExpr, ProvableExprPlan},
+        proof::{exercise_verification, VerifiableQueryResult},
+    },
+};
+use bumpalo::Bump;
+use curve25519_dalek::ristretto::RistrettoPoint;
+
+#[test]
+fn we_can_prove_a_simple_complement_query() {
+    let data = owned_table([
+        bigint("a", [1_i64, 2, 3, 4]),
+        bigint("b", [0_i64, 1, 0, 1]),
+        varchar("d", ["ab", "t", "g", "efg"]),
+    ]);
+    let t = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(t, data, 0, ());
+    let ast = dense_filter(
+        cols_expr_plan(t, &["a", "d"], &accessor),
+        tab(t),
+        complement(equal(column(t, "b", &accessor), const_bigint(1))),
+    );
+    let verifiable_res = VerifiableQueryResult::new(&ast, &accessor, &());
+    exercise_verification(&verifiable_res, &ast, &accessor, t);
+    let res = verifiable_res.verify(&ast, &accessor, &()).unwrap().table;
+    let expected_res = owned_table([bigint("a", [1_i64, 3]), varchar("d", ["ab", "g"])]);
+    assert_eq!(res, expected_res);
+}
+
+#[test]
+fn a_selection_and_its_complement_partition_the_table() {
+    let data = owned_table([
+        bigint("a", [1_i64, 2, 3, 4, 5, 6, 7]),
+        bigint("b", [3_i64, 1, 3, 3, 2, 1, 3]),
+    ]);
+    let t = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(t, data, 0, ());
+    let predicate = || equal(column(t, "b", &accessor), const_bigint(3));
+    let selected_ast = dense_filter(cols_expr_plan(t, &["a"], &accessor), tab(t), predicate());
+    let complement_ast = dense_filter(
+        cols_expr_plan(t, &["a"], &accessor),
+        tab(t),
+        complement(predicate()),
+    );
+
+    let selected_res = VerifiableQueryResult::new(&selected_ast, &accessor, &());
+    exercise_verification(&selected_res, &selected_ast, &accessor, t);
+    let selected = selected_res
+        .verify(&selected_ast, &accessor, &())
+        .unwrap()
+        .table;
+    let complement_res = VerifiableQueryResult::new(&complement_ast, &accessor, &());
+    exercise_verification(&complement_res, &complement_ast, &accessor, t);
+    let complemented = complement_res
+        .verify(&complement_ast, &accessor, &())
+        .unwrap()
+        .table;
+
+    assert_eq!(selected, owned_table([bigint("a", [1_i64, 3, 4, 7])]));
+    assert_eq!(complemented, owned_table([bigint("a", [2_i64, 5, 6])]));
+    assert_eq!(selected.num_rows() + complemented.num_rows(), 7);
+}
+
+#[test]
+fn the_complement_of_a_selection_of_every_row_is_empty() {
+    let data = owned_table([bigint("a", [1_i64, 2, 3])]);
+    let t = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(t, data, 0, ());
+    let ast = dense_filter(
+        cols_expr_plan(t, &["a"], &accessor),
+        tab(t),
+        complement(const_bool(true)),
+    );
+    let verifiable_res = VerifiableQueryResult::new(&ast, &accessor, &());
+    exercise_verification(&verifiable_res, &ast, &accessor, t);
+    let res = verifiable_res.verify(&ast, &accessor, &()).unwrap().table;
+    assert_eq!(res, owned_table([bigint("a", [0_i64; 0])]));
+}
+
+#[test]
+fn we_can_compute_the_result_of_a_complement_without_a_proof() {
+    let data = owned_table([bigint("b", [0_i64, 1, 0, 1])]);
+    let t = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(t, data, 0, ());
+    let complement_expr: ProvableExprPlan<RistrettoPoint> =
+        complement(equal(column(t, "b", &accessor), const_bigint(1)));
+    let alloc = Bump::new();
+    let res = complement_expr.result_evaluate(4, &alloc, &accessor);
+    let expected_res = Column::Boolean(&[true, false, true, false]);
+    assert_eq!(res, expected_res);
+}
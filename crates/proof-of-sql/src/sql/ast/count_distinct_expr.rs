@@ -0,0 +1,228 @@
+use super::{
+    distinct_chain::{count_distinct_chain, verifier_evaluate_distinct_chain},
+    ProvableBooleanExpr, ProvableExpr, ProvableExprPlan, TableExpr,
+};
+#[cfg(feature = "prover")]
+use super::{
+    distinct_chain::{count_selected_values, prove_distinct_chain},
+    semi_join_expr::integer_values,
+};
+use crate::{
+    base::{
+        commitment::Commitment,
+        database::{
+            ColumnField, ColumnRef, ColumnType, CommitmentAccessor, MetadataAccessor, OwnedColumn,
+            OwnedTable,
+        },
+        proof::ProofError,
+    },
+    sql::{
+        parse::{ConversionError, ConversionResult},
+        proof::{CountBuilder, ProofExecutionPlan, VerificationBuilder},
+    },
+};
+#[cfg(feature = "prover")]
+use crate::{
+    base::{database::DataAccessor, MleAllocator},
+    sql::proof::{Indexes, ProofBuilder, ProverEvaluate, ResultBuilder, SumcheckSubpolynomialType},
+};
+use indexmap::IndexSet;
+#[cfg(feature = "prover")]
+use num_traits::One;
+use proof_of_sql_parser::Identifier;
+use serde::{Deserialize, Serialize};
+
+/// A value smaller than any key, used as the first node of the sorted chain of distinct values.
+const LOWER_SENTINEL: i128 = i64::MIN as i128 - 1;
+
+/// Provable expressions for queries of the form
+/// ```ignore
+///     SELECT COUNT(DISTINCT <expr>) AS <alias> FROM <table> WHERE <where_clause>
+/// ```
+///
+/// `expr` must be an integer expression. The result has a single `BigInt` row holding the count.
+///
+/// The prover commits to the sorted distinct values `k_1 < ... < k_m` of the selected rows, which
+/// are shown to be the distinct values with the argument of [`verifier_evaluate_distinct_chain`].
+/// The count is the number of rows of the chain.
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct CountDistinctExpr<C: Commitment> {
+    pub(super) expr: ProvableExprPlan<C>,
+    pub(super) alias: Identifier,
+    pub(super) table: TableExpr,
+    pub(super) where_clause: ProvableExprPlan<C>,
+}
+
+impl<C: Commitment> CountDistinctExpr<C> {
+    /// Creates a new count distinct expression.
+    ///
+    /// `expr` must be a `SmallInt`, `Int` or `BigInt` expression.
+    pub fn try_new(
+        expr: ProvableExprPlan<C>,
+        alias: Identifier,
+        table: TableExpr,
+        where_clause: ProvableExprPlan<C>,
+    ) -> ConversionResult<Self> {
+        match expr.data_type() {
+            ColumnType::SmallInt | ColumnType::Int | ColumnType::BigInt => Ok(Self {
+                expr,
+                alias,
+                table,
+                where_clause,
+            }),
+            data_type => Err(ConversionError::InvalidDataType {
+                expected: ColumnType::BigInt,
+                actual: data_type,
+            }),
+        }
+    }
+}
+
+impl<C: Commitment> ProofExecutionPlan<C> for CountDistinctExpr<C> {
+    fn count(
+        &self,
+        builder: &mut CountBuilder,
+        _accessor: &dyn MetadataAccessor,
+    ) -> Result<(), ProofError> {
+        self.where_clause.count(builder)?;
+        self.expr.count(builder)?;
+        builder.count_result_columns(1);
+        builder.count_post_result_challenges(1);
+        builder.count_intermediate_mles(1);
+        count_distinct_chain(builder)?;
+        // the sum of the chain
+        builder.count_subpolynomials(1);
+        Ok(())
+    }
+
+    fn get_length(&self, accessor: &dyn MetadataAccessor) -> usize {
+        accessor.get_length(self.table.table_ref)
+    }
+
+    fn get_offset(&self, accessor: &dyn MetadataAccessor) -> usize {
+        accessor.get_offset(self.table.table_ref)
+    }
+
+    fn verifier_evaluate(
+        &self,
+        builder: &mut VerificationBuilder<C>,
+        accessor: &dyn CommitmentAccessor<C>,
+        result: Option<&OwnedTable<C::Scalar>>,
+    ) -> Result<(), ProofError> {
+        let first_row_eval = builder.mle_evaluations.truncated_one_evaluation(1);
+
+        // 1. selection
+        let s = self
+            .where_clause
+            .verifier_evaluate_selection(builder, accessor)?;
+        // 2. column
+        let a = self.expr.verifier_evaluate(builder, accessor)?;
+        // 3. result
+        // The count is read directly from the result table below, so its MLE isn't needed.
+        let _ = builder.consume_result_mle();
+        let count: i64 = match result {
+            Some(table) => match table.inner_table().get(&self.alias) {
+                Some(OwnedColumn::BigInt(values)) if values.len() == 1 => values[0],
+                _ => Err(ProofError::VerificationError(
+                    "Result of count distinct must have exactly one bigint row.",
+                ))?,
+            },
+            None => Err(ProofError::VerificationError(
+                "CountDistinctExpr is only supported at the top level of a query plan.",
+            ))?,
+        };
+        let count = C::Scalar::from(count);
+
+        let gamma = builder.consume_post_result_challenge();
+
+        // 4. the chain of sorted distinct values
+        let hi = builder.consume_intermediate_mle();
+        let (p, _) = verifier_evaluate_distinct_chain(
+            builder,
+            gamma,
+            C::Scalar::from(LOWER_SENTINEL),
+            s,
+            a,
+            hi,
+        )?;
+        // sum p - count * chi_0 = 0
+        builder.produce_sumcheck_subpolynomial_evaluation(&(p - count * first_row_eval));
+        Ok(())
+    }
+
+    fn get_column_result_fields(&self) -> Vec<ColumnField> {
+        vec![ColumnField::new(self.alias, ColumnType::BigInt)]
+    }
+
+    fn get_column_references(&self) -> IndexSet<ColumnRef> {
+        let mut columns = IndexSet::new();
+        self.expr.get_column_references(&mut columns);
+        self.where_clause.get_column_references(&mut columns);
+        columns
+    }
+}
+
+#[cfg(feature = "prover")]
+impl<C: Commitment> ProverEvaluate<C::Scalar> for CountDistinctExpr<C> {
+    #[tracing::instrument(name = "CountDistinctExpr::result_evaluate", level = "debug", skip_all)]
+    fn result_evaluate<'a>(
+        &self,
+        builder: &mut ResultBuilder<'a>,
+        alloc: &'a dyn MleAllocator,
+        accessor: &'a dyn DataAccessor<C::Scalar>,
+    ) {
+        let selection =
+            self.where_clause
+                .result_evaluate_selection(builder.table_length(), alloc, accessor);
+        let column = self
+            .expr
+            .result_evaluate(builder.table_length(), alloc, accessor);
+        let distinct_values = count_selected_values(&integer_values(&column), selection);
+        let result = alloc.alloc_slice_copy(&[distinct_values.len() as i64]);
+        builder.set_result_indexes(Indexes::Dense(0..1));
+        builder.produce_result_column(result as &[_]);
+        builder.request_post_result_challenges(1);
+    }
+
+    #[tracing::instrument(name = "CountDistinctExpr::prover_evaluate", level = "debug", skip_all)]
+    fn prover_evaluate<'a>(
+        &self,
+        builder: &mut ProofBuilder<'a, C::Scalar>,
+        alloc: &'a dyn MleAllocator,
+        accessor: &'a dyn DataAccessor<C::Scalar>,
+    ) {
+        let n = builder.table_length();
+        let s = self
+            .where_clause
+            .prover_evaluate_selection(builder, alloc, accessor);
+        let a_column = self.expr.prover_evaluate(builder, alloc, accessor);
+        let a = integer_values(&a_column);
+        let gamma = builder.consume_post_result_challenge();
+
+        // the chain of sorted distinct values
+        let distinct_values: Vec<i64> = count_selected_values(&a, s).into_keys().collect();
+        let m = distinct_values.len();
+        let hi = alloc.alloc_slice_fill_with(n, |i| distinct_values.get(i).copied().unwrap_or(0))
+            as &[_];
+        builder.produce_intermediate_mle(hi);
+        let (p, _) = prove_distinct_chain(
+            builder,
+            alloc,
+            gamma,
+            C::Scalar::from(LOWER_SENTINEL),
+            s,
+            alloc.alloc_slice_fill_with(n, |i| C::Scalar::from(a[i])),
+            alloc.alloc_slice_fill_with(n, |i| C::Scalar::from(hi[i])),
+        );
+
+        // sum p - count * chi_0 = 0
+        let chi_first_row = alloc.alloc_slice_fill_copy(1, true) as &[_];
+        builder.produce_sumcheck_subpolynomial(
+            SumcheckSubpolynomialType::ZeroSum,
+            vec![
+                (C::Scalar::one(), vec![Box::new(p)]),
+                (-C::Scalar::from(m as i64), vec![Box::new(chi_first_row)]),
+            ],
+        );
+    }
+}
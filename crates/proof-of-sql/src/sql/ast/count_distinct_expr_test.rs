@@ -0,0 +1,155 @@
+use super::{test_utility::*, CountDistinctExpr};
+use crate::{
+    base::{
+        commitment::InnerProductProof,
+        database::{owned_table_utility::*, ColumnType, OwnedTableTestAccessor, TestAccessor},
+    },
+    sql::{
+        parse::ConversionError,
+        proof::{exercise_verification, VerifiableQueryResult},
+    },
+};
+use curve25519_dalek::ristretto::RistrettoPoint;
+use rand::{
+    distributions::{Distribution, Uniform},
+    rngs::StdRng,
+};
+use rand_core::SeedableRng;
+use std::collections::HashSet;
+
+/// select count(distinct a) as n from sxt.t where b = 99
+#[test]
+fn we_can_prove_a_simple_count_distinct_query() {
+    let data = owned_table([
+        bigint("a", [5, -2, 5, -7, 1, -2]),
+        bigint("b", [99, 99, 99, 0, 99, 99]),
+    ]);
+    let t = "sxt.t".parse().unwrap();
+    let mut accessor = OwnedTableTestAccessor::<InnerProductProof>::new_empty_with_setup(());
+    accessor.add_table(t, data, 0);
+    let expr = count_distinct(
+        column(t, "a", &accessor),
+        "n",
+        tab(t),
+        equal(column(t, "b", &accessor), const_bigint(99)),
+    );
+    let res = VerifiableQueryResult::new(&expr, &accessor, &());
+    exercise_verification(&res, &expr, &accessor, t);
+    let res = res.verify(&expr, &accessor, &()).unwrap().table;
+    assert_eq!(res, owned_table([bigint("n", [3])]));
+}
+
+/// select count(distinct a) as n from sxt.t where true
+#[test]
+fn we_can_prove_count_distinct_of_a_column_where_all_values_are_equal() {
+    let data = owned_table([int("a", [i32::MIN; 5])]);
+    let t = "sxt.t".parse().unwrap();
+    let mut accessor = OwnedTableTestAccessor::<InnerProductProof>::new_empty_with_setup(());
+    accessor.add_table(t, data, 0);
+    let expr = count_distinct(column(t, "a", &accessor), "n", tab(t), const_bool(true));
+    let res = VerifiableQueryResult::new(&expr, &accessor, &());
+    exercise_verification(&res, &expr, &accessor, t);
+    let res = res.verify(&expr, &accessor, &()).unwrap().table;
+    assert_eq!(res, owned_table([bigint("n", [1])]));
+}
+
+/// select count(distinct a) as n from sxt.t where true
+#[test]
+fn we_can_prove_count_distinct_of_a_column_where_all_values_are_distinct() {
+    let data = owned_table([bigint("a", [i64::MAX, 3, i64::MIN, -1, 0, 2])]);
+    let t = "sxt.t".parse().unwrap();
+    let mut accessor = OwnedTableTestAccessor::<InnerProductProof>::new_empty_with_setup(());
+    accessor.add_table(t, data, 0);
+    let expr = count_distinct(column(t, "a", &accessor), "n", tab(t), const_bool(true));
+    let res = VerifiableQueryResult::new(&expr, &accessor, &());
+    exercise_verification(&res, &expr, &accessor, t);
+    let res = res.verify(&expr, &accessor, &()).unwrap().table;
+    assert_eq!(res, owned_table([bigint("n", [6])]));
+}
+
+/// select count(distinct a) as n from sxt.t where b = 1
+#[test]
+fn we_can_prove_count_distinct_with_an_empty_selection() {
+    let data = owned_table([smallint("a", [1_i16, 2, 2]), bigint("b", [0, 0, 0])]);
+    let t = "sxt.t".parse().unwrap();
+    let mut accessor = OwnedTableTestAccessor::<InnerProductProof>::new_empty_with_setup(());
+    accessor.add_table(t, data, 0);
+    let expr = count_distinct(
+        column(t, "a", &accessor),
+        "n",
+        tab(t),
+        equal(column(t, "b", &accessor), const_bigint(1)),
+    );
+    let res = VerifiableQueryResult::new(&expr, &accessor, &());
+    exercise_verification(&res, &expr, &accessor, t);
+    let res = res.verify(&expr, &accessor, &()).unwrap().table;
+    assert_eq!(res, owned_table([bigint("n", [0])]));
+}
+
+#[test]
+fn we_cannot_count_distinct_values_of_a_non_integer_column() {
+    let data = owned_table([varchar("a", ["x", "y"])]);
+    let t = "sxt.t".parse().unwrap();
+    let mut accessor = OwnedTableTestAccessor::<InnerProductProof>::new_empty_with_setup(());
+    accessor.add_table(t, data, 0);
+    let res = CountDistinctExpr::<RistrettoPoint>::try_new(
+        column(t, "a", &accessor),
+        "n".parse().unwrap(),
+        tab(t),
+        const_bool(true),
+    );
+    assert!(matches!(
+        res,
+        Err(ConversionError::InvalidDataType {
+            actual: ColumnType::VarChar,
+            ..
+        })
+    ));
+}
+
+fn test_random_tables_with_given_offset(offset: usize) {
+    let dist = Uniform::new(-3, 4);
+    let mut rng = StdRng::from_seed([0u8; 32]);
+    for _ in 0..20 {
+        let n = Uniform::new(1, 21).sample(&mut rng);
+        let a: Vec<i64> = dist.sample_iter(&mut rng).take(n).collect();
+        let b: Vec<i64> = dist.sample_iter(&mut rng).take(n).collect();
+        let filter_val = dist.sample(&mut rng);
+
+        let t = "sxt.t".parse().unwrap();
+        let mut accessor = OwnedTableTestAccessor::<InnerProductProof>::new_empty_with_setup(());
+        accessor.add_table(
+            t,
+            owned_table([bigint("a", a.clone()), bigint("b", b.clone())]),
+            offset,
+        );
+        let expr = count_distinct(
+            column(t, "a", &accessor),
+            "n",
+            tab(t),
+            lte(column(t, "b", &accessor), const_bigint(filter_val)),
+        );
+        let res = VerifiableQueryResult::new(&expr, &accessor, &());
+        exercise_verification(&res, &expr, &accessor, t);
+        let res = res.verify(&expr, &accessor, &()).unwrap().table;
+
+        let expected = a
+            .iter()
+            .zip(&b)
+            .filter(|(_, b)| **b <= filter_val)
+            .map(|(a, _)| a)
+            .collect::<HashSet<_>>()
+            .len() as i64;
+        assert_eq!(res, owned_table([bigint("n", [expected])]));
+    }
+}
+
+#[test]
+fn we_can_query_random_tables_using_a_zero_offset() {
+    test_random_tables_with_given_offset(0);
+}
+
+#[test]
+fn we_can_query_random_tables_using_a_non_zero_offset() {
+    test_random_tables_with_given_offset(121);
+}
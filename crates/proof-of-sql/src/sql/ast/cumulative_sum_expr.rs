@@ -0,0 +1,289 @@
+use super::{ColumnExpr, ProvableExpr, TableExpr};
+use crate::{
+    base::{
+        commitment::Commitment,
+        database::{
+            ColumnField, ColumnRef, ColumnType, CommitmentAccessor, MetadataAccessor, OwnedTable,
+        },
+        proof::ProofError,
+        scalar::Scalar,
+    },
+    sql::{
+        parse::{ConversionError, ConversionResult},
+        proof::{CountBuilder, ProofExecutionPlan, VerificationBuilder},
+    },
+};
+#[cfg(feature = "prover")]
+use crate::{
+    base::{
+        database::{Column, DataAccessor},
+        slice_ops, MleAllocator,
+    },
+    sql::proof::{Indexes, ProofBuilder, ProverEvaluate, ResultBuilder, SumcheckSubpolynomialType},
+};
+use indexmap::IndexSet;
+use num_traits::{One, Zero};
+use proof_of_sql_parser::Identifier;
+use serde::{Deserialize, Serialize};
+
+/// Provable expressions for queries of the form
+/// ```ignore
+///     SELECT SUM(<column>) OVER (ROWS UNBOUNDED PRECEDING) AS <alias> FROM <table>
+/// ```
+/// where the rows are taken in the order of the table, e.g. the balances of a ledger.
+///
+/// The result is an `Int128` column holding the running sum of the column, so it cannot overflow.
+///
+/// The first row of the result is the first row of the column, and every other row satisfies
+/// `out_i - out_{i-1} = src_i`. The latter is shown with a multiset argument: the pairs
+/// `(i + 1, out_i)` of all rows but the last are the pairs `(i, out_i - src_i)` of all rows but
+/// the first. The verifier evaluates the MLE of the row indexes itself.
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct CumulativeSumExpr<C: Commitment> {
+    pub(super) column: ColumnExpr<C>,
+    pub(super) table: TableExpr,
+    pub(super) alias: Identifier,
+}
+
+impl<C: Commitment> CumulativeSumExpr<C> {
+    /// Creates a new cumulative sum expression.
+    ///
+    /// The column must be a `SmallInt`, `Int` or `BigInt` column of `table`.
+    pub fn try_new(
+        column: ColumnExpr<C>,
+        table: TableExpr,
+        alias: Identifier,
+    ) -> ConversionResult<Self> {
+        match column.data_type() {
+            ColumnType::SmallInt | ColumnType::Int | ColumnType::BigInt => {}
+            data_type => {
+                return Err(ConversionError::InvalidDataType {
+                    expected: ColumnType::BigInt,
+                    actual: data_type,
+                })
+            }
+        }
+        if column.get_column_reference().table_ref() != table.table_ref {
+            return Err(ConversionError::InvalidExpression(
+                "the summed column must belong to the table".to_string(),
+            ));
+        }
+        Ok(Self {
+            column,
+            table,
+            alias,
+        })
+    }
+}
+
+impl<C: Commitment> ProofExecutionPlan<C> for CumulativeSumExpr<C> {
+    fn count(
+        &self,
+        builder: &mut CountBuilder,
+        _accessor: &dyn MetadataAccessor,
+    ) -> Result<(), ProofError> {
+        self.column.count(builder)?;
+        builder.count_result_columns(1);
+        builder.count_post_result_challenges(2);
+        builder.count_intermediate_mles(2);
+        builder.count_subpolynomials(4);
+        builder.count_degree(3);
+        Ok(())
+    }
+
+    fn get_length(&self, accessor: &dyn MetadataAccessor) -> usize {
+        accessor.get_length(self.table.table_ref)
+    }
+
+    fn get_offset(&self, accessor: &dyn MetadataAccessor) -> usize {
+        accessor.get_offset(self.table.table_ref)
+    }
+
+    fn verifier_evaluate(
+        &self,
+        builder: &mut VerificationBuilder<C>,
+        accessor: &dyn CommitmentAccessor<C>,
+        _result: Option<&OwnedTable<C::Scalar>>,
+    ) -> Result<(), ProofError> {
+        let length = accessor.get_length(self.table.table_ref);
+        let one_eval = builder.mle_evaluations.one_evaluation;
+        let rand_eval = builder.mle_evaluations.random_evaluation;
+        let first_row_eval = builder.mle_evaluations.truncated_one_evaluation(1);
+        let pairs_eval = builder
+            .mle_evaluations
+            .truncated_one_evaluation(length.saturating_sub(1));
+        // The MLE of the row indexes, `sum 2^i * x_i`
+        let index_eval = builder
+            .mle_evaluations
+            .evaluation_point
+            .iter()
+            .rev()
+            .fold(C::Scalar::zero(), |acc, &x| acc + acc + x);
+
+        // 1. column
+        let src = self.column.verifier_evaluate(builder, accessor)?;
+        // 2. indexes
+        let chi_eval = builder
+            .mle_evaluations
+            .result_indexes_evaluation
+            .ok_or(ProofError::VerificationError("invalid indexes"))?;
+        if chi_eval != one_eval {
+            return Err(ProofError::VerificationError(
+                "a cumulative sum must have a row for every row of the table",
+            ));
+        }
+        // 3. result
+        let out = builder.consume_result_mle();
+
+        // 4. witness
+        let gamma = builder.consume_post_result_challenge();
+        let delta = builder.consume_post_result_challenge();
+        let out_star = builder.consume_intermediate_mle();
+        let previous_star = builder.consume_intermediate_mle();
+
+        // chi_0 * (out - src) = 0
+        builder
+            .produce_sumcheck_subpolynomial_evaluation(&(rand_eval * first_row_eval * (out - src)));
+        // (gamma + 1 + index + delta * out) * out_star - 1 = 0
+        builder.produce_sumcheck_subpolynomial_evaluation(
+            &(rand_eval
+                * ((gamma + C::Scalar::one() + index_eval + delta * out) * out_star - one_eval)),
+        );
+        // (gamma + index + delta * (out - src)) * previous_star - 1 = 0
+        builder.produce_sumcheck_subpolynomial_evaluation(
+            &(rand_eval * ((gamma + index_eval + delta * (out - src)) * previous_star - one_eval)),
+        );
+        // sum chi_{n-1} * out_star - (chi_n - chi_0) * previous_star = 0
+        builder.produce_sumcheck_subpolynomial_evaluation(
+            &(pairs_eval * out_star - one_eval * previous_star + first_row_eval * previous_star),
+        );
+        Ok(())
+    }
+
+    fn get_column_result_fields(&self) -> Vec<ColumnField> {
+        vec![ColumnField::new(self.alias, ColumnType::Int128)]
+    }
+
+    fn get_column_references(&self) -> IndexSet<ColumnRef> {
+        let mut columns = IndexSet::new();
+        self.column.get_column_references(&mut columns);
+        columns
+    }
+}
+
+#[cfg(feature = "prover")]
+impl<C: Commitment> ProverEvaluate<C::Scalar> for CumulativeSumExpr<C> {
+    #[tracing::instrument(name = "CumulativeSumExpr::result_evaluate", level = "debug", skip_all)]
+    fn result_evaluate<'a>(
+        &self,
+        builder: &mut ResultBuilder<'a>,
+        alloc: &'a dyn MleAllocator,
+        accessor: &'a dyn DataAccessor<C::Scalar>,
+    ) {
+        let n = builder.table_length();
+        let column = self.column.result_evaluate(n, alloc, accessor);
+        let result = running_sum(&column, alloc);
+        builder.set_result_indexes(Indexes::Dense(0..(n as u64)));
+        builder.produce_result_column(result as &[_]);
+        builder.request_post_result_challenges(2);
+    }
+
+    #[tracing::instrument(name = "CumulativeSumExpr::prover_evaluate", level = "debug", skip_all)]
+    fn prover_evaluate<'a>(
+        &self,
+        builder: &mut ProofBuilder<'a, C::Scalar>,
+        alloc: &'a dyn MleAllocator,
+        accessor: &'a dyn DataAccessor<C::Scalar>,
+    ) {
+        let n = builder.table_length();
+        let one = C::Scalar::one();
+
+        // 1. column
+        let src_column = self.column.prover_evaluate(builder, alloc, accessor);
+        let src = alloc.alloc_slice_copy(&src_column.to_scalar_with_scaling(0)) as &[_];
+        let out = running_sum(&src_column, alloc);
+
+        // 2. witness
+        let gamma = builder.consume_post_result_challenge();
+        let delta = builder.consume_post_result_challenge();
+        let index = alloc.alloc_slice_fill_with(1 << builder.num_sumcheck_variables(), |i| {
+            C::Scalar::from(i as i64)
+        }) as &[_];
+        let out_star = alloc.alloc_slice_fill_with(n, |i| {
+            gamma + one + index[i] + delta * C::Scalar::from(out[i])
+        });
+        slice_ops::batch_inversion(out_star);
+        let previous_star = alloc.alloc_slice_fill_with(n, |i| {
+            gamma + index[i] + delta * (C::Scalar::from(out[i]) - src[i])
+        });
+        slice_ops::batch_inversion(previous_star);
+        let (out_star, previous_star) = (&*out_star, &*previous_star);
+        let chi_table = alloc.alloc_slice_fill_copy(n, true) as &[_];
+        let chi_first_row = alloc.alloc_slice_fill_copy(1, true) as &[_];
+        let chi_pairs = alloc.alloc_slice_fill_copy(n.saturating_sub(1), true) as &[_];
+
+        builder.produce_intermediate_mle(out_star);
+        builder.produce_intermediate_mle(previous_star);
+
+        // chi_0 * (out - src) = 0
+        builder.produce_sumcheck_subpolynomial(
+            SumcheckSubpolynomialType::Identity,
+            vec![
+                (one, vec![Box::new(chi_first_row), Box::new(out)]),
+                (-one, vec![Box::new(chi_first_row), Box::new(src)]),
+            ],
+        );
+        // (gamma + 1 + index + delta * out) * out_star - 1 = 0
+        builder.produce_sumcheck_subpolynomial(
+            SumcheckSubpolynomialType::Identity,
+            vec![
+                (gamma + one, vec![Box::new(out_star)]),
+                (one, vec![Box::new(index), Box::new(out_star)]),
+                (delta, vec![Box::new(out), Box::new(out_star)]),
+                (-one, vec![]),
+            ],
+        );
+        // (gamma + index + delta * (out - src)) * previous_star - 1 = 0
+        builder.produce_sumcheck_subpolynomial(
+            SumcheckSubpolynomialType::Identity,
+            vec![
+                (gamma, vec![Box::new(previous_star)]),
+                (one, vec![Box::new(index), Box::new(previous_star)]),
+                (delta, vec![Box::new(out), Box::new(previous_star)]),
+                (-delta, vec![Box::new(src), Box::new(previous_star)]),
+                (-one, vec![]),
+            ],
+        );
+        // sum chi_{n-1} * out_star - (chi_n - chi_0) * previous_star = 0
+        builder.produce_sumcheck_subpolynomial(
+            SumcheckSubpolynomialType::ZeroSum,
+            vec![
+                (one, vec![Box::new(chi_pairs), Box::new(out_star)]),
+                (-one, vec![Box::new(chi_table), Box::new(previous_star)]),
+                (one, vec![Box::new(chi_first_row), Box::new(previous_star)]),
+            ],
+        );
+    }
+}
+
+/// Computes the running sum of an integer column.
+///
+/// # Panics
+/// Panics if the column is not a `SmallInt`, `Int` or `BigInt` column.
+#[cfg(feature = "prover")]
+fn running_sum<'a, S: Scalar>(column: &Column<'a, S>, alloc: &'a dyn MleAllocator) -> &'a [i128] {
+    let values: Vec<i128> = match column {
+        Column::SmallInt(col) => col.iter().map(|&v| v.into()).collect(),
+        Column::Int(col) => col.iter().map(|&v| v.into()).collect(),
+        Column::BigInt(col) => col.iter().map(|&v| v.into()).collect(),
+        _ => panic!("Cumulative sums are only supported on SmallInt, Int and BigInt columns"),
+    };
+    let sums: Vec<i128> = values
+        .into_iter()
+        .scan(0i128, |sum, value| {
+            *sum += value;
+            Some(*sum)
+        })
+        .collect();
+    alloc.alloc_slice_copy(&sums)
+}
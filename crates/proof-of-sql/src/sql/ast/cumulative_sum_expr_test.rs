@@ -0,0 +1,116 @@
+use super::{test_utility::*, CumulativeSumExpr};
+use crate::{
+    base::{
+        commitment::InnerProductProof,
+        database::{owned_table_utility::*, ColumnType, OwnedTableTestAccessor, TestAccessor},
+    },
+    sql::{
+        parse::ConversionError,
+        proof::{exercise_verification, VerifiableQueryResult},
+    },
+};
+use curve25519_dalek::ristretto::RistrettoPoint;
+use rand::{
+    distributions::{Distribution, Uniform},
+    rngs::StdRng,
+};
+use rand_core::SeedableRng;
+
+/// select sum(amount) over (rows unbounded preceding) as balance from sxt.t
+#[test]
+fn we_can_prove_the_running_balance_of_a_ledger() {
+    let t = "sxt.t".parse().unwrap();
+    let mut accessor = OwnedTableTestAccessor::<InnerProductProof>::new_empty_with_setup(());
+    accessor.add_table(
+        t,
+        owned_table([bigint("amount", [100, -30, 5, -75, 42])]),
+        0,
+    );
+    let expr = cumulative_sum(col_expr(t, "amount", &accessor), tab(t), "balance");
+    let res = VerifiableQueryResult::new(&expr, &accessor, &());
+    exercise_verification(&res, &expr, &accessor, t);
+    let res = res.verify(&expr, &accessor, &()).unwrap().table;
+    assert_eq!(res, owned_table([int128("balance", [100, 70, 75, 0, 42])]));
+}
+
+/// select sum(a) over (rows unbounded preceding) as s from sxt.t
+#[test]
+fn the_running_sum_of_large_values_does_not_overflow() {
+    let t = "sxt.t".parse().unwrap();
+    let mut accessor = OwnedTableTestAccessor::<InnerProductProof>::new_empty_with_setup(());
+    accessor.add_table(t, owned_table([bigint("a", [i64::MAX, i64::MAX, 1])]), 3);
+    let expr = cumulative_sum(col_expr(t, "a", &accessor), tab(t), "s");
+    let res = VerifiableQueryResult::new(&expr, &accessor, &());
+    exercise_verification(&res, &expr, &accessor, t);
+    let res = res.verify(&expr, &accessor, &()).unwrap().table;
+    let max = i128::from(i64::MAX);
+    assert_eq!(res, owned_table([int128("s", [max, 2 * max, 2 * max + 1])]));
+}
+
+/// select sum(a) over (rows unbounded preceding) as s from sxt.t
+#[test]
+fn we_can_prove_the_running_sum_of_a_single_row() {
+    let t = "sxt.t".parse().unwrap();
+    let mut accessor = OwnedTableTestAccessor::<InnerProductProof>::new_empty_with_setup(());
+    accessor.add_table(t, owned_table([smallint("a", [-7_i16])]), 0);
+    let expr = cumulative_sum(col_expr(t, "a", &accessor), tab(t), "s");
+    let res = VerifiableQueryResult::new(&expr, &accessor, &());
+    exercise_verification(&res, &expr, &accessor, t);
+    let res = res.verify(&expr, &accessor, &()).unwrap().table;
+    assert_eq!(res, owned_table([int128("s", [-7])]));
+}
+
+#[test]
+fn we_cannot_create_a_running_sum_of_a_non_integer_column() {
+    let t = "sxt.t".parse().unwrap();
+    let mut accessor = OwnedTableTestAccessor::<InnerProductProof>::new_empty_with_setup(());
+    accessor.add_table(t, owned_table([varchar("a", ["x", "y"])]), 0);
+    let res = CumulativeSumExpr::<RistrettoPoint>::try_new(
+        col_expr(t, "a", &accessor),
+        tab(t),
+        "s".parse().unwrap(),
+    );
+    assert!(matches!(
+        res,
+        Err(ConversionError::InvalidDataType {
+            actual: ColumnType::VarChar,
+            ..
+        })
+    ));
+}
+
+fn test_random_tables_with_given_offset(offset: usize) {
+    let dist = Uniform::new(-1000, 1000);
+    let mut rng = StdRng::from_seed([0u8; 32]);
+    for _ in 0..20 {
+        let n = Uniform::new(1, 21).sample(&mut rng);
+        let a: Vec<i64> = dist.sample_iter(&mut rng).take(n).collect();
+
+        let t = "sxt.t".parse().unwrap();
+        let mut accessor = OwnedTableTestAccessor::<InnerProductProof>::new_empty_with_setup(());
+        accessor.add_table(t, owned_table([bigint("a", a.clone())]), offset);
+        let expr = cumulative_sum(col_expr(t, "a", &accessor), tab(t), "s");
+        let res = VerifiableQueryResult::new(&expr, &accessor, &());
+        exercise_verification(&res, &expr, &accessor, t);
+        let res = res.verify(&expr, &accessor, &()).unwrap().table;
+
+        let expected: Vec<i128> = a
+            .iter()
+            .scan(0i128, |sum, &value| {
+                *sum += i128::from(value);
+                Some(*sum)
+            })
+            .collect();
+        assert_eq!(res, owned_table([int128("s", expected)]));
+    }
+}
+
+#[test]
+fn we_can_query_random_tables_using_a_zero_offset() {
+    test_random_tables_with_given_offset(0);
+}
+
+#[test]
+fn we_can_query_random_tables_using_a_non_zero_offset() {
+    test_random_tables_with_given_offset(121);
+}
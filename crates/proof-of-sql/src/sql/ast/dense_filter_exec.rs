@@ -1,25 +1,29 @@
-use super::{
-    fold_columns, fold_vals, AliasedProvableExprPlan, ProvableExpr, ProvableExprPlan, TableExpr,
-};
+#[cfg(feature = "prover")]
+use super::fold_columns;
+use super::{fold_vals, AliasedProvableExprPlan, ProvableExpr, ProvableExprPlan, TableExpr};
 use crate::{
     base::{
         commitment::Commitment,
-        database::{
-            filter_util::filter_columns, Column, ColumnField, ColumnRef, CommitmentAccessor,
-            DataAccessor, MetadataAccessor, OwnedTable,
-        },
+        database::{ColumnField, ColumnRef, CommitmentAccessor, MetadataAccessor, OwnedTable},
         proof::ProofError,
         scalar::Scalar,
-        slice_ops,
     },
     sql::proof::{
-        CountBuilder, HonestProver, Indexes, ProofBuilder, ProofExecutionPlan, ProverEvaluate,
-        ProverHonestyMarker, ResultBuilder, SumcheckSubpolynomialType, VerificationBuilder,
+        CountBuilder, HonestProver, ProofExecutionPlan, ProverEvaluate, ProverHonestyMarker,
+        VerificationBuilder,
+    },
+};
+#[cfg(feature = "prover")]
+use crate::{
+    base::{
+        database::{filter_util::filter_columns, Column, DataAccessor},
+        slice_ops, MleAllocator,
     },
+    sql::proof::{Indexes, ProofBuilder, ResultBuilder, SumcheckSubpolynomialType},
 };
-use bumpalo::Bump;
 use core::{iter::repeat_with, marker::PhantomData};
 use indexmap::IndexSet;
+#[cfg(feature = "prover")]
 use num_traits::{One, Zero};
 use serde::{Deserialize, Serialize};
 
@@ -145,12 +149,13 @@ where
 /// Alias for a dense filter expression with a honest prover.
 pub type DenseFilterExec<C> = OstensibleDenseFilterExec<C, HonestProver>;
 
+#[cfg(feature = "prover")]
 impl<C: Commitment> ProverEvaluate<C::Scalar> for DenseFilterExec<C> {
     #[tracing::instrument(name = "DenseFilterExec::result_evaluate", level = "debug", skip_all)]
     fn result_evaluate<'a>(
         &self,
         builder: &mut ResultBuilder<'a>,
-        alloc: &'a Bump,
+        alloc: &'a dyn MleAllocator,
         accessor: &'a dyn DataAccessor<C::Scalar>,
     ) {
         // 1. selection
@@ -183,7 +188,7 @@ impl<C: Commitment> ProverEvaluate<C::Scalar> for DenseFilterExec<C> {
     fn prover_evaluate<'a>(
         &self,
         builder: &mut ProofBuilder<'a, C::Scalar>,
-        alloc: &'a Bump,
+        alloc: &'a dyn MleAllocator,
         accessor: &'a dyn DataAccessor<C::Scalar>,
     ) {
         // 1. selection
@@ -218,7 +223,7 @@ impl<C: Commitment> ProverEvaluate<C::Scalar> for DenseFilterExec<C> {
     }
 }
 
-fn verify_filter<C: Commitment>(
+pub(super) fn verify_filter<C: Commitment>(
     builder: &mut VerificationBuilder<C>,
     alpha: C::Scalar,
     beta: C::Scalar,
@@ -255,10 +260,11 @@ fn verify_filter<C: Commitment>(
     Ok(())
 }
 
+#[cfg(feature = "prover")]
 #[allow(clippy::too_many_arguments)]
 pub(super) fn prove_filter<'a, S: Scalar + 'a>(
     builder: &mut ProofBuilder<'a, S>,
-    alloc: &'a Bump,
+    alloc: &'a dyn MleAllocator,
     alpha: S,
     beta: S,
     c: &[Column<S>],
@@ -5,6 +5,7 @@ use crate::{
         database::{filter_util::*, Column, DataAccessor, OwnedTableTestAccessor, TestAccessor},
         proof::ProofError,
         scalar::Curve25519Scalar,
+        MleAllocator,
     },
     sql::{
         // Making this explicit to ensure that we don't accidentally use the
@@ -17,7 +18,6 @@ use crate::{
     },
 };
 use blitzar::proof::InnerProductProof;
-use bumpalo::Bump;
 use curve25519_dalek::ristretto::RistrettoPoint;
 use num_traits::One;
 
@@ -35,7 +35,7 @@ impl ProverEvaluate<Curve25519Scalar> for DishonestDenseFilterExec<RistrettoPoin
     fn result_evaluate<'a>(
         &self,
         builder: &mut ResultBuilder<'a>,
-        alloc: &'a Bump,
+        alloc: &'a dyn MleAllocator,
         accessor: &'a dyn DataAccessor<Curve25519Scalar>,
     ) {
         // 1. selection
@@ -72,7 +72,7 @@ impl ProverEvaluate<Curve25519Scalar> for DishonestDenseFilterExec<RistrettoPoin
     fn prover_evaluate<'a>(
         &self,
         builder: &mut ProofBuilder<'a, Curve25519Scalar>,
-        alloc: &'a Bump,
+        alloc: &'a dyn MleAllocator,
         accessor: &'a dyn DataAccessor<Curve25519Scalar>,
     ) {
         // 1. selection
@@ -109,7 +109,7 @@ impl ProverEvaluate<Curve25519Scalar> for DishonestDenseFilterExec<RistrettoPoin
 
 /// Tamper with the first element of the first column that is a Scalar. This could be changed for different types of tests.
 fn tamper_column<'a>(
-    alloc: &'a Bump,
+    alloc: &'a dyn MleAllocator,
     mut columns: Vec<Column<'a, Curve25519Scalar>>,
 ) -> Vec<Column<'a, Curve25519Scalar>> {
     for column in columns.iter_mut() {
@@ -0,0 +1,290 @@
+#[cfg(feature = "prover")]
+use super::prover_evaluate_sign;
+use super::{count_sign, verifier_evaluate_sign};
+use crate::{
+    base::{commitment::Commitment, proof::ProofError, scalar::Scalar},
+    sql::proof::{CountBuilder, VerificationBuilder},
+};
+#[cfg(feature = "prover")]
+use crate::{
+    base::{slice_ops, MleAllocator},
+    sql::proof::{ProofBuilder, SumcheckSubpolynomialType},
+};
+use num_traits::Inv;
+#[cfg(feature = "prover")]
+use num_traits::{One, Zero};
+#[cfg(feature = "prover")]
+use std::collections::BTreeMap;
+
+/// Count the argument of [`verifier_evaluate_distinct_chain`] and [`prove_distinct_chain`].
+pub(super) fn count_distinct_chain(builder: &mut CountBuilder) -> Result<(), ProofError> {
+    builder.count_intermediate_mles(9);
+    // boolean and indicator checks, the sum of the last pair, and the strict ordering
+    builder.count_subpolynomials(5);
+    // inverses, the chain and permutation arguments, and the non-zero multiplicities
+    builder.count_subpolynomials(6);
+    builder.count_degree(3);
+    count_sign(builder)?;
+    builder.count_subpolynomials(1);
+    Ok(())
+}
+
+/// Verify that the chain `hi` holds the distinct values `a` of the rows selected by `s`, and
+/// return the evaluations of the indicator of the rows of the chain and of the number of times
+/// each value of the chain is selected.
+///
+/// The prover commits to the left ends `lo` of the chain of pairs
+/// `(LOWER, k_1), (k_1, k_2), ..., (k_{m-1}, k_m)` of the distinct values `k_1 < ... < k_m`, laid
+/// out on the first rows, whose right ends are `hi`. Every pair must be strictly increasing, and
+/// the multiset of left ends (plus `k_m`) must equal the multiset of right ends (plus `LOWER`),
+/// which makes the pairs a single path. The selected rows are then shown to be a permutation, with
+/// multiplicities, of the right ends, each of which must occur at least once.
+///
+/// `lower_sentinel` must be smaller than any value, and `gamma` must be a post result challenge.
+pub(super) fn verifier_evaluate_distinct_chain<C: Commitment>(
+    builder: &mut VerificationBuilder<C>,
+    gamma: C::Scalar,
+    lower_sentinel: C::Scalar,
+    s: C::Scalar,
+    a: C::Scalar,
+    hi: C::Scalar,
+) -> Result<(C::Scalar, C::Scalar), ProofError> {
+    let one_eval = builder.mle_evaluations.one_evaluation;
+    let rand_eval = builder.mle_evaluations.random_evaluation;
+    let first_row_eval = builder.mle_evaluations.truncated_one_evaluation(1);
+
+    let p = builder.consume_intermediate_mle();
+    let e = builder.consume_intermediate_mle();
+    let lo = builder.consume_intermediate_mle();
+    let w = builder.consume_intermediate_mle();
+    let multiplicities = builder.consume_intermediate_mle();
+    let inverse_multiplicities = builder.consume_intermediate_mle();
+    let lo_star = builder.consume_intermediate_mle();
+    let hi_star = builder.consume_intermediate_mle();
+    let a_star = builder.consume_intermediate_mle();
+
+    // p - p * p = 0
+    builder.produce_sumcheck_subpolynomial_evaluation(&(rand_eval * (p - p * p)));
+    // e - e * e = 0
+    builder.produce_sumcheck_subpolynomial_evaluation(&(rand_eval * (e - e * e)));
+    // e - e * p = 0
+    builder.produce_sumcheck_subpolynomial_evaluation(&(rand_eval * (e - e * p)));
+    // sum e - chi_0 * p = 0
+    builder.produce_sumcheck_subpolynomial_evaluation(&(e - first_row_eval * p));
+    // w - p * (hi - lo - 1) = 0
+    builder.produce_sumcheck_subpolynomial_evaluation(&(rand_eval * (w - p * hi + p * lo + p)));
+
+    // (gamma + x) * x_star - 1 = 0
+    for (x, x_star) in [(lo, lo_star), (hi, hi_star), (a, a_star)] {
+        builder.produce_sumcheck_subpolynomial_evaluation(
+            &(rand_eval * ((gamma + x) * x_star - one_eval)),
+        );
+    }
+
+    // sum p * lo_star - p * hi_star + e * hi_star - 1 / (gamma + LOWER) * chi_0 * p = 0
+    let lower_term = lower_sentinel_inverse(gamma, lower_sentinel)?;
+    builder.produce_sumcheck_subpolynomial_evaluation(
+        &(p * lo_star - p * hi_star + e * hi_star - lower_term * first_row_eval * p),
+    );
+    // sum s * a_star - multiplicities * p * hi_star = 0
+    builder.produce_sumcheck_subpolynomial_evaluation(&(s * a_star - multiplicities * p * hi_star));
+    // multiplicities * inverse_multiplicities - p = 0
+    builder.produce_sumcheck_subpolynomial_evaluation(
+        &(rand_eval * (multiplicities * inverse_multiplicities - p)),
+    );
+
+    // sign(w) == 0
+    let sign = verifier_evaluate_sign(builder, w, one_eval)?;
+    builder.produce_sumcheck_subpolynomial_evaluation(&(rand_eval * sign));
+
+    Ok((p, multiplicities))
+}
+
+/// Prove that `distinct_values` holds the distinct `values` of the rows of `selection`, in
+/// increasing order on its first rows and zero afterwards, and return the indicator of the rows
+/// of the chain and the number of times each value of the chain is selected.
+///
+/// The columns must be of the table length of `builder`. `distinct_values` must already be
+/// committed to, or be a linear combination of committed columns, as must `values`.
+///
+/// # Panics
+/// Panics if a selected value is missing from `distinct_values`.
+#[cfg(feature = "prover")]
+pub(super) fn prove_distinct_chain<'a, S: Scalar>(
+    builder: &mut ProofBuilder<'a, S>,
+    alloc: &'a dyn MleAllocator,
+    gamma: S,
+    lower_sentinel: S,
+    selection: &'a [bool],
+    values: &'a [S],
+    distinct_values: &'a [S],
+) -> (&'a [bool], &'a [i64]) {
+    let n = builder.table_length();
+    let counts = count_selected_values(values, selection);
+    let m = counts.len();
+    let hi = distinct_values;
+    let p = alloc.alloc_slice_fill_with(n, |i| i < m) as &[_];
+    let e = alloc.alloc_slice_fill_with(n, |i| i + 1 == m) as &[_];
+    let lo = alloc.alloc_slice_fill_with(n, |i| match i {
+        0 if m > 0 => lower_sentinel,
+        i if i < m => hi[i - 1],
+        _ => S::zero(),
+    }) as &[_];
+    let w =
+        alloc.alloc_slice_fill_with(n, |i| S::from(p[i]) * (hi[i] - lo[i] - S::one())) as &[_];
+    let multiplicities =
+        alloc.alloc_slice_fill_with(n, |i| if i < m { counts[&hi[i]] } else { 0 }) as &[_];
+    let inverse_multiplicities = alloc.alloc_slice_fill_with(n, |i| S::from(multiplicities[i]));
+    slice_ops::batch_inversion(inverse_multiplicities);
+    let inverse_multiplicities = &*inverse_multiplicities;
+
+    let inverted = |x: &[S]| -> &'a [S] {
+        let res = alloc.alloc_slice_fill_with(n, |i| gamma + x[i]);
+        slice_ops::batch_inversion(res);
+        res
+    };
+    let lo_star = inverted(lo);
+    let hi_star = inverted(hi);
+    let a_star = inverted(values);
+
+    builder.produce_intermediate_mle(p);
+    builder.produce_intermediate_mle(e);
+    builder.produce_intermediate_mle(lo);
+    builder.produce_intermediate_mle(w);
+    builder.produce_intermediate_mle(multiplicities);
+    builder.produce_intermediate_mle(inverse_multiplicities);
+    builder.produce_intermediate_mle(lo_star);
+    builder.produce_intermediate_mle(hi_star);
+    builder.produce_intermediate_mle(a_star);
+
+    let one = S::one();
+    let chi_first_row = alloc.alloc_slice_fill_copy(1, true) as &[_];
+
+    // p - p * p = 0
+    builder.produce_sumcheck_subpolynomial(
+        SumcheckSubpolynomialType::Identity,
+        vec![
+            (one, vec![Box::new(p)]),
+            (-one, vec![Box::new(p), Box::new(p)]),
+        ],
+    );
+    // e - e * e = 0
+    builder.produce_sumcheck_subpolynomial(
+        SumcheckSubpolynomialType::Identity,
+        vec![
+            (one, vec![Box::new(e)]),
+            (-one, vec![Box::new(e), Box::new(e)]),
+        ],
+    );
+    // e - e * p = 0
+    builder.produce_sumcheck_subpolynomial(
+        SumcheckSubpolynomialType::Identity,
+        vec![
+            (one, vec![Box::new(e)]),
+            (-one, vec![Box::new(e), Box::new(p)]),
+        ],
+    );
+    // sum e - chi_0 * p = 0
+    builder.produce_sumcheck_subpolynomial(
+        SumcheckSubpolynomialType::ZeroSum,
+        vec![
+            (one, vec![Box::new(e)]),
+            (-one, vec![Box::new(chi_first_row), Box::new(p)]),
+        ],
+    );
+    // w - p * (hi - lo - 1) = 0
+    builder.produce_sumcheck_subpolynomial(
+        SumcheckSubpolynomialType::Identity,
+        vec![
+            (one, vec![Box::new(w)]),
+            (-one, vec![Box::new(p), Box::new(hi)]),
+            (one, vec![Box::new(p), Box::new(lo)]),
+            (one, vec![Box::new(p)]),
+        ],
+    );
+
+    // (gamma + x) * x_star - 1 = 0
+    for (x, x_star) in [(lo, lo_star), (hi, hi_star), (values, a_star)] {
+        builder.produce_sumcheck_subpolynomial(
+            SumcheckSubpolynomialType::Identity,
+            vec![
+                (gamma, vec![Box::new(x_star)]),
+                (one, vec![Box::new(x), Box::new(x_star)]),
+                (-one, vec![]),
+            ],
+        );
+    }
+
+    // sum p * lo_star - p * hi_star + e * hi_star - 1 / (gamma + LOWER) * chi_0 * p = 0
+    let lower_term = lower_sentinel_inverse(gamma, lower_sentinel)
+        .expect("challenges should never be degenerate");
+    builder.produce_sumcheck_subpolynomial(
+        SumcheckSubpolynomialType::ZeroSum,
+        vec![
+            (one, vec![Box::new(p), Box::new(lo_star)]),
+            (-one, vec![Box::new(p), Box::new(hi_star)]),
+            (one, vec![Box::new(e), Box::new(hi_star)]),
+            (-lower_term, vec![Box::new(chi_first_row), Box::new(p)]),
+        ],
+    );
+    // sum s * a_star - multiplicities * p * hi_star = 0
+    builder.produce_sumcheck_subpolynomial(
+        SumcheckSubpolynomialType::ZeroSum,
+        vec![
+            (one, vec![Box::new(selection), Box::new(a_star)]),
+            (
+                -one,
+                vec![Box::new(multiplicities), Box::new(p), Box::new(hi_star)],
+            ),
+        ],
+    );
+    // multiplicities * inverse_multiplicities - p = 0
+    builder.produce_sumcheck_subpolynomial(
+        SumcheckSubpolynomialType::Identity,
+        vec![
+            (
+                one,
+                vec![Box::new(multiplicities), Box::new(inverse_multiplicities)],
+            ),
+            (-one, vec![Box::new(p)]),
+        ],
+    );
+
+    // sign(w) == 0
+    let sign = prover_evaluate_sign(
+        builder,
+        alloc,
+        w,
+        #[cfg(test)]
+        false,
+    );
+    builder.produce_sumcheck_subpolynomial(
+        SumcheckSubpolynomialType::Identity,
+        vec![(one, vec![Box::new(sign)])],
+    );
+
+    (p, multiplicities)
+}
+
+/// `1 / (gamma + LOWER)`, the contribution of the first node of the chain.
+fn lower_sentinel_inverse<S: Scalar>(gamma: S, lower_sentinel: S) -> Result<S, ProofError> {
+    (gamma + lower_sentinel)
+        .inv()
+        .ok_or(ProofError::VerificationError(
+            "degenerate distinct chain challenge",
+        ))
+}
+
+/// Returns the distinct selected values, in increasing order, with the number of times each
+/// is selected.
+#[cfg(feature = "prover")]
+pub(super) fn count_selected_values<T: Ord + Copy>(
+    values: &[T],
+    selection: &[bool],
+) -> BTreeMap<T, i64> {
+    let mut counts = BTreeMap::new();
+    for (&value, _) in values.iter().zip(selection).filter(|(_, s)| **s) {
+        *counts.entry(value).or_insert(0) += 1;
+    }
+    counts
+}
@@ -0,0 +1,265 @@
+use super::{
+    count_range_check, count_sign, integer_type_bounds, range_bit_width,
+    verifier_evaluate_range_check, verifier_evaluate_sign, ProvableExpr, ProvableExprPlan,
+};
+#[cfg(feature = "prover")]
+use super::{prover_evaluate_range_check, prover_evaluate_sign};
+use crate::{
+    base::{
+        commitment::Commitment,
+        database::{ColumnRef, ColumnType, CommitmentAccessor},
+        proof::ProofError,
+        scalar::Scalar,
+    },
+    sql::proof::{CountBuilder, VerificationBuilder},
+};
+#[cfg(feature = "prover")]
+use crate::{
+    base::{
+        database::{Column, DataAccessor},
+        MleAllocator,
+    },
+    sql::proof::{ProofBuilder, SumcheckSubpolynomialType},
+};
+use indexmap::IndexSet;
+#[cfg(feature = "prover")]
+use num_traits::One;
+use serde::{Deserialize, Serialize};
+
+/// Provable integer division by a nonzero constant, `lhs / divisor`
+///
+/// The division is Euclidean: the quotient `q` and remainder `r` satisfy `lhs = q * divisor + r`
+/// with `0 <= r < |divisor|`. In particular, negative dividends round towards negative infinity
+/// when the divisor is positive, e.g. `-7 / 2 = -4` with remainder `1`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(try_from = "UncheckedDivExpr<C>")]
+pub struct DivExpr<C: Commitment> {
+    lhs: Box<ProvableExprPlan<C>>,
+    divisor: i64,
+}
+
+/// The serialized form of a [`DivExpr`], which is only accepted if the divisor is nonzero.
+#[derive(Deserialize)]
+struct UncheckedDivExpr<C: Commitment> {
+    lhs: Box<ProvableExprPlan<C>>,
+    divisor: i64,
+}
+
+impl<C: Commitment> TryFrom<UncheckedDivExpr<C>> for DivExpr<C> {
+    type Error = ProofError;
+
+    fn try_from(value: UncheckedDivExpr<C>) -> Result<Self, Self::Error> {
+        Self::try_new(value.lhs, value.divisor)
+    }
+}
+
+impl<C: Commitment> DivExpr<C> {
+    /// Create a new integer division expression
+    pub fn try_new(lhs: Box<ProvableExprPlan<C>>, divisor: i64) -> Result<Self, ProofError> {
+        if divisor == 0 {
+            return Err(ProofError::DivisionByZero);
+        }
+        Ok(Self { lhs, divisor })
+    }
+
+    /// The inclusive range of the quotient, whose magnitude is at most that of the dividend.
+    pub(super) fn quotient_bounds(&self) -> Result<(i128, i128), ProofError> {
+        let (min, max) = integer_type_bounds(self.lhs.data_type()).ok_or(
+            ProofError::VerificationError("division is only supported on integer expressions"),
+        )?;
+        Ok((min.min(-max), max.max(-min)))
+    }
+}
+
+impl<C: Commitment> ProvableExpr<C> for DivExpr<C> {
+    fn count(&self, builder: &mut CountBuilder) -> Result<(), ProofError> {
+        self.lhs.count(builder)?;
+        builder.count_intermediate_mles(2);
+        builder.count_subpolynomials(1);
+        builder.count_degree(2);
+        count_remainder(builder)?;
+        let quotient_bounds = self.quotient_bounds()?;
+        count_range_check(builder, Some(range_bit_width(quotient_bounds)))?;
+        Ok(())
+    }
+
+    fn data_type(&self) -> ColumnType {
+        ColumnType::Int128
+    }
+
+    #[cfg(feature = "prover")]
+    fn result_evaluate<'a>(
+        &self,
+        table_length: usize,
+        alloc: &'a dyn MleAllocator,
+        accessor: &'a dyn DataAccessor<C::Scalar>,
+    ) -> Column<'a, C::Scalar> {
+        let lhs_column: Column<'a, C::Scalar> =
+            self.lhs.result_evaluate(table_length, alloc, accessor);
+        let (quotient, _) = divide_column(&lhs_column, self.divisor, alloc);
+        Column::Int128(quotient)
+    }
+
+    #[cfg(feature = "prover")]
+    #[tracing::instrument(
+        name = "proofs.sql.ast.div_expr.prover_evaluate",
+        level = "info",
+        skip_all
+    )]
+    fn prover_evaluate<'a>(
+        &self,
+        builder: &mut ProofBuilder<'a, C::Scalar>,
+        alloc: &'a dyn MleAllocator,
+        accessor: &'a dyn DataAccessor<C::Scalar>,
+    ) -> Column<'a, C::Scalar> {
+        let lhs_column: Column<'a, C::Scalar> = self.lhs.prover_evaluate(builder, alloc, accessor);
+
+        // quotient and remainder
+        let (quotient, remainder) = divide_column(&lhs_column, self.divisor, alloc);
+        let quotient_scalars: &[C::Scalar] =
+            alloc.alloc_slice_fill_with(quotient.len(), |i| C::Scalar::from(quotient[i]));
+        let remainder_scalars: &[C::Scalar] =
+            alloc.alloc_slice_fill_with(remainder.len(), |i| C::Scalar::from(remainder[i]));
+        builder.produce_intermediate_mle(quotient_scalars);
+        builder.produce_intermediate_mle(remainder_scalars);
+
+        // subpolynomial: lhs - divisor * quotient - remainder
+        builder.produce_sumcheck_subpolynomial(
+            SumcheckSubpolynomialType::Identity,
+            vec![
+                (C::Scalar::one(), vec![Box::new(lhs_column)]),
+                (
+                    -C::Scalar::from(self.divisor),
+                    vec![Box::new(quotient_scalars)],
+                ),
+                (-C::Scalar::one(), vec![Box::new(remainder_scalars)]),
+            ],
+        );
+
+        // 0 <= remainder <= |divisor| - 1
+        let bound = C::Scalar::from(self.divisor.unsigned_abs() as i128 - 1);
+        let max_remainder = alloc.alloc_slice_fill_copy(remainder_scalars.len(), bound);
+        prove_remainder(builder, alloc, remainder_scalars, max_remainder);
+
+        // the quotient is an integer within the range of the dividend's magnitude
+        let quotient_bounds = self
+            .quotient_bounds()
+            .expect("the dividend is an integer expression");
+        prover_evaluate_range_check(builder, alloc, quotient_scalars, quotient_bounds);
+        Column::Int128(quotient)
+    }
+
+    fn verifier_evaluate(
+        &self,
+        builder: &mut VerificationBuilder<C>,
+        accessor: &dyn CommitmentAccessor<C>,
+    ) -> Result<C::Scalar, ProofError> {
+        let one_eval = builder.mle_evaluations.one_evaluation;
+        let rand_eval = builder.mle_evaluations.random_evaluation;
+        let lhs = self.lhs.verifier_evaluate(builder, accessor)?;
+
+        // quotient and remainder
+        let quotient = builder.consume_intermediate_mle();
+        let remainder = builder.consume_intermediate_mle();
+
+        // subpolynomial: lhs - divisor * quotient - remainder
+        let eval = rand_eval * (lhs - C::Scalar::from(self.divisor) * quotient - remainder);
+        builder.produce_sumcheck_subpolynomial_evaluation(&eval);
+
+        // 0 <= remainder <= |divisor| - 1
+        let bound = C::Scalar::from(self.divisor.unsigned_abs() as i128 - 1);
+        verifier_evaluate_remainder(builder, remainder, bound * one_eval)?;
+
+        // the quotient is an integer within the range of the dividend's magnitude
+        verifier_evaluate_range_check(builder, quotient, self.quotient_bounds()?)?;
+
+        Ok(quotient)
+    }
+
+    fn get_column_references(&self, columns: &mut IndexSet<ColumnRef>) {
+        self.lhs.get_column_references(columns);
+    }
+}
+
+/// Count the checks of [`verifier_evaluate_remainder`] and [`prove_remainder`].
+pub(super) fn count_remainder(builder: &mut CountBuilder) -> Result<(), ProofError> {
+    count_sign(builder)?;
+    count_sign(builder)?;
+    builder.count_subpolynomials(2);
+    Ok(())
+}
+
+/// Verify that `0 <= remainder <= max_remainder`, which bounds the remainder of a Euclidean
+/// division when `max_remainder` is one less than the magnitude of the divisor.
+pub(super) fn verifier_evaluate_remainder<C: Commitment>(
+    builder: &mut VerificationBuilder<C>,
+    remainder: C::Scalar,
+    max_remainder: C::Scalar,
+) -> Result<(), ProofError> {
+    let one_eval = builder.mle_evaluations.one_evaluation;
+    let rand_eval = builder.mle_evaluations.random_evaluation;
+
+    // sign(remainder) == 0
+    let sign = verifier_evaluate_sign(builder, remainder, one_eval)?;
+    builder.produce_sumcheck_subpolynomial_evaluation(&(rand_eval * sign));
+
+    // sign(max_remainder - remainder) == 0
+    let sign = verifier_evaluate_sign(builder, max_remainder - remainder, one_eval)?;
+    builder.produce_sumcheck_subpolynomial_evaluation(&(rand_eval * sign));
+    Ok(())
+}
+
+/// Prove that `0 <= remainder <= max_remainder` on every row.
+#[cfg(feature = "prover")]
+pub(super) fn prove_remainder<'a, S: Scalar>(
+    builder: &mut ProofBuilder<'a, S>,
+    alloc: &'a dyn MleAllocator,
+    remainder: &'a [S],
+    max_remainder: &'a [S],
+) {
+    // sign(remainder) == 0
+    let sign = prover_evaluate_sign(
+        builder,
+        alloc,
+        remainder,
+        #[cfg(test)]
+        false,
+    );
+    builder.produce_sumcheck_subpolynomial(
+        SumcheckSubpolynomialType::Identity,
+        vec![(S::one(), vec![Box::new(sign)])],
+    );
+
+    // sign(max_remainder - remainder) == 0
+    let slack = alloc.alloc_slice_fill_with(remainder.len(), |i| max_remainder[i] - remainder[i]);
+    let sign = prover_evaluate_sign(
+        builder,
+        alloc,
+        slack,
+        #[cfg(test)]
+        false,
+    );
+    builder.produce_sumcheck_subpolynomial(
+        SumcheckSubpolynomialType::Identity,
+        vec![(S::one(), vec![Box::new(sign)])],
+    );
+}
+
+/// Computes the Euclidean quotient and remainder of each entry of an integer column.
+#[cfg(feature = "prover")]
+fn divide_column<'a, S: Scalar>(
+    column: &Column<'a, S>,
+    divisor: i64,
+    alloc: &'a dyn MleAllocator,
+) -> (&'a [i128], &'a [i128]) {
+    let values: &'a [i128] = match column {
+        Column::SmallInt(col) => alloc.alloc_slice_fill_iter(col.iter().map(|&v| v as i128)),
+        Column::Int(col) => alloc.alloc_slice_fill_iter(col.iter().map(|&v| v as i128)),
+        Column::BigInt(col) => alloc.alloc_slice_fill_iter(col.iter().map(|&v| v as i128)),
+        _ => panic!("Division is only supported on SmallInt, Int and BigInt columns"),
+    };
+    let divisor = divisor as i128;
+    let quotient = alloc.alloc_slice_fill_iter(values.iter().map(|v| v.div_euclid(divisor)));
+    let remainder = alloc.alloc_slice_fill_iter(values.iter().map(|v| v.rem_euclid(divisor)));
+    (quotient, remainder)
+}
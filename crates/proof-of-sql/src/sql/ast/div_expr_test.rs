@@ -0,0 +1,279 @@
+use super::{div_expr::prove_remainder, prover_evaluate_range_check, DivExpr, ProvableExpr};
+use crate::{
+    base::{
+        commitment::InnerProductProof,
+        database::{
+            owned_table_utility::*, Column, ColumnField, ColumnRef, ColumnType, CommitmentAccessor,
+            DataAccessor, MetadataAccessor, OwnedTable, OwnedTableTestAccessor,
+        },
+        proof::ProofError,
+        scalar::Curve25519Scalar,
+        MleAllocator,
+    },
+    sql::{
+        ast::{test_utility::*, ProvableExprPlan},
+        parse::ConversionError,
+        proof::{
+            exercise_verification, CountBuilder, Indexes, ProofBuilder, ProofExecutionPlan,
+            ProverEvaluate, QueryProof, ResultBuilder, SumcheckSubpolynomialType,
+            VerifiableQueryResult, VerificationBuilder,
+        },
+    },
+};
+use curve25519_dalek::ristretto::RistrettoPoint;
+use indexmap::IndexSet;
+use num_traits::{Inv, One, Zero};
+use rand::{
+    distributions::{Distribution, Uniform},
+    rngs::StdRng,
+    Rng,
+};
+use rand_core::SeedableRng;
+use serde::Serialize;
+
+/// Returns `sxt.t.a` and proves `a / divisor` with a forged witness: each remainder is zero, and
+/// each quotient is the field element `a / divisor`, which satisfies `a = q * divisor + r` even
+/// when the division is not exact.
+#[derive(Debug, Serialize)]
+struct ForgedQuotientTestProofExecutionPlan {
+    lhs: ProvableExprPlan<RistrettoPoint>,
+    div: DivExpr<RistrettoPoint>,
+    divisor: i64,
+}
+impl ForgedQuotientTestProofExecutionPlan {
+    fn new(lhs: ProvableExprPlan<RistrettoPoint>, divisor: i64) -> Self {
+        let div = DivExpr::try_new(Box::new(lhs.clone()), divisor).unwrap();
+        Self { lhs, div, divisor }
+    }
+}
+impl ProverEvaluate<Curve25519Scalar> for ForgedQuotientTestProofExecutionPlan {
+    fn result_evaluate<'a>(
+        &self,
+        builder: &mut ResultBuilder<'a>,
+        alloc: &'a dyn MleAllocator,
+        accessor: &'a dyn DataAccessor<Curve25519Scalar>,
+    ) {
+        let a = self
+            .lhs
+            .result_evaluate(builder.table_length(), alloc, accessor);
+        builder.set_result_indexes(Indexes::Dense(0..a.len() as u64));
+        builder.produce_result_column(a);
+    }
+
+    fn prover_evaluate<'a>(
+        &self,
+        builder: &mut ProofBuilder<'a, Curve25519Scalar>,
+        alloc: &'a dyn MleAllocator,
+        accessor: &'a dyn DataAccessor<Curve25519Scalar>,
+    ) {
+        let lhs_column = self.lhs.prover_evaluate(builder, alloc, accessor);
+        let a = match &lhs_column {
+            Column::BigInt(a) => *a,
+            _ => panic!("the dividend is a bigint column"),
+        };
+
+        // forged quotient and remainder
+        let divisor = Curve25519Scalar::from(self.divisor);
+        let divisor_inverse = divisor.inv().unwrap();
+        let quotient: &'a [Curve25519Scalar] = alloc
+            .alloc_slice_fill_with(a.len(), |i| Curve25519Scalar::from(a[i]) * divisor_inverse);
+        let remainder: &'a [Curve25519Scalar] =
+            alloc.alloc_slice_fill_copy(a.len(), Curve25519Scalar::zero());
+        builder.produce_intermediate_mle(quotient);
+        builder.produce_intermediate_mle(remainder);
+
+        // the same constraints as `DivExpr::prover_evaluate`
+        builder.produce_sumcheck_subpolynomial(
+            SumcheckSubpolynomialType::Identity,
+            vec![
+                (Curve25519Scalar::one(), vec![Box::new(lhs_column)]),
+                (-divisor, vec![Box::new(quotient)]),
+                (-Curve25519Scalar::one(), vec![Box::new(remainder)]),
+            ],
+        );
+        let bound = Curve25519Scalar::from(self.divisor.unsigned_abs() as i128 - 1);
+        let max_remainder = alloc.alloc_slice_fill_copy(a.len(), bound);
+        prove_remainder(builder, alloc, remainder, max_remainder);
+        prover_evaluate_range_check(
+            builder,
+            alloc,
+            quotient,
+            self.div.quotient_bounds().unwrap(),
+        );
+    }
+}
+impl ProofExecutionPlan<RistrettoPoint> for ForgedQuotientTestProofExecutionPlan {
+    fn count(
+        &self,
+        builder: &mut CountBuilder,
+        _accessor: &dyn MetadataAccessor,
+    ) -> Result<(), ProofError> {
+        builder.count_result_columns(1);
+        self.div.count(builder)
+    }
+    fn get_length(&self, accessor: &dyn MetadataAccessor) -> usize {
+        accessor.get_length("sxt.t".parse().unwrap())
+    }
+    fn get_offset(&self, accessor: &dyn MetadataAccessor) -> usize {
+        accessor.get_offset("sxt.t".parse().unwrap())
+    }
+    fn verifier_evaluate(
+        &self,
+        builder: &mut VerificationBuilder<RistrettoPoint>,
+        accessor: &dyn CommitmentAccessor<RistrettoPoint>,
+        _result: Option<&OwnedTable<Curve25519Scalar>>,
+    ) -> Result<(), ProofError> {
+        builder.consume_result_mle();
+        self.div.verifier_evaluate(builder, accessor)?;
+        Ok(())
+    }
+    fn get_column_result_fields(&self) -> Vec<ColumnField> {
+        vec![ColumnField::new("a".parse().unwrap(), ColumnType::BigInt)]
+    }
+    fn get_column_references(&self) -> IndexSet<ColumnRef> {
+        let mut columns = IndexSet::new();
+        self.lhs.get_column_references(&mut columns);
+        columns
+    }
+}
+
+// select a / 2 as q, a / -3 as r from sxt.t where b <= 5
+//
+// Division is Euclidean, so the remainder is always non-negative: e.g. -7 / 2 = -4 and
+// -7 / -3 = 3.
+#[test]
+fn we_can_prove_a_typical_div_query_with_negative_dividends() {
+    let data = owned_table([
+        bigint("a", [-7_i64, 7, -1, 0, 8, -6]),
+        bigint("b", [1_i64, 2, 3, 4, 5, 6]),
+    ]);
+    let t = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(t, data, 0, ());
+    let ast = dense_filter(
+        vec![
+            aliased_plan(divide(column(t, "a", &accessor), 2), "q"),
+            aliased_plan(divide(column(t, "a", &accessor), -3), "r"),
+        ],
+        tab(t),
+        lte(column(t, "b", &accessor), const_bigint(5)),
+    );
+    let verifiable_res = VerifiableQueryResult::new(&ast, &accessor, &());
+    exercise_verification(&verifiable_res, &ast, &accessor, t);
+    let res = verifiable_res.verify(&ast, &accessor, &()).unwrap().table;
+    let expected_res = owned_table([
+        int128("q", [-4_i128, 3, -1, 0, 4]),
+        int128("r", [3_i128, -2, 1, 0, -2]),
+    ]);
+    assert_eq!(res, expected_res);
+}
+
+// The prover replaces the remainder 1 of 7 / 3 with 0, and the quotient with the field element
+// 7 / 3. Both satisfy `a = q * 3 + r` with `r` in range, so only the range check on `q` catches it.
+#[test]
+fn we_cannot_verify_a_div_whose_quotient_is_not_an_integer() {
+    let data = owned_table([bigint("a", [6_i64, 7])]);
+    let t = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(t, data, 0, ());
+    let expr = ForgedQuotientTestProofExecutionPlan::new(column(t, "a", &accessor), 3);
+    let (proof, result) = QueryProof::<InnerProductProof>::new(&expr, &accessor, &());
+    assert!(matches!(
+        proof.verify(&expr, &accessor, &result, &()),
+        Err(ProofError::VerificationError(_))
+    ));
+}
+
+// The same plan with exact divisions has an integer quotient, so the forged witness is the honest
+// one and verifies.
+#[test]
+fn we_can_verify_a_div_whose_forged_quotient_is_exact() {
+    let data = owned_table([bigint("a", [6_i64, -9])]);
+    let t = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(t, data, 0, ());
+    let expr = ForgedQuotientTestProofExecutionPlan::new(column(t, "a", &accessor), 3);
+    let (proof, result) = QueryProof::<InnerProductProof>::new(&expr, &accessor, &());
+    assert!(proof.verify(&expr, &accessor, &result, &()).is_ok());
+}
+
+#[test]
+fn we_cannot_divide_by_zero() {
+    let data = owned_table([bigint("a", [1_i64])]);
+    let t = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(t, data, 0, ());
+    assert!(matches!(
+        DivExpr::<RistrettoPoint>::try_new(Box::new(column(t, "a", &accessor)), 0),
+        Err(ProofError::DivisionByZero)
+    ));
+    assert!(matches!(
+        ProvableExprPlan::<RistrettoPoint>::try_new_divide(column(t, "a", &accessor), 0),
+        Err(ConversionError::InvalidExpression(_))
+    ));
+}
+
+#[test]
+fn we_cannot_divide_a_non_integer_expression() {
+    let data = owned_table([varchar("d", ["ab"])]);
+    let t = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(t, data, 0, ());
+    assert!(matches!(
+        ProvableExprPlan::<RistrettoPoint>::try_new_divide(column(t, "d", &accessor), 2),
+        Err(ConversionError::InvalidDataType { .. })
+    ));
+}
+
+fn test_random_tables_with_given_offset(offset: usize) {
+    let dist = Uniform::new(-3, 4);
+    let divisor_dist = Uniform::new(1, 5);
+    let mut rng = StdRng::from_seed([0u8; 32]);
+    for _ in 0..20 {
+        // Generate random table
+        let n = Uniform::new(1, 21).sample(&mut rng);
+        let data = owned_table([
+            bigint("a", dist.sample_iter(&mut rng).take(n)),
+            bigint("b", dist.sample_iter(&mut rng).take(n)),
+        ]);
+
+        // Generate random values to divide by and filter with
+        let divisor = divisor_dist.sample(&mut rng) * if rng.gen_bool(0.5) { 1 } else { -1 };
+        let filter_val = dist.sample(&mut rng);
+
+        // Create and verify proof
+        let t = "sxt.t".parse().unwrap();
+        let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(
+            t,
+            data.clone(),
+            offset,
+            (),
+        );
+        let ast = dense_filter(
+            vec![aliased_plan(
+                divide(column(t, "a", &accessor), divisor),
+                "q",
+            )],
+            tab(t),
+            lte(column(t, "b", &accessor), const_bigint(filter_val)),
+        );
+        let verifiable_res = VerifiableQueryResult::new(&ast, &accessor, &());
+        exercise_verification(&verifiable_res, &ast, &accessor, t);
+        let res = verifiable_res.verify(&ast, &accessor, &()).unwrap().table;
+
+        // Calculate/compare expected result
+        let expected_q: Vec<_> = data["a"]
+            .i64_iter()
+            .zip(data["b"].i64_iter())
+            .filter(|(_, b)| **b <= filter_val)
+            .map(|(a, _)| i128::from(*a).div_euclid(i128::from(divisor)))
+            .collect();
+        let expected_result = owned_table([int128("q", expected_q)]);
+        assert_eq!(expected_result, res)
+    }
+}
+
+#[test]
+fn we_can_query_random_tables_using_a_zero_offset() {
+    test_random_tables_with_given_offset(0);
+}
+
+#[test]
+fn we_can_query_random_tables_using_a_non_zero_offset() {
+    test_random_tables_with_given_offset(75);
+}
@@ -1,15 +1,23 @@
-use super::{scale_and_add_subtract_eval, scale_and_subtract, ProvableExpr, ProvableExprPlan};
+#[cfg(feature = "prover")]
+use super::scale_and_subtract;
+use super::{scale_and_add_subtract_eval, ProvableBooleanExpr, ProvableExpr, ProvableExprPlan};
 use crate::{
     base::{
         commitment::Commitment,
-        database::{Column, ColumnRef, ColumnType, CommitmentAccessor, DataAccessor},
+        database::{ColumnRef, ColumnType, CommitmentAccessor},
         proof::ProofError,
         scalar::Scalar,
-        slice_ops,
     },
-    sql::proof::{CountBuilder, ProofBuilder, SumcheckSubpolynomialType, VerificationBuilder},
+    sql::proof::{CountBuilder, VerificationBuilder},
+};
+#[cfg(feature = "prover")]
+use crate::{
+    base::{
+        database::{Column, DataAccessor},
+        slice_ops, MleAllocator,
+    },
+    sql::proof::{ProofBuilder, SumcheckSubpolynomialType},
 };
-use bumpalo::Bump;
 use indexmap::IndexSet;
 use serde::{Deserialize, Serialize};
 
@@ -39,39 +47,76 @@ impl<C: Commitment> ProvableExpr<C> for EqualsExpr<C> {
         ColumnType::Boolean
     }
 
+    #[cfg(feature = "prover")]
     #[tracing::instrument(name = "EqualsExpr::result_evaluate", level = "debug", skip_all)]
     fn result_evaluate<'a>(
         &self,
         table_length: usize,
-        alloc: &'a Bump,
+        alloc: &'a dyn MleAllocator,
+        accessor: &'a dyn DataAccessor<C::Scalar>,
+    ) -> Column<'a, C::Scalar> {
+        Column::Boolean(self.result_evaluate_selection(table_length, alloc, accessor))
+    }
+
+    #[cfg(feature = "prover")]
+    #[tracing::instrument(name = "EqualsExpr::prover_evaluate", level = "debug", skip_all)]
+    fn prover_evaluate<'a>(
+        &self,
+        builder: &mut ProofBuilder<'a, C::Scalar>,
+        alloc: &'a dyn MleAllocator,
         accessor: &'a dyn DataAccessor<C::Scalar>,
     ) -> Column<'a, C::Scalar> {
+        Column::Boolean(self.prover_evaluate_selection(builder, alloc, accessor))
+    }
+
+    fn verifier_evaluate(
+        &self,
+        builder: &mut VerificationBuilder<C>,
+        accessor: &dyn CommitmentAccessor<C>,
+    ) -> Result<C::Scalar, ProofError> {
+        self.verifier_evaluate_selection(builder, accessor)
+    }
+
+    fn get_column_references(&self, columns: &mut IndexSet<ColumnRef>) {
+        self.lhs.get_column_references(columns);
+        self.rhs.get_column_references(columns);
+    }
+}
+
+impl<C: Commitment> ProvableBooleanExpr<C> for EqualsExpr<C> {
+    #[cfg(feature = "prover")]
+    fn result_evaluate_selection<'a>(
+        &self,
+        table_length: usize,
+        alloc: &'a dyn MleAllocator,
+        accessor: &'a dyn DataAccessor<C::Scalar>,
+    ) -> &'a [bool] {
         let lhs_column = self.lhs.result_evaluate(table_length, alloc, accessor);
         let rhs_column = self.rhs.result_evaluate(table_length, alloc, accessor);
         let lhs_scale = self.lhs.data_type().scale().unwrap_or(0);
         let rhs_scale = self.rhs.data_type().scale().unwrap_or(0);
         let res = scale_and_subtract(alloc, lhs_column, rhs_column, lhs_scale, rhs_scale, true)
             .expect("Failed to scale and subtract");
-        Column::Boolean(result_evaluate_equals_zero(table_length, alloc, res))
+        result_evaluate_equals_zero(table_length, alloc, res)
     }
 
-    #[tracing::instrument(name = "EqualsExpr::prover_evaluate", level = "debug", skip_all)]
-    fn prover_evaluate<'a>(
+    #[cfg(feature = "prover")]
+    fn prover_evaluate_selection<'a>(
         &self,
         builder: &mut ProofBuilder<'a, C::Scalar>,
-        alloc: &'a Bump,
+        alloc: &'a dyn MleAllocator,
         accessor: &'a dyn DataAccessor<C::Scalar>,
-    ) -> Column<'a, C::Scalar> {
+    ) -> &'a [bool] {
         let lhs_column = self.lhs.prover_evaluate(builder, alloc, accessor);
         let rhs_column = self.rhs.prover_evaluate(builder, alloc, accessor);
         let lhs_scale = self.lhs.data_type().scale().unwrap_or(0);
         let rhs_scale = self.rhs.data_type().scale().unwrap_or(0);
         let res = scale_and_subtract(alloc, lhs_column, rhs_column, lhs_scale, rhs_scale, true)
             .expect("Failed to scale and subtract");
-        Column::Boolean(prover_evaluate_equals_zero(builder, alloc, res))
+        prover_evaluate_equals_zero(builder, alloc, res)
     }
 
-    fn verifier_evaluate(
+    fn verifier_evaluate_selection(
         &self,
         builder: &mut VerificationBuilder<C>,
         accessor: &dyn CommitmentAccessor<C>,
@@ -83,25 +128,22 @@ impl<C: Commitment> ProvableExpr<C> for EqualsExpr<C> {
         let res = scale_and_add_subtract_eval(lhs_eval, rhs_eval, lhs_scale, rhs_scale, true);
         Ok(verifier_evaluate_equals_zero(builder, res))
     }
-
-    fn get_column_references(&self, columns: &mut IndexSet<ColumnRef>) {
-        self.lhs.get_column_references(columns);
-        self.rhs.get_column_references(columns);
-    }
 }
 
+#[cfg(feature = "prover")]
 pub fn result_evaluate_equals_zero<'a, S: Scalar>(
     table_length: usize,
-    alloc: &'a Bump,
+    alloc: &'a dyn MleAllocator,
     lhs: &'a [S],
 ) -> &'a [bool] {
     assert_eq!(table_length, lhs.len());
     alloc.alloc_slice_fill_with(table_length, |i| lhs[i] == S::zero())
 }
 
+#[cfg(feature = "prover")]
 pub fn prover_evaluate_equals_zero<'a, S: Scalar>(
     builder: &mut ProofBuilder<'a, S>,
-    alloc: &'a Bump,
+    alloc: &'a dyn MleAllocator,
     lhs: &'a [S],
 ) -> &'a [bool] {
     let table_length = builder.table_length();
@@ -11,7 +11,7 @@ use crate::{
 };
 use bumpalo::Bump;
 use curve25519_dalek::ristretto::RistrettoPoint;
-use itertools::{multizip, MultiUnzip};
+use proof_of_sql_parser::utility as parser_utility;
 use rand::{
     distributions::{Distribution, Uniform},
     rngs::StdRng,
@@ -313,6 +313,49 @@ fn we_can_prove_an_equality_query_with_a_string_comparison() {
     assert_eq!(res, expected_res);
 }
 
+#[test]
+fn we_can_prove_an_equality_query_over_raw_scalar_columns() {
+    let big = Curve25519Scalar::MAX_SIGNED + Curve25519Scalar::from(5);
+    let data: OwnedTable<Curve25519Scalar> = owned_table([
+        bigint("a", [1, 2, 3, 4, 5]),
+        scalar(
+            "s",
+            [
+                Curve25519Scalar::from(-1),
+                big,
+                Curve25519Scalar::MAX_SIGNED,
+                Curve25519Scalar::ZERO,
+                big,
+            ],
+        ),
+        scalar(
+            "t",
+            [
+                Curve25519Scalar::from(-1),
+                big,
+                Curve25519Scalar::MAX_SIGNED + Curve25519Scalar::ONE,
+                Curve25519Scalar::ONE,
+                Curve25519Scalar::from(5),
+            ],
+        ),
+    ]);
+    let t = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(t, data, 0, ());
+    let ast = dense_filter(
+        cols_expr_plan(t, &["a", "s"], &accessor),
+        tab(t),
+        equal(column(t, "s", &accessor), column(t, "t", &accessor)),
+    );
+    let verifiable_res = VerifiableQueryResult::new(&ast, &accessor, &());
+    exercise_verification(&verifiable_res, &ast, &accessor, t);
+    let res = verifiable_res.verify(&ast, &accessor, &()).unwrap().table;
+    let expected_res = owned_table([
+        bigint("a", [1, 2]),
+        scalar("s", [Curve25519Scalar::from(-1), big]),
+    ]);
+    assert_eq!(res, expected_res);
+}
+
 fn test_random_tables_with_given_offset(offset: usize) {
     let dist = Uniform::new(-3, 4);
     let mut rng = StdRng::from_seed([0u8; 32]);
@@ -356,21 +399,16 @@ fn test_random_tables_with_given_offset(offset: usize) {
         let res = verifiable_res.verify(&ast, &accessor, &()).unwrap().table;
 
         // Calculate/compare expected result
-        let (expected_a, expected_d): (Vec<_>, Vec<_>) = multizip((
-            data["a"].i64_iter(),
-            data["b"].string_iter(),
-            data["c"].i64_iter(),
-            data["d"].string_iter(),
-        ))
-        .filter_map(|(a, b, _c, d)| {
-            if b == &filter_val {
-                Some((*a, d.clone()))
-            } else {
-                None
-            }
-        })
-        .multiunzip();
-        let expected_result = owned_table([bigint("a", expected_a), varchar("d", expected_d)]);
+        let expected_result = data
+            .filter(&parser_utility::equal(
+                parser_utility::col("b"),
+                parser_utility::lit(filter_val),
+            ))
+            .unwrap();
+        let expected_result = owned_table([
+            ("a".parse().unwrap(), expected_result["a"].clone()),
+            ("d".parse().unwrap(), expected_result["d"].clone()),
+        ]);
 
         assert_eq!(expected_result, res)
     }
@@ -0,0 +1,192 @@
+#[cfg(feature = "prover")]
+use super::prover_evaluate_equals_zero;
+use super::{count_equals_zero, verifier_evaluate_equals_zero, ProvableExpr, ProvableExprPlan};
+use crate::{
+    base::{
+        commitment::Commitment,
+        database::{ColumnRef, ColumnType, CommitmentAccessor},
+        proof::ProofError,
+    },
+    sql::{
+        parse::{ConversionError, ConversionResult},
+        proof::{CountBuilder, VerificationBuilder},
+    },
+};
+#[cfg(feature = "prover")]
+use crate::{
+    base::{
+        database::{Column, DataAccessor},
+        MleAllocator,
+    },
+    sql::proof::ProofBuilder,
+};
+use indexmap::IndexSet;
+use num_traits::Zero;
+use serde::{Deserialize, Serialize};
+
+/// The most ASCII letters the value of an [`EqualsIgnoreCaseExpr`] may have.
+///
+/// The proof grows with the number of case variants of the value, which doubles with every
+/// letter.
+pub const MAX_CASE_FOLDED_LETTERS: usize = 8;
+
+/// Provable case-insensitive equality of a varchar expression with a constant,
+/// `LOWER(<expr>) = LOWER(<value>)`
+///
+/// Only ASCII letters are folded: every other byte, including the bytes of non-ASCII characters,
+/// must match exactly. So `'Straße'` equals `'STRAßE'`, but not `'STRASSE'` or `'STRAẞE'`.
+///
+/// Strings are committed by their hash, so the lowercase of a committed string can't be related
+/// to it arithmetically. Instead, the expression proves that the value of each row is one of the
+/// case variants of `value`. The variants are distinct strings, so a row equals at most one of
+/// them, and the result is the sum of the equalities with each variant. This costs one equality
+/// per variant, which is why `value` is limited to [`MAX_CASE_FOLDED_LETTERS`] letters.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(try_from = "UncheckedEqualsIgnoreCaseExpr<C>")]
+pub struct EqualsIgnoreCaseExpr<C: Commitment> {
+    expr: Box<ProvableExprPlan<C>>,
+    value: String,
+}
+
+/// The serialized form of an [`EqualsIgnoreCaseExpr`], which is only accepted if its expression
+/// is a varchar and its value does not have too many letters.
+#[derive(Deserialize)]
+struct UncheckedEqualsIgnoreCaseExpr<C: Commitment> {
+    expr: Box<ProvableExprPlan<C>>,
+    value: String,
+}
+
+impl<C: Commitment> TryFrom<UncheckedEqualsIgnoreCaseExpr<C>> for EqualsIgnoreCaseExpr<C> {
+    type Error = ConversionError;
+
+    fn try_from(value: UncheckedEqualsIgnoreCaseExpr<C>) -> Result<Self, Self::Error> {
+        Self::try_new(value.expr, value.value)
+    }
+}
+
+impl<C: Commitment> EqualsIgnoreCaseExpr<C> {
+    /// Create a new case-insensitive equality expression
+    ///
+    /// `expr` must be a `VarChar` expression.
+    pub fn try_new(expr: Box<ProvableExprPlan<C>>, value: String) -> ConversionResult<Self> {
+        if expr.data_type() != ColumnType::VarChar {
+            return Err(ConversionError::InvalidDataType {
+                expected: ColumnType::VarChar,
+                actual: expr.data_type(),
+            });
+        }
+        let letters = value.bytes().filter(u8::is_ascii_alphabetic).count();
+        if letters > MAX_CASE_FOLDED_LETTERS {
+            return Err(ConversionError::TooManyCaseVariants {
+                letters,
+                max: MAX_CASE_FOLDED_LETTERS,
+            });
+        }
+        Ok(Self { expr, value })
+    }
+
+    /// Every string whose ASCII lowercase is the ASCII lowercase of the value
+    fn variants(&self) -> Vec<String> {
+        self.value
+            .to_ascii_lowercase()
+            .chars()
+            .fold(vec![String::new()], |variants, c| {
+                let cases = if c.is_ascii_alphabetic() {
+                    vec![c, c.to_ascii_uppercase()]
+                } else {
+                    vec![c]
+                };
+                variants
+                    .iter()
+                    .flat_map(|variant| {
+                        cases.iter().map(move |&case| {
+                            let mut variant = variant.clone();
+                            variant.push(case);
+                            variant
+                        })
+                    })
+                    .collect()
+            })
+    }
+
+    fn variant_scalars(&self) -> Vec<C::Scalar> {
+        self.variants()
+            .iter()
+            .map(|variant| C::Scalar::from(variant.as_str()))
+            .collect()
+    }
+}
+
+impl<C: Commitment> ProvableExpr<C> for EqualsIgnoreCaseExpr<C> {
+    fn count(&self, builder: &mut CountBuilder) -> Result<(), ProofError> {
+        self.expr.count(builder)?;
+        for _ in self.variants() {
+            count_equals_zero(builder);
+        }
+        Ok(())
+    }
+
+    fn data_type(&self) -> ColumnType {
+        ColumnType::Boolean
+    }
+
+    #[cfg(feature = "prover")]
+    fn result_evaluate<'a>(
+        &self,
+        table_length: usize,
+        alloc: &'a dyn MleAllocator,
+        accessor: &'a dyn DataAccessor<C::Scalar>,
+    ) -> Column<'a, C::Scalar> {
+        let value = self.value.to_ascii_lowercase();
+        let strings = match self.expr.result_evaluate(table_length, alloc, accessor) {
+            Column::VarChar((strings, _)) => strings,
+            _ => panic!("case-insensitive equality is only defined for varchar expressions"),
+        };
+        Column::Boolean(
+            alloc.alloc_slice_fill_with(table_length, |i| strings[i].to_ascii_lowercase() == value),
+        )
+    }
+
+    #[cfg(feature = "prover")]
+    fn prover_evaluate<'a>(
+        &self,
+        builder: &mut ProofBuilder<'a, C::Scalar>,
+        alloc: &'a dyn MleAllocator,
+        accessor: &'a dyn DataAccessor<C::Scalar>,
+    ) -> Column<'a, C::Scalar> {
+        let table_length = builder.table_length();
+        let scalars = match self.expr.prover_evaluate(builder, alloc, accessor) {
+            Column::VarChar((_, scalars)) => scalars,
+            _ => panic!("case-insensitive equality is only defined for varchar expressions"),
+        };
+        let selection = alloc.alloc_slice_fill_copy(table_length, false);
+        for variant in self.variant_scalars() {
+            let difference = alloc.alloc_slice_fill_with(table_length, |i| scalars[i] - variant);
+            let equals = prover_evaluate_equals_zero(builder, alloc, difference);
+            for (selected, equal) in selection.iter_mut().zip(equals) {
+                *selected |= *equal;
+            }
+        }
+        Column::Boolean(selection)
+    }
+
+    fn verifier_evaluate(
+        &self,
+        builder: &mut VerificationBuilder<C>,
+        accessor: &dyn CommitmentAccessor<C>,
+    ) -> Result<C::Scalar, ProofError> {
+        let expr_eval = self.expr.verifier_evaluate(builder, accessor)?;
+        let one_eval = builder.mle_evaluations.one_evaluation;
+        Ok(self
+            .variant_scalars()
+            .into_iter()
+            .fold(C::Scalar::zero(), |selection_eval, variant| {
+                selection_eval
+                    + verifier_evaluate_equals_zero(builder, expr_eval - one_eval * variant)
+            }))
+    }
+
+    fn get_column_references(&self, columns: &mut IndexSet<ColumnRef>) {
+        self.expr.get_column_references(columns);
+    }
+}
@@ -0,0 +1,123 @@
+use super::MAX_CASE_FOLDED_LETTERS;
+use crate::{
+    base::{
+        commitment::InnerProductProof,
+        database::{owned_table_utility::*, Column, OwnedTableTestAccessor, TestAccessor},
+    },
+    sql::{
+        ast::{test_utility::*, ProvableExpr, ProvableExprPlan},
+        parse::ConversionError,
+        proof::{exercise_verification, VerifiableQueryResult},
+    },
+};
+use bumpalo::Bump;
+use curve25519_dalek::ristretto::RistrettoPoint;
+
+#[test]
+fn we_can_prove_a_case_insensitive_equality_query() {
+    let data = owned_table([
+        bigint("a", [1_i64, 2, 3, 4, 5, 6]),
+        varchar("c", ["foo", "Foo", "fo", "bar", "foo ", "fOO"]),
+    ]);
+    let t = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(t, data, 0, ());
+    let ast = dense_filter(
+        cols_expr_plan(t, &["a", "c"], &accessor),
+        tab(t),
+        equal_ignore_case(column(t, "c", &accessor), "FOO"),
+    );
+    let verifiable_res = VerifiableQueryResult::new(&ast, &accessor, &());
+    exercise_verification(&verifiable_res, &ast, &accessor, t);
+    let res = verifiable_res.verify(&ast, &accessor, &()).unwrap().table;
+    let expected_res = owned_table([
+        bigint("a", [1_i64, 2, 6]),
+        varchar("c", ["foo", "Foo", "fOO"]),
+    ]);
+    assert_eq!(res, expected_res);
+}
+
+#[test]
+fn we_can_prove_a_case_insensitive_equality_query_with_non_ascii_characters() {
+    let data = owned_table([
+        bigint("a", [1_i64, 2, 3, 4, 5]),
+        varchar("c", ["straße", "STRAßE", "STRASSE", "STRAẞE", "sTrAßE"]),
+    ]);
+    let t = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(t, data, 0, ());
+    let ast = dense_filter(
+        cols_expr_plan(t, &["a"], &accessor),
+        tab(t),
+        equal_ignore_case(column(t, "c", &accessor), "Straße"),
+    );
+    let verifiable_res = VerifiableQueryResult::new(&ast, &accessor, &());
+    exercise_verification(&verifiable_res, &ast, &accessor, t);
+    let res = verifiable_res.verify(&ast, &accessor, &()).unwrap().table;
+    let expected_res = owned_table([bigint("a", [1_i64, 2, 5])]);
+    assert_eq!(res, expected_res);
+}
+
+#[test]
+fn we_can_compute_the_correct_output_of_a_case_insensitive_equality_using_result_evaluate() {
+    let data = owned_table([
+        bigint("a", [1, 2, 3, 4]),
+        varchar("c", ["Foo", "fo", "FOO", "f00"]),
+    ]);
+    let mut accessor = OwnedTableTestAccessor::<InnerProductProof>::new_empty_with_setup(());
+    let t = "sxt.t".parse().unwrap();
+    accessor.add_table(t, data, 0);
+    let expr: ProvableExprPlan<RistrettoPoint> =
+        equal_ignore_case(column(t, "c", &accessor), "foo");
+    let alloc = Bump::new();
+    let res = expr.result_evaluate(4, &alloc, &accessor);
+    let expected_res = Column::Boolean(&[true, false, true, false]);
+    assert_eq!(res, expected_res);
+}
+
+#[test]
+fn we_cannot_compare_a_value_with_too_many_letters_ignoring_case() {
+    let data = owned_table([varchar("c", ["a"])]);
+    let t = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(t, data, 0, ());
+    let value = "a".repeat(MAX_CASE_FOLDED_LETTERS);
+    assert!(
+        ProvableExprPlan::<RistrettoPoint>::try_new_equals_ignore_case(
+            column(t, "c", &accessor),
+            &value
+        )
+        .is_ok()
+    );
+    let value = "a".repeat(MAX_CASE_FOLDED_LETTERS + 1);
+    assert!(matches!(
+        ProvableExprPlan::<RistrettoPoint>::try_new_equals_ignore_case(
+            column(t, "c", &accessor),
+            &value
+        ),
+        Err(ConversionError::TooManyCaseVariants {
+            letters,
+            max: MAX_CASE_FOLDED_LETTERS,
+        }) if letters == MAX_CASE_FOLDED_LETTERS + 1
+    ));
+    // Characters other than letters have a single case, so they are not limited
+    let value = format!("{}{}", "a".repeat(MAX_CASE_FOLDED_LETTERS), "0".repeat(20));
+    assert!(
+        ProvableExprPlan::<RistrettoPoint>::try_new_equals_ignore_case(
+            column(t, "c", &accessor),
+            &value
+        )
+        .is_ok()
+    );
+}
+
+#[test]
+fn we_cannot_compare_a_non_varchar_expression_ignoring_case() {
+    let data = owned_table([bigint("a", [1])]);
+    let t = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(t, data, 0, ());
+    assert!(matches!(
+        ProvableExprPlan::<RistrettoPoint>::try_new_equals_ignore_case(
+            column(t, "a", &accessor),
+            "1"
+        ),
+        Err(ConversionError::InvalidDataType { .. })
+    ));
+}
@@ -0,0 +1,252 @@
+#[cfg(feature = "prover")]
+use super::{
+    dense_filter_exec::prove_filter,
+    semi_join_expr::{integer_values, prove_membership},
+};
+use super::{
+    dense_filter_exec::verify_filter,
+    semi_join_expr::{count_membership, verifier_evaluate_membership},
+    ColumnExpr, ProvableExpr, TableExpr,
+};
+use crate::{
+    base::{
+        commitment::Commitment,
+        database::{
+            ColumnField, ColumnRef, ColumnType, CommitmentAccessor, MetadataAccessor, OwnedTable,
+            TableRef,
+        },
+        proof::ProofError,
+    },
+    sql::{
+        parse::{ConversionError, ConversionResult},
+        proof::{CountBuilder, ProofExecutionPlan, VerificationBuilder},
+    },
+};
+#[cfg(feature = "prover")]
+use crate::{
+    base::{
+        database::{filter_util::filter_columns, DataAccessor},
+        MleAllocator,
+    },
+    sql::proof::{Indexes, ProofBuilder, ProverEvaluate, ResultBuilder},
+};
+use indexmap::IndexSet;
+use serde::{Deserialize, Serialize};
+#[cfg(feature = "prover")]
+use std::collections::HashSet;
+
+/// Provable expressions for queries of the form
+/// ```ignore
+///     SELECT <column> FROM <table>
+///     EXCEPT
+///     SELECT <except_column> FROM <except_table>
+/// ```
+///
+/// The result is sent in a dense form, like the [`DenseFilterExec`](super::DenseFilterExec).
+///
+/// Every row of `<column>` whose key is not in `<except_column>` is kept, so duplicates are kept
+/// as they are, like in an anti join. The rows are separated with the membership argument of the
+/// [`SemiJoinExpr`](super::SemiJoinExpr): kept rows are shown to lie strictly between two
+/// consecutive distinct keys of `<except_column>`, and the other rows are looked up among them.
+///
+/// Both tables must be committed with the same generator offset.
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct ExceptExpr<C: Commitment> {
+    pub(super) column: ColumnExpr<C>,
+    pub(super) table: TableExpr,
+    pub(super) except_column: ColumnExpr<C>,
+}
+
+impl<C: Commitment> ExceptExpr<C> {
+    /// Creates a new except expression.
+    ///
+    /// Both columns must have the same integer type, and `column` must belong to `table`.
+    pub fn try_new(
+        column: ColumnExpr<C>,
+        table: TableExpr,
+        except_column: ColumnExpr<C>,
+    ) -> ConversionResult<Self> {
+        match column.data_type() {
+            ColumnType::SmallInt | ColumnType::Int | ColumnType::BigInt => {}
+            data_type => {
+                return Err(ConversionError::InvalidDataType {
+                    expected: ColumnType::BigInt,
+                    actual: data_type,
+                })
+            }
+        }
+        if except_column.data_type() != column.data_type() {
+            return Err(ConversionError::InvalidDataType {
+                expected: column.data_type(),
+                actual: except_column.data_type(),
+            });
+        }
+        if column.get_column_reference().table_ref() != table.table_ref {
+            return Err(ConversionError::InvalidExpression(
+                "the except column must belong to the table".to_string(),
+            ));
+        }
+        Ok(Self {
+            column,
+            table,
+            except_column,
+        })
+    }
+
+    fn except_table_ref(&self) -> TableRef {
+        self.except_column.get_column_reference().table_ref()
+    }
+}
+
+impl<C: Commitment> ProofExecutionPlan<C> for ExceptExpr<C> {
+    fn count(
+        &self,
+        builder: &mut CountBuilder,
+        _accessor: &dyn MetadataAccessor,
+    ) -> Result<(), ProofError> {
+        self.column.count(builder)?;
+        self.except_column.count(builder)?;
+        builder.count_result_columns(1);
+        builder.count_post_result_challenges(4);
+        count_membership(builder)?;
+        // filter
+        builder.count_intermediate_mles(2);
+        builder.count_subpolynomials(3);
+        Ok(())
+    }
+
+    fn get_length(&self, accessor: &dyn MetadataAccessor) -> usize {
+        // The chain of excluded keys has one more row than the excluded table.
+        accessor
+            .get_length(self.table.table_ref)
+            .max(accessor.get_length(self.except_table_ref()) + 1)
+    }
+
+    fn get_offset(&self, accessor: &dyn MetadataAccessor) -> usize {
+        accessor.get_offset(self.table.table_ref)
+    }
+
+    fn verifier_evaluate(
+        &self,
+        builder: &mut VerificationBuilder<C>,
+        accessor: &dyn CommitmentAccessor<C>,
+        _result: Option<&OwnedTable<C::Scalar>>,
+    ) -> Result<(), ProofError> {
+        if accessor.get_offset(self.except_table_ref()) != accessor.get_offset(self.table.table_ref)
+        {
+            return Err(ProofError::VerificationError(
+                "except tables must share a generator offset",
+            ));
+        }
+        let length = accessor.get_length(self.table.table_ref);
+        let chi_eval = builder.mle_evaluations.truncated_one_evaluation(length);
+
+        // 1. columns
+        let a = self.column.verifier_evaluate(builder, accessor)?;
+        let b = self.except_column.verifier_evaluate(builder, accessor)?;
+        // 2. filtered column
+        let d = builder.consume_result_mle();
+
+        let alpha = builder.consume_post_result_challenge();
+        let beta = builder.consume_post_result_challenge();
+        let gamma = builder.consume_post_result_challenge();
+        let delta = builder.consume_post_result_challenge();
+
+        // 3. witness
+        let s = verifier_evaluate_membership(
+            builder,
+            a,
+            b,
+            length,
+            accessor.get_length(self.except_table_ref()),
+            gamma,
+            delta,
+        )?;
+
+        // The rows that are kept are the rows of the table that are not members.
+        verify_filter(builder, alpha, beta, vec![a], chi_eval - s, vec![d])
+    }
+
+    fn get_column_result_fields(&self) -> Vec<ColumnField> {
+        vec![self.column.get_column_field()]
+    }
+
+    fn get_column_references(&self) -> IndexSet<ColumnRef> {
+        let mut columns = IndexSet::new();
+        self.column.get_column_references(&mut columns);
+        self.except_column.get_column_references(&mut columns);
+        columns
+    }
+}
+
+#[cfg(feature = "prover")]
+impl<C: Commitment> ProverEvaluate<C::Scalar> for ExceptExpr<C> {
+    #[tracing::instrument(name = "ExceptExpr::result_evaluate", level = "debug", skip_all)]
+    fn result_evaluate<'a>(
+        &self,
+        builder: &mut ResultBuilder<'a>,
+        alloc: &'a dyn MleAllocator,
+        accessor: &'a dyn DataAccessor<C::Scalar>,
+    ) {
+        let length = accessor.get_length(self.table.table_ref);
+        let column = self.column.result_evaluate(length, alloc, accessor);
+        let excluded_keys: HashSet<i64> = integer_values(&self.except_column.result_evaluate(
+            accessor.get_length(self.except_table_ref()),
+            alloc,
+            accessor,
+        ))
+        .into_iter()
+        .collect();
+        let selection = Vec::from_iter(
+            integer_values(&column)
+                .iter()
+                .map(|key| !excluded_keys.contains(key)),
+        );
+
+        let (filtered_columns, result_len) = filter_columns(alloc, &[column], &selection);
+        builder.set_result_indexes(Indexes::Dense(0..(result_len as u64)));
+        for col in filtered_columns {
+            builder.produce_result_column(col);
+        }
+        builder.request_post_result_challenges(4);
+    }
+
+    #[tracing::instrument(name = "ExceptExpr::prover_evaluate", level = "debug", skip_all)]
+    fn prover_evaluate<'a>(
+        &self,
+        builder: &mut ProofBuilder<'a, C::Scalar>,
+        alloc: &'a dyn MleAllocator,
+        accessor: &'a dyn DataAccessor<C::Scalar>,
+    ) {
+        let n = builder.table_length();
+
+        // 1. columns
+        let a_column = self.column.prover_evaluate(builder, alloc, accessor);
+        let b_column = self.except_column.prover_evaluate(builder, alloc, accessor);
+
+        let alpha = builder.consume_post_result_challenge();
+        let beta = builder.consume_post_result_challenge();
+        let gamma = builder.consume_post_result_challenge();
+        let delta = builder.consume_post_result_challenge();
+
+        // 2. witness
+        let length = a_column.len();
+        let s = prove_membership(builder, alloc, a_column.clone(), b_column, gamma, delta);
+        let selection = alloc.alloc_slice_fill_with(n, |i| i < length && !s[i]) as &[_];
+
+        // 3. filter
+        let c_columns = [a_column];
+        let (filtered_columns, result_len) =
+            filter_columns(alloc, &c_columns, &selection[..length]);
+        prove_filter::<C::Scalar>(
+            builder,
+            alloc,
+            alpha,
+            beta,
+            &c_columns,
+            selection,
+            &filtered_columns,
+            result_len,
+        );
+    }
+}
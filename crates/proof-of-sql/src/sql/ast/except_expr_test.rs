@@ -0,0 +1,166 @@
+use super::{test_utility::*, ExceptExpr};
+use crate::{
+    base::{
+        commitment::InnerProductProof,
+        database::{owned_table_utility::*, ColumnType, OwnedTableTestAccessor, TestAccessor},
+    },
+    sql::{
+        parse::ConversionError,
+        proof::{exercise_verification, VerifiableQueryResult},
+    },
+};
+use curve25519_dalek::ristretto::RistrettoPoint;
+use rand::{
+    distributions::{Distribution, Uniform},
+    rngs::StdRng,
+};
+use rand_core::SeedableRng;
+use std::collections::HashSet;
+
+/// select a from sxt.t except select k from sxt.u
+#[test]
+fn we_can_prove_a_simple_except() {
+    let t = "sxt.t".parse().unwrap();
+    let u = "sxt.u".parse().unwrap();
+    let mut accessor = OwnedTableTestAccessor::<InnerProductProof>::new_empty_with_setup(());
+    accessor.add_table(t, owned_table([bigint("a", [1, 2, 3, 4, 5, 4, 1])]), 0);
+    accessor.add_table(u, owned_table([bigint("k", [4, 2, 7, 4])]), 0);
+    let expr = except(
+        col_expr(t, "a", &accessor),
+        tab(t),
+        col_expr(u, "k", &accessor),
+    );
+    let res = VerifiableQueryResult::new(&expr, &accessor, &());
+    exercise_verification(&res, &expr, &accessor, t);
+    let res = res.verify(&expr, &accessor, &()).unwrap().table;
+    assert_eq!(res, owned_table([bigint("a", [1, 3, 5, 1])]));
+}
+
+/// select a from sxt.t except select k from sxt.u
+#[test]
+fn we_can_prove_an_except_of_a_superset() {
+    let t = "sxt.t".parse().unwrap();
+    let u = "sxt.u".parse().unwrap();
+    let mut accessor = OwnedTableTestAccessor::<InnerProductProof>::new_empty_with_setup(());
+    accessor.add_table(t, owned_table([int("a", [3, 1, 3])]), 0);
+    accessor.add_table(u, owned_table([int("k", [1, 2, 3, 4, 5])]), 0);
+    let expr = except(
+        col_expr(t, "a", &accessor),
+        tab(t),
+        col_expr(u, "k", &accessor),
+    );
+    let res = VerifiableQueryResult::new(&expr, &accessor, &());
+    exercise_verification(&res, &expr, &accessor, t);
+    let res = res.verify(&expr, &accessor, &()).unwrap().table;
+    assert_eq!(res, owned_table([int("a", [0; 0])]));
+}
+
+/// select a from sxt.t except select k from sxt.u
+#[test]
+fn we_can_prove_an_except_of_an_empty_table() {
+    let t = "sxt.t".parse().unwrap();
+    let u = "sxt.u".parse().unwrap();
+    let mut accessor = OwnedTableTestAccessor::<InnerProductProof>::new_empty_with_setup(());
+    accessor.add_table(t, owned_table([bigint("a", [i64::MIN, 2, i64::MAX])]), 0);
+    accessor.add_table(u, owned_table([bigint("k", [0; 0])]), 0);
+    let expr = except(
+        col_expr(t, "a", &accessor),
+        tab(t),
+        col_expr(u, "k", &accessor),
+    );
+    let res = VerifiableQueryResult::new(&expr, &accessor, &());
+    exercise_verification(&res, &expr, &accessor, t);
+    let res = res.verify(&expr, &accessor, &()).unwrap().table;
+    assert_eq!(res, owned_table([bigint("a", [i64::MIN, 2, i64::MAX])]));
+}
+
+#[test]
+fn we_cannot_create_an_except_of_columns_with_different_types() {
+    let t = "sxt.t".parse().unwrap();
+    let u = "sxt.u".parse().unwrap();
+    let mut accessor = OwnedTableTestAccessor::<InnerProductProof>::new_empty_with_setup(());
+    accessor.add_table(t, owned_table([bigint("a", [1])]), 0);
+    accessor.add_table(u, owned_table([int("k", [1]), varchar("v", ["x"])]), 0);
+    let res = ExceptExpr::<RistrettoPoint>::try_new(
+        col_expr(t, "a", &accessor),
+        tab(t),
+        col_expr(u, "k", &accessor),
+    );
+    assert!(matches!(
+        res,
+        Err(ConversionError::InvalidDataType {
+            expected: ColumnType::BigInt,
+            actual: ColumnType::Int,
+        })
+    ));
+    let res = ExceptExpr::<RistrettoPoint>::try_new(
+        col_expr(u, "v", &accessor),
+        tab(u),
+        col_expr(u, "v", &accessor),
+    );
+    assert!(matches!(
+        res,
+        Err(ConversionError::InvalidDataType {
+            actual: ColumnType::VarChar,
+            ..
+        })
+    ));
+}
+
+#[test]
+fn we_cannot_verify_an_except_of_tables_with_different_offsets() {
+    let t = "sxt.t".parse().unwrap();
+    let u = "sxt.u".parse().unwrap();
+    let mut accessor = OwnedTableTestAccessor::<InnerProductProof>::new_empty_with_setup(());
+    accessor.add_table(t, owned_table([bigint("a", [1, 2, 3])]), 0);
+    accessor.add_table(u, owned_table([bigint("k", [2])]), 3);
+    let expr = except(
+        col_expr(t, "a", &accessor),
+        tab(t),
+        col_expr(u, "k", &accessor),
+    );
+    let res = VerifiableQueryResult::new(&expr, &accessor, &());
+    assert!(res.verify(&expr, &accessor, &()).is_err());
+}
+
+/// The rows of `a` without a match in `k`, i.e. the anti join of `a` with `k`.
+fn anti_join(a: &[i64], k: &[i64]) -> Vec<i64> {
+    let k: HashSet<_> = k.iter().collect();
+    a.iter().filter(|key| !k.contains(key)).copied().collect()
+}
+
+fn test_random_tables_with_given_offset(offset: usize) {
+    let dist = Uniform::<i64>::new(-3, 4);
+    let mut rng = StdRng::from_seed([0u8; 32]);
+    for _ in 0..20 {
+        let outer_len = Uniform::new(0, 12).sample(&mut rng);
+        let inner_len = Uniform::new(0, 12).sample(&mut rng);
+        let a = Vec::from_iter(dist.sample_iter(&mut rng).take(outer_len));
+        let k = Vec::from_iter(dist.sample_iter(&mut rng).take(inner_len));
+
+        let t = "sxt.t".parse().unwrap();
+        let u = "sxt.u".parse().unwrap();
+        let mut accessor = OwnedTableTestAccessor::<InnerProductProof>::new_empty_with_setup(());
+        accessor.add_table(t, owned_table([bigint("a", a.clone())]), offset);
+        accessor.add_table(u, owned_table([bigint("k", k.clone())]), offset);
+        let expr = except(
+            col_expr(t, "a", &accessor),
+            tab(t),
+            col_expr(u, "k", &accessor),
+        );
+        let res = VerifiableQueryResult::new(&expr, &accessor, &());
+        exercise_verification(&res, &expr, &accessor, t);
+        let res = res.verify(&expr, &accessor, &()).unwrap().table;
+        assert_eq!(res, owned_table([bigint("a", anti_join(&a, &k))]));
+    }
+}
+
+#[test]
+fn we_can_query_random_tables_using_a_zero_offset() {
+    test_random_tables_with_given_offset(0);
+}
+
+#[test]
+fn we_can_query_random_tables_using_a_non_zero_offset() {
+    test_random_tables_with_given_offset(121);
+}
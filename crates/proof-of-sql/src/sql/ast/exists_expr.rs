@@ -0,0 +1,202 @@
+use super::{ProvableBooleanExpr, ProvableExprPlan, TableExpr};
+use crate::{
+    base::{
+        commitment::Commitment,
+        database::{
+            ColumnField, ColumnRef, ColumnType, CommitmentAccessor, MetadataAccessor, OwnedColumn,
+            OwnedTable,
+        },
+        proof::ProofError,
+        scalar::Scalar,
+    },
+    sql::proof::{CountBuilder, ProofExecutionPlan, VerificationBuilder},
+};
+#[cfg(feature = "prover")]
+use crate::{
+    base::{database::DataAccessor, MleAllocator},
+    sql::proof::{Indexes, ProofBuilder, ProverEvaluate, ResultBuilder, SumcheckSubpolynomialType},
+};
+use indexmap::IndexSet;
+use num_traits::One;
+#[cfg(feature = "prover")]
+use num_traits::{Inv, Zero};
+use proof_of_sql_parser::Identifier;
+use serde::{Deserialize, Serialize};
+
+/// Provable expressions for queries of the form
+/// ```ignore
+///     SELECT EXISTS(SELECT * FROM <table> WHERE <where_clause>) AS <alias>
+/// ```
+///
+/// The result is a single boolean row that is true exactly when at least one row of the table
+/// satisfies the where clause.
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct ExistsExpr<C: Commitment> {
+    pub(super) where_clause: ProvableExprPlan<C>,
+    pub(super) table: TableExpr,
+    pub(super) alias: Identifier,
+}
+
+impl<C: Commitment> ExistsExpr<C> {
+    /// Creates a new exists expression.
+    pub fn new(where_clause: ProvableExprPlan<C>, table: TableExpr, alias: Identifier) -> Self {
+        Self {
+            where_clause,
+            table,
+            alias,
+        }
+    }
+}
+
+impl<C: Commitment> ProofExecutionPlan<C> for ExistsExpr<C> {
+    fn count(
+        &self,
+        builder: &mut CountBuilder,
+        _accessor: &dyn MetadataAccessor,
+    ) -> Result<(), ProofError> {
+        self.where_clause.count(builder)?;
+        builder.count_result_columns(1);
+        builder.count_intermediate_mles(1);
+        builder.count_subpolynomials(2);
+        builder.count_degree(2);
+        Ok(())
+    }
+
+    fn get_length(&self, accessor: &dyn MetadataAccessor) -> usize {
+        accessor.get_length(self.table.table_ref)
+    }
+
+    fn get_offset(&self, accessor: &dyn MetadataAccessor) -> usize {
+        accessor.get_offset(self.table.table_ref)
+    }
+
+    /// No row of an empty table satisfies the where clause, so the result is a single `false`.
+    fn get_empty_table_result(&self) -> Option<OwnedTable<C::Scalar>> {
+        Some(
+            OwnedTable::try_from_iter([(self.alias, OwnedColumn::Boolean(vec![false]))])
+                .expect("a table with a single column is valid"),
+        )
+    }
+
+    fn verifier_evaluate(
+        &self,
+        builder: &mut VerificationBuilder<C>,
+        accessor: &dyn CommitmentAccessor<C>,
+        result: Option<&OwnedTable<C::Scalar>>,
+    ) -> Result<(), ProofError> {
+        // 1. selection
+        let selection_eval = self
+            .where_clause
+            .verifier_evaluate_selection(builder, accessor)?;
+        // 2. indexes
+        builder
+            .mle_evaluations
+            .result_indexes_evaluation
+            .ok_or(ProofError::VerificationError("invalid indexes"))?;
+        // 3. result and witness
+        let result_eval = builder.consume_result_mle();
+        let witness_eval = builder.consume_intermediate_mle();
+        let exists: C::Scalar = match result {
+            Some(table) => match table.inner_table().get(&self.alias) {
+                Some(OwnedColumn::Boolean(values)) if values.len() == 1 => values[0].into(),
+                _ => Err(ProofError::VerificationError(
+                    "Result of exists must be a single boolean.",
+                ))?,
+            },
+            None => Err(ProofError::VerificationError(
+                "ExistsExpr is only supported at the top level of a query plan.",
+            ))?,
+        };
+
+        // sum selection * witness - result = 0
+        builder.produce_sumcheck_subpolynomial_evaluation(
+            &(selection_eval * witness_eval - result_eval),
+        );
+        // sum (1 - exists) * selection = 0
+        builder.produce_sumcheck_subpolynomial_evaluation(
+            &((C::Scalar::one() - exists) * selection_eval),
+        );
+        Ok(())
+    }
+
+    fn get_column_result_fields(&self) -> Vec<ColumnField> {
+        vec![ColumnField::new(self.alias, ColumnType::Boolean)]
+    }
+
+    fn get_column_references(&self) -> IndexSet<ColumnRef> {
+        let mut columns = IndexSet::new();
+        self.where_clause.get_column_references(&mut columns);
+        columns
+    }
+}
+
+#[cfg(feature = "prover")]
+impl<C: Commitment> ProverEvaluate<C::Scalar> for ExistsExpr<C> {
+    #[tracing::instrument(name = "ExistsExpr::result_evaluate", level = "debug", skip_all)]
+    fn result_evaluate<'a>(
+        &self,
+        builder: &mut ResultBuilder<'a>,
+        alloc: &'a dyn MleAllocator,
+        accessor: &'a dyn DataAccessor<C::Scalar>,
+    ) {
+        let selection =
+            self.where_clause
+                .result_evaluate_selection(builder.table_length(), alloc, accessor);
+        let exists = alloc.alloc_slice_fill_copy(1, selection.iter().any(|&b| b));
+        builder.set_result_indexes(Indexes::Dense(0..1));
+        builder.produce_result_column(exists as &[_]);
+    }
+
+    #[tracing::instrument(name = "ExistsExpr::prover_evaluate", level = "debug", skip_all)]
+    fn prover_evaluate<'a>(
+        &self,
+        builder: &mut ProofBuilder<'a, C::Scalar>,
+        alloc: &'a dyn MleAllocator,
+        accessor: &'a dyn DataAccessor<C::Scalar>,
+    ) {
+        let selection = self
+            .where_clause
+            .prover_evaluate_selection(builder, alloc, accessor);
+        prove_exists(builder, alloc, selection);
+    }
+}
+
+/// Proves that `result = [selection.any()]`.
+///
+/// The witness `w` is `1 / count` where `count` is the number of selected rows, so that
+/// `sum selection * w = 1` whenever some row is selected. When no row is selected,
+/// the witness is zero and the second subpolynomial forces the selection to be all false
+/// unless the claimed result is true.
+#[cfg(feature = "prover")]
+fn prove_exists<'a, S: Scalar + 'a>(
+    builder: &mut ProofBuilder<'a, S>,
+    alloc: &'a dyn MleAllocator,
+    selection: &'a [bool],
+) {
+    let n = builder.table_length();
+    let count = selection.iter().filter(|&&b| b).count();
+    let exists = count > 0;
+    let witness_value = S::from(count as i64).inv().unwrap_or(S::zero());
+    let witness = alloc.alloc_slice_fill_copy(n, witness_value);
+    let result = alloc.alloc_slice_fill_copy(1, exists);
+
+    builder.produce_intermediate_mle(witness as &[_]);
+
+    // sum selection * witness - result = 0
+    builder.produce_sumcheck_subpolynomial(
+        SumcheckSubpolynomialType::ZeroSum,
+        vec![
+            (
+                S::one(),
+                vec![Box::new(selection), Box::new(witness as &[_])],
+            ),
+            (-S::one(), vec![Box::new(result as &[_])]),
+        ],
+    );
+
+    // sum (1 - exists) * selection = 0
+    builder.produce_sumcheck_subpolynomial(
+        SumcheckSubpolynomialType::ZeroSum,
+        vec![(S::one() - S::from(exists), vec![Box::new(selection)])],
+    );
+}
@@ -0,0 +1,127 @@
+use super::test_utility::*;
+use crate::{
+    base::{
+        commitment::InnerProductProof,
+        database::{owned_table_utility::*, OwnedTableTestAccessor, TestAccessor},
+    },
+    sql::proof::{exercise_verification, VerifiableQueryResult},
+};
+use rand::{
+    distributions::{Distribution, Uniform},
+    rngs::StdRng,
+};
+use rand_core::SeedableRng;
+
+/// select exists(select * from sxt.t where b = 99) as e
+#[test]
+fn we_can_prove_an_exists_query_where_some_rows_match() {
+    let data = owned_table([bigint("a", [1, 2, 3, 4, 5]), bigint("b", [0, 99, 0, 99, 0])]);
+    let t = "sxt.t".parse().unwrap();
+    let mut accessor = OwnedTableTestAccessor::<InnerProductProof>::new_empty_with_setup(());
+    accessor.add_table(t, data, 0);
+    let expr = exists(
+        tab(t),
+        equal(column(t, "b", &accessor), const_bigint(99)),
+        "e",
+    );
+    let res = VerifiableQueryResult::new(&expr, &accessor, &());
+    exercise_verification(&res, &expr, &accessor, t);
+    let res = res.verify(&expr, &accessor, &()).unwrap().table;
+    assert_eq!(res, owned_table([boolean("e", [true])]));
+}
+
+/// select exists(select * from sxt.t where b = 99) as e
+#[test]
+fn we_can_prove_an_exists_query_where_no_rows_match() {
+    let data = owned_table([bigint("a", [1, 2, 3, 4, 5]), bigint("b", [0, 1, 2, 3, 4])]);
+    let t = "sxt.t".parse().unwrap();
+    let mut accessor = OwnedTableTestAccessor::<InnerProductProof>::new_empty_with_setup(());
+    accessor.add_table(t, data, 0);
+    let expr = exists(
+        tab(t),
+        equal(column(t, "b", &accessor), const_bigint(99)),
+        "e",
+    );
+    let res = VerifiableQueryResult::new(&expr, &accessor, &());
+    exercise_verification(&res, &expr, &accessor, t);
+    let res = res.verify(&expr, &accessor, &()).unwrap().table;
+    assert_eq!(res, owned_table([boolean("e", [false])]));
+}
+
+/// select exists(select * from sxt.t where b = 99) as e
+#[test]
+fn an_exists_query_on_an_empty_table_is_false() {
+    let data = owned_table([bigint("a", [0; 0]), bigint("b", [0; 0])]);
+    let t = "sxt.t".parse().unwrap();
+    let mut accessor = OwnedTableTestAccessor::<InnerProductProof>::new_empty_with_setup(());
+    accessor.add_table(t, data, 0);
+    let expr = exists(
+        tab(t),
+        equal(column(t, "b", &accessor), const_bigint(99)),
+        "e",
+    );
+    let res = VerifiableQueryResult::new(&expr, &accessor, &());
+    exercise_verification(&res, &expr, &accessor, t);
+    let res = res.verify(&expr, &accessor, &()).unwrap().table;
+    assert_eq!(res, owned_table([boolean("e", [false])]));
+}
+
+fn test_random_tables_with_given_offset(offset: usize) {
+    let dist = Uniform::new(-3, 4);
+    let mut rng = StdRng::from_seed([0u8; 32]);
+    for _ in 0..20 {
+        // Generate random table
+        let n = Uniform::new(1, 21).sample(&mut rng);
+        let data = owned_table([
+            bigint("a", dist.sample_iter(&mut rng).take(n)),
+            varchar(
+                "b",
+                dist.sample_iter(&mut rng).take(n).map(|v| format!("s{v}")),
+            ),
+        ]);
+
+        // Generate random values to filter by
+        let filter_val1 = dist.sample(&mut rng);
+        let filter_val2 = format!("s{}", dist.sample(&mut rng));
+
+        // Create and verify proof
+        let t = "sxt.t".parse().unwrap();
+        let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(
+            t,
+            data.clone(),
+            offset,
+            (),
+        );
+        let expr = exists(
+            tab(t),
+            and(
+                lte(column(t, "a", &accessor), const_bigint(filter_val1)),
+                equal(
+                    column(t, "b", &accessor),
+                    const_varchar(filter_val2.as_str()),
+                ),
+            ),
+            "e",
+        );
+        let verifiable_res = VerifiableQueryResult::new(&expr, &accessor, &());
+        exercise_verification(&verifiable_res, &expr, &accessor, t);
+        let res = verifiable_res.verify(&expr, &accessor, &()).unwrap().table;
+
+        // Calculate/compare expected result
+        let expected = data["a"]
+            .i64_iter()
+            .zip(data["b"].string_iter())
+            .any(|(a, b)| *a <= filter_val1 && b == &filter_val2);
+        assert_eq!(res, owned_table([boolean("e", [expected])]));
+    }
+}
+
+#[test]
+fn we_can_query_random_tables_using_a_zero_offset() {
+    test_random_tables_with_given_offset(0);
+}
+
+#[test]
+fn we_can_query_random_tables_using_a_non_zero_offset() {
+    test_random_tables_with_given_offset(75);
+}
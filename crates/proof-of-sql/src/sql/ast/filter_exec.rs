@@ -1,19 +1,29 @@
-use super::{provable_expr_plan::ProvableExprPlan, FilterResultExpr, ProvableExpr, TableExpr};
+#[cfg(feature = "prover")]
+use super::filter_result_expr::prover_evaluate_batched;
+use super::{
+    filter_result_expr::{count_batched, verifier_evaluate_batched},
+    provable_expr_plan::ProvableExprPlan,
+    FilterResultExpr, ProvableExpr, TableExpr,
+};
 use crate::{
     base::{
         commitment::Commitment,
-        database::{
-            Column, ColumnField, ColumnRef, CommitmentAccessor, DataAccessor, MetadataAccessor,
-            OwnedTable,
-        },
+        database::{ColumnField, ColumnRef, CommitmentAccessor, MetadataAccessor, OwnedTable},
         proof::ProofError,
     },
     sql::proof::{
-        CountBuilder, HonestProver, Indexes, ProofBuilder, ProofExecutionPlan, ProverEvaluate,
-        ProverHonestyMarker, ResultBuilder, VerificationBuilder,
+        CountBuilder, HonestProver, ProofExecutionPlan, ProverEvaluate, ProverHonestyMarker,
+        VerificationBuilder,
+    },
+};
+#[cfg(feature = "prover")]
+use crate::{
+    base::{
+        database::{Column, DataAccessor},
+        MleAllocator,
     },
+    sql::proof::{Indexes, ProofBuilder, ResultBuilder},
 };
-use bumpalo::Bump;
 use core::marker::PhantomData;
 use indexmap::IndexSet;
 use serde::{Deserialize, Serialize};
@@ -22,9 +32,12 @@ use serde::{Deserialize, Serialize};
 /// ```ignore
 ///     SELECT <result_expr1>, ..., <result_exprN> FROM <table> WHERE <where_clause>
 /// ```
+///
+/// When there is more than one result column, the columns are proven together under the
+/// shared selection with a single subpolynomial.
 #[derive(Debug, PartialEq, Serialize, Deserialize)]
 pub struct OstensibleFilterExec<C: Commitment, H: ProverHonestyMarker> {
-    pub(super) results: Vec<FilterResultExpr>,
+    pub(super) results: Vec<FilterResultExpr<C>>,
     pub(super) table: TableExpr,
     pub(super) where_clause: ProvableExprPlan<C>,
     phantom: PhantomData<H>,
@@ -33,7 +46,7 @@ pub struct OstensibleFilterExec<C: Commitment, H: ProverHonestyMarker> {
 impl<C: Commitment, H: ProverHonestyMarker> OstensibleFilterExec<C, H> {
     /// Creates a new filter expression.
     pub fn new(
-        results: Vec<FilterResultExpr>,
+        results: Vec<FilterResultExpr<C>>,
         table: TableExpr,
         where_clause: ProvableExprPlan<C>,
     ) -> Self {
@@ -45,10 +58,47 @@ impl<C: Commitment, H: ProverHonestyMarker> OstensibleFilterExec<C, H> {
         }
     }
 
+    /// Creates a filter expression selecting every column of the table, as in
+    /// ```ignore
+    ///     SELECT * FROM <table> WHERE <where_clause>
+    /// ```
+    ///
+    /// The columns are looked up in the schema of `accessor` and appear in the result in schema
+    /// order. A table without any columns gives a filter without result columns.
+    pub fn select_all(
+        table: TableExpr,
+        where_clause: ProvableExprPlan<C>,
+        accessor: &dyn MetadataAccessor,
+    ) -> Self {
+        let results = accessor
+            .schema()
+            .into_iter()
+            .find(|(table_ref, _)| *table_ref == table.table_ref)
+            .map(|(table_ref, fields)| {
+                fields
+                    .into_iter()
+                    .map(|field| {
+                        FilterResultExpr::new(ColumnRef::new(
+                            table_ref,
+                            field.name(),
+                            field.data_type(),
+                        ))
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+        Self::new(results, table, where_clause)
+    }
+
     /// Returns the result expressions.
-    pub fn get_results(&self) -> &[FilterResultExpr] {
+    pub fn get_results(&self) -> &[FilterResultExpr<C>] {
         &self.results[..]
     }
+
+    /// Whether the result columns are proven together under one shared selection
+    fn is_batched(&self) -> bool {
+        self.results.len() > 1
+    }
 }
 
 impl<C: Commitment, H: ProverHonestyMarker> ProofExecutionPlan<C> for OstensibleFilterExec<C, H>
@@ -61,8 +111,12 @@ where
         _accessor: &dyn MetadataAccessor,
     ) -> Result<(), ProofError> {
         self.where_clause.count(builder)?;
-        for expr in self.results.iter() {
-            expr.count(builder);
+        if self.is_batched() {
+            count_batched(&self.results, builder)?;
+        } else {
+            for expr in self.results.iter() {
+                expr.count(builder)?;
+            }
         }
         Ok(())
     }
@@ -82,8 +136,12 @@ where
         _result: Option<&OwnedTable<C::Scalar>>,
     ) -> Result<(), ProofError> {
         let selection_eval = self.where_clause.verifier_evaluate(builder, accessor)?;
-        for expr in self.results.iter() {
-            expr.verifier_evaluate(builder, accessor, &selection_eval);
+        if self.is_batched() {
+            verifier_evaluate_batched(&self.results, builder, accessor, &selection_eval)?;
+        } else {
+            for expr in self.results.iter() {
+                expr.verifier_evaluate(builder, accessor, &selection_eval)?;
+            }
         }
         Ok(())
     }
@@ -100,7 +158,7 @@ where
         let mut columns = IndexSet::new();
 
         for col in self.results.iter() {
-            columns.insert(col.get_column_reference());
+            col.get_column_references(&mut columns);
         }
 
         self.where_clause.get_column_references(&mut columns);
@@ -110,12 +168,13 @@ where
 }
 
 pub type FilterExec<C> = OstensibleFilterExec<C, HonestProver>;
+#[cfg(feature = "prover")]
 impl<C: Commitment> ProverEvaluate<C::Scalar> for FilterExec<C> {
     #[tracing::instrument(name = "FilterExec::result_evaluate", level = "debug", skip_all)]
     fn result_evaluate<'a>(
         &self,
         builder: &mut ResultBuilder<'a>,
-        alloc: &'a Bump,
+        alloc: &'a dyn MleAllocator,
         accessor: &'a dyn DataAccessor<C::Scalar>,
     ) {
         // evaluate where clause
@@ -137,7 +196,10 @@ impl<C: Commitment> ProverEvaluate<C::Scalar> for FilterExec<C> {
 
         // evaluate result columns
         for expr in self.results.iter() {
-            expr.result_evaluate(builder, accessor);
+            expr.result_evaluate(builder, alloc, accessor);
+        }
+        if self.is_batched() {
+            builder.request_post_result_challenges(1);
         }
     }
 
@@ -145,7 +207,7 @@ impl<C: Commitment> ProverEvaluate<C::Scalar> for FilterExec<C> {
     fn prover_evaluate<'a>(
         &self,
         builder: &mut ProofBuilder<'a, C::Scalar>,
-        alloc: &'a Bump,
+        alloc: &'a dyn MleAllocator,
         accessor: &'a dyn DataAccessor<C::Scalar>,
     ) {
         // evaluate where clause
@@ -154,8 +216,12 @@ impl<C: Commitment> ProverEvaluate<C::Scalar> for FilterExec<C> {
         let selection = selection_column
             .as_boolean()
             .expect("selection is not boolean");
-        for expr in self.results.iter() {
-            expr.prover_evaluate(builder, alloc, accessor, selection);
+        if self.is_batched() {
+            prover_evaluate_batched(&self.results, builder, alloc, accessor, selection);
+        } else {
+            for expr in self.results.iter() {
+                expr.prover_evaluate(builder, alloc, accessor, selection);
+            }
         }
     }
 }
@@ -10,11 +10,12 @@ use crate::{
     proof_primitive::dory::DoryCommitment,
     sql::{
         ast::{
-            test_utility::*, ColumnExpr, FilterExec, FilterResultExpr, LiteralExpr,
-            ProvableExprPlan, TableExpr,
+            test_utility::*, ColumnExpr, FilterExec, FilterResultExpr, LiteralExpr, ProofPlan,
+            ProvableExpr, ProvableExprPlan, TableExpr,
         },
+        parse::ConversionError,
         proof::{
-            exercise_verification, ProofExecutionPlan, ProverEvaluate, ResultBuilder,
+            exercise_verification, CountBuilder, ProofExecutionPlan, ProverEvaluate, ResultBuilder,
             VerifiableQueryResult,
         },
     },
@@ -158,6 +159,62 @@ fn we_can_prove_and_get_the_correct_result_from_a_basic_filter() {
     assert_eq!(res, expected_res);
 }
 
+/// select a, b from sxt.t where a = 5 or a = 2
+#[test]
+fn a_column_referenced_several_times_is_only_included_in_the_proof_once() {
+    let data = owned_table([bigint("a", [1, 4, 5, 2, 5]), bigint("b", [1, 2, 3, 4, 5])]);
+    let t = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(t, data, 0, ());
+    let where_clause = or(
+        equal(column(t, "a", &accessor), const_bigint(5)),
+        equal(column(t, "a", &accessor), const_bigint(2)),
+    );
+    let ast: ProofPlan<RistrettoPoint> =
+        filter(cols_result(t, &["a", "b"], &accessor), tab(t), where_clause);
+    let mut builder = CountBuilder::new(&[]);
+    ast.count(&mut builder, &accessor).unwrap();
+    assert_eq!(builder.counts().unwrap().anchored_mles, 2);
+
+    let verifiable_res = VerifiableQueryResult::new(&ast, &accessor, &());
+    exercise_verification(&verifiable_res, &ast, &accessor, t);
+    let res = verifiable_res.verify(&ast, &accessor, &()).unwrap().table;
+    let expected_res = owned_table([bigint("a", [5, 2, 5]), bigint("b", [3, 4, 5])]);
+    assert_eq!(res, expected_res);
+}
+
+/// select * from sxt.t where a = 5
+#[test]
+fn we_can_prove_a_filter_selecting_all_columns() {
+    let data = owned_table([
+        bigint("a", [1, 4, 5, 2, 5]),
+        varchar("c", ["1", "2", "3", "4", "5"]),
+        bigint("b", [1, 2, 3, 4, 5]),
+    ]);
+    let t = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(t, data, 0, ());
+    let where_clause = equal(column(t, "a", &accessor), const_int128(5));
+    let ast = ProofPlan::Filter(FilterExec::select_all(tab(t), where_clause, &accessor));
+    let verifiable_res = VerifiableQueryResult::new(&ast, &accessor, &());
+    exercise_verification(&verifiable_res, &ast, &accessor, t);
+    let res = verifiable_res.verify(&ast, &accessor, &()).unwrap().table;
+    let expected_res = owned_table([
+        bigint("a", [5, 5]),
+        varchar("c", ["3", "5"]),
+        bigint("b", [3, 5]),
+    ]);
+    assert_eq!(res, expected_res);
+}
+
+#[test]
+fn selecting_all_columns_of_a_table_without_columns_gives_no_result_columns() {
+    let t = "sxt.t".parse().unwrap();
+    let mut accessor = OwnedTableTestAccessor::<InnerProductProof>::new_empty_with_setup(());
+    accessor.add_table(t, OwnedTable::try_new(IndexMap::new()).unwrap(), 0);
+    let expr = FilterExec::<RistrettoPoint>::select_all(tab(t), const_bool(true), &accessor);
+    assert!(expr.get_results().is_empty());
+    assert!(expr.get_column_result_fields().is_empty());
+}
+
 #[test]
 fn we_can_get_an_empty_result_from_a_basic_filter_on_an_empty_table_using_result_evaluate() {
     let data = owned_table([
@@ -319,3 +376,163 @@ fn we_can_get_the_correct_result_from_a_basic_filter_using_result_evaluate() {
 
     assert_eq!(res, expected);
 }
+
+#[test]
+fn we_can_prove_a_highly_selective_filter_over_a_large_table_with_compressed_indexes() {
+    let n: i64 = 4096;
+    let data = owned_table([
+        bigint("a", (0..n).map(|i| i64::from(i / 3 == 1000))),
+        bigint("b", 0..n),
+    ]);
+    let t = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(t, data, 0, ());
+    let where_clause = equal(column(t, "a", &accessor), const_bigint(1));
+    let ast = filter(cols_result(t, &["b"], &accessor), tab(t), where_clause);
+    let verifiable_res = VerifiableQueryResult::new(&ast, &accessor, &());
+
+    let indexes = verifiable_res.provable_result.as_ref().unwrap().indexes();
+    let selected: Vec<u64> = indexes.iter().collect();
+    assert_eq!(selected, [3000, 3001, 3002]);
+    let verbatim_len = 1 + postcard::to_allocvec(&selected).unwrap().len();
+    assert!(postcard::to_allocvec(indexes).unwrap().len() < verbatim_len);
+
+    let serialized = postcard::to_allocvec(&verifiable_res).unwrap();
+    let deserialized: VerifiableQueryResult<InnerProductProof> =
+        postcard::from_bytes(&serialized).unwrap();
+    exercise_verification(&deserialized, &ast, &accessor, t);
+    let res = deserialized.verify(&ast, &accessor, &()).unwrap().table;
+    let expected_res = owned_table([bigint("b", [3000_i64, 3001, 3002])]);
+    assert_eq!(res, expected_res);
+}
+
+#[test]
+fn we_can_prove_a_wide_filter_with_batched_result_columns() {
+    let data = owned_table([
+        bigint("a", [1_i64, 4, 5, 2, 5]),
+        bigint("b", [1_i64, 2, 3, 4, 5]),
+        int128("c", [1_i128, 2, 3, 4, 5]),
+        varchar("d", ["1", "2", "3", "4", "5"]),
+        boolean("e", [true, false, true, false, true]),
+        decimal75("f", 12, 1, [1, 2, 3, 4, 5]),
+    ]);
+    let t = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(t, data, 0, ());
+    let names = ["b", "c", "d", "e", "f"];
+    let where_clause = equal(column(t, "a", &accessor), const_int128(5));
+    let ast = filter(
+        cols_result(t, &names, &accessor),
+        tab(t),
+        where_clause.clone(),
+    );
+    let verifiable_res = VerifiableQueryResult::new(&ast, &accessor, &());
+    exercise_verification(&verifiable_res, &ast, &accessor, t);
+    let res = verifiable_res.verify(&ast, &accessor, &()).unwrap().table;
+    let expected_res = owned_table([
+        bigint("b", [3_i64, 5]),
+        int128("c", [3_i128, 5]),
+        varchar("d", ["3", "5"]),
+        boolean("e", [true, true]),
+        decimal75("f", 12, 1, [3, 5]),
+    ]);
+    assert_eq!(res, expected_res);
+
+    // the batched proof needs a single subpolynomial for all of the result columns
+    let count = |ast: &ProofPlan<RistrettoPoint>| {
+        let mut builder = CountBuilder::new(&[]);
+        ast.count(&mut builder, &accessor).unwrap();
+        builder.counts().unwrap()
+    };
+    let where_counts = {
+        let mut builder = CountBuilder::new(&[]);
+        where_clause.count(&mut builder).unwrap();
+        builder.counts().unwrap()
+    };
+    let batched_counts = count(&ast);
+    let unbatched_subpolynomials: usize = names
+        .iter()
+        .map(|name| {
+            let ast = filter(
+                cols_result(t, &[*name], &accessor),
+                tab(t),
+                where_clause.clone(),
+            );
+            count(&ast).sumcheck_subpolynomials - where_counts.sumcheck_subpolynomials
+        })
+        .sum();
+    assert_eq!(
+        batched_counts.sumcheck_subpolynomials - where_counts.sumcheck_subpolynomials,
+        1
+    );
+    assert_eq!(unbatched_subpolynomials, 5);
+    assert_eq!(batched_counts.result_columns, 5);
+    assert_eq!(batched_counts.anchored_mles - where_counts.anchored_mles, 5);
+}
+
+#[test]
+fn we_can_prove_a_filter_with_an_aliased_computed_result_column() {
+    let data = owned_table([
+        bigint("a", [1_i64, 4, 5, 2, 5]),
+        bigint("b", [1_i64, 2, 3, 4, 5]),
+    ]);
+    let t = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(t, data, 0, ());
+    let where_clause = equal(column(t, "a", &accessor), const_int128(5));
+    let results = vec![
+        aliased_result(
+            add(column(t, "a", &accessor), column(t, "b", &accessor)),
+            "total",
+        ),
+        col_result(t, "b", &accessor),
+    ];
+    let ast = filter(results, tab(t), where_clause);
+    assert_eq!(
+        ast.get_column_result_fields(),
+        vec![
+            ColumnField::new("total".parse().unwrap(), ColumnType::BigInt),
+            ColumnField::new("b".parse().unwrap(), ColumnType::BigInt),
+        ]
+    );
+    let verifiable_res = VerifiableQueryResult::new(&ast, &accessor, &());
+    exercise_verification(&verifiable_res, &ast, &accessor, t);
+    let res = verifiable_res.verify(&ast, &accessor, &()).unwrap().table;
+    let expected_res = owned_table([bigint("total", [8_i64, 10]), bigint("b", [3_i64, 5])]);
+    assert_eq!(res, expected_res);
+
+    // a single computed result column is proven without batching
+    let ast = filter(
+        vec![aliased_result(
+            add(column(t, "a", &accessor), column(t, "b", &accessor)),
+            "total",
+        )],
+        tab(t),
+        equal(column(t, "a", &accessor), const_int128(5)),
+    );
+    let verifiable_res = VerifiableQueryResult::new(&ast, &accessor, &());
+    exercise_verification(&verifiable_res, &ast, &accessor, t);
+    let res = verifiable_res.verify(&ast, &accessor, &()).unwrap().table;
+    assert_eq!(res, owned_table([bigint("total", [8_i64, 10])]));
+}
+
+#[test]
+fn we_cannot_create_a_computed_result_column_without_an_alias() {
+    let t = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(
+        t,
+        owned_table([bigint("a", [1_i64]), bigint("b", [1_i64])]),
+        0,
+        (),
+    );
+    assert!(matches!(
+        FilterResultExpr::<RistrettoPoint>::try_new(
+            add(column(t, "a", &accessor), column(t, "b", &accessor)),
+            None
+        ),
+        Err(ConversionError::InvalidExpression(_))
+    ));
+    let result =
+        FilterResultExpr::<RistrettoPoint>::try_new(column(t, "a", &accessor), None).unwrap();
+    assert_eq!(
+        result.get_column_field(),
+        ColumnField::new("a".parse().unwrap(), ColumnType::BigInt)
+    );
+}
@@ -4,6 +4,7 @@ use crate::{
         database::{owned_table_utility::*, Column, DataAccessor, OwnedTableTestAccessor},
         proof::ProofError,
         scalar::Curve25519Scalar,
+        MleAllocator,
     },
     sql::{
         ast::test_utility::*,
@@ -14,7 +15,6 @@ use crate::{
     },
 };
 use blitzar::proof::InnerProductProof;
-use bumpalo::Bump;
 use curve25519_dalek::RistrettoPoint;
 
 #[derive(Debug, PartialEq)]
@@ -31,7 +31,7 @@ impl ProverEvaluate<Curve25519Scalar> for DishonestFilterExec {
     fn result_evaluate<'a>(
         &self,
         builder: &mut ResultBuilder<'a>,
-        alloc: &'a Bump,
+        alloc: &'a dyn MleAllocator,
         accessor: &'a dyn DataAccessor<Curve25519Scalar>,
     ) {
         // evaluate where clause
@@ -61,7 +61,7 @@ impl ProverEvaluate<Curve25519Scalar> for DishonestFilterExec {
     fn prover_evaluate<'a>(
         &self,
         builder: &mut ProofBuilder<'a, Curve25519Scalar>,
-        alloc: &'a Bump,
+        alloc: &'a dyn MleAllocator,
         accessor: &'a dyn DataAccessor<Curve25519Scalar>,
     ) {
         // evaluate where clause
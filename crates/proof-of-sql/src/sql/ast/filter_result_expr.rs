@@ -1,69 +1,113 @@
+#[cfg(feature = "prover")]
+use super::fold_columns;
+use super::{fold_vals, ProvableExpr, ProvableExprPlan};
 use crate::{
     base::{
         commitment::Commitment,
-        database::{Column, ColumnField, ColumnRef, CommitmentAccessor, DataAccessor},
+        database::{Column, ColumnField, ColumnRef, CommitmentAccessor},
+        proof::ProofError,
         scalar::Scalar,
     },
-    sql::proof::{
-        CountBuilder, ProofBuilder, ResultBuilder, SumcheckSubpolynomialType, VerificationBuilder,
+    sql::{
+        parse::{ConversionError, ConversionResult},
+        proof::{CountBuilder, VerificationBuilder},
     },
 };
-use bumpalo::Bump;
-use num_traits::One;
+#[cfg(feature = "prover")]
+use crate::{
+    base::{database::DataAccessor, MleAllocator},
+    sql::proof::{ProofBuilder, ResultBuilder, SumcheckSubpolynomialType},
+};
+use indexmap::IndexSet;
+#[cfg(feature = "prover")]
+use num_traits::{One, Zero};
+use proof_of_sql_parser::Identifier;
 use serde::{Deserialize, Serialize};
 
 /// Provable expression for a result column within a filter SQL expression
 ///
-/// Note: this is currently limited to named column expressions.
-#[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
-pub struct FilterResultExpr {
-    column_ref: ColumnRef,
+/// The result column is the value of `expr` on each selected row, output under `alias`.
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+pub struct FilterResultExpr<C: Commitment> {
+    expr: ProvableExprPlan<C>,
+    alias: Identifier,
 }
 
-impl FilterResultExpr {
-    /// Create a new filter result expression
+impl<C: Commitment> FilterResultExpr<C> {
+    /// Create a new filter result expression for a column, output under the column's name
     pub fn new(column_ref: ColumnRef) -> Self {
-        Self { column_ref }
+        Self {
+            expr: ProvableExprPlan::new_column(column_ref),
+            alias: column_ref.column_id(),
+        }
+    }
+
+    /// Create a new filter result expression for an arbitrary expression
+    ///
+    /// If `alias` is `None`, the output name is derived from `expr`, which is only possible when
+    /// `expr` is a bare column.
+    pub fn try_new(expr: ProvableExprPlan<C>, alias: Option<Identifier>) -> ConversionResult<Self> {
+        let alias = match (alias, &expr) {
+            (Some(alias), _) => alias,
+            (None, ProvableExprPlan::Column(column)) => column.column_id(),
+            (None, _) => Err(ConversionError::InvalidExpression(
+                "a computed result column must have an alias".to_string(),
+            ))?,
+        };
+        Ok(Self { expr, alias })
+    }
+
+    /// Return the expression evaluated by this FilterResultExpr
+    pub fn get_expr(&self) -> &ProvableExprPlan<C> {
+        &self.expr
     }
 
-    /// Return the column referenced by this FilterResultExpr
-    pub fn get_column_reference(&self) -> ColumnRef {
-        self.column_ref
+    /// Insert the columns referenced by this FilterResultExpr into `columns`
+    pub fn get_column_references(&self, columns: &mut IndexSet<ColumnRef>) {
+        self.expr.get_column_references(columns);
     }
 
-    /// Wrap the column output name and its type within the ColumnField
+    /// Wrap the output name and the type of the expression within the ColumnField
     pub fn get_column_field(&self) -> ColumnField {
-        ColumnField::new(self.column_ref.column_id(), *self.column_ref.column_type())
+        ColumnField::new(self.alias, self.expr.data_type())
     }
 
     /// Count the number of proof terms needed by this expression
-    pub fn count(&self, builder: &mut CountBuilder) {
+    pub fn count(&self, builder: &mut CountBuilder) -> Result<(), ProofError> {
+        self.expr.count(builder)?;
         builder.count_result_columns(1);
         builder.count_subpolynomials(1);
-        builder.count_anchored_mles(1);
         builder.count_degree(3);
+        Ok(())
     }
 
     /// Given the selected rows (as a slice of booleans), evaluate the filter result expression and
     /// add the result to the ResultBuilder
-    pub fn result_evaluate<'a, S: Scalar>(
+    #[cfg(feature = "prover")]
+    pub fn result_evaluate<'a>(
         &self,
         builder: &mut ResultBuilder<'a>,
-        accessor: &'a dyn DataAccessor<S>,
+        alloc: &'a dyn MleAllocator,
+        accessor: &'a dyn DataAccessor<C::Scalar>,
     ) {
-        builder.produce_result_column(accessor.get_column(self.column_ref));
+        builder.produce_result_column(self.expr.result_evaluate(
+            builder.table_length(),
+            alloc,
+            accessor,
+        ));
     }
 
     /// Given the selected rows (as a slice of booleans), evaluate the filter result expression and
     /// add the components needed to prove the result
-    pub fn prover_evaluate<'a, S: Scalar>(
+    #[cfg(feature = "prover")]
+    pub fn prover_evaluate<'a>(
         &self,
-        builder: &mut ProofBuilder<'a, S>,
-        alloc: &'a Bump,
-        accessor: &'a dyn DataAccessor<S>,
+        builder: &mut ProofBuilder<'a, C::Scalar>,
+        alloc: &'a dyn MleAllocator,
+        accessor: &'a dyn DataAccessor<C::Scalar>,
         selection: &'a [bool],
     ) {
-        match accessor.get_column(self.column_ref) {
+        match self.expr.prover_evaluate(builder, alloc, accessor) {
             Column::Boolean(col) => prover_evaluate_impl(builder, alloc, selection, col),
             Column::SmallInt(col) => prover_evaluate_impl(builder, alloc, selection, col),
             Column::Int(col) => prover_evaluate_impl(builder, alloc, selection, col),
@@ -81,26 +125,26 @@ impl FilterResultExpr {
 
     /// Given the evaluation of the selected row's multilinear extension at sumcheck's random point,
     /// add components needed to verify this filter result expression
-    pub fn verifier_evaluate<C: Commitment>(
+    pub fn verifier_evaluate(
         &self,
         builder: &mut VerificationBuilder<C>,
         accessor: &dyn CommitmentAccessor<C>,
         selection_eval: &C::Scalar,
-    ) {
-        let col_commit = accessor.get_commitment(self.column_ref);
-
+    ) -> Result<(), ProofError> {
+        let col_eval = self.expr.verifier_evaluate(builder, accessor)?;
         let result_eval = builder.consume_result_mle();
-        let col_eval = builder.consume_anchored_mle(col_commit);
 
         let poly_eval =
             builder.mle_evaluations.random_evaluation * (result_eval - col_eval * *selection_eval);
         builder.produce_sumcheck_subpolynomial_evaluation(&poly_eval);
+        Ok(())
     }
 }
 
+#[cfg(feature = "prover")]
 fn prover_evaluate_impl<'a, S: Scalar, T: Clone + Default + Sync>(
     builder: &mut ProofBuilder<'a, S>,
-    alloc: &'a Bump,
+    alloc: &'a dyn MleAllocator,
     selection: &'a [bool],
     col_scalars: &'a [T],
 ) where
@@ -124,7 +168,90 @@ fn prover_evaluate_impl<'a, S: Scalar, T: Clone + Default + Sync>(
             (-S::one(), vec![Box::new(col_scalars), Box::new(selection)]),
         ],
     );
+}
+
+/// Count the proof terms needed to prove several result columns under one shared selection
+///
+/// The columns are folded together with a post-result challenge so that a single
+/// subpolynomial proves all of them, rather than one subpolynomial per column.
+pub(super) fn count_batched<C: Commitment>(
+    results: &[FilterResultExpr<C>],
+    builder: &mut CountBuilder,
+) -> Result<(), ProofError> {
+    for result in results {
+        result.expr.count(builder)?;
+    }
+    builder.count_result_columns(results.len());
+    builder.count_subpolynomials(1);
+    builder.count_degree(3);
+    builder.count_post_result_challenges(1);
+    Ok(())
+}
+
+/// Add the components needed to prove several result columns under one shared selection
+///
+/// With `beta` the post-result challenge, this proves
+/// `sum beta^j * result_j = (sum beta^j * column_j) * selection`.
+#[cfg(feature = "prover")]
+pub(super) fn prover_evaluate_batched<'a, C: Commitment>(
+    results: &[FilterResultExpr<C>],
+    builder: &mut ProofBuilder<'a, C::Scalar>,
+    alloc: &'a dyn MleAllocator,
+    accessor: &'a dyn DataAccessor<C::Scalar>,
+    selection: &'a [bool],
+) {
+    let beta = builder.consume_post_result_challenge();
+    let columns = Vec::from_iter(
+        results
+            .iter()
+            .map(|result| result.expr.prover_evaluate(builder, alloc, accessor)),
+    );
+
+    // fold the columns and the selected result values
+    let folded_columns = alloc.alloc_slice_fill_copy(builder.table_length(), C::Scalar::zero());
+    fold_columns(folded_columns, C::Scalar::one(), beta, &columns);
+    let folded_columns = &*folded_columns;
+    let folded_selected_vals = alloc.alloc_slice_fill_with(builder.table_length(), |i| {
+        if selection[i] {
+            folded_columns[i]
+        } else {
+            C::Scalar::zero()
+        }
+    });
+
+    // add sumcheck term for folded_columns * selection
+    builder.produce_sumcheck_subpolynomial(
+        SumcheckSubpolynomialType::Identity,
+        vec![
+            (
+                C::Scalar::one(),
+                vec![Box::new(folded_selected_vals as &[_])],
+            ),
+            (
+                -C::Scalar::one(),
+                vec![Box::new(folded_columns), Box::new(selection)],
+            ),
+        ],
+    );
+}
+
+/// Given the evaluation of the selected row's multilinear extension at sumcheck's random point,
+/// add the components needed to verify several result columns under one shared selection
+pub(super) fn verifier_evaluate_batched<C: Commitment>(
+    results: &[FilterResultExpr<C>],
+    builder: &mut VerificationBuilder<C>,
+    accessor: &dyn CommitmentAccessor<C>,
+    selection_eval: &C::Scalar,
+) -> Result<(), ProofError> {
+    let beta = builder.consume_post_result_challenge();
+    let col_evals = results
+        .iter()
+        .map(|result| result.expr.verifier_evaluate(builder, accessor))
+        .collect::<Result<Vec<_>, _>>()?;
+    let result_evals = Vec::from_iter(results.iter().map(|_| builder.consume_result_mle()));
 
-    // add MLE for result column
-    builder.produce_anchored_mle(col_scalars);
+    let poly_eval = builder.mle_evaluations.random_evaluation
+        * (fold_vals(beta, &result_evals) - fold_vals(beta, &col_evals) * *selection_eval);
+    builder.produce_sumcheck_subpolynomial_evaluation(&poly_eval);
+    Ok(())
 }
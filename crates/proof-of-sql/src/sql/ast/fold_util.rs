@@ -1,4 +1,6 @@
-use crate::base::{polynomial::MultilinearExtension, scalar::Scalar};
+#[cfg(feature = "prover")]
+use crate::base::polynomial::MultilinearExtension;
+use crate::base::scalar::Scalar;
 
 /// This function takes a set of columns and fold it into a slice of scalars.
 ///
@@ -8,6 +10,7 @@ use crate::base::{polynomial::MultilinearExtension, scalar::Scalar};
 ///
 /// This is similar to adding `mul * fold_vals(beta,...)` on each row.
 
+#[cfg(feature = "prover")]
 pub fn fold_columns<S: Scalar>(
     res: &mut [S],
     mul: S,
@@ -0,0 +1,363 @@
+use super::{
+    distinct_chain::{count_distinct_chain, verifier_evaluate_distinct_chain},
+    group_by_exec::verify_group_by,
+    ColumnExpr, ProvableBooleanExpr, ProvableExpr, ProvableExprPlan, TableExpr,
+};
+#[cfg(feature = "prover")]
+use super::{
+    distinct_chain::{count_selected_values, prove_distinct_chain},
+    group_by_exec::prove_group_by,
+    semi_join_expr::integer_values,
+};
+use crate::{
+    base::{
+        commitment::Commitment,
+        database::{
+            group_by_util::compare_indexes_by_owned_columns, ColumnField, ColumnRef, ColumnType,
+            CommitmentAccessor, MetadataAccessor, OwnedColumn, OwnedTable,
+        },
+        proof::ProofError,
+        scalar::Scalar,
+    },
+    sql::{
+        parse::{ConversionError, ConversionResult},
+        proof::{CountBuilder, ProofExecutionPlan, VerificationBuilder},
+    },
+};
+#[cfg(feature = "prover")]
+use crate::{
+    base::{
+        database::{Column, DataAccessor},
+        MleAllocator,
+    },
+    sql::proof::{Indexes, ProofBuilder, ProverEvaluate, ResultBuilder},
+};
+use indexmap::IndexSet;
+use proof_of_sql_parser::Identifier;
+use serde::{Deserialize, Serialize};
+#[cfg(feature = "prover")]
+use std::collections::BTreeMap;
+
+/// Provable expressions for queries of the form
+/// ```ignore
+///     SELECT <group_by_expr>, COUNT(DISTINCT <expr>) AS <alias>
+///     FROM <table>
+///     WHERE <where_clause>
+///     GROUP BY <group_by_expr>
+/// ```
+///
+/// The group by column and `expr` must be integers. The result holds a row for each group,
+/// ordered by key, with the number of distinct values of `expr` in the group.
+///
+/// The proof combines the arguments of [`CountDistinctExpr`](super::CountDistinctExpr) and
+/// [`GroupByExec`](super::GroupByExec). Each selected row is identified by the composite value
+/// `key * 2^64 + value`, which orders the rows by key and then by value. The prover commits to the
+/// distinct pairs `(key, value)` of the selected rows, which are shown to be the distinct
+/// composite values with the argument of [`verifier_evaluate_distinct_chain`], exactly as the
+/// distinct values of a count distinct. The pairs are then grouped by key with the group by
+/// argument, so that the count of each group is its number of distinct pairs.
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct GroupByCountDistinctExpr<C: Commitment> {
+    pub(super) group_by_expr: ColumnExpr<C>,
+    pub(super) expr: ProvableExprPlan<C>,
+    pub(super) alias: Identifier,
+    pub(super) table: TableExpr,
+    pub(super) where_clause: ProvableExprPlan<C>,
+}
+
+impl<C: Commitment> GroupByCountDistinctExpr<C> {
+    /// Creates a new group by count distinct expression.
+    ///
+    /// The group by column and `expr` must be `SmallInt`, `Int` or `BigInt`.
+    pub fn try_new(
+        group_by_expr: ColumnExpr<C>,
+        expr: ProvableExprPlan<C>,
+        alias: Identifier,
+        table: TableExpr,
+        where_clause: ProvableExprPlan<C>,
+    ) -> ConversionResult<Self> {
+        for data_type in [group_by_expr.data_type(), expr.data_type()] {
+            match data_type {
+                ColumnType::SmallInt | ColumnType::Int | ColumnType::BigInt => {}
+                data_type => {
+                    return Err(ConversionError::InvalidDataType {
+                        expected: ColumnType::BigInt,
+                        actual: data_type,
+                    })
+                }
+            }
+        }
+        Ok(Self {
+            group_by_expr,
+            expr,
+            alias,
+            table,
+            where_clause,
+        })
+    }
+}
+
+impl<C: Commitment> ProofExecutionPlan<C> for GroupByCountDistinctExpr<C> {
+    fn count(
+        &self,
+        builder: &mut CountBuilder,
+        _accessor: &dyn MetadataAccessor,
+    ) -> Result<(), ProofError> {
+        self.where_clause.count(builder)?;
+        self.group_by_expr.count(builder)?;
+        self.expr.count(builder)?;
+        builder.count_result_columns(2);
+        builder.count_post_result_challenges(3);
+        builder.count_intermediate_mles(2);
+        count_distinct_chain(builder)?;
+        // the group by argument over the distinct pairs
+        builder.count_intermediate_mles(2);
+        builder.count_subpolynomials(3);
+        Ok(())
+    }
+
+    fn get_length(&self, accessor: &dyn MetadataAccessor) -> usize {
+        accessor.get_length(self.table.table_ref)
+    }
+
+    fn get_offset(&self, accessor: &dyn MetadataAccessor) -> usize {
+        accessor.get_offset(self.table.table_ref)
+    }
+
+    fn verifier_evaluate(
+        &self,
+        builder: &mut VerificationBuilder<C>,
+        accessor: &dyn CommitmentAccessor<C>,
+        result: Option<&OwnedTable<C::Scalar>>,
+    ) -> Result<(), ProofError> {
+        let shift = key_shift::<C::Scalar>();
+
+        // 1. selection
+        let s = self
+            .where_clause
+            .verifier_evaluate_selection(builder, accessor)?;
+        // 2. columns
+        let key = self.group_by_expr.verifier_evaluate(builder, accessor)?;
+        let a = self.expr.verifier_evaluate(builder, accessor)?;
+        // 3. result
+        let key_result = builder.consume_result_mle();
+        let count_result = builder.consume_result_mle();
+        match result {
+            Some(table) => {
+                let keys = table
+                    .inner_table()
+                    .get(&self.group_by_expr.column_id())
+                    .ok_or(ProofError::VerificationError(
+                        "Result does not have the group by column.",
+                    ))?;
+                if (1..table.num_rows())
+                    .any(|i| compare_indexes_by_owned_columns(&[keys], i - 1, i).is_ge())
+                {
+                    Err(ProofError::VerificationError(
+                        "Result of group by not ordered as expected.",
+                    ))?;
+                }
+                // A result row outside of the selected groups would have a count of zero.
+                match table.inner_table().get(&self.alias) {
+                    Some(OwnedColumn::BigInt(counts)) if counts.iter().all(|&count| count > 0) => {}
+                    _ => Err(ProofError::VerificationError(
+                        "Result of group by count distinct does not hold only selected groups.",
+                    ))?,
+                }
+            }
+            None => Err(ProofError::VerificationError(
+                "GroupByCountDistinctExpr is only supported at the top level of a query plan.",
+            ))?,
+        };
+
+        let gamma = builder.consume_post_result_challenge();
+        let alpha = builder.consume_post_result_challenge();
+        let beta = builder.consume_post_result_challenge();
+
+        // 4. the chain of sorted distinct pairs
+        let pair_key = builder.consume_intermediate_mle();
+        let pair_value = builder.consume_intermediate_mle();
+        let (p, _) = verifier_evaluate_distinct_chain(
+            builder,
+            gamma,
+            lower_sentinel(),
+            s,
+            shift * key + a,
+            shift * pair_key + pair_value,
+        )?;
+
+        // group the distinct pairs by key
+        verify_group_by(
+            builder,
+            alpha,
+            beta,
+            (vec![pair_key], vec![], p),
+            (vec![key_result], vec![], count_result),
+        )?;
+        Ok(())
+    }
+
+    fn get_column_result_fields(&self) -> Vec<ColumnField> {
+        vec![
+            self.group_by_expr.get_column_field(),
+            ColumnField::new(self.alias, ColumnType::BigInt),
+        ]
+    }
+
+    fn get_column_references(&self) -> IndexSet<ColumnRef> {
+        let mut columns = IndexSet::new();
+        columns.insert(self.group_by_expr.get_column_reference());
+        self.expr.get_column_references(&mut columns);
+        self.where_clause.get_column_references(&mut columns);
+        columns
+    }
+}
+
+#[cfg(feature = "prover")]
+impl<C: Commitment> ProverEvaluate<C::Scalar> for GroupByCountDistinctExpr<C> {
+    #[tracing::instrument(
+        name = "GroupByCountDistinctExpr::result_evaluate",
+        level = "debug",
+        skip_all
+    )]
+    fn result_evaluate<'a>(
+        &self,
+        builder: &mut ResultBuilder<'a>,
+        alloc: &'a dyn MleAllocator,
+        accessor: &'a dyn DataAccessor<C::Scalar>,
+    ) {
+        let n = builder.table_length();
+        let selection = self
+            .where_clause
+            .result_evaluate_selection(n, alloc, accessor);
+        let key_column = self.group_by_expr.result_evaluate(n, alloc, accessor);
+        let column = self.expr.result_evaluate(n, alloc, accessor);
+        let pairs = count_selected_pairs(
+            &integer_values(&key_column),
+            &integer_values(&column),
+            selection,
+        );
+        let (keys, counts) = count_distinct_values_by_key(&pairs);
+        builder.set_result_indexes(Indexes::Dense(0..(keys.len() as u64)));
+        builder.produce_result_column(key_values_column::<C::Scalar>(
+            alloc,
+            self.group_by_expr.data_type(),
+            &keys,
+        ));
+        builder.produce_result_column(alloc.alloc_slice_copy(&counts) as &[_]);
+        builder.request_post_result_challenges(3);
+    }
+
+    #[tracing::instrument(
+        name = "GroupByCountDistinctExpr::prover_evaluate",
+        level = "debug",
+        skip_all
+    )]
+    fn prover_evaluate<'a>(
+        &self,
+        builder: &mut ProofBuilder<'a, C::Scalar>,
+        alloc: &'a dyn MleAllocator,
+        accessor: &'a dyn DataAccessor<C::Scalar>,
+    ) {
+        let n = builder.table_length();
+        let shift = key_shift::<C::Scalar>();
+        let s = self
+            .where_clause
+            .prover_evaluate_selection(builder, alloc, accessor);
+        let key_column = self.group_by_expr.prover_evaluate(builder, alloc, accessor);
+        let a_column = self.expr.prover_evaluate(builder, alloc, accessor);
+        let keys = integer_values(&key_column);
+        let a = integer_values(&a_column);
+        let gamma = builder.consume_post_result_challenge();
+        let alpha = builder.consume_post_result_challenge();
+        let beta = builder.consume_post_result_challenge();
+
+        // the chain of sorted distinct pairs
+        let pairs = count_selected_values(
+            &Vec::from_iter(keys.iter().copied().zip(a.iter().copied())),
+            s,
+        );
+        let pair_key = alloc.alloc_slice_fill_copy(n, 0i64);
+        let pair_value = alloc.alloc_slice_fill_copy(n, 0i64);
+        for (i, &(key, value)) in pairs.keys().enumerate() {
+            pair_key[i] = key;
+            pair_value[i] = value;
+        }
+        let (pair_key, pair_value) = (&*pair_key, &*pair_value);
+        builder.produce_intermediate_mle(pair_key);
+        builder.produce_intermediate_mle(pair_value);
+        let composite =
+            |key: i64, value: i64| shift * C::Scalar::from(key) + C::Scalar::from(value);
+        let (p, _) = prove_distinct_chain(
+            builder,
+            alloc,
+            gamma,
+            lower_sentinel(),
+            s,
+            alloc.alloc_slice_fill_with(n, |i| composite(keys[i], a[i])),
+            alloc.alloc_slice_fill_with(n, |i| composite(pair_key[i], pair_value[i])),
+        );
+
+        // group the distinct pairs by key
+        let (group_keys, counts) = count_distinct_values_by_key(&pairs);
+        let key_result = key_values_column(alloc, self.group_by_expr.data_type(), &group_keys);
+        let counts = alloc.alloc_slice_copy(&counts) as &[_];
+        prove_group_by(
+            builder,
+            alloc,
+            alpha,
+            beta,
+            (&[Column::BigInt(pair_key)], &[], p),
+            (&[key_result], &[], counts),
+        );
+    }
+}
+
+/// `2^64`, the factor by which the key is shifted in the composite value of a pair.
+///
+/// Both the key and the value are 64-bit integers, so the composite values of the pairs are
+/// ordered by key and then by value.
+fn key_shift<S: Scalar>() -> S {
+    S::from(1i128 << 64)
+}
+
+/// `-2^127 - 2^64`, a value smaller than the composite value of any pair, used as the first node
+/// of the sorted chain of distinct pairs.
+fn lower_sentinel<S: Scalar>() -> S {
+    S::from(i128::MIN) - key_shift::<S>()
+}
+
+/// Returns the keys of the distinct pairs, in increasing order, with the number of distinct
+/// values of each.
+#[cfg(feature = "prover")]
+fn count_distinct_values_by_key(pairs: &BTreeMap<(i64, i64), i64>) -> (Vec<i64>, Vec<i64>) {
+    let mut keys: Vec<i64> = Vec::new();
+    let mut counts: Vec<i64> = Vec::new();
+    for &(key, _) in pairs.keys() {
+        if keys.last() == Some(&key) {
+            *counts.last_mut().unwrap() += 1;
+        } else {
+            keys.push(key);
+            counts.push(1);
+        }
+    }
+    (keys, counts)
+}
+
+/// Converts group keys back to the type of the group by column.
+#[cfg(feature = "prover")]
+fn key_values_column<'a, S: Scalar>(
+    alloc: &'a dyn MleAllocator,
+    data_type: ColumnType,
+    keys: &[i64],
+) -> Column<'a, S> {
+    match data_type {
+        ColumnType::SmallInt => {
+            Column::SmallInt(alloc.alloc_slice_fill_iter(keys.iter().map(|&key| key as i16)))
+        }
+        ColumnType::Int => {
+            Column::Int(alloc.alloc_slice_fill_iter(keys.iter().map(|&key| key as i32)))
+        }
+        _ => Column::BigInt(alloc.alloc_slice_copy(keys)),
+    }
+}
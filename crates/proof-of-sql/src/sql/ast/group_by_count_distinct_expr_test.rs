@@ -0,0 +1,217 @@
+use super::{test_utility::*, GroupByCountDistinctExpr};
+use crate::{
+    base::{
+        commitment::InnerProductProof,
+        database::{owned_table_utility::*, ColumnType, OwnedTableTestAccessor, TestAccessor},
+    },
+    sql::{
+        parse::ConversionError,
+        proof::{exercise_verification, VerifiableQueryResult},
+    },
+};
+use curve25519_dalek::ristretto::RistrettoPoint;
+use rand::{
+    distributions::{Distribution, Uniform},
+    rngs::StdRng,
+};
+use rand_core::SeedableRng;
+use std::collections::{BTreeMap, BTreeSet};
+
+/// select k, count(distinct v) as n from sxt.t where b = 99 group by k
+#[test]
+fn we_can_prove_a_simple_group_by_count_distinct_query() {
+    let data = owned_table([
+        bigint("k", [2, 1, 2, 3, 1, 3, 2, 3, 1]),
+        bigint("v", [7, 4, 7, 5, 4, 6, 7, 8, 9]),
+        bigint("b", [99, 99, 99, 99, 99, 99, 99, 99, 0]),
+    ]);
+    let t = "sxt.t".parse().unwrap();
+    let mut accessor = OwnedTableTestAccessor::<InnerProductProof>::new_empty_with_setup(());
+    accessor.add_table(t, data, 0);
+    let expr = group_by_count_distinct(
+        col_expr(t, "k", &accessor),
+        column(t, "v", &accessor),
+        "n",
+        tab(t),
+        equal(column(t, "b", &accessor), const_bigint(99)),
+    );
+    let res = VerifiableQueryResult::new(&expr, &accessor, &());
+    exercise_verification(&res, &expr, &accessor, t);
+    let res = res.verify(&expr, &accessor, &()).unwrap().table;
+    assert_eq!(
+        res,
+        owned_table([bigint("k", [1, 2, 3]), bigint("n", [1, 1, 3])])
+    );
+}
+
+/// select k, count(distinct v) as n from sxt.t where true group by k
+#[test]
+fn we_can_prove_group_by_count_distinct_with_a_single_group_of_distinct_values() {
+    let data = owned_table([
+        int("k", [-4; 5]),
+        bigint("v", [i64::MAX, 3, i64::MIN, -1, 0]),
+    ]);
+    let t = "sxt.t".parse().unwrap();
+    let mut accessor = OwnedTableTestAccessor::<InnerProductProof>::new_empty_with_setup(());
+    accessor.add_table(t, data, 0);
+    let expr = group_by_count_distinct(
+        col_expr(t, "k", &accessor),
+        column(t, "v", &accessor),
+        "n",
+        tab(t),
+        const_bool(true),
+    );
+    let res = VerifiableQueryResult::new(&expr, &accessor, &());
+    exercise_verification(&res, &expr, &accessor, t);
+    let res = res.verify(&expr, &accessor, &()).unwrap().table;
+    assert_eq!(res, owned_table([int("k", [-4]), bigint("n", [5])]));
+}
+
+/// select k, count(distinct v) as n from sxt.t where true group by k
+#[test]
+fn we_can_prove_group_by_count_distinct_with_extreme_keys_and_values() {
+    let data = owned_table([
+        bigint("k", [i64::MAX, i64::MIN, i64::MAX, i64::MIN, 0]),
+        bigint("v", [i64::MIN, i64::MAX, i64::MAX, i64::MAX, i64::MIN]),
+    ]);
+    let t = "sxt.t".parse().unwrap();
+    let mut accessor = OwnedTableTestAccessor::<InnerProductProof>::new_empty_with_setup(());
+    accessor.add_table(t, data, 0);
+    let expr = group_by_count_distinct(
+        col_expr(t, "k", &accessor),
+        column(t, "v", &accessor),
+        "n",
+        tab(t),
+        const_bool(true),
+    );
+    let res = VerifiableQueryResult::new(&expr, &accessor, &());
+    exercise_verification(&res, &expr, &accessor, t);
+    let res = res.verify(&expr, &accessor, &()).unwrap().table;
+    assert_eq!(
+        res,
+        owned_table([bigint("k", [i64::MIN, 0, i64::MAX]), bigint("n", [1, 1, 2])])
+    );
+}
+
+/// select k, count(distinct v) as n from sxt.t where b = 1 group by k
+#[test]
+fn we_can_prove_group_by_count_distinct_with_an_empty_selection() {
+    let data = owned_table([
+        bigint("k", [1, 2, 2]),
+        smallint("v", [1_i16, 2, 2]),
+        bigint("b", [0, 0, 0]),
+    ]);
+    let t = "sxt.t".parse().unwrap();
+    let mut accessor = OwnedTableTestAccessor::<InnerProductProof>::new_empty_with_setup(());
+    accessor.add_table(t, data, 0);
+    let expr = group_by_count_distinct(
+        col_expr(t, "k", &accessor),
+        column(t, "v", &accessor),
+        "n",
+        tab(t),
+        equal(column(t, "b", &accessor), const_bigint(1)),
+    );
+    let res = VerifiableQueryResult::new(&expr, &accessor, &());
+    exercise_verification(&res, &expr, &accessor, t);
+    let res = res.verify(&expr, &accessor, &()).unwrap().table;
+    assert_eq!(res, owned_table([bigint("k", [0; 0]), bigint("n", [0; 0])]));
+}
+
+#[test]
+fn we_cannot_group_by_count_distinct_values_of_a_non_integer_column() {
+    let data = owned_table([bigint("k", [1, 2]), varchar("v", ["x", "y"])]);
+    let t = "sxt.t".parse().unwrap();
+    let mut accessor = OwnedTableTestAccessor::<InnerProductProof>::new_empty_with_setup(());
+    accessor.add_table(t, data, 0);
+    let res = GroupByCountDistinctExpr::<RistrettoPoint>::try_new(
+        col_expr(t, "k", &accessor),
+        column(t, "v", &accessor),
+        "n".parse().unwrap(),
+        tab(t),
+        const_bool(true),
+    );
+    assert!(matches!(
+        res,
+        Err(ConversionError::InvalidDataType {
+            actual: ColumnType::VarChar,
+            ..
+        })
+    ));
+}
+
+#[test]
+fn we_cannot_group_by_a_non_integer_column() {
+    let data = owned_table([varchar("k", ["x", "y"]), bigint("v", [1, 2])]);
+    let t = "sxt.t".parse().unwrap();
+    let mut accessor = OwnedTableTestAccessor::<InnerProductProof>::new_empty_with_setup(());
+    accessor.add_table(t, data, 0);
+    let res = GroupByCountDistinctExpr::<RistrettoPoint>::try_new(
+        col_expr(t, "k", &accessor),
+        column(t, "v", &accessor),
+        "n".parse().unwrap(),
+        tab(t),
+        const_bool(true),
+    );
+    assert!(matches!(
+        res,
+        Err(ConversionError::InvalidDataType {
+            actual: ColumnType::VarChar,
+            ..
+        })
+    ));
+}
+
+fn test_random_tables_with_given_offset(offset: usize) {
+    let dist = Uniform::new(-3, 4);
+    let mut rng = StdRng::from_seed([0u8; 32]);
+    for _ in 0..20 {
+        let n = Uniform::new(1, 21).sample(&mut rng);
+        let k: Vec<i64> = dist.sample_iter(&mut rng).take(n).collect();
+        let v: Vec<i64> = dist.sample_iter(&mut rng).take(n).collect();
+        let b: Vec<i64> = dist.sample_iter(&mut rng).take(n).collect();
+        let filter_val = dist.sample(&mut rng);
+
+        let t = "sxt.t".parse().unwrap();
+        let mut accessor = OwnedTableTestAccessor::<InnerProductProof>::new_empty_with_setup(());
+        accessor.add_table(
+            t,
+            owned_table([
+                bigint("k", k.clone()),
+                bigint("v", v.clone()),
+                bigint("b", b.clone()),
+            ]),
+            offset,
+        );
+        let expr = group_by_count_distinct(
+            col_expr(t, "k", &accessor),
+            column(t, "v", &accessor),
+            "n",
+            tab(t),
+            lte(column(t, "b", &accessor), const_bigint(filter_val)),
+        );
+        let res = VerifiableQueryResult::new(&expr, &accessor, &());
+        exercise_verification(&res, &expr, &accessor, t);
+        let res = res.verify(&expr, &accessor, &()).unwrap().table;
+
+        let mut groups: BTreeMap<i64, BTreeSet<i64>> = BTreeMap::new();
+        for ((&k, &v), _) in k.iter().zip(&v).zip(&b).filter(|(_, b)| **b <= filter_val) {
+            groups.entry(k).or_default().insert(v);
+        }
+        let expected_keys: Vec<i64> = groups.keys().copied().collect();
+        let expected_counts: Vec<i64> = groups.values().map(|vs| vs.len() as i64).collect();
+        assert_eq!(
+            res,
+            owned_table([bigint("k", expected_keys), bigint("n", expected_counts)])
+        );
+    }
+}
+
+#[test]
+fn we_can_query_random_tables_using_a_zero_offset() {
+    test_random_tables_with_given_offset(0);
+}
+
+#[test]
+fn we_can_query_random_tables_using_a_non_zero_offset() {
+    test_random_tables_with_given_offset(121);
+}
@@ -1,29 +1,34 @@
+#[cfg(feature = "prover")]
+use super::fold_columns;
 use super::{
-    fold_columns, fold_vals, AliasedProvableExprPlan, ColumnExpr, ProvableExpr, ProvableExprPlan,
-    TableExpr,
+    fold_vals, AliasedProvableExprPlan, ColumnExpr, ProvableExpr, ProvableExprPlan, TableExpr,
 };
 use crate::{
     base::{
         commitment::Commitment,
         database::{
-            group_by_util::{
-                aggregate_columns, compare_indexes_by_owned_columns, AggregatedColumns,
-            },
-            Column, ColumnField, ColumnRef, ColumnType, CommitmentAccessor, DataAccessor,
-            MetadataAccessor, OwnedTable,
+            group_by_util::compare_indexes_by_owned_columns, ColumnField, ColumnRef, ColumnType,
+            CommitmentAccessor, MetadataAccessor, OwnedTable,
         },
         proof::ProofError,
         scalar::Scalar,
-        slice_ops,
     },
-    sql::proof::{
-        CountBuilder, Indexes, ProofBuilder, ProofExecutionPlan, ProverEvaluate, ResultBuilder,
-        SumcheckSubpolynomialType, VerificationBuilder,
+    sql::proof::{CountBuilder, ProofExecutionPlan, VerificationBuilder},
+};
+#[cfg(feature = "prover")]
+use crate::{
+    base::{
+        database::{
+            group_by_util::{aggregate_columns, AggregatedColumns},
+            Column, DataAccessor,
+        },
+        slice_ops, MleAllocator,
     },
+    sql::proof::{Indexes, ProofBuilder, ProverEvaluate, ResultBuilder, SumcheckSubpolynomialType},
 };
-use bumpalo::Bump;
 use core::iter::repeat_with;
 use indexmap::IndexSet;
+#[cfg(feature = "prover")]
 use num_traits::One;
 use proof_of_sql_parser::Identifier;
 use serde::{Deserialize, Serialize};
@@ -39,6 +44,12 @@ use serde::{Deserialize, Serialize};
 /// ```
 ///
 /// Note: if `group_by_exprs` is empty, then the query is equivalent to removing the `GROUP BY` clause.
+///
+/// When there are several group by columns, each row's key columns are combined into a single
+/// composite key `alpha + sum beta^j * key[j]`, where `alpha` and `beta` are challenges drawn after
+/// the result is committed. Two distinct keys combine to the same value with probability at most
+/// `M / |F|` for `M` key columns, so the proof of the grouping over the composite key is a proof
+/// of the grouping over the key columns themselves.
 #[derive(Debug, PartialEq, Serialize, Deserialize)]
 pub struct GroupByExec<C: Commitment> {
     pub(super) group_by_exprs: Vec<ColumnExpr<C>>,
@@ -199,12 +210,13 @@ impl<C: Commitment> ProofExecutionPlan<C> for GroupByExec<C> {
     }
 }
 
+#[cfg(feature = "prover")]
 impl<C: Commitment> ProverEvaluate<C::Scalar> for GroupByExec<C> {
     #[tracing::instrument(name = "GroupByExec::result_evaluate", level = "debug", skip_all)]
     fn result_evaluate<'a>(
         &self,
         builder: &mut ResultBuilder<'a>,
-        alloc: &'a Bump,
+        alloc: &'a dyn MleAllocator,
         accessor: &'a dyn DataAccessor<C::Scalar>,
     ) {
         // 1. selection
@@ -253,7 +265,7 @@ impl<C: Commitment> ProverEvaluate<C::Scalar> for GroupByExec<C> {
     fn prover_evaluate<'a>(
         &self,
         builder: &mut ProofBuilder<'a, C::Scalar>,
-        alloc: &'a Bump,
+        alloc: &'a dyn MleAllocator,
         accessor: &'a dyn DataAccessor<C::Scalar>,
     ) {
         // 1. selection
@@ -297,7 +309,7 @@ impl<C: Commitment> ProverEvaluate<C::Scalar> for GroupByExec<C> {
     }
 }
 
-fn verify_group_by<C: Commitment>(
+pub(super) fn verify_group_by<C: Commitment>(
     builder: &mut VerificationBuilder<C>,
     alpha: C::Scalar,
     beta: C::Scalar,
@@ -338,9 +350,10 @@ fn verify_group_by<C: Commitment>(
     Ok(())
 }
 
+#[cfg(feature = "prover")]
 pub fn prove_group_by<'a, S: Scalar>(
     builder: &mut ProofBuilder<'a, S>,
-    alloc: &'a Bump,
+    alloc: &'a dyn MleAllocator,
     alpha: S,
     beta: S,
     (g_in, sum_in, sel_in): (&[Column<S>], &[Column<S>], &'a [bool]),
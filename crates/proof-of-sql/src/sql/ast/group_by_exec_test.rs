@@ -7,6 +7,7 @@ use crate::{
     },
     sql::proof::{exercise_verification, VerifiableQueryResult},
 };
+use std::collections::BTreeMap;
 
 /// select a, sum(c) as sum_c, count(*) as __count__ from sxt.t where b = 99 group by a
 #[test]
@@ -226,3 +227,45 @@ fn we_can_prove_a_complex_group_by_query_with_many_columns() {
     ]);
     assert_eq!(res, expected);
 }
+
+/// select a, b, sum(c) as sum_c, count(*) as __count__ from sxt.t where c <> 13 group by a, b
+#[test]
+fn we_can_prove_a_group_by_with_two_key_columns() {
+    let a: Vec<i64> = (0..40).map(|i| (i * 7) % 3).collect();
+    let b: Vec<i128> = (0..40).map(|i| ((i * 5) % 4) as i128 - 1).collect();
+    let c: Vec<i64> = (0..40).collect();
+    let data = owned_table([
+        bigint("a", a.clone()),
+        int128("b", b.clone()),
+        bigint("c", c.clone()),
+    ]);
+    let t = "sxt.t".parse().unwrap();
+    let mut accessor = OwnedTableTestAccessor::<InnerProductProof>::new_empty_with_setup(());
+    accessor.add_table(t, data, 0);
+    let expr = group_by(
+        cols_expr(t, &["a", "b"], &accessor),
+        vec![sum_expr(column(t, "c", &accessor), "sum_c")],
+        "__count__",
+        tab(t),
+        not(equal(column(t, "c", &accessor), const_bigint(13))),
+    );
+    let res = VerifiableQueryResult::new(&expr, &accessor, &());
+    exercise_verification(&res, &expr, &accessor, t);
+    let res = res.verify(&expr, &accessor, &()).unwrap().table;
+
+    // group the rows by both keys independently of the prover
+    let mut groups = BTreeMap::<(i64, i128), (i64, i64)>::new();
+    for ((&a, &b), &c) in a.iter().zip(&b).zip(&c).filter(|(_, c)| **c != 13) {
+        let (sum, count) = groups.entry((a, b)).or_default();
+        *sum += c;
+        *count += 1;
+    }
+    let expected = owned_table([
+        bigint("a", groups.keys().map(|&(a, _)| a)),
+        int128("b", groups.keys().map(|&(_, b)| b)),
+        bigint("sum_c", groups.values().map(|&(sum, _)| sum)),
+        bigint("__count__", groups.values().map(|&(_, count)| count)),
+    ]);
+    assert_eq!(groups.len(), 12);
+    assert_eq!(res, expected);
+}
@@ -0,0 +1,319 @@
+use super::{
+    count_sign,
+    distinct_chain::{count_distinct_chain, verifier_evaluate_distinct_chain},
+    verifier_evaluate_sign, ColumnExpr, GroupByExec, ProvableBooleanExpr, ProvableExpr,
+};
+#[cfg(feature = "prover")]
+use super::{
+    distinct_chain::{count_selected_values, prove_distinct_chain},
+    prover_evaluate_sign,
+    semi_join_expr::integer_values,
+};
+use crate::{
+    base::{
+        commitment::Commitment,
+        database::{
+            ColumnField, ColumnRef, ColumnType, CommitmentAccessor, MetadataAccessor, OwnedColumn,
+            OwnedTable,
+        },
+        proof::ProofError,
+        scalar::Scalar,
+    },
+    sql::{
+        parse::{ConversionError, ConversionResult},
+        proof::{CountBuilder, ProofExecutionPlan, VerificationBuilder},
+    },
+};
+#[cfg(feature = "prover")]
+use crate::{
+    base::{
+        database::{Column, DataAccessor},
+        MleAllocator,
+    },
+    sql::proof::{Indexes, ProofBuilder, ProverEvaluate, ResultBuilder, SumcheckSubpolynomialType},
+};
+use indexmap::IndexSet;
+use num_traits::One;
+use serde::{Deserialize, Serialize};
+#[cfg(feature = "prover")]
+use std::collections::BTreeMap;
+
+/// A value smaller than any key, used as the first node of the sorted chain of group keys.
+const LOWER_SENTINEL: i128 = i64::MIN as i128 - 1;
+
+/// Provable expressions for queries of the form
+/// ```ignore
+///     SELECT <group_by_expr>, COUNT(*) as count_alias
+///     FROM <table>
+///     WHERE <where_clause>
+///     GROUP BY <group_by_expr>
+///     HAVING COUNT(*) > <threshold>
+/// ```
+///
+/// The group by must have a single `SmallInt`, `Int` or `BigInt` column and no sums. The result
+/// holds the groups whose count passes the predicate, ordered by key, and is empty if none do.
+///
+/// The prover commits to the keys of every group, passing or not, in increasing order
+/// `k_1 < ... < k_m`, which are shown to be the distinct keys of the selected rows with the
+/// argument of [`verifier_evaluate_distinct_chain`], so that the multiplicities of the chain are
+/// the counts of the groups. A group is selected when the sign bit of `count - threshold - 1` is
+/// zero, and the result holds the selected rows of the group table.
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct HavingExpr<C: Commitment> {
+    pub(super) group_by: GroupByExec<C>,
+    pub(super) threshold: i64,
+}
+
+impl<C: Commitment> HavingExpr<C> {
+    /// Creates a new having expression, keeping the groups of `group_by` whose count is greater
+    /// than `threshold`.
+    ///
+    /// Every group has a count of at least one, so a negative threshold is the same as zero.
+    pub fn try_new(group_by: GroupByExec<C>, threshold: i64) -> ConversionResult<Self> {
+        if group_by.group_by_exprs.len() != 1 || !group_by.sum_expr.is_empty() {
+            return Err(ConversionError::InvalidExpression(
+                "HAVING is only supported on a group by of a single column without sums"
+                    .to_string(),
+            ));
+        }
+        match group_by.group_by_exprs[0].data_type() {
+            ColumnType::SmallInt | ColumnType::Int | ColumnType::BigInt => Ok(Self {
+                group_by,
+                threshold: threshold.max(0),
+            }),
+            data_type => Err(ConversionError::InvalidDataType {
+                expected: ColumnType::BigInt,
+                actual: data_type,
+            }),
+        }
+    }
+
+    fn key(&self) -> &ColumnExpr<C> {
+        &self.group_by.group_by_exprs[0]
+    }
+}
+
+impl<C: Commitment> ProofExecutionPlan<C> for HavingExpr<C> {
+    fn count(
+        &self,
+        builder: &mut CountBuilder,
+        _accessor: &dyn MetadataAccessor,
+    ) -> Result<(), ProofError> {
+        self.group_by.where_clause.count(builder)?;
+        self.key().count(builder)?;
+        builder.count_result_columns(2);
+        builder.count_post_result_challenges(1);
+        builder.count_intermediate_mles(1);
+        count_distinct_chain(builder)?;
+        // the selection of the groups
+        count_sign(builder)?;
+        builder.count_subpolynomials(2);
+        Ok(())
+    }
+
+    fn get_length(&self, accessor: &dyn MetadataAccessor) -> usize {
+        self.group_by.get_length(accessor)
+    }
+
+    fn get_offset(&self, accessor: &dyn MetadataAccessor) -> usize {
+        self.group_by.get_offset(accessor)
+    }
+
+    fn verifier_evaluate(
+        &self,
+        builder: &mut VerificationBuilder<C>,
+        accessor: &dyn CommitmentAccessor<C>,
+        result: Option<&OwnedTable<C::Scalar>>,
+    ) -> Result<(), ProofError> {
+        let one_eval = builder.mle_evaluations.one_evaluation;
+        let rand_eval = builder.mle_evaluations.random_evaluation;
+
+        // 1. selection
+        let s = self
+            .group_by
+            .where_clause
+            .verifier_evaluate_selection(builder, accessor)?;
+        // 2. column
+        let a = self.key().verifier_evaluate(builder, accessor)?;
+        // 3. result
+        let key_result = builder.consume_result_mle();
+        let count_result = builder.consume_result_mle();
+        match result {
+            Some(table) => match table.inner_table().get(&self.group_by.count_alias) {
+                // A result row outside of the selected groups would have a count of zero.
+                Some(OwnedColumn::BigInt(counts))
+                    if counts.iter().all(|&count| count > self.threshold) => {}
+                _ => Err(ProofError::VerificationError(
+                    "Result of having does not hold only passing groups.",
+                ))?,
+            },
+            None => Err(ProofError::VerificationError(
+                "HavingExpr is only supported at the top level of a query plan.",
+            ))?,
+        }
+
+        let gamma = builder.consume_post_result_challenge();
+
+        // 4. the chain of sorted group keys
+        let k = builder.consume_intermediate_mle();
+        let (p, c) = verifier_evaluate_distinct_chain(
+            builder,
+            gamma,
+            C::Scalar::from(LOWER_SENTINEL),
+            s,
+            a,
+            k,
+        )?;
+
+        // the selected groups are those where sign(c - threshold - 1) == 0
+        let threshold = C::Scalar::from(self.threshold) + C::Scalar::one();
+        let sign = verifier_evaluate_sign(builder, c - threshold * one_eval, one_eval)?;
+        // key_result - p * (1 - sign) * k = 0
+        builder.produce_sumcheck_subpolynomial_evaluation(
+            &(rand_eval * (key_result - p * k + p * sign * k)),
+        );
+        // count_result - p * (1 - sign) * c = 0
+        builder.produce_sumcheck_subpolynomial_evaluation(
+            &(rand_eval * (count_result - p * c + p * sign * c)),
+        );
+        Ok(())
+    }
+
+    fn get_column_result_fields(&self) -> Vec<ColumnField> {
+        self.group_by.get_column_result_fields()
+    }
+
+    fn get_column_references(&self) -> IndexSet<ColumnRef> {
+        self.group_by.get_column_references()
+    }
+}
+
+#[cfg(feature = "prover")]
+impl<C: Commitment> ProverEvaluate<C::Scalar> for HavingExpr<C> {
+    #[tracing::instrument(name = "HavingExpr::result_evaluate", level = "debug", skip_all)]
+    fn result_evaluate<'a>(
+        &self,
+        builder: &mut ResultBuilder<'a>,
+        alloc: &'a dyn MleAllocator,
+        accessor: &'a dyn DataAccessor<C::Scalar>,
+    ) {
+        let n = builder.table_length();
+        let selection = self
+            .group_by
+            .where_clause
+            .result_evaluate_selection(n, alloc, accessor);
+        let column = self.key().result_evaluate(n, alloc, accessor);
+        let groups = count_selected_values(&integer_values(&column), selection);
+        let (keys, counts) = group_table(n, &groups);
+        builder.set_result_indexes(Indexes::Sparse(
+            (0..groups.len())
+                .filter(|&i| counts[i] > self.threshold)
+                .map(|i| i as u64)
+                .collect(),
+        ));
+        builder.produce_result_column(key_column::<C::Scalar>(
+            alloc,
+            self.key().data_type(),
+            &keys,
+        ));
+        builder.produce_result_column(alloc.alloc_slice_copy(&counts) as &[_]);
+        builder.request_post_result_challenges(1);
+    }
+
+    #[tracing::instrument(name = "HavingExpr::prover_evaluate", level = "debug", skip_all)]
+    fn prover_evaluate<'a>(
+        &self,
+        builder: &mut ProofBuilder<'a, C::Scalar>,
+        alloc: &'a dyn MleAllocator,
+        accessor: &'a dyn DataAccessor<C::Scalar>,
+    ) {
+        let n = builder.table_length();
+        let s = self
+            .group_by
+            .where_clause
+            .prover_evaluate_selection(builder, alloc, accessor);
+        let a_column = self.key().prover_evaluate(builder, alloc, accessor);
+        let a = integer_values(&a_column);
+        let gamma = builder.consume_post_result_challenge();
+
+        // the chain of sorted group keys
+        let groups = count_selected_values(&a, s);
+        let (keys, _) = group_table(n, &groups);
+        let k = alloc.alloc_slice_copy(&keys) as &[_];
+        builder.produce_intermediate_mle(k);
+        let (p, c) = prove_distinct_chain(
+            builder,
+            alloc,
+            gamma,
+            C::Scalar::from(LOWER_SENTINEL),
+            s,
+            alloc.alloc_slice_fill_with(n, |i| C::Scalar::from(a[i])),
+            alloc.alloc_slice_fill_with(n, |i| C::Scalar::from(k[i])),
+        );
+
+        // the selected groups are those where sign(c - threshold - 1) == 0
+        let one = C::Scalar::one();
+        let threshold = C::Scalar::from(self.threshold) + one;
+        let d_scalars =
+            alloc.alloc_slice_fill_with(n, |i| C::Scalar::from(c[i]) - threshold) as &[_];
+        let sign = prover_evaluate_sign(
+            builder,
+            alloc,
+            d_scalars,
+            #[cfg(test)]
+            false,
+        );
+        let key_result =
+            alloc.alloc_slice_fill_with(n, |i| if p[i] && !sign[i] { k[i] } else { 0 }) as &[_];
+        let count_result =
+            alloc.alloc_slice_fill_with(n, |i| if p[i] && !sign[i] { c[i] } else { 0 }) as &[_];
+        // key_result - p * (1 - sign) * k = 0
+        builder.produce_sumcheck_subpolynomial(
+            SumcheckSubpolynomialType::Identity,
+            vec![
+                (one, vec![Box::new(key_result)]),
+                (-one, vec![Box::new(p), Box::new(k)]),
+                (one, vec![Box::new(p), Box::new(sign), Box::new(k)]),
+            ],
+        );
+        // count_result - p * (1 - sign) * c = 0
+        builder.produce_sumcheck_subpolynomial(
+            SumcheckSubpolynomialType::Identity,
+            vec![
+                (one, vec![Box::new(count_result)]),
+                (-one, vec![Box::new(p), Box::new(c)]),
+                (one, vec![Box::new(p), Box::new(sign), Box::new(c)]),
+            ],
+        );
+    }
+}
+
+/// Lays out the groups as the first rows of columns of keys and counts of length `n`.
+#[cfg(feature = "prover")]
+fn group_table(n: usize, groups: &BTreeMap<i64, i64>) -> (Vec<i64>, Vec<i64>) {
+    let mut keys = vec![0; n];
+    let mut counts = vec![0; n];
+    for (i, (&key, &count)) in groups.iter().enumerate() {
+        keys[i] = key;
+        counts[i] = count;
+    }
+    (keys, counts)
+}
+
+/// Converts group keys back to the type of the group by column.
+#[cfg(feature = "prover")]
+fn key_column<'a, S: Scalar>(
+    alloc: &'a dyn MleAllocator,
+    data_type: ColumnType,
+    keys: &[i64],
+) -> Column<'a, S> {
+    match data_type {
+        ColumnType::SmallInt => {
+            Column::SmallInt(alloc.alloc_slice_fill_iter(keys.iter().map(|&key| key as i16)))
+        }
+        ColumnType::Int => {
+            Column::Int(alloc.alloc_slice_fill_iter(keys.iter().map(|&key| key as i32)))
+        }
+        _ => Column::BigInt(alloc.alloc_slice_copy(keys)),
+    }
+}
@@ -0,0 +1,198 @@
+use super::{test_utility::*, GroupByExec, HavingExpr};
+use crate::{
+    base::{
+        commitment::InnerProductProof,
+        database::{owned_table_utility::*, ColumnType, OwnedTableTestAccessor, TestAccessor},
+    },
+    sql::{
+        parse::ConversionError,
+        proof::{exercise_verification, VerifiableQueryResult},
+    },
+};
+use curve25519_dalek::ristretto::RistrettoPoint;
+use rand::{
+    distributions::{Distribution, Uniform},
+    rngs::StdRng,
+};
+use rand_core::SeedableRng;
+use std::collections::BTreeMap;
+
+/// select a, count(*) as __count__ from sxt.t where b = 99 group by a having count(*) > 2
+#[test]
+fn we_can_prove_a_simple_having_query() {
+    let data = owned_table([
+        bigint("a", [3, 1, 2, 3, 1, 3, 2, 1, 3, 1]),
+        bigint("b", [99, 99, 99, 99, 99, 99, 99, 0, 99, 99]),
+    ]);
+    let t = "sxt.t".parse().unwrap();
+    let mut accessor = OwnedTableTestAccessor::<InnerProductProof>::new_empty_with_setup(());
+    accessor.add_table(t, data, 0);
+    let expr = having(
+        cols_expr(t, &["a"], &accessor),
+        "__count__",
+        tab(t),
+        equal(column(t, "b", &accessor), const_bigint(99)),
+        2,
+    );
+    let res = VerifiableQueryResult::new(&expr, &accessor, &());
+    exercise_verification(&res, &expr, &accessor, t);
+    let res = res.verify(&expr, &accessor, &()).unwrap().table;
+    let expected = owned_table([bigint("a", [1, 3]), bigint("__count__", [3, 4])]);
+    assert_eq!(res, expected);
+}
+
+/// select a, count(*) as __count__ from sxt.t group by a having count(*) > 1
+#[test]
+fn we_can_prove_a_having_query_with_a_smallint_column_and_extreme_keys() {
+    let data = owned_table([smallint(
+        "a",
+        [i16::MAX, i16::MIN, 0, i16::MIN, i16::MAX, 7],
+    )]);
+    let t = "sxt.t".parse().unwrap();
+    let mut accessor = OwnedTableTestAccessor::<InnerProductProof>::new_empty_with_setup(());
+    accessor.add_table(t, data, 0);
+    let expr = having(
+        cols_expr(t, &["a"], &accessor),
+        "__count__",
+        tab(t),
+        const_bool(true),
+        1,
+    );
+    let res = VerifiableQueryResult::new(&expr, &accessor, &());
+    exercise_verification(&res, &expr, &accessor, t);
+    let res = res.verify(&expr, &accessor, &()).unwrap().table;
+    let expected = owned_table([
+        smallint("a", [i16::MIN, i16::MAX]),
+        bigint("__count__", [2, 2]),
+    ]);
+    assert_eq!(res, expected);
+}
+
+/// select a, count(*) as __count__ from sxt.t group by a having count(*) > 5
+#[test]
+fn we_can_prove_a_having_query_where_no_group_passes() {
+    let data = owned_table([bigint("a", [1, 2, 2, 1, 2])]);
+    let t = "sxt.t".parse().unwrap();
+    let mut accessor = OwnedTableTestAccessor::<InnerProductProof>::new_empty_with_setup(());
+    accessor.add_table(t, data, 0);
+    let expr = having(
+        cols_expr(t, &["a"], &accessor),
+        "__count__",
+        tab(t),
+        const_bool(true),
+        5,
+    );
+    let res = VerifiableQueryResult::new(&expr, &accessor, &());
+    exercise_verification(&res, &expr, &accessor, t);
+    let res = res.verify(&expr, &accessor, &()).unwrap().table;
+    let expected = owned_table([bigint("a", [0; 0]), bigint("__count__", [0; 0])]);
+    assert_eq!(res, expected);
+}
+
+/// select a, count(*) as __count__ from sxt.t where b = 1 group by a having count(*) > 0
+#[test]
+fn we_can_prove_a_having_query_with_an_empty_selection() {
+    let data = owned_table([int("a", [1, 2, 2]), bigint("b", [0, 0, 0])]);
+    let t = "sxt.t".parse().unwrap();
+    let mut accessor = OwnedTableTestAccessor::<InnerProductProof>::new_empty_with_setup(());
+    accessor.add_table(t, data, 0);
+    let expr = having(
+        cols_expr(t, &["a"], &accessor),
+        "__count__",
+        tab(t),
+        equal(column(t, "b", &accessor), const_bigint(1)),
+        0,
+    );
+    let res = VerifiableQueryResult::new(&expr, &accessor, &());
+    exercise_verification(&res, &expr, &accessor, t);
+    let res = res.verify(&expr, &accessor, &()).unwrap().table;
+    let expected = owned_table([int("a", [0; 0]), bigint("__count__", [0; 0])]);
+    assert_eq!(res, expected);
+}
+
+#[test]
+fn we_cannot_create_a_having_query_on_a_non_integer_column_or_with_sums() {
+    let data = owned_table([varchar("a", ["x", "y"]), bigint("b", [1, 2])]);
+    let t = "sxt.t".parse().unwrap();
+    let mut accessor = OwnedTableTestAccessor::<InnerProductProof>::new_empty_with_setup(());
+    accessor.add_table(t, data, 0);
+    let res = HavingExpr::<RistrettoPoint>::try_new(
+        GroupByExec::new(
+            cols_expr(t, &["a"], &accessor),
+            vec![],
+            "__count__".parse().unwrap(),
+            tab(t),
+            const_bool(true),
+        ),
+        1,
+    );
+    assert!(matches!(
+        res,
+        Err(ConversionError::InvalidDataType {
+            actual: ColumnType::VarChar,
+            ..
+        })
+    ));
+    let res = HavingExpr::<RistrettoPoint>::try_new(
+        GroupByExec::new(
+            cols_expr(t, &["b"], &accessor),
+            vec![sum_expr(column(t, "b", &accessor), "sum_b")],
+            "__count__".parse().unwrap(),
+            tab(t),
+            const_bool(true),
+        ),
+        1,
+    );
+    assert!(matches!(res, Err(ConversionError::InvalidExpression(_))));
+}
+
+fn test_random_tables_with_given_offset(offset: usize) {
+    let dist = Uniform::new(-3, 4);
+    let mut rng = StdRng::from_seed([0u8; 32]);
+    for _ in 0..20 {
+        let n = Uniform::new(1, 21).sample(&mut rng);
+        let a: Vec<i64> = dist.sample_iter(&mut rng).take(n).collect();
+        let b: Vec<i64> = dist.sample_iter(&mut rng).take(n).collect();
+        let filter_val = dist.sample(&mut rng);
+        let threshold = Uniform::new(-1, 4).sample(&mut rng);
+
+        let t = "sxt.t".parse().unwrap();
+        let mut accessor = OwnedTableTestAccessor::<InnerProductProof>::new_empty_with_setup(());
+        accessor.add_table(
+            t,
+            owned_table([bigint("a", a.clone()), bigint("b", b.clone())]),
+            offset,
+        );
+        let expr = having(
+            cols_expr(t, &["a"], &accessor),
+            "__count__",
+            tab(t),
+            lte(column(t, "b", &accessor), const_bigint(filter_val)),
+            threshold,
+        );
+        let res = VerifiableQueryResult::new(&expr, &accessor, &());
+        exercise_verification(&res, &expr, &accessor, t);
+        let res = res.verify(&expr, &accessor, &()).unwrap().table;
+
+        let mut counts = BTreeMap::<i64, i64>::new();
+        for (a, _) in a.iter().zip(&b).filter(|(_, b)| **b <= filter_val) {
+            *counts.entry(*a).or_default() += 1;
+        }
+        let (expected_a, expected_count): (Vec<_>, Vec<_>) = counts
+            .into_iter()
+            .filter(|(_, count)| *count > threshold)
+            .unzip();
+        let expected = owned_table([bigint("a", expected_a), bigint("__count__", expected_count)]);
+        assert_eq!(res, expected);
+    }
+}
+
+#[test]
+fn we_can_query_random_tables_using_a_zero_offset() {
+    test_random_tables_with_given_offset(0);
+}
+
+#[test]
+fn we_can_query_random_tables_using_a_non_zero_offset() {
+    test_random_tables_with_given_offset(121);
+}
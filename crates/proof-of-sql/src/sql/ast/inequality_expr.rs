@@ -1,19 +1,30 @@
 use super::{
-    count_equals_zero, count_or, count_sign, prover_evaluate_equals_zero, prover_evaluate_or,
-    prover_evaluate_sign, result_evaluate_equals_zero, result_evaluate_or, result_evaluate_sign,
-    scale_and_add_subtract_eval, scale_and_subtract, verifier_evaluate_equals_zero,
-    verifier_evaluate_or, verifier_evaluate_sign, ProvableExpr, ProvableExprPlan,
+    count_equals_zero, count_or, count_sign_with_bit_width, scale_and_add_subtract_eval,
+    verifier_evaluate_equals_zero, verifier_evaluate_or, verifier_evaluate_sign,
+    ProvableBooleanExpr, ProvableExpr, ProvableExprPlan,
+};
+#[cfg(feature = "prover")]
+use super::{
+    prover_evaluate_equals_zero, prover_evaluate_or, prover_evaluate_sign,
+    result_evaluate_equals_zero, result_evaluate_or, result_evaluate_sign, scale_and_subtract,
 };
 use crate::{
     base::{
         commitment::Commitment,
-        database::{Column, ColumnRef, ColumnType, CommitmentAccessor, DataAccessor},
+        database::{
+            Column, ColumnRef, ColumnType, CommitmentAccessor, LiteralValue, MetadataAccessor,
+        },
         proof::ProofError,
     },
-    sql::proof::{CountBuilder, ProofBuilder, VerificationBuilder},
+    sql::proof::{CountBuilder, VerificationBuilder},
+};
+#[cfg(feature = "prover")]
+use crate::{
+    base::{database::DataAccessor, MleAllocator},
+    sql::proof::ProofBuilder,
 };
-use bumpalo::Bump;
 use indexmap::IndexSet;
+use num_traits::Zero;
 use serde::{Deserialize, Serialize};
 
 /// Provable AST expression for an inequality expression
@@ -22,6 +33,12 @@ pub struct InequalityExpr<C: Commitment> {
     lhs: Box<ProvableExprPlan<C>>,
     rhs: Box<ProvableExprPlan<C>>,
     is_lte: bool,
+    /// The result of the comparison when it is already determined by known column bounds
+    #[serde(default)]
+    bounded_result: Option<bool>,
+    /// The number of bits the absolute difference of the operands is declared to fit in
+    #[serde(default)]
+    bit_width: Option<u8>,
     #[cfg(test)]
     pub(crate) treat_column_of_zeros_as_negative: bool,
 }
@@ -33,18 +50,96 @@ impl<C: Commitment> InequalityExpr<C> {
             lhs,
             rhs,
             is_lte,
+            bounded_result: None,
+            bit_width: None,
             #[cfg(test)]
             treat_column_of_zeros_as_negative: false,
         }
     }
+
+    /// Use the column bounds known to `accessor` to skip proving the comparison
+    ///
+    /// If the bounds guarantee the result of the comparison for every row, no sign decomposition
+    /// is needed. The verifier checks that its own accessor knows bounds that guarantee the
+    /// same result.
+    ///
+    /// Otherwise, the sign decomposition is declared to only need as many bits as the bounds
+    /// allow the difference of the operands to have. The verifier rejects proofs whose
+    /// decomposition is any wider.
+    pub fn with_column_bounds(mut self, accessor: &(impl MetadataAccessor + ?Sized)) -> Self {
+        self.bounded_result = self.result_from_bounds(accessor);
+        self.bit_width = match (self.bounded_result, self.diff_bounds(accessor)) {
+            (None, Some((diff_min, diff_max))) => {
+                let max_abs = diff_min.unsigned_abs().max(diff_max.unsigned_abs());
+                Some((u128::BITS - max_abs.leading_zeros()) as u8)
+            }
+            _ => None,
+        };
+        self
+    }
+
+    /// The bounds of lhs - rhs, or rhs - lhs for a greater than or equal comparison, if the
+    /// column bounds known to `accessor` determine them
+    fn diff_bounds(&self, accessor: &(impl MetadataAccessor + ?Sized)) -> Option<(i128, i128)> {
+        let (lhs_min, lhs_max) = expr_bounds(&self.lhs, accessor)?;
+        let (rhs_min, rhs_max) = expr_bounds(&self.rhs, accessor)?;
+        if self.is_lte {
+            Some((lhs_min.checked_sub(rhs_max)?, lhs_max.checked_sub(rhs_min)?))
+        } else {
+            Some((rhs_min.checked_sub(lhs_max)?, rhs_max.checked_sub(lhs_min)?))
+        }
+    }
+
+    /// The result of the comparison if the column bounds known to `accessor` guarantee it
+    fn result_from_bounds(&self, accessor: &(impl MetadataAccessor + ?Sized)) -> Option<bool> {
+        let (diff_min, diff_max) = self.diff_bounds(accessor)?;
+        if diff_max <= 0 {
+            Some(true)
+        } else if diff_min > 0 {
+            Some(false)
+        } else {
+            None
+        }
+    }
+}
+
+/// The `(min, max)` bounds of an integer column or literal, if they are known
+fn expr_bounds<C: Commitment>(
+    expr: &ProvableExprPlan<C>,
+    accessor: &(impl MetadataAccessor + ?Sized),
+) -> Option<(i128, i128)> {
+    match expr {
+        ProvableExprPlan::Column(expr) => {
+            let column_ref = expr.get_column_reference();
+            if !column_ref.column_type().is_integer() {
+                return None;
+            }
+            let (min, max) = accessor.column_bounds(column_ref)?;
+            Some((min.into(), max.into()))
+        }
+        ProvableExprPlan::Literal(expr) => {
+            let value = match expr.value() {
+                LiteralValue::SmallInt(value) => (*value).into(),
+                LiteralValue::Int(value) => (*value).into(),
+                LiteralValue::BigInt(value) => (*value).into(),
+                LiteralValue::Int128(value) => *value,
+                _ => return None,
+            };
+            Some((value, value))
+        }
+        _ => None,
+    }
 }
 
 impl<C: Commitment> ProvableExpr<C> for InequalityExpr<C> {
     fn count(&self, builder: &mut CountBuilder) -> Result<(), ProofError> {
+        if self.bounded_result.is_some() {
+            return Ok(());
+        }
         self.lhs.count(builder)?;
         self.rhs.count(builder)?;
         count_equals_zero(builder);
-        count_sign(builder)?;
+        count_sign_with_bit_width(builder, self.bit_width)?;
         count_or(builder);
         Ok(())
     }
@@ -53,13 +148,53 @@ impl<C: Commitment> ProvableExpr<C> for InequalityExpr<C> {
         ColumnType::Boolean
     }
 
+    #[cfg(feature = "prover")]
     #[tracing::instrument(name = "InequalityExpr::result_evaluate", level = "debug", skip_all)]
     fn result_evaluate<'a>(
         &self,
         table_length: usize,
-        alloc: &'a Bump,
+        alloc: &'a dyn MleAllocator,
         accessor: &'a dyn DataAccessor<C::Scalar>,
     ) -> Column<'a, C::Scalar> {
+        Column::Boolean(self.result_evaluate_selection(table_length, alloc, accessor))
+    }
+
+    #[cfg(feature = "prover")]
+    #[tracing::instrument(name = "InequalityExpr::prover_evaluate", level = "debug", skip_all)]
+    fn prover_evaluate<'a>(
+        &self,
+        builder: &mut ProofBuilder<'a, C::Scalar>,
+        alloc: &'a dyn MleAllocator,
+        accessor: &'a dyn DataAccessor<C::Scalar>,
+    ) -> Column<'a, C::Scalar> {
+        Column::Boolean(self.prover_evaluate_selection(builder, alloc, accessor))
+    }
+
+    fn verifier_evaluate(
+        &self,
+        builder: &mut VerificationBuilder<C>,
+        accessor: &dyn CommitmentAccessor<C>,
+    ) -> Result<C::Scalar, ProofError> {
+        self.verifier_evaluate_selection(builder, accessor)
+    }
+
+    fn get_column_references(&self, columns: &mut IndexSet<ColumnRef>) {
+        self.lhs.get_column_references(columns);
+        self.rhs.get_column_references(columns);
+    }
+}
+
+impl<C: Commitment> ProvableBooleanExpr<C> for InequalityExpr<C> {
+    #[cfg(feature = "prover")]
+    fn result_evaluate_selection<'a>(
+        &self,
+        table_length: usize,
+        alloc: &'a dyn MleAllocator,
+        accessor: &'a dyn DataAccessor<C::Scalar>,
+    ) -> &'a [bool] {
+        if let Some(result) = self.bounded_result {
+            return alloc.alloc_slice_fill_copy(table_length, result);
+        }
         let lhs_column = self.lhs.result_evaluate(table_length, alloc, accessor);
         let rhs_column = self.rhs.result_evaluate(table_length, alloc, accessor);
         let lhs_scale = self.lhs.data_type().scale().unwrap_or(0);
@@ -79,16 +214,19 @@ impl<C: Commitment> ProvableExpr<C> for InequalityExpr<C> {
         let sign = result_evaluate_sign(table_length, alloc, diff);
 
         // (diff == 0) || (sign(diff) == -1)
-        Column::Boolean(result_evaluate_or(table_length, alloc, equals_zero, sign))
+        result_evaluate_or(table_length, alloc, equals_zero, sign)
     }
 
-    #[tracing::instrument(name = "InequalityExpr::prover_evaluate", level = "debug", skip_all)]
-    fn prover_evaluate<'a>(
+    #[cfg(feature = "prover")]
+    fn prover_evaluate_selection<'a>(
         &self,
         builder: &mut ProofBuilder<'a, C::Scalar>,
-        alloc: &'a Bump,
+        alloc: &'a dyn MleAllocator,
         accessor: &'a dyn DataAccessor<C::Scalar>,
-    ) -> Column<'a, C::Scalar> {
+    ) -> &'a [bool] {
+        if let Some(result) = self.bounded_result {
+            return alloc.alloc_slice_fill_copy(builder.table_length(), result);
+        }
         let lhs_column = self.lhs.prover_evaluate(builder, alloc, accessor);
         let rhs_column = self.rhs.prover_evaluate(builder, alloc, accessor);
         let lhs_scale = self.lhs.data_type().scale().unwrap_or(0);
@@ -114,15 +252,23 @@ impl<C: Commitment> ProvableExpr<C> for InequalityExpr<C> {
         );
 
         // (diff == 0) || (sign(diff) == -1)
-        Column::Boolean(prover_evaluate_or(builder, alloc, equals_zero, sign))
+        prover_evaluate_or(builder, alloc, equals_zero, sign)
     }
 
-    fn verifier_evaluate(
+    fn verifier_evaluate_selection(
         &self,
         builder: &mut VerificationBuilder<C>,
         accessor: &dyn CommitmentAccessor<C>,
     ) -> Result<C::Scalar, ProofError> {
         let one_eval = builder.mle_evaluations.one_evaluation;
+        if let Some(result) = self.bounded_result {
+            if self.result_from_bounds(accessor) != Some(result) {
+                return Err(ProofError::VerificationError(
+                    "column bounds do not determine the comparison",
+                ));
+            }
+            return Ok(if result { one_eval } else { Zero::zero() });
+        }
         let lhs_eval = self.lhs.verifier_evaluate(builder, accessor)?;
         let rhs_eval = self.rhs.verifier_evaluate(builder, accessor)?;
         let lhs_scale = self.lhs.data_type().scale().unwrap_or(0);
@@ -142,9 +288,4 @@ impl<C: Commitment> ProvableExpr<C> for InequalityExpr<C> {
         // (diff == 0) || (sign(diff) == -1)
         Ok(verifier_evaluate_or(builder, &equals_zero, &sign))
     }
-
-    fn get_column_references(&self, columns: &mut IndexSet<ColumnRef>) {
-        self.lhs.get_column_references(columns);
-        self.rhs.get_column_references(columns);
-    }
 }
@@ -1,9 +1,9 @@
 use crate::{
     base::{
-        commitment::InnerProductProof,
+        commitment::{CommitmentSnapshot, InnerProductProof, QueryCommitments, TableCommitment},
         database::{
-            owned_table_utility::*, Column, LiteralValue, OwnedTable, OwnedTableTestAccessor,
-            TestAccessor,
+            owned_table_utility::*, Column, ColumnRef, ColumnType, LiteralValue, MetadataAccessor,
+            OwnedTable, OwnedTableTestAccessor, TestAccessor,
         },
         math::decimal::scale_scalar,
         scalar::{Curve25519Scalar, Scalar},
@@ -588,3 +588,154 @@ fn we_can_compute_the_correct_output_of_a_gte_inequality_expr_using_result_evalu
     let expected_res = Column::Boolean(&[false, true, true]);
     assert_eq!(res, expected_res);
 }
+
+// select a, b from sxt.t where a <= 200, with a known to be within 0..=150
+#[test]
+fn we_can_skip_the_sign_decomposition_of_a_comparison_determined_by_column_bounds() {
+    let data = owned_table([
+        bigint("a", [0_i64, 17, 42, 99, 150]),
+        bigint("b", [1_i64, 2, 3, 4, 5]),
+    ]);
+    let t = "sxt.t".parse().unwrap();
+    let mut accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(t, data, 0, ());
+    accessor.set_column_bounds(
+        ColumnRef::new(t, "a".parse().unwrap(), ColumnType::BigInt),
+        (0, 150),
+    );
+    let where_clause: ProvableExprPlan<RistrettoPoint> =
+        lte(column(t, "a", &accessor), const_bigint(200));
+    let unbounded_ast = dense_filter(
+        cols_expr_plan(t, &["a", "b"], &accessor),
+        tab(t),
+        where_clause.clone(),
+    );
+    let bounded_ast = dense_filter(
+        cols_expr_plan(t, &["a", "b"], &accessor),
+        tab(t),
+        where_clause.with_column_bounds(&accessor),
+    );
+    let expected_res = owned_table([
+        bigint("a", [0_i64, 17, 42, 99, 150]),
+        bigint("b", [1_i64, 2, 3, 4, 5]),
+    ]);
+
+    let unbounded_res = VerifiableQueryResult::new(&unbounded_ast, &accessor, &());
+    exercise_verification(&unbounded_res, &unbounded_ast, &accessor, t);
+    let bounded_res = VerifiableQueryResult::new(&bounded_ast, &accessor, &());
+    exercise_verification(&bounded_res, &bounded_ast, &accessor, t);
+
+    let num_commitments = |res: &VerifiableQueryResult<InnerProductProof>| {
+        res.proof.as_ref().unwrap().commitments.len()
+    };
+    assert!(num_commitments(&bounded_res) < num_commitments(&unbounded_res));
+
+    let res = unbounded_res
+        .verify(&unbounded_ast, &accessor, &())
+        .unwrap()
+        .table;
+    assert_eq!(res, expected_res);
+    let res = bounded_res
+        .verify(&bounded_ast, &accessor, &())
+        .unwrap()
+        .table;
+    assert_eq!(res, expected_res);
+}
+
+#[test]
+fn we_cannot_verify_a_bounded_comparison_if_the_verifier_does_not_know_the_bounds() {
+    let data = owned_table([bigint("a", [0_i64, 17, 150]), bigint("b", [1_i64, 2, 3])]);
+    let t = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(t, data, 0, ());
+    let mut bounded_accessor = accessor.clone();
+    bounded_accessor.set_column_bounds(
+        ColumnRef::new(t, "a".parse().unwrap(), ColumnType::BigInt),
+        (0, 150),
+    );
+    let ast = dense_filter(
+        cols_expr_plan(t, &["b"], &accessor),
+        tab(t),
+        lte(column(t, "a", &accessor), const_bigint(200)).with_column_bounds(&bounded_accessor),
+    );
+    let res = VerifiableQueryResult::new(&ast, &bounded_accessor, &());
+    assert!(res.verify(&ast, &bounded_accessor, &()).is_ok());
+    assert!(res.verify(&ast, &accessor, &()).is_err());
+}
+
+// select b from sxt.t where a <= 200, with the bounds of a taken from its commitment metadata
+#[test]
+fn we_can_verify_a_bounded_comparison_against_commitments_that_record_the_bounds() {
+    let data = owned_table([bigint("a", [0_i64, 17, 150]), bigint("b", [1_i64, 2, 3])]);
+    let t = "sxt.t".parse().unwrap();
+    let accessor =
+        OwnedTableTestAccessor::<InnerProductProof>::new_from_table(t, data.clone(), 0, ());
+    let commitments = QueryCommitments::from_iter([(
+        t,
+        TableCommitment::from_owned_table_with_offset(&data, 0, &()),
+    )]);
+    let a = ColumnRef::new(t, "a".parse().unwrap(), ColumnType::BigInt);
+    assert_eq!(commitments.column_bounds(a), Some((0, 150)));
+
+    let ast = dense_filter(
+        cols_expr_plan(t, &["b"], &accessor),
+        tab(t),
+        lte(column(t, "a", &accessor), const_bigint(200)).with_column_bounds(&commitments),
+    );
+    let res = VerifiableQueryResult::new(&ast, &accessor, &());
+    let expected_res = owned_table([bigint("b", [1_i64, 2, 3])]);
+    assert_eq!(
+        res.verify(&ast, &commitments, &()).unwrap().table,
+        expected_res
+    );
+
+    // the bounds carry over to a snapshot of the commitments
+    let snapshot = CommitmentSnapshot::from_accessor([a], &commitments);
+    assert_eq!(snapshot.column_bounds(a), Some((0, 150)));
+}
+
+// select b from sxt.t where a <= 0, with a known to be within 0..=255
+#[test]
+fn a_comparison_over_a_bounded_column_only_decomposes_the_bits_of_the_bounds() {
+    let data = owned_table([
+        bigint("a", [0_i64, 1, 2, 4, 8, 16, 32, 64, 128, 255]),
+        bigint("b", [0_i64, 1, 2, 3, 4, 5, 6, 7, 8, 9]),
+    ]);
+    let t = "sxt.t".parse().unwrap();
+    let mut accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(t, data, 0, ());
+    accessor.set_column_bounds(
+        ColumnRef::new(t, "a".parse().unwrap(), ColumnType::BigInt),
+        (0, 255),
+    );
+    let ast = dense_filter(
+        cols_expr_plan(t, &["b"], &accessor),
+        tab(t),
+        lte(column(t, "a", &accessor), const_bigint(0)).with_column_bounds(&accessor),
+    );
+    let res = VerifiableQueryResult::new(&ast, &accessor, &());
+    exercise_verification(&res, &ast, &accessor, t);
+    let bit_distributions = &res.proof.as_ref().unwrap().bit_distributions;
+    assert_eq!(bit_distributions.len(), 1);
+    assert_eq!(bit_distributions[0].num_varying_bits(), 8);
+    let res = res.verify(&ast, &accessor, &()).unwrap().table;
+    assert_eq!(res, owned_table([bigint("b", [0_i64])]));
+}
+
+#[test]
+fn we_cannot_verify_a_comparison_over_a_value_wider_than_the_declared_bit_width() {
+    let data = owned_table([
+        bigint("a", [0_i64, 1, 255, 256]),
+        bigint("b", [0_i64, 1, 2, 3]),
+    ]);
+    let t = "sxt.t".parse().unwrap();
+    let mut accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(t, data, 0, ());
+    accessor.set_column_bounds(
+        ColumnRef::new(t, "a".parse().unwrap(), ColumnType::BigInt),
+        (0, 255),
+    );
+    let ast = dense_filter(
+        cols_expr_plan(t, &["b"], &accessor),
+        tab(t),
+        lte(column(t, "a", &accessor), const_bigint(0)).with_column_bounds(&accessor),
+    );
+    let res = VerifiableQueryResult::new(&ast, &accessor, &());
+    assert!(res.verify(&ast, &accessor, &()).is_err());
+}
@@ -2,13 +2,20 @@ use super::ProvableExpr;
 use crate::{
     base::{
         commitment::Commitment,
-        database::{Column, ColumnRef, ColumnType, CommitmentAccessor, DataAccessor, LiteralValue},
+        database::{ColumnRef, ColumnType, CommitmentAccessor, LiteralValue},
         proof::ProofError,
         scalar::Scalar,
     },
-    sql::proof::{CountBuilder, ProofBuilder, VerificationBuilder},
+    sql::proof::{CountBuilder, VerificationBuilder},
+};
+#[cfg(feature = "prover")]
+use crate::{
+    base::{
+        database::{Column, DataAccessor},
+        MleAllocator,
+    },
+    sql::proof::ProofBuilder,
 };
-use bumpalo::Bump;
 use indexmap::IndexSet;
 use serde::{Deserialize, Serialize};
 
@@ -33,6 +40,11 @@ impl<S: Scalar> LiteralExpr<S> {
     pub fn new(value: LiteralValue<S>) -> Self {
         Self { value }
     }
+
+    /// The value of the literal
+    pub fn value(&self) -> &LiteralValue<S> {
+        &self.value
+    }
 }
 
 impl<C: Commitment> ProvableExpr<C> for LiteralExpr<C::Scalar> {
@@ -44,21 +56,23 @@ impl<C: Commitment> ProvableExpr<C> for LiteralExpr<C::Scalar> {
         self.value.column_type()
     }
 
+    #[cfg(feature = "prover")]
     #[tracing::instrument(name = "LiteralExpr::result_evaluate", level = "debug", skip_all)]
     fn result_evaluate<'a>(
         &self,
         table_length: usize,
-        alloc: &'a Bump,
+        alloc: &'a dyn MleAllocator,
         _accessor: &'a dyn DataAccessor<C::Scalar>,
     ) -> Column<'a, C::Scalar> {
         Column::from_literal_with_length(&self.value, table_length, alloc)
     }
 
+    #[cfg(feature = "prover")]
     #[tracing::instrument(name = "LiteralExpr::prover_evaluate", level = "debug", skip_all)]
     fn prover_evaluate<'a>(
         &self,
         builder: &mut ProofBuilder<'a, C::Scalar>,
-        alloc: &'a Bump,
+        alloc: &'a dyn MleAllocator,
         _accessor: &'a dyn DataAccessor<C::Scalar>,
     ) -> Column<'a, C::Scalar> {
         let table_length = builder.table_length();
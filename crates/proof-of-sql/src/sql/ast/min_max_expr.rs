@@ -0,0 +1,347 @@
+#[cfg(feature = "prover")]
+use super::prover_evaluate_sign;
+use super::{
+    count_sign, verifier_evaluate_sign, ProvableBooleanExpr, ProvableExpr, ProvableExprPlan,
+    TableExpr,
+};
+use crate::{
+    base::{
+        commitment::Commitment,
+        database::{
+            ColumnField, ColumnRef, ColumnType, CommitmentAccessor, MetadataAccessor, OwnedColumn,
+            OwnedTable,
+        },
+        proof::ProofError,
+        scalar::Scalar,
+    },
+    sql::{
+        parse::{ConversionError, ConversionResult},
+        proof::{CountBuilder, ProofExecutionPlan, VerificationBuilder},
+    },
+};
+#[cfg(feature = "prover")]
+use crate::{
+    base::{
+        database::{Column, DataAccessor},
+        MleAllocator,
+    },
+    sql::proof::{Indexes, ProofBuilder, ProverEvaluate, ResultBuilder, SumcheckSubpolynomialType},
+};
+use indexmap::IndexSet;
+use num_traits::{One, Zero};
+use proof_of_sql_parser::Identifier;
+use serde::{Deserialize, Serialize};
+
+/// Provable expressions for queries of the form
+/// ```ignore
+///     SELECT MIN(<expr>) AS <alias> FROM <table> WHERE <where_clause>
+/// ```
+/// when `IS_MAX` is false, or
+/// ```ignore
+///     SELECT MAX(<expr>) AS <alias> FROM <table> WHERE <where_clause>
+/// ```
+/// when `IS_MAX` is true.
+///
+/// `expr` must be a `BigInt` expression. The result has a single row containing the extremum,
+/// or no rows if the where clause selects nothing.
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct ExtremumExpr<C: Commitment, const IS_MAX: bool> {
+    pub(super) expr: ProvableExprPlan<C>,
+    pub(super) alias: Identifier,
+    pub(super) table: TableExpr,
+    pub(super) where_clause: ProvableExprPlan<C>,
+}
+
+/// Alias for a min expression.
+pub type MinExpr<C> = ExtremumExpr<C, false>;
+/// Alias for a max expression.
+pub type MaxExpr<C> = ExtremumExpr<C, true>;
+
+impl<C: Commitment, const IS_MAX: bool> ExtremumExpr<C, IS_MAX> {
+    /// Creates a new min or max expression.
+    ///
+    /// `expr` must be a `BigInt` expression.
+    pub fn try_new(
+        expr: ProvableExprPlan<C>,
+        alias: Identifier,
+        table: TableExpr,
+        where_clause: ProvableExprPlan<C>,
+    ) -> ConversionResult<Self> {
+        match expr.data_type() {
+            ColumnType::BigInt => Ok(Self {
+                expr,
+                alias,
+                table,
+                where_clause,
+            }),
+            data_type => Err(ConversionError::InvalidDataType {
+                expected: ColumnType::BigInt,
+                actual: data_type,
+            }),
+        }
+    }
+}
+
+impl<C: Commitment, const IS_MAX: bool> ProofExecutionPlan<C> for ExtremumExpr<C, IS_MAX> {
+    fn count(
+        &self,
+        builder: &mut CountBuilder,
+        _accessor: &dyn MetadataAccessor,
+    ) -> Result<(), ProofError> {
+        self.where_clause.count(builder)?;
+        self.expr.count(builder)?;
+        builder.count_result_columns(1);
+        builder.count_intermediate_mles(1);
+        builder.count_subpolynomials(5);
+        builder.count_degree(3);
+        count_sign(builder)?;
+        Ok(())
+    }
+
+    fn get_length(&self, accessor: &dyn MetadataAccessor) -> usize {
+        accessor.get_length(self.table.table_ref)
+    }
+
+    fn get_offset(&self, accessor: &dyn MetadataAccessor) -> usize {
+        accessor.get_offset(self.table.table_ref)
+    }
+
+    fn verifier_evaluate(
+        &self,
+        builder: &mut VerificationBuilder<C>,
+        accessor: &dyn CommitmentAccessor<C>,
+        result: Option<&OwnedTable<C::Scalar>>,
+    ) -> Result<(), ProofError> {
+        let one_eval = builder.mle_evaluations.one_evaluation;
+        let rand_eval = builder.mle_evaluations.random_evaluation;
+        // 1. selection
+        let selection_eval = self
+            .where_clause
+            .verifier_evaluate_selection(builder, accessor)?;
+        // 2. column
+        let column_eval = self.expr.verifier_evaluate(builder, accessor)?;
+        // 3. indexes
+        let chi_eval = builder
+            .mle_evaluations
+            .result_indexes_evaluation
+            .ok_or(ProofError::VerificationError("invalid indexes"))?;
+        // 4. result and witness
+        // The extremum is read directly from the result table below, so its MLE isn't needed.
+        let _ = builder.consume_result_mle();
+        let witness_eval = builder.consume_intermediate_mle();
+        let extremum: Option<C::Scalar> = match result {
+            Some(table) => match table.inner_table().get(&self.alias) {
+                Some(OwnedColumn::BigInt(values)) if values.len() <= 1 => {
+                    values.first().map(|&value| value.into())
+                }
+                _ => Err(ProofError::VerificationError(
+                    "Result of min/max must have at most one bigint row.",
+                ))?,
+            },
+            None => Err(ProofError::VerificationError(
+                "MinExpr and MaxExpr are only supported at the top level of a query plan.",
+            ))?,
+        };
+        let has_extremum = C::Scalar::from(extremum.is_some());
+        let extremum = extremum.unwrap_or_else(Zero::zero);
+
+        verify_extremum::<C, IS_MAX>(
+            builder,
+            one_eval,
+            rand_eval,
+            (selection_eval, column_eval, chi_eval, witness_eval),
+            (extremum, has_extremum),
+        )
+    }
+
+    fn get_column_result_fields(&self) -> Vec<ColumnField> {
+        vec![ColumnField::new(self.alias, ColumnType::BigInt)]
+    }
+
+    fn get_column_references(&self) -> IndexSet<ColumnRef> {
+        let mut columns = IndexSet::new();
+        self.expr.get_column_references(&mut columns);
+        self.where_clause.get_column_references(&mut columns);
+        columns
+    }
+}
+
+#[cfg(feature = "prover")]
+impl<C: Commitment, const IS_MAX: bool> ProverEvaluate<C::Scalar> for ExtremumExpr<C, IS_MAX> {
+    #[tracing::instrument(name = "ExtremumExpr::result_evaluate", level = "debug", skip_all)]
+    fn result_evaluate<'a>(
+        &self,
+        builder: &mut ResultBuilder<'a>,
+        alloc: &'a dyn MleAllocator,
+        accessor: &'a dyn DataAccessor<C::Scalar>,
+    ) {
+        let selection =
+            self.where_clause
+                .result_evaluate_selection(builder.table_length(), alloc, accessor);
+        let column = self
+            .expr
+            .result_evaluate(builder.table_length(), alloc, accessor);
+        let values = as_bigint(column);
+        let extremum = compute_extremum::<IS_MAX>(values, selection);
+        let result = alloc.alloc_slice_fill_iter(extremum.map(|(_, value)| value));
+        builder.set_result_indexes(Indexes::Dense(0..(result.len() as u64)));
+        builder.produce_result_column(result as &[_]);
+    }
+
+    #[tracing::instrument(name = "ExtremumExpr::prover_evaluate", level = "debug", skip_all)]
+    fn prover_evaluate<'a>(
+        &self,
+        builder: &mut ProofBuilder<'a, C::Scalar>,
+        alloc: &'a dyn MleAllocator,
+        accessor: &'a dyn DataAccessor<C::Scalar>,
+    ) {
+        let selection = self
+            .where_clause
+            .prover_evaluate_selection(builder, alloc, accessor);
+        let column = self.expr.prover_evaluate(builder, alloc, accessor);
+        let values = as_bigint(column);
+        prove_extremum::<C::Scalar, IS_MAX>(builder, alloc, values, selection);
+    }
+}
+
+#[cfg(feature = "prover")]
+fn as_bigint<'a, S: Scalar>(column: Column<'a, S>) -> &'a [i64] {
+    match column {
+        Column::BigInt(values) => values,
+        _ => panic!("min/max is only supported on bigint columns"),
+    }
+}
+
+/// Returns the index and value of the first selected row holding the extremum.
+#[cfg(feature = "prover")]
+fn compute_extremum<const IS_MAX: bool>(
+    values: &[i64],
+    selection: &[bool],
+) -> Option<(usize, i64)> {
+    let selected = values
+        .iter()
+        .copied()
+        .enumerate()
+        .zip(selection)
+        .filter_map(|(row, &is_selected)| is_selected.then_some(row));
+    if IS_MAX {
+        selected.reduce(|best, row| if row.1 > best.1 { row } else { best })
+    } else {
+        selected.reduce(|best, row| if row.1 < best.1 { row } else { best })
+    }
+}
+
+/// Proves that `m`, the claimed extremum, is the min (or max) of the selected rows of `a`.
+///
+/// The witness `w` is an indicator of a single selected row where `a == m`, which shows that
+/// `m` appears in the selection. The sign of `a - m` (or `m - a` for max) is then shown to be
+/// non-negative on every selected row. If the selection is empty, there is no result row and
+/// the selection is shown to be all false instead.
+#[cfg(feature = "prover")]
+fn prove_extremum<'a, S: Scalar, const IS_MAX: bool>(
+    builder: &mut ProofBuilder<'a, S>,
+    alloc: &'a dyn MleAllocator,
+    a: &'a [i64],
+    s: &'a [bool],
+) {
+    let n = builder.table_length();
+    let extremum = compute_extremum::<IS_MAX>(a, s);
+    let m = extremum.map_or(S::zero(), |(_, value)| S::from(value));
+    let has_extremum = S::from(extremum.is_some());
+
+    let w = alloc.alloc_slice_fill_copy(n, false);
+    let chi = alloc.alloc_slice_fill_copy(n, false);
+    if let Some((row, _)) = extremum {
+        w[row] = true;
+        chi[0] = true;
+    }
+    builder.produce_intermediate_mle(w as &[_]);
+
+    // w - w * s = 0
+    builder.produce_sumcheck_subpolynomial(
+        SumcheckSubpolynomialType::Identity,
+        vec![
+            (S::one(), vec![Box::new(w as &[_])]),
+            (-S::one(), vec![Box::new(w as &[_]), Box::new(s)]),
+        ],
+    );
+
+    // w * a - m * w = 0
+    builder.produce_sumcheck_subpolynomial(
+        SumcheckSubpolynomialType::Identity,
+        vec![
+            (S::one(), vec![Box::new(w as &[_]), Box::new(a)]),
+            (-m, vec![Box::new(w as &[_])]),
+        ],
+    );
+
+    // sum w - chi = 0
+    builder.produce_sumcheck_subpolynomial(
+        SumcheckSubpolynomialType::ZeroSum,
+        vec![
+            (S::one(), vec![Box::new(w as &[_])]),
+            (-S::one(), vec![Box::new(chi as &[_])]),
+        ],
+    );
+
+    // sum (1 - has_extremum) * s = 0
+    builder.produce_sumcheck_subpolynomial(
+        SumcheckSubpolynomialType::ZeroSum,
+        vec![(S::one() - has_extremum, vec![Box::new(s)])],
+    );
+
+    // sign(a - m) == 0 for min, sign(m - a) == 0 for max
+    let diff = alloc.alloc_slice_fill_with(n, |i| {
+        if IS_MAX {
+            m - S::from(a[i])
+        } else {
+            S::from(a[i]) - m
+        }
+    });
+    let sign = prover_evaluate_sign(
+        builder,
+        alloc,
+        diff,
+        #[cfg(test)]
+        false,
+    );
+
+    // s * sign = 0
+    builder.produce_sumcheck_subpolynomial(
+        SumcheckSubpolynomialType::Identity,
+        vec![(S::one(), vec![Box::new(s), Box::new(sign)])],
+    );
+}
+
+fn verify_extremum<C: Commitment, const IS_MAX: bool>(
+    builder: &mut VerificationBuilder<C>,
+    one_eval: C::Scalar,
+    rand_eval: C::Scalar,
+    (s_eval, a_eval, chi_eval, w_eval): (C::Scalar, C::Scalar, C::Scalar, C::Scalar),
+    (m, has_extremum): (C::Scalar, C::Scalar),
+) -> Result<(), ProofError> {
+    // w - w * s = 0
+    builder.produce_sumcheck_subpolynomial_evaluation(&(rand_eval * (w_eval - w_eval * s_eval)));
+
+    // w * a - m * w = 0
+    builder.produce_sumcheck_subpolynomial_evaluation(&(rand_eval * (w_eval * (a_eval - m))));
+
+    // sum w - chi = 0
+    builder.produce_sumcheck_subpolynomial_evaluation(&(w_eval - chi_eval));
+
+    // sum (1 - has_extremum) * s = 0
+    builder
+        .produce_sumcheck_subpolynomial_evaluation(&((C::Scalar::one() - has_extremum) * s_eval));
+
+    // sign(a - m) == 0 for min, sign(m - a) == 0 for max
+    let diff_eval = if IS_MAX {
+        m * one_eval - a_eval
+    } else {
+        a_eval - m * one_eval
+    };
+    let sign_eval = verifier_evaluate_sign(builder, diff_eval, one_eval)?;
+
+    // s * sign = 0
+    builder.produce_sumcheck_subpolynomial_evaluation(&(rand_eval * s_eval * sign_eval));
+    Ok(())
+}
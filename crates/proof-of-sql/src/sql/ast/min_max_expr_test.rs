@@ -0,0 +1,164 @@
+use super::{test_utility::*, MaxExpr};
+use crate::{
+    base::{
+        commitment::InnerProductProof,
+        database::{owned_table_utility::*, ColumnType, OwnedTableTestAccessor, TestAccessor},
+    },
+    sql::{
+        parse::ConversionError,
+        proof::{exercise_verification, VerifiableQueryResult},
+    },
+};
+use curve25519_dalek::ristretto::RistrettoPoint;
+use rand::{
+    distributions::{Distribution, Uniform},
+    rngs::StdRng,
+};
+use rand_core::SeedableRng;
+
+/// select min(a) as min_a from sxt.t where b = 99
+#[test]
+fn we_can_prove_a_simple_min_query() {
+    let data = owned_table([
+        bigint("a", [5, -2, 3, -7, 1]),
+        bigint("b", [99, 99, 99, 0, 99]),
+    ]);
+    let t = "sxt.t".parse().unwrap();
+    let mut accessor = OwnedTableTestAccessor::<InnerProductProof>::new_empty_with_setup(());
+    accessor.add_table(t, data, 0);
+    let expr = min_expr(
+        column(t, "a", &accessor),
+        "min_a",
+        tab(t),
+        equal(column(t, "b", &accessor), const_bigint(99)),
+    );
+    let res = VerifiableQueryResult::new(&expr, &accessor, &());
+    exercise_verification(&res, &expr, &accessor, t);
+    let res = res.verify(&expr, &accessor, &()).unwrap().table;
+    assert_eq!(res, owned_table([bigint("min_a", [-2])]));
+}
+
+/// select max(a) as max_a from sxt.t where b = 99
+#[test]
+fn we_can_prove_a_simple_max_query() {
+    let data = owned_table([
+        bigint("a", [5, -2, 3, 7, 5]),
+        bigint("b", [99, 99, 99, 0, 99]),
+    ]);
+    let t = "sxt.t".parse().unwrap();
+    let mut accessor = OwnedTableTestAccessor::<InnerProductProof>::new_empty_with_setup(());
+    accessor.add_table(t, data, 0);
+    let expr = max_expr(
+        column(t, "a", &accessor),
+        "max_a",
+        tab(t),
+        equal(column(t, "b", &accessor), const_bigint(99)),
+    );
+    let res = VerifiableQueryResult::new(&expr, &accessor, &());
+    exercise_verification(&res, &expr, &accessor, t);
+    let res = res.verify(&expr, &accessor, &()).unwrap().table;
+    assert_eq!(res, owned_table([bigint("max_a", [5])]));
+}
+
+/// select min(a) as min_a from sxt.t where b = 99
+#[test]
+fn we_can_prove_a_min_query_with_an_empty_selection() {
+    let data = owned_table([bigint("a", [5, -2, 3]), bigint("b", [0, 1, 2])]);
+    let t = "sxt.t".parse().unwrap();
+    let mut accessor = OwnedTableTestAccessor::<InnerProductProof>::new_empty_with_setup(());
+    accessor.add_table(t, data, 0);
+    let expr = min_expr(
+        column(t, "a", &accessor),
+        "min_a",
+        tab(t),
+        equal(column(t, "b", &accessor), const_bigint(99)),
+    );
+    let res = VerifiableQueryResult::new(&expr, &accessor, &());
+    exercise_verification(&res, &expr, &accessor, t);
+    let res = res.verify(&expr, &accessor, &()).unwrap().table;
+    assert_eq!(res, owned_table([bigint("min_a", [0; 0])]));
+}
+
+#[test]
+fn we_cannot_take_the_max_of_a_non_bigint_column() {
+    let data = owned_table([int("a", [1, 2])]);
+    let t = "sxt.t".parse().unwrap();
+    let mut accessor = OwnedTableTestAccessor::<InnerProductProof>::new_empty_with_setup(());
+    accessor.add_table(t, data, 0);
+    let res = MaxExpr::<RistrettoPoint>::try_new(
+        column(t, "a", &accessor),
+        "max_a".parse().unwrap(),
+        tab(t),
+        const_bool(true),
+    );
+    assert!(matches!(
+        res,
+        Err(ConversionError::InvalidDataType {
+            actual: ColumnType::Int,
+            ..
+        })
+    ));
+}
+
+fn test_random_tables_with_given_offset(offset: usize) {
+    let dist = Uniform::new(-3, 4);
+    let mut rng = StdRng::from_seed([0u8; 32]);
+    for _ in 0..20 {
+        // Generate random table
+        let n = Uniform::new(1, 21).sample(&mut rng);
+        let data = owned_table([
+            bigint("a", dist.sample_iter(&mut rng).take(n)),
+            bigint("b", dist.sample_iter(&mut rng).take(n)),
+        ]);
+
+        // Generate random values to filter by
+        let filter_val = dist.sample(&mut rng);
+
+        // Create and verify proofs
+        let t = "sxt.t".parse().unwrap();
+        let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(
+            t,
+            data.clone(),
+            offset,
+            (),
+        );
+        let min_ast = min_expr(
+            column(t, "a", &accessor),
+            "min_a",
+            tab(t),
+            lte(column(t, "b", &accessor), const_bigint(filter_val)),
+        );
+        let max_ast = max_expr(
+            column(t, "a", &accessor),
+            "max_a",
+            tab(t),
+            lte(column(t, "b", &accessor), const_bigint(filter_val)),
+        );
+        let min_res = VerifiableQueryResult::new(&min_ast, &accessor, &());
+        exercise_verification(&min_res, &min_ast, &accessor, t);
+        let min_res = min_res.verify(&min_ast, &accessor, &()).unwrap().table;
+        let max_res = VerifiableQueryResult::new(&max_ast, &accessor, &());
+        exercise_verification(&max_res, &max_ast, &accessor, t);
+        let max_res = max_res.verify(&max_ast, &accessor, &()).unwrap().table;
+
+        // Calculate/compare expected result
+        let selected = data["a"]
+            .i64_iter()
+            .zip(data["b"].i64_iter())
+            .filter_map(|(a, b)| (*b <= filter_val).then_some(*a));
+        let expected_min = selected.clone().min();
+        let expected_max = selected.max();
+        assert_eq!(min_res, owned_table([bigint("min_a", expected_min)]));
+        assert_eq!(max_res, owned_table([bigint("max_a", expected_max)]));
+    }
+}
+
+#[test]
+fn we_can_query_random_tables_using_a_zero_offset() {
+    test_random_tables_with_given_offset(0);
+}
+
+#[test]
+fn we_can_query_random_tables_using_a_non_zero_offset() {
+    test_random_tables_with_given_offset(75);
+}
@@ -7,7 +7,7 @@ pub(crate) use filter_result_expr::FilterResultExpr;
 
 mod add_subtract_expr;
 pub(crate) use add_subtract_expr::AddSubtractExpr;
-#[cfg(all(test, feature = "blitzar"))]
+#[cfg(all(test, feature = "blitzar", feature = "prover"))]
 mod add_subtract_expr_test;
 
 mod aggregate_expr;
@@ -15,16 +15,31 @@ pub(crate) use aggregate_expr::AggregateExpr;
 
 mod multiply_expr;
 use multiply_expr::MultiplyExpr;
-#[cfg(all(test, feature = "blitzar"))]
+#[cfg(all(test, feature = "blitzar", feature = "prover"))]
 mod multiply_expr_test;
 
+mod case_expr;
+use case_expr::CaseExpr;
+#[cfg(all(test, feature = "blitzar", feature = "prover"))]
+mod case_expr_test;
+
+mod div_expr;
+use div_expr::DivExpr;
+#[cfg(all(test, feature = "blitzar", feature = "prover"))]
+mod div_expr_test;
+
+mod cast_expr;
+use cast_expr::CastExpr;
+#[cfg(all(test, feature = "blitzar", feature = "prover"))]
+mod cast_expr_test;
+
 mod filter_exec;
 pub(crate) use filter_exec::FilterExec;
 #[cfg(test)]
 pub(crate) use filter_exec::OstensibleFilterExec;
-#[cfg(all(test, feature = "blitzar"))]
+#[cfg(all(test, feature = "blitzar", feature = "prover"))]
 mod filter_exec_test;
-#[cfg(all(test, feature = "blitzar"))]
+#[cfg(all(test, feature = "blitzar", feature = "prover"))]
 mod filter_exec_test_dishonest_prover;
 
 mod bitwise_verification;
@@ -37,55 +52,86 @@ pub(crate) use provable_expr_plan::ProvableExprPlan;
 
 mod provable_expr;
 pub(crate) use provable_expr::ProvableExpr;
-#[cfg(all(test, feature = "blitzar"))]
+#[cfg(all(test, feature = "blitzar", feature = "prover"))]
 mod provable_expr_test;
 
+mod provable_boolean_expr;
+pub(crate) use provable_boolean_expr::ProvableBooleanExpr;
+
 mod projection_exec;
 pub(crate) use projection_exec::ProjectionExec;
-#[cfg(all(test, feature = "blitzar"))]
+#[cfg(all(test, feature = "blitzar", feature = "prover"))]
 mod projection_exec_test;
 
 mod literal_expr;
 pub(crate) use literal_expr::LiteralExpr;
-#[cfg(all(test, feature = "blitzar"))]
+#[cfg(all(test, feature = "blitzar", feature = "prover"))]
 mod literal_expr_test;
 
+mod row_range_expr;
+use row_range_expr::RowRangeExpr;
+#[cfg(all(test, feature = "blitzar", feature = "prover"))]
+mod row_range_expr_test;
+
 mod and_expr;
 use and_expr::AndExpr;
-#[cfg(all(test, feature = "blitzar"))]
+#[cfg(all(test, feature = "blitzar", feature = "prover"))]
 mod and_expr_test;
 
 mod inequality_expr;
 use inequality_expr::*;
-#[cfg(all(test, feature = "blitzar"))]
+#[cfg(all(test, feature = "blitzar", feature = "prover"))]
 mod inequality_expr_test;
 
 mod or_expr;
 use or_expr::*;
-#[cfg(all(test, feature = "blitzar"))]
+#[cfg(all(test, feature = "blitzar", feature = "prover"))]
 mod or_expr_test;
 
+mod xor_expr;
+use xor_expr::XorExpr;
+#[cfg(all(test, feature = "blitzar", feature = "prover"))]
+mod xor_expr_test;
+
+mod complement_expr;
+use complement_expr::ComplementExpr;
+#[cfg(all(test, feature = "blitzar", feature = "prover"))]
+mod complement_expr_test;
+
 mod not_expr;
 use not_expr::NotExpr;
-#[cfg(all(test, feature = "blitzar"))]
+#[cfg(all(test, feature = "blitzar", feature = "prover"))]
 mod not_expr_test;
 
+#[cfg(feature = "prover")]
 mod comparison_util;
+#[cfg(feature = "prover")]
 pub(crate) use comparison_util::scale_and_subtract;
 
 mod numerical_util;
+#[cfg(feature = "prover")]
 pub(crate) use numerical_util::{
-    add_subtract_columns, multiply_columns, scale_and_add_subtract_eval,
+    add_subtract_columns, multiply_columns, prover_evaluate_range_check,
+};
+pub(crate) use numerical_util::{
+    count_range_check, integer_type_bounds, range_bit_width, scale_and_add_subtract_eval,
+    verifier_evaluate_range_check,
 };
 
 mod equals_expr;
 use equals_expr::*;
-#[cfg(all(test, feature = "blitzar"))]
+#[cfg(all(test, feature = "blitzar", feature = "prover"))]
 mod equals_expr_test;
 
+mod equals_ignore_case_expr;
+use equals_ignore_case_expr::EqualsIgnoreCaseExpr;
+pub use equals_ignore_case_expr::MAX_CASE_FOLDED_LETTERS;
+#[cfg(all(test, feature = "blitzar", feature = "prover"))]
+mod equals_ignore_case_expr_test;
+
 mod sign_expr;
 use sign_expr::*;
-#[cfg(all(test, feature = "blitzar"))]
+#[cfg(all(test, feature = "blitzar", feature = "prover"))]
 mod sign_expr_test;
 
 mod table_expr;
@@ -96,28 +142,99 @@ pub(crate) mod test_utility;
 
 mod column_expr;
 pub(crate) use column_expr::ColumnExpr;
-#[cfg(all(test, feature = "blitzar"))]
+#[cfg(all(test, feature = "blitzar", feature = "prover"))]
 mod column_expr_test;
 
 mod dense_filter_exec;
 pub(crate) use dense_filter_exec::DenseFilterExec;
 #[cfg(test)]
 pub(crate) use dense_filter_exec::OstensibleDenseFilterExec;
-#[cfg(all(test, feature = "blitzar"))]
+#[cfg(all(test, feature = "blitzar", feature = "prover"))]
 mod dense_filter_exec_test;
-#[cfg(all(test, feature = "blitzar"))]
+#[cfg(all(test, feature = "blitzar", feature = "prover"))]
 mod dense_filter_exec_test_dishonest_prover;
 
 mod fold_util;
-pub(crate) use fold_util::{fold_columns, fold_vals};
-#[cfg(test)]
+#[cfg(feature = "prover")]
+pub(crate) use fold_util::fold_columns;
+pub(crate) use fold_util::fold_vals;
+#[cfg(all(test, feature = "prover"))]
 mod fold_util_test;
 
 mod group_by_exec;
 pub(crate) use group_by_exec::GroupByExec;
 
-#[cfg(all(test, feature = "blitzar"))]
+#[cfg(all(test, feature = "blitzar", feature = "prover"))]
 mod group_by_exec_test;
 
+mod exists_expr;
+pub(crate) use exists_expr::ExistsExpr;
+#[cfg(all(test, feature = "blitzar", feature = "prover"))]
+mod exists_expr_test;
+
+mod row_count_equals_expr;
+pub(crate) use row_count_equals_expr::RowCountEqualsExpr;
+#[cfg(all(test, feature = "blitzar", feature = "prover"))]
+mod row_count_equals_expr_test;
+
+mod min_max_expr;
+pub(crate) use min_max_expr::{MaxExpr, MinExpr};
+#[cfg(all(test, feature = "blitzar", feature = "prover"))]
+mod min_max_expr_test;
+
+mod semi_join_expr;
+pub(crate) use semi_join_expr::SemiJoinExpr;
+#[cfg(all(test, feature = "blitzar", feature = "prover"))]
+mod semi_join_expr_test;
+
+mod except_expr;
+pub(crate) use except_expr::ExceptExpr;
+#[cfg(all(test, feature = "blitzar", feature = "prover"))]
+mod except_expr_test;
+
+mod sortedness_argument;
+pub use sortedness_argument::SortednessArgument;
+#[cfg(all(test, feature = "blitzar", feature = "prover"))]
+mod sortedness_argument_test;
+
+mod monotonic_expr;
+pub(crate) use monotonic_expr::MonotonicExpr;
+#[cfg(all(test, feature = "blitzar", feature = "prover"))]
+mod monotonic_expr_test;
+
+mod cumulative_sum_expr;
+pub(crate) use cumulative_sum_expr::CumulativeSumExpr;
+#[cfg(all(test, feature = "blitzar", feature = "prover"))]
+mod cumulative_sum_expr_test;
+
+mod distinct_chain;
+
+mod count_distinct_expr;
+pub(crate) use count_distinct_expr::CountDistinctExpr;
+#[cfg(all(test, feature = "blitzar", feature = "prover"))]
+mod count_distinct_expr_test;
+
+mod group_by_count_distinct_expr;
+pub(crate) use group_by_count_distinct_expr::GroupByCountDistinctExpr;
+#[cfg(all(test, feature = "blitzar", feature = "prover"))]
+mod group_by_count_distinct_expr_test;
+
+mod avg_expr;
+pub(crate) use avg_expr::AvgExpr;
+#[cfg(all(test, feature = "blitzar", feature = "prover"))]
+mod avg_expr_test;
+
+mod having_expr;
+pub(crate) use having_expr::HavingExpr;
+#[cfg(all(test, feature = "blitzar", feature = "prover"))]
+mod having_expr_test;
+
 mod proof_plan;
 pub use proof_plan::ProofPlan;
+#[cfg(all(test, feature = "blitzar", feature = "prover"))]
+mod proof_plan_test;
+
+mod view_accessor;
+pub use view_accessor::{ViewAccessor, ViewError};
+#[cfg(all(test, feature = "blitzar", feature = "prover"))]
+mod view_accessor_test;
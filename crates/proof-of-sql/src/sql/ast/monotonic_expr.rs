@@ -0,0 +1,234 @@
+use super::{ColumnExpr, ProvableExpr, SortednessArgument, TableExpr};
+use crate::{
+    base::{
+        commitment::Commitment,
+        database::{
+            ColumnField, ColumnRef, ColumnType, CommitmentAccessor, MetadataAccessor, OwnedColumn,
+            OwnedTable,
+        },
+        proof::ProofError,
+        scalar::Scalar,
+    },
+    sql::{
+        parse::{ConversionError, ConversionResult},
+        proof::{CountBuilder, ProofExecutionPlan, VerificationBuilder},
+    },
+};
+#[cfg(feature = "prover")]
+use crate::{
+    base::{database::DataAccessor, MleAllocator},
+    sql::proof::{Indexes, ProofBuilder, ProverEvaluate, ResultBuilder, SumcheckSubpolynomialType},
+};
+use indexmap::IndexSet;
+#[cfg(feature = "prover")]
+use num_traits::{Inv, One, Zero};
+use proof_of_sql_parser::Identifier;
+use serde::{Deserialize, Serialize};
+
+/// Provable expression showing whether a column is strictly increasing over its whole table,
+/// e.g. that the timestamps of an append-only ledger were inserted in order.
+///
+/// The result is a single boolean row that is true exactly when every row of the column is
+/// smaller than the next one. Tables with at most one row are trivially monotonic.
+///
+/// The rows that are out of order are found with the strict [`SortednessArgument`], and the
+/// result is shown to be true exactly when there are none of them.
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct MonotonicExpr<C: Commitment> {
+    pub(super) column: ColumnExpr<C>,
+    pub(super) table: TableExpr,
+    pub(super) alias: Identifier,
+}
+
+impl<C: Commitment> MonotonicExpr<C> {
+    /// Creates a new monotonic expression.
+    ///
+    /// The column must be an integer or timestamp column of `table`.
+    pub fn try_new(
+        column: ColumnExpr<C>,
+        table: TableExpr,
+        alias: Identifier,
+    ) -> ConversionResult<Self> {
+        match column.data_type() {
+            ColumnType::SmallInt
+            | ColumnType::Int
+            | ColumnType::BigInt
+            | ColumnType::TimestampTZ(_, _) => {}
+            data_type => {
+                return Err(ConversionError::InvalidDataType {
+                    expected: ColumnType::BigInt,
+                    actual: data_type,
+                })
+            }
+        }
+        if column.get_column_reference().table_ref() != table.table_ref {
+            return Err(ConversionError::InvalidExpression(
+                "the monotonic column must belong to the table".to_string(),
+            ));
+        }
+        Ok(Self {
+            column,
+            table,
+            alias,
+        })
+    }
+}
+
+impl<C: Commitment> ProofExecutionPlan<C> for MonotonicExpr<C> {
+    fn count(
+        &self,
+        builder: &mut CountBuilder,
+        _accessor: &dyn MetadataAccessor,
+    ) -> Result<(), ProofError> {
+        self.column.count(builder)?;
+        builder.count_result_columns(1);
+        SortednessArgument::strict().count_out_of_order(builder)?;
+        builder.count_intermediate_mles(1);
+        builder.count_subpolynomials(2);
+        builder.count_degree(3);
+        Ok(())
+    }
+
+    fn get_length(&self, accessor: &dyn MetadataAccessor) -> usize {
+        accessor.get_length(self.table.table_ref)
+    }
+
+    fn get_offset(&self, accessor: &dyn MetadataAccessor) -> usize {
+        accessor.get_offset(self.table.table_ref)
+    }
+
+    fn verifier_evaluate(
+        &self,
+        builder: &mut VerificationBuilder<C>,
+        accessor: &dyn CommitmentAccessor<C>,
+        result: Option<&OwnedTable<C::Scalar>>,
+    ) -> Result<(), ProofError> {
+        let length = accessor.get_length(self.table.table_ref);
+        let first_row_eval = builder.mle_evaluations.truncated_one_evaluation(1);
+        let pairs_eval = builder
+            .mle_evaluations
+            .truncated_one_evaluation(length.saturating_sub(1));
+
+        // 1. column
+        let a = self.column.verifier_evaluate(builder, accessor)?;
+        // 2. indexes
+        builder
+            .mle_evaluations
+            .result_indexes_evaluation
+            .ok_or(ProofError::VerificationError("invalid indexes"))?;
+        // 3. result
+        let result_eval = builder.consume_result_mle();
+        let monotonic: C::Scalar = match result {
+            Some(table) => match table.inner_table().get(&self.alias) {
+                Some(OwnedColumn::Boolean(values)) if values.len() == 1 => values[0].into(),
+                _ => Err(ProofError::VerificationError(
+                    "Result of monotonic must be a single boolean.",
+                ))?,
+            },
+            None => Err(ProofError::VerificationError(
+                "MonotonicExpr is only supported at the top level of a query plan.",
+            ))?,
+        };
+
+        // 4. witness
+        let out_of_order =
+            SortednessArgument::strict().verifier_evaluate_out_of_order(builder, a)?;
+        let witness = builder.consume_intermediate_mle();
+        // sum chi_{n-1} * out_of_order * witness - (chi_0 - result) = 0
+        builder.produce_sumcheck_subpolynomial_evaluation(
+            &(pairs_eval * out_of_order * witness - first_row_eval + result_eval),
+        );
+        // sum monotonic * chi_{n-1} * out_of_order = 0
+        builder.produce_sumcheck_subpolynomial_evaluation(&(monotonic * pairs_eval * out_of_order));
+        Ok(())
+    }
+
+    fn get_column_result_fields(&self) -> Vec<ColumnField> {
+        vec![ColumnField::new(self.alias, ColumnType::Boolean)]
+    }
+
+    fn get_column_references(&self) -> IndexSet<ColumnRef> {
+        let mut columns = IndexSet::new();
+        self.column.get_column_references(&mut columns);
+        columns
+    }
+}
+
+#[cfg(feature = "prover")]
+impl<C: Commitment> ProverEvaluate<C::Scalar> for MonotonicExpr<C> {
+    #[tracing::instrument(name = "MonotonicExpr::result_evaluate", level = "debug", skip_all)]
+    fn result_evaluate<'a>(
+        &self,
+        builder: &mut ResultBuilder<'a>,
+        alloc: &'a dyn MleAllocator,
+        accessor: &'a dyn DataAccessor<C::Scalar>,
+    ) {
+        let values = self
+            .column
+            .result_evaluate(builder.table_length(), alloc, accessor)
+            .to_scalar_with_scaling(0);
+        let monotonic = values
+            .windows(2)
+            .all(|pair| pair[0].signed_cmp(&pair[1]).is_lt());
+        let monotonic = alloc.alloc_slice_fill_copy(1, monotonic);
+        builder.set_result_indexes(Indexes::Dense(0..1));
+        builder.produce_result_column(monotonic as &[_]);
+        builder.request_post_result_challenges(2);
+    }
+
+    #[tracing::instrument(name = "MonotonicExpr::prover_evaluate", level = "debug", skip_all)]
+    fn prover_evaluate<'a>(
+        &self,
+        builder: &mut ProofBuilder<'a, C::Scalar>,
+        alloc: &'a dyn MleAllocator,
+        accessor: &'a dyn DataAccessor<C::Scalar>,
+    ) {
+        let n = builder.table_length();
+        let one = C::Scalar::one();
+
+        // 1. column
+        let a_column = self.column.prover_evaluate(builder, alloc, accessor);
+
+        // 2. witness
+        let out_of_order =
+            SortednessArgument::strict().prover_evaluate_out_of_order(builder, alloc, a_column);
+        let chi_first_row = alloc.alloc_slice_fill_copy(1, true) as &[_];
+        let chi_pairs = alloc.alloc_slice_fill_copy(n.saturating_sub(1), true) as &[_];
+        let num_out_of_order = out_of_order[..n.saturating_sub(1)]
+            .iter()
+            .filter(|&&b| b)
+            .count();
+        let monotonic = num_out_of_order == 0;
+        let witness_value = C::Scalar::from(num_out_of_order as i64)
+            .inv()
+            .unwrap_or(C::Scalar::zero());
+        let witness = alloc.alloc_slice_fill_copy(n, witness_value) as &[_];
+        let result = alloc.alloc_slice_fill_copy(1, monotonic) as &[_];
+        builder.produce_intermediate_mle(witness);
+
+        // sum chi_{n-1} * out_of_order * witness - (chi_0 - result) = 0
+        builder.produce_sumcheck_subpolynomial(
+            SumcheckSubpolynomialType::ZeroSum,
+            vec![
+                (
+                    one,
+                    vec![
+                        Box::new(chi_pairs),
+                        Box::new(out_of_order),
+                        Box::new(witness),
+                    ],
+                ),
+                (-one, vec![Box::new(chi_first_row)]),
+                (one, vec![Box::new(result)]),
+            ],
+        );
+        // sum monotonic * chi_{n-1} * out_of_order = 0
+        builder.produce_sumcheck_subpolynomial(
+            SumcheckSubpolynomialType::ZeroSum,
+            vec![(
+                C::Scalar::from(monotonic),
+                vec![Box::new(chi_pairs), Box::new(out_of_order)],
+            )],
+        );
+    }
+}
@@ -0,0 +1,84 @@
+use super::{test_utility::*, MonotonicExpr};
+use crate::{
+    base::{
+        commitment::InnerProductProof,
+        database::{owned_table_utility::*, ColumnType, OwnedTableTestAccessor, TestAccessor},
+    },
+    sql::{
+        parse::ConversionError,
+        proof::{exercise_verification, VerifiableQueryResult},
+    },
+};
+use curve25519_dalek::ristretto::RistrettoPoint;
+
+/// Prove whether `sxt.t.ts` is strictly increasing
+fn prove_monotonic(ts: Vec<i64>, offset: usize) -> bool {
+    let t = "sxt.t".parse().unwrap();
+    let mut accessor = OwnedTableTestAccessor::<InnerProductProof>::new_empty_with_setup(());
+    accessor.add_table(t, owned_table([bigint("ts", ts)]), offset);
+    let expr = monotonic(col_expr(t, "ts", &accessor), tab(t), "m");
+    let res = VerifiableQueryResult::new(&expr, &accessor, &());
+    exercise_verification(&res, &expr, &accessor, t);
+    let res = res.verify(&expr, &accessor, &()).unwrap().table;
+    if res == owned_table([boolean("m", [true])]) {
+        true
+    } else {
+        assert_eq!(res, owned_table([boolean("m", [false])]));
+        false
+    }
+}
+
+#[test]
+fn we_can_prove_that_an_increasing_column_is_monotonic() {
+    assert!(prove_monotonic(vec![1, 3, 4, 10, 11, 200, 201], 0));
+    assert!(prove_monotonic(vec![i64::MIN, -1, 0, i64::MAX], 5));
+}
+
+#[test]
+fn we_can_prove_that_a_shuffled_column_is_not_monotonic() {
+    assert!(!prove_monotonic(vec![1, 4, 3, 10, 11, 200, 201], 0));
+    assert!(!prove_monotonic(vec![201, 200, 11, 10, 4, 3, 1], 3));
+}
+
+#[test]
+fn a_column_with_a_repeated_value_is_not_monotonic() {
+    assert!(!prove_monotonic(vec![1, 2, 2, 3], 0));
+}
+
+#[test]
+fn a_single_row_is_monotonic() {
+    assert!(prove_monotonic(vec![42], 0));
+}
+
+#[test]
+fn a_proof_of_monotonicity_fails_to_verify_against_a_shuffled_column() {
+    let t = "sxt.t".parse().unwrap();
+    let mut accessor = OwnedTableTestAccessor::<InnerProductProof>::new_empty_with_setup(());
+    accessor.add_table(t, owned_table([bigint("ts", [1, 2, 3, 4, 5])]), 0);
+    let expr = monotonic(col_expr(t, "ts", &accessor), tab(t), "m");
+    let res = VerifiableQueryResult::new(&expr, &accessor, &());
+
+    let mut shuffled_accessor =
+        OwnedTableTestAccessor::<InnerProductProof>::new_empty_with_setup(());
+    shuffled_accessor.add_table(t, owned_table([bigint("ts", [1, 3, 2, 4, 5])]), 0);
+    assert!(res.verify(&expr, &shuffled_accessor, &()).is_err());
+}
+
+#[test]
+fn we_cannot_check_the_monotonicity_of_a_varchar_column() {
+    let t = "sxt.t".parse().unwrap();
+    let mut accessor = OwnedTableTestAccessor::<InnerProductProof>::new_empty_with_setup(());
+    accessor.add_table(t, owned_table([varchar("ts", ["a", "b"])]), 0);
+    let res = MonotonicExpr::<RistrettoPoint>::try_new(
+        col_expr(t, "ts", &accessor),
+        tab(t),
+        "m".parse().unwrap(),
+    );
+    assert!(matches!(
+        res,
+        Err(ConversionError::InvalidDataType {
+            actual: ColumnType::VarChar,
+            ..
+        })
+    ));
+}
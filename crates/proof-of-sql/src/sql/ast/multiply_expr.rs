@@ -1,20 +1,30 @@
-use super::{ProvableExpr, ProvableExprPlan};
+#[cfg(feature = "prover")]
+use super::prover_evaluate_range_check;
+use super::{
+    count_range_check, integer_type_bounds, range_bit_width, verifier_evaluate_range_check,
+    ProvableExpr, ProvableExprPlan,
+};
 use crate::{
     base::{
         commitment::Commitment,
-        database::{
-            try_multiply_column_types, Column, ColumnRef, ColumnType, CommitmentAccessor,
-            DataAccessor,
-        },
+        database::{try_multiply_column_types, ColumnRef, ColumnType, CommitmentAccessor},
         proof::ProofError,
     },
+    sql::proof::{CountBuilder, VerificationBuilder},
+};
+#[cfg(feature = "prover")]
+use crate::{
+    base::{
+        database::{Column, DataAccessor},
+        MleAllocator,
+    },
     sql::{
         ast::multiply_columns,
-        proof::{CountBuilder, ProofBuilder, SumcheckSubpolynomialType, VerificationBuilder},
+        proof::{ProofBuilder, SumcheckSubpolynomialType},
     },
 };
-use bumpalo::Bump;
 use indexmap::IndexSet;
+#[cfg(feature = "prover")]
 use num_traits::One;
 use serde::{Deserialize, Serialize};
 
@@ -23,12 +33,35 @@ use serde::{Deserialize, Serialize};
 pub struct MultiplyExpr<C: Commitment> {
     lhs: Box<ProvableExprPlan<C>>,
     rhs: Box<ProvableExprPlan<C>>,
+    #[serde(default)]
+    checked_arithmetic: bool,
 }
 
 impl<C: Commitment> MultiplyExpr<C> {
     /// Create numerical `*` expression
     pub fn new(lhs: Box<ProvableExprPlan<C>>, rhs: Box<ProvableExprPlan<C>>) -> Self {
-        Self { lhs, rhs }
+        Self {
+            lhs,
+            rhs,
+            checked_arithmetic: false,
+        }
+    }
+
+    /// Prove that the result fits in its integer type rather than letting it exceed the range
+    pub fn with_checked_arithmetic(self) -> Self {
+        Self {
+            checked_arithmetic: true,
+            ..self
+        }
+    }
+
+    /// The bounds that the result is range checked against, if any
+    fn checked_bounds(&self) -> Option<(i128, i128)> {
+        if self.checked_arithmetic {
+            integer_type_bounds(self.data_type())
+        } else {
+            None
+        }
     }
 }
 
@@ -39,6 +72,9 @@ impl<C: Commitment> ProvableExpr<C> for MultiplyExpr<C> {
         builder.count_subpolynomials(1);
         builder.count_intermediate_mles(1);
         builder.count_degree(3);
+        if let Some(bounds) = self.checked_bounds() {
+            count_range_check(builder, Some(range_bit_width(bounds)))?;
+        }
         Ok(())
     }
 
@@ -47,10 +83,11 @@ impl<C: Commitment> ProvableExpr<C> for MultiplyExpr<C> {
             .expect("Failed to multiply column types")
     }
 
+    #[cfg(feature = "prover")]
     fn result_evaluate<'a>(
         &self,
         table_length: usize,
-        alloc: &'a Bump,
+        alloc: &'a dyn MleAllocator,
         accessor: &'a dyn DataAccessor<C::Scalar>,
     ) -> Column<'a, C::Scalar> {
         let lhs_column: Column<'a, C::Scalar> =
@@ -61,6 +98,7 @@ impl<C: Commitment> ProvableExpr<C> for MultiplyExpr<C> {
         Column::Scalar(scalars)
     }
 
+    #[cfg(feature = "prover")]
     #[tracing::instrument(
         name = "proofs.sql.ast.multiply_expr.prover_evaluate",
         level = "info",
@@ -69,7 +107,7 @@ impl<C: Commitment> ProvableExpr<C> for MultiplyExpr<C> {
     fn prover_evaluate<'a>(
         &self,
         builder: &mut ProofBuilder<'a, C::Scalar>,
-        alloc: &'a Bump,
+        alloc: &'a dyn MleAllocator,
         accessor: &'a dyn DataAccessor<C::Scalar>,
     ) -> Column<'a, C::Scalar> {
         let lhs_column: Column<'a, C::Scalar> = self.lhs.prover_evaluate(builder, alloc, accessor);
@@ -90,6 +128,9 @@ impl<C: Commitment> ProvableExpr<C> for MultiplyExpr<C> {
                 ),
             ],
         );
+        if let Some(bounds) = self.checked_bounds() {
+            prover_evaluate_range_check(builder, alloc, lhs_times_rhs, bounds);
+        }
         Column::Scalar(lhs_times_rhs)
     }
 
@@ -108,6 +149,10 @@ impl<C: Commitment> ProvableExpr<C> for MultiplyExpr<C> {
         let eval = builder.mle_evaluations.random_evaluation * (lhs_times_rhs - lhs * rhs);
         builder.produce_sumcheck_subpolynomial_evaluation(&eval);
 
+        if let Some(bounds) = self.checked_bounds() {
+            verifier_evaluate_range_check(builder, lhs_times_rhs, bounds)?;
+        }
+
         // selection
         Ok(lhs_times_rhs)
     }
@@ -1,13 +1,20 @@
-use super::{ProvableExpr, ProvableExprPlan};
+use super::{ProvableBooleanExpr, ProvableExpr, ProvableExprPlan};
 use crate::{
     base::{
         commitment::Commitment,
-        database::{Column, ColumnRef, ColumnType, CommitmentAccessor, DataAccessor},
+        database::{ColumnRef, ColumnType, CommitmentAccessor},
         proof::ProofError,
     },
-    sql::proof::{CountBuilder, ProofBuilder, VerificationBuilder},
+    sql::proof::{CountBuilder, VerificationBuilder},
+};
+#[cfg(feature = "prover")]
+use crate::{
+    base::{
+        database::{Column, DataAccessor},
+        MleAllocator,
+    },
+    sql::proof::ProofBuilder,
 };
-use bumpalo::Bump;
 use indexmap::IndexSet;
 use serde::{Deserialize, Serialize};
 
@@ -33,30 +40,26 @@ impl<C: Commitment> ProvableExpr<C> for NotExpr<C> {
         ColumnType::Boolean
     }
 
+    #[cfg(feature = "prover")]
     #[tracing::instrument(name = "NotExpr::result_evaluate", level = "debug", skip_all)]
     fn result_evaluate<'a>(
         &self,
         table_length: usize,
-        alloc: &'a Bump,
+        alloc: &'a dyn MleAllocator,
         accessor: &'a dyn DataAccessor<C::Scalar>,
     ) -> Column<'a, C::Scalar> {
-        let expr_column: Column<'a, C::Scalar> =
-            self.expr.result_evaluate(table_length, alloc, accessor);
-        let expr = expr_column.as_boolean().expect("expr is not boolean");
-        Column::Boolean(alloc.alloc_slice_fill_with(expr.len(), |i| !expr[i]))
+        Column::Boolean(self.result_evaluate_selection(table_length, alloc, accessor))
     }
 
+    #[cfg(feature = "prover")]
     #[tracing::instrument(name = "NotExpr::prover_evaluate", level = "debug", skip_all)]
     fn prover_evaluate<'a>(
         &self,
         builder: &mut ProofBuilder<'a, C::Scalar>,
-        alloc: &'a Bump,
+        alloc: &'a dyn MleAllocator,
         accessor: &'a dyn DataAccessor<C::Scalar>,
     ) -> Column<'a, C::Scalar> {
-        let expr_column: Column<'a, C::Scalar> =
-            self.expr.prover_evaluate(builder, alloc, accessor);
-        let expr = expr_column.as_boolean().expect("expr is not boolean");
-        Column::Boolean(alloc.alloc_slice_fill_with(expr.len(), |i| !expr[i]))
+        Column::Boolean(self.prover_evaluate_selection(builder, alloc, accessor))
     }
 
     fn verifier_evaluate(
@@ -64,11 +67,47 @@ impl<C: Commitment> ProvableExpr<C> for NotExpr<C> {
         builder: &mut VerificationBuilder<C>,
         accessor: &dyn CommitmentAccessor<C>,
     ) -> Result<C::Scalar, ProofError> {
-        let eval = self.expr.verifier_evaluate(builder, accessor)?;
-        Ok(builder.mle_evaluations.one_evaluation - eval)
+        self.verifier_evaluate_selection(builder, accessor)
     }
 
     fn get_column_references(&self, columns: &mut IndexSet<ColumnRef>) {
         self.expr.get_column_references(columns);
     }
 }
+
+impl<C: Commitment> ProvableBooleanExpr<C> for NotExpr<C> {
+    #[cfg(feature = "prover")]
+    fn result_evaluate_selection<'a>(
+        &self,
+        table_length: usize,
+        alloc: &'a dyn MleAllocator,
+        accessor: &'a dyn DataAccessor<C::Scalar>,
+    ) -> &'a [bool] {
+        let expr = self
+            .expr
+            .result_evaluate_selection(table_length, alloc, accessor);
+        alloc.alloc_slice_fill_with(expr.len(), |i| !expr[i])
+    }
+
+    #[cfg(feature = "prover")]
+    fn prover_evaluate_selection<'a>(
+        &self,
+        builder: &mut ProofBuilder<'a, C::Scalar>,
+        alloc: &'a dyn MleAllocator,
+        accessor: &'a dyn DataAccessor<C::Scalar>,
+    ) -> &'a [bool] {
+        let expr = self
+            .expr
+            .prover_evaluate_selection(builder, alloc, accessor);
+        alloc.alloc_slice_fill_with(expr.len(), |i| !expr[i])
+    }
+
+    fn verifier_evaluate_selection(
+        &self,
+        builder: &mut VerificationBuilder<C>,
+        accessor: &dyn CommitmentAccessor<C>,
+    ) -> Result<C::Scalar, ProofError> {
+        let eval = self.expr.verifier_evaluate_selection(builder, accessor)?;
+        Ok(builder.mle_evaluations.one_evaluation - eval)
+    }
+}
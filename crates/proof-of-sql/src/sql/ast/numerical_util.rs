@@ -1,13 +1,29 @@
-use crate::base::{database::Column, math::decimal::scale_scalar, scalar::Scalar};
-use bumpalo::Bump;
+#[cfg(feature = "prover")]
+use super::prover_evaluate_sign;
+use super::{count_sign_with_bit_width, verifier_evaluate_sign};
+use crate::{
+    base::{
+        commitment::Commitment, database::ColumnType, math::decimal::scale_scalar,
+        proof::ProofError, scalar::Scalar,
+    },
+    sql::proof::{CountBuilder, VerificationBuilder},
+};
+#[cfg(feature = "prover")]
+use crate::{
+    base::{database::Column, MleAllocator},
+    sql::proof::{ProofBuilder, SumcheckSubpolynomialType},
+};
+#[cfg(feature = "prover")]
+use num_traits::One;
 
 /// Add or subtract two columns together.
+#[cfg(feature = "prover")]
 pub(crate) fn add_subtract_columns<'a, S: Scalar>(
     lhs: Column<'a, S>,
     rhs: Column<'a, S>,
     lhs_scale: i8,
     rhs_scale: i8,
-    alloc: &'a Bump,
+    alloc: &'a dyn MleAllocator,
     is_subtract: bool,
 ) -> &'a [S] {
     let lhs_len = lhs.len();
@@ -30,10 +46,11 @@ pub(crate) fn add_subtract_columns<'a, S: Scalar>(
 }
 
 /// Multiply two columns together.
+#[cfg(feature = "prover")]
 pub(crate) fn multiply_columns<'a, S: Scalar>(
     lhs: &Column<'a, S>,
     rhs: &Column<'a, S>,
-    alloc: &'a Bump,
+    alloc: &'a dyn MleAllocator,
 ) -> &'a [S] {
     let lhs_len = lhs.len();
     let rhs_len = rhs.len();
@@ -65,3 +82,89 @@ pub(crate) fn scale_and_add_subtract_eval<S: Scalar>(
         scaled_lhs_eval + scaled_rhs_eval
     }
 }
+
+/// The inclusive range of values of a `SmallInt`, `Int` or `BigInt` column type.
+///
+/// Returns `None` for other types, which are not range checked. In particular, an overflowing
+/// `Int128` can exceed the 128-bit magnitudes supported by the sign decomposition.
+pub(crate) fn integer_type_bounds(data_type: ColumnType) -> Option<(i128, i128)> {
+    match data_type {
+        ColumnType::SmallInt => Some((i16::MIN.into(), i16::MAX.into())),
+        ColumnType::Int => Some((i32::MIN.into(), i32::MAX.into())),
+        ColumnType::BigInt => Some((i64::MIN.into(), i64::MAX.into())),
+        _ => None,
+    }
+}
+
+/// The number of bits needed for the distance between the ends of the inclusive range `bounds`.
+///
+/// This is the narrowest bit width a range check over `bounds` can declare.
+pub(crate) fn range_bit_width((min, max): (i128, i128)) -> u8 {
+    (u128::BITS - max.abs_diff(min).leading_zeros()) as u8
+}
+
+/// Count the components needed to prove that a column lies within `bounds`.
+///
+/// The differences between the column and the ends of the range are declared to fit in
+/// `bit_width` bits, which the verifier enforces, so that a range check only commits to the bits
+/// it needs. `None` only limits the differences to the range accepted by any sign decomposition.
+pub(crate) fn count_range_check(
+    builder: &mut CountBuilder,
+    bit_width: Option<u8>,
+) -> Result<(), ProofError> {
+    builder.count_subpolynomials(2);
+    builder.count_degree(2);
+    count_sign_with_bit_width(builder, bit_width)?;
+    count_sign_with_bit_width(builder, bit_width)?;
+    Ok(())
+}
+
+/// Prove that every value of `column` lies within the inclusive range `bounds`.
+///
+/// This is done by showing that `column - min` and `max - column` are both non-negative.
+#[cfg(feature = "prover")]
+pub(crate) fn prover_evaluate_range_check<'a, S: Scalar>(
+    builder: &mut ProofBuilder<'a, S>,
+    alloc: &'a dyn MleAllocator,
+    column: &'a [S],
+    (min, max): (i128, i128),
+) {
+    let min = S::from(min);
+    let max = S::from(max);
+    let differences: [&'a [S]; 2] = [
+        alloc.alloc_slice_fill_with(column.len(), |i| column[i] - min),
+        alloc.alloc_slice_fill_with(column.len(), |i| max - column[i]),
+    ];
+    for difference in differences {
+        // sign(difference) == 0
+        let sign = prover_evaluate_sign(
+            builder,
+            alloc,
+            difference,
+            #[cfg(test)]
+            false,
+        );
+        builder.produce_sumcheck_subpolynomial(
+            SumcheckSubpolynomialType::Identity,
+            vec![(S::one(), vec![Box::new(sign)])],
+        );
+    }
+}
+
+/// The counterpart of `prover_evaluate_range_check`, given the evaluation of the column.
+pub(crate) fn verifier_evaluate_range_check<C: Commitment>(
+    builder: &mut VerificationBuilder<C>,
+    eval: C::Scalar,
+    (min, max): (i128, i128),
+) -> Result<(), ProofError> {
+    let one_eval = builder.mle_evaluations.one_evaluation;
+    let rand_eval = builder.mle_evaluations.random_evaluation;
+    let min = C::Scalar::from(min);
+    let max = C::Scalar::from(max);
+    for difference in [eval - min * one_eval, max * one_eval - eval] {
+        // sign(difference) == 0
+        let sign = verifier_evaluate_sign(builder, difference, one_eval)?;
+        builder.produce_sumcheck_subpolynomial_evaluation(&(rand_eval * sign));
+    }
+    Ok(())
+}
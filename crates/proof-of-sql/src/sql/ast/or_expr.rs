@@ -1,14 +1,21 @@
-use super::{ProvableExpr, ProvableExprPlan};
+use super::{ProvableBooleanExpr, ProvableExpr, ProvableExprPlan};
 use crate::{
     base::{
         commitment::Commitment,
-        database::{Column, ColumnRef, ColumnType, CommitmentAccessor, DataAccessor},
+        database::{ColumnRef, ColumnType, CommitmentAccessor},
         proof::ProofError,
         scalar::Scalar,
     },
-    sql::proof::{CountBuilder, ProofBuilder, SumcheckSubpolynomialType, VerificationBuilder},
+    sql::proof::{CountBuilder, VerificationBuilder},
+};
+#[cfg(feature = "prover")]
+use crate::{
+    base::{
+        database::{Column, DataAccessor},
+        MleAllocator,
+    },
+    sql::proof::{ProofBuilder, SumcheckSubpolynomialType},
 };
-use bumpalo::Bump;
 use indexmap::IndexSet;
 use serde::{Deserialize, Serialize};
 
@@ -38,34 +45,26 @@ impl<C: Commitment> ProvableExpr<C> for OrExpr<C> {
         ColumnType::Boolean
     }
 
+    #[cfg(feature = "prover")]
     #[tracing::instrument(name = "OrExpr::result_evaluate", level = "debug", skip_all)]
     fn result_evaluate<'a>(
         &self,
         table_length: usize,
-        alloc: &'a Bump,
+        alloc: &'a dyn MleAllocator,
         accessor: &'a dyn DataAccessor<C::Scalar>,
     ) -> Column<'a, C::Scalar> {
-        let lhs_column: Column<'a, C::Scalar> =
-            self.lhs.result_evaluate(table_length, alloc, accessor);
-        let rhs_column: Column<'a, C::Scalar> =
-            self.rhs.result_evaluate(table_length, alloc, accessor);
-        let lhs = lhs_column.as_boolean().expect("lhs is not boolean");
-        let rhs = rhs_column.as_boolean().expect("rhs is not boolean");
-        Column::Boolean(result_evaluate_or(table_length, alloc, lhs, rhs))
+        Column::Boolean(self.result_evaluate_selection(table_length, alloc, accessor))
     }
 
+    #[cfg(feature = "prover")]
     #[tracing::instrument(name = "OrExpr::prover_evaluate", level = "debug", skip_all)]
     fn prover_evaluate<'a>(
         &self,
         builder: &mut ProofBuilder<'a, C::Scalar>,
-        alloc: &'a Bump,
+        alloc: &'a dyn MleAllocator,
         accessor: &'a dyn DataAccessor<C::Scalar>,
     ) -> Column<'a, C::Scalar> {
-        let lhs_column: Column<'a, C::Scalar> = self.lhs.prover_evaluate(builder, alloc, accessor);
-        let rhs_column: Column<'a, C::Scalar> = self.rhs.prover_evaluate(builder, alloc, accessor);
-        let lhs = lhs_column.as_boolean().expect("lhs is not boolean");
-        let rhs = rhs_column.as_boolean().expect("rhs is not boolean");
-        Column::Boolean(prover_evaluate_or(builder, alloc, lhs, rhs))
+        Column::Boolean(self.prover_evaluate_selection(builder, alloc, accessor))
     }
 
     fn verifier_evaluate(
@@ -73,10 +72,7 @@ impl<C: Commitment> ProvableExpr<C> for OrExpr<C> {
         builder: &mut VerificationBuilder<C>,
         accessor: &dyn CommitmentAccessor<C>,
     ) -> Result<C::Scalar, ProofError> {
-        let lhs = self.lhs.verifier_evaluate(builder, accessor)?;
-        let rhs = self.rhs.verifier_evaluate(builder, accessor)?;
-
-        Ok(verifier_evaluate_or(builder, &lhs, &rhs))
+        self.verifier_evaluate_selection(builder, accessor)
     }
 
     fn get_column_references(&self, columns: &mut IndexSet<ColumnRef>) {
@@ -85,9 +81,51 @@ impl<C: Commitment> ProvableExpr<C> for OrExpr<C> {
     }
 }
 
+impl<C: Commitment> ProvableBooleanExpr<C> for OrExpr<C> {
+    #[cfg(feature = "prover")]
+    fn result_evaluate_selection<'a>(
+        &self,
+        table_length: usize,
+        alloc: &'a dyn MleAllocator,
+        accessor: &'a dyn DataAccessor<C::Scalar>,
+    ) -> &'a [bool] {
+        let lhs = self
+            .lhs
+            .result_evaluate_selection(table_length, alloc, accessor);
+        let rhs = self
+            .rhs
+            .result_evaluate_selection(table_length, alloc, accessor);
+        result_evaluate_or(table_length, alloc, lhs, rhs)
+    }
+
+    #[cfg(feature = "prover")]
+    fn prover_evaluate_selection<'a>(
+        &self,
+        builder: &mut ProofBuilder<'a, C::Scalar>,
+        alloc: &'a dyn MleAllocator,
+        accessor: &'a dyn DataAccessor<C::Scalar>,
+    ) -> &'a [bool] {
+        let lhs = self.lhs.prover_evaluate_selection(builder, alloc, accessor);
+        let rhs = self.rhs.prover_evaluate_selection(builder, alloc, accessor);
+        prover_evaluate_or(builder, alloc, lhs, rhs)
+    }
+
+    fn verifier_evaluate_selection(
+        &self,
+        builder: &mut VerificationBuilder<C>,
+        accessor: &dyn CommitmentAccessor<C>,
+    ) -> Result<C::Scalar, ProofError> {
+        let lhs = self.lhs.verifier_evaluate_selection(builder, accessor)?;
+        let rhs = self.rhs.verifier_evaluate_selection(builder, accessor)?;
+
+        Ok(verifier_evaluate_or(builder, &lhs, &rhs))
+    }
+}
+
+#[cfg(feature = "prover")]
 pub fn result_evaluate_or<'a>(
     table_length: usize,
-    alloc: &'a Bump,
+    alloc: &'a dyn MleAllocator,
     lhs: &[bool],
     rhs: &[bool],
 ) -> &'a [bool] {
@@ -96,9 +134,10 @@ pub fn result_evaluate_or<'a>(
     alloc.alloc_slice_fill_with(table_length, |i| lhs[i] || rhs[i])
 }
 
+#[cfg(feature = "prover")]
 pub fn prover_evaluate_or<'a, S: Scalar>(
     builder: &mut ProofBuilder<'a, S>,
-    alloc: &'a Bump,
+    alloc: &'a dyn MleAllocator,
     lhs: &'a [bool],
     rhs: &'a [bool],
 ) -> &'a [bool] {
@@ -1,26 +1,36 @@
-use super::{AliasedProvableExprPlan, ProvableExpr, TableExpr};
+use super::{AliasedProvableExprPlan, ProvableBooleanExpr, ProvableExpr, TableExpr};
 use crate::{
     base::{
         commitment::Commitment,
         database::{
-            ColumnField, ColumnRef, CommitmentAccessor, DataAccessor, MetadataAccessor, OwnedTable,
+            ColumnField, ColumnRef, ColumnType, CommitmentAccessor, MetadataAccessor, OwnedTable,
         },
         proof::ProofError,
     },
-    sql::proof::{
-        CountBuilder, Indexes, ProofBuilder, ProofExecutionPlan, ProverEvaluate, ResultBuilder,
-        VerificationBuilder,
+    sql::proof::{CountBuilder, ProofExecutionPlan, VerificationBuilder},
+};
+#[cfg(feature = "prover")]
+use crate::{
+    base::{
+        database::{Column, DataAccessor},
+        MleAllocator,
     },
+    sql::proof::{Indexes, ProofBuilder, ProverEvaluate, ResultBuilder, SumcheckSubpolynomialType},
 };
-use bumpalo::Bump;
 use core::iter::repeat_with;
 use indexmap::IndexSet;
+#[cfg(feature = "prover")]
+use num_traits::One;
 use serde::{Deserialize, Serialize};
 
 /// Provable expressions for queries of the form
 /// ```ignore
 ///     SELECT <result_expr1>, ..., <result_exprN> FROM <table>
 /// ```
+///
+/// A boolean result expression, such as `(b > 5) AS flag`, is projected through its selection
+/// indicator: the indicator is materialized as a `Boolean` result column instead of being used
+/// to filter rows, and the result column is checked to equal it on every row.
 #[derive(Debug, PartialEq, Serialize, Deserialize)]
 pub struct ProjectionExec<C: Commitment> {
     pub(super) aliased_results: Vec<AliasedProvableExprPlan<C>>,
@@ -46,6 +56,10 @@ impl<C: Commitment> ProofExecutionPlan<C> for ProjectionExec<C> {
         for aliased_expr in self.aliased_results.iter() {
             aliased_expr.expr.count(builder)?;
             builder.count_result_columns(1);
+            if aliased_expr.expr.data_type() == ColumnType::Boolean {
+                builder.count_subpolynomials(1);
+                builder.count_degree(2);
+            }
         }
         Ok(())
     }
@@ -58,20 +72,39 @@ impl<C: Commitment> ProofExecutionPlan<C> for ProjectionExec<C> {
         accessor.get_offset(self.table.table_ref)
     }
 
-    #[allow(unused_variables)]
     fn verifier_evaluate(
         &self,
         builder: &mut VerificationBuilder<C>,
         accessor: &dyn CommitmentAccessor<C>,
         _result: Option<&OwnedTable<C::Scalar>>,
     ) -> Result<(), ProofError> {
-        self.aliased_results
+        let expr_evals = self
+            .aliased_results
             .iter()
-            .map(|aliased_expr| aliased_expr.expr.verifier_evaluate(builder, accessor))
+            .map(|aliased_expr| match aliased_expr.expr.data_type() {
+                ColumnType::Boolean => aliased_expr
+                    .expr
+                    .verifier_evaluate_selection(builder, accessor),
+                _ => aliased_expr.expr.verifier_evaluate(builder, accessor),
+            })
             .collect::<Result<Vec<_>, _>>()?;
-        let _columns_evals = Vec::from_iter(
+        let columns_evals = Vec::from_iter(
             repeat_with(|| builder.consume_result_mle()).take(self.aliased_results.len()),
         );
+        let rand_eval = builder.mle_evaluations.random_evaluation;
+        for ((aliased_expr, expr_eval), column_eval) in self
+            .aliased_results
+            .iter()
+            .zip(expr_evals)
+            .zip(columns_evals)
+        {
+            if aliased_expr.expr.data_type() == ColumnType::Boolean {
+                // result - selection = 0
+                builder.produce_sumcheck_subpolynomial_evaluation(
+                    &(rand_eval * (column_eval - expr_eval)),
+                );
+            }
+        }
         Ok(())
     }
 
@@ -91,35 +124,66 @@ impl<C: Commitment> ProofExecutionPlan<C> for ProjectionExec<C> {
     }
 }
 
+#[cfg(feature = "prover")]
 impl<C: Commitment> ProverEvaluate<C::Scalar> for ProjectionExec<C> {
     #[tracing::instrument(name = "ProjectionExec::result_evaluate", level = "debug", skip_all)]
     fn result_evaluate<'a>(
         &self,
         builder: &mut ResultBuilder<'a>,
-        alloc: &'a Bump,
+        alloc: &'a dyn MleAllocator,
         accessor: &'a dyn DataAccessor<C::Scalar>,
     ) {
+        let table_length = builder.table_length();
         let columns = Vec::from_iter(self.aliased_results.iter().map(|aliased_expr| {
-            aliased_expr
-                .expr
-                .result_evaluate(builder.table_length(), alloc, accessor)
+            match aliased_expr.expr.data_type() {
+                ColumnType::Boolean => Column::Boolean(
+                    aliased_expr
+                        .expr
+                        .result_evaluate_selection(table_length, alloc, accessor),
+                ),
+                _ => aliased_expr
+                    .expr
+                    .result_evaluate(table_length, alloc, accessor),
+            }
         }));
-        builder.set_result_indexes(Indexes::Dense(0..(builder.table_length() as u64)));
+        builder.set_result_indexes(Indexes::Dense(0..(table_length as u64)));
         for col in columns {
             builder.produce_result_column(col);
         }
     }
 
     #[tracing::instrument(name = "ProjectionExec::prover_evaluate", level = "debug", skip_all)]
-    #[allow(unused_variables)]
     fn prover_evaluate<'a>(
         &self,
         builder: &mut ProofBuilder<'a, C::Scalar>,
-        alloc: &'a Bump,
+        alloc: &'a dyn MleAllocator,
         accessor: &'a dyn DataAccessor<C::Scalar>,
     ) {
-        self.aliased_results.iter().for_each(|aliased_expr| {
-            aliased_expr.expr.prover_evaluate(builder, alloc, accessor);
-        });
+        let selections = Vec::from_iter(self.aliased_results.iter().filter_map(|aliased_expr| {
+            match aliased_expr.expr.data_type() {
+                ColumnType::Boolean => Some(
+                    aliased_expr
+                        .expr
+                        .prover_evaluate_selection(builder, alloc, accessor),
+                ),
+                _ => {
+                    aliased_expr.expr.prover_evaluate(builder, alloc, accessor);
+                    None
+                }
+            }
+        }));
+        // result - selection = 0
+        //
+        // The result column of an honest prover is the selection itself.
+        let one = C::Scalar::one();
+        for selection in selections {
+            builder.produce_sumcheck_subpolynomial(
+                SumcheckSubpolynomialType::Identity,
+                vec![
+                    (one, vec![Box::new(selection)]),
+                    (-one, vec![Box::new(selection)]),
+                ],
+            );
+        }
     }
 }
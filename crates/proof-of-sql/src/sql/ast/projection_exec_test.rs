@@ -151,6 +151,33 @@ fn we_can_prove_and_get_the_correct_result_from_a_nontrivial_projection() {
     assert_eq!(res, expected);
 }
 
+#[test]
+fn we_can_prove_a_projection_and_get_the_result_columns_in_the_declared_order() {
+    let data = owned_table([
+        bigint("a", [1_i64, 4, 5]),
+        varchar("b", ["x", "y", "z"]),
+        int128("c", [7_i128, 8, 9]),
+    ]);
+    let t = "sxt.t".parse().unwrap();
+    let mut accessor = OwnedTableTestAccessor::<InnerProductProof>::new_empty_with_setup(());
+    accessor.add_table(t, data, 0);
+    let ast = projection(cols_expr_plan(t, &["c", "a", "b"], &accessor), tab(t));
+    let verifiable_res = VerifiableQueryResult::new(&ast, &accessor, &());
+    exercise_verification(&verifiable_res, &ast, &accessor, t);
+    let res = verifiable_res.verify(&ast, &accessor, &()).unwrap().table;
+    let expected = owned_table([
+        int128("c", [7_i128, 8, 9]),
+        bigint("a", [1_i64, 4, 5]),
+        varchar("b", ["x", "y", "z"]),
+    ]);
+    assert_eq!(res, expected);
+    // `OwnedTable` equality ignores column order, so check it explicitly.
+    assert_eq!(
+        res.inner_table().keys().collect::<Vec<_>>(),
+        expected.inner_table().keys().collect::<Vec<_>>()
+    );
+}
+
 #[test]
 fn we_can_get_an_empty_result_from_a_basic_projection_on_an_empty_table_using_result_evaluate() {
     let data = owned_table([
@@ -340,3 +367,37 @@ fn we_can_prove_a_projection() {
     ]);
     assert_eq!(res, expected);
 }
+
+/// select a, (b >= 6) as flag from sxt.t
+#[test]
+fn we_can_prove_a_projection_of_a_comparison_as_a_boolean_column() {
+    let data = owned_table([
+        bigint("a", [1, 2, 3, 4, 5, 6]),
+        int("b", [7, 2, 6, 5, 100, -8]),
+    ]);
+    let t = "sxt.t".parse().unwrap();
+    let mut accessor = OwnedTableTestAccessor::<InnerProductProof>::new_empty_with_setup(());
+    accessor.add_table(t, data, 0);
+    let expr = projection(
+        vec![
+            col_expr_plan(t, "a", &accessor),
+            aliased_plan(gte(column(t, "b", &accessor), const_int(6)), "flag"),
+        ],
+        tab(t),
+    );
+    assert_eq!(
+        expr.get_column_result_fields(),
+        vec![
+            ColumnField::new("a".parse().unwrap(), ColumnType::BigInt),
+            ColumnField::new("flag".parse().unwrap(), ColumnType::Boolean),
+        ]
+    );
+    let res = VerifiableQueryResult::new(&expr, &accessor, &());
+    exercise_verification(&res, &expr, &accessor, t);
+    let res = res.verify(&expr, &accessor, &()).unwrap().table;
+    let expected = owned_table([
+        bigint("a", [1, 2, 3, 4, 5, 6]),
+        boolean("flag", [true, false, true, false, true, false]),
+    ]);
+    assert_eq!(res, expected);
+}
@@ -1,8 +1,15 @@
-use super::{DenseFilterExec, FilterExec, GroupByExec, ProjectionExec};
+use super::{
+    AvgExpr, CountDistinctExpr, CumulativeSumExpr, DenseFilterExec, ExceptExpr, ExistsExpr,
+    FilterExec, GroupByCountDistinctExpr, GroupByExec, HavingExpr, MaxExpr, MinExpr, MonotonicExpr,
+    ProjectionExec, ProvableExprPlan, RowCountEqualsExpr, SemiJoinExpr, TableExpr,
+};
+#[cfg(feature = "prover")]
+use crate::sql::proof::ProverEvaluate;
 use crate::{
     base::commitment::Commitment,
-    sql::proof::{ProofExecutionPlan, ProverEvaluate},
+    sql::proof::{PaginatedProofPlan, ProofExecutionPlan},
 };
+use core::ops::Range;
 use serde::{Deserialize, Serialize};
 
 /// The query plan for proving a query
@@ -33,6 +40,103 @@ pub enum ProofPlan<C: Commitment> {
     ///     SELECT <result_expr1>, ..., <result_exprN> FROM <table> WHERE <where_clause>
     /// ```
     DenseFilter(DenseFilterExec<C>),
+    /// Provable expressions for queries of the form
+    /// ```ignore
+    ///     SELECT EXISTS(SELECT * FROM <table> WHERE <where_clause>) AS <alias>
+    /// ```
+    Exists(ExistsExpr<C>),
+    /// Provable expressions for queries of the form
+    /// ```ignore
+    ///     SELECT MIN(<expr>) AS <alias> FROM <table> WHERE <where_clause>
+    /// ```
+    Min(MinExpr<C>),
+    /// Provable expressions for queries of the form
+    /// ```ignore
+    ///     SELECT MAX(<expr>) AS <alias> FROM <table> WHERE <where_clause>
+    /// ```
+    Max(MaxExpr<C>),
+    /// Provable expressions for queries of the form, where the result is sent in a dense form
+    /// ```ignore
+    ///     SELECT <column1>, ..., <columnN> FROM <table>
+    ///     WHERE <column> IN (SELECT <inner_column> FROM <inner_table>)
+    /// ```
+    SemiJoin(SemiJoinExpr<C>),
+    /// Provable expressions for queries of the form
+    /// ```ignore
+    ///     SELECT COUNT(DISTINCT <expr>) AS <alias> FROM <table> WHERE <where_clause>
+    /// ```
+    CountDistinct(CountDistinctExpr<C>),
+    /// Provable expressions for queries of the form
+    /// ```ignore
+    ///     SELECT <group_by_expr>, COUNT(DISTINCT <expr>) AS <alias>
+    ///     FROM <table>
+    ///     WHERE <where_clause>
+    ///     GROUP BY <group_by_expr>
+    /// ```
+    GroupByCountDistinct(GroupByCountDistinctExpr<C>),
+    /// Provable expressions for queries of the form
+    /// ```ignore
+    ///     SELECT AVG(<expr>) AS <alias> FROM <table> WHERE <where_clause>
+    /// ```
+    Avg(AvgExpr<C>),
+    /// Provable expressions for queries of the form
+    /// ```ignore
+    ///     SELECT <group_by_expr>, COUNT(*) as count_alias
+    ///     FROM <table>
+    ///     WHERE <where_clause>
+    ///     GROUP BY <group_by_expr>
+    ///     HAVING COUNT(*) > <threshold>
+    /// ```
+    Having(HavingExpr<C>),
+    /// Provable expressions for queries of the form, where the result is sent in a dense form
+    /// ```ignore
+    ///     SELECT <column> FROM <table> EXCEPT SELECT <except_column> FROM <except_table>
+    /// ```
+    Except(ExceptExpr<C>),
+    /// Provable expressions showing whether a column is strictly increasing over its table,
+    /// with a single boolean row as result
+    Monotonic(MonotonicExpr<C>),
+    /// Provable expressions for queries of the form
+    /// ```ignore
+    ///     SELECT SUM(<column>) OVER (ROWS UNBOUNDED PRECEDING) AS <alias> FROM <table>
+    /// ```
+    CumulativeSum(CumulativeSumExpr<C>),
+    /// Provable expressions for queries of the form
+    /// ```ignore
+    ///     SELECT (SELECT COUNT(*) FROM <table>) = (SELECT COUNT(*) FROM <other_table>) AS <alias>
+    /// ```
+    RowCountEquals(RowCountEqualsExpr),
+}
+
+impl<C: Commitment> PaginatedProofPlan<C> for ProofPlan<C> {
+    /// Filters are restricted to a range of rows by adding the range to their where clause.
+    /// Other plans cannot be paginated.
+    fn restrict_to_rows(&self, rows: Range<u64>) -> Option<Self> {
+        let restrict = |where_clause: &ProvableExprPlan<C>| {
+            ProvableExprPlan::try_new_and(
+                where_clause.clone(),
+                ProvableExprPlan::new_row_range(rows.clone()),
+            )
+            .ok()
+        };
+        match self {
+            ProofPlan::Filter(expr) => Some(ProofPlan::Filter(FilterExec::new(
+                expr.results.clone(),
+                TableExpr {
+                    table_ref: expr.table.table_ref,
+                },
+                restrict(&expr.where_clause)?,
+            ))),
+            ProofPlan::DenseFilter(expr) => Some(ProofPlan::DenseFilter(DenseFilterExec::new(
+                expr.aliased_results.clone(),
+                TableExpr {
+                    table_ref: expr.table.table_ref,
+                },
+                restrict(&expr.where_clause)?,
+            ))),
+            _ => None,
+        }
+    }
 }
 
 impl<C: Commitment> ProofExecutionPlan<C> for ProofPlan<C> {
@@ -46,6 +150,20 @@ impl<C: Commitment> ProofExecutionPlan<C> for ProofPlan<C> {
             ProofPlan::Filter(expr) => expr.count(builder, accessor),
             ProofPlan::GroupBy(expr) => expr.count(builder, accessor),
             ProofPlan::DenseFilter(expr) => expr.count(builder, accessor),
+            ProofPlan::Exists(expr) => expr.count(builder, accessor),
+            ProofPlan::Min(expr) => expr.count(builder, accessor),
+            ProofPlan::Max(expr) => expr.count(builder, accessor),
+            ProofPlan::SemiJoin(expr) => expr.count(builder, accessor),
+            ProofPlan::CountDistinct(expr) => expr.count(builder, accessor),
+            ProofPlan::GroupByCountDistinct(expr) => expr.count(builder, accessor),
+            ProofPlan::Avg(expr) => expr.count(builder, accessor),
+            ProofPlan::Having(expr) => expr.count(builder, accessor),
+            ProofPlan::Except(expr) => expr.count(builder, accessor),
+            ProofPlan::Monotonic(expr) => expr.count(builder, accessor),
+            ProofPlan::CumulativeSum(expr) => expr.count(builder, accessor),
+            ProofPlan::RowCountEquals(expr) => {
+                ProofExecutionPlan::<C>::count(expr, builder, accessor)
+            }
         }
     }
 
@@ -55,6 +173,18 @@ impl<C: Commitment> ProofExecutionPlan<C> for ProofPlan<C> {
             ProofPlan::Filter(expr) => expr.get_length(accessor),
             ProofPlan::GroupBy(expr) => expr.get_length(accessor),
             ProofPlan::DenseFilter(expr) => expr.get_length(accessor),
+            ProofPlan::Exists(expr) => expr.get_length(accessor),
+            ProofPlan::Min(expr) => expr.get_length(accessor),
+            ProofPlan::Max(expr) => expr.get_length(accessor),
+            ProofPlan::SemiJoin(expr) => expr.get_length(accessor),
+            ProofPlan::CountDistinct(expr) => expr.get_length(accessor),
+            ProofPlan::GroupByCountDistinct(expr) => expr.get_length(accessor),
+            ProofPlan::Avg(expr) => expr.get_length(accessor),
+            ProofPlan::Having(expr) => expr.get_length(accessor),
+            ProofPlan::Except(expr) => expr.get_length(accessor),
+            ProofPlan::Monotonic(expr) => expr.get_length(accessor),
+            ProofPlan::CumulativeSum(expr) => expr.get_length(accessor),
+            ProofPlan::RowCountEquals(expr) => ProofExecutionPlan::<C>::get_length(expr, accessor),
         }
     }
 
@@ -64,6 +194,41 @@ impl<C: Commitment> ProofExecutionPlan<C> for ProofPlan<C> {
             ProofPlan::Filter(expr) => expr.get_offset(accessor),
             ProofPlan::GroupBy(expr) => expr.get_offset(accessor),
             ProofPlan::DenseFilter(expr) => expr.get_offset(accessor),
+            ProofPlan::Exists(expr) => expr.get_offset(accessor),
+            ProofPlan::Min(expr) => expr.get_offset(accessor),
+            ProofPlan::Max(expr) => expr.get_offset(accessor),
+            ProofPlan::SemiJoin(expr) => expr.get_offset(accessor),
+            ProofPlan::CountDistinct(expr) => expr.get_offset(accessor),
+            ProofPlan::GroupByCountDistinct(expr) => expr.get_offset(accessor),
+            ProofPlan::Avg(expr) => expr.get_offset(accessor),
+            ProofPlan::Having(expr) => expr.get_offset(accessor),
+            ProofPlan::Except(expr) => expr.get_offset(accessor),
+            ProofPlan::Monotonic(expr) => expr.get_offset(accessor),
+            ProofPlan::CumulativeSum(expr) => expr.get_offset(accessor),
+            ProofPlan::RowCountEquals(expr) => ProofExecutionPlan::<C>::get_offset(expr, accessor),
+        }
+    }
+
+    fn get_empty_table_result(&self) -> Option<crate::base::database::OwnedTable<C::Scalar>> {
+        match self {
+            ProofPlan::Projection(expr) => expr.get_empty_table_result(),
+            ProofPlan::Filter(expr) => expr.get_empty_table_result(),
+            ProofPlan::GroupBy(expr) => expr.get_empty_table_result(),
+            ProofPlan::DenseFilter(expr) => expr.get_empty_table_result(),
+            ProofPlan::Exists(expr) => expr.get_empty_table_result(),
+            ProofPlan::Min(expr) => expr.get_empty_table_result(),
+            ProofPlan::Max(expr) => expr.get_empty_table_result(),
+            ProofPlan::SemiJoin(expr) => expr.get_empty_table_result(),
+            ProofPlan::CountDistinct(expr) => expr.get_empty_table_result(),
+            ProofPlan::GroupByCountDistinct(expr) => expr.get_empty_table_result(),
+            ProofPlan::Avg(expr) => expr.get_empty_table_result(),
+            ProofPlan::Having(expr) => expr.get_empty_table_result(),
+            ProofPlan::Except(expr) => expr.get_empty_table_result(),
+            ProofPlan::Monotonic(expr) => expr.get_empty_table_result(),
+            ProofPlan::CumulativeSum(expr) => expr.get_empty_table_result(),
+            ProofPlan::RowCountEquals(expr) => {
+                ProofExecutionPlan::<C>::get_empty_table_result(expr)
+            }
         }
     }
 
@@ -79,6 +244,20 @@ impl<C: Commitment> ProofExecutionPlan<C> for ProofPlan<C> {
             ProofPlan::Filter(expr) => expr.verifier_evaluate(builder, accessor, result),
             ProofPlan::GroupBy(expr) => expr.verifier_evaluate(builder, accessor, result),
             ProofPlan::DenseFilter(expr) => expr.verifier_evaluate(builder, accessor, result),
+            ProofPlan::Exists(expr) => expr.verifier_evaluate(builder, accessor, result),
+            ProofPlan::Min(expr) => expr.verifier_evaluate(builder, accessor, result),
+            ProofPlan::Max(expr) => expr.verifier_evaluate(builder, accessor, result),
+            ProofPlan::SemiJoin(expr) => expr.verifier_evaluate(builder, accessor, result),
+            ProofPlan::CountDistinct(expr) => expr.verifier_evaluate(builder, accessor, result),
+            ProofPlan::GroupByCountDistinct(expr) => {
+                expr.verifier_evaluate(builder, accessor, result)
+            }
+            ProofPlan::Avg(expr) => expr.verifier_evaluate(builder, accessor, result),
+            ProofPlan::Having(expr) => expr.verifier_evaluate(builder, accessor, result),
+            ProofPlan::Except(expr) => expr.verifier_evaluate(builder, accessor, result),
+            ProofPlan::Monotonic(expr) => expr.verifier_evaluate(builder, accessor, result),
+            ProofPlan::CumulativeSum(expr) => expr.verifier_evaluate(builder, accessor, result),
+            ProofPlan::RowCountEquals(expr) => expr.verifier_evaluate(builder, accessor, result),
         }
     }
 
@@ -88,6 +267,20 @@ impl<C: Commitment> ProofExecutionPlan<C> for ProofPlan<C> {
             ProofPlan::Filter(expr) => expr.get_column_result_fields(),
             ProofPlan::GroupBy(expr) => expr.get_column_result_fields(),
             ProofPlan::DenseFilter(expr) => expr.get_column_result_fields(),
+            ProofPlan::Exists(expr) => expr.get_column_result_fields(),
+            ProofPlan::Min(expr) => expr.get_column_result_fields(),
+            ProofPlan::Max(expr) => expr.get_column_result_fields(),
+            ProofPlan::SemiJoin(expr) => expr.get_column_result_fields(),
+            ProofPlan::CountDistinct(expr) => expr.get_column_result_fields(),
+            ProofPlan::GroupByCountDistinct(expr) => expr.get_column_result_fields(),
+            ProofPlan::Avg(expr) => expr.get_column_result_fields(),
+            ProofPlan::Having(expr) => expr.get_column_result_fields(),
+            ProofPlan::Except(expr) => expr.get_column_result_fields(),
+            ProofPlan::Monotonic(expr) => expr.get_column_result_fields(),
+            ProofPlan::CumulativeSum(expr) => expr.get_column_result_fields(),
+            ProofPlan::RowCountEquals(expr) => {
+                ProofExecutionPlan::<C>::get_column_result_fields(expr)
+            }
         }
     }
 
@@ -97,16 +290,29 @@ impl<C: Commitment> ProofExecutionPlan<C> for ProofPlan<C> {
             ProofPlan::Filter(expr) => expr.get_column_references(),
             ProofPlan::GroupBy(expr) => expr.get_column_references(),
             ProofPlan::DenseFilter(expr) => expr.get_column_references(),
+            ProofPlan::Exists(expr) => expr.get_column_references(),
+            ProofPlan::Min(expr) => expr.get_column_references(),
+            ProofPlan::Max(expr) => expr.get_column_references(),
+            ProofPlan::SemiJoin(expr) => expr.get_column_references(),
+            ProofPlan::CountDistinct(expr) => expr.get_column_references(),
+            ProofPlan::GroupByCountDistinct(expr) => expr.get_column_references(),
+            ProofPlan::Avg(expr) => expr.get_column_references(),
+            ProofPlan::Having(expr) => expr.get_column_references(),
+            ProofPlan::Except(expr) => expr.get_column_references(),
+            ProofPlan::Monotonic(expr) => expr.get_column_references(),
+            ProofPlan::CumulativeSum(expr) => expr.get_column_references(),
+            ProofPlan::RowCountEquals(expr) => ProofExecutionPlan::<C>::get_column_references(expr),
         }
     }
 }
 
+#[cfg(feature = "prover")]
 impl<C: Commitment> ProverEvaluate<C::Scalar> for ProofPlan<C> {
     #[tracing::instrument(name = "ProofPlan::result_evaluate", level = "debug", skip_all)]
     fn result_evaluate<'a>(
         &self,
         builder: &mut crate::sql::proof::ResultBuilder<'a>,
-        alloc: &'a bumpalo::Bump,
+        alloc: &'a dyn crate::base::MleAllocator,
         accessor: &'a dyn crate::base::database::DataAccessor<C::Scalar>,
     ) {
         match self {
@@ -114,6 +320,18 @@ impl<C: Commitment> ProverEvaluate<C::Scalar> for ProofPlan<C> {
             ProofPlan::Filter(expr) => expr.result_evaluate(builder, alloc, accessor),
             ProofPlan::GroupBy(expr) => expr.result_evaluate(builder, alloc, accessor),
             ProofPlan::DenseFilter(expr) => expr.result_evaluate(builder, alloc, accessor),
+            ProofPlan::Exists(expr) => expr.result_evaluate(builder, alloc, accessor),
+            ProofPlan::Min(expr) => expr.result_evaluate(builder, alloc, accessor),
+            ProofPlan::Max(expr) => expr.result_evaluate(builder, alloc, accessor),
+            ProofPlan::SemiJoin(expr) => expr.result_evaluate(builder, alloc, accessor),
+            ProofPlan::CountDistinct(expr) => expr.result_evaluate(builder, alloc, accessor),
+            ProofPlan::GroupByCountDistinct(expr) => expr.result_evaluate(builder, alloc, accessor),
+            ProofPlan::Avg(expr) => expr.result_evaluate(builder, alloc, accessor),
+            ProofPlan::Having(expr) => expr.result_evaluate(builder, alloc, accessor),
+            ProofPlan::Except(expr) => expr.result_evaluate(builder, alloc, accessor),
+            ProofPlan::Monotonic(expr) => expr.result_evaluate(builder, alloc, accessor),
+            ProofPlan::CumulativeSum(expr) => expr.result_evaluate(builder, alloc, accessor),
+            ProofPlan::RowCountEquals(expr) => expr.result_evaluate(builder, alloc, accessor),
         }
     }
 
@@ -121,7 +339,7 @@ impl<C: Commitment> ProverEvaluate<C::Scalar> for ProofPlan<C> {
     fn prover_evaluate<'a>(
         &self,
         builder: &mut crate::sql::proof::ProofBuilder<'a, C::Scalar>,
-        alloc: &'a bumpalo::Bump,
+        alloc: &'a dyn crate::base::MleAllocator,
         accessor: &'a dyn crate::base::database::DataAccessor<C::Scalar>,
     ) {
         match self {
@@ -129,6 +347,18 @@ impl<C: Commitment> ProverEvaluate<C::Scalar> for ProofPlan<C> {
             ProofPlan::Filter(expr) => expr.prover_evaluate(builder, alloc, accessor),
             ProofPlan::GroupBy(expr) => expr.prover_evaluate(builder, alloc, accessor),
             ProofPlan::DenseFilter(expr) => expr.prover_evaluate(builder, alloc, accessor),
+            ProofPlan::Exists(expr) => expr.prover_evaluate(builder, alloc, accessor),
+            ProofPlan::Min(expr) => expr.prover_evaluate(builder, alloc, accessor),
+            ProofPlan::Max(expr) => expr.prover_evaluate(builder, alloc, accessor),
+            ProofPlan::SemiJoin(expr) => expr.prover_evaluate(builder, alloc, accessor),
+            ProofPlan::CountDistinct(expr) => expr.prover_evaluate(builder, alloc, accessor),
+            ProofPlan::GroupByCountDistinct(expr) => expr.prover_evaluate(builder, alloc, accessor),
+            ProofPlan::Avg(expr) => expr.prover_evaluate(builder, alloc, accessor),
+            ProofPlan::Having(expr) => expr.prover_evaluate(builder, alloc, accessor),
+            ProofPlan::Except(expr) => expr.prover_evaluate(builder, alloc, accessor),
+            ProofPlan::Monotonic(expr) => expr.prover_evaluate(builder, alloc, accessor),
+            ProofPlan::CumulativeSum(expr) => expr.prover_evaluate(builder, alloc, accessor),
+            ProofPlan::RowCountEquals(expr) => expr.prover_evaluate(builder, alloc, accessor),
         }
     }
 }
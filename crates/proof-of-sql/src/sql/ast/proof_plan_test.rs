@@ -0,0 +1,104 @@
+use super::{test_utility::*, ProofPlan};
+use crate::{
+    base::{
+        commitment::InnerProductProof,
+        database::{owned_table_utility::*, OwnedTableTestAccessor},
+    },
+    sql::proof::{exercise_verification, VerifiableQueryResult},
+};
+use curve25519_dalek::RistrettoPoint;
+
+// select a, b / 2 as c from sxt.t where (a = 5 and not b = 3) or c <= 2
+#[test]
+fn we_can_round_trip_a_nested_filter_plan_and_prove_with_the_deserialized_copy() {
+    let data = owned_table([
+        bigint("a", [5_i64, 5, 1, 5, 2]),
+        bigint("b", [3_i64, 4, 8, 7, 6]),
+        bigint("c", [9_i64, 9, 2, 9, 3]),
+    ]);
+    let t = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(t, data, 0, ());
+    let ast = dense_filter(
+        vec![
+            col_expr_plan(t, "a", &accessor),
+            aliased_plan(divide(column(t, "b", &accessor), 2), "c"),
+        ],
+        tab(t),
+        or(
+            and(
+                equal(column(t, "a", &accessor), const_bigint(5)),
+                not(equal(column(t, "b", &accessor), const_bigint(3))),
+            ),
+            lte(column(t, "c", &accessor), const_bigint(2)),
+        ),
+    );
+
+    let json = serde_json::to_string(&ast).unwrap();
+    let from_json: ProofPlan<RistrettoPoint> = serde_json::from_str(&json).unwrap();
+    assert_eq!(from_json, ast);
+    let bytes = postcard::to_allocvec(&ast).unwrap();
+    let from_postcard: ProofPlan<RistrettoPoint> = postcard::from_bytes(&bytes).unwrap();
+    assert_eq!(from_postcard, ast);
+
+    let verifiable_res = VerifiableQueryResult::new(&from_json, &accessor, &());
+    exercise_verification(&verifiable_res, &from_json, &accessor, t);
+    let res = verifiable_res.verify(&ast, &accessor, &()).unwrap().table;
+    let expected_res = owned_table([bigint("a", [5_i64, 1, 5]), int128("c", [2_i128, 4, 3])]);
+    assert_eq!(res, expected_res);
+}
+
+#[test]
+fn we_cannot_deserialize_a_plan_with_an_unknown_tag() {
+    let data = owned_table([bigint("a", [5_i64]), bigint("b", [3_i64])]);
+    let t = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(t, data, 0, ());
+    let ast = dense_filter(
+        cols_expr_plan(t, &["a"], &accessor),
+        tab(t),
+        not(equal(column(t, "b", &accessor), const_bigint(3))),
+    );
+    let json = serde_json::to_string(&ast).unwrap();
+    assert!(json.contains("\"Equals\""));
+
+    let unknown_plan = json.replacen("\"DenseFilter\"", "\"UnknownFilter\"", 1);
+    assert!(serde_json::from_str::<ProofPlan<RistrettoPoint>>(&unknown_plan).is_err());
+    let unknown_expr = json.replacen("\"Equals\"", "\"UnknownEquals\"", 1);
+    assert!(serde_json::from_str::<ProofPlan<RistrettoPoint>>(&unknown_expr).is_err());
+}
+
+#[test]
+fn we_cannot_deserialize_a_plan_that_divides_by_zero() {
+    let data = owned_table([bigint("a", [5_i64])]);
+    let t = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(t, data, 0, ());
+    let ast = projection(
+        vec![aliased_plan(divide(column(t, "a", &accessor), 7), "q")],
+        tab(t),
+    );
+    let json = serde_json::to_string(&ast).unwrap();
+    assert!(json.contains("\"divisor\":7"));
+    let zero_divisor = json.replacen("\"divisor\":7", "\"divisor\":0", 1);
+    assert!(serde_json::from_str::<ProofPlan<RistrettoPoint>>(&zero_divisor).is_err());
+}
+
+#[test]
+fn we_cannot_deserialize_a_case_when_plan_with_branches_of_different_types() {
+    let data = owned_table([bigint("a", [5_i64])]);
+    let t = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(t, data, 0, ());
+    let ast = projection(
+        vec![aliased_plan(
+            case_when(
+                equal(column(t, "a", &accessor), const_bigint(5)),
+                const_bigint(1),
+                const_bigint(2),
+            ),
+            "x",
+        )],
+        tab(t),
+    );
+    let json = serde_json::to_string(&ast).unwrap();
+    assert!(json.contains("{\"BigInt\":2}"));
+    let boolean_else = json.replacen("{\"BigInt\":2}", "{\"Boolean\":true}", 1);
+    assert!(serde_json::from_str::<ProofPlan<RistrettoPoint>>(&boolean_else).is_err());
+}
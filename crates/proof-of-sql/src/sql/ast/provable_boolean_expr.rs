@@ -0,0 +1,53 @@
+use super::ProvableExpr;
+use crate::{
+    base::{commitment::Commitment, database::CommitmentAccessor, proof::ProofError},
+    sql::proof::VerificationBuilder,
+};
+#[cfg(feature = "prover")]
+use crate::{
+    base::{database::DataAccessor, MleAllocator},
+    sql::proof::ProofBuilder,
+};
+
+/// Provable AST expression that evaluates to a selection, i.e. a boolean column
+///
+/// The default implementations simply evaluate the expression and interpret the result
+/// as a boolean column. Predicates override these so that they can be composed
+/// by logical operators without going through a `Column`.
+pub trait ProvableBooleanExpr<C: Commitment>: ProvableExpr<C> {
+    /// Evaluate the selection on the given table without adding any proof components
+    #[cfg(feature = "prover")]
+    fn result_evaluate_selection<'a>(
+        &self,
+        table_length: usize,
+        alloc: &'a dyn MleAllocator,
+        accessor: &'a dyn DataAccessor<C::Scalar>,
+    ) -> &'a [bool] {
+        self.result_evaluate(table_length, alloc, accessor)
+            .as_boolean()
+            .expect("expression is not boolean")
+    }
+
+    /// Evaluate the selection, add components needed to prove it, and return the selection indicator
+    #[cfg(feature = "prover")]
+    fn prover_evaluate_selection<'a>(
+        &self,
+        builder: &mut ProofBuilder<'a, C::Scalar>,
+        alloc: &'a dyn MleAllocator,
+        accessor: &'a dyn DataAccessor<C::Scalar>,
+    ) -> &'a [bool] {
+        self.prover_evaluate(builder, alloc, accessor)
+            .as_boolean()
+            .expect("expression is not boolean")
+    }
+
+    /// Compute the evaluation of the selection indicator at the random sumcheck point
+    /// and add components needed to verify it to the `VerificationBuilder`
+    fn verifier_evaluate_selection(
+        &self,
+        builder: &mut VerificationBuilder<C>,
+        accessor: &dyn CommitmentAccessor<C>,
+    ) -> Result<C::Scalar, ProofError> {
+        self.verifier_evaluate(builder, accessor)
+    }
+}
@@ -1,19 +1,26 @@
 use super::{
-    AddSubtractExpr, AggregateExpr, AndExpr, ColumnExpr, EqualsExpr, InequalityExpr, LiteralExpr,
-    MultiplyExpr, NotExpr, OrExpr, ProvableExpr,
+    AddSubtractExpr, AggregateExpr, AndExpr, CaseExpr, CastExpr, ColumnExpr, ComplementExpr,
+    DivExpr, EqualsExpr, EqualsIgnoreCaseExpr, InequalityExpr, LiteralExpr, MultiplyExpr, NotExpr,
+    OrExpr, ProvableBooleanExpr, ProvableExpr, RowRangeExpr, XorExpr,
 };
 use crate::{
     base::{
         commitment::Commitment,
-        database::{Column, ColumnRef, ColumnType, CommitmentAccessor, DataAccessor, LiteralValue},
+        database::{
+            Column, ColumnRef, ColumnType, CommitmentAccessor, LiteralValue, MetadataAccessor,
+        },
         proof::ProofError,
     },
     sql::{
         parse::{type_check_binary_operation, ConversionError, ConversionResult},
-        proof::{CountBuilder, ProofBuilder, VerificationBuilder},
+        proof::{CountBuilder, VerificationBuilder},
     },
 };
-use bumpalo::Bump;
+#[cfg(feature = "prover")]
+use crate::{
+    base::{database::DataAccessor, MleAllocator},
+    sql::proof::ProofBuilder,
+};
 use indexmap::IndexSet;
 use proof_of_sql_parser::intermediate_ast::{AggregationOperator, BinaryOperator};
 use serde::{Deserialize, Serialize};
@@ -34,6 +41,8 @@ pub enum ProvableExprPlan<C: Commitment> {
     Literal(LiteralExpr<C::Scalar>),
     /// Provable AST expression for an equals expression
     Equals(EqualsExpr<C>),
+    /// Provable case-insensitive equality of a varchar expression with a constant
+    EqualsIgnoreCase(EqualsIgnoreCaseExpr<C>),
     /// Provable AST expression for an inequality expression
     Inequality(InequalityExpr<C>),
     /// Provable numeric `+` / `-` expression
@@ -42,6 +51,18 @@ pub enum ProvableExprPlan<C: Commitment> {
     Multiply(MultiplyExpr<C>),
     /// Provable aggregate expression
     Aggregate(AggregateExpr<C>),
+    /// Provable `CASE WHEN` expression
+    Case(CaseExpr<C>),
+    /// Provable integer division by a constant
+    Div(DivExpr<C>),
+    /// Provable lossless conversion to a wider numeric type
+    Cast(CastExpr<C>),
+    /// Provable expression that is true exactly for a range of table rows
+    RowRange(RowRangeExpr),
+    /// Provable logical XOR expression
+    Xor(XorExpr<C>),
+    /// Provable complement of a selection, checked to partition the table with it
+    Complement(ComplementExpr<C>),
 }
 impl<C: Commitment> ProvableExprPlan<C> {
     /// Create column expression
@@ -71,10 +92,38 @@ impl<C: Commitment> ProvableExprPlan<C> {
         expr.check_data_type(ColumnType::Boolean)?;
         Ok(Self::Not(NotExpr::new(Box::new(expr))))
     }
+    /// Create logical XOR expression
+    pub fn try_new_xor(
+        lhs: ProvableExprPlan<C>,
+        rhs: ProvableExprPlan<C>,
+    ) -> ConversionResult<Self> {
+        lhs.check_data_type(ColumnType::Boolean)?;
+        rhs.check_data_type(ColumnType::Boolean)?;
+        Ok(Self::Xor(XorExpr::new(Box::new(lhs), Box::new(rhs))))
+    }
+    /// Create the complement of a boolean expression
+    ///
+    /// This selects the same rows as the NOT of the expression, but also proves that the
+    /// expression and its complement together select every row of the table exactly once.
+    pub fn try_new_complement(expr: ProvableExprPlan<C>) -> ConversionResult<Self> {
+        expr.check_data_type(ColumnType::Boolean)?;
+        Ok(Self::Complement(ComplementExpr::new(Box::new(expr))))
+    }
+    /// Create logical NAND expression, which is the NOT of an AND expression
+    pub fn try_new_nand(
+        lhs: ProvableExprPlan<C>,
+        rhs: ProvableExprPlan<C>,
+    ) -> ConversionResult<Self> {
+        Self::try_new_not(Self::try_new_and(lhs, rhs)?)
+    }
     /// Create CONST expression
     pub fn new_literal(value: LiteralValue<C::Scalar>) -> Self {
         Self::Literal(LiteralExpr::new(value))
     }
+    /// Create an expression that is true exactly for the table rows in `rows`
+    pub fn new_row_range(rows: core::ops::Range<u64>) -> Self {
+        Self::RowRange(RowRangeExpr::new(rows))
+    }
     /// Create a new equals expression
     pub fn try_new_equals(
         lhs: ProvableExprPlan<C>,
@@ -91,6 +140,15 @@ impl<C: Commitment> ProvableExprPlan<C> {
             Ok(Self::Equals(EqualsExpr::new(Box::new(lhs), Box::new(rhs))))
         }
     }
+    /// Create a new case-insensitive equality of a varchar expression with `value`
+    ///
+    /// See [`EqualsIgnoreCaseExpr`] for which strings are considered equal.
+    pub fn try_new_equals_ignore_case(
+        expr: ProvableExprPlan<C>,
+        value: &str,
+    ) -> ConversionResult<Self> {
+        EqualsIgnoreCaseExpr::try_new(Box::new(expr), value.to_string()).map(Self::EqualsIgnoreCase)
+    }
     /// Create a new inequality expression
     pub fn try_new_inequality(
         lhs: ProvableExprPlan<C>,
@@ -184,6 +242,64 @@ impl<C: Commitment> ProvableExprPlan<C> {
         Self::Aggregate(AggregateExpr::new(op, Box::new(expr)))
     }
 
+    /// Create a new `CASE WHEN <predicate> THEN <then_expr> ELSE <else_expr> END` expression
+    pub fn try_new_case(
+        predicate: ProvableExprPlan<C>,
+        then_expr: ProvableExprPlan<C>,
+        else_expr: ProvableExprPlan<C>,
+    ) -> ConversionResult<Self> {
+        CaseExpr::try_new(
+            Box::new(predicate),
+            Box::new(then_expr),
+            Box::new(else_expr),
+        )
+        .map(Self::Case)
+    }
+
+    /// Create a new integer division by a nonzero constant
+    pub fn try_new_divide(lhs: ProvableExprPlan<C>, divisor: i64) -> ConversionResult<Self> {
+        match lhs.data_type() {
+            ColumnType::SmallInt | ColumnType::Int | ColumnType::BigInt => {
+                DivExpr::try_new(Box::new(lhs), divisor)
+                    .map(Self::Div)
+                    .map_err(|err| ConversionError::InvalidExpression(err.to_string()))
+            }
+            data_type => Err(ConversionError::InvalidDataType {
+                expected: ColumnType::BigInt,
+                actual: data_type,
+            }),
+        }
+    }
+
+    /// Create a new `CAST(<expr> AS <to_type>)` expression
+    pub fn try_new_cast(expr: ProvableExprPlan<C>, to_type: ColumnType) -> ConversionResult<Self> {
+        CastExpr::try_new(Box::new(expr), to_type)
+            .map(Self::Cast)
+            .map_err(|err| ConversionError::InvalidExpression(err.to_string()))
+    }
+
+    /// Range check the result of this `+`, `-` or `*` expression so that verification fails
+    /// if it overflows its integer type, rather than returning a value outside of the type's range.
+    ///
+    /// This only applies to the outermost expression, and has no effect on other expressions.
+    pub fn with_checked_arithmetic(self) -> Self {
+        match self {
+            Self::AddSubtract(expr) => Self::AddSubtract(expr.with_checked_arithmetic()),
+            Self::Multiply(expr) => Self::Multiply(expr.with_checked_arithmetic()),
+            expr => expr,
+        }
+    }
+
+    /// Use the column bounds known to `accessor` to skip proving a comparison they determine
+    ///
+    /// This only applies to the outermost expression, and has no effect on other expressions.
+    pub fn with_column_bounds(self, accessor: &(impl MetadataAccessor + ?Sized)) -> Self {
+        match self {
+            Self::Inequality(expr) => Self::Inequality(expr.with_column_bounds(accessor)),
+            expr => expr,
+        }
+    }
+
     /// Check that the plan has the correct data type
     fn check_data_type(&self, data_type: ColumnType) -> ConversionResult<()> {
         if self.data_type() == data_type {
@@ -204,12 +320,19 @@ impl<C: Commitment> ProvableExpr<C> for ProvableExprPlan<C> {
             ProvableExprPlan::And(expr) => ProvableExpr::<C>::count(expr, builder),
             ProvableExprPlan::Or(expr) => ProvableExpr::<C>::count(expr, builder),
             ProvableExprPlan::Not(expr) => ProvableExpr::<C>::count(expr, builder),
+            ProvableExprPlan::Xor(expr) => ProvableExpr::<C>::count(expr, builder),
+            ProvableExprPlan::Complement(expr) => ProvableExpr::<C>::count(expr, builder),
             ProvableExprPlan::Literal(expr) => ProvableExpr::<C>::count(expr, builder),
             ProvableExprPlan::Equals(expr) => ProvableExpr::<C>::count(expr, builder),
+            ProvableExprPlan::EqualsIgnoreCase(expr) => ProvableExpr::<C>::count(expr, builder),
             ProvableExprPlan::Inequality(expr) => ProvableExpr::<C>::count(expr, builder),
             ProvableExprPlan::AddSubtract(expr) => ProvableExpr::<C>::count(expr, builder),
             ProvableExprPlan::Multiply(expr) => ProvableExpr::<C>::count(expr, builder),
             ProvableExprPlan::Aggregate(expr) => ProvableExpr::<C>::count(expr, builder),
+            ProvableExprPlan::Case(expr) => ProvableExpr::<C>::count(expr, builder),
+            ProvableExprPlan::Div(expr) => ProvableExpr::<C>::count(expr, builder),
+            ProvableExprPlan::Cast(expr) => ProvableExpr::<C>::count(expr, builder),
+            ProvableExprPlan::RowRange(expr) => ProvableExpr::<C>::count(expr, builder),
         }
     }
 
@@ -219,19 +342,27 @@ impl<C: Commitment> ProvableExpr<C> for ProvableExprPlan<C> {
             ProvableExprPlan::AddSubtract(expr) => expr.data_type(),
             ProvableExprPlan::Multiply(expr) => expr.data_type(),
             ProvableExprPlan::Aggregate(expr) => expr.data_type(),
+            ProvableExprPlan::Case(expr) => expr.data_type(),
+            ProvableExprPlan::Div(expr) => expr.data_type(),
+            ProvableExprPlan::Cast(expr) => expr.data_type(),
             ProvableExprPlan::Literal(expr) => ProvableExpr::<C>::data_type(expr),
             ProvableExprPlan::And(_)
             | ProvableExprPlan::Or(_)
             | ProvableExprPlan::Not(_)
+            | ProvableExprPlan::Xor(_)
+            | ProvableExprPlan::Complement(_)
             | ProvableExprPlan::Equals(_)
-            | ProvableExprPlan::Inequality(_) => ColumnType::Boolean,
+            | ProvableExprPlan::EqualsIgnoreCase(_)
+            | ProvableExprPlan::Inequality(_)
+            | ProvableExprPlan::RowRange(_) => ColumnType::Boolean,
         }
     }
 
+    #[cfg(feature = "prover")]
     fn result_evaluate<'a>(
         &self,
         table_length: usize,
-        alloc: &'a Bump,
+        alloc: &'a dyn MleAllocator,
         accessor: &'a dyn DataAccessor<C::Scalar>,
     ) -> Column<'a, C::Scalar> {
         match self {
@@ -247,12 +378,21 @@ impl<C: Commitment> ProvableExpr<C> for ProvableExprPlan<C> {
             ProvableExprPlan::Not(expr) => {
                 ProvableExpr::<C>::result_evaluate(expr, table_length, alloc, accessor)
             }
+            ProvableExprPlan::Xor(expr) => {
+                ProvableExpr::<C>::result_evaluate(expr, table_length, alloc, accessor)
+            }
+            ProvableExprPlan::Complement(expr) => {
+                ProvableExpr::<C>::result_evaluate(expr, table_length, alloc, accessor)
+            }
             ProvableExprPlan::Literal(expr) => {
                 ProvableExpr::<C>::result_evaluate(expr, table_length, alloc, accessor)
             }
             ProvableExprPlan::Equals(expr) => {
                 ProvableExpr::<C>::result_evaluate(expr, table_length, alloc, accessor)
             }
+            ProvableExprPlan::EqualsIgnoreCase(expr) => {
+                ProvableExpr::<C>::result_evaluate(expr, table_length, alloc, accessor)
+            }
             ProvableExprPlan::Inequality(expr) => {
                 ProvableExpr::<C>::result_evaluate(expr, table_length, alloc, accessor)
             }
@@ -265,13 +405,26 @@ impl<C: Commitment> ProvableExpr<C> for ProvableExprPlan<C> {
             ProvableExprPlan::Aggregate(expr) => {
                 ProvableExpr::<C>::result_evaluate(expr, table_length, alloc, accessor)
             }
+            ProvableExprPlan::Case(expr) => {
+                ProvableExpr::<C>::result_evaluate(expr, table_length, alloc, accessor)
+            }
+            ProvableExprPlan::Div(expr) => {
+                ProvableExpr::<C>::result_evaluate(expr, table_length, alloc, accessor)
+            }
+            ProvableExprPlan::Cast(expr) => {
+                ProvableExpr::<C>::result_evaluate(expr, table_length, alloc, accessor)
+            }
+            ProvableExprPlan::RowRange(expr) => {
+                ProvableExpr::<C>::result_evaluate(expr, table_length, alloc, accessor)
+            }
         }
     }
 
+    #[cfg(feature = "prover")]
     fn prover_evaluate<'a>(
         &self,
         builder: &mut ProofBuilder<'a, C::Scalar>,
-        alloc: &'a Bump,
+        alloc: &'a dyn MleAllocator,
         accessor: &'a dyn DataAccessor<C::Scalar>,
     ) -> Column<'a, C::Scalar> {
         match self {
@@ -287,12 +440,21 @@ impl<C: Commitment> ProvableExpr<C> for ProvableExprPlan<C> {
             ProvableExprPlan::Not(expr) => {
                 ProvableExpr::<C>::prover_evaluate(expr, builder, alloc, accessor)
             }
+            ProvableExprPlan::Xor(expr) => {
+                ProvableExpr::<C>::prover_evaluate(expr, builder, alloc, accessor)
+            }
+            ProvableExprPlan::Complement(expr) => {
+                ProvableExpr::<C>::prover_evaluate(expr, builder, alloc, accessor)
+            }
             ProvableExprPlan::Literal(expr) => {
                 ProvableExpr::<C>::prover_evaluate(expr, builder, alloc, accessor)
             }
             ProvableExprPlan::Equals(expr) => {
                 ProvableExpr::<C>::prover_evaluate(expr, builder, alloc, accessor)
             }
+            ProvableExprPlan::EqualsIgnoreCase(expr) => {
+                ProvableExpr::<C>::prover_evaluate(expr, builder, alloc, accessor)
+            }
             ProvableExprPlan::Inequality(expr) => {
                 ProvableExpr::<C>::prover_evaluate(expr, builder, alloc, accessor)
             }
@@ -305,6 +467,18 @@ impl<C: Commitment> ProvableExpr<C> for ProvableExprPlan<C> {
             ProvableExprPlan::Aggregate(expr) => {
                 ProvableExpr::<C>::prover_evaluate(expr, builder, alloc, accessor)
             }
+            ProvableExprPlan::Case(expr) => {
+                ProvableExpr::<C>::prover_evaluate(expr, builder, alloc, accessor)
+            }
+            ProvableExprPlan::Div(expr) => {
+                ProvableExpr::<C>::prover_evaluate(expr, builder, alloc, accessor)
+            }
+            ProvableExprPlan::Cast(expr) => {
+                ProvableExpr::<C>::prover_evaluate(expr, builder, alloc, accessor)
+            }
+            ProvableExprPlan::RowRange(expr) => {
+                ProvableExpr::<C>::prover_evaluate(expr, builder, alloc, accessor)
+            }
         }
     }
 
@@ -320,12 +494,19 @@ impl<C: Commitment> ProvableExpr<C> for ProvableExprPlan<C> {
             ProvableExprPlan::And(expr) => expr.verifier_evaluate(builder, accessor),
             ProvableExprPlan::Or(expr) => expr.verifier_evaluate(builder, accessor),
             ProvableExprPlan::Not(expr) => expr.verifier_evaluate(builder, accessor),
+            ProvableExprPlan::Xor(expr) => expr.verifier_evaluate(builder, accessor),
+            ProvableExprPlan::Complement(expr) => expr.verifier_evaluate(builder, accessor),
             ProvableExprPlan::Literal(expr) => expr.verifier_evaluate(builder, accessor),
             ProvableExprPlan::Equals(expr) => expr.verifier_evaluate(builder, accessor),
+            ProvableExprPlan::EqualsIgnoreCase(expr) => expr.verifier_evaluate(builder, accessor),
             ProvableExprPlan::Inequality(expr) => expr.verifier_evaluate(builder, accessor),
             ProvableExprPlan::AddSubtract(expr) => expr.verifier_evaluate(builder, accessor),
             ProvableExprPlan::Multiply(expr) => expr.verifier_evaluate(builder, accessor),
             ProvableExprPlan::Aggregate(expr) => expr.verifier_evaluate(builder, accessor),
+            ProvableExprPlan::Case(expr) => expr.verifier_evaluate(builder, accessor),
+            ProvableExprPlan::Div(expr) => expr.verifier_evaluate(builder, accessor),
+            ProvableExprPlan::Cast(expr) => expr.verifier_evaluate(builder, accessor),
+            ProvableExprPlan::RowRange(expr) => expr.verifier_evaluate(builder, accessor),
         }
     }
 
@@ -337,12 +518,19 @@ impl<C: Commitment> ProvableExpr<C> for ProvableExprPlan<C> {
             ProvableExprPlan::And(expr) => ProvableExpr::<C>::get_column_references(expr, columns),
             ProvableExprPlan::Or(expr) => ProvableExpr::<C>::get_column_references(expr, columns),
             ProvableExprPlan::Not(expr) => ProvableExpr::<C>::get_column_references(expr, columns),
+            ProvableExprPlan::Xor(expr) => ProvableExpr::<C>::get_column_references(expr, columns),
+            ProvableExprPlan::Complement(expr) => {
+                ProvableExpr::<C>::get_column_references(expr, columns)
+            }
             ProvableExprPlan::Literal(expr) => {
                 ProvableExpr::<C>::get_column_references(expr, columns)
             }
             ProvableExprPlan::Equals(expr) => {
                 ProvableExpr::<C>::get_column_references(expr, columns)
             }
+            ProvableExprPlan::EqualsIgnoreCase(expr) => {
+                ProvableExpr::<C>::get_column_references(expr, columns)
+            }
             ProvableExprPlan::Inequality(expr) => {
                 ProvableExpr::<C>::get_column_references(expr, columns)
             }
@@ -355,6 +543,99 @@ impl<C: Commitment> ProvableExpr<C> for ProvableExprPlan<C> {
             ProvableExprPlan::Aggregate(expr) => {
                 ProvableExpr::<C>::get_column_references(expr, columns)
             }
+            ProvableExprPlan::Case(expr) => ProvableExpr::<C>::get_column_references(expr, columns),
+            ProvableExprPlan::Div(expr) => ProvableExpr::<C>::get_column_references(expr, columns),
+            ProvableExprPlan::Cast(expr) => ProvableExpr::<C>::get_column_references(expr, columns),
+            ProvableExprPlan::RowRange(expr) => {
+                ProvableExpr::<C>::get_column_references(expr, columns)
+            }
+        }
+    }
+}
+
+impl<C: Commitment> ProvableBooleanExpr<C> for ProvableExprPlan<C> {
+    #[cfg(feature = "prover")]
+    fn result_evaluate_selection<'a>(
+        &self,
+        table_length: usize,
+        alloc: &'a dyn MleAllocator,
+        accessor: &'a dyn DataAccessor<C::Scalar>,
+    ) -> &'a [bool] {
+        match self {
+            ProvableExprPlan::And(expr) => {
+                expr.result_evaluate_selection(table_length, alloc, accessor)
+            }
+            ProvableExprPlan::Or(expr) => {
+                expr.result_evaluate_selection(table_length, alloc, accessor)
+            }
+            ProvableExprPlan::Not(expr) => {
+                expr.result_evaluate_selection(table_length, alloc, accessor)
+            }
+            ProvableExprPlan::Xor(expr) => {
+                expr.result_evaluate_selection(table_length, alloc, accessor)
+            }
+            ProvableExprPlan::Complement(expr) => {
+                expr.result_evaluate_selection(table_length, alloc, accessor)
+            }
+            ProvableExprPlan::Equals(expr) => {
+                expr.result_evaluate_selection(table_length, alloc, accessor)
+            }
+            ProvableExprPlan::Inequality(expr) => {
+                expr.result_evaluate_selection(table_length, alloc, accessor)
+            }
+            _ => self
+                .result_evaluate(table_length, alloc, accessor)
+                .as_boolean()
+                .expect("expression is not boolean"),
+        }
+    }
+
+    #[cfg(feature = "prover")]
+    fn prover_evaluate_selection<'a>(
+        &self,
+        builder: &mut ProofBuilder<'a, C::Scalar>,
+        alloc: &'a dyn MleAllocator,
+        accessor: &'a dyn DataAccessor<C::Scalar>,
+    ) -> &'a [bool] {
+        match self {
+            ProvableExprPlan::And(expr) => expr.prover_evaluate_selection(builder, alloc, accessor),
+            ProvableExprPlan::Or(expr) => expr.prover_evaluate_selection(builder, alloc, accessor),
+            ProvableExprPlan::Not(expr) => expr.prover_evaluate_selection(builder, alloc, accessor),
+            ProvableExprPlan::Xor(expr) => expr.prover_evaluate_selection(builder, alloc, accessor),
+            ProvableExprPlan::Complement(expr) => {
+                expr.prover_evaluate_selection(builder, alloc, accessor)
+            }
+            ProvableExprPlan::Equals(expr) => {
+                expr.prover_evaluate_selection(builder, alloc, accessor)
+            }
+            ProvableExprPlan::Inequality(expr) => {
+                expr.prover_evaluate_selection(builder, alloc, accessor)
+            }
+            _ => self
+                .prover_evaluate(builder, alloc, accessor)
+                .as_boolean()
+                .expect("expression is not boolean"),
+        }
+    }
+
+    fn verifier_evaluate_selection(
+        &self,
+        builder: &mut VerificationBuilder<C>,
+        accessor: &dyn CommitmentAccessor<C>,
+    ) -> Result<C::Scalar, ProofError> {
+        match self {
+            ProvableExprPlan::And(expr) => expr.verifier_evaluate_selection(builder, accessor),
+            ProvableExprPlan::Or(expr) => expr.verifier_evaluate_selection(builder, accessor),
+            ProvableExprPlan::Not(expr) => expr.verifier_evaluate_selection(builder, accessor),
+            ProvableExprPlan::Xor(expr) => expr.verifier_evaluate_selection(builder, accessor),
+            ProvableExprPlan::Complement(expr) => {
+                expr.verifier_evaluate_selection(builder, accessor)
+            }
+            ProvableExprPlan::Equals(expr) => expr.verifier_evaluate_selection(builder, accessor),
+            ProvableExprPlan::Inequality(expr) => {
+                expr.verifier_evaluate_selection(builder, accessor)
+            }
+            _ => self.verifier_evaluate(builder, accessor),
         }
     }
 }
@@ -0,0 +1,143 @@
+use super::TableExpr;
+use crate::{
+    base::{
+        commitment::Commitment,
+        database::{
+            ColumnField, ColumnRef, ColumnType, CommitmentAccessor, MetadataAccessor, OwnedColumn,
+            OwnedTable,
+        },
+        proof::ProofError,
+        scalar::Scalar,
+    },
+    sql::proof::{CountBuilder, ProofExecutionPlan, VerificationBuilder},
+};
+#[cfg(feature = "prover")]
+use crate::{
+    base::{database::DataAccessor, MleAllocator},
+    sql::proof::{Indexes, ProofBuilder, ProverEvaluate, ResultBuilder},
+};
+use indexmap::IndexSet;
+use proof_of_sql_parser::Identifier;
+use serde::{Deserialize, Serialize};
+
+/// Provable expressions for queries of the form
+/// ```ignore
+///     SELECT (SELECT COUNT(*) FROM <table>) = (SELECT COUNT(*) FROM <other_table>) AS <alias>
+/// ```
+///
+/// The result is a single boolean row that is true exactly when the two tables have the same
+/// number of rows. Two empty tables have the same number of rows.
+///
+/// The verifier already knows the length of both tables, so it compares the result with them
+/// directly, and only needs the proof to place the result on the first row.
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct RowCountEqualsExpr {
+    pub(super) table: TableExpr,
+    pub(super) other_table: TableExpr,
+    pub(super) alias: Identifier,
+}
+
+impl RowCountEqualsExpr {
+    /// Creates a new row count equality expression.
+    pub fn new(table: TableExpr, other_table: TableExpr, alias: Identifier) -> Self {
+        Self {
+            table,
+            other_table,
+            alias,
+        }
+    }
+}
+
+impl<C: Commitment> ProofExecutionPlan<C> for RowCountEqualsExpr {
+    fn count(
+        &self,
+        builder: &mut CountBuilder,
+        _accessor: &dyn MetadataAccessor,
+    ) -> Result<(), ProofError> {
+        builder.count_result_columns(1);
+        Ok(())
+    }
+
+    fn get_length(&self, accessor: &dyn MetadataAccessor) -> usize {
+        // The result has a row even when both tables are empty.
+        accessor
+            .get_length(self.table.table_ref)
+            .max(accessor.get_length(self.other_table.table_ref))
+            .max(1)
+    }
+
+    fn get_offset(&self, accessor: &dyn MetadataAccessor) -> usize {
+        accessor.get_offset(self.table.table_ref)
+    }
+
+    fn verifier_evaluate(
+        &self,
+        builder: &mut VerificationBuilder<C>,
+        accessor: &dyn CommitmentAccessor<C>,
+        result: Option<&OwnedTable<C::Scalar>>,
+    ) -> Result<(), ProofError> {
+        // 1. indexes, which must be the first row
+        let indexes_eval = builder
+            .mle_evaluations
+            .result_indexes_evaluation
+            .ok_or(ProofError::VerificationError("invalid indexes"))?;
+        if indexes_eval != builder.mle_evaluations.truncated_one_evaluation(1) {
+            Err(ProofError::VerificationError(
+                "Result of a row count equality must be on the first row.",
+            ))?;
+        }
+        // 2. result
+        // The result is compared with the table lengths below, so its MLE isn't needed.
+        let _ = builder.consume_result_mle();
+        let equal = accessor.get_length(self.table.table_ref)
+            == accessor.get_length(self.other_table.table_ref);
+        match result.map(|table| table.inner_table().get(&self.alias)) {
+            Some(Some(OwnedColumn::Boolean(values))) if *values == [equal] => Ok(()),
+            _ => Err(ProofError::VerificationError(
+                "Result of a row count equality must be a single boolean matching the row counts.",
+            )),
+        }
+    }
+
+    fn get_column_result_fields(&self) -> Vec<ColumnField> {
+        vec![ColumnField::new(self.alias, ColumnType::Boolean)]
+    }
+
+    fn get_column_references(&self) -> IndexSet<ColumnRef> {
+        IndexSet::new()
+    }
+}
+
+#[cfg(feature = "prover")]
+impl<S: Scalar> ProverEvaluate<S> for RowCountEqualsExpr {
+    #[tracing::instrument(
+        name = "RowCountEqualsExpr::result_evaluate",
+        level = "debug",
+        skip_all
+    )]
+    fn result_evaluate<'a>(
+        &self,
+        builder: &mut ResultBuilder<'a>,
+        alloc: &'a dyn MleAllocator,
+        accessor: &'a dyn DataAccessor<S>,
+    ) {
+        let equal = accessor.get_length(self.table.table_ref)
+            == accessor.get_length(self.other_table.table_ref);
+        let result = alloc.alloc_slice_fill_copy(1, equal);
+        builder.set_result_indexes(Indexes::Dense(0..1));
+        builder.produce_result_column(result as &[_]);
+    }
+
+    #[tracing::instrument(
+        name = "RowCountEqualsExpr::prover_evaluate",
+        level = "debug",
+        skip_all
+    )]
+    fn prover_evaluate<'a>(
+        &self,
+        _builder: &mut ProofBuilder<'a, S>,
+        _alloc: &'a dyn MleAllocator,
+        _accessor: &'a dyn DataAccessor<S>,
+    ) {
+    }
+}
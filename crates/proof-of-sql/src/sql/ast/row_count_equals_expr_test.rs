@@ -0,0 +1,77 @@
+use super::test_utility::*;
+use crate::{
+    base::{
+        commitment::InnerProductProof,
+        database::{owned_table_utility::*, OwnedTableTestAccessor, TestAccessor},
+    },
+    sql::proof::{Indexes, VerifiableQueryResult},
+};
+
+/// select (select count(*) from sxt.t) = (select count(*) from sxt.u) as e
+#[test]
+fn we_can_prove_that_two_tables_with_the_same_number_of_rows_have_equal_row_counts() {
+    let t = "sxt.t".parse().unwrap();
+    let u = "sxt.u".parse().unwrap();
+    let mut accessor = OwnedTableTestAccessor::<InnerProductProof>::new_empty_with_setup(());
+    accessor.add_table(t, owned_table([bigint("a", [1, 2, 3, 4])]), 0);
+    accessor.add_table(u, owned_table([varchar("b", ["w", "x", "y", "z"])]), 0);
+    let expr = row_count_equals(tab(t), tab(u), "e");
+    let res = VerifiableQueryResult::new(&expr, &accessor, &());
+    let res = res.verify(&expr, &accessor, &()).unwrap().table;
+    assert_eq!(res, owned_table([boolean("e", [true])]));
+}
+
+/// select (select count(*) from sxt.t) = (select count(*) from sxt.u) as e
+#[test]
+fn we_can_prove_that_two_tables_with_different_numbers_of_rows_have_unequal_row_counts() {
+    let t = "sxt.t".parse().unwrap();
+    let u = "sxt.u".parse().unwrap();
+    let mut accessor = OwnedTableTestAccessor::<InnerProductProof>::new_empty_with_setup(());
+    accessor.add_table(t, owned_table([bigint("a", [1, 2, 3, 4, 5])]), 0);
+    accessor.add_table(u, owned_table([bigint("b", [1, 2, 3])]), 0);
+    for (table, other_table) in [(t, u), (u, t)] {
+        let expr = row_count_equals(tab(table), tab(other_table), "e");
+        let res = VerifiableQueryResult::new(&expr, &accessor, &());
+
+        // the result can be neither moved off the first row nor changed
+        let mut res_p = res.clone();
+        *res_p.provable_result.as_mut().unwrap().indexes_mut() = Indexes::Dense(1..2);
+        assert!(res_p.verify(&expr, &accessor, &()).is_err());
+        let mut res_p = res.clone();
+        res_p.provable_result.as_mut().unwrap().data_mut()[0] += 1;
+        assert!(res_p.verify(&expr, &accessor, &()).is_err());
+
+        let res = res.verify(&expr, &accessor, &()).unwrap().table;
+        assert_eq!(res, owned_table([boolean("e", [false])]));
+    }
+}
+
+/// select (select count(*) from sxt.t) = (select count(*) from sxt.u) as e
+#[test]
+fn two_empty_tables_have_equal_row_counts() {
+    let t = "sxt.t".parse().unwrap();
+    let u = "sxt.u".parse().unwrap();
+    let mut accessor = OwnedTableTestAccessor::<InnerProductProof>::new_empty_with_setup(());
+    accessor.add_table(t, owned_table([bigint("a", [0; 0])]), 0);
+    accessor.add_table(u, owned_table([bigint("b", [0; 0])]), 0);
+    let expr = row_count_equals(tab(t), tab(u), "e");
+    let res = VerifiableQueryResult::new(&expr, &accessor, &());
+    let res = res.verify(&expr, &accessor, &()).unwrap().table;
+    assert_eq!(res, owned_table([boolean("e", [true])]));
+}
+
+#[test]
+fn we_cannot_verify_a_row_count_equality_against_tables_of_other_lengths() {
+    let t = "sxt.t".parse().unwrap();
+    let u = "sxt.u".parse().unwrap();
+    let mut accessor = OwnedTableTestAccessor::<InnerProductProof>::new_empty_with_setup(());
+    accessor.add_table(t, owned_table([bigint("a", [1, 2, 3])]), 0);
+    accessor.add_table(u, owned_table([bigint("b", [1, 2, 3])]), 0);
+    let mut other_accessor = OwnedTableTestAccessor::<InnerProductProof>::new_empty_with_setup(());
+    other_accessor.add_table(t, owned_table([bigint("a", [1, 2, 3])]), 0);
+    other_accessor.add_table(u, owned_table([bigint("b", [1, 2])]), 0);
+    let expr = row_count_equals(tab(t), tab(u), "e");
+    let res = VerifiableQueryResult::new(&expr, &accessor, &());
+    assert!(res.verify(&expr, &accessor, &()).is_ok());
+    assert!(res.verify(&expr, &other_accessor, &()).is_err());
+}
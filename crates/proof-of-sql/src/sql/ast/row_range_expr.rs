@@ -0,0 +1,89 @@
+use super::ProvableExpr;
+use crate::{
+    base::{
+        commitment::Commitment,
+        database::{ColumnRef, ColumnType, CommitmentAccessor},
+        proof::ProofError,
+    },
+    sql::proof::{CountBuilder, VerificationBuilder},
+};
+#[cfg(feature = "prover")]
+use crate::{
+    base::{
+        database::{Column, DataAccessor},
+        MleAllocator,
+    },
+    sql::proof::ProofBuilder,
+};
+use core::ops::Range;
+use indexmap::IndexSet;
+use serde::{Deserialize, Serialize};
+
+/// Provable expression that is true exactly for the rows of the table in a range
+///
+/// This restricts a query to a window of its table, which is how a result is split into pages.
+/// The verifier computes the evaluation of this expression itself, so it needs no commitment.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RowRangeExpr {
+    rows: Range<u64>,
+}
+
+impl RowRangeExpr {
+    /// Create a row range expression
+    pub fn new(rows: Range<u64>) -> Self {
+        Self { rows }
+    }
+
+    /// The rows for which the expression is true
+    pub fn rows(&self) -> &Range<u64> {
+        &self.rows
+    }
+
+    fn contains(&self, row: usize) -> bool {
+        self.rows.contains(&(row as u64))
+    }
+}
+
+impl<C: Commitment> ProvableExpr<C> for RowRangeExpr {
+    fn count(&self, _builder: &mut CountBuilder) -> Result<(), ProofError> {
+        Ok(())
+    }
+
+    fn data_type(&self) -> ColumnType {
+        ColumnType::Boolean
+    }
+
+    #[cfg(feature = "prover")]
+    fn result_evaluate<'a>(
+        &self,
+        table_length: usize,
+        alloc: &'a dyn MleAllocator,
+        _accessor: &'a dyn DataAccessor<C::Scalar>,
+    ) -> Column<'a, C::Scalar> {
+        Column::Boolean(alloc.alloc_slice_fill_with(table_length, |row| self.contains(row)))
+    }
+
+    #[cfg(feature = "prover")]
+    fn prover_evaluate<'a>(
+        &self,
+        builder: &mut ProofBuilder<'a, C::Scalar>,
+        alloc: &'a dyn MleAllocator,
+        _accessor: &'a dyn DataAccessor<C::Scalar>,
+    ) -> Column<'a, C::Scalar> {
+        let table_length = builder.table_length();
+        Column::Boolean(alloc.alloc_slice_fill_with(table_length, |row| self.contains(row)))
+    }
+
+    fn verifier_evaluate(
+        &self,
+        builder: &mut VerificationBuilder<C>,
+        _accessor: &dyn CommitmentAccessor<C>,
+    ) -> Result<C::Scalar, ProofError> {
+        let evaluations = &builder.mle_evaluations;
+        let end = (self.rows.end as usize).min(evaluations.table_length);
+        let start = (self.rows.start as usize).min(end);
+        Ok(evaluations.truncated_one_evaluation(end) - evaluations.truncated_one_evaluation(start))
+    }
+
+    fn get_column_references(&self, _columns: &mut IndexSet<ColumnRef>) {}
+}
@@ -0,0 +1,51 @@
+use crate::{
+    base::{
+        commitment::InnerProductProof,
+        database::{owned_table_utility::*, OwnedTableTestAccessor},
+    },
+    sql::{
+        ast::test_utility::*,
+        proof::{exercise_verification, VerifiableQueryResult},
+    },
+};
+
+#[test]
+fn we_can_prove_a_filter_restricted_to_a_range_of_rows() {
+    let data = owned_table([
+        bigint("a", [1_i64, 5, 5, 2, 5, 5, 3]),
+        bigint("b", [10_i64, 20, 30, 40, 50, 60, 70]),
+    ]);
+    let t = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(t, data, 0, ());
+    let ast = filter(
+        cols_result(t, &["b"], &accessor),
+        tab(t),
+        and(
+            equal(column(t, "a", &accessor), const_bigint(5)),
+            row_range(2..5),
+        ),
+    );
+    let verifiable_res = VerifiableQueryResult::new(&ast, &accessor, &());
+    exercise_verification(&verifiable_res, &ast, &accessor, t);
+    let res = verifiable_res.verify(&ast, &accessor, &()).unwrap().table;
+    assert_eq!(res, owned_table([bigint("b", [30_i64, 50])]));
+}
+
+#[test]
+fn we_can_prove_a_filter_over_row_ranges_at_the_ends_of_the_table() {
+    let data = owned_table([bigint("b", [10_i64, 20, 30, 40, 50])]);
+    let t = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(t, data, 3, ());
+    for (rows, expected) in [
+        (0..2, vec![10_i64, 20]),
+        (3..100, vec![40, 50]),
+        (4..4, vec![]),
+        (0..5, vec![10, 20, 30, 40, 50]),
+    ] {
+        let ast = filter(cols_result(t, &["b"], &accessor), tab(t), row_range(rows));
+        let verifiable_res = VerifiableQueryResult::new(&ast, &accessor, &());
+        exercise_verification(&verifiable_res, &ast, &accessor, t);
+        let res = verifiable_res.verify(&ast, &accessor, &()).unwrap().table;
+        assert_eq!(res, owned_table([bigint("b", expected)]));
+    }
+}
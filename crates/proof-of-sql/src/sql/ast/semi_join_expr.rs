@@ -0,0 +1,697 @@
+use super::{count_sign, dense_filter_exec::verify_filter, verifier_evaluate_sign};
+#[cfg(feature = "prover")]
+use super::{dense_filter_exec::prove_filter, prover_evaluate_sign};
+use super::{ColumnExpr, ProvableExpr, TableExpr};
+use crate::{
+    base::{
+        commitment::Commitment,
+        database::{
+            ColumnField, ColumnRef, ColumnType, CommitmentAccessor, MetadataAccessor, OwnedTable,
+            TableRef,
+        },
+        proof::ProofError,
+        scalar::Scalar,
+    },
+    sql::{
+        parse::{ConversionError, ConversionResult},
+        proof::{CountBuilder, ProofExecutionPlan, VerificationBuilder},
+    },
+};
+#[cfg(feature = "prover")]
+use crate::{
+    base::{
+        database::{filter_util::filter_columns, Column, DataAccessor},
+        slice_ops, MleAllocator,
+    },
+    sql::proof::{Indexes, ProofBuilder, ProverEvaluate, ResultBuilder, SumcheckSubpolynomialType},
+};
+use core::iter::{once, repeat_with};
+use indexmap::IndexSet;
+use num_traits::Inv;
+#[cfg(feature = "prover")]
+use num_traits::One;
+use serde::{Deserialize, Serialize};
+#[cfg(feature = "prover")]
+use std::collections::{BTreeSet, HashMap, HashSet};
+
+/// A value smaller than any key, used as the first node of the sorted chain of inner keys.
+const LOWER_SENTINEL: i128 = i64::MIN as i128 - 1;
+/// A value larger than any key, used as the last node of the sorted chain of inner keys.
+const UPPER_SENTINEL: i128 = i64::MAX as i128 + 1;
+
+/// Provable expressions for queries of the form
+/// ```ignore
+///     SELECT <result_column1>, ..., <result_columnN> FROM <table>
+///     WHERE <column> IN (SELECT <inner_column> FROM <inner_table>)
+/// ```
+///
+/// The result is sent in a dense form, like the [`DenseFilterExec`](super::DenseFilterExec).
+///
+/// Selected rows are shown to be in the inner table with a lookup argument. Rows that are not
+/// selected are shown to lie strictly between two consecutive distinct inner keys. To do this,
+/// the prover commits to the sorted distinct inner keys as a chain of pairs
+/// `(LOWER, k_1), (k_1, k_2), ..., (k_m, UPPER)`: every pair must be increasing, and the
+/// multiset of left ends (plus `UPPER`) must equal the multiset of right ends (plus `LOWER`).
+/// Every inner key is looked up among the left ends, and every excluded outer row is looked up
+/// among the pairs.
+///
+/// Both tables must be committed with the same generator offset.
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct SemiJoinExpr<C: Commitment> {
+    pub(super) results: Vec<ColumnExpr<C>>,
+    pub(super) table: TableExpr,
+    pub(super) column: ColumnExpr<C>,
+    pub(super) inner_column: ColumnExpr<C>,
+}
+
+impl<C: Commitment> SemiJoinExpr<C> {
+    /// Creates a new semi join expression.
+    ///
+    /// The key columns must be integers, and the result columns and `column` must belong to
+    /// `table`.
+    pub fn try_new(
+        results: Vec<ColumnExpr<C>>,
+        table: TableExpr,
+        column: ColumnExpr<C>,
+        inner_column: ColumnExpr<C>,
+    ) -> ConversionResult<Self> {
+        for key in [&column, &inner_column] {
+            match key.data_type() {
+                ColumnType::SmallInt | ColumnType::Int | ColumnType::BigInt => {}
+                data_type => {
+                    return Err(ConversionError::InvalidDataType {
+                        expected: ColumnType::BigInt,
+                        actual: data_type,
+                    })
+                }
+            }
+        }
+        if results
+            .iter()
+            .chain(once(&column))
+            .any(|expr| expr.get_column_reference().table_ref() != table.table_ref)
+        {
+            return Err(ConversionError::InvalidExpression(
+                "semi join columns must belong to the outer table".to_string(),
+            ));
+        }
+        Ok(Self {
+            results,
+            table,
+            column,
+            inner_column,
+        })
+    }
+
+    fn inner_table_ref(&self) -> TableRef {
+        self.inner_column.get_column_reference().table_ref()
+    }
+}
+
+impl<C: Commitment> ProofExecutionPlan<C> for SemiJoinExpr<C> {
+    fn count(
+        &self,
+        builder: &mut CountBuilder,
+        _accessor: &dyn MetadataAccessor,
+    ) -> Result<(), ProofError> {
+        self.column.count(builder)?;
+        self.inner_column.count(builder)?;
+        for expr in self.results.iter() {
+            expr.count(builder)?;
+            builder.count_result_columns(1);
+        }
+        builder.count_post_result_challenges(4);
+        count_membership(builder)?;
+        // filter
+        builder.count_intermediate_mles(2);
+        builder.count_subpolynomials(3);
+        Ok(())
+    }
+
+    fn get_length(&self, accessor: &dyn MetadataAccessor) -> usize {
+        // The chain of inner keys has one more row than the inner table.
+        accessor
+            .get_length(self.table.table_ref)
+            .max(accessor.get_length(self.inner_table_ref()) + 1)
+    }
+
+    fn get_offset(&self, accessor: &dyn MetadataAccessor) -> usize {
+        accessor.get_offset(self.table.table_ref)
+    }
+
+    fn verifier_evaluate(
+        &self,
+        builder: &mut VerificationBuilder<C>,
+        accessor: &dyn CommitmentAccessor<C>,
+        _result: Option<&OwnedTable<C::Scalar>>,
+    ) -> Result<(), ProofError> {
+        if accessor.get_offset(self.inner_table_ref()) != accessor.get_offset(self.table.table_ref)
+        {
+            return Err(ProofError::VerificationError(
+                "semi join tables must share a generator offset",
+            ));
+        }
+        // 1. columns
+        let a = self.column.verifier_evaluate(builder, accessor)?;
+        let b = self.inner_column.verifier_evaluate(builder, accessor)?;
+        let c_evals = self
+            .results
+            .iter()
+            .map(|expr| expr.verifier_evaluate(builder, accessor))
+            .collect::<Result<Vec<_>, _>>()?;
+        // 2. filtered columns
+        let d_evals =
+            Vec::from_iter(repeat_with(|| builder.consume_result_mle()).take(self.results.len()));
+
+        let alpha = builder.consume_post_result_challenge();
+        let beta = builder.consume_post_result_challenge();
+        let gamma = builder.consume_post_result_challenge();
+        let delta = builder.consume_post_result_challenge();
+
+        // 3. witness
+        let s = verifier_evaluate_membership(
+            builder,
+            a,
+            b,
+            accessor.get_length(self.table.table_ref),
+            accessor.get_length(self.inner_table_ref()),
+            gamma,
+            delta,
+        )?;
+
+        verify_filter(builder, alpha, beta, c_evals, s, d_evals)
+    }
+
+    fn get_column_result_fields(&self) -> Vec<ColumnField> {
+        self.results
+            .iter()
+            .map(|expr| expr.get_column_field())
+            .collect()
+    }
+
+    fn get_column_references(&self) -> IndexSet<ColumnRef> {
+        let mut columns = IndexSet::new();
+        for expr in self.results.iter() {
+            expr.get_column_references(&mut columns);
+        }
+        self.column.get_column_references(&mut columns);
+        self.inner_column.get_column_references(&mut columns);
+        columns
+    }
+}
+
+#[cfg(feature = "prover")]
+impl<C: Commitment> ProverEvaluate<C::Scalar> for SemiJoinExpr<C> {
+    #[tracing::instrument(name = "SemiJoinExpr::result_evaluate", level = "debug", skip_all)]
+    fn result_evaluate<'a>(
+        &self,
+        builder: &mut ResultBuilder<'a>,
+        alloc: &'a dyn MleAllocator,
+        accessor: &'a dyn DataAccessor<C::Scalar>,
+    ) {
+        let outer_length = accessor.get_length(self.table.table_ref);
+        let keys = integer_values(&self.column.result_evaluate(outer_length, alloc, accessor));
+        let inner_keys: HashSet<i64> = integer_values(&self.inner_column.result_evaluate(
+            accessor.get_length(self.inner_table_ref()),
+            alloc,
+            accessor,
+        ))
+        .into_iter()
+        .collect();
+        let selection = Vec::from_iter(keys.iter().map(|key| inner_keys.contains(key)));
+
+        let columns = Vec::from_iter(
+            self.results
+                .iter()
+                .map(|expr| expr.result_evaluate(outer_length, alloc, accessor)),
+        );
+        let (filtered_columns, result_len) = filter_columns(alloc, &columns, &selection);
+        builder.set_result_indexes(Indexes::Dense(0..(result_len as u64)));
+        for col in filtered_columns {
+            builder.produce_result_column(col);
+        }
+        builder.request_post_result_challenges(4);
+    }
+
+    #[tracing::instrument(name = "SemiJoinExpr::prover_evaluate", level = "debug", skip_all)]
+    fn prover_evaluate<'a>(
+        &self,
+        builder: &mut ProofBuilder<'a, C::Scalar>,
+        alloc: &'a dyn MleAllocator,
+        accessor: &'a dyn DataAccessor<C::Scalar>,
+    ) {
+        // 1. columns
+        let a_column = self.column.prover_evaluate(builder, alloc, accessor);
+        let b_column = self.inner_column.prover_evaluate(builder, alloc, accessor);
+        let c_columns = Vec::from_iter(
+            self.results
+                .iter()
+                .map(|expr| expr.prover_evaluate(builder, alloc, accessor)),
+        );
+
+        let alpha = builder.consume_post_result_challenge();
+        let beta = builder.consume_post_result_challenge();
+        let gamma = builder.consume_post_result_challenge();
+        let delta = builder.consume_post_result_challenge();
+
+        // 2. witness
+        let outer_length = a_column.len();
+        let s = prove_membership(builder, alloc, a_column, b_column, gamma, delta);
+
+        // 3. filter
+        let (filtered_columns, result_len) = filter_columns(alloc, &c_columns, &s[..outer_length]);
+        prove_filter::<C::Scalar>(
+            builder,
+            alloc,
+            alpha,
+            beta,
+            &c_columns,
+            s,
+            &filtered_columns,
+            result_len,
+        );
+    }
+}
+
+/// Count the argument of [`verifier_evaluate_membership`] and [`prove_membership`].
+pub(super) fn count_membership(builder: &mut CountBuilder) -> Result<(), ProofError> {
+    builder.count_intermediate_mles(16);
+    builder.count_subpolynomials(17);
+    builder.count_degree(3);
+    count_sign(builder)?;
+    count_sign(builder)?;
+    count_sign(builder)?;
+    Ok(())
+}
+
+/// Verify which rows of the outer key column `a` have a key in the inner key column `b`, and
+/// return the evaluation of the selection of those rows.
+///
+/// `gamma` and `delta` must be post result challenges.
+pub(super) fn verifier_evaluate_membership<C: Commitment>(
+    builder: &mut VerificationBuilder<C>,
+    a: C::Scalar,
+    b: C::Scalar,
+    outer_length: usize,
+    inner_length: usize,
+    gamma: C::Scalar,
+    delta: C::Scalar,
+) -> Result<C::Scalar, ProofError> {
+    let one_eval = builder.mle_evaluations.one_evaluation;
+    let rand_eval = builder.mle_evaluations.random_evaluation;
+    let outer_eval = builder
+        .mle_evaluations
+        .truncated_one_evaluation(outer_length);
+    let inner_eval = builder
+        .mle_evaluations
+        .truncated_one_evaluation(inner_length);
+    let first_row_eval = builder.mle_evaluations.truncated_one_evaluation(1);
+
+    let s = builder.consume_intermediate_mle();
+    let lo = builder.consume_intermediate_mle();
+    let hi = builder.consume_intermediate_mle();
+    let p = builder.consume_intermediate_mle();
+    let lo_a = builder.consume_intermediate_mle();
+    let hi_a = builder.consume_intermediate_mle();
+    let w = builder.consume_intermediate_mle();
+    let key_multiplicities = builder.consume_intermediate_mle();
+    let selected_multiplicities = builder.consume_intermediate_mle();
+    let excluded_multiplicities = builder.consume_intermediate_mle();
+    let b_star = builder.consume_intermediate_mle();
+    let lo_star = builder.consume_intermediate_mle();
+    let hi_star = builder.consume_intermediate_mle();
+    let a_star = builder.consume_intermediate_mle();
+    let pair_star = builder.consume_intermediate_mle();
+    let neighbor_star = builder.consume_intermediate_mle();
+
+    // s - s * s = 0
+    builder.produce_sumcheck_subpolynomial_evaluation(&(rand_eval * (s - s * s)));
+    // s - s * chi_outer = 0
+    builder.produce_sumcheck_subpolynomial_evaluation(&(rand_eval * (s - s * outer_eval)));
+    // p - p * p = 0
+    builder.produce_sumcheck_subpolynomial_evaluation(&(rand_eval * (p - p * p)));
+    // w - p * (hi - lo - 1) = 0
+    builder.produce_sumcheck_subpolynomial_evaluation(&(rand_eval * (w - p * hi + p * lo + p)));
+
+    // (gamma + x) * x_star - 1 = 0
+    for (x, x_star) in [(b, b_star), (lo, lo_star), (hi, hi_star), (a, a_star)] {
+        builder.produce_sumcheck_subpolynomial_evaluation(
+            &(rand_eval * ((gamma + x) * x_star - one_eval)),
+        );
+    }
+    builder.produce_sumcheck_subpolynomial_evaluation(
+        &(rand_eval * ((gamma + lo + delta * hi) * pair_star - one_eval)),
+    );
+    builder.produce_sumcheck_subpolynomial_evaluation(
+        &(rand_eval * ((gamma + lo_a + delta * hi_a) * neighbor_star - one_eval)),
+    );
+
+    // sum p * lo_star - p * hi_star + (1 / (gamma + UPPER) - 1 / (gamma + LOWER)) * chi_0 = 0
+    let sentinel_term = sentinel_term(gamma)?;
+    builder.produce_sumcheck_subpolynomial_evaluation(
+        &(p * lo_star - p * hi_star + sentinel_term * first_row_eval),
+    );
+    // sum chi_inner * b_star - key_multiplicities * p * lo_star = 0
+    builder.produce_sumcheck_subpolynomial_evaluation(
+        &(inner_eval * b_star - key_multiplicities * p * lo_star),
+    );
+    // sum s * a_star - selected_multiplicities * chi_inner * b_star = 0
+    builder.produce_sumcheck_subpolynomial_evaluation(
+        &(s * a_star - selected_multiplicities * inner_eval * b_star),
+    );
+    // sum (chi_outer - s) * neighbor_star - excluded_multiplicities * p * pair_star = 0
+    builder.produce_sumcheck_subpolynomial_evaluation(
+        &(outer_eval * neighbor_star - s * neighbor_star - excluded_multiplicities * p * pair_star),
+    );
+
+    // sign(w) == 0, sign(a - lo_a - 1) == 0 and sign(hi_a - a - 1) == 0
+    for eval in [w, a - lo_a - one_eval, hi_a - a - one_eval] {
+        let sign = verifier_evaluate_sign(builder, eval, one_eval)?;
+        builder.produce_sumcheck_subpolynomial_evaluation(&(rand_eval * sign));
+    }
+
+    Ok(s)
+}
+
+/// Prove which rows of the outer key column `a_column` have a key in the inner key column
+/// `b_column`, and return the selection of those rows.
+#[cfg(feature = "prover")]
+pub(super) fn prove_membership<'a, S: Scalar>(
+    builder: &mut ProofBuilder<'a, S>,
+    alloc: &'a dyn MleAllocator,
+    a_column: Column<'a, S>,
+    b_column: Column<'a, S>,
+    gamma: S,
+    delta: S,
+) -> &'a [bool] {
+    let n = builder.table_length();
+    let a = integer_values(&a_column);
+    let b = integer_values(&b_column);
+    let witness = SemiJoinWitness::new(&a, &b, n);
+    let s = alloc.alloc_slice_copy(&witness.selection) as &[_];
+    let lo = alloc.alloc_slice_copy(&witness.lo) as &[_];
+    let hi = alloc.alloc_slice_copy(&witness.hi) as &[_];
+    let p = alloc.alloc_slice_copy(&witness.pair) as &[_];
+    let lo_a = alloc.alloc_slice_copy(&witness.neighbor_lo) as &[_];
+    let hi_a = alloc.alloc_slice_copy(&witness.neighbor_hi) as &[_];
+    let w = alloc.alloc_slice_fill_with(n, |i| {
+        i128::from(witness.pair[i]) * (witness.hi[i] - witness.lo[i] - 1)
+    }) as &[_];
+    let key_multiplicities = alloc.alloc_slice_copy(&witness.key_multiplicities) as &[_];
+    let selected_multiplicities =
+        alloc.alloc_slice_copy(&witness.selected_multiplicities) as &[_];
+    let excluded_multiplicities =
+        alloc.alloc_slice_copy(&witness.excluded_multiplicities) as &[_];
+
+    let key = |values: &[i64], i: usize| values.get(i).copied().unwrap_or(0);
+    let inverted = |f: &dyn Fn(usize) -> S| -> &'a [S] {
+        let res = alloc.alloc_slice_fill_with(n, |i| gamma + f(i));
+        slice_ops::batch_inversion(res);
+        res
+    };
+    let b_star = inverted(&|i| S::from(key(&b, i)));
+    let lo_star = inverted(&|i| S::from(lo[i]));
+    let hi_star = inverted(&|i| S::from(hi[i]));
+    let a_star = inverted(&|i| S::from(key(&a, i)));
+    let pair_star = inverted(&|i| S::from(lo[i]) + delta * S::from(hi[i]));
+    let neighbor_star = inverted(&|i| S::from(lo_a[i]) + delta * S::from(hi_a[i]));
+
+    builder.produce_intermediate_mle(s);
+    builder.produce_intermediate_mle(lo);
+    builder.produce_intermediate_mle(hi);
+    builder.produce_intermediate_mle(p);
+    builder.produce_intermediate_mle(lo_a);
+    builder.produce_intermediate_mle(hi_a);
+    builder.produce_intermediate_mle(w);
+    builder.produce_intermediate_mle(key_multiplicities);
+    builder.produce_intermediate_mle(selected_multiplicities);
+    builder.produce_intermediate_mle(excluded_multiplicities);
+    builder.produce_intermediate_mle(b_star);
+    builder.produce_intermediate_mle(lo_star);
+    builder.produce_intermediate_mle(hi_star);
+    builder.produce_intermediate_mle(a_star);
+    builder.produce_intermediate_mle(pair_star);
+    builder.produce_intermediate_mle(neighbor_star);
+
+    let one = S::one();
+    let chi_outer = alloc.alloc_slice_fill_copy(a.len(), true) as &[_];
+    let chi_inner = alloc.alloc_slice_fill_copy(b.len(), true) as &[_];
+    let chi_first_row = alloc.alloc_slice_fill_copy(1, true) as &[_];
+
+    // s - s * s = 0
+    builder.produce_sumcheck_subpolynomial(
+        SumcheckSubpolynomialType::Identity,
+        vec![
+            (one, vec![Box::new(s)]),
+            (-one, vec![Box::new(s), Box::new(s)]),
+        ],
+    );
+    // s - s * chi_outer = 0
+    builder.produce_sumcheck_subpolynomial(
+        SumcheckSubpolynomialType::Identity,
+        vec![
+            (one, vec![Box::new(s)]),
+            (-one, vec![Box::new(s), Box::new(chi_outer)]),
+        ],
+    );
+    // p - p * p = 0
+    builder.produce_sumcheck_subpolynomial(
+        SumcheckSubpolynomialType::Identity,
+        vec![
+            (one, vec![Box::new(p)]),
+            (-one, vec![Box::new(p), Box::new(p)]),
+        ],
+    );
+    // w - p * (hi - lo - 1) = 0
+    builder.produce_sumcheck_subpolynomial(
+        SumcheckSubpolynomialType::Identity,
+        vec![
+            (one, vec![Box::new(w)]),
+            (-one, vec![Box::new(p), Box::new(hi)]),
+            (one, vec![Box::new(p), Box::new(lo)]),
+            (one, vec![Box::new(p)]),
+        ],
+    );
+
+    // (gamma + x) * x_star - 1 = 0
+    for (x, x_star) in [
+        (b_column, b_star),
+        (Column::Int128(lo), lo_star),
+        (Column::Int128(hi), hi_star),
+        (a_column, a_star),
+    ] {
+        builder.produce_sumcheck_subpolynomial(
+            SumcheckSubpolynomialType::Identity,
+            vec![
+                (gamma, vec![Box::new(x_star)]),
+                (one, vec![Box::new(x), Box::new(x_star)]),
+                (-one, vec![]),
+            ],
+        );
+    }
+    for (x_lo, x_hi, x_star) in [(lo, hi, pair_star), (lo_a, hi_a, neighbor_star)] {
+        builder.produce_sumcheck_subpolynomial(
+            SumcheckSubpolynomialType::Identity,
+            vec![
+                (gamma, vec![Box::new(x_star)]),
+                (one, vec![Box::new(x_lo), Box::new(x_star)]),
+                (delta, vec![Box::new(x_hi), Box::new(x_star)]),
+                (-one, vec![]),
+            ],
+        );
+    }
+
+    // sum p * lo_star - p * hi_star + (1 / (gamma + UPPER) - 1 / (gamma + LOWER)) * chi_0 = 0
+    let sentinel_term = sentinel_term(gamma).expect("challenges should never be degenerate");
+    builder.produce_sumcheck_subpolynomial(
+        SumcheckSubpolynomialType::ZeroSum,
+        vec![
+            (one, vec![Box::new(p), Box::new(lo_star)]),
+            (-one, vec![Box::new(p), Box::new(hi_star)]),
+            (sentinel_term, vec![Box::new(chi_first_row)]),
+        ],
+    );
+    // sum chi_inner * b_star - key_multiplicities * p * lo_star = 0
+    builder.produce_sumcheck_subpolynomial(
+        SumcheckSubpolynomialType::ZeroSum,
+        vec![
+            (one, vec![Box::new(chi_inner), Box::new(b_star)]),
+            (
+                -one,
+                vec![Box::new(key_multiplicities), Box::new(p), Box::new(lo_star)],
+            ),
+        ],
+    );
+    // sum s * a_star - selected_multiplicities * chi_inner * b_star = 0
+    builder.produce_sumcheck_subpolynomial(
+        SumcheckSubpolynomialType::ZeroSum,
+        vec![
+            (one, vec![Box::new(s), Box::new(a_star)]),
+            (
+                -one,
+                vec![
+                    Box::new(selected_multiplicities),
+                    Box::new(chi_inner),
+                    Box::new(b_star),
+                ],
+            ),
+        ],
+    );
+    // sum (chi_outer - s) * neighbor_star - excluded_multiplicities * p * pair_star = 0
+    builder.produce_sumcheck_subpolynomial(
+        SumcheckSubpolynomialType::ZeroSum,
+        vec![
+            (one, vec![Box::new(chi_outer), Box::new(neighbor_star)]),
+            (-one, vec![Box::new(s), Box::new(neighbor_star)]),
+            (
+                -one,
+                vec![
+                    Box::new(excluded_multiplicities),
+                    Box::new(p),
+                    Box::new(pair_star),
+                ],
+            ),
+        ],
+    );
+
+    // sign(w) == 0, sign(a - lo_a - 1) == 0 and sign(hi_a - a - 1) == 0
+    let differences: [&'a [S]; 3] = [
+        alloc.alloc_slice_fill_with(n, |i| S::from(w[i])),
+        alloc.alloc_slice_fill_with(n, |i| S::from(i128::from(key(&a, i)) - lo_a[i] - 1)),
+        alloc.alloc_slice_fill_with(n, |i| S::from(hi_a[i] - i128::from(key(&a, i)) - 1)),
+    ];
+    for difference in differences {
+        let sign = prover_evaluate_sign(
+            builder,
+            alloc,
+            difference,
+            #[cfg(test)]
+            false,
+        );
+        builder.produce_sumcheck_subpolynomial(
+            SumcheckSubpolynomialType::Identity,
+            vec![(one, vec![Box::new(sign)])],
+        );
+    }
+    s
+}
+
+/// `1 / (gamma + UPPER) - 1 / (gamma + LOWER)`, the contribution of the chain's end points.
+fn sentinel_term<S: Scalar>(gamma: S) -> Result<S, ProofError> {
+    let inv = |value: S| {
+        value.inv().ok_or(ProofError::VerificationError(
+            "degenerate semi join challenge",
+        ))
+    };
+    Ok(inv(gamma + S::from(UPPER_SENTINEL))? - inv(gamma + S::from(LOWER_SENTINEL))?)
+}
+
+/// Reads an integer column, such as a key column.
+///
+/// # Panics
+/// Panics if the column is not a `SmallInt`, `Int` or `BigInt` column.
+#[cfg(feature = "prover")]
+pub(super) fn integer_values<S: Scalar>(column: &Column<S>) -> Vec<i64> {
+    match column {
+        Column::SmallInt(col) => col.iter().map(|&v| v.into()).collect(),
+        Column::Int(col) => col.iter().map(|&v| v.into()).collect(),
+        Column::BigInt(col) => col.to_vec(),
+        _ => panic!("Only SmallInt, Int and BigInt columns are supported"),
+    }
+}
+
+/// The columns the prover commits to in order to prove a semi join, each of the table length.
+#[cfg(feature = "prover")]
+struct SemiJoinWitness {
+    /// Whether each outer row is selected
+    selection: Vec<bool>,
+    /// The left ends of the chain of sorted distinct inner keys
+    lo: Vec<i128>,
+    /// The right ends of the chain of sorted distinct inner keys
+    hi: Vec<i128>,
+    /// Whether each row is part of the chain
+    pair: Vec<bool>,
+    /// For each excluded outer row, the left end of the chain pair surrounding its key
+    neighbor_lo: Vec<i128>,
+    /// For each excluded outer row, the right end of the chain pair surrounding its key
+    neighbor_hi: Vec<i128>,
+    /// The number of inner rows with each left end of the chain as key
+    key_multiplicities: Vec<i64>,
+    /// The number of selected outer rows that match each inner row
+    selected_multiplicities: Vec<i64>,
+    /// The number of excluded outer rows that fall in each pair of the chain
+    excluded_multiplicities: Vec<i64>,
+}
+
+#[cfg(feature = "prover")]
+impl SemiJoinWitness {
+    fn new(outer_keys: &[i64], inner_keys: &[i64], n: usize) -> Self {
+        let distinct_keys = Vec::from_iter(inner_keys.iter().copied().collect::<BTreeSet<_>>());
+        let nodes = Vec::from_iter(
+            once(LOWER_SENTINEL)
+                .chain(distinct_keys.iter().map(|&key| key.into()))
+                .chain(once(UPPER_SENTINEL)),
+        );
+        let num_pairs = nodes.len() - 1;
+        assert!(num_pairs <= n);
+
+        let mut lo = vec![0; n];
+        let mut hi = vec![0; n];
+        let mut pair = vec![false; n];
+        lo[..num_pairs].copy_from_slice(&nodes[..num_pairs]);
+        hi[..num_pairs].copy_from_slice(&nodes[1..]);
+        pair[..num_pairs].fill(true);
+
+        let mut key_multiplicities = vec![0; n];
+        for key in inner_keys {
+            let index = distinct_keys
+                .binary_search(key)
+                .expect("key is an inner key");
+            key_multiplicities[index + 1] += 1;
+        }
+
+        let mut first_occurrences = HashMap::with_capacity(distinct_keys.len());
+        for (i, &key) in inner_keys.iter().enumerate() {
+            first_occurrences.entry(key).or_insert(i);
+        }
+
+        let mut selection = vec![false; n];
+        let mut neighbor_lo = vec![0; n];
+        let mut neighbor_hi = vec![0; n];
+        let mut selected_multiplicities = vec![0; n];
+        let mut excluded_multiplicities = vec![0; n];
+        for i in 0..n {
+            let key = outer_keys.get(i).copied();
+            match key.map(|key| (key, first_occurrences.get(&key))) {
+                Some((_, Some(&j))) => {
+                    selection[i] = true;
+                    selected_multiplicities[j] += 1;
+                }
+                Some((key, None)) => {
+                    let k = distinct_keys.partition_point(|&inner_key| inner_key < key);
+                    neighbor_lo[i] = nodes[k];
+                    neighbor_hi[i] = nodes[k + 1];
+                    excluded_multiplicities[k] += 1;
+                    continue;
+                }
+                None => {}
+            }
+            // The neighbors of rows that are selected or padding are unconstrained.
+            let key = i128::from(key.unwrap_or(0));
+            neighbor_lo[i] = key - 1;
+            neighbor_hi[i] = key + 1;
+        }
+
+        Self {
+            selection,
+            lo,
+            hi,
+            pair,
+            neighbor_lo,
+            neighbor_hi,
+            key_multiplicities,
+            selected_multiplicities,
+            excluded_multiplicities,
+        }
+    }
+}
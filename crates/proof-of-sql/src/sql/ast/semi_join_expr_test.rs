@@ -0,0 +1,212 @@
+use super::{test_utility::*, SemiJoinExpr};
+use crate::{
+    base::{
+        commitment::InnerProductProof,
+        database::{owned_table_utility::*, ColumnType, OwnedTableTestAccessor, TestAccessor},
+    },
+    sql::{
+        parse::ConversionError,
+        proof::{exercise_verification, VerifiableQueryResult},
+    },
+};
+use curve25519_dalek::ristretto::RistrettoPoint;
+use rand::{
+    distributions::{Distribution, Uniform},
+    rngs::StdRng,
+};
+use rand_core::SeedableRng;
+
+/// select b from sxt.t where a in (select k from sxt.u)
+#[test]
+fn we_can_prove_a_simple_semi_join() {
+    let t = "sxt.t".parse().unwrap();
+    let u = "sxt.u".parse().unwrap();
+    let mut accessor = OwnedTableTestAccessor::<InnerProductProof>::new_empty_with_setup(());
+    accessor.add_table(
+        t,
+        owned_table([
+            bigint("a", [1, 2, 3, 4, 5, 4]),
+            bigint("b", [10, 20, 30, 40, 50, 60]),
+        ]),
+        0,
+    );
+    accessor.add_table(u, owned_table([bigint("k", [4, 2, 7, 4])]), 0);
+    let expr = semi_join(
+        cols_expr(t, &["b"], &accessor),
+        tab(t),
+        col_expr(t, "a", &accessor),
+        col_expr(u, "k", &accessor),
+    );
+    let res = VerifiableQueryResult::new(&expr, &accessor, &());
+    exercise_verification(&res, &expr, &accessor, t);
+    let res = res.verify(&expr, &accessor, &()).unwrap().table;
+    assert_eq!(res, owned_table([bigint("b", [20, 40, 60])]));
+}
+
+/// select a, b from sxt.t where a in (select k from sxt.u)
+#[test]
+fn we_can_prove_a_semi_join_with_extreme_keys() {
+    let t = "sxt.t".parse().unwrap();
+    let u = "sxt.u".parse().unwrap();
+    let mut accessor = OwnedTableTestAccessor::<InnerProductProof>::new_empty_with_setup(());
+    accessor.add_table(
+        t,
+        owned_table([
+            bigint("a", [i64::MIN, i64::MAX, 0, -1, i64::MIN + 1]),
+            int("b", [1, 2, 3, 4, 5]),
+        ]),
+        0,
+    );
+    accessor.add_table(u, owned_table([int("k", [0, i32::MIN])]), 0);
+    let expr = semi_join(
+        cols_expr(t, &["a", "b"], &accessor),
+        tab(t),
+        col_expr(t, "a", &accessor),
+        col_expr(u, "k", &accessor),
+    );
+    let res = VerifiableQueryResult::new(&expr, &accessor, &());
+    exercise_verification(&res, &expr, &accessor, t);
+    let res = res.verify(&expr, &accessor, &()).unwrap().table;
+    assert_eq!(res, owned_table([bigint("a", [0]), int("b", [3])]));
+}
+
+/// select b from sxt.t where a in (select k from sxt.u)
+#[test]
+fn we_can_prove_a_semi_join_against_an_empty_inner_table() {
+    let t = "sxt.t".parse().unwrap();
+    let u = "sxt.u".parse().unwrap();
+    let mut accessor = OwnedTableTestAccessor::<InnerProductProof>::new_empty_with_setup(());
+    accessor.add_table(
+        t,
+        owned_table([bigint("a", [1, 2, 3]), bigint("b", [10, 20, 30])]),
+        0,
+    );
+    accessor.add_table(u, owned_table([bigint("k", [0; 0])]), 0);
+    let expr = semi_join(
+        cols_expr(t, &["b"], &accessor),
+        tab(t),
+        col_expr(t, "a", &accessor),
+        col_expr(u, "k", &accessor),
+    );
+    let res = VerifiableQueryResult::new(&expr, &accessor, &());
+    exercise_verification(&res, &expr, &accessor, t);
+    let res = res.verify(&expr, &accessor, &()).unwrap().table;
+    assert_eq!(res, owned_table([bigint("b", [0; 0])]));
+}
+
+/// select b from sxt.t where a in (select k from sxt.u)
+#[test]
+fn we_can_prove_a_semi_join_with_an_inner_table_longer_than_the_outer_table() {
+    let t = "sxt.t".parse().unwrap();
+    let u = "sxt.u".parse().unwrap();
+    let mut accessor = OwnedTableTestAccessor::<InnerProductProof>::new_empty_with_setup(());
+    accessor.add_table(
+        t,
+        owned_table([bigint("a", [3, 8]), varchar("b", ["x", "y"])]),
+        0,
+    );
+    accessor.add_table(
+        u,
+        owned_table([smallint("k", [1, 2, 3, 4, 5, 6, 7, 9, 10, 3])]),
+        0,
+    );
+    let expr = semi_join(
+        cols_expr(t, &["b"], &accessor),
+        tab(t),
+        col_expr(t, "a", &accessor),
+        col_expr(u, "k", &accessor),
+    );
+    let res = VerifiableQueryResult::new(&expr, &accessor, &());
+    exercise_verification(&res, &expr, &accessor, t);
+    let res = res.verify(&expr, &accessor, &()).unwrap().table;
+    assert_eq!(res, owned_table([varchar("b", ["x"])]));
+}
+
+#[test]
+fn we_cannot_create_a_semi_join_on_non_integer_keys() {
+    let t = "sxt.t".parse().unwrap();
+    let u = "sxt.u".parse().unwrap();
+    let mut accessor = OwnedTableTestAccessor::<InnerProductProof>::new_empty_with_setup(());
+    accessor.add_table(t, owned_table([varchar("a", ["x"]), bigint("b", [1])]), 0);
+    accessor.add_table(u, owned_table([bigint("k", [1])]), 0);
+    let res = SemiJoinExpr::<RistrettoPoint>::try_new(
+        cols_expr(t, &["b"], &accessor),
+        tab(t),
+        col_expr(t, "a", &accessor),
+        col_expr(u, "k", &accessor),
+    );
+    assert!(matches!(
+        res,
+        Err(ConversionError::InvalidDataType {
+            actual: ColumnType::VarChar,
+            ..
+        })
+    ));
+}
+
+#[test]
+fn we_cannot_verify_a_semi_join_of_tables_with_different_offsets() {
+    let t = "sxt.t".parse().unwrap();
+    let u = "sxt.u".parse().unwrap();
+    let mut accessor = OwnedTableTestAccessor::<InnerProductProof>::new_empty_with_setup(());
+    accessor.add_table(
+        t,
+        owned_table([bigint("a", [1, 2, 3]), bigint("b", [10, 20, 30])]),
+        0,
+    );
+    accessor.add_table(u, owned_table([bigint("k", [2])]), 3);
+    let expr = semi_join(
+        cols_expr(t, &["b"], &accessor),
+        tab(t),
+        col_expr(t, "a", &accessor),
+        col_expr(u, "k", &accessor),
+    );
+    let res = VerifiableQueryResult::new(&expr, &accessor, &());
+    assert!(res.verify(&expr, &accessor, &()).is_err());
+}
+
+fn test_random_tables_with_given_offset(offset: usize) {
+    let dist = Uniform::<i64>::new(-3, 4);
+    let mut rng = StdRng::from_seed([0u8; 32]);
+    for _ in 0..20 {
+        let outer_len = Uniform::new(0, 12).sample(&mut rng);
+        let inner_len = Uniform::new(0, 12).sample(&mut rng);
+        let a = Vec::from_iter(dist.sample_iter(&mut rng).take(outer_len));
+        let b = Vec::from_iter(dist.sample_iter(&mut rng).take(outer_len));
+        let k = Vec::from_iter(dist.sample_iter(&mut rng).take(inner_len));
+
+        let t = "sxt.t".parse().unwrap();
+        let u = "sxt.u".parse().unwrap();
+        let mut accessor = OwnedTableTestAccessor::<InnerProductProof>::new_empty_with_setup(());
+        accessor.add_table(
+            t,
+            owned_table([bigint("a", a.clone()), bigint("b", b.clone())]),
+            offset,
+        );
+        accessor.add_table(u, owned_table([bigint("k", k.clone())]), offset);
+        let expr = semi_join(
+            cols_expr(t, &["a", "b"], &accessor),
+            tab(t),
+            col_expr(t, "a", &accessor),
+            col_expr(u, "k", &accessor),
+        );
+        let res = VerifiableQueryResult::new(&expr, &accessor, &());
+        exercise_verification(&res, &expr, &accessor, t);
+        let res = res.verify(&expr, &accessor, &()).unwrap().table;
+
+        let (expected_a, expected_b): (Vec<i64>, Vec<i64>) =
+            a.into_iter().zip(b).filter(|(a, _)| k.contains(a)).unzip();
+        let expected = owned_table([bigint("a", expected_a), bigint("b", expected_b)]);
+        assert_eq!(res, expected);
+    }
+}
+
+#[test]
+fn we_can_query_random_tables_using_a_zero_offset() {
+    test_random_tables_with_given_offset(0);
+}
+
+#[test]
+fn we_can_query_random_tables_using_a_non_zero_offset() {
+    test_random_tables_with_given_offset(121);
+}
@@ -1,29 +1,44 @@
 use super::{
-    is_within_acceptable_range, verify_constant_abs_decomposition,
+    is_within_acceptable_range, is_within_bit_width, verify_constant_abs_decomposition,
     verify_constant_sign_decomposition,
 };
+#[cfg(feature = "prover")]
 use crate::{
-    base::{
-        bit::{compute_varying_bit_matrix, BitDistribution},
-        commitment::Commitment,
-        proof::ProofError,
-        scalar::Scalar,
-    },
-    sql::proof::{
-        CountBuilder, ProofBuilder, SumcheckSubpolynomialTerm, SumcheckSubpolynomialType,
-        VerificationBuilder,
-    },
+    base::{bit::compute_varying_bit_matrix, MleAllocator},
+    sql::proof::{ProofBuilder, SumcheckSubpolynomialTerm, SumcheckSubpolynomialType},
+};
+use crate::{
+    base::{bit::BitDistribution, commitment::Commitment, proof::ProofError, scalar::Scalar},
+    sql::proof::{CountBuilder, VerificationBuilder},
 };
-use bumpalo::Bump;
 
 /// Count the number of components needed to prove a sign decomposition
 pub fn count_sign(builder: &mut CountBuilder) -> Result<(), ProofError> {
+    count_sign_with_bit_width(builder, None)
+}
+
+/// Count the number of components needed to prove a sign decomposition of values whose absolute
+/// values are declared to fit in `bit_width` bits.
+///
+/// A proof whose bit distribution has a set or varying bit at position `bit_width` or above is
+/// rejected, so the decomposition commits to at most `bit_width` absolute bits and the sign.
+pub fn count_sign_with_bit_width(
+    builder: &mut CountBuilder,
+    bit_width: Option<u8>,
+) -> Result<(), ProofError> {
     let dist = builder.consume_bit_distribution()?;
     if !is_within_acceptable_range(&dist) {
         return Err(ProofError::VerificationError(
             "bit distribution outside of acceptable range",
         ));
     }
+    if let Some(bit_width) = bit_width {
+        if !is_within_bit_width(&dist, bit_width) {
+            return Err(ProofError::VerificationError(
+                "bit distribution wider than the declared bit width",
+            ));
+        }
+    }
     if dist.num_varying_bits() == 0 {
         return Ok(());
     }
@@ -39,9 +54,10 @@ pub fn count_sign(builder: &mut CountBuilder) -> Result<(), ProofError> {
 /// Compute the sign bit for a column of scalars.
 ///
 /// todo! make this more efficient and targeted at just the sign bit rather than all bits to create a proof
+#[cfg(feature = "prover")]
 pub fn result_evaluate_sign<'a, S: Scalar>(
     table_length: usize,
-    alloc: &'a Bump,
+    alloc: &'a dyn MleAllocator,
     expr: &'a [S],
 ) -> &'a [bool] {
     assert_eq!(table_length, expr.len());
@@ -72,9 +88,10 @@ pub fn result_evaluate_sign<'a, S: Scalar>(
 ///
 /// Note: We can only prove the sign bit for non-zero scalars, and we restict
 /// the range of non-zero scalar so that there is a unique sign representation.
+#[cfg(feature = "prover")]
 pub fn prover_evaluate_sign<'a, S: Scalar>(
     builder: &mut ProofBuilder<'a, S>,
-    alloc: &'a Bump,
+    alloc: &'a dyn MleAllocator,
     expr: &'a [S],
     #[cfg(test)] treat_column_of_zeros_as_negative: bool,
 ) -> &'a [bool] {
@@ -162,6 +179,7 @@ fn verifier_const_sign_evaluate<S: Scalar>(
     }
 }
 
+#[cfg(feature = "prover")]
 fn prove_bits_are_binary<'a, S: Scalar>(builder: &mut ProofBuilder<'a, S>, bits: &[&'a [bool]]) {
     for &seq in bits.iter() {
         builder.produce_intermediate_mle(seq);
@@ -186,9 +204,10 @@ fn verify_bits_are_binary<C: Commitment>(
     }
 }
 
+#[cfg(feature = "prover")]
 fn prove_bit_decomposition<'a, S: Scalar>(
     builder: &mut ProofBuilder<'a, S>,
-    alloc: &'a Bump,
+    alloc: &'a dyn MleAllocator,
     expr: &'a [S],
     bits: &[&'a [bool]],
     dist: &BitDistribution,
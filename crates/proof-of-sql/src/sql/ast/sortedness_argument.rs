@@ -0,0 +1,254 @@
+#[cfg(feature = "prover")]
+use super::prover_evaluate_sign;
+use super::{count_sign, verifier_evaluate_sign};
+#[cfg(feature = "prover")]
+use crate::sql::proof::ProofBuilder;
+use crate::{
+    base::{commitment::Commitment, proof::ProofError, scalar::Scalar},
+    sql::proof::{CountBuilder, VerificationBuilder},
+};
+#[cfg(feature = "prover")]
+use crate::{
+    base::{database::Column, slice_ops, MleAllocator},
+    sql::proof::SumcheckSubpolynomialType,
+};
+use num_traits::{One, Zero};
+
+/// Argument that a column spanning the whole table of a plan is sorted in increasing order.
+///
+/// The prover commits to the column shifted up by one row, and shows it is the shift with a
+/// multiset argument over the pairs `(row index, value)`: the verifier evaluates the MLE of the
+/// row indexes itself. Each adjacent pair is then compared with a sign decomposition of
+/// `next - current - 1` when the order is strict, or of `next - current` otherwise, which is
+/// negative exactly for the rows that are out of order.
+///
+/// The argument consumes two post-result challenges, which the plan must request.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SortednessArgument {
+    strict: bool,
+}
+
+impl SortednessArgument {
+    /// An argument that every row is smaller than the next one.
+    pub fn strict() -> Self {
+        Self { strict: true }
+    }
+
+    /// An argument that every row is smaller than or equal to the next one.
+    pub fn non_strict() -> Self {
+        Self { strict: false }
+    }
+
+    /// Whether adjacent rows must differ.
+    pub fn is_strict(&self) -> bool {
+        self.strict
+    }
+
+    /// Count the components needed to show which rows are out of order.
+    ///
+    /// This must be called from the [`count`](crate::sql::proof::ProofExecutionPlan::count) of
+    /// the plan, in the same order as the corresponding
+    /// [`prover_evaluate_out_of_order`](Self::prover_evaluate_out_of_order) and
+    /// [`verifier_evaluate_out_of_order`](Self::verifier_evaluate_out_of_order) calls.
+    pub fn count_out_of_order(&self, builder: &mut CountBuilder) -> Result<(), ProofError> {
+        builder.count_post_result_challenges(2);
+        builder.count_intermediate_mles(3);
+        builder.count_subpolynomials(3);
+        builder.count_degree(3);
+        count_sign(builder)
+    }
+
+    /// Count the components needed to prove that the column is sorted.
+    ///
+    /// This must be called from the [`count`](crate::sql::proof::ProofExecutionPlan::count) of
+    /// the plan, in the same order as the corresponding [`prove`](Self::prove) and
+    /// [`verify`](Self::verify) calls.
+    pub fn count(&self, builder: &mut CountBuilder) -> Result<(), ProofError> {
+        self.count_out_of_order(builder)?;
+        builder.count_subpolynomials(1);
+        Ok(())
+    }
+
+    /// Prove which rows of `column` are out of order, returning an indicator of them.
+    ///
+    /// The indicator is only meaningful for the rows that have a next row, i.e. all but the
+    /// last row of the table. The column must span the whole table of the plan.
+    ///
+    /// # Panics
+    /// Panics if the length of `column` is not the table length of `builder`.
+    #[cfg(feature = "prover")]
+    pub fn prover_evaluate_out_of_order<'a, S: Scalar>(
+        &self,
+        builder: &mut ProofBuilder<'a, S>,
+        alloc: &'a dyn MleAllocator,
+        column: Column<'a, S>,
+    ) -> &'a [bool] {
+        let n = builder.table_length();
+        assert_eq!(column.len(), n, "the column must span the whole table");
+        let one = S::one();
+        let gap = if self.strict { one } else { S::zero() };
+        let a = alloc.alloc_slice_copy(&column.to_scalar_with_scaling(0)) as &[_];
+
+        let gamma = builder.consume_post_result_challenge();
+        let delta = builder.consume_post_result_challenge();
+
+        let next =
+            alloc.alloc_slice_fill_with(n, |i| a.get(i + 1).copied().unwrap_or_default()) as &[_];
+        let index = alloc
+            .alloc_slice_fill_with(1 << builder.num_sumcheck_variables(), |i| S::from(i as i64))
+            as &[_];
+        let a_star = alloc.alloc_slice_fill_with(n, |i| gamma + index[i] + delta * a[i]);
+        slice_ops::batch_inversion(a_star);
+        let next_star =
+            alloc.alloc_slice_fill_with(n, |i| gamma + index[i] + one + delta * next[i]);
+        slice_ops::batch_inversion(next_star);
+        let difference = alloc.alloc_slice_fill_with(n, |i| next[i] - a[i] - gap) as &[_];
+        let chi_table = alloc.alloc_slice_fill_copy(n, true) as &[_];
+        let chi_first_row = alloc.alloc_slice_fill_copy(1, true) as &[_];
+        let chi_pairs = alloc.alloc_slice_fill_copy(n.saturating_sub(1), true) as &[_];
+
+        builder.produce_intermediate_mle(next);
+        builder.produce_intermediate_mle(a_star as &[_]);
+        builder.produce_intermediate_mle(next_star as &[_]);
+
+        // (gamma + index + delta * a) * a_star - 1 = 0
+        builder.produce_sumcheck_subpolynomial(
+            SumcheckSubpolynomialType::Identity,
+            vec![
+                (gamma, vec![Box::new(a_star as &[_])]),
+                (one, vec![Box::new(index), Box::new(a_star as &[_])]),
+                (delta, vec![Box::new(column), Box::new(a_star as &[_])]),
+                (-one, vec![]),
+            ],
+        );
+        // (gamma + index + 1 + delta * next) * next_star - 1 = 0
+        builder.produce_sumcheck_subpolynomial(
+            SumcheckSubpolynomialType::Identity,
+            vec![
+                (gamma + one, vec![Box::new(next_star as &[_])]),
+                (one, vec![Box::new(index), Box::new(next_star as &[_])]),
+                (delta, vec![Box::new(next), Box::new(next_star as &[_])]),
+                (-one, vec![]),
+            ],
+        );
+        // sum (chi_n - chi_0) * a_star - chi_{n-1} * next_star = 0
+        builder.produce_sumcheck_subpolynomial(
+            SumcheckSubpolynomialType::ZeroSum,
+            vec![
+                (one, vec![Box::new(chi_table), Box::new(a_star as &[_])]),
+                (
+                    -one,
+                    vec![Box::new(chi_first_row), Box::new(a_star as &[_])],
+                ),
+                (-one, vec![Box::new(chi_pairs), Box::new(next_star as &[_])]),
+            ],
+        );
+
+        // sign(next - a - gap) == 1 exactly for the rows that are out of order
+        prover_evaluate_sign(
+            builder,
+            alloc,
+            difference,
+            #[cfg(test)]
+            false,
+        )
+    }
+
+    /// Verify which rows of the column evaluating to `column_eval` are out of order, returning
+    /// the evaluation of the indicator of them.
+    ///
+    /// See [`SortednessArgument::prover_evaluate_out_of_order`]. This fails if the proof does
+    /// not contain the components counted by [`SortednessArgument::count_out_of_order`].
+    pub fn verifier_evaluate_out_of_order<C: Commitment>(
+        &self,
+        builder: &mut VerificationBuilder<C>,
+        column_eval: C::Scalar,
+    ) -> Result<C::Scalar, ProofError> {
+        let length = builder.table_length();
+        let one_eval = builder.mle_evaluations.one_evaluation;
+        let rand_eval = builder.mle_evaluations.random_evaluation;
+        let first_row_eval = builder.mle_evaluations.truncated_one_evaluation(1);
+        let pairs_eval = builder
+            .mle_evaluations
+            .truncated_one_evaluation(length.saturating_sub(1));
+        let gap_eval = if self.strict {
+            one_eval
+        } else {
+            C::Scalar::zero()
+        };
+        // The MLE of the row indexes, `sum 2^i * x_i`
+        let index_eval = builder
+            .mle_evaluations
+            .evaluation_point
+            .iter()
+            .rev()
+            .fold(C::Scalar::zero(), |acc, &x| acc + acc + x);
+        let a = column_eval;
+
+        let gamma = builder.consume_post_result_challenge();
+        let delta = builder.consume_post_result_challenge();
+
+        let next = builder.consume_intermediate_mle();
+        let a_star = builder.consume_intermediate_mle();
+        let next_star = builder.consume_intermediate_mle();
+
+        // (gamma + index + delta * a) * a_star - 1 = 0
+        builder.produce_sumcheck_subpolynomial_evaluation(
+            &(rand_eval * ((gamma + index_eval + delta * a) * a_star - one_eval)),
+        );
+        // (gamma + index + 1 + delta * next) * next_star - 1 = 0
+        builder.produce_sumcheck_subpolynomial_evaluation(
+            &(rand_eval
+                * ((gamma + index_eval + C::Scalar::one() + delta * next) * next_star - one_eval)),
+        );
+        // sum (chi_n - chi_0) * a_star - chi_{n-1} * next_star = 0
+        builder.produce_sumcheck_subpolynomial_evaluation(
+            &(one_eval * a_star - first_row_eval * a_star - pairs_eval * next_star),
+        );
+
+        // sign(next - a - gap) == 1 exactly for the rows that are out of order
+        verifier_evaluate_sign(builder, next - a - gap_eval, one_eval)
+    }
+
+    /// Prove that `column` is sorted.
+    ///
+    /// An unsorted column yields a proof that fails to verify. The column must span the whole
+    /// table of the plan.
+    ///
+    /// # Panics
+    /// Panics if the length of `column` is not the table length of `builder`.
+    #[cfg(feature = "prover")]
+    pub fn prove<'a, S: Scalar>(
+        &self,
+        builder: &mut ProofBuilder<'a, S>,
+        alloc: &'a dyn MleAllocator,
+        column: Column<'a, S>,
+    ) {
+        let n = builder.table_length();
+        let out_of_order = self.prover_evaluate_out_of_order(builder, alloc, column);
+        let chi_pairs = alloc.alloc_slice_fill_copy(n.saturating_sub(1), true) as &[_];
+        // sum chi_{n-1} * out_of_order = 0
+        builder.produce_sumcheck_subpolynomial(
+            SumcheckSubpolynomialType::ZeroSum,
+            vec![(S::one(), vec![Box::new(chi_pairs), Box::new(out_of_order)])],
+        );
+    }
+
+    /// Verify that the column evaluating to `column_eval` is sorted.
+    ///
+    /// See [`SortednessArgument::prove`]. The sumcheck proof fails to verify afterwards if the
+    /// column is not sorted.
+    pub fn verify<C: Commitment>(
+        &self,
+        builder: &mut VerificationBuilder<C>,
+        column_eval: C::Scalar,
+    ) -> Result<(), ProofError> {
+        let pairs_eval = builder
+            .mle_evaluations
+            .truncated_one_evaluation(builder.table_length().saturating_sub(1));
+        let out_of_order = self.verifier_evaluate_out_of_order(builder, column_eval)?;
+        // sum chi_{n-1} * out_of_order = 0
+        builder.produce_sumcheck_subpolynomial_evaluation(&(pairs_eval * out_of_order));
+        Ok(())
+    }
+}
@@ -0,0 +1,145 @@
+use super::SortednessArgument;
+use crate::{
+    base::{
+        commitment::{Commitment, InnerProductProof},
+        database::{
+            owned_table_utility::{bigint, owned_table},
+            ColumnField, ColumnRef, ColumnType, CommitmentAccessor, DataAccessor, MetadataAccessor,
+            OwnedTable, OwnedTableTestAccessor,
+        },
+        proof::ProofError,
+        scalar::Scalar,
+        MleAllocator,
+    },
+    sql::proof::{
+        CountBuilder, Indexes, ProofBuilder, ProofExecutionPlan, ProverEvaluate, QueryProof,
+        ResultBuilder, VerificationBuilder,
+    },
+};
+use indexmap::IndexSet;
+use serde::Serialize;
+
+/// Returns `sxt.test.x` and proves it is sorted
+#[derive(Debug, Serialize)]
+struct SortedTestProofExecutionPlan {
+    strict: bool,
+}
+impl SortedTestProofExecutionPlan {
+    fn argument(&self) -> SortednessArgument {
+        if self.strict {
+            SortednessArgument::strict()
+        } else {
+            SortednessArgument::non_strict()
+        }
+    }
+    fn column() -> ColumnRef {
+        ColumnRef::new(
+            "sxt.test".parse().unwrap(),
+            "x".parse().unwrap(),
+            ColumnType::BigInt,
+        )
+    }
+}
+impl<S: Scalar> ProverEvaluate<S> for SortedTestProofExecutionPlan {
+    fn result_evaluate<'a>(
+        &self,
+        builder: &mut ResultBuilder<'a>,
+        _alloc: &'a dyn MleAllocator,
+        accessor: &'a dyn DataAccessor<S>,
+    ) {
+        let x = accessor.get_column(Self::column());
+        builder.set_result_indexes(Indexes::Dense(0..x.len() as u64));
+        builder.produce_result_column(x);
+        builder.request_post_result_challenges(2);
+    }
+
+    fn prover_evaluate<'a>(
+        &self,
+        builder: &mut ProofBuilder<'a, S>,
+        alloc: &'a dyn MleAllocator,
+        accessor: &'a dyn DataAccessor<S>,
+    ) {
+        let x = accessor.get_column(Self::column());
+        builder.produce_column_mle(Self::column(), x.clone());
+        self.argument().prove(builder, alloc, x);
+    }
+}
+impl<C: Commitment> ProofExecutionPlan<C> for SortedTestProofExecutionPlan {
+    fn count(
+        &self,
+        builder: &mut CountBuilder,
+        _accessor: &dyn MetadataAccessor,
+    ) -> Result<(), ProofError> {
+        builder.count_result_columns(1);
+        builder.count_anchored_mles(1);
+        self.argument().count(builder)
+    }
+    fn get_length(&self, accessor: &dyn MetadataAccessor) -> usize {
+        accessor.get_length("sxt.test".parse().unwrap())
+    }
+    fn get_offset(&self, accessor: &dyn MetadataAccessor) -> usize {
+        accessor.get_offset("sxt.test".parse().unwrap())
+    }
+    fn verifier_evaluate(
+        &self,
+        builder: &mut VerificationBuilder<C>,
+        accessor: &dyn CommitmentAccessor<C>,
+        _result: Option<&OwnedTable<C::Scalar>>,
+    ) -> Result<(), ProofError> {
+        let x_eval =
+            builder.consume_column_mle(Self::column(), accessor.get_commitment(Self::column()));
+        builder.consume_result_mle();
+        self.argument().verify(builder, x_eval)
+    }
+    fn get_column_result_fields(&self) -> Vec<ColumnField> {
+        vec![ColumnField::new("x".parse().unwrap(), ColumnType::BigInt)]
+    }
+    fn get_column_references(&self) -> IndexSet<ColumnRef> {
+        IndexSet::from_iter([Self::column()])
+    }
+}
+
+/// Prove that `x` is sorted, returning whether the proof verifies
+fn prove_sorted(x: Vec<i64>, strict: bool) -> bool {
+    let expr = SortedTestProofExecutionPlan { strict };
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(
+        "sxt.test".parse().unwrap(),
+        owned_table([bigint("x", x)]),
+        0,
+        (),
+    );
+    let (proof, result) = QueryProof::<InnerProductProof>::new(&expr, &accessor, &());
+    proof.verify(&expr, &accessor, &result, &()).is_ok()
+}
+
+#[test]
+fn we_can_prove_a_strictly_increasing_column_is_sorted() {
+    assert!(prove_sorted(vec![1, 3, 4, 10, 11, 200, 201], true));
+    assert!(prove_sorted(vec![i64::MIN, -1, 0, i64::MAX], true));
+    assert!(prove_sorted(vec![42], true));
+}
+
+#[test]
+fn we_cannot_prove_an_unsorted_column_is_strictly_sorted() {
+    assert!(!prove_sorted(vec![1, 4, 3, 10, 11, 200, 201], true));
+    assert!(!prove_sorted(vec![201, 200, 11, 10, 4, 3, 1], true));
+}
+
+#[test]
+fn we_cannot_prove_a_column_with_a_repeated_value_is_strictly_sorted() {
+    assert!(!prove_sorted(vec![1, 2, 2, 3], true));
+}
+
+#[test]
+fn we_can_prove_a_non_decreasing_column_is_sorted() {
+    assert!(prove_sorted(vec![1, 2, 2, 3, 3, 3, 10], false));
+    assert!(prove_sorted(vec![i64::MIN, i64::MIN, 0, i64::MAX], false));
+    assert!(prove_sorted(vec![5, 5, 5], false));
+    assert!(prove_sorted(vec![42], false));
+}
+
+#[test]
+fn we_cannot_prove_an_unsorted_column_is_sorted() {
+    assert!(!prove_sorted(vec![1, 2, 2, 1], false));
+    assert!(!prove_sorted(vec![201, 200, 11, 10, 4, 3, 1], false));
+}
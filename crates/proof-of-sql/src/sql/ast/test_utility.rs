@@ -1,10 +1,12 @@
 use super::{
-    AliasedProvableExprPlan, ColumnExpr, DenseFilterExec, FilterExec, FilterResultExpr,
-    GroupByExec, ProjectionExec, ProofPlan, ProvableExprPlan, TableExpr,
+    AliasedProvableExprPlan, AvgExpr, ColumnExpr, CountDistinctExpr, CumulativeSumExpr,
+    DenseFilterExec, ExceptExpr, ExistsExpr, FilterExec, FilterResultExpr,
+    GroupByCountDistinctExpr, GroupByExec, HavingExpr, MaxExpr, MinExpr, MonotonicExpr,
+    ProjectionExec, ProofPlan, ProvableExprPlan, RowCountEqualsExpr, SemiJoinExpr, TableExpr,
 };
 use crate::base::{
     commitment::Commitment,
-    database::{ColumnRef, LiteralValue, SchemaAccessor, TableRef},
+    database::{ColumnRef, ColumnType, LiteralValue, SchemaAccessor, TableRef},
     math::decimal::Precision,
 };
 use proof_of_sql_parser::intermediate_ast::AggregationOperator;
@@ -32,6 +34,13 @@ pub fn equal<C: Commitment>(
     ProvableExprPlan::try_new_equals(left, right).unwrap()
 }
 
+pub fn equal_ignore_case<C: Commitment>(
+    expr: ProvableExprPlan<C>,
+    value: &str,
+) -> ProvableExprPlan<C> {
+    ProvableExprPlan::try_new_equals_ignore_case(expr, value).unwrap()
+}
+
 pub fn lte<C: Commitment>(
     left: ProvableExprPlan<C>,
     right: ProvableExprPlan<C>,
@@ -57,6 +66,10 @@ pub fn and<C: Commitment>(
     ProvableExprPlan::try_new_and(left, right).unwrap()
 }
 
+pub fn row_range<C: Commitment>(rows: core::ops::Range<u64>) -> ProvableExprPlan<C> {
+    ProvableExprPlan::new_row_range(rows)
+}
+
 pub fn or<C: Commitment>(
     left: ProvableExprPlan<C>,
     right: ProvableExprPlan<C>,
@@ -64,6 +77,24 @@ pub fn or<C: Commitment>(
     ProvableExprPlan::try_new_or(left, right).unwrap()
 }
 
+pub fn complement<C: Commitment>(expr: ProvableExprPlan<C>) -> ProvableExprPlan<C> {
+    ProvableExprPlan::try_new_complement(expr).unwrap()
+}
+
+pub fn xor<C: Commitment>(
+    left: ProvableExprPlan<C>,
+    right: ProvableExprPlan<C>,
+) -> ProvableExprPlan<C> {
+    ProvableExprPlan::try_new_xor(left, right).unwrap()
+}
+
+pub fn nand<C: Commitment>(
+    left: ProvableExprPlan<C>,
+    right: ProvableExprPlan<C>,
+) -> ProvableExprPlan<C> {
+    ProvableExprPlan::try_new_nand(left, right).unwrap()
+}
+
 pub fn add<C: Commitment>(
     left: ProvableExprPlan<C>,
     right: ProvableExprPlan<C>,
@@ -85,6 +116,22 @@ pub fn multiply<C: Commitment>(
     ProvableExprPlan::try_new_multiply(left, right).unwrap()
 }
 
+pub fn divide<C: Commitment>(lhs: ProvableExprPlan<C>, divisor: i64) -> ProvableExprPlan<C> {
+    ProvableExprPlan::try_new_divide(lhs, divisor).unwrap()
+}
+
+pub fn cast<C: Commitment>(expr: ProvableExprPlan<C>, to_type: ColumnType) -> ProvableExprPlan<C> {
+    ProvableExprPlan::try_new_cast(expr, to_type).unwrap()
+}
+
+pub fn case_when<C: Commitment>(
+    predicate: ProvableExprPlan<C>,
+    then_expr: ProvableExprPlan<C>,
+    else_expr: ProvableExprPlan<C>,
+) -> ProvableExprPlan<C> {
+    ProvableExprPlan::try_new_case(predicate, then_expr, else_expr).unwrap()
+}
+
 pub fn const_bool<C: Commitment>(val: bool) -> ProvableExprPlan<C> {
     ProvableExprPlan::new_literal(LiteralValue::Boolean(val))
 }
@@ -134,15 +181,26 @@ pub fn tab(tab: TableRef) -> TableExpr {
     TableExpr { table_ref: tab }
 }
 
-pub fn col_result(tab: TableRef, name: &str, accessor: &impl SchemaAccessor) -> FilterResultExpr {
+pub fn col_result<C: Commitment>(
+    tab: TableRef,
+    name: &str,
+    accessor: &impl SchemaAccessor,
+) -> FilterResultExpr<C> {
     FilterResultExpr::new(col_ref(tab, name, accessor))
 }
 
-pub fn cols_result(
+pub fn aliased_result<C: Commitment>(
+    expr: ProvableExprPlan<C>,
+    alias: &str,
+) -> FilterResultExpr<C> {
+    FilterResultExpr::try_new(expr, Some(alias.parse().unwrap())).unwrap()
+}
+
+pub fn cols_result<C: Commitment>(
     tab: TableRef,
     names: &[&str],
     accessor: &impl SchemaAccessor,
-) -> Vec<FilterResultExpr> {
+) -> Vec<FilterResultExpr<C>> {
     names
         .iter()
         .map(|name| col_result(tab, name, accessor))
@@ -150,7 +208,7 @@ pub fn cols_result(
 }
 
 pub fn filter<C: Commitment>(
-    results: Vec<FilterResultExpr>,
+    results: Vec<FilterResultExpr<C>>,
     table: TableExpr,
     where_clause: ProvableExprPlan<C>,
 ) -> ProofPlan<C> {
@@ -271,3 +329,137 @@ pub fn group_by<C: Commitment>(
         where_clause,
     ))
 }
+
+pub fn exists<C: Commitment>(
+    table: TableExpr,
+    where_clause: ProvableExprPlan<C>,
+    alias: &str,
+) -> ProofPlan<C> {
+    ProofPlan::Exists(ExistsExpr::new(where_clause, table, alias.parse().unwrap()))
+}
+
+pub fn row_count_equals<C: Commitment>(
+    table: TableExpr,
+    other_table: TableExpr,
+    alias: &str,
+) -> ProofPlan<C> {
+    ProofPlan::RowCountEquals(RowCountEqualsExpr::new(
+        table,
+        other_table,
+        alias.parse().unwrap(),
+    ))
+}
+
+pub fn min_expr<C: Commitment>(
+    expr: ProvableExprPlan<C>,
+    alias: &str,
+    table: TableExpr,
+    where_clause: ProvableExprPlan<C>,
+) -> ProofPlan<C> {
+    ProofPlan::Min(MinExpr::try_new(expr, alias.parse().unwrap(), table, where_clause).unwrap())
+}
+
+pub fn max_expr<C: Commitment>(
+    expr: ProvableExprPlan<C>,
+    alias: &str,
+    table: TableExpr,
+    where_clause: ProvableExprPlan<C>,
+) -> ProofPlan<C> {
+    ProofPlan::Max(MaxExpr::try_new(expr, alias.parse().unwrap(), table, where_clause).unwrap())
+}
+
+pub fn semi_join<C: Commitment>(
+    results: Vec<ColumnExpr<C>>,
+    table: TableExpr,
+    column: ColumnExpr<C>,
+    inner_column: ColumnExpr<C>,
+) -> ProofPlan<C> {
+    ProofPlan::SemiJoin(SemiJoinExpr::try_new(results, table, column, inner_column).unwrap())
+}
+
+pub fn except<C: Commitment>(
+    column: ColumnExpr<C>,
+    table: TableExpr,
+    except_column: ColumnExpr<C>,
+) -> ProofPlan<C> {
+    ProofPlan::Except(ExceptExpr::try_new(column, table, except_column).unwrap())
+}
+
+pub fn monotonic<C: Commitment>(
+    column: ColumnExpr<C>,
+    table: TableExpr,
+    alias: &str,
+) -> ProofPlan<C> {
+    ProofPlan::Monotonic(MonotonicExpr::try_new(column, table, alias.parse().unwrap()).unwrap())
+}
+
+pub fn cumulative_sum<C: Commitment>(
+    column: ColumnExpr<C>,
+    table: TableExpr,
+    alias: &str,
+) -> ProofPlan<C> {
+    ProofPlan::CumulativeSum(
+        CumulativeSumExpr::try_new(column, table, alias.parse().unwrap()).unwrap(),
+    )
+}
+
+pub fn count_distinct<C: Commitment>(
+    expr: ProvableExprPlan<C>,
+    alias: &str,
+    table: TableExpr,
+    where_clause: ProvableExprPlan<C>,
+) -> ProofPlan<C> {
+    ProofPlan::CountDistinct(
+        CountDistinctExpr::try_new(expr, alias.parse().unwrap(), table, where_clause).unwrap(),
+    )
+}
+
+pub fn group_by_count_distinct<C: Commitment>(
+    group_by_expr: ColumnExpr<C>,
+    expr: ProvableExprPlan<C>,
+    alias: &str,
+    table: TableExpr,
+    where_clause: ProvableExprPlan<C>,
+) -> ProofPlan<C> {
+    ProofPlan::GroupByCountDistinct(
+        GroupByCountDistinctExpr::try_new(
+            group_by_expr,
+            expr,
+            alias.parse().unwrap(),
+            table,
+            where_clause,
+        )
+        .unwrap(),
+    )
+}
+
+pub fn avg<C: Commitment>(
+    expr: ProvableExprPlan<C>,
+    alias: &str,
+    table: TableExpr,
+    where_clause: ProvableExprPlan<C>,
+) -> ProofPlan<C> {
+    ProofPlan::Avg(AvgExpr::try_new(expr, alias.parse().unwrap(), table, where_clause).unwrap())
+}
+
+pub fn having<C: Commitment>(
+    group_by_exprs: Vec<ColumnExpr<C>>,
+    count_alias: &str,
+    table: TableExpr,
+    where_clause: ProvableExprPlan<C>,
+    threshold: i64,
+) -> ProofPlan<C> {
+    ProofPlan::Having(
+        HavingExpr::try_new(
+            GroupByExec::new(
+                group_by_exprs,
+                vec![],
+                count_alias.parse().unwrap(),
+                table,
+                where_clause,
+            ),
+            threshold,
+        )
+        .unwrap(),
+    )
+}
@@ -0,0 +1,267 @@
+use super::{DenseFilterExec, FilterExec, GroupByExec, ProofPlan, ProvableExprPlan, TableExpr};
+use crate::{
+    base::{
+        commitment::Commitment,
+        database::{
+            Column, ColumnField, ColumnRef, ColumnType, CommitmentAccessor, DataAccessor,
+            MetadataAccessor, SchemaAccessor, TableRef,
+        },
+    },
+    sql::{parse::ConversionError, proof::ProofExecutionPlan},
+};
+use indexmap::IndexMap;
+use proof_of_sql_parser::Identifier;
+use thiserror::Error;
+
+/// Errors from defining or inlining views.
+#[derive(Error, Debug, PartialEq, Eq)]
+pub enum ViewError {
+    /// The plan of a view must be a filter whose results are plain columns.
+    #[error("view {0} must be a filter selecting plain columns")]
+    UnsupportedView(TableRef),
+    /// The plan of a view reads from the view itself, possibly through other views.
+    #[error("view {0} refers to itself")]
+    RecursiveView(TableRef),
+    /// Only filters and group bys can read from a view.
+    #[error("a plan of this kind cannot read from a view")]
+    UnsupportedPlan,
+    /// The where clause of a view could not be combined with the where clause of the query.
+    #[error(transparent)]
+    ConversionError(#[from] ConversionError),
+}
+
+/// A saved filter, split into the parts that are inlined into the queries reading from it
+#[derive(Debug)]
+struct View<C: Commitment> {
+    table: TableRef,
+    /// The column each output of the view selects, by output name
+    columns: IndexMap<Identifier, ColumnRef>,
+    where_clause: ProvableExprPlan<C>,
+}
+
+/// An accessor that adds views, defined as saved plans, to the tables of another accessor.
+///
+/// A view is a filter over a table, or over another view, that selects plain columns. A query
+/// reads from a view as from a table: the view's columns can be looked up with
+/// [`SchemaAccessor`], and are accessed as the columns of the table they select.
+///
+/// Before a plan reading from views is proved, [`ViewAccessor::inline`] replaces each view it
+/// reads from by the view's table, and adds the view's where clause to the plan's where clause.
+/// The inlined plan must then be proved and verified with a `ViewAccessor` defining the same
+/// views, since it still refers to the columns of the views.
+pub struct ViewAccessor<'a, C: Commitment, A: ?Sized> {
+    inner: &'a A,
+    views: IndexMap<TableRef, View<C>>,
+}
+
+impl<'a, C: Commitment, A: ?Sized> ViewAccessor<'a, C, A> {
+    /// Create an accessor with no views over the tables of `inner`.
+    pub fn new(inner: &'a A) -> Self {
+        Self {
+            inner,
+            views: IndexMap::new(),
+        }
+    }
+
+    /// Define the view `name` as the result of `plan`, replacing any view of the same name.
+    ///
+    /// `plan` must be a filter whose results are plain columns, and must not read from `name`,
+    /// either directly or through other views.
+    pub fn define_view(&mut self, name: TableRef, plan: ProofPlan<C>) -> Result<(), ViewError> {
+        let (table, results, where_clause) = match plan {
+            ProofPlan::Filter(filter) => (
+                filter.table.table_ref,
+                filter
+                    .results
+                    .iter()
+                    .map(|result| (result.get_column_field().name(), result.get_expr().clone()))
+                    .collect::<Vec<_>>(),
+                filter.where_clause,
+            ),
+            ProofPlan::DenseFilter(filter) => (
+                filter.table.table_ref,
+                filter
+                    .aliased_results
+                    .into_iter()
+                    .map(|result| (result.alias, result.expr))
+                    .collect(),
+                filter.where_clause,
+            ),
+            _ => return Err(ViewError::UnsupportedView(name)),
+        };
+        let columns = results
+            .into_iter()
+            .map(|(alias, expr)| match expr {
+                ProvableExprPlan::Column(column) => Ok((alias, column.get_column_reference())),
+                _ => Err(ViewError::UnsupportedView(name)),
+            })
+            .collect::<Result<_, _>>()?;
+        // The existing views never form a cycle, so a cycle through the new view must lead
+        // back to it.
+        let mut source = table;
+        while source != name {
+            match self.views.get(&source) {
+                Some(view) => source = view.table,
+                None => break,
+            }
+        }
+        if source == name {
+            return Err(ViewError::RecursiveView(name));
+        }
+        self.views.insert(
+            name,
+            View {
+                table,
+                columns,
+                where_clause,
+            },
+        );
+        Ok(())
+    }
+
+    /// Replace the views `plan` reads from by their tables, adding their where clauses to the
+    /// where clause of `plan`.
+    ///
+    /// Plans that don't read from a view are returned unchanged. Only filters and group bys can
+    /// read from a view.
+    pub fn inline(&self, plan: ProofPlan<C>) -> Result<ProofPlan<C>, ViewError> {
+        Ok(match plan {
+            ProofPlan::Filter(filter) => {
+                let (table, where_clause) =
+                    self.inline_table(filter.table.table_ref, filter.where_clause)?;
+                ProofPlan::Filter(FilterExec::new(filter.results, table, where_clause))
+            }
+            ProofPlan::DenseFilter(filter) => {
+                let (table, where_clause) =
+                    self.inline_table(filter.table.table_ref, filter.where_clause)?;
+                ProofPlan::DenseFilter(DenseFilterExec::new(
+                    filter.aliased_results,
+                    table,
+                    where_clause,
+                ))
+            }
+            ProofPlan::GroupBy(group_by) => {
+                let (table, where_clause) =
+                    self.inline_table(group_by.table.table_ref, group_by.where_clause)?;
+                ProofPlan::GroupBy(GroupByExec::new(
+                    group_by.group_by_exprs,
+                    group_by.sum_expr,
+                    group_by.count_alias,
+                    table,
+                    where_clause,
+                ))
+            }
+            plan => {
+                if plan
+                    .get_column_references()
+                    .iter()
+                    .any(|column| self.views.contains_key(&column.table_ref()))
+                {
+                    return Err(ViewError::UnsupportedPlan);
+                }
+                plan
+            }
+        })
+    }
+
+    fn inline_table(
+        &self,
+        mut table_ref: TableRef,
+        mut where_clause: ProvableExprPlan<C>,
+    ) -> Result<(TableExpr, ProvableExprPlan<C>), ViewError> {
+        while let Some(view) = self.views.get(&table_ref) {
+            where_clause = ProvableExprPlan::try_new_and(view.where_clause.clone(), where_clause)?;
+            table_ref = view.table;
+        }
+        Ok((TableExpr { table_ref }, where_clause))
+    }
+
+    /// The table that `table_ref` reads from, following views to the table they select from
+    fn resolve_table(&self, mut table_ref: TableRef) -> TableRef {
+        while let Some(view) = self.views.get(&table_ref) {
+            table_ref = view.table;
+        }
+        table_ref
+    }
+
+    /// The column of a table that `column` selects, following views to the column they select
+    fn resolve_column(&self, mut column: ColumnRef) -> ColumnRef {
+        while let Some(view) = self.views.get(&column.table_ref()) {
+            match view.columns.get(&column.column_id()) {
+                Some(selected) => column = *selected,
+                None => break,
+            }
+        }
+        column
+    }
+
+    fn view_schema(view: &View<C>) -> impl Iterator<Item = (Identifier, ColumnType)> + '_ {
+        view.columns
+            .iter()
+            .map(|(&name, column)| (name, *column.column_type()))
+    }
+}
+
+impl<C: Commitment, A: MetadataAccessor + ?Sized> MetadataAccessor for ViewAccessor<'_, C, A> {
+    fn get_length(&self, table_ref: TableRef) -> usize {
+        self.inner.get_length(self.resolve_table(table_ref))
+    }
+
+    fn get_offset(&self, table_ref: TableRef) -> usize {
+        self.inner.get_offset(self.resolve_table(table_ref))
+    }
+
+    fn schema(&self) -> Vec<(TableRef, Vec<ColumnField>)> {
+        let mut schema = self.inner.schema();
+        schema.extend(self.views.iter().map(|(&name, view)| {
+            let fields = Self::view_schema(view)
+                .map(|(column, column_type)| ColumnField::new(column, column_type))
+                .collect();
+            (name, fields)
+        }));
+        schema
+    }
+
+    fn column_bounds(&self, column: ColumnRef) -> Option<(i64, i64)> {
+        self.inner.column_bounds(self.resolve_column(column))
+    }
+
+    fn column_epoch(&self, column: ColumnRef) -> Option<u64> {
+        self.inner.column_epoch(self.resolve_column(column))
+    }
+}
+
+impl<C: Commitment, A: DataAccessor<C::Scalar> + ?Sized> DataAccessor<C::Scalar>
+    for ViewAccessor<'_, C, A>
+{
+    fn get_column(&self, column: ColumnRef) -> Column<C::Scalar> {
+        self.inner.get_column(self.resolve_column(column))
+    }
+}
+
+impl<C: Commitment, A: CommitmentAccessor<C> + ?Sized> CommitmentAccessor<C>
+    for ViewAccessor<'_, C, A>
+{
+    fn get_commitment(&self, column: ColumnRef) -> C {
+        self.inner.get_commitment(self.resolve_column(column))
+    }
+}
+
+impl<C: Commitment, A: SchemaAccessor + ?Sized> SchemaAccessor for ViewAccessor<'_, C, A> {
+    fn lookup_column(&self, table_ref: TableRef, column_id: Identifier) -> Option<ColumnType> {
+        match self.views.get(&table_ref) {
+            Some(view) => view
+                .columns
+                .get(&column_id)
+                .map(|column| *column.column_type()),
+            None => self.inner.lookup_column(table_ref, column_id),
+        }
+    }
+
+    fn lookup_schema(&self, table_ref: TableRef) -> Vec<(Identifier, ColumnType)> {
+        match self.views.get(&table_ref) {
+            Some(view) => Self::view_schema(view).collect(),
+            None => self.inner.lookup_schema(table_ref),
+        }
+    }
+}
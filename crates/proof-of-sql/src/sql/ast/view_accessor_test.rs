@@ -0,0 +1,133 @@
+use super::{test_utility::*, ProofPlan, ViewAccessor, ViewError};
+use crate::{
+    base::{
+        commitment::InnerProductProof,
+        database::{owned_table_utility::*, OwnedTableTestAccessor, TableRef},
+    },
+    sql::proof::VerifiableQueryResult,
+};
+use curve25519_dalek::ristretto::RistrettoPoint;
+
+fn accessor() -> OwnedTableTestAccessor<InnerProductProof> {
+    let data = owned_table([
+        bigint("a", [1_i64, 2, 2, 1, 2, 3]),
+        bigint("b", [99_i64, 99, 0, 99, 99, 99]),
+        bigint("c", [10_i64, 20, 30, 40, 50, 60]),
+    ]);
+    OwnedTableTestAccessor::new_from_table("sxt.t".parse().unwrap(), data, 0, ())
+}
+
+/// select a as x, c from sxt.t where b = 99
+fn filter_view(accessor: &OwnedTableTestAccessor<InnerProductProof>) -> ProofPlan<RistrettoPoint> {
+    let t = "sxt.t".parse().unwrap();
+    dense_filter(
+        vec![
+            aliased_col_expr_plan(t, "a", "x", accessor),
+            aliased_col_expr_plan(t, "c", "c", accessor),
+        ],
+        tab(t),
+        equal(column(t, "b", accessor), const_bigint(99)),
+    )
+}
+
+/// select x, count(*) as __count__ from sxt.v where c >= 20 group by x
+#[test]
+fn we_can_prove_a_count_aggregation_over_a_filter_view() {
+    let accessor = accessor();
+    let v: TableRef = "sxt.v".parse().unwrap();
+    let mut views = ViewAccessor::new(&accessor);
+    views.define_view(v, filter_view(&accessor)).unwrap();
+    let expr = group_by(
+        cols_expr(v, &["x"], &views),
+        vec![],
+        "__count__",
+        tab(v),
+        gte(column(v, "c", &views), const_bigint(20)),
+    );
+    let expr = views.inline(expr).unwrap();
+    let res = VerifiableQueryResult::new(&expr, &views, &());
+    let res = res.verify(&expr, &views, &()).unwrap().table;
+    let expected = owned_table([
+        bigint("x", [1_i64, 2, 3]),
+        bigint("__count__", [1_i64, 2, 1]),
+    ]);
+    assert_eq!(res, expected);
+}
+
+#[test]
+fn we_can_prove_a_query_over_a_view_of_a_view() {
+    let accessor = accessor();
+    let v: TableRef = "sxt.v".parse().unwrap();
+    let w: TableRef = "sxt.w".parse().unwrap();
+    let mut views = ViewAccessor::new(&accessor);
+    views.define_view(v, filter_view(&accessor)).unwrap();
+    let view = dense_filter(
+        cols_expr_plan(v, &["x"], &views),
+        tab(v),
+        gte(column(v, "c", &views), const_bigint(20)),
+    );
+    views.define_view(w, view).unwrap();
+    let expr = dense_filter(cols_expr_plan(w, &["x"], &views), tab(w), const_bool(true));
+    let expr = views.inline(expr).unwrap();
+    let res = VerifiableQueryResult::new(&expr, &views, &());
+    let res = res.verify(&expr, &views, &()).unwrap().table;
+    assert_eq!(res, owned_table([bigint("x", [2_i64, 1, 2, 3])]));
+}
+
+#[test]
+fn we_cannot_define_a_view_that_refers_to_itself() {
+    let accessor = accessor();
+    let t: TableRef = "sxt.t".parse().unwrap();
+    let u: TableRef = "sxt.u".parse().unwrap();
+    let v: TableRef = "sxt.v".parse().unwrap();
+    let mut views = ViewAccessor::new(&accessor);
+    let self_referential = dense_filter(
+        cols_expr_plan(t, &["a"], &accessor),
+        tab(v),
+        const_bool(true),
+    );
+    assert_eq!(
+        views.define_view(v, self_referential),
+        Err(ViewError::RecursiveView(v))
+    );
+
+    views.define_view(v, filter_view(&accessor)).unwrap();
+    let view = dense_filter(cols_expr_plan(v, &["x"], &views), tab(v), const_bool(true));
+    views.define_view(u, view).unwrap();
+    let cyclic = dense_filter(cols_expr_plan(u, &["x"], &views), tab(u), const_bool(true));
+    assert_eq!(
+        views.define_view(v, cyclic),
+        Err(ViewError::RecursiveView(v))
+    );
+}
+
+#[test]
+fn we_cannot_define_a_view_that_is_not_a_filter_of_plain_columns() {
+    let accessor = accessor();
+    let t: TableRef = "sxt.t".parse().unwrap();
+    let v: TableRef = "sxt.v".parse().unwrap();
+    let mut views = ViewAccessor::<RistrettoPoint, _>::new(&accessor);
+    let computed = dense_filter(
+        vec![aliased_plan(
+            add(column(t, "a", &accessor), column(t, "c", &accessor)),
+            "x",
+        )],
+        tab(t),
+        const_bool(true),
+    );
+    assert_eq!(
+        views.define_view(v, computed),
+        Err(ViewError::UnsupportedView(v))
+    );
+    let aggregation = group_by(
+        cols_expr(t, &["a"], &accessor),
+        vec![],
+        "__count__",
+        tab(t),
+        const_bool(true),
+    );
+    assert_eq!(
+        views.define_view(v, aggregation),
+        Err(ViewError::UnsupportedView(v))
+    );
+}
@@ -0,0 +1,186 @@
+use super::{ProvableBooleanExpr, ProvableExpr, ProvableExprPlan};
+use crate::{
+    base::{
+        commitment::Commitment,
+        database::{ColumnRef, ColumnType, CommitmentAccessor},
+        proof::ProofError,
+        scalar::Scalar,
+    },
+    sql::proof::{CountBuilder, VerificationBuilder},
+};
+#[cfg(feature = "prover")]
+use crate::{
+    base::{
+        database::{Column, DataAccessor},
+        MleAllocator,
+    },
+    sql::proof::{ProofBuilder, SumcheckSubpolynomialType},
+};
+use indexmap::IndexSet;
+use serde::{Deserialize, Serialize};
+
+/// Provable logical XOR expression
+///
+/// A row is selected when exactly one side selects it, i.e. `lhs + rhs - 2 * lhs * rhs`.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct XorExpr<C: Commitment> {
+    lhs: Box<ProvableExprPlan<C>>,
+    rhs: Box<ProvableExprPlan<C>>,
+}
+
+impl<C: Commitment> XorExpr<C> {
+    /// Create logical XOR expression
+    pub fn new(lhs: Box<ProvableExprPlan<C>>, rhs: Box<ProvableExprPlan<C>>) -> Self {
+        Self { lhs, rhs }
+    }
+}
+
+impl<C: Commitment> ProvableExpr<C> for XorExpr<C> {
+    fn count(&self, builder: &mut CountBuilder) -> Result<(), ProofError> {
+        self.lhs.count(builder)?;
+        self.rhs.count(builder)?;
+        count_xor(builder);
+        Ok(())
+    }
+
+    fn data_type(&self) -> ColumnType {
+        ColumnType::Boolean
+    }
+
+    #[cfg(feature = "prover")]
+    #[tracing::instrument(name = "XorExpr::result_evaluate", level = "debug", skip_all)]
+    fn result_evaluate<'a>(
+        &self,
+        table_length: usize,
+        alloc: &'a dyn MleAllocator,
+        accessor: &'a dyn DataAccessor<C::Scalar>,
+    ) -> Column<'a, C::Scalar> {
+        Column::Boolean(self.result_evaluate_selection(table_length, alloc, accessor))
+    }
+
+    #[cfg(feature = "prover")]
+    #[tracing::instrument(name = "XorExpr::prover_evaluate", level = "debug", skip_all)]
+    fn prover_evaluate<'a>(
+        &self,
+        builder: &mut ProofBuilder<'a, C::Scalar>,
+        alloc: &'a dyn MleAllocator,
+        accessor: &'a dyn DataAccessor<C::Scalar>,
+    ) -> Column<'a, C::Scalar> {
+        Column::Boolean(self.prover_evaluate_selection(builder, alloc, accessor))
+    }
+
+    fn verifier_evaluate(
+        &self,
+        builder: &mut VerificationBuilder<C>,
+        accessor: &dyn CommitmentAccessor<C>,
+    ) -> Result<C::Scalar, ProofError> {
+        self.verifier_evaluate_selection(builder, accessor)
+    }
+
+    fn get_column_references(&self, columns: &mut IndexSet<ColumnRef>) {
+        self.lhs.get_column_references(columns);
+        self.rhs.get_column_references(columns);
+    }
+}
+
+impl<C: Commitment> ProvableBooleanExpr<C> for XorExpr<C> {
+    #[cfg(feature = "prover")]
+    fn result_evaluate_selection<'a>(
+        &self,
+        table_length: usize,
+        alloc: &'a dyn MleAllocator,
+        accessor: &'a dyn DataAccessor<C::Scalar>,
+    ) -> &'a [bool] {
+        let lhs = self
+            .lhs
+            .result_evaluate_selection(table_length, alloc, accessor);
+        let rhs = self
+            .rhs
+            .result_evaluate_selection(table_length, alloc, accessor);
+        result_evaluate_xor(table_length, alloc, lhs, rhs)
+    }
+
+    #[cfg(feature = "prover")]
+    fn prover_evaluate_selection<'a>(
+        &self,
+        builder: &mut ProofBuilder<'a, C::Scalar>,
+        alloc: &'a dyn MleAllocator,
+        accessor: &'a dyn DataAccessor<C::Scalar>,
+    ) -> &'a [bool] {
+        let lhs = self.lhs.prover_evaluate_selection(builder, alloc, accessor);
+        let rhs = self.rhs.prover_evaluate_selection(builder, alloc, accessor);
+        prover_evaluate_xor(builder, alloc, lhs, rhs)
+    }
+
+    fn verifier_evaluate_selection(
+        &self,
+        builder: &mut VerificationBuilder<C>,
+        accessor: &dyn CommitmentAccessor<C>,
+    ) -> Result<C::Scalar, ProofError> {
+        let lhs = self.lhs.verifier_evaluate_selection(builder, accessor)?;
+        let rhs = self.rhs.verifier_evaluate_selection(builder, accessor)?;
+
+        Ok(verifier_evaluate_xor(builder, &lhs, &rhs))
+    }
+}
+
+#[cfg(feature = "prover")]
+pub fn result_evaluate_xor<'a>(
+    table_length: usize,
+    alloc: &'a dyn MleAllocator,
+    lhs: &[bool],
+    rhs: &[bool],
+) -> &'a [bool] {
+    assert_eq!(table_length, lhs.len());
+    assert_eq!(table_length, rhs.len());
+    alloc.alloc_slice_fill_with(table_length, |i| lhs[i] != rhs[i])
+}
+
+#[cfg(feature = "prover")]
+pub fn prover_evaluate_xor<'a, S: Scalar>(
+    builder: &mut ProofBuilder<'a, S>,
+    alloc: &'a dyn MleAllocator,
+    lhs: &'a [bool],
+    rhs: &'a [bool],
+) -> &'a [bool] {
+    let n = lhs.len();
+    assert_eq!(n, rhs.len());
+
+    // lhs_and_rhs
+    let lhs_and_rhs: &[_] = alloc.alloc_slice_fill_with(n, |i| lhs[i] && rhs[i]);
+    builder.produce_intermediate_mle(lhs_and_rhs);
+
+    // subpolynomial: lhs_and_rhs - lhs * rhs
+    builder.produce_sumcheck_subpolynomial(
+        SumcheckSubpolynomialType::Identity,
+        vec![
+            (S::one(), vec![Box::new(lhs_and_rhs)]),
+            (-S::one(), vec![Box::new(lhs), Box::new(rhs)]),
+        ],
+    );
+
+    // selection
+    alloc.alloc_slice_fill_with(n, |i| lhs[i] != rhs[i])
+}
+
+pub fn verifier_evaluate_xor<C: Commitment>(
+    builder: &mut VerificationBuilder<C>,
+    lhs: &C::Scalar,
+    rhs: &C::Scalar,
+) -> C::Scalar {
+    // lhs_and_rhs
+    let lhs_and_rhs = builder.consume_intermediate_mle();
+
+    // subpolynomial: lhs_and_rhs - lhs * rhs
+    let eval = builder.mle_evaluations.random_evaluation * (lhs_and_rhs - *lhs * *rhs);
+    builder.produce_sumcheck_subpolynomial_evaluation(&eval);
+
+    // selection
+    *lhs + *rhs - (lhs_and_rhs + lhs_and_rhs)
+}
+
+pub fn count_xor(builder: &mut CountBuilder) {
+    builder.count_subpolynomials(1);
+    builder.count_intermediate_mles(1);
+    builder.count_degree(3);
+}
@@ -0,0 +1,188 @@
+use crate::{
+    base::{
+        commitment::InnerProductProof,
+        database::{owned_table_utility::*, Column, OwnedTableTestAccessor, TestAccessor},
+    },
+    sql::{
+        ast::{test_utility::*, ProvableExpr, ProvableExprPlan},
+        proof::{exercise_verification, VerifiableQueryResult},
+    },
+};
+use bumpalo::Bump;
+use curve25519_dalek::ristretto::RistrettoPoint;
+use itertools::{multizip, MultiUnzip};
+use rand::{
+    distributions::{Distribution, Uniform},
+    rngs::StdRng,
+};
+use rand_core::SeedableRng;
+
+#[test]
+fn we_can_prove_a_simple_xor_query() {
+    let data = owned_table([
+        bigint("a", [1_i64, 2, 3, 4]),
+        int128("b", [0_i128, 1, 1, 1]),
+        varchar("d", ["ab", "t", "g", "efg"]),
+    ]);
+    let t = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(t, data, 0, ());
+    let ast = dense_filter(
+        cols_expr_plan(t, &["a", "d"], &accessor),
+        tab(t),
+        xor(
+            equal(column(t, "b", &accessor), const_bigint(1)),
+            equal(column(t, "d", &accessor), const_varchar("g")),
+        ),
+    );
+    let verifiable_res = VerifiableQueryResult::new(&ast, &accessor, &());
+    exercise_verification(&verifiable_res, &ast, &accessor, t);
+    let res = verifiable_res.verify(&ast, &accessor, &()).unwrap().table;
+    let expected_res = owned_table([bigint("a", [2_i64, 4]), varchar("d", ["t", "efg"])]);
+    assert_eq!(res, expected_res);
+}
+
+#[test]
+fn we_can_prove_a_xor_query_where_both_sides_select_the_same_rows() {
+    let data = owned_table([
+        bigint("a", [1_i64, 2, 3, 4]),
+        bigint("b", [0_i64, 1, 0, 1]),
+        varchar("d", ["ab", "t", "g", "efg"]),
+    ]);
+    let t = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(t, data, 0, ());
+    let ast = dense_filter(
+        cols_expr_plan(t, &["a", "d"], &accessor),
+        tab(t),
+        xor(
+            equal(column(t, "b", &accessor), const_bigint(1)),
+            equal(column(t, "b", &accessor), const_bigint(1)),
+        ),
+    );
+    let verifiable_res = VerifiableQueryResult::new(&ast, &accessor, &());
+    exercise_verification(&verifiable_res, &ast, &accessor, t);
+    let res = verifiable_res.verify(&ast, &accessor, &()).unwrap().table;
+    let expected_res = owned_table([bigint("a", [0_i64; 0]), varchar("d", [""; 0])]);
+    assert_eq!(res, expected_res);
+}
+
+#[test]
+fn we_can_prove_a_simple_nand_query() {
+    let data = owned_table([
+        bigint("a", [1_i64, 2, 3, 4]),
+        int128("b", [0_i128, 1, 1, 1]),
+        varchar("d", ["ab", "t", "g", "efg"]),
+    ]);
+    let t = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(t, data, 0, ());
+    let ast = dense_filter(
+        cols_expr_plan(t, &["a", "d"], &accessor),
+        tab(t),
+        nand(
+            equal(column(t, "b", &accessor), const_bigint(1)),
+            equal(column(t, "d", &accessor), const_varchar("g")),
+        ),
+    );
+    let verifiable_res = VerifiableQueryResult::new(&ast, &accessor, &());
+    exercise_verification(&verifiable_res, &ast, &accessor, t);
+    let res = verifiable_res.verify(&ast, &accessor, &()).unwrap().table;
+    let expected_res = owned_table([bigint("a", [1_i64, 2, 4]), varchar("d", ["ab", "t", "efg"])]);
+    assert_eq!(res, expected_res);
+}
+
+fn test_random_tables_with_given_offset(offset: usize) {
+    let dist = Uniform::new(-3, 4);
+    let mut rng = StdRng::from_seed([0u8; 32]);
+    for _ in 0..20 {
+        // Generate random table
+        let n = Uniform::new(1, 21).sample(&mut rng);
+        let data = owned_table([
+            bigint("a", dist.sample_iter(&mut rng).take(n)),
+            varchar(
+                "b",
+                dist.sample_iter(&mut rng).take(n).map(|v| format!("s{v}")),
+            ),
+            bigint("c", dist.sample_iter(&mut rng).take(n)),
+            varchar(
+                "d",
+                dist.sample_iter(&mut rng).take(n).map(|v| format!("s{v}")),
+            ),
+        ]);
+
+        // Generate random values to filter by
+        let filter_val1 = format!("s{}", dist.sample(&mut rng));
+        let filter_val2 = dist.sample(&mut rng);
+
+        // Create and verify proof
+        let t = "sxt.t".parse().unwrap();
+        let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(
+            t,
+            data.clone(),
+            offset,
+            (),
+        );
+        let ast = dense_filter(
+            cols_expr_plan(t, &["a", "d"], &accessor),
+            tab(t),
+            xor(
+                equal(
+                    column(t, "b", &accessor),
+                    const_varchar(filter_val1.as_str()),
+                ),
+                equal(column(t, "c", &accessor), const_bigint(filter_val2)),
+            ),
+        );
+        let verifiable_res = VerifiableQueryResult::new(&ast, &accessor, &());
+        exercise_verification(&verifiable_res, &ast, &accessor, t);
+        let res = verifiable_res.verify(&ast, &accessor, &()).unwrap().table;
+
+        // Calculate/compare expected result
+        let (expected_a, expected_d): (Vec<_>, Vec<_>) = multizip((
+            data["a"].i64_iter(),
+            data["b"].string_iter(),
+            data["c"].i64_iter(),
+            data["d"].string_iter(),
+        ))
+        .filter_map(|(a, b, c, d)| {
+            if (b == &filter_val1) ^ (c == &filter_val2) {
+                Some((*a, d.clone()))
+            } else {
+                None
+            }
+        })
+        .multiunzip();
+        let expected_result = owned_table([bigint("a", expected_a), varchar("d", expected_d)]);
+
+        assert_eq!(expected_result, res)
+    }
+}
+
+#[test]
+fn we_can_query_random_tables_with_a_zero_offset() {
+    test_random_tables_with_given_offset(0);
+}
+
+#[test]
+fn we_can_query_random_tables_with_a_non_zero_offset() {
+    test_random_tables_with_given_offset(1001);
+}
+
+#[test]
+fn we_can_compute_the_correct_output_of_a_xor_expr_using_result_evaluate() {
+    let data = owned_table([
+        bigint("a", [1, 2, 3, 4]),
+        bigint("b", [0, 1, 0, 1]),
+        bigint("c", [0, 2, 2, 0]),
+        varchar("d", ["ab", "t", "g", "efg"]),
+    ]);
+    let mut accessor = OwnedTableTestAccessor::<InnerProductProof>::new_empty_with_setup(());
+    let t = "sxt.t".parse().unwrap();
+    accessor.add_table(t, data, 0);
+    let xor_expr: ProvableExprPlan<RistrettoPoint> = xor(
+        equal(column(t, "b", &accessor), const_int128(1)),
+        equal(column(t, "d", &accessor), const_varchar("t")),
+    );
+    let alloc = Bump::new();
+    let res = xor_expr.result_evaluate(4, &alloc, &accessor);
+    let expected_res = Column::Boolean(&[false, false, false, true]);
+    assert_eq!(res, expected_res);
+}
@@ -76,6 +76,15 @@ pub enum ConversionError {
     #[error(transparent)]
     PostprocessingError(#[from] crate::sql::postprocessing::PostprocessingError),
 
+    #[error("Case-insensitive comparison with {letters} letters exceeds the maximum of {max}")]
+    /// A case-insensitive comparison would need to consider too many case variants of its value
+    TooManyCaseVariants {
+        /// The number of ASCII letters in the value
+        letters: usize,
+        /// The maximum number of letters supported
+        max: usize,
+    },
+
     #[error("Query not provable because: {0}")]
     /// Query requires unprovable feature
     Unprovable(String),
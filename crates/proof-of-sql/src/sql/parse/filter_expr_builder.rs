@@ -0,0 +1,141 @@
+use super::{ConversionError, ConversionResult};
+use crate::{
+    base::{
+        commitment::Commitment,
+        database::{ColumnRef, LiteralValue, MetadataAccessor, TableRef},
+    },
+    sql::ast::{AliasedProvableExprPlan, DenseFilterExec, ProofPlan, ProvableExprPlan, TableExpr},
+};
+use proof_of_sql_parser::Identifier;
+
+enum Condition<C: Commitment> {
+    Equals(String, LiteralValue<C::Scalar>),
+    Not(ProvableExprPlan<C>),
+}
+
+/// A fluent builder for plans of the form
+/// ```ignore
+///     SELECT <column1>, ..., <columnN> FROM <table> WHERE <condition1> AND ... AND <conditionM>
+/// ```
+///
+/// Column names are resolved against the schema of the provided [`MetadataAccessor`] when
+/// [`FilterExprBuilder::build`] is called, so their types never need to be spelled out.
+pub struct FilterExprBuilder<'a, C: Commitment> {
+    accessor: &'a dyn MetadataAccessor,
+    table: Option<String>,
+    columns: Vec<String>,
+    conditions: Vec<Condition<C>>,
+}
+
+impl<'a, C: Commitment> FilterExprBuilder<'a, C> {
+    /// Creates a new builder that resolves columns against the schema of `accessor`.
+    pub fn new(accessor: &'a dyn MetadataAccessor) -> Self {
+        Self {
+            accessor,
+            table: None,
+            columns: vec![],
+            conditions: vec![],
+        }
+    }
+
+    /// Sets the table to query, e.g. `"sxt.t"`.
+    pub fn from_table(mut self, name: &str) -> Self {
+        self.table = Some(name.to_string());
+        self
+    }
+
+    /// Adds a column to the result.
+    pub fn select(mut self, column: &str) -> Self {
+        self.columns.push(column.to_string());
+        self
+    }
+
+    /// Adds the condition `<column> = <value>` to the where clause.
+    pub fn where_eq(mut self, column: &str, value: LiteralValue<C::Scalar>) -> Self {
+        self.conditions
+            .push(Condition::Equals(column.to_string(), value));
+        self
+    }
+
+    /// Adds the condition `NOT <expr>` to the where clause.
+    pub fn where_not(mut self, expr: ProvableExprPlan<C>) -> Self {
+        self.conditions.push(Condition::Not(expr));
+        self
+    }
+
+    /// Resolves all columns and builds the plan.
+    ///
+    /// Conditions are combined with `AND`. If there are no conditions, every row is selected.
+    pub fn build(self) -> ConversionResult<ProofPlan<C>> {
+        let table_ref: TableRef = self
+            .table
+            .as_deref()
+            .ok_or(ConversionError::InvalidExpression(
+                "a table is required to build a filter".to_string(),
+            ))?
+            .parse()?;
+        let fields = self
+            .accessor
+            .schema()
+            .into_iter()
+            .find_map(|(schema_table_ref, fields)| {
+                (schema_table_ref == table_ref).then_some(fields)
+            })
+            .unwrap_or_default();
+        let resolve = |name: &str| -> ConversionResult<(Identifier, ColumnRef)> {
+            let column_id = Identifier::try_new(name)?;
+            fields
+                .iter()
+                .find(|field| field.name() == column_id)
+                .map(|field| {
+                    (
+                        column_id,
+                        ColumnRef::new(table_ref, column_id, field.data_type()),
+                    )
+                })
+                .ok_or_else(|| {
+                    ConversionError::MissingColumn(
+                        Box::new(column_id),
+                        Box::new(table_ref.resource_id()),
+                    )
+                })
+        };
+
+        let aliased_results = self
+            .columns
+            .iter()
+            .map(|name| {
+                let (alias, column_ref) = resolve(name)?;
+                Ok(AliasedProvableExprPlan {
+                    expr: ProvableExprPlan::new_column(column_ref),
+                    alias,
+                })
+            })
+            .collect::<ConversionResult<Vec<_>>>()?;
+
+        let where_clause = self
+            .conditions
+            .into_iter()
+            .map(|condition| match condition {
+                Condition::Equals(name, value) => ProvableExprPlan::try_new_equals(
+                    ProvableExprPlan::new_column(resolve(&name)?.1),
+                    ProvableExprPlan::new_literal(value),
+                ),
+                Condition::Not(expr) => ProvableExprPlan::try_new_not(expr),
+            })
+            .try_fold(None, |acc, condition| {
+                let condition = condition?;
+                Ok::<_, ConversionError>(Some(match acc {
+                    Some(acc) => ProvableExprPlan::try_new_and(acc, condition)?,
+                    None => condition,
+                }))
+            })?
+            .unwrap_or_else(|| ProvableExprPlan::new_literal(LiteralValue::Boolean(true)));
+
+        Ok(ProofPlan::DenseFilter(DenseFilterExec::new(
+            aliased_results,
+            TableExpr { table_ref },
+            where_clause,
+        )))
+    }
+}
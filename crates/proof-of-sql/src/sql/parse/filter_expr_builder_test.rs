@@ -0,0 +1,101 @@
+use super::{ConversionError, FilterExprBuilder};
+use crate::{
+    base::{
+        commitment::InnerProductProof,
+        database::{owned_table_utility::*, LiteralValue, OwnedTableTestAccessor},
+    },
+    sql::{
+        ast::test_utility::*,
+        proof::{exercise_verification, VerifiableQueryResult},
+    },
+};
+use curve25519_dalek::ristretto::RistrettoPoint;
+
+#[test]
+fn we_can_build_and_prove_a_not_equals_query_with_the_builder() {
+    let data = owned_table([
+        bigint("a", [123_i64, 456]),
+        bigint("b", [0_i64, 1]),
+        varchar("d", ["alfa", "gama"]),
+    ]);
+    let t = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(t, data, 0, ());
+    let expected_ast = dense_filter(
+        cols_expr_plan(t, &["a", "d"], &accessor),
+        tab(t),
+        not(equal(column(t, "b", &accessor), const_bigint(1))),
+    );
+    let ast = FilterExprBuilder::new(&accessor)
+        .from_table("sxt.t")
+        .select("a")
+        .select("d")
+        .where_not(equal(column(t, "b", &accessor), const_bigint(1)))
+        .build()
+        .unwrap();
+    assert_eq!(ast, expected_ast);
+
+    let verifiable_res = VerifiableQueryResult::new(&ast, &accessor, &());
+    exercise_verification(&verifiable_res, &ast, &accessor, t);
+    let res = verifiable_res.verify(&ast, &accessor, &()).unwrap().table;
+    let expected_res = VerifiableQueryResult::new(&expected_ast, &accessor, &())
+        .verify(&expected_ast, &accessor, &())
+        .unwrap()
+        .table;
+    assert_eq!(res, expected_res);
+    assert_eq!(
+        res,
+        owned_table([bigint("a", [123]), varchar("d", ["alfa"])])
+    );
+}
+
+#[test]
+fn we_can_build_a_query_with_multiple_conditions_and_resolved_types() {
+    let data = owned_table([
+        bigint("a", [1_i64, 2, 3]),
+        varchar("b", ["x", "y", "x"]),
+        bigint("c", [0_i64, 0, 1]),
+    ]);
+    let t = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(t, data, 0, ());
+    let ast = FilterExprBuilder::new(&accessor)
+        .from_table("sxt.t")
+        .select("a")
+        .where_eq("b", LiteralValue::VarChar(("x".to_string(), "x".into())))
+        .where_eq("c", LiteralValue::BigInt(0))
+        .build()
+        .unwrap();
+    let expected_ast = dense_filter(
+        cols_expr_plan(t, &["a"], &accessor),
+        tab(t),
+        and(
+            equal(column(t, "b", &accessor), const_varchar("x")),
+            equal(column(t, "c", &accessor), const_bigint(0)),
+        ),
+    );
+    assert_eq!(ast, expected_ast);
+    let res = VerifiableQueryResult::new(&ast, &accessor, &())
+        .verify(&ast, &accessor, &())
+        .unwrap()
+        .table;
+    assert_eq!(res, owned_table([bigint("a", [1])]));
+}
+
+#[test]
+fn we_cannot_build_a_query_with_a_missing_column_or_table() {
+    let data = owned_table([bigint("a", [1_i64, 2, 3])]);
+    let t = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(t, data, 0, ());
+    assert!(matches!(
+        FilterExprBuilder::<RistrettoPoint>::new(&accessor)
+            .from_table("sxt.t")
+            .select("missing")
+            .build(),
+        Err(ConversionError::MissingColumn(_, _))
+    ));
+    assert!(matches!(
+        FilterExprBuilder::<RistrettoPoint>::new(&accessor)
+            .select("a")
+            .build(),
+        Err(ConversionError::InvalidExpression(_))
+    ));
+}
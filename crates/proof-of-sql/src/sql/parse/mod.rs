@@ -16,6 +16,11 @@ pub use query_expr::QueryExpr;
 mod filter_exec_builder;
 pub(crate) use filter_exec_builder::FilterExecBuilder;
 
+mod filter_expr_builder;
+pub use filter_expr_builder::FilterExprBuilder;
+#[cfg(all(test, feature = "blitzar", feature = "prover"))]
+mod filter_expr_builder_test;
+
 pub(crate) mod query_context;
 pub(crate) use query_context::QueryContext;
 
@@ -11,7 +11,7 @@ use proof_of_sql_parser::{
         AggregationOperator, AliasedResultExpr, BinaryOperator, Expression, Literal, OrderBy,
         SelectResultExpr, Slice, TableExpression, UnaryOperator,
     },
-    Identifier, ResourceId,
+    Identifier,
 };
 
 pub struct QueryContextBuilder<'a> {
@@ -37,10 +37,10 @@ impl<'a> QueryContextBuilder<'a> {
         assert_eq!(table_expr.len(), 1);
         match *table_expr[0] {
             TableExpression::Named { table, schema } => {
-                self.context.set_table_ref(TableRef::new(ResourceId::new(
+                self.context.set_table_ref(TableRef::from_names(
                     schema.unwrap_or(default_schema),
                     table,
-                )));
+                ));
             }
         }
         self
@@ -0,0 +1,191 @@
+use super::{decode_and_convert, decode_multiple_elements, ProvableResultElement, QueryError};
+use crate::base::{
+    database::{ColumnType, OwnedColumn},
+    math::decimal::Precision,
+    scalar::Scalar,
+};
+use core::mem::{discriminant, Discriminant};
+use indexmap::IndexMap;
+use proof_of_sql_parser::posql_time::{PoSQLTimeUnit, PoSQLTimeZone};
+
+/// Decodes the elements of a result column of a particular [`ColumnType`].
+pub trait ColumnDecoder<S: Scalar> {
+    /// Decode a single element as a scalar, returning it along with the number of bytes read.
+    ///
+    /// This is used to evaluate the result MLEs.
+    fn decode_scalar(&self, data: &[u8]) -> Result<(S, usize), QueryError>;
+
+    /// Decode `n` elements of a column of type `column_type`, returning the column along with the
+    /// number of bytes read.
+    fn decode_column(
+        &self,
+        data: &[u8],
+        column_type: ColumnType,
+        n: usize,
+    ) -> Result<(OwnedColumn<S>, usize), QueryError>;
+}
+
+/// A table of [`ColumnDecoder`]s keyed by [`ColumnType`].
+///
+/// Decoders are registered per variant of [`ColumnType`], so the parameters of a type such as
+/// `Decimal75(precision, scale)` are ignored when looking up its decoder. They are still passed
+/// on to [`ColumnDecoder::decode_column`]. The default registry contains a decoder for every
+/// built-in type.
+pub struct ColumnDecoderRegistry<S: Scalar> {
+    decoders: IndexMap<Discriminant<ColumnType>, Box<dyn ColumnDecoder<S>>>,
+}
+
+impl<S: Scalar> ColumnDecoderRegistry<S> {
+    /// Create a registry with no decoders.
+    pub fn empty() -> Self {
+        Self {
+            decoders: IndexMap::new(),
+        }
+    }
+
+    /// Register `decoder` for columns of the same variant as `column_type`, replacing any
+    /// decoder that was already registered for it.
+    pub fn register(
+        &mut self,
+        column_type: ColumnType,
+        decoder: impl ColumnDecoder<S> + 'static,
+    ) -> &mut Self {
+        self.decoders
+            .insert(discriminant(&column_type), Box::new(decoder));
+        self
+    }
+
+    /// The decoder registered for `column_type`.
+    ///
+    /// Returns [`QueryError::MiscellaneousDecodingError`] if there is none.
+    pub fn get(&self, column_type: ColumnType) -> Result<&dyn ColumnDecoder<S>, QueryError> {
+        self.decoders
+            .get(&discriminant(&column_type))
+            .map(Box::as_ref)
+            .ok_or(QueryError::MiscellaneousDecodingError)
+    }
+}
+
+impl<S: Scalar> Default for ColumnDecoderRegistry<S> {
+    fn default() -> Self {
+        let mut registry = Self::empty();
+        registry
+            .register(
+                ColumnType::Boolean,
+                ElementDecoder::<bool, S>::new(|_, col| OwnedColumn::Boolean(col)),
+            )
+            .register(
+                ColumnType::SmallInt,
+                ElementDecoder::<i16, S>::new(|_, col| OwnedColumn::SmallInt(col)),
+            )
+            .register(
+                ColumnType::Int,
+                ElementDecoder::<i32, S>::new(|_, col| OwnedColumn::Int(col)),
+            )
+            .register(
+                ColumnType::BigInt,
+                ElementDecoder::<i64, S>::new(|_, col| OwnedColumn::BigInt(col)),
+            )
+            .register(
+                ColumnType::Int128,
+                ElementDecoder::<i128, S>::new(|_, col| OwnedColumn::Int128(col)),
+            )
+            .register(
+                ColumnType::VarChar,
+                ElementDecoder::<String, S>::new(|_, col| OwnedColumn::VarChar(col)),
+            )
+            .register(ColumnType::Scalar, CanonicalScalarDecoder)
+            .register(
+                ColumnType::Decimal75(Precision::new(1).expect("1 is a valid precision"), 0),
+                ElementDecoder::<S, S>::new(|column_type, col| match column_type {
+                    ColumnType::Decimal75(precision, scale) => {
+                        OwnedColumn::Decimal75(precision, scale, col)
+                    }
+                    _ => unreachable!("decoders are looked up by column type"),
+                }),
+            )
+            .register(
+                ColumnType::TimestampTZ(PoSQLTimeUnit::Second, PoSQLTimeZone::Utc),
+                ElementDecoder::<i64, S>::new(|column_type, col| match column_type {
+                    ColumnType::TimestampTZ(time_unit, timezone) => {
+                        OwnedColumn::TimestampTZ(time_unit, timezone, col)
+                    }
+                    _ => unreachable!("decoders are looked up by column type"),
+                }),
+            );
+        registry
+    }
+}
+
+/// Decoder for the built-in types, whose elements are encoded as `T`.
+struct ElementDecoder<T, S: Scalar> {
+    into_column: fn(ColumnType, Vec<T>) -> OwnedColumn<S>,
+}
+
+impl<T, S: Scalar> ElementDecoder<T, S> {
+    fn new(into_column: fn(ColumnType, Vec<T>) -> OwnedColumn<S>) -> Self {
+        Self { into_column }
+    }
+}
+
+impl<T, S: Scalar> ColumnDecoder<S> for ElementDecoder<T, S>
+where
+    T: for<'a> ProvableResultElement<'a>,
+    S: From<T>,
+{
+    fn decode_scalar(&self, data: &[u8]) -> Result<(S, usize), QueryError> {
+        decode_and_convert::<T, S>(data)
+    }
+
+    fn decode_column(
+        &self,
+        data: &[u8],
+        column_type: ColumnType,
+        n: usize,
+    ) -> Result<(OwnedColumn<S>, usize), QueryError> {
+        let (col, num_read) = decode_multiple_elements::<T>(data, n)?;
+        Ok(((self.into_column)(column_type, col), num_read))
+    }
+}
+
+/// Decoder for raw field elements, which only accepts the canonical encoding of each scalar.
+///
+/// The varint encoding of a scalar can be padded with redundant bytes or hold a value outside of
+/// the field, which would decode to the same scalar as its canonical encoding. Such encodings
+/// are rejected with [`QueryError::NonCanonicalScalar`] rather than reduced, so that every raw
+/// scalar result has a single byte representation.
+struct CanonicalScalarDecoder;
+
+impl CanonicalScalarDecoder {
+    fn decode_canonical<S: Scalar>(data: &[u8]) -> Result<(S, usize), QueryError> {
+        let (value, num_read) = <S as ProvableResultElement>::decode(data)?;
+        let mut canonical = vec![0u8; ProvableResultElement::required_bytes(&value)];
+        ProvableResultElement::encode(&value, &mut canonical);
+        if canonical[..] != data[..num_read] {
+            return Err(QueryError::NonCanonicalScalar);
+        }
+        Ok((value, num_read))
+    }
+}
+
+impl<S: Scalar> ColumnDecoder<S> for CanonicalScalarDecoder {
+    fn decode_scalar(&self, data: &[u8]) -> Result<(S, usize), QueryError> {
+        Self::decode_canonical(data)
+    }
+
+    fn decode_column(
+        &self,
+        data: &[u8],
+        _column_type: ColumnType,
+        n: usize,
+    ) -> Result<(OwnedColumn<S>, usize), QueryError> {
+        let mut col = Vec::with_capacity(n);
+        let mut num_read = 0;
+        for _ in 0..n {
+            let (value, len) = Self::decode_canonical::<S>(&data[num_read..])?;
+            col.push(value);
+            num_read += len;
+        }
+        Ok((OwnedColumn::Scalar(col), num_read))
+    }
+}
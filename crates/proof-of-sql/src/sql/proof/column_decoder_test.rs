@@ -0,0 +1,129 @@
+use super::{
+    decode_multiple_elements, ColumnDecoder, ColumnDecoderRegistry, Indexes, ProvableQueryResult,
+    ProvableResultColumn, ProvableResultElement, QueryError,
+};
+use crate::base::{
+    database::{owned_table_utility::*, ColumnField, ColumnType, OwnedColumn},
+    encode::{VarInt, U256},
+    scalar::{Curve25519Scalar, Scalar},
+};
+
+/// A decoder for bigint columns that stores every value negated.
+struct NegatedBigIntDecoder;
+
+impl<S: Scalar> ColumnDecoder<S> for NegatedBigIntDecoder {
+    fn decode_scalar(&self, data: &[u8]) -> Result<(S, usize), QueryError> {
+        let (value, num_read) = i64::decode(data)?;
+        Ok((S::from(-value), num_read))
+    }
+
+    fn decode_column(
+        &self,
+        data: &[u8],
+        _column_type: ColumnType,
+        n: usize,
+    ) -> Result<(OwnedColumn<S>, usize), QueryError> {
+        let (values, num_read) = decode_multiple_elements::<i64>(data, n)?;
+        Ok((
+            OwnedColumn::BigInt(values.into_iter().map(|v| -v).collect()),
+            num_read,
+        ))
+    }
+}
+
+fn bigint_result() -> (ProvableQueryResult, Vec<ColumnField>) {
+    let indexes = Indexes::Sparse(vec![0, 2]);
+    let values: [i64; 3] = [10, 11, 12];
+    let cols: [Box<dyn ProvableResultColumn>; 1] = [Box::new(values)];
+    let fields = vec![ColumnField::new("a".parse().unwrap(), ColumnType::BigInt)];
+    (ProvableQueryResult::new(&indexes, &cols), fields)
+}
+
+#[test]
+fn we_can_decode_a_column_through_a_custom_decoder() {
+    let (res, fields) = bigint_result();
+    let mut decoders = ColumnDecoderRegistry::<Curve25519Scalar>::default();
+    decoders.register(ColumnType::BigInt, NegatedBigIntDecoder);
+    let table = res
+        .to_owned_table_with_decoders(&fields, &decoders)
+        .unwrap();
+    assert_eq!(table, owned_table([bigint("a", [-10, -12])]));
+    let evaluation_point = [
+        Curve25519Scalar::from(10u64),
+        Curve25519Scalar::from(100u64),
+    ];
+    assert_eq!(
+        res.evaluate_with_decoders(&evaluation_point, 4, &fields, &decoders)
+            .unwrap(),
+        res.evaluate(&evaluation_point, 4, &fields)
+            .unwrap()
+            .into_iter()
+            .map(|x| -x)
+            .collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn we_can_decode_a_column_through_the_default_registry() {
+    let (res, fields) = bigint_result();
+    let table = res.to_owned_table::<Curve25519Scalar>(&fields).unwrap();
+    assert_eq!(table, owned_table([bigint("a", [10, 12])]));
+}
+
+#[test]
+fn we_cannot_decode_a_column_without_a_registered_decoder() {
+    let (res, fields) = bigint_result();
+    let decoders = ColumnDecoderRegistry::<Curve25519Scalar>::empty();
+    assert!(matches!(
+        res.to_owned_table_with_decoders(&fields, &decoders),
+        Err(QueryError::MiscellaneousDecodingError)
+    ));
+}
+
+fn scalar_result(data: Vec<u8>) -> (ProvableQueryResult, Vec<ColumnField>) {
+    let res = ProvableQueryResult::new_from_raw_data(1, Indexes::Dense(0..2), data);
+    let fields = vec![ColumnField::new("a".parse().unwrap(), ColumnType::Scalar)];
+    (res, fields)
+}
+
+#[test]
+fn we_can_decode_canonically_encoded_raw_scalars() {
+    let values = [Curve25519Scalar::from(-1i64), Curve25519Scalar::MAX_SIGNED];
+    let cols: [Box<dyn ProvableResultColumn>; 1] = [Box::new(values)];
+    let res = ProvableQueryResult::new(&Indexes::Dense(0..2), &cols);
+    let fields = vec![ColumnField::new("a".parse().unwrap(), ColumnType::Scalar)];
+    assert_eq!(
+        res.to_owned_table::<Curve25519Scalar>(&fields).unwrap(),
+        owned_table([scalar("a", values)])
+    );
+}
+
+#[test]
+fn we_cannot_decode_a_raw_scalar_with_redundant_bytes() {
+    // 1 is canonically encoded as the single byte 0x02
+    let (res, fields) = scalar_result(vec![0x02, 0x82, 0x00]);
+    assert!(matches!(
+        res.to_owned_table::<Curve25519Scalar>(&fields),
+        Err(QueryError::NonCanonicalScalar)
+    ));
+    assert!(matches!(
+        res.evaluate(&[Curve25519Scalar::from(10u64)], 2, &fields),
+        Err(QueryError::NonCanonicalScalar)
+    ));
+}
+
+#[test]
+fn we_cannot_decode_a_raw_scalar_outside_of_the_field() {
+    let mut data = vec![0x02; 1 + 37];
+    let out_of_field = U256 {
+        low: u128::MAX,
+        high: u128::MAX,
+    };
+    let len = out_of_field.encode_var(&mut data[1..]);
+    data.truncate(1 + len);
+    let (res, fields) = scalar_result(data);
+    assert!(matches!(
+        res.to_owned_table::<Curve25519Scalar>(&fields),
+        Err(QueryError::NonCanonicalScalar)
+    ));
+}
@@ -1,13 +1,18 @@
 use crate::{
-    base::{bit::BitDistribution, proof::ProofError},
+    base::{bit::BitDistribution, database::ColumnRef, proof::ProofError},
+    proof_primitive::sumcheck::MAX_SUMCHECK_DEGREE,
     sql::proof::ProofCounts,
 };
+use indexmap::IndexSet;
 use std::cmp::max;
 
 /// Track the number of components expected for in a query's proof
 pub struct CountBuilder<'a> {
     bit_distributions: &'a [BitDistribution],
     counts: ProofCounts,
+    counted_columns: IndexSet<ColumnRef>,
+    estimating: bool,
+    num_consumed_bit_distributions: usize,
 }
 
 impl<'a> CountBuilder<'a> {
@@ -15,15 +20,38 @@ impl<'a> CountBuilder<'a> {
         Self {
             bit_distributions,
             counts: Default::default(),
+            counted_columns: IndexSet::new(),
+            estimating: false,
+            num_consumed_bit_distributions: 0,
         }
     }
 
+    /// Create a builder for estimating the counts of a query before it is proven.
+    ///
+    /// Since the bit distributions are only known once the query is evaluated, every bit
+    /// distribution consumed is assumed to be the widest one accepted by the verifier, so the
+    /// counts are an upper bound on those of the actual proof.
+    pub fn new_for_estimate() -> Self {
+        Self {
+            estimating: true,
+            ..Self::new(&[])
+        }
+    }
+
+    /// The number of bit distributions consumed so far
+    pub fn num_consumed_bit_distributions(&self) -> usize {
+        self.num_consumed_bit_distributions
+    }
+
     /// Proof counts can be dependent on how bits are distributed in a column of data.
     ///
     /// This method provides access to the bit distributions of a proof during the counting
     /// pass of verification.
     pub fn consume_bit_distribution(&mut self) -> Result<BitDistribution, ProofError> {
-        if self.bit_distributions.is_empty() {
+        self.num_consumed_bit_distributions += 1;
+        if self.estimating {
+            Ok(BitDistribution::widest_acceptable())
+        } else if self.bit_distributions.is_empty() {
             Err(ProofError::VerificationError(
                 "expected prover to provide bit distribution",
             ))
@@ -46,6 +74,15 @@ impl<'a> CountBuilder<'a> {
         self.counts.anchored_mles += cnt;
     }
 
+    /// Count the anchored MLE of a column, unless the column was already counted
+    ///
+    /// The MLE of a column is only included in a proof once, however often it is referenced.
+    pub fn count_column_mle(&mut self, column: ColumnRef) {
+        if self.counted_columns.insert(column) {
+            self.count_anchored_mles(1);
+        }
+    }
+
     pub fn count_intermediate_mles(&mut self, cnt: usize) {
         self.counts.intermediate_mles += cnt;
     }
@@ -55,12 +92,22 @@ impl<'a> CountBuilder<'a> {
             max(self.counts.sumcheck_max_multiplicands, degree);
     }
 
+    /// The counts of the proof
+    ///
+    /// Fails if bit distributions were left unconsumed, or if the degree exceeds
+    /// [`MAX_SUMCHECK_DEGREE`].
     pub fn counts(&self) -> Result<ProofCounts, ProofError> {
         if !self.bit_distributions.is_empty() {
             return Err(ProofError::VerificationError(
                 "incorrect number of bit distributions provided",
             ));
         }
+        if self.counts.sumcheck_max_multiplicands > MAX_SUMCHECK_DEGREE {
+            return Err(ProofError::DegreeTooHigh {
+                degree: self.counts.sumcheck_max_multiplicands,
+                max: MAX_SUMCHECK_DEGREE,
+            });
+        }
         Ok(self.counts)
     }
 
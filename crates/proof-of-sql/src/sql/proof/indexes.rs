@@ -2,9 +2,14 @@ use crate::base::{polynomial::compute_truncated_lagrange_basis_sum, scalar::Scal
 use core::ops::Range;
 use num_traits::Zero;
 use serde::{Deserialize, Serialize};
+use thiserror::Error;
 
 #[derive(Clone, Serialize, Deserialize)]
+#[serde(into = "IndexesEncoding", try_from = "IndexesEncoding")]
 /// Indexes of a table for use in the ProvableQueryResult
+///
+/// When serialized, sparse indexes are written as the gaps between consecutive indexes whenever
+/// that is smaller, which shrinks the results of highly selective queries over large tables.
 pub enum Indexes {
     /// Sparse indexes. (i.e. explicitly specified indexes)
     Sparse(Vec<u64>),
@@ -105,3 +110,85 @@ impl Indexes {
         }
     }
 }
+
+/// The serialized form of [`Indexes`].
+#[derive(Serialize, Deserialize)]
+enum IndexesEncoding {
+    Sparse(Vec<u64>),
+    Dense(Range<u64>),
+    /// Sparse indexes, where each entry is the number of indexes skipped since the previous one.
+    /// Consecutive indexes are encoded as `0`.
+    SparseGaps(Vec<u64>),
+}
+
+/// Errors that can occur when decoding [`Indexes`].
+#[derive(Error, Debug)]
+enum IndexesDecodeError {
+    /// The gaps between sparse indexes add up to more than `u64::MAX`.
+    #[error("sparse index gaps overflow")]
+    GapOverflow,
+}
+
+/// The number of bytes a varint encoding of `value` takes.
+fn varint_len(value: u64) -> usize {
+    ((u64::BITS - value.leading_zeros()) as usize)
+        .max(1)
+        .div_ceil(7)
+}
+
+/// The gaps between consecutive indexes, or `None` if the indexes are not strictly increasing.
+fn index_gaps(indexes: &[u64]) -> Option<Vec<u64>> {
+    let mut next = 0u64;
+    indexes
+        .iter()
+        .map(|&index| {
+            let gap = index.checked_sub(next)?;
+            next = index.checked_add(1)?;
+            Some(gap)
+        })
+        .collect()
+}
+
+impl From<Indexes> for IndexesEncoding {
+    fn from(value: Indexes) -> Self {
+        match value {
+            Indexes::Dense(range) => Self::Dense(range),
+            Indexes::Sparse(indexes) => match index_gaps(&indexes) {
+                Some(gaps)
+                    if gaps.iter().copied().map(varint_len).sum::<usize>()
+                        < indexes.iter().copied().map(varint_len).sum::<usize>() =>
+                {
+                    Self::SparseGaps(gaps)
+                }
+                _ => Self::Sparse(indexes),
+            },
+        }
+    }
+}
+
+impl TryFrom<IndexesEncoding> for Indexes {
+    type Error = IndexesDecodeError;
+
+    fn try_from(value: IndexesEncoding) -> Result<Self, Self::Error> {
+        Ok(match value {
+            IndexesEncoding::Sparse(indexes) => Self::Sparse(indexes),
+            IndexesEncoding::Dense(range) => Self::Dense(range),
+            IndexesEncoding::SparseGaps(gaps) => {
+                let mut next = 0u64;
+                Self::Sparse(
+                    gaps.into_iter()
+                        .map(|gap| {
+                            let index = next
+                                .checked_add(gap)
+                                .ok_or(IndexesDecodeError::GapOverflow)?;
+                            next = index
+                                .checked_add(1)
+                                .ok_or(IndexesDecodeError::GapOverflow)?;
+                            Ok(index)
+                        })
+                        .collect::<Result<_, _>>()?,
+                )
+            }
+        })
+    }
+}
@@ -213,3 +213,60 @@ fn we_can_evaluate_indexes_at_an_evaluation_point() {
         )
     );
 }
+
+#[test]
+fn clustered_sparse_indexes_serialize_as_gaps_and_round_trip() {
+    let indexes = vec![1_000_000, 1_000_001, 1_000_002];
+    let ix = Indexes::Sparse(indexes.clone());
+    let bytes = postcard::to_allocvec(&ix).unwrap();
+    // tag + verbatim indexes
+    let verbatim_len = 1 + postcard::to_allocvec(&indexes).unwrap().len();
+    assert!(bytes.len() < verbatim_len);
+    match postcard::from_bytes(&bytes).unwrap() {
+        Indexes::Sparse(decoded) => assert_eq!(decoded, indexes),
+        Indexes::Dense(_) => panic!("expected sparse indexes"),
+    }
+}
+
+#[test]
+fn spread_out_sparse_indexes_serialize_verbatim_and_round_trip() {
+    let indexes = vec![0, 1_000_000, 2_000_000, 3_000_000];
+    let ix = Indexes::Sparse(indexes.clone());
+    let bytes = postcard::to_allocvec(&ix).unwrap();
+    assert_eq!(
+        bytes.len(),
+        1 + postcard::to_allocvec(&indexes).unwrap().len()
+    );
+    match postcard::from_bytes(&bytes).unwrap() {
+        Indexes::Sparse(decoded) => assert_eq!(decoded, indexes),
+        Indexes::Dense(_) => panic!("expected sparse indexes"),
+    }
+}
+
+#[test]
+fn unsorted_sparse_indexes_round_trip() {
+    let ix = Indexes::Sparse(vec![5, 3, 3]);
+    let bytes = postcard::to_allocvec(&ix).unwrap();
+    match postcard::from_bytes(&bytes).unwrap() {
+        Indexes::Sparse(decoded) => assert_eq!(decoded, vec![5, 3, 3]),
+        Indexes::Dense(_) => panic!("expected sparse indexes"),
+    }
+}
+
+#[test]
+fn dense_indexes_round_trip() {
+    let bytes = postcard::to_allocvec(&Indexes::Dense(3..7)).unwrap();
+    match postcard::from_bytes(&bytes).unwrap() {
+        Indexes::Dense(range) => assert_eq!(range, 3..7),
+        Indexes::Sparse(_) => panic!("expected dense indexes"),
+    }
+}
+
+#[test]
+fn sparse_index_gaps_that_overflow_cannot_be_deserialized() {
+    // tag 2 (gaps), 2 entries, u64::MAX, 0
+    let mut bytes = vec![2, 2];
+    bytes.extend(postcard::to_allocvec(&u64::MAX).unwrap());
+    bytes.push(0);
+    assert!(postcard::from_bytes::<Indexes>(&bytes).is_err());
+}
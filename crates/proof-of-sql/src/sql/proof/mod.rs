@@ -2,19 +2,29 @@
 mod count_builder;
 pub(crate) use count_builder::CountBuilder;
 
+#[cfg(feature = "prover")]
 mod proof_builder;
+#[cfg(feature = "prover")]
 pub(crate) use proof_builder::ProofBuilder;
-#[cfg(all(test, feature = "blitzar"))]
+#[cfg(all(test, feature = "blitzar", feature = "prover"))]
 mod proof_builder_test;
 
+#[cfg(feature = "prover")]
 mod composite_polynomial_builder;
+#[cfg(feature = "prover")]
 pub(crate) use composite_polynomial_builder::CompositePolynomialBuilder;
-#[cfg(test)]
+#[cfg(all(test, feature = "prover"))]
 mod composite_polynomial_builder_test;
 
 mod proof_counts;
 pub(crate) use proof_counts::ProofCounts;
 
+mod proof_cost_estimate;
+pub use proof_cost_estimate::ProofCostEstimate;
+
+mod proof_header;
+pub use proof_header::ProofHeader;
+
 mod verification_builder;
 pub(crate) use verification_builder::VerificationBuilder;
 #[cfg(test)]
@@ -36,42 +46,89 @@ mod sumcheck_mle_evaluations_test;
 mod sumcheck_random_scalars;
 pub(crate) use sumcheck_random_scalars::SumcheckRandomScalars;
 
+#[cfg(feature = "deterministic")]
+mod sumcheck_seed;
+#[cfg(feature = "deterministic")]
+pub use sumcheck_seed::with_sumcheck_seed;
+
 mod proof_execution_plan;
 pub use proof_execution_plan::ProofExecutionPlan;
 pub(crate) use proof_execution_plan::{HonestProver, ProverEvaluate, ProverHonestyMarker};
 
+mod query_proof_options;
+pub use query_proof_options::{ProofSizeLimits, QueryProofOptions};
+
 mod query_proof;
-pub use query_proof::QueryProof;
-#[cfg(all(test, feature = "blitzar"))]
+pub use crate::proof_primitive::sumcheck::{SumcheckStrategy, MAX_SUMCHECK_DEGREE};
+pub use query_proof::{
+    QueryProof, MIN_SUPPORTED_QUERY_PROOF_FORMAT_MINOR_VERSION, QUERY_PROOF_FORMAT_MAJOR_VERSION,
+    QUERY_PROOF_FORMAT_MINOR_VERSION,
+};
+#[cfg(all(test, feature = "blitzar", feature = "prover"))]
 mod query_proof_test;
 
 mod query_result;
 pub use query_result::{QueryData, QueryError, QueryResult};
+#[cfg(test)]
+mod query_result_test;
 
 mod sumcheck_subpolynomial;
 pub(crate) use sumcheck_subpolynomial::{
     SumcheckSubpolynomial, SumcheckSubpolynomialTerm, SumcheckSubpolynomialType,
 };
 
+mod verification_hash;
+pub use verification_hash::{VerificationHash, VerificationHashParseError};
+#[cfg(test)]
+mod verification_hash_test;
+
 mod verifiable_query_result;
 pub use verifiable_query_result::VerifiableQueryResult;
-#[cfg(all(test, feature = "blitzar"))]
+
+mod query_row;
+pub use proof_of_sql_derive::FromQueryRow;
+pub use query_row::{FromColumnValue, FromQueryRow, FromQueryRowError, QueryRow};
+#[cfg(all(test, feature = "blitzar", feature = "prover"))]
+mod query_row_test;
+
+mod result_page;
+pub use result_page::{PaginatedProofPlan, ResultCursor, VerifiableResultPage};
+#[cfg(all(test, feature = "blitzar", feature = "prover"))]
+mod result_page_test;
+#[cfg(all(test, feature = "blitzar", feature = "prover"))]
 mod verifiable_query_result_test;
 
-#[cfg(all(test, feature = "blitzar"))]
+#[cfg(all(test, feature = "blitzar", feature = "prover"))]
 mod verifiable_query_result_test_utility;
-#[cfg(all(test, feature = "blitzar"))]
+#[cfg(all(test, feature = "blitzar", feature = "prover"))]
 pub(crate) use verifiable_query_result_test_utility::exercise_verification;
 
+#[cfg(any(test, feature = "test"))]
+mod scaling_test_utility;
+#[cfg(any(test, feature = "test"))]
+pub use scaling_test_utility::{generate_scaling_plan, ScalingTestProofExecutionPlan};
+#[cfg(all(test, feature = "blitzar", feature = "prover"))]
+mod scaling_test_utility_test;
+
 mod result_element_serialization;
 pub(crate) use result_element_serialization::{
     decode_and_convert, decode_multiple_elements, ProvableResultElement,
 };
 
+mod column_decoder;
+pub use column_decoder::{ColumnDecoder, ColumnDecoderRegistry};
+#[cfg(test)]
+mod column_decoder_test;
+
 mod indexes;
 pub(crate) use indexes::Indexes;
 #[cfg(test)]
 mod indexes_test;
 
+#[cfg(feature = "prover")]
 mod result_builder;
+#[cfg(feature = "prover")]
 pub(crate) use result_builder::ResultBuilder;
+
+#[cfg(all(test, not(feature = "prover")))]
+mod verifier_only_test;
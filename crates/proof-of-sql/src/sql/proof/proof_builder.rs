@@ -2,13 +2,31 @@ use super::{
     CompositePolynomialBuilder, SumcheckRandomScalars, SumcheckSubpolynomial,
     SumcheckSubpolynomialTerm, SumcheckSubpolynomialType,
 };
-use crate::base::{
-    bit::BitDistribution,
-    commitment::{Commitment, CommittableColumn, VecCommitmentExt},
-    polynomial::{CompositePolynomial, MultilinearExtension},
-    scalar::Scalar,
+use crate::{
+    base::{
+        bit::BitDistribution,
+        commitment::{Commitment, CommittableColumn, VecCommitmentExt},
+        database::{Column, ColumnRef},
+        polynomial::{CompositePolynomial, MultilinearExtension},
+        scalar::Scalar,
+    },
+    proof_primitive::sumcheck::SumcheckStrategy,
 };
+use indexmap::IndexSet;
 use num_traits::Zero;
+use std::{
+    collections::{hash_map::DefaultHasher, HashMap},
+    hash::{Hash, Hasher},
+};
+
+/// Where the values of an MLE that a later intermediate MLE can be an alias of are kept
+#[derive(Clone, Copy)]
+enum AliasableMle {
+    /// The entry of `commitment_descriptor` at this position
+    Intermediate(usize),
+    /// The entry of `column_descriptor` at this position
+    Column(usize),
+}
 
 /// Track components used to form a query's proof
 pub struct ProofBuilder<'a, S: Scalar> {
@@ -16,7 +34,18 @@ pub struct ProofBuilder<'a, S: Scalar> {
     num_sumcheck_variables: usize,
     bit_distributions: Vec<BitDistribution>,
     commitment_descriptor: Vec<CommittableColumn<'a>>,
+    /// The values of the MLEs produced with [`Self::produce_column_mle`]
+    column_descriptor: Vec<CommittableColumn<'a>>,
+    /// The MLEs that a later intermediate MLE can be an alias of, keyed by the hash of their
+    /// values, along with their index among the pcs proof MLEs
+    aliasable_mles: HashMap<u64, Vec<(AliasableMle, usize)>>,
+    /// The intermediate MLEs that share the commitment of an identical earlier MLE, as pairs
+    /// of `(alias, original)` indexes, where the original is indexed among the pcs proof MLEs
+    intermediate_mle_aliases: Vec<(u64, u64)>,
+    num_intermediate_mles: usize,
     pcs_proof_mles: Vec<Box<dyn MultilinearExtension<S> + 'a>>,
+    /// The columns whose MLEs have been produced
+    produced_columns: IndexSet<ColumnRef>,
     sumcheck_subpolynomials: Vec<SumcheckSubpolynomial<'a, S>>,
     /// The challenges used in creation of the constraints in the proof.
     /// Specifically, these are the challenges that the verifier sends to
@@ -26,6 +55,7 @@ pub struct ProofBuilder<'a, S: Scalar> {
     /// Note: this vector is treated as a stack and the first
     /// challenge is the last entry in the vector.
     post_result_challenges: Vec<S>,
+    sumcheck_strategy: SumcheckStrategy,
 }
 
 impl<'a, S: Scalar> ProofBuilder<'a, S> {
@@ -39,9 +69,15 @@ impl<'a, S: Scalar> ProofBuilder<'a, S> {
             num_sumcheck_variables,
             bit_distributions: Vec::new(),
             commitment_descriptor: Vec::new(),
+            column_descriptor: Vec::new(),
+            aliasable_mles: HashMap::new(),
+            intermediate_mle_aliases: Vec::new(),
+            num_intermediate_mles: 0,
             pcs_proof_mles: Vec::new(),
+            produced_columns: IndexSet::new(),
             sumcheck_subpolynomials: Vec::new(),
             post_result_challenges,
+            sumcheck_strategy: SumcheckStrategy::default(),
         }
     }
 
@@ -57,6 +93,16 @@ impl<'a, S: Scalar> ProofBuilder<'a, S> {
         self.sumcheck_subpolynomials.len()
     }
 
+    /// The strategy used when proving the sumcheck polynomial
+    pub fn sumcheck_strategy(&self) -> SumcheckStrategy {
+        self.sumcheck_strategy
+    }
+
+    /// Choose how many variables each round of the sumcheck proof binds
+    pub fn set_sumcheck_strategy(&mut self, strategy: SumcheckStrategy) {
+        self.sumcheck_strategy = strategy;
+    }
+
     /// Produce a bit distribution that describes which bits are constant
     /// and which bits varying in a column of data
     pub fn produce_bit_distribution(&mut self, dist: BitDistribution) {
@@ -70,16 +116,86 @@ impl<'a, S: Scalar> ProofBuilder<'a, S> {
         self.pcs_proof_mles.push(Box::new(data));
     }
 
+    /// Produce the anchored MLE of a column, unless it was already produced.
+    ///
+    /// The MLE of a column is only included in the proof once, so the verifier only checks one
+    /// evaluation of it however often it is referenced. Intermediate MLEs identical to the
+    /// column are aliases of it.
+    pub fn produce_column_mle(&mut self, column: ColumnRef, data: Column<'a, S>) {
+        if self.produced_columns.insert(column) {
+            let values = CommittableColumn::from(&data);
+            self.record_aliasable_mle(
+                hash_values(&values),
+                AliasableMle::Column(self.column_descriptor.len()),
+            );
+            self.column_descriptor.push(values);
+            self.produce_anchored_mle(data);
+        }
+    }
+
     /// Produce an MLE for a intermediate computed column that we can reference in sumcheck.
     ///
     /// Because the verifier doesn't have access to the MLE's commitment, we will need to
     /// commit to the MLE before we form the sumcheck polynomial.
+    ///
+    /// An MLE identical to an intermediate MLE or a column MLE that was already produced is not
+    /// committed to again: it is recorded as an alias of the earlier one, and shares its
+    /// commitment and evaluation. Only values of the same column type are considered identical.
     pub fn produce_intermediate_mle(
         &mut self,
         data: impl MultilinearExtension<S> + Into<CommittableColumn<'a>> + Copy + 'a,
     ) {
-        self.commitment_descriptor.push(data.into());
-        self.produce_anchored_mle(data);
+        let index = self.num_intermediate_mles;
+        self.num_intermediate_mles += 1;
+        let values = data.into();
+        let hash = hash_values(&values);
+        match self.find_aliasable_mle(hash, &values) {
+            Some(original) => self
+                .intermediate_mle_aliases
+                .push((index as u64, original as u64)),
+            None => {
+                self.record_aliasable_mle(
+                    hash,
+                    AliasableMle::Intermediate(self.commitment_descriptor.len()),
+                );
+                self.commitment_descriptor.push(values);
+                self.produce_anchored_mle(data);
+            }
+        }
+    }
+
+    /// The index among the pcs proof MLEs of an earlier MLE with the given values
+    fn find_aliasable_mle(&self, hash: u64, values: &CommittableColumn<'a>) -> Option<usize> {
+        self.aliasable_mles
+            .get(&hash)?
+            .iter()
+            .find_map(|&(mle, pcs_index)| {
+                let candidate = match mle {
+                    AliasableMle::Intermediate(position) => &self.commitment_descriptor[position],
+                    AliasableMle::Column(position) => &self.column_descriptor[position],
+                };
+                (candidate == values).then_some(pcs_index)
+            })
+    }
+
+    /// Record that the next pcs proof MLE can be the original of later aliases
+    fn record_aliasable_mle(&mut self, hash: u64, mle: AliasableMle) {
+        self.aliasable_mles
+            .entry(hash)
+            .or_default()
+            .push((mle, self.pcs_proof_mles.len()));
+    }
+
+    /// The number of intermediate MLEs produced, including those that share a commitment
+    pub fn num_intermediate_mles(&self) -> usize {
+        self.num_intermediate_mles
+    }
+
+    /// The intermediate MLEs that share the commitment of an identical earlier MLE, as pairs of
+    /// `(alias, original)` indexes in the order the MLEs were produced, where the original is
+    /// indexed among the pcs proof MLEs
+    pub fn intermediate_mle_aliases(&self) -> &[(u64, u64)] {
+        &self.intermediate_mle_aliases
     }
 
     /// Produce a subpolynomial to be aggegated into sumcheck where the sum across binary
@@ -177,3 +293,10 @@ impl<'a, S: Scalar> ProofBuilder<'a, S> {
         self.post_result_challenges.pop().unwrap()
     }
 }
+
+/// Hash the values of an MLE, so that identical MLEs can be found without comparing them all
+fn hash_values(values: &CommittableColumn) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    values.hash(&mut hasher);
+    hasher.finish()
+}
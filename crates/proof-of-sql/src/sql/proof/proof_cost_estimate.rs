@@ -0,0 +1,104 @@
+use super::{ProofCounts, ProofHeader};
+use crate::{
+    base::{
+        bit::BitDistribution, commitment::Commitment, math::log2_up, proof::MessageLabel,
+        scalar::Scalar,
+    },
+    proof_primitive::sumcheck::SumcheckStrategy,
+};
+use serde::Serialize;
+use std::cmp::max;
+
+/// The predicted cost of proving and verifying a query, computed without proving it.
+///
+/// The estimate is derived from the counts of the query's plan and the length of its input
+/// table, so it is cheap to compute. Plans whose counts depend on bit distributions are
+/// estimated with the widest distribution the verifier accepts, so the estimate is an upper
+/// bound for them. Likewise, intermediate MLEs that turn out to be identical share a
+/// commitment, so the counts of commitments and MLE evaluations are upper bounds.
+///
+/// Field operation counts follow a simple model of the protocol: they are meant for comparing
+/// queries with each other, not for predicting running times exactly.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ProofCostEstimate {
+    /// The number of commitments to intermediate MLEs in the proof
+    pub num_commitments: usize,
+    /// The number of MLE evaluations in the proof
+    pub num_mle_evaluations: usize,
+    /// The number of evaluations sent over all rounds of the sumcheck proof
+    pub num_sumcheck_evaluations: usize,
+    /// The serialized size of the proof in bytes, excluding the evaluation proof, whose size
+    /// depends on the commitment scheme's evaluation proof rather than on the plan
+    pub proof_size_bytes: usize,
+    /// The number of field operations done by the prover
+    pub prover_field_operations: usize,
+    /// The number of field operations done by the verifier
+    pub verifier_field_operations: usize,
+}
+
+impl ProofCostEstimate {
+    /// Estimate the cost of a proof with the given counts over a table of `table_length` rows
+    /// which consumes `num_bit_distributions` bit distributions.
+    pub(crate) fn new<C: Commitment + Serialize>(
+        table_length: usize,
+        counts: &ProofCounts,
+        num_bit_distributions: usize,
+    ) -> Self {
+        let num_sumcheck_variables = max(log2_up(table_length), 1);
+        let degree = max(counts.sumcheck_max_multiplicands, 2);
+        let num_commitments = counts.intermediate_mles;
+        let num_mle_evaluations = counts.intermediate_mles + counts.anchored_mles;
+        let strategy = SumcheckStrategy::default();
+        let num_rounds = strategy.num_rounds(num_sumcheck_variables);
+        let num_sumcheck_evaluations = strategy
+            .round_variables(num_sumcheck_variables)
+            .map(|round_variables| SumcheckStrategy::round_length(degree, round_variables))
+            .sum::<usize>();
+
+        let header = ProofHeader::new(table_length, counts, MessageLabel::QueryProof.as_bytes());
+        let commitment_size = serialized_size(&C::default());
+        let scalar_size = serialized_size(&C::Scalar::MAX_SIGNED);
+        let proof_size_bytes = serialized_size(&header)
+            + serialized_size(&num_bit_distributions)
+            + num_bit_distributions * serialized_size(&BitDistribution::widest_acceptable())
+            + serialized_size(&num_commitments)
+            + num_commitments * commitment_size
+            + serialized_size(&num_rounds)
+            + num_rounds * serialized_size(&(degree + 1))
+            + num_sumcheck_evaluations * scalar_size
+            + serialized_size(&strategy)
+            + serialized_size(&num_mle_evaluations)
+            + num_mle_evaluations * scalar_size;
+
+        // The prover evaluates every MLE, including the result columns, at the sumcheck point,
+        // and computes `degree + 1` evaluations of every subpolynomial, each a product of up to
+        // `degree` factors, over a table which halves each round.
+        let table_size = 1 << num_sumcheck_variables;
+        let num_mles = num_mle_evaluations + counts.result_columns;
+        let prover_field_operations =
+            table_size * (num_mles + 2 * (degree + 1) * counts.sumcheck_subpolynomials * degree);
+
+        // The verifier interpolates each round at its challenge, evaluates the result columns
+        // directly, and combines the subpolynomial evaluations.
+        let verifier_field_operations = num_sumcheck_evaluations * (degree + 1)
+            + counts.result_columns * table_length
+            + counts.sumcheck_subpolynomials * degree
+            + num_mle_evaluations
+            + 2 * num_sumcheck_variables;
+
+        Self {
+            num_commitments,
+            num_mle_evaluations,
+            num_sumcheck_evaluations,
+            proof_size_bytes,
+            prover_field_operations,
+            verifier_field_operations,
+        }
+    }
+}
+
+fn serialized_size(value: &impl Serialize) -> usize {
+    postcard::to_allocvec(value)
+        .expect("serialization to a vector cannot fail")
+        .len()
+}
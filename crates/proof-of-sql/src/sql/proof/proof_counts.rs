@@ -1,8 +1,8 @@
 use serde::{Deserialize, Serialize};
-use std::fmt::Debug;
+use std::{cmp::max, fmt::Debug, ops::Add};
 
 /// Counters for different terms used within a proof
-#[derive(Default, Debug, Clone, Copy, Serialize, Deserialize)]
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub struct ProofCounts {
     pub sumcheck_max_multiplicands: usize,
     pub result_columns: usize,
@@ -18,6 +18,21 @@ pub struct ProofCounts {
 }
 
 impl ProofCounts {
+    /// Combine the counts of another proof into these counts
+    ///
+    /// The degree is the max of the two degrees, while all other counts are summed.
+    pub fn merge(&mut self, other: &ProofCounts) {
+        self.sumcheck_max_multiplicands = max(
+            self.sumcheck_max_multiplicands,
+            other.sumcheck_max_multiplicands,
+        );
+        self.result_columns += other.result_columns;
+        self.anchored_mles += other.anchored_mles;
+        self.intermediate_mles += other.intermediate_mles;
+        self.sumcheck_subpolynomials += other.sumcheck_subpolynomials;
+        self.post_result_challenges += other.post_result_challenges;
+    }
+
     #[tracing::instrument(name = "ProofCounts::annotate_trace", level = "debug", skip_all)]
     pub fn annotate_trace(&self) {
         tracing::info!(
@@ -34,3 +49,12 @@ impl ProofCounts {
         tracing::info!("post_result_challenges = {:?}", self.post_result_challenges);
     }
 }
+
+impl Add for ProofCounts {
+    type Output = ProofCounts;
+
+    fn add(mut self, rhs: ProofCounts) -> ProofCounts {
+        self.merge(&rhs);
+        self
+    }
+}
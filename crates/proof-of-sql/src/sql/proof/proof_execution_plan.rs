@@ -1,14 +1,16 @@
-use super::{CountBuilder, ProofBuilder, ResultBuilder, VerificationBuilder};
+use super::{CountBuilder, ProofCostEstimate, VerificationBuilder};
+#[cfg(feature = "prover")]
+use super::{ProofBuilder, ResultBuilder};
 use crate::base::{
     commitment::Commitment,
-    database::{
-        ColumnField, ColumnRef, CommitmentAccessor, DataAccessor, MetadataAccessor, OwnedTable,
-    },
+    database::{ColumnField, ColumnRef, CommitmentAccessor, MetadataAccessor, OwnedTable},
     proof::ProofError,
     scalar::Scalar,
 };
-use bumpalo::Bump;
+#[cfg(feature = "prover")]
+use crate::base::{database::DataAccessor, MleAllocator};
 use indexmap::IndexSet;
+use serde::Serialize;
 use std::fmt::Debug;
 
 /// Provable nodes in the provable AST.
@@ -33,6 +35,14 @@ pub trait ProofExecutionPlan<C: Commitment>:
         self.get_length(accessor) == 0
     }
 
+    /// The result of the query when the input table is empty, if it has any rows
+    ///
+    /// Queries on an empty table are answered without a proof, and by default their result has
+    /// no rows.
+    fn get_empty_table_result(&self) -> Option<OwnedTable<C::Scalar>> {
+        None
+    }
+
     /// Form components needed to verify and proof store into VerificationBuilder
     fn verifier_evaluate(
         &self,
@@ -46,14 +56,57 @@ pub trait ProofExecutionPlan<C: Commitment>:
 
     /// Return all the columns referenced in the Query
     fn get_column_references(&self) -> IndexSet<ColumnRef>;
+
+    /// Return all the columns referenced in the Query, sorted by table and then by column name
+    ///
+    /// The order of [`get_column_references`](Self::get_column_references) depends on the order
+    /// in which the set was built. This order doesn't, so it is the one absorbed into the
+    /// transcript, keeping proofs reproducible.
+    fn get_canonical_column_references(&self) -> Vec<ColumnRef> {
+        let mut columns = Vec::from_iter(self.get_column_references());
+        columns.sort_by_key(|column| {
+            (
+                column.table_ref().schema_id(),
+                column.table_ref().table_id(),
+                column.column_id(),
+            )
+        });
+        columns
+    }
+
+    /// Estimate the cost of proving and verifying the query without proving it
+    ///
+    /// The estimate only depends on the plan's counts and the length of the input table.
+    ///
+    /// Panics if the degree of the plan exceeds [`MAX_SUMCHECK_DEGREE`](super::MAX_SUMCHECK_DEGREE),
+    /// since no proof of it can be verified.
+    fn estimate_cost(&self, accessor: &dyn MetadataAccessor) -> ProofCostEstimate
+    where
+        C: Serialize,
+    {
+        // when estimating, counting consumes the widest acceptable bit distributions,
+        // so it never fails
+        let mut builder = CountBuilder::new_for_estimate();
+        self.count(&mut builder, accessor)
+            .expect("counting a plan for an estimate should not fail");
+        let counts = builder
+            .counts()
+            .expect("the degree of the plan should not exceed the maximum sumcheck degree");
+        ProofCostEstimate::new::<C>(
+            self.get_length(accessor),
+            &counts,
+            builder.num_consumed_bit_distributions(),
+        )
+    }
 }
 
+#[cfg(feature = "prover")]
 pub trait ProverEvaluate<S: Scalar> {
     /// Evaluate the query and modify `ResultBuilder` to track the result of the query.
     fn result_evaluate<'a>(
         &self,
         builder: &mut ResultBuilder<'a>,
-        alloc: &'a Bump,
+        alloc: &'a dyn MleAllocator,
         accessor: &'a dyn DataAccessor<S>,
     );
 
@@ -66,11 +119,18 @@ pub trait ProverEvaluate<S: Scalar> {
     fn prover_evaluate<'a>(
         &self,
         builder: &mut ProofBuilder<'a, S>,
-        alloc: &'a Bump,
+        alloc: &'a dyn MleAllocator,
         accessor: &'a dyn DataAccessor<S>,
     );
 }
 
+/// Without the `prover` feature there is nothing to evaluate, so every type trivially
+/// satisfies the [`ProofExecutionPlan`] bound on `ProverEvaluate`.
+#[cfg(not(feature = "prover"))]
+pub trait ProverEvaluate<S: Scalar> {}
+#[cfg(not(feature = "prover"))]
+impl<S: Scalar, T: ?Sized> ProverEvaluate<S> for T {}
+
 /// Marker used as a trait bound for generic [`ProofExecutionPlan`] types to indicate the honesty of their implementation.
 ///
 /// This allows us to define alternative prover implementations that misbehave, and test that the verifier rejects their results.
@@ -0,0 +1,119 @@
+use super::ProofCounts;
+use crate::base::proof::ProofError;
+use serde::{Deserialize, Serialize};
+use std::cmp;
+
+/// A compact summary of the shape of a [`QueryProof`](super::QueryProof), placed at its front.
+///
+/// The verifier compares the header with the counts of the plan before doing any field
+/// arithmetic, so that a proof of the wrong shape is rejected immediately. Because the header is
+/// untrusted, a matching header does not imply a matching proof: the components of the proof are
+/// still checked during verification.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ProofHeader {
+    /// The length of the table the proof is over
+    pub table_length: u64,
+    /// The number of result columns
+    pub result_columns: u64,
+    /// The number of intermediate MLEs. Each of them has a commitment in the proof, unless it
+    /// is listed in `intermediate_mle_aliases`
+    pub intermediate_mles: u64,
+    /// The number of anchored MLEs
+    pub anchored_mles: u64,
+    /// The number of sumcheck subpolynomials
+    pub sumcheck_subpolynomials: u64,
+    /// The number of challenges drawn after the result is sent
+    pub post_result_challenges: u64,
+    /// The degree of the sumcheck polynomial
+    pub sumcheck_max_multiplicands: u64,
+    /// The label the Fiat-Shamir transcript was created with
+    pub transcript_label: Vec<u8>,
+    /// The intermediate MLEs that are identical to an earlier MLE and share its commitment and
+    /// evaluation, as pairs of `(alias, original)` indexes in the order the MLEs are produced.
+    /// The original is indexed among the MLEs with an evaluation in the proof, so it can be an
+    /// anchored MLE as well as an intermediate one
+    pub intermediate_mle_aliases: Vec<(u64, u64)>,
+}
+
+impl ProofHeader {
+    /// Create the header of a proof with the given shape.
+    pub(crate) fn new(table_length: usize, counts: &ProofCounts, transcript_label: &[u8]) -> Self {
+        Self {
+            table_length: table_length as u64,
+            result_columns: counts.result_columns as u64,
+            intermediate_mles: counts.intermediate_mles as u64,
+            anchored_mles: counts.anchored_mles as u64,
+            sumcheck_subpolynomials: counts.sumcheck_subpolynomials as u64,
+            post_result_challenges: counts.post_result_challenges as u64,
+            sumcheck_max_multiplicands: counts.sumcheck_max_multiplicands as u64,
+            transcript_label: transcript_label.to_vec(),
+            intermediate_mle_aliases: Vec::new(),
+        }
+    }
+
+    /// Resolves the aliases of a proof with `num_intermediate_mles` intermediate MLEs into the
+    /// original of each MLE, or `None` for the MLEs that have their own commitment.
+    ///
+    /// Each alias must be listed only once, in increasing order, so that every proof has a
+    /// single valid header. Whether the original was consumed before its alias depends on the
+    /// plan, so it is checked by the [`VerificationBuilder`](super::VerificationBuilder).
+    pub(crate) fn resolve_intermediate_mle_aliases(
+        &self,
+        num_intermediate_mles: usize,
+    ) -> Result<Vec<Option<usize>>, ProofError> {
+        let invalid = ProofError::VerificationError("invalid intermediate mle aliases");
+        if self.intermediate_mle_aliases.len() > num_intermediate_mles {
+            return Err(invalid);
+        }
+        let mut originals = vec![None; num_intermediate_mles];
+        let mut previous_alias = None;
+        for &(alias, original) in &self.intermediate_mle_aliases {
+            if alias >= num_intermediate_mles as u64
+                || matches!(previous_alias, Some(previous) if alias <= previous)
+            {
+                return Err(invalid);
+            }
+            originals[alias as usize] = Some(original as usize);
+            previous_alias = Some(alias);
+        }
+        Ok(originals)
+    }
+
+    /// Checks that the header describes a proof of a plan with the given `counts`.
+    ///
+    /// The degree of the sumcheck polynomial is at least 2, whatever the plan counts, since
+    /// `CompositePolynomialBuilder::make_composite_polynomial` always adds a degree 2 term.
+    pub(crate) fn check(
+        &self,
+        table_length: usize,
+        counts: &ProofCounts,
+        transcript_label: &[u8],
+    ) -> Result<(), ProofError> {
+        let expected = Self::new(
+            table_length,
+            &ProofCounts {
+                sumcheck_max_multiplicands: cmp::max(counts.sumcheck_max_multiplicands, 2),
+                ..*counts
+            },
+            transcript_label,
+        );
+        if self.table_length != expected.table_length {
+            return Err(ProofError::HeaderMismatch("table length"));
+        }
+        if self.transcript_label != expected.transcript_label {
+            return Err(ProofError::HeaderMismatch("transcript label"));
+        }
+        if self.result_columns != expected.result_columns
+            || self.intermediate_mles != expected.intermediate_mles
+            || self.anchored_mles != expected.anchored_mles
+            || self.sumcheck_subpolynomials != expected.sumcheck_subpolynomials
+            || self.post_result_challenges != expected.post_result_challenges
+        {
+            return Err(ProofError::HeaderMismatch("counts"));
+        }
+        if self.sumcheck_max_multiplicands != expected.sumcheck_max_multiplicands {
+            return Err(ProofError::HeaderMismatch("degree"));
+        }
+        Ok(())
+    }
+}
@@ -1,8 +1,6 @@
-use super::{
-    decode_and_convert, decode_multiple_elements, Indexes, ProvableResultColumn, QueryError,
-};
+use super::{ColumnDecoderRegistry, Indexes, ProvableResultColumn, QueryError};
 use crate::base::{
-    database::{ColumnField, ColumnType, OwnedColumn, OwnedTable},
+    database::{ColumnField, OwnedTable},
     polynomial::compute_evaluation_vector,
     scalar::Scalar,
 };
@@ -84,7 +82,28 @@ impl ProvableQueryResult {
         table_length: usize,
         column_result_fields: &[ColumnField],
     ) -> Result<Vec<S>, QueryError> {
-        assert_eq!(self.num_columns as usize, column_result_fields.len());
+        self.evaluate_with_decoders(
+            evaluation_point,
+            table_length,
+            column_result_fields,
+            &ColumnDecoderRegistry::default(),
+        )
+    }
+
+    /// Same as [`ProvableQueryResult::evaluate`], but decodes the columns with `decoders`.
+    pub fn evaluate_with_decoders<S: Scalar>(
+        &self,
+        evaluation_point: &[S],
+        table_length: usize,
+        column_result_fields: &[ColumnField],
+        decoders: &ColumnDecoderRegistry<S>,
+    ) -> Result<Vec<S>, QueryError> {
+        if column_result_fields.len() != self.num_columns() {
+            return Err(QueryError::ColumnCountMismatch {
+                expected: column_result_fields.len(),
+                actual: self.num_columns(),
+            });
+        }
 
         if !self.indexes.valid(table_length) {
             return Err(QueryError::InvalidIndexes);
@@ -103,22 +122,10 @@ impl ProvableQueryResult {
         let mut res = Vec::with_capacity(self.num_columns as usize);
 
         for field in column_result_fields {
+            let decoder = decoders.get(field.data_type())?;
             let mut val = S::zero();
             for index in self.indexes.iter() {
-                let (x, sz) = match field.data_type() {
-                    ColumnType::Boolean => decode_and_convert::<bool, S>(&self.data[offset..]),
-                    ColumnType::SmallInt => decode_and_convert::<i16, S>(&self.data[offset..]),
-                    ColumnType::Int => decode_and_convert::<i32, S>(&self.data[offset..]),
-                    ColumnType::BigInt => decode_and_convert::<i64, S>(&self.data[offset..]),
-                    ColumnType::Int128 => decode_and_convert::<i128, S>(&self.data[offset..]),
-                    ColumnType::Decimal75(_, _) => decode_and_convert::<S, S>(&self.data[offset..]),
-
-                    ColumnType::Scalar => decode_and_convert::<S, S>(&self.data[offset..]),
-                    ColumnType::VarChar => decode_and_convert::<&str, S>(&self.data[offset..]),
-                    ColumnType::TimestampTZ(_, _) => {
-                        decode_and_convert::<i64, S>(&self.data[offset..])
-                    }
-                }?;
+                let (x, sz) = decoder.decode_scalar(&self.data[offset..])?;
                 val += evaluation_vec[index as usize] * x;
                 offset += sz;
             }
@@ -134,72 +141,69 @@ impl ProvableQueryResult {
 
     /// Convert the intermediate query result into a final query result
     ///
-    /// The result is essentially an `OwnedTable` type.
+    /// The result is essentially an `OwnedTable` type. The decoded columns are matched to
+    /// `column_result_fields` by position, so the columns of the table appear in the declared
+    /// order. A result with no columns converts to an empty table.
     pub fn to_owned_table<S: Scalar>(
         &self,
         column_result_fields: &[ColumnField],
     ) -> Result<OwnedTable<S>, QueryError> {
-        assert_eq!(column_result_fields.len(), self.num_columns());
+        self.to_owned_table_with_decoders(column_result_fields, &ColumnDecoderRegistry::default())
+    }
+
+    /// Same as [`ProvableQueryResult::to_owned_table`], but decodes the columns with `decoders`.
+    pub fn to_owned_table_with_decoders<S: Scalar>(
+        &self,
+        column_result_fields: &[ColumnField],
+        decoders: &ColumnDecoderRegistry<S>,
+    ) -> Result<OwnedTable<S>, QueryError> {
+        if column_result_fields.len() != self.num_columns() {
+            return Err(QueryError::ColumnCountMismatch {
+                expected: column_result_fields.len(),
+                actual: self.num_columns(),
+            });
+        }
 
         let n = self.indexes.len();
         let mut offset: usize = 0;
 
+        let columns = column_result_fields
+            .iter()
+            .map(|field| {
+                let (column, num_read) = decoders.get(field.data_type())?.decode_column(
+                    &self.data[offset..],
+                    field.data_type(),
+                    n,
+                )?;
+                offset += num_read;
+                Ok(column)
+            })
+            .collect::<Result<Vec<_>, QueryError>>()?;
+
+        if offset != self.data.len() {
+            return Err(QueryError::MiscellaneousDecodingError);
+        }
+
         let owned_table = OwnedTable::try_new(
             column_result_fields
                 .iter()
-                .map(|field| match field.data_type() {
-                    ColumnType::Boolean => {
-                        let (col, num_read) = decode_multiple_elements(&self.data[offset..], n)?;
-                        offset += num_read;
-                        Ok((field.name(), OwnedColumn::Boolean(col)))
-                    }
-                    ColumnType::SmallInt => {
-                        let (col, num_read) = decode_multiple_elements(&self.data[offset..], n)?;
-                        offset += num_read;
-                        Ok((field.name(), OwnedColumn::SmallInt(col)))
-                    }
-                    ColumnType::Int => {
-                        let (col, num_read) = decode_multiple_elements(&self.data[offset..], n)?;
-                        offset += num_read;
-                        Ok((field.name(), OwnedColumn::Int(col)))
-                    }
-                    ColumnType::BigInt => {
-                        let (col, num_read) = decode_multiple_elements(&self.data[offset..], n)?;
-                        offset += num_read;
-                        Ok((field.name(), OwnedColumn::BigInt(col)))
-                    }
-                    ColumnType::Int128 => {
-                        let (col, num_read) = decode_multiple_elements(&self.data[offset..], n)?;
-                        offset += num_read;
-                        Ok((field.name(), OwnedColumn::Int128(col)))
-                    }
-                    ColumnType::VarChar => {
-                        let (col, num_read) = decode_multiple_elements(&self.data[offset..], n)?;
-                        offset += num_read;
-                        Ok((field.name(), OwnedColumn::VarChar(col)))
-                    }
-                    ColumnType::Scalar => {
-                        let (col, num_read) = decode_multiple_elements(&self.data[offset..], n)?;
-                        offset += num_read;
-                        Ok((field.name(), OwnedColumn::Scalar(col)))
-                    }
-                    ColumnType::Decimal75(precision, scale) => {
-                        let (col, num_read) = decode_multiple_elements(&self.data[offset..], n)?;
-                        offset += num_read;
-                        Ok((field.name(), OwnedColumn::Decimal75(precision, scale, col)))
-                    }
-                    ColumnType::TimestampTZ(tu, tz) => {
-                        let (col, num_read) = decode_multiple_elements(&self.data[offset..], n)?;
-                        offset += num_read;
-                        Ok((field.name(), OwnedColumn::TimestampTZ(tu, tz, col)))
-                    }
-                })
-                .collect::<Result<_, QueryError>>()?,
+                .map(ColumnField::name)
+                .zip(columns)
+                .collect(),
         )?;
 
-        assert_eq!(offset, self.data.len());
         assert_eq!(owned_table.num_columns(), self.num_columns());
 
         Ok(owned_table)
     }
+
+    /// Convert the intermediate query result into a final query result, consuming it
+    ///
+    /// See [`ProvableQueryResult::to_owned_table`].
+    pub fn into_owned_table<S: Scalar>(
+        self,
+        column_result_fields: &[ColumnField],
+    ) -> Result<OwnedTable<S>, QueryError> {
+        self.to_owned_table(column_result_fields)
+    }
 }
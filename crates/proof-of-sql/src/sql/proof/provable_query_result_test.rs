@@ -1,7 +1,10 @@
 use super::{ProvableQueryResult, ProvableResultColumn, QueryError};
 use crate::{
     base::{
-        database::{ColumnField, ColumnType},
+        database::{
+            owned_table_utility::{bigint, int128, owned_table, varchar},
+            ColumnField, ColumnType,
+        },
         math::decimal::Precision,
         polynomial::compute_evaluation_vector,
         scalar::{Curve25519Scalar, Scalar},
@@ -295,6 +298,27 @@ fn evaluation_fails_if_integer_overflow_happens() {
     ));
 }
 
+#[test]
+fn a_scalar_outside_the_i64_range_cannot_be_decoded_as_a_bigint() {
+    let indexes = Indexes::Sparse(vec![0]);
+    let values = [Curve25519Scalar::from(i64::MAX as i128 + 1)];
+    let cols: [Box<dyn ProvableResultColumn>; 1] = [Box::new(values)];
+    let res = ProvableQueryResult::new(&indexes, &cols);
+    let evaluation_point = [
+        Curve25519Scalar::from(10u64),
+        Curve25519Scalar::from(100u64),
+    ];
+    let column_fields = vec![ColumnField::new("a".parse().unwrap(), ColumnType::BigInt)];
+    assert!(matches!(
+        res.evaluate(&evaluation_point, 4, &column_fields[..]),
+        Err(QueryError::Overflow)
+    ));
+    assert!(matches!(
+        res.to_owned_table::<Curve25519Scalar>(&column_fields),
+        Err(QueryError::Overflow)
+    ));
+}
+
 #[test]
 fn evaluation_fails_if_data_is_missing() {
     let indexes = Indexes::Sparse(vec![0, 2]);
@@ -470,3 +494,104 @@ fn we_cannot_convert_a_provable_result_with_invalid_string_data() {
         .to_owned_table::<Curve25519Scalar>(&column_fields)
         .is_err());
 }
+
+#[test]
+fn we_can_convert_a_provable_result_to_a_final_result_with_columns_in_the_declared_order() {
+    let indexes = Indexes::Sparse(vec![0, 2]);
+    let values1: [i64; 3] = [10, 11, 12];
+    let values2: [&str; 3] = ["a", "b", "c"];
+    let values3: [i128; 3] = [7, 8, 9];
+    let cols: [Box<dyn ProvableResultColumn>; 3] =
+        [Box::new(values1), Box::new(values2), Box::new(values3)];
+    let res = ProvableQueryResult::new(&indexes, &cols);
+    let column_fields = vec![
+        ColumnField::new("c".parse().unwrap(), ColumnType::BigInt),
+        ColumnField::new("a".parse().unwrap(), ColumnType::VarChar),
+        ColumnField::new("b".parse().unwrap(), ColumnType::Int128),
+    ];
+    let res = res
+        .into_owned_table::<Curve25519Scalar>(&column_fields)
+        .unwrap();
+    let expected_res = owned_table([
+        bigint("c", [10, 12]),
+        varchar("a", ["a", "c"]),
+        int128("b", [7, 9]),
+    ]);
+    assert_eq!(res, expected_res);
+    assert_eq!(
+        res.inner_table().keys().copied().collect::<Vec<_>>(),
+        column_fields
+            .iter()
+            .map(ColumnField::name)
+            .collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn we_cannot_convert_a_provable_result_with_the_wrong_number_of_fields() {
+    let indexes = Indexes::Sparse(vec![0, 2]);
+    let values: [i64; 3] = [10, 11, 12];
+    let cols: [Box<dyn ProvableResultColumn>; 1] = [Box::new(values)];
+    let res = ProvableQueryResult::new(&indexes, &cols);
+    let column_fields = vec![
+        ColumnField::new("a".parse().unwrap(), ColumnType::BigInt),
+        ColumnField::new("b".parse().unwrap(), ColumnType::BigInt),
+    ];
+    assert!(matches!(
+        res.into_owned_table::<Curve25519Scalar>(&column_fields),
+        Err(QueryError::ColumnCountMismatch {
+            expected: 2,
+            actual: 1
+        })
+    ));
+}
+
+#[test]
+fn we_cannot_evaluate_a_provable_result_with_the_wrong_number_of_fields() {
+    let indexes = Indexes::Sparse(vec![0, 2]);
+    let values: [i64; 3] = [10, 11, 12];
+    let cols: [Box<dyn ProvableResultColumn>; 1] = [Box::new(values)];
+    let res = ProvableQueryResult::new(&indexes, &cols);
+    let evaluation_point = [
+        Curve25519Scalar::from(10u64),
+        Curve25519Scalar::from(100u64),
+    ];
+    assert!(matches!(
+        res.evaluate(&evaluation_point, 4, &[]),
+        Err(QueryError::ColumnCountMismatch {
+            expected: 0,
+            actual: 1
+        })
+    ));
+}
+
+#[test]
+fn we_can_evaluate_and_convert_a_provable_result_with_no_columns() {
+    let indexes = Indexes::Sparse(vec![0, 2]);
+    let cols: [Box<dyn ProvableResultColumn>; 0] = [];
+    let res = ProvableQueryResult::new(&indexes, &cols);
+    let evaluation_point = [
+        Curve25519Scalar::from(10u64),
+        Curve25519Scalar::from(100u64),
+    ];
+    assert_eq!(
+        res.evaluate(&evaluation_point, 4, &[]).unwrap(),
+        Vec::<Curve25519Scalar>::new()
+    );
+    let table = res.to_owned_table::<Curve25519Scalar>(&[]).unwrap();
+    assert!(table.is_empty());
+    assert_eq!(table.num_rows(), 0);
+}
+
+#[test]
+fn we_cannot_convert_a_provable_result_with_no_columns_but_trailing_data() {
+    let res = ProvableQueryResult::new_from_raw_data(0, Indexes::Sparse(vec![0]), vec![1, 2, 3]);
+    assert!(matches!(
+        res.to_owned_table::<Curve25519Scalar>(&[]),
+        Err(QueryError::MiscellaneousDecodingError)
+    ));
+    assert!(matches!(
+        res.evaluate(&[Curve25519Scalar::from(10u64)], 2, &[]),
+        Err(QueryError::MiscellaneousEvaluationError)
+    ));
+}
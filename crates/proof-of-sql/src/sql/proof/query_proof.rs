@@ -1,32 +1,77 @@
 use super::{
-    CountBuilder, ProofBuilder, ProofCounts, ProofExecutionPlan, ProvableQueryResult, QueryResult,
-    SumcheckMleEvaluations, SumcheckRandomScalars, VerificationBuilder,
+    CountBuilder, ProofCounts, ProofExecutionPlan, ProofHeader, ProofSizeLimits,
+    ProvableQueryResult, QueryProofOptions, QueryResult, SumcheckMleEvaluations,
+    SumcheckRandomScalars, VerificationBuilder, VerificationHash,
 };
+#[cfg(feature = "prover")]
+use super::{ProofBuilder, ResultBuilder};
+#[cfg(feature = "prover")]
+use crate::base::{database::DataAccessor, polynomial::compute_evaluation_vector, MleAllocator};
 use crate::{
     base::{
         bit::BitDistribution,
         commitment::{Commitment, CommitmentEvaluationProof},
-        database::{CommitmentAccessor, DataAccessor},
+        database::{CommitmentAccessor, MetadataAccessor},
         math::log2_up,
-        polynomial::{compute_evaluation_vector, CompositePolynomialInfo},
-        proof::{MessageLabel, ProofError, TranscriptProtocol},
+        polynomial::CompositePolynomialInfo,
+        proof::{MessageLabel, ProofError, TranscriptCore, TranscriptProtocol},
     },
-    proof_primitive::sumcheck::SumcheckProof,
-    sql::proof::{QueryData, ResultBuilder},
+    proof_primitive::sumcheck::{Subclaim, SumcheckProof, SumcheckRoundVerifier, SumcheckStrategy},
+    sql::proof::QueryData,
 };
+#[cfg(feature = "prover")]
 use bumpalo::Bump;
 use merlin::Transcript;
 use num_traits::Zero;
-use serde::{Deserialize, Serialize};
-use std::cmp;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use std::{
+    borrow::Borrow,
+    cmp,
+    io::{self, Read, Write},
+    marker::PhantomData,
+};
+
+/// The major version of the byte format written by [`QueryProof::to_bytes`].
+///
+/// Proofs with a different major version are never read.
+pub const QUERY_PROOF_FORMAT_MAJOR_VERSION: u16 = 1;
+/// The minor version of the byte format written by [`QueryProof::to_bytes`].
+pub const QUERY_PROOF_FORMAT_MINOR_VERSION: u16 = 1;
+/// The oldest minor version of the byte format that [`QueryProof::try_from_bytes`] reads.
+///
+/// Minor versions from this one up to [`QUERY_PROOF_FORMAT_MINOR_VERSION`] share the layout of
+/// the proof itself, so that a verifier can be upgraded while proofs from the previous minor
+/// versions are still in flight. A change to the layout must bump the major version, or raise
+/// this version past the minor versions it can no longer read.
+pub const MIN_SUPPORTED_QUERY_PROOF_FORMAT_MINOR_VERSION: u16 = 0;
+
+/// Enters a span around a phase of proving or verifying a query, and returns its guard.
+///
+/// Phase spans are only recorded with the `tracing-spans` feature, so that the fields are not
+/// computed otherwise.
+macro_rules! phase_span {
+    ($($args:tt)+) => {{
+        #[cfg(feature = "tracing-spans")]
+        let span = tracing::info_span!($($args)+);
+        #[cfg(not(feature = "tracing-spans"))]
+        let span = tracing::Span::none();
+        span.entered()
+    }};
+}
 
 /// The proof for a query.
 ///
 /// Note: Because the class is deserialized from untrusted data, it
 /// cannot maintain any invariant on its data members; hence, they are
 /// all public so as to allow for easy manipulation for testing.
+///
+/// The Fiat-Shamir transcript is built on the hash selected by `T`. This defaults to merlin,
+/// while [`Keccak256Transcript`](crate::base::proof::Keccak256Transcript) allows challenges to be
+/// recomputed cheaply on the EVM. A proof only verifies under the transcript it was created with.
 #[derive(Clone, Serialize, Deserialize)]
-pub struct QueryProof<CP: CommitmentEvaluationProof> {
+pub struct QueryProof<CP: CommitmentEvaluationProof, T: TranscriptCore = Transcript> {
+    /// The shape of the proof, which is checked before the rest of the proof
+    pub header: ProofHeader,
     /// Bit distributions
     pub bit_distributions: Vec<BitDistribution>,
     /// Commitments
@@ -37,29 +82,164 @@ pub struct QueryProof<CP: CommitmentEvaluationProof> {
     pub pcs_proof_evaluations: Vec<CP::Scalar>,
     /// Inner product proof of the MLEs' evaluations
     pub evaluation_proof: CP,
+    /// The transcript the proof was created with
+    #[serde(skip)]
+    pub transcript: PhantomData<T>,
 }
 
-impl<CP: CommitmentEvaluationProof> QueryProof<CP> {
+impl<CP: CommitmentEvaluationProof, T: TranscriptCore> QueryProof<CP, T> {
     /// Create a new `QueryProof`.
-    #[tracing::instrument(name = "QueryProof::new", level = "debug", skip_all)]
+    ///
+    /// With the `zeroize` feature, the allocator holding the witness data is zeroized before the
+    /// proof is returned. This only covers the columns the plan allocates: the copies of the
+    /// witness the prover makes on the heap, such as the MLEs of the sumcheck polynomial, the
+    /// folded MLE of the evaluation proof, and the evaluations of the MLEs, are freed without
+    /// being cleared.
+    #[cfg(feature = "prover")]
     pub fn new(
         expr: &(impl ProofExecutionPlan<CP::Commitment> + Serialize),
         accessor: &impl DataAccessor<CP::Scalar>,
         setup: &CP::ProverPublicSetup<'_>,
     ) -> (Self, ProvableQueryResult) {
+        Self::new_with_nonce(expr, accessor, setup, &[])
+    }
+
+    /// Create a new `QueryProof` with the witness data allocated into `alloc`.
+    ///
+    /// This allows a pooled or arena allocator tuned to the workload, or a counting allocator for
+    /// profiling, to be used instead of the default [`Bump`]. The allocator is not zeroized, even
+    /// with the `zeroize` feature, so clearing the witness data it holds is left to the caller.
+    #[cfg(feature = "prover")]
+    pub fn new_with_allocator(
+        expr: &(impl ProofExecutionPlan<CP::Commitment> + Serialize),
+        accessor: &impl DataAccessor<CP::Scalar>,
+        setup: &CP::ProverPublicSetup<'_>,
+        alloc: &dyn MleAllocator,
+    ) -> (Self, ProvableQueryResult) {
+        Self::create_with_allocator(expr, accessor, setup, &Default::default(), alloc)
+            .expect("the result is not limited")
+    }
+
+    /// Create a new `QueryProof` whose sumcheck proof uses `strategy`.
+    ///
+    /// Binding more variables per sumcheck round gives a larger proof with fewer rounds. The
+    /// strategy is recorded in the proof, so the verifier needs no extra input.
+    #[cfg(feature = "prover")]
+    pub fn new_with_sumcheck_strategy(
+        expr: &(impl ProofExecutionPlan<CP::Commitment> + Serialize),
+        accessor: &impl DataAccessor<CP::Scalar>,
+        setup: &CP::ProverPublicSetup<'_>,
+        strategy: SumcheckStrategy,
+    ) -> (Self, ProvableQueryResult) {
+        let options = QueryProofOptions {
+            sumcheck_strategy: strategy,
+            ..Default::default()
+        };
+        Self::new_with_options(expr, accessor, setup, &options).expect("the result is not limited")
+    }
+
+    /// Create a new `QueryProof`, unless the plan selects more than `max_result_rows` rows.
+    ///
+    /// This bounds the work of the prover and the size of the proof. The result is checked
+    /// before anything is committed to, and an oversized result fails with
+    /// [`ProofError::ResultTooLarge`].
+    #[cfg(feature = "prover")]
+    pub fn try_new_with_max_result_rows(
+        expr: &(impl ProofExecutionPlan<CP::Commitment> + Serialize),
+        accessor: &impl DataAccessor<CP::Scalar>,
+        setup: &CP::ProverPublicSetup<'_>,
+        max_result_rows: usize,
+    ) -> Result<(Self, ProvableQueryResult), ProofError> {
+        let options = QueryProofOptions {
+            max_result_rows: Some(max_result_rows),
+            ..Default::default()
+        };
+        Self::new_with_options(expr, accessor, setup, &options)
+    }
+
+    /// Create a new `QueryProof` bound to an application-supplied `nonce`.
+    ///
+    /// See [`QueryProofOptions::nonce`].
+    #[cfg(feature = "prover")]
+    pub fn new_with_nonce(
+        expr: &(impl ProofExecutionPlan<CP::Commitment> + Serialize),
+        accessor: &impl DataAccessor<CP::Scalar>,
+        setup: &CP::ProverPublicSetup<'_>,
+        nonce: &[u8],
+    ) -> (Self, ProvableQueryResult) {
+        let options = QueryProofOptions {
+            nonce,
+            ..Default::default()
+        };
+        Self::new_with_options(expr, accessor, setup, &options).expect("the result is not limited")
+    }
+
+    /// Create a new `QueryProof` with the given `options`.
+    ///
+    /// The proof only verifies with [`QueryProof::verify_with_options`] under the same nonce and
+    /// seed. This fails with [`ProofError::ResultTooLarge`] if the plan selects more rows than
+    /// [`QueryProofOptions::max_result_rows`]. With the `zeroize` feature, the allocator holding
+    /// the witness data is zeroized before the proof is returned, as with [`QueryProof::new`].
+    #[cfg(feature = "prover")]
+    pub fn new_with_options(
+        expr: &(impl ProofExecutionPlan<CP::Commitment> + Serialize),
+        accessor: &impl DataAccessor<CP::Scalar>,
+        setup: &CP::ProverPublicSetup<'_>,
+        options: &QueryProofOptions,
+    ) -> Result<(Self, ProvableQueryResult), ProofError> {
+        Self::create_with_bump(expr, accessor, setup, options, &mut Bump::new())
+    }
+
+    /// Create a `QueryProof` with the witness data allocated into `alloc`, which is zeroized
+    /// afterwards with the `zeroize` feature.
+    #[cfg(feature = "prover")]
+    pub(super) fn create_with_bump(
+        expr: &(impl ProofExecutionPlan<CP::Commitment> + Serialize),
+        accessor: &impl DataAccessor<CP::Scalar>,
+        setup: &CP::ProverPublicSetup<'_>,
+        options: &QueryProofOptions,
+        alloc: &mut Bump,
+    ) -> Result<(Self, ProvableQueryResult), ProofError> {
+        // the witness data is only referenced through the builders, which are dropped by now
+        let res = Self::create_with_allocator(expr, accessor, setup, options, alloc);
+        #[cfg(feature = "zeroize")]
+        zeroize_bump(alloc);
+        res
+    }
+
+    #[cfg(feature = "prover")]
+    #[tracing::instrument(name = "QueryProof::new", level = "debug", skip_all)]
+    fn create_with_allocator(
+        expr: &(impl ProofExecutionPlan<CP::Commitment> + Serialize),
+        accessor: &impl DataAccessor<CP::Scalar>,
+        setup: &CP::ProverPublicSetup<'_>,
+        options: &QueryProofOptions,
+        alloc: &dyn MleAllocator,
+    ) -> Result<(Self, ProvableQueryResult), ProofError> {
         let table_length = expr.get_length(accessor);
         let num_sumcheck_variables = cmp::max(log2_up(table_length), 1);
         let generator_offset = expr.get_offset(accessor);
         assert!(num_sumcheck_variables > 0);
 
-        let alloc = Bump::new();
+        let span = phase_span!("QueryProof::new::result_evaluation", table_length);
         let mut result_builder = ResultBuilder::new(table_length);
-        expr.result_evaluate(&mut result_builder, &alloc, accessor);
+        if let Some(max_result_rows) = options.max_result_rows {
+            result_builder.set_max_result_rows(max_result_rows);
+        }
+        expr.result_evaluate(&mut result_builder, alloc, accessor);
+        result_builder.check_result_size()?;
         let provable_result = result_builder.make_provable_query_result();
+        drop(span);
 
         // construct a transcript for the proof
-        let mut transcript: Transcript =
-            make_transcript(expr, &provable_result, table_length, generator_offset);
+        let mut transcript: T = make_transcript(
+            expr,
+            accessor,
+            &provable_result,
+            table_length,
+            generator_offset,
+            options,
+        );
 
         // These are the challenges that will be consumed by the proof
         // Specifically, these are the challenges that the verifier sends to
@@ -73,9 +253,15 @@ impl<CP: CommitmentEvaluationProof> QueryProof<CP> {
             MessageLabel::PostResultChallenges,
         );
 
+        let span = phase_span!(
+            "QueryProof::new::commitment",
+            table_length,
+            num_sumcheck_variables
+        );
         let mut builder =
             ProofBuilder::new(table_length, num_sumcheck_variables, post_result_challenges);
-        expr.prover_evaluate(&mut builder, &alloc, accessor);
+        builder.set_sumcheck_strategy(options.sumcheck_strategy);
+        expr.prover_evaluate(&mut builder, alloc, accessor);
 
         let num_sumcheck_variables = builder.num_sumcheck_variables();
         let table_length = builder.table_length();
@@ -84,9 +270,21 @@ impl<CP: CommitmentEvaluationProof> QueryProof<CP> {
         let commitments = builder.commit_intermediate_mles(generator_offset, setup);
 
         // add the commitments and bit distributions to the proof
-        extend_transcript(&mut transcript, &commitments, builder.bit_distributions());
+        extend_transcript(
+            &mut transcript,
+            &commitments,
+            builder.intermediate_mle_aliases(),
+            builder.bit_distributions(),
+        );
+        drop(span);
 
         // construct the sumcheck polynomial
+        let span = phase_span!(
+            "QueryProof::new::sumcheck",
+            table_length,
+            num_sumcheck_variables,
+            sumcheck_subpolynomials = builder.num_sumcheck_subpolynomials()
+        );
         let num_random_scalars = num_sumcheck_variables + builder.num_sumcheck_subpolynomials();
         let mut random_scalars = vec![Zero::zero(); num_random_scalars];
         transcript.challenge_scalars(&mut random_scalars, MessageLabel::QuerySumcheckChallenge);
@@ -98,9 +296,16 @@ impl<CP: CommitmentEvaluationProof> QueryProof<CP> {
 
         // create the sumcheck proof -- this is the main part of proving a query
         let mut evaluation_point = vec![Zero::zero(); poly.num_variables];
-        let sumcheck_proof = SumcheckProof::create(&mut transcript, &mut evaluation_point, &poly);
+        let sumcheck_proof = SumcheckProof::create_with_strategy(
+            &mut transcript,
+            &mut evaluation_point,
+            &poly,
+            builder.sumcheck_strategy(),
+        );
+        drop(span);
 
         // evaluate the MLEs used in sumcheck except for the result columns
+        let span = phase_span!("QueryProof::new::evaluation_proof", table_length);
         let mut evaluation_vec = vec![Zero::zero(); table_length];
         compute_evaluation_vector(&mut evaluation_vec, &evaluation_point);
         let pcs_proof_evaluations = builder.evaluate_pcs_proof_mles(&evaluation_vec);
@@ -119,25 +324,62 @@ impl<CP: CommitmentEvaluationProof> QueryProof<CP> {
         let folded_mle = builder.fold_pcs_proof_mles(&random_scalars);
 
         // finally, form the inner product proof of the MLEs' evaluations
-        let evaluation_proof = CP::new(
-            &mut transcript,
-            &folded_mle,
-            &evaluation_point,
-            generator_offset as u64,
-            setup,
-        );
+        let evaluation_proof = transcript.with_merlin_transcript(|transcript| {
+            CP::new(
+                transcript,
+                &folded_mle,
+                &evaluation_point,
+                generator_offset as u64,
+                setup,
+            )
+        });
+        drop(span);
+
+        let counts = ProofCounts {
+            sumcheck_max_multiplicands: poly.max_multiplicands,
+            result_columns: provable_result.num_columns(),
+            anchored_mles: pcs_proof_evaluations.len() - commitments.len(),
+            intermediate_mles: builder.num_intermediate_mles(),
+            sumcheck_subpolynomials: builder.num_sumcheck_subpolynomials(),
+            post_result_challenges: result_builder.num_post_result_challenges(),
+        };
+
+        #[cfg(feature = "self-check")]
+        self_check(expr, accessor, builder.bit_distributions(), &counts);
 
+        let mut header =
+            ProofHeader::new(table_length, &counts, MessageLabel::QueryProof.as_bytes());
+        header.intermediate_mle_aliases = builder.intermediate_mle_aliases().to_vec();
         let proof = Self {
+            header,
             bit_distributions: builder.bit_distributions().to_vec(),
             commitments,
             sumcheck_proof,
             pcs_proof_evaluations,
             evaluation_proof,
+            transcript: PhantomData,
         };
-        (proof, provable_result)
+
+        Ok((proof, provable_result))
+    }
+
+    /// Create a `QueryProof` and verify it against the same accessor, returning the verified
+    /// result.
+    ///
+    /// This is a convenience for checking that a plan round-trips, e.g. in tests. It offers no
+    /// assurance beyond what the prover already knows, since the prover supplies the commitments
+    /// it verifies against. Note: This does NOT transform the result!
+    #[cfg(feature = "prover")]
+    pub fn prove_and_verify(
+        expr: &(impl ProofExecutionPlan<CP::Commitment> + Serialize),
+        accessor: &(impl DataAccessor<CP::Scalar> + CommitmentAccessor<CP::Commitment>),
+        prover_setup: &CP::ProverPublicSetup<'_>,
+        verifier_setup: &CP::VerifierPublicSetup<'_>,
+    ) -> QueryResult<CP::Scalar> {
+        let (proof, result) = Self::new(expr, accessor, prover_setup);
+        proof.verify(expr, accessor, &result, verifier_setup)
     }
 
-    #[tracing::instrument(name = "QueryProof::verify", level = "debug", skip_all, err)]
     /// Verify a `QueryProof`. Note: This does NOT transform the result!
     pub fn verify(
         &self,
@@ -146,123 +388,524 @@ impl<CP: CommitmentEvaluationProof> QueryProof<CP> {
         result: &ProvableQueryResult,
         setup: &CP::VerifierPublicSetup<'_>,
     ) -> QueryResult<CP::Scalar> {
-        let table_length = expr.get_length(accessor);
-        let generator_offset = expr.get_offset(accessor);
-        let num_sumcheck_variables = cmp::max(log2_up(table_length), 1);
-        assert!(num_sumcheck_variables > 0);
+        self.verify_with_nonce(expr, accessor, result, setup, &[])
+    }
 
-        // validate bit decompositions
-        for dist in self.bit_distributions.iter() {
-            if !dist.is_valid() {
-                Err(ProofError::VerificationError("invalid bit distributions"))?;
-            }
-        }
+    /// Verify a `QueryProof` that was created with [`QueryProof::new_with_nonce`].
+    /// Note: This does NOT transform the result!
+    pub fn verify_with_nonce(
+        &self,
+        expr: &(impl ProofExecutionPlan<CP::Commitment> + Serialize),
+        accessor: &impl CommitmentAccessor<CP::Commitment>,
+        result: &ProvableQueryResult,
+        setup: &CP::VerifierPublicSetup<'_>,
+        nonce: &[u8],
+    ) -> QueryResult<CP::Scalar> {
+        let options = QueryProofOptions {
+            nonce,
+            ..Default::default()
+        };
+        self.verify_with_options(expr, accessor, result, setup, &options)
+    }
 
-        // count terms
-        let counts = {
-            let mut builder = CountBuilder::new(&self.bit_distributions);
-            expr.count(&mut builder, accessor)?;
-            builder.counts()
-        }?;
+    /// Verify a `QueryProof`, making the final accept/reject comparisons in constant time.
+    ///
+    /// This is for verifiers that an adversary can time, e.g. over a network. The evaluation of
+    /// the sumcheck polynomial is compared with the value it must have using
+    /// [`subtle::ConstantTimeEq`], and the [`VerificationHash`] of the result always compares in
+    /// constant time.
+    ///
+    /// The rest of verification still branches on the proof: the shape and size checks, the
+    /// checks of each sumcheck round, the checks that a plan makes of its result table, and the
+    /// verification of the evaluation proof, whose timing depends on the commitment scheme. A
+    /// rejection also returns as soon as the first failing check is found. Note: This does NOT
+    /// transform the result!
+    pub fn verify_constant_time(
+        &self,
+        expr: &(impl ProofExecutionPlan<CP::Commitment> + Serialize),
+        accessor: &impl CommitmentAccessor<CP::Commitment>,
+        result: &ProvableQueryResult,
+        setup: &CP::VerifierPublicSetup<'_>,
+    ) -> QueryResult<CP::Scalar> {
+        let options = QueryProofOptions {
+            constant_time: true,
+            ..Default::default()
+        };
+        self.verify_with_options(expr, accessor, result, setup, &options)
+    }
 
-        // verify sizes
-        if !self.validate_sizes(&counts, result) {
-            Err(ProofError::VerificationError("invalid proof size"))?;
-        }
+    /// Verify a `QueryProof` that was created with [`QueryProof::new_with_options`] under the
+    /// same nonce and seed. Note: This does NOT transform the result!
+    #[tracing::instrument(name = "QueryProof::verify", level = "debug", skip_all, err)]
+    pub fn verify_with_options(
+        &self,
+        expr: &(impl ProofExecutionPlan<CP::Commitment> + Serialize),
+        accessor: &impl CommitmentAccessor<CP::Commitment>,
+        result: &ProvableQueryResult,
+        setup: &CP::VerifierPublicSetup<'_>,
+        options: &QueryProofOptions,
+    ) -> QueryResult<CP::Scalar> {
+        let table_length = expr.get_length(accessor);
+        let num_sumcheck_variables = cmp::max(log2_up(table_length), 1);
+        let counts = count_terms(expr, accessor, &self.bit_distributions)?;
 
-        // construct a transcript for the proof
-        let mut transcript = make_transcript(expr, result, table_length, generator_offset);
+        // reject proofs of the wrong shape before doing any field arithmetic
+        self.header
+            .check(table_length, &counts, MessageLabel::QueryProof.as_bytes())?;
 
-        // These are the challenges that will be consumed by the proof
-        // Specifically, these are the challenges that the verifier sends to
-        // the prover after the prover sends the result, but before the prover
-        // send commitments to the intermediate witness columns.
-        // Note: the last challenge in the vec is the first one that is consumed.
-        let mut post_result_challenges = vec![Zero::zero(); counts.post_result_challenges];
-        transcript.challenge_scalars(
-            &mut post_result_challenges,
-            MessageLabel::PostResultChallenges,
-        );
-
-        // add the commitments and bit disctibutions to the proof
-        extend_transcript(&mut transcript, &self.commitments, &self.bit_distributions);
+        // reject oversized proofs before allocating anything based on their contents
+        self.validate_size_limits(
+            &options.size_limits,
+            &counts,
+            result,
+            table_length,
+            num_sumcheck_variables,
+        )?;
 
-        // draw the random scalars for sumcheck
-        let num_random_scalars = num_sumcheck_variables + counts.sumcheck_subpolynomials;
-        let mut random_scalars = vec![Zero::zero(); num_random_scalars];
-        transcript.challenge_scalars(&mut random_scalars, MessageLabel::QuerySumcheckChallenge);
-        let sumcheck_random_scalars =
-            SumcheckRandomScalars::new(&random_scalars, table_length, num_sumcheck_variables);
+        let prefix = ProofPrefix {
+            counts,
+            header: &self.header,
+            bit_distributions: &self.bit_distributions,
+            commitments: &self.commitments,
+        };
+        verify_parts::<CP, T, _, _>(
+            expr,
+            accessor,
+            result,
+            setup,
+            options,
+            prefix,
+            |transcript, poly_info| {
+                let subclaim = self.sumcheck_proof.verify_without_evaluation(
+                    transcript,
+                    poly_info,
+                    &Zero::zero(),
+                )?;
+                Ok((
+                    subclaim,
+                    &self.pcs_proof_evaluations[..],
+                    &self.evaluation_proof,
+                ))
+            },
+        )
+    }
 
-        // verify sumcheck up to the evaluation check
+    /// Checks that no component of the proof exceeds `limits` or the bounds implied by `counts`.
+    ///
+    /// Unlike the exact size checks made during verification, this only rejects proofs that are
+    /// larger than any honest proof of the query could be, so that a malicious prover cannot
+    /// exhaust the verifier's memory.
+    fn validate_size_limits(
+        &self,
+        limits: &ProofSizeLimits,
+        counts: &ProofCounts,
+        result: &ProvableQueryResult,
+        table_length: usize,
+        num_sumcheck_variables: usize,
+    ) -> Result<(), ProofError> {
+        limits.check_commitments(
+            counts,
+            self.commitments.len(),
+            self.header.intermediate_mle_aliases.len(),
+        )?;
+        limits.check_mle_evaluations(counts, self.pcs_proof_evaluations.len())?;
         let poly_info = CompositePolynomialInfo {
-            // This needs to be at least 2 since `CompositePolynomialBuilder::make_composite_polynomial`
-            // always adds a degree 2 term.
             max_multiplicands: core::cmp::max(counts.sumcheck_max_multiplicands, 2),
             num_variables: num_sumcheck_variables,
         };
-        let subclaim = self.sumcheck_proof.verify_without_evaluation(
-            &mut transcript,
-            poly_info,
-            &Zero::zero(),
-        )?;
+        if !self.sumcheck_proof.is_within_bounds(&poly_info) {
+            return Err(ProofError::ProofTooLarge("too many sumcheck evaluations"));
+        }
+        limits.check_result_rows(result.indexes().len(), table_length)
+    }
+}
 
-        // commit to mle evaluations
-        transcript.append_canonical_serialize(
-            MessageLabel::QueryMleEvaluations,
-            &self.pcs_proof_evaluations,
-        );
+impl<CP: CommitmentEvaluationProof + Serialize, T: TranscriptCore> QueryProof<CP, T> {
+    /// Write the proof in the format read by [`QueryProof::verify_streaming`].
+    ///
+    /// Each component of the proof, and each round of the sumcheck proof, is written as its
+    /// length in bytes followed by its postcard serialization.
+    pub fn write_streaming(&self, writer: &mut impl Write) -> io::Result<()> {
+        write_frame(writer, &self.header)?;
+        write_frame(writer, &self.bit_distributions)?;
+        write_frame(writer, &self.commitments)?;
+        write_frame(writer, &self.sumcheck_proof.strategy())?;
+        for round_evaluation in self.sumcheck_proof.round_evaluations() {
+            write_frame(writer, round_evaluation)?;
+        }
+        write_frame(writer, &self.pcs_proof_evaluations)?;
+        write_frame(writer, &self.evaluation_proof)
+    }
+}
 
-        // draw the random scalars for the evaluation proof
-        // (i.e. the folding/random linear combination of the pcs_proof_mles)
-        let mut evaluation_random_scalars = vec![Zero::zero(); self.pcs_proof_evaluations.len()];
-        transcript.challenge_scalars(
-            &mut evaluation_random_scalars,
-            MessageLabel::QueryMleEvaluationsChallenge,
-        );
+impl<CP: CommitmentEvaluationProof + Serialize, T: TranscriptCore> QueryProof<CP, T> {
+    /// Serialize the proof in the format read by [`QueryProof::try_from_bytes`].
+    ///
+    /// The bytes start with the major and minor format versions, each as a little-endian `u16`,
+    /// followed by the postcard serialization of the proof.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        bytes.extend_from_slice(&QUERY_PROOF_FORMAT_MAJOR_VERSION.to_le_bytes());
+        bytes.extend_from_slice(&QUERY_PROOF_FORMAT_MINOR_VERSION.to_le_bytes());
+        bytes.extend(postcard::to_allocvec(self).expect("serializing a proof cannot fail"));
+        bytes
+    }
+}
 
-        let column_result_fields = expr.get_column_result_fields();
+impl<CP: CommitmentEvaluationProof + DeserializeOwned, T: TranscriptCore> QueryProof<CP, T> {
+    /// Deserialize a proof written by [`QueryProof::to_bytes`].
+    ///
+    /// Proofs are accepted when their major version is [`QUERY_PROOF_FORMAT_MAJOR_VERSION`] and
+    /// their minor version is between [`MIN_SUPPORTED_QUERY_PROOF_FORMAT_MINOR_VERSION`] and
+    /// [`QUERY_PROOF_FORMAT_MINOR_VERSION`]. Any other version is rejected with
+    /// [`ProofError::UnsupportedVersion`].
+    pub fn try_from_bytes(bytes: &[u8]) -> Result<Self, ProofError> {
+        if bytes.len() < 4 {
+            return Err(ProofError::VerificationError("proof bytes are truncated"));
+        }
+        let major = u16::from_le_bytes([bytes[0], bytes[1]]);
+        let minor = u16::from_le_bytes([bytes[2], bytes[3]]);
+        if major != QUERY_PROOF_FORMAT_MAJOR_VERSION
+            || !(MIN_SUPPORTED_QUERY_PROOF_FORMAT_MINOR_VERSION..=QUERY_PROOF_FORMAT_MINOR_VERSION)
+                .contains(&minor)
+        {
+            return Err(ProofError::UnsupportedVersion { major, minor });
+        }
+        postcard::from_bytes(&bytes[4..])
+            .map_err(|_| ProofError::VerificationError("invalid proof bytes"))
+    }
 
-        // compute the evaluation of the result MLEs
-        let result_evaluations = result.evaluate(
-            &subclaim.evaluation_point,
-            table_length,
-            &column_result_fields[..],
+    /// Verify a proof that is read from `reader` as it is needed, rather than held in memory.
+    ///
+    /// The proof must be in the format written by [`QueryProof::write_streaming`]. The sumcheck
+    /// rounds are verified one at a time as they are read. This produces the same result as
+    /// [`QueryProof::verify`], and rejects components longer than the default
+    /// [`ProofSizeLimits::max_frame_length`]. Note: This does NOT transform the result!
+    pub fn verify_streaming(
+        reader: &mut impl Read,
+        expr: &(impl ProofExecutionPlan<CP::Commitment> + Serialize),
+        accessor: &impl CommitmentAccessor<CP::Commitment>,
+        result: &ProvableQueryResult,
+        setup: &CP::VerifierPublicSetup<'_>,
+    ) -> QueryResult<CP::Scalar> {
+        Self::verify_streaming_with_options(
+            reader,
+            expr,
+            accessor,
+            result,
+            setup,
+            &Default::default(),
+        )
+    }
+
+    /// Verify a streamed proof, as with [`QueryProof::verify_streaming`], that was created with
+    /// [`QueryProof::new_with_options`] under the same nonce and seed.
+    /// Note: This does NOT transform the result!
+    #[tracing::instrument(name = "QueryProof::verify_streaming", level = "debug", skip_all, err)]
+    pub fn verify_streaming_with_options(
+        reader: &mut impl Read,
+        expr: &(impl ProofExecutionPlan<CP::Commitment> + Serialize),
+        accessor: &impl CommitmentAccessor<CP::Commitment>,
+        result: &ProvableQueryResult,
+        setup: &CP::VerifierPublicSetup<'_>,
+        options: &QueryProofOptions,
+    ) -> QueryResult<CP::Scalar> {
+        let limits = &options.size_limits;
+        let table_length = expr.get_length(accessor);
+        limits.check_result_rows(result.indexes().len(), table_length)?;
+        let header: ProofHeader = read_frame(reader, limits)?;
+        let bit_distributions: Vec<BitDistribution> = read_frame(reader, limits)?;
+        let counts = count_terms(expr, accessor, &bit_distributions)?;
+        header.check(table_length, &counts, MessageLabel::QueryProof.as_bytes())?;
+        let commitments: Vec<CP::Commitment> = read_frame(reader, limits)?;
+        limits.check_commitments(
+            &counts,
+            commitments.len(),
+            header.intermediate_mle_aliases.len(),
         )?;
+        let prefix = ProofPrefix {
+            counts,
+            header: &header,
+            bit_distributions: &bit_distributions,
+            commitments: &commitments,
+        };
+        verify_parts::<CP, T, _, _>(
+            expr,
+            accessor,
+            result,
+            setup,
+            options,
+            prefix,
+            |transcript, poly_info| {
+                let strategy: SumcheckStrategy = read_frame(reader, limits)?;
+                let num_rounds = strategy.num_rounds(poly_info.num_variables);
+                let mut verifier = SumcheckRoundVerifier::<CP::Scalar>::new(
+                    transcript,
+                    poly_info,
+                    &Zero::zero(),
+                    strategy,
+                );
+                for _ in 0..num_rounds {
+                    let round_evaluation: Vec<CP::Scalar> = read_frame(reader, limits)?;
+                    verifier.verify_round(transcript, &round_evaluation)?;
+                }
+                let subclaim = verifier.finish()?;
+                let pcs_proof_evaluations: Vec<CP::Scalar> = read_frame(reader, limits)?;
+                limits.check_mle_evaluations(&counts, pcs_proof_evaluations.len())?;
+                let evaluation_proof: CP = read_frame(reader, limits)?;
+                Ok((subclaim, pcs_proof_evaluations, evaluation_proof))
+            },
+        )
+    }
+}
 
-        // pass over the provable AST to fill in the verification builder
-        let sumcheck_evaluations = SumcheckMleEvaluations::new(
-            table_length,
-            &subclaim.evaluation_point,
-            &sumcheck_random_scalars,
-            &self.pcs_proof_evaluations,
-            &result_evaluations,
-            result.indexes(),
-        );
-        let mut builder = VerificationBuilder::new(
-            generator_offset,
-            sumcheck_evaluations,
-            &self.bit_distributions,
-            &self.commitments,
-            sumcheck_random_scalars.subpolynomial_multipliers,
-            &evaluation_random_scalars,
-            post_result_challenges,
-        );
-        let owned_table_result = result.to_owned_table(&column_result_fields[..])?;
-        expr.verifier_evaluate(&mut builder, accessor, Some(&owned_table_result))?;
-
-        // perform the evaluation check of the sumcheck polynomial
-        if builder.sumcheck_evaluation() != subclaim.expected_evaluation {
-            Err(ProofError::VerificationError(
-                "sumcheck evaluation check failed",
-            ))?;
+/// Validates the bit distributions of a proof and counts the terms it should contain.
+fn count_terms<C: Commitment>(
+    expr: &impl ProofExecutionPlan<C>,
+    accessor: &dyn MetadataAccessor,
+    bit_distributions: &[BitDistribution],
+) -> Result<ProofCounts, ProofError> {
+    let _span = phase_span!(
+        "QueryProof::verify::count",
+        bit_distributions = bit_distributions.len()
+    );
+    // validate bit decompositions
+    for dist in bit_distributions.iter() {
+        if !dist.is_valid() {
+            Err(ProofError::VerificationError("invalid bit distributions"))?;
+        }
+    }
+
+    let mut builder = CountBuilder::new(bit_distributions);
+    expr.count(&mut builder, accessor)?;
+    builder.counts()
+}
+
+/// Checks that a just-created proof has the components the verifier will expect of it, panicking
+/// with the differing counts if it does not.
+///
+/// A plan whose `prover_evaluate` disagrees with its `count` produces proofs that never verify.
+/// This catches such a plan when proving rather than at a later call to verify.
+#[cfg(all(feature = "prover", feature = "self-check"))]
+fn self_check<C: Commitment>(
+    expr: &impl ProofExecutionPlan<C>,
+    accessor: &dyn MetadataAccessor,
+    bit_distributions: &[BitDistribution],
+    produced: &ProofCounts,
+) {
+    let mut builder = CountBuilder::new(bit_distributions);
+    let expected = expr
+        .count(&mut builder, accessor)
+        .and_then(|()| builder.counts())
+        .unwrap_or_else(|err| panic!("proof self-check failed: cannot count the plan: {err}"));
+    let expected = ProofCounts {
+        sumcheck_max_multiplicands: cmp::max(expected.sumcheck_max_multiplicands, 2),
+        ..expected
+    };
+    assert_eq!(
+        *produced, expected,
+        "proof self-check failed: the proof does not have the counts of its plan"
+    );
+}
+
+/// Overwrites every chunk of `alloc` with zeros, so that the witness data allocated while proving
+/// does not remain in memory after the proof is created.
+#[cfg(all(feature = "prover", feature = "zeroize"))]
+fn zeroize_bump(alloc: &mut Bump) {
+    use zeroize::Zeroize;
+    // SAFETY: `alloc` is borrowed mutably, so nothing else references its chunks, and each chunk
+    // is valid for writes of its length.
+    unsafe {
+        for (ptr, len) in alloc.iter_allocated_chunks_raw() {
+            core::slice::from_raw_parts_mut(ptr, len).zeroize();
         }
+    }
+}
+
+/// The components of a proof that precede its sumcheck proof, along with the counts of the terms
+/// the proof should contain.
+struct ProofPrefix<'a, C> {
+    counts: ProofCounts,
+    header: &'a ProofHeader,
+    bit_distributions: &'a [BitDistribution],
+    commitments: &'a [C],
+}
+
+/// Verifies a proof given its components.
+///
+/// The sumcheck proof is verified by `verify_sumcheck`, which also provides the components that
+/// follow it, so that those can be read after the sumcheck proof.
+fn verify_parts<
+    CP: CommitmentEvaluationProof,
+    T: TranscriptCore,
+    E: Borrow<[CP::Scalar]>,
+    P: Borrow<CP>,
+>(
+    expr: &(impl ProofExecutionPlan<CP::Commitment> + Serialize),
+    accessor: &impl CommitmentAccessor<CP::Commitment>,
+    result: &ProvableQueryResult,
+    setup: &CP::VerifierPublicSetup<'_>,
+    options: &QueryProofOptions,
+    prefix: ProofPrefix<'_, CP::Commitment>,
+    verify_sumcheck: impl FnOnce(
+        &mut T,
+        CompositePolynomialInfo,
+    ) -> Result<(Subclaim<CP::Scalar>, E, P), ProofError>,
+) -> QueryResult<CP::Scalar> {
+    let ProofPrefix {
+        counts,
+        header,
+        bit_distributions,
+        commitments,
+    } = prefix;
+    let table_length = expr.get_length(accessor);
+    let generator_offset = expr.get_offset(accessor);
+    let num_sumcheck_variables = cmp::max(log2_up(table_length), 1);
+    assert!(num_sumcheck_variables > 0);
+
+    // verify sizes
+    let intermediate_mle_aliases =
+        header.resolve_intermediate_mle_aliases(counts.intermediate_mles)?;
+    let num_aliases = header.intermediate_mle_aliases.len();
+    if result.num_columns() != counts.result_columns
+        || commitments.len() + num_aliases != counts.intermediate_mles
+    {
+        Err(ProofError::VerificationError("invalid proof size"))?;
+    }
+
+    // the result MLE evaluation assumes each index names a distinct row of the table
+    if !result.indexes().valid(table_length) {
+        Err(ProofError::InvalidIndexes)?;
+    }
+
+    // construct a transcript for the proof
+    let mut transcript: T = make_transcript(
+        expr,
+        accessor,
+        result,
+        table_length,
+        generator_offset,
+        options,
+    );
+
+    // These are the challenges that will be consumed by the proof
+    // Specifically, these are the challenges that the verifier sends to
+    // the prover after the prover sends the result, but before the prover
+    // send commitments to the intermediate witness columns.
+    // Note: the last challenge in the vec is the first one that is consumed.
+    let mut post_result_challenges = vec![Zero::zero(); counts.post_result_challenges];
+    transcript.challenge_scalars(
+        &mut post_result_challenges,
+        MessageLabel::PostResultChallenges,
+    );
 
-        // finally, check the MLE evaluations with the inner product proof
-        let product = builder.folded_pcs_proof_evaluation();
-        self.evaluation_proof
-            .verify_batched_proof(
-                &mut transcript,
+    // add the commitments and bit disctibutions to the proof
+    extend_transcript(
+        &mut transcript,
+        commitments,
+        &header.intermediate_mle_aliases,
+        bit_distributions,
+    );
+
+    // draw the random scalars for sumcheck
+    let num_random_scalars = num_sumcheck_variables + counts.sumcheck_subpolynomials;
+    let mut random_scalars = vec![Zero::zero(); num_random_scalars];
+    transcript.challenge_scalars(&mut random_scalars, MessageLabel::QuerySumcheckChallenge);
+    let sumcheck_random_scalars =
+        SumcheckRandomScalars::new(&random_scalars, table_length, num_sumcheck_variables);
+
+    // verify sumcheck up to the evaluation check
+    let poly_info = CompositePolynomialInfo {
+        // This needs to be at least 2 since `CompositePolynomialBuilder::make_composite_polynomial`
+        // always adds a degree 2 term.
+        max_multiplicands: core::cmp::max(counts.sumcheck_max_multiplicands, 2),
+        num_variables: num_sumcheck_variables,
+    };
+    let span = phase_span!(
+        "QueryProof::verify::sumcheck",
+        table_length,
+        num_sumcheck_variables,
+        sumcheck_subpolynomials = counts.sumcheck_subpolynomials,
+        intermediate_mles = counts.intermediate_mles,
+        anchored_mles = counts.anchored_mles
+    );
+    let (subclaim, pcs_proof_evaluations, evaluation_proof) =
+        verify_sumcheck(&mut transcript, poly_info)?;
+    drop(span);
+    let pcs_proof_evaluations: &[CP::Scalar] = pcs_proof_evaluations.borrow();
+    if pcs_proof_evaluations.len() + num_aliases != counts.intermediate_mles + counts.anchored_mles
+    {
+        Err(ProofError::VerificationError("invalid proof size"))?;
+    }
+
+    // commit to mle evaluations
+    transcript.append_canonical_serialize(MessageLabel::QueryMleEvaluations, pcs_proof_evaluations);
+
+    // draw the random scalars for the evaluation proof
+    // (i.e. the folding/random linear combination of the pcs_proof_mles)
+    let mut evaluation_random_scalars = vec![Zero::zero(); pcs_proof_evaluations.len()];
+    transcript.challenge_scalars(
+        &mut evaluation_random_scalars,
+        MessageLabel::QueryMleEvaluationsChallenge,
+    );
+
+    let column_result_fields = expr.get_column_result_fields();
+
+    // compute the evaluation of the result MLEs
+    let span = phase_span!(
+        "QueryProof::verify::result_evaluation",
+        table_length,
+        result_columns = counts.result_columns
+    );
+    let result_evaluations = result.evaluate(
+        &subclaim.evaluation_point,
+        table_length,
+        &column_result_fields[..],
+    )?;
+    drop(span);
+
+    // pass over the provable AST to fill in the verification builder
+    let _span = phase_span!("QueryProof::verify::final_checks", table_length);
+    let sumcheck_evaluations = SumcheckMleEvaluations::new(
+        table_length,
+        &subclaim.evaluation_point,
+        &sumcheck_random_scalars,
+        pcs_proof_evaluations,
+        &result_evaluations,
+        result.indexes(),
+    );
+    let mut builder = VerificationBuilder::new(
+        generator_offset,
+        sumcheck_evaluations,
+        bit_distributions,
+        commitments,
+        sumcheck_random_scalars.subpolynomial_multipliers,
+        &evaluation_random_scalars,
+        post_result_challenges,
+    );
+    builder.set_intermediate_mle_aliases(intermediate_mle_aliases);
+    builder.set_constant_time(options.constant_time);
+    let owned_table_result = result.to_owned_table(&column_result_fields[..])?;
+    expr.verifier_evaluate(&mut builder, accessor, Some(&owned_table_result))?;
+    builder.check_intermediate_mle_aliases()?;
+    builder.check_column_lengths(accessor)?;
+
+    // perform the evaluation check of the sumcheck polynomial
+    if !builder.evaluations_match(
+        &builder.sumcheck_evaluation(),
+        &subclaim.expected_evaluation,
+    ) {
+        Err(ProofError::VerificationError(
+            "sumcheck evaluation check failed",
+        ))?;
+    }
+
+    // finally, check the MLE evaluations with the inner product proof
+    let product = builder.folded_pcs_proof_evaluation();
+    transcript
+        .with_merlin_transcript(|transcript| {
+            evaluation_proof.borrow().verify_batched_proof(
+                transcript,
                 builder.pcs_proof_commitments(),
                 builder.inner_product_multipliers(),
                 &product,
@@ -271,29 +914,61 @@ impl<CP: CommitmentEvaluationProof> QueryProof<CP> {
                 table_length,
                 setup,
             )
-            .map_err(|_e| {
-                ProofError::VerificationError("Inner product proof of MLE evaluations failed")
-            })?;
-
-        let mut verification_hash = [0u8; 32];
-        transcript.challenge_bytes(
-            MessageLabel::VerificationHash.as_bytes(),
-            &mut verification_hash,
-        );
-        Ok(QueryData {
-            table: owned_table_result,
-            verification_hash,
         })
-    }
+        .map_err(|_e| match builder.mismatched_column() {
+            Some(column) => ProofError::CommitmentMismatch { column },
+            None => ProofError::VerificationError("Inner product proof of MLE evaluations failed"),
+        })?;
 
-    fn validate_sizes(&self, counts: &ProofCounts, result: &ProvableQueryResult) -> bool {
-        result.num_columns() == counts.result_columns
-            && self.commitments.len() == counts.intermediate_mles
-            && self.pcs_proof_evaluations.len() == counts.intermediate_mles + counts.anchored_mles
+    Ok(QueryData {
+        table: owned_table_result,
+        verification_hash: VerificationHash::from_proof(&mut transcript),
+    })
+}
+
+/// Writes `value` as its length in bytes followed by its postcard serialization.
+fn write_frame(writer: &mut impl Write, value: &(impl Serialize + ?Sized)) -> io::Result<()> {
+    let bytes = postcard::to_allocvec(value).map_err(|_| {
+        io::Error::new(
+            io::ErrorKind::InvalidData,
+            "failed to serialize proof component",
+        )
+    })?;
+    writer.write_all(&(bytes.len() as u64).to_le_bytes())?;
+    writer.write_all(&bytes)
+}
+
+/// Reads a value written by [`write_frame`].
+///
+/// Frames longer than [`ProofSizeLimits::max_frame_length`] are rejected before anything is read.
+fn read_frame<T: DeserializeOwned>(
+    reader: &mut impl Read,
+    limits: &ProofSizeLimits,
+) -> Result<T, ProofError> {
+    let mut len = [0u8; 8];
+    reader
+        .read_exact(&mut len)
+        .map_err(|_| ProofError::VerificationError("streamed proof is truncated"))?;
+    let len = u64::from_le_bytes(len);
+    if len > limits.max_frame_length as u64 {
+        return Err(ProofError::ProofTooLarge(
+            "streamed proof component is too long",
+        ));
+    }
+    let mut bytes = Vec::new();
+    reader
+        .by_ref()
+        .take(len)
+        .read_to_end(&mut bytes)
+        .map_err(|_| ProofError::VerificationError("streamed proof is truncated"))?;
+    if bytes.len() as u64 != len {
+        return Err(ProofError::VerificationError("streamed proof is truncated"));
     }
+    postcard::from_bytes(&bytes)
+        .map_err(|_| ProofError::VerificationError("invalid streamed proof component"))
 }
 
-/// Creates a transcript using the Merlin library.
+/// Creates a transcript using the hash selected by `T`.
 ///
 /// This function is used to produce a transcript for a proof expression
 /// and a provable query result, along with additional parameters like
@@ -305,6 +980,8 @@ impl<CP: CommitmentEvaluationProof> QueryProof<CP> {
 /// * `expr` - A reference to an object that implements `ProofExecutionPlan` and `Serialize`.
 ///   This is the proof expression which is part of the proof.
 ///
+/// * `accessor` - The accessor the epochs of the columns referenced by `expr` are read from.
+///
 /// * `result` - A reference to a `ProvableQueryResult`, which is the result
 ///   of a query that needs to be proven.
 ///
@@ -312,29 +989,57 @@ impl<CP: CommitmentEvaluationProof> QueryProof<CP> {
 ///
 /// * `generator_offset` - The offset of the generator used in the proof, as a `usize`.
 ///
+/// * `options` - The options holding the nonce and the seed the transcript is bound to.
+///
 /// # Returns
-/// This function returns a `merlin::Transcript`. The transcript is a record
+/// This function returns a transcript of type `T`. The transcript is a record
 /// of all the operations and data involved in creating a proof.
 /// ```
-fn make_transcript<C: Commitment>(
+fn make_transcript<C: Commitment, T: TranscriptCore>(
     expr: &(impl ProofExecutionPlan<C> + Serialize),
+    accessor: &dyn MetadataAccessor,
     result: &ProvableQueryResult,
     table_length: usize,
     generator_offset: usize,
-) -> merlin::Transcript {
-    let mut transcript = Transcript::new(MessageLabel::QueryProof.as_bytes());
+    options: &QueryProofOptions,
+) -> T {
+    let mut transcript = T::new(MessageLabel::QueryProof.as_bytes());
+    if let Some(seed) = options.transcript_seed() {
+        transcript.append_auto(MessageLabel::TranscriptSeed, &seed);
+    }
     transcript.append_auto(MessageLabel::QueryResultData, result);
     transcript.append_auto(MessageLabel::ProofExecutionPlan, expr);
+    let column_references = expr.get_canonical_column_references();
+    transcript.append_auto(MessageLabel::ColumnReferences, &column_references);
+    // proofs of unversioned columns keep the transcript they had before epochs were introduced
+    let column_epochs: Vec<Option<u64>> = column_references
+        .iter()
+        .map(|&column| accessor.column_epoch(column))
+        .collect();
+    if column_epochs.iter().any(Option::is_some) {
+        transcript.append_auto(MessageLabel::ColumnEpochs, &column_epochs);
+    }
     transcript.append_auto(MessageLabel::TableLength, &table_length);
     transcript.append_auto(MessageLabel::GeneratorOffset, &generator_offset);
+    if !options.nonce.is_empty() {
+        transcript.append_auto(MessageLabel::QueryNonce, &options.nonce);
+    }
     transcript
 }
 
 fn extend_transcript<C: serde::Serialize>(
-    transcript: &mut Transcript,
+    transcript: &mut impl TranscriptProtocol,
     commitments: &C,
+    intermediate_mle_aliases: &[(u64, u64)],
     bit_distributions: &[BitDistribution],
 ) {
     transcript.append_auto(MessageLabel::QueryCommit, commitments);
+    // proofs without aliases keep the transcript they had before aliases were introduced
+    if !intermediate_mle_aliases.is_empty() {
+        transcript.append_auto(
+            MessageLabel::IntermediateMleAliases,
+            &intermediate_mle_aliases,
+        );
+    }
     transcript.append_auto(MessageLabel::QueryBitDistributions, bit_distributions);
 }
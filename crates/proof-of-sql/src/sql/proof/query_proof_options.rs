@@ -0,0 +1,124 @@
+use super::ProofCounts;
+use crate::{base::proof::ProofError, proof_primitive::sumcheck::SumcheckStrategy};
+
+/// Options for creating and verifying a [`QueryProof`](super::QueryProof).
+///
+/// The prover and the verifier of a proof are expected to use the same options. Each option
+/// that only applies to one of them is ignored by the other.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct QueryProofOptions<'a> {
+    /// An application-supplied nonce the proof is bound to.
+    ///
+    /// The nonce is absorbed into the transcript before any challenge is drawn, so the proof only
+    /// verifies under the same nonce. This prevents a proof from being replayed in another
+    /// context. An empty nonce is equivalent to no nonce.
+    pub nonce: &'a [u8],
+    /// A seed, shared by every party, that the transcript starts from.
+    ///
+    /// In multi-party proving, a coordinator can hand the same seed to every prover and to the
+    /// verifier. The seed is absorbed before anything else, so every challenge of the proof
+    /// depends on it. Unlike a nonce, the seed fixes the starting state of the transcript rather
+    /// than binding the proof to an application context; the two can be used together.
+    ///
+    /// With the `deterministic` feature, a seed installed with
+    /// [`with_sumcheck_seed`](super::with_sumcheck_seed) is used when this is `None`.
+    pub seed: Option<[u8; 32]>,
+    /// The strategy of the sumcheck proof. Only used by the prover, as the strategy is recorded
+    /// in the proof.
+    pub sumcheck_strategy: SumcheckStrategy,
+    /// The maximum number of rows the plan may select. Only used by the prover, which fails with
+    /// [`ProofError::ResultTooLarge`](crate::base::proof::ProofError::ResultTooLarge) before
+    /// committing to anything when the result is larger.
+    pub max_result_rows: Option<usize>,
+    /// Whether the final accept/reject comparisons are made in constant time. Only used by the
+    /// verifier; see [`QueryProof::verify_constant_time`](super::QueryProof::verify_constant_time).
+    pub constant_time: bool,
+    /// The limits on the size of the proofs the verifier accepts. Only used by the verifier.
+    pub size_limits: ProofSizeLimits,
+}
+
+impl QueryProofOptions<'_> {
+    /// The seed the transcript starts from, if any
+    pub(super) fn transcript_seed(&self) -> Option<[u8; 32]> {
+        #[cfg(feature = "deterministic")]
+        if self.seed.is_none() {
+            return super::sumcheck_seed::current_sumcheck_seed();
+        }
+        self.seed
+    }
+}
+
+/// Limits on the size of the proofs a verifier accepts.
+///
+/// A proof is always rejected with [`ProofError::ProofTooLarge`] if it has more components than
+/// any honest proof of the query could have. These limits additionally cap the absolute size of
+/// a proof, before anything is allocated based on its contents.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ProofSizeLimits {
+    /// The maximum number of commitments to intermediate MLEs
+    pub max_commitments: usize,
+    /// The maximum number of MLE evaluations
+    pub max_mle_evaluations: usize,
+    /// The maximum number of rows in the result
+    pub max_result_rows: usize,
+    /// The maximum length in bytes of each component of a streamed proof
+    pub max_frame_length: usize,
+}
+
+impl Default for ProofSizeLimits {
+    /// No limits beyond the ones implied by the query, except for a 64 MiB cap on the components
+    /// of a streamed proof.
+    fn default() -> Self {
+        Self {
+            max_commitments: usize::MAX,
+            max_mle_evaluations: usize::MAX,
+            max_result_rows: usize::MAX,
+            max_frame_length: 64 << 20,
+        }
+    }
+}
+
+impl ProofSizeLimits {
+    /// Checks the number of commitments and of intermediate MLE aliases against `counts`.
+    pub(super) fn check_commitments(
+        &self,
+        counts: &ProofCounts,
+        num_commitments: usize,
+        num_aliases: usize,
+    ) -> Result<(), ProofError> {
+        if num_commitments > counts.intermediate_mles.min(self.max_commitments) {
+            return Err(ProofError::ProofTooLarge("too many commitments"));
+        }
+        if num_aliases > counts.intermediate_mles {
+            return Err(ProofError::ProofTooLarge(
+                "too many intermediate mle aliases",
+            ));
+        }
+        Ok(())
+    }
+
+    /// Checks the number of MLE evaluations against `counts`.
+    pub(super) fn check_mle_evaluations(
+        &self,
+        counts: &ProofCounts,
+        num_evaluations: usize,
+    ) -> Result<(), ProofError> {
+        let max_evaluations = counts.intermediate_mles + counts.anchored_mles;
+        if num_evaluations > max_evaluations.min(self.max_mle_evaluations) {
+            return Err(ProofError::ProofTooLarge("too many mle evaluations"));
+        }
+        Ok(())
+    }
+
+    /// Checks the number of rows in the result against the length of the table.
+    pub(super) fn check_result_rows(
+        &self,
+        num_rows: usize,
+        table_length: usize,
+    ) -> Result<(), ProofError> {
+        if num_rows > table_length.min(self.max_result_rows) {
+            return Err(ProofError::ProofTooLarge("too many result elements"));
+        }
+        Ok(())
+    }
+}
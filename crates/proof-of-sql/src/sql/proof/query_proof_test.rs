@@ -1,22 +1,40 @@
 use super::{
-    CountBuilder, ProofBuilder, ProofExecutionPlan, ProverEvaluate, QueryProof, VerificationBuilder,
+    verification_builder::CONSTANT_TIME_COMPARISONS, CountBuilder, ProofBuilder, ProofCounts,
+    ProofExecutionPlan, ProofHeader, ProofSizeLimits, ProvableQueryResult, ProverEvaluate,
+    QueryProof, QueryProofOptions, VerificationBuilder,
 };
 use crate::{
     base::{
-        commitment::{Commitment, InnerProductProof},
+        commitment::{
+            Commitment, CommitmentSnapshot, InnerProductProof, QueryCommitments,
+            StaticCommitmentAccessor, TableCommitment, TableSnapshotMetadata,
+        },
         database::{
             owned_table_utility::{bigint, owned_table},
-            ColumnField, ColumnRef, ColumnType, CommitmentAccessor, DataAccessor, MetadataAccessor,
-            OwnedTable, OwnedTableTestAccessor, TestAccessor, UnimplementedTestAccessor,
+            Column, ColumnField, ColumnRef, ColumnType, CommitmentAccessor, DataAccessor,
+            MetadataAccessor, OwnedTable, OwnedTableTestAccessor, RecordingAccessor, SignedOffset,
+            TestAccessor, UnimplementedTestAccessor,
         },
-        proof::ProofError,
+        proof::{Keccak256Transcript, ProofError},
         scalar::{Curve25519Scalar, Scalar},
+        MleAllocator,
+    },
+    sql::{
+        ast::test_utility::{cols_result, column, const_bigint, filter, lte, tab},
+        proof::{
+            Indexes, QueryData, QueryError, ResultBuilder, SumcheckStrategy,
+            SumcheckSubpolynomialType, VerificationHash, MAX_SUMCHECK_DEGREE,
+            MIN_SUPPORTED_QUERY_PROOF_FORMAT_MINOR_VERSION, QUERY_PROOF_FORMAT_MAJOR_VERSION,
+            QUERY_PROOF_FORMAT_MINOR_VERSION,
+        },
     },
-    sql::proof::{Indexes, QueryData, ResultBuilder, SumcheckSubpolynomialType},
 };
 use bumpalo::Bump;
+use core::{alloc::Layout, cell::Cell, ptr::NonNull};
+use curve25519_dalek::RistrettoPoint;
 use indexmap::IndexSet;
 use serde::Serialize;
+use std::{io::Cursor, marker::PhantomData};
 
 /// Type to allow us to prove and verify an artificial polynomial where we prove
 /// that every entry in the result is zero
@@ -27,6 +45,11 @@ struct TrivialTestProofExecutionPlan {
     column_fill_value: i64,
     evaluation: i64,
     anchored_mle_count: usize,
+    degree: usize,
+    /// Not part of the serialized plan, so that plans only differing in the order of their
+    /// column references serialize identically
+    #[serde(skip)]
+    column_references: Vec<ColumnRef>,
 }
 impl Default for TrivialTestProofExecutionPlan {
     fn default() -> Self {
@@ -36,6 +59,8 @@ impl Default for TrivialTestProofExecutionPlan {
             column_fill_value: 0,
             evaluation: 0,
             anchored_mle_count: 0,
+            degree: 2,
+            column_references: Vec::new(),
         }
     }
 }
@@ -43,7 +68,7 @@ impl<S: Scalar> ProverEvaluate<S> for TrivialTestProofExecutionPlan {
     fn result_evaluate<'a>(
         &self,
         builder: &mut ResultBuilder<'a>,
-        alloc: &'a Bump,
+        alloc: &'a dyn MleAllocator,
         _accessor: &'a dyn DataAccessor<S>,
     ) {
         let col = alloc.alloc_slice_fill_copy(builder.table_length(), self.column_fill_value);
@@ -55,7 +80,7 @@ impl<S: Scalar> ProverEvaluate<S> for TrivialTestProofExecutionPlan {
     fn prover_evaluate<'a>(
         &self,
         builder: &mut ProofBuilder<'a, S>,
-        alloc: &'a Bump,
+        alloc: &'a dyn MleAllocator,
         _accessor: &'a dyn DataAccessor<S>,
     ) {
         let col = alloc.alloc_slice_fill_copy(builder.table_length(), self.column_fill_value);
@@ -71,7 +96,7 @@ impl<C: Commitment> ProofExecutionPlan<C> for TrivialTestProofExecutionPlan {
         builder: &mut CountBuilder,
         _accessor: &dyn MetadataAccessor,
     ) -> Result<(), ProofError> {
-        builder.count_degree(2);
+        builder.count_degree(self.degree);
         builder.count_result_columns(1);
         builder.count_subpolynomials(1);
         builder.count_anchored_mles(self.anchored_mle_count);
@@ -97,7 +122,7 @@ impl<C: Commitment> ProofExecutionPlan<C> for TrivialTestProofExecutionPlan {
         vec![ColumnField::new("a1".parse().unwrap(), ColumnType::BigInt)]
     }
     fn get_column_references(&self) -> IndexSet<ColumnRef> {
-        unimplemented!("no real usage for this function yet")
+        IndexSet::from_iter(self.column_references.iter().copied())
     }
 }
 
@@ -113,7 +138,7 @@ fn verify_a_trivial_query_proof_with_given_offset(n: usize, offset_generators: u
         verification_hash,
         table,
     } = proof.verify(&expr, &accessor, &result, &()).unwrap();
-    assert_ne!(verification_hash, [0; 32]);
+    assert_ne!(verification_hash, VerificationHash::default());
     let expected_result = owned_table([bigint("a1", [0])]);
     assert_eq!(table, expected_result);
 }
@@ -132,6 +157,79 @@ fn we_can_verify_a_trivial_query_proof_with_a_non_zero_offset() {
     }
 }
 
+#[test]
+fn we_can_prove_and_verify_a_trivial_query_in_a_single_call() {
+    let expr = TrivialTestProofExecutionPlan {
+        length: 3,
+        ..Default::default()
+    };
+    let accessor = UnimplementedTestAccessor::new_empty();
+    let QueryData {
+        verification_hash,
+        table,
+    } = QueryProof::<InnerProductProof>::prove_and_verify(&expr, &accessor, &(), &()).unwrap();
+    assert_ne!(verification_hash, VerificationHash::default());
+    assert_eq!(table, owned_table([bigint("a1", [0])]));
+}
+
+#[test]
+fn proofs_are_identical_whatever_the_order_the_column_references_are_built_in() {
+    let column_ref = |table: &str, column: &str| {
+        ColumnRef::new(
+            table.parse().unwrap(),
+            column.parse().unwrap(),
+            ColumnType::BigInt,
+        )
+    };
+    let columns = [
+        column_ref("sxt.u", "a"),
+        column_ref("sxt.test", "y"),
+        column_ref("other.test", "z"),
+        column_ref("sxt.test", "x"),
+    ];
+    let expr = TrivialTestProofExecutionPlan {
+        column_references: columns.to_vec(),
+        ..Default::default()
+    };
+    let reordered_expr = TrivialTestProofExecutionPlan {
+        column_references: columns.iter().rev().copied().collect(),
+        ..Default::default()
+    };
+    assert_ne!(
+        Vec::from_iter(ProofExecutionPlan::<RistrettoPoint>::get_column_references(
+            &expr
+        )),
+        Vec::from_iter(ProofExecutionPlan::<RistrettoPoint>::get_column_references(
+            &reordered_expr
+        ))
+    );
+    assert_eq!(
+        ProofExecutionPlan::<RistrettoPoint>::get_canonical_column_references(&expr),
+        [
+            column_ref("other.test", "z"),
+            column_ref("sxt.test", "x"),
+            column_ref("sxt.test", "y"),
+            column_ref("sxt.u", "a"),
+        ]
+    );
+
+    let accessor = UnimplementedTestAccessor::new_empty();
+    let (proof, result) = QueryProof::<InnerProductProof>::new(&expr, &accessor, &());
+    let (reordered_proof, reordered_result) =
+        QueryProof::<InnerProductProof>::new(&reordered_expr, &accessor, &());
+    assert_eq!(
+        postcard::to_allocvec(&proof).unwrap(),
+        postcard::to_allocvec(&reordered_proof).unwrap()
+    );
+    assert_eq!(
+        postcard::to_allocvec(&result).unwrap(),
+        postcard::to_allocvec(&reordered_result).unwrap()
+    );
+    assert!(proof
+        .verify(&reordered_expr, &accessor, &result, &())
+        .is_ok());
+}
+
 #[test]
 fn verify_fails_if_the_summation_in_sumcheck_isnt_zero() {
     // set up a proof for an artificial polynomial that doesn't sum to zero
@@ -157,6 +255,42 @@ fn verify_fails_if_the_sumcheck_evaluation_isnt_correct() {
     assert!(proof.verify(&expr, &accessor, &result, &()).is_err());
 }
 
+#[test]
+fn we_can_verify_a_proof_in_constant_time() {
+    let expr = TrivialTestProofExecutionPlan {
+        length: 3,
+        ..Default::default()
+    };
+    let accessor = UnimplementedTestAccessor::new_empty();
+    let (proof, result) = QueryProof::<InnerProductProof>::new(&expr, &accessor, &());
+    CONSTANT_TIME_COMPARISONS.with(|count| count.set(0));
+    let expected = proof.verify(&expr, &accessor, &result, &()).unwrap();
+    assert_eq!(CONSTANT_TIME_COMPARISONS.with(Cell::get), 0);
+
+    let verified = proof
+        .verify_constant_time(&expr, &accessor, &result, &())
+        .unwrap();
+    assert_eq!(verified.table, expected.table);
+    assert_eq!(verified.verification_hash, expected.verification_hash);
+    // the final evaluation check is routed through `ConstantTimeEq`
+    assert!(CONSTANT_TIME_COMPARISONS.with(Cell::get) > 0);
+}
+
+#[test]
+fn verify_constant_time_fails_if_the_sumcheck_evaluation_isnt_correct() {
+    let expr = TrivialTestProofExecutionPlan {
+        evaluation: 123,
+        ..Default::default()
+    };
+    let accessor = UnimplementedTestAccessor::new_empty();
+    let (proof, result) = QueryProof::<InnerProductProof>::new(&expr, &accessor, &());
+    CONSTANT_TIME_COMPARISONS.with(|count| count.set(0));
+    assert!(proof
+        .verify_constant_time(&expr, &accessor, &result, &())
+        .is_err());
+    assert!(CONSTANT_TIME_COMPARISONS.with(Cell::get) > 0);
+}
+
 #[test]
 fn veriy_fails_if_result_mle_evaluation_fails() {
     // prove and try to verify an artificial polynomial where we prove
@@ -176,6 +310,10 @@ fn veriy_fails_if_result_mle_evaluation_fails() {
 }
 
 #[test]
+#[cfg_attr(
+    feature = "self-check",
+    should_panic(expected = "proof self-check failed")
+)]
 fn verify_fails_if_counts_dont_match() {
     // prove and verify an artificial polynomial where we try to prove
     // that every entry in the result is zero
@@ -188,6 +326,184 @@ fn verify_fails_if_counts_dont_match() {
     assert!(proof.verify(&expr, &accessor, &result, &()).is_err());
 }
 
+#[cfg(feature = "self-check")]
+#[test]
+#[should_panic(expected = "proof self-check failed")]
+fn the_self_check_panics_when_proving_a_plan_whose_degree_does_not_match_its_count() {
+    let expr = TrivialTestProofExecutionPlan {
+        degree: 3,
+        ..Default::default()
+    };
+    let accessor = UnimplementedTestAccessor::new_empty();
+    QueryProof::<InnerProductProof>::new(&expr, &accessor, &());
+}
+
+#[test]
+fn counting_fails_with_degree_too_high_if_the_degree_exceeds_the_maximum() {
+    let accessor = UnimplementedTestAccessor::new_empty();
+    let count = |degree: usize| {
+        let expr = TrivialTestProofExecutionPlan {
+            degree,
+            ..Default::default()
+        };
+        let mut builder = CountBuilder::new(&[]);
+        ProofExecutionPlan::<RistrettoPoint>::count(&expr, &mut builder, &accessor).unwrap();
+        builder.counts()
+    };
+    assert!(count(MAX_SUMCHECK_DEGREE).is_ok());
+    assert!(matches!(
+        count(MAX_SUMCHECK_DEGREE + 1),
+        Err(ProofError::DegreeTooHigh { degree, max })
+            if degree == MAX_SUMCHECK_DEGREE + 1 && max == MAX_SUMCHECK_DEGREE
+    ));
+}
+
+#[cfg(not(feature = "self-check"))]
+#[test]
+fn verify_fails_with_degree_too_high_if_the_plan_declares_an_excessive_degree() {
+    let expr = TrivialTestProofExecutionPlan {
+        degree: MAX_SUMCHECK_DEGREE + 1,
+        ..Default::default()
+    };
+    let accessor = UnimplementedTestAccessor::new_empty();
+    let (proof, result) = QueryProof::<InnerProductProof>::new(&expr, &accessor, &());
+    assert!(matches!(
+        proof.verify(&expr, &accessor, &result, &()),
+        Err(QueryError::ProofError(ProofError::DegreeTooHigh { .. }))
+    ));
+}
+
+#[test]
+fn verify_fails_with_a_header_mismatch_if_the_header_disagrees_with_the_plan() {
+    let expr = TrivialTestProofExecutionPlan {
+        ..Default::default()
+    };
+    let accessor = UnimplementedTestAccessor::new_empty();
+    let (proof, result) = QueryProof::<InnerProductProof>::new(&expr, &accessor, &());
+    assert!(proof.verify(&expr, &accessor, &result, &()).is_ok());
+
+    let mut tampered_proof = proof.clone();
+    tampered_proof.header.sumcheck_subpolynomials += 1;
+    assert!(matches!(
+        tampered_proof.verify(&expr, &accessor, &result, &()),
+        Err(QueryError::ProofError(ProofError::HeaderMismatch("counts")))
+    ));
+
+    let mut tampered_proof = proof.clone();
+    tampered_proof.header.sumcheck_max_multiplicands = 3;
+    assert!(matches!(
+        tampered_proof.verify(&expr, &accessor, &result, &()),
+        Err(QueryError::ProofError(ProofError::HeaderMismatch("degree")))
+    ));
+
+    let mut tampered_proof = proof;
+    tampered_proof.header.table_length = 1_000_000;
+    assert!(matches!(
+        tampered_proof.verify(&expr, &accessor, &result, &()),
+        Err(QueryError::ProofError(ProofError::HeaderMismatch(
+            "table length"
+        )))
+    ));
+}
+
+#[test]
+fn verify_fails_with_proof_too_large_if_there_are_too_many_commitments() {
+    let expr = TrivialTestProofExecutionPlan {
+        ..Default::default()
+    };
+    let accessor = UnimplementedTestAccessor::new_empty();
+    let (mut proof, result) = QueryProof::<InnerProductProof>::new(&expr, &accessor, &());
+    proof.commitments = vec![Default::default(); 1000];
+    assert!(matches!(
+        proof.verify(&expr, &accessor, &result, &()),
+        Err(QueryError::ProofError(ProofError::ProofTooLarge(_)))
+    ));
+}
+
+#[test]
+fn verify_fails_with_proof_too_large_if_the_proof_exceeds_the_configured_limits() {
+    let expr = DoubleSquareTestProofExecutionPlan::default();
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(
+        "sxt.test".parse().unwrap(),
+        owned_table([bigint("x", [3, 5])]),
+        0,
+        (),
+    );
+    let (proof, result) = QueryProof::<InnerProductProof>::new(&expr, &accessor, &());
+    let verify_with_limits = |size_limits| {
+        let options = QueryProofOptions {
+            size_limits,
+            ..Default::default()
+        };
+        proof.verify_with_options(&expr, &accessor, &result, &(), &options)
+    };
+    assert!(verify_with_limits(ProofSizeLimits::default()).is_ok());
+    assert!(matches!(
+        verify_with_limits(ProofSizeLimits {
+            max_commitments: 0,
+            ..Default::default()
+        }),
+        Err(QueryError::ProofError(ProofError::ProofTooLarge(_)))
+    ));
+    assert!(matches!(
+        verify_with_limits(ProofSizeLimits {
+            max_mle_evaluations: 0,
+            ..Default::default()
+        }),
+        Err(QueryError::ProofError(ProofError::ProofTooLarge(_)))
+    ));
+    assert!(matches!(
+        verify_with_limits(ProofSizeLimits {
+            max_result_rows: 1,
+            ..Default::default()
+        }),
+        Err(QueryError::ProofError(ProofError::ProofTooLarge(_)))
+    ));
+}
+
+#[test]
+fn verify_fails_with_proof_too_large_if_there_are_too_many_result_elements() {
+    let expr = TrivialTestProofExecutionPlan {
+        ..Default::default()
+    };
+    let accessor = UnimplementedTestAccessor::new_empty();
+    let (proof, mut result) = QueryProof::<InnerProductProof>::new(&expr, &accessor, &());
+    *result.indexes_mut() = Indexes::Dense(0..1_000_000);
+    assert!(matches!(
+        proof.verify(&expr, &accessor, &result, &()),
+        Err(QueryError::ProofError(ProofError::ProofTooLarge(_)))
+    ));
+}
+
+fn verify_fails_with_invalid_indexes_if_the_result_has_sparse_indexes(indexes: Vec<u64>) {
+    let expr = TrivialTestProofExecutionPlan {
+        length: 4,
+        ..Default::default()
+    };
+    let accessor = UnimplementedTestAccessor::new_empty();
+    let (proof, mut result) = QueryProof::<InnerProductProof>::new(&expr, &accessor, &());
+    *result.indexes_mut() = Indexes::Sparse(indexes);
+    assert!(matches!(
+        proof.verify(&expr, &accessor, &result, &()),
+        Err(QueryError::ProofError(ProofError::InvalidIndexes))
+    ));
+}
+
+#[test]
+fn verify_fails_with_invalid_indexes_if_result_indexes_are_duplicated() {
+    verify_fails_with_invalid_indexes_if_the_result_has_sparse_indexes(vec![1, 1]);
+}
+
+#[test]
+fn verify_fails_with_invalid_indexes_if_result_indexes_are_out_of_range() {
+    verify_fails_with_invalid_indexes_if_the_result_has_sparse_indexes(vec![4]);
+}
+
+#[test]
+fn verify_fails_with_invalid_indexes_if_result_indexes_are_descending() {
+    verify_fails_with_invalid_indexes_if_the_result_has_sparse_indexes(vec![2, 1]);
+}
+
 /// prove and verify an artificial query where
 ///     res_i = x_i * x_i
 /// where the commitment for x is known
@@ -208,7 +524,7 @@ impl<S: Scalar> ProverEvaluate<S> for SquareTestProofExecutionPlan {
     fn result_evaluate<'a>(
         &self,
         builder: &mut ResultBuilder<'a>,
-        _alloc: &'a Bump,
+        _alloc: &'a dyn MleAllocator,
         _accessor: &'a dyn DataAccessor<S>,
     ) {
         builder.set_result_indexes(Indexes::Sparse(vec![0, 1]));
@@ -218,7 +534,7 @@ impl<S: Scalar> ProverEvaluate<S> for SquareTestProofExecutionPlan {
     fn prover_evaluate<'a>(
         &self,
         builder: &mut ProofBuilder<'a, S>,
-        alloc: &'a Bump,
+        alloc: &'a dyn MleAllocator,
         accessor: &'a dyn DataAccessor<S>,
     ) {
         let x = accessor.get_column(ColumnRef::new(
@@ -262,13 +578,14 @@ impl<C: Commitment> ProofExecutionPlan<C> for SquareTestProofExecutionPlan {
         _result: Option<&OwnedTable<C::Scalar>>,
     ) -> Result<(), ProofError> {
         let res_eval = builder.consume_result_mle();
-        let x_commit = C::Scalar::from(self.anchored_commit_multiplier)
-            * accessor.get_commitment(ColumnRef::new(
-                "sxt.test".parse().unwrap(),
-                "x".parse().unwrap(),
-                ColumnType::BigInt,
-            ));
-        let x_eval = builder.consume_anchored_mle(x_commit);
+        let x = ColumnRef::new(
+            "sxt.test".parse().unwrap(),
+            "x".parse().unwrap(),
+            ColumnType::BigInt,
+        );
+        let x_commit =
+            C::Scalar::from(self.anchored_commit_multiplier) * accessor.get_commitment(x);
+        let x_eval = builder.consume_column_mle(x, x_commit);
         let eval = builder.mle_evaluations.random_evaluation * (res_eval - x_eval * x_eval);
         builder.produce_sumcheck_subpolynomial_evaluation(&eval);
         Ok(())
@@ -277,7 +594,11 @@ impl<C: Commitment> ProofExecutionPlan<C> for SquareTestProofExecutionPlan {
         vec![ColumnField::new("a1".parse().unwrap(), ColumnType::BigInt)]
     }
     fn get_column_references(&self) -> IndexSet<ColumnRef> {
-        unimplemented!("no real usage for this function yet")
+        IndexSet::from_iter([ColumnRef::new(
+            "sxt.test".parse().unwrap(),
+            "x".parse().unwrap(),
+            ColumnType::BigInt,
+        )])
     }
 }
 
@@ -299,7 +620,7 @@ fn verify_a_proof_with_an_anchored_commitment_and_given_offset(offset_generators
         verification_hash,
         table,
     } = proof.verify(&expr, &accessor, &result, &()).unwrap();
-    assert_ne!(verification_hash, [0; 32]);
+    assert_ne!(verification_hash, VerificationHash::default());
     let expected_result = owned_table([bigint("a1", [9, 25])]);
     assert_eq!(table, expected_result);
 
@@ -323,15 +644,24 @@ fn we_can_verify_a_proof_with_an_anchored_commitment_and_with_a_non_zero_offset(
     verify_a_proof_with_an_anchored_commitment_and_given_offset(123);
 }
 
+/// An allocator that counts the allocations made through it.
+#[derive(Default)]
+struct CountingAllocator {
+    alloc: Bump,
+    count: Cell<usize>,
+}
+
+// SAFETY: every allocation is forwarded to the `Bump`.
+unsafe impl MleAllocator for CountingAllocator {
+    fn alloc_layout(&self, layout: Layout) -> NonNull<u8> {
+        self.count.set(self.count.get() + 1);
+        self.alloc.alloc_layout(layout)
+    }
+}
+
 #[test]
-fn verify_fails_if_the_result_doesnt_satisfy_an_anchored_equation() {
-    // attempt to prove and verify an artificial query where
-    //     res_i = x_i * x_i
-    // where the commitment for x is known and
-    //     res_i != x_i * x_i
-    // for some i
+fn we_can_create_a_proof_with_a_custom_allocator() {
     let expr = SquareTestProofExecutionPlan {
-        res: [9, 26],
         ..Default::default()
     };
     let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(
@@ -340,17 +670,20 @@ fn verify_fails_if_the_result_doesnt_satisfy_an_anchored_equation() {
         0,
         (),
     );
-    let (proof, result) = QueryProof::<InnerProductProof>::new(&expr, &accessor, &());
-    assert!(proof.verify(&expr, &accessor, &result, &()).is_err());
+    let alloc = CountingAllocator::default();
+    let (proof, result) =
+        QueryProof::<InnerProductProof>::new_with_allocator(&expr, &accessor, &(), &alloc);
+    // the square plan only allocates a copy of its result
+    assert_eq!(alloc.count.get(), 1);
+    let QueryData { table, .. } = proof.verify(&expr, &accessor, &result, &()).unwrap();
+    assert_eq!(table, owned_table([bigint("a1", [9, 25])]));
 }
 
 #[test]
-fn verify_fails_if_the_anchored_commitment_doesnt_match() {
-    // prove and verify an artificial query where
-    //     res_i = x_i * x_i
-    // where the commitment for x is known
+fn verify_fails_if_an_anchored_column_is_longer_than_the_table_length_of_the_proof() {
+    // the plan declares a table length of 2, so a trailing zero doesn't change the commitment
+    // of x, but its last row would not be covered by the proof
     let expr = SquareTestProofExecutionPlan {
-        anchored_commit_multiplier: 2,
         ..Default::default()
     };
     let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(
@@ -360,70 +693,424 @@ fn verify_fails_if_the_anchored_commitment_doesnt_match() {
         (),
     );
     let (proof, result) = QueryProof::<InnerProductProof>::new(&expr, &accessor, &());
-    assert!(proof.verify(&expr, &accessor, &result, &()).is_err());
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(
+        "sxt.test".parse().unwrap(),
+        owned_table([bigint("x", [3, 5, 0])]),
+        0,
+        (),
+    );
+    let x = ColumnRef::new(
+        "sxt.test".parse().unwrap(),
+        "x".parse().unwrap(),
+        ColumnType::BigInt,
+    );
+    assert!(matches!(
+        proof.verify(&expr, &accessor, &result, &()),
+        Err(QueryError::ProofError(ProofError::LengthMismatch {
+            column,
+            expected: 2,
+            actual: 3,
+        })) if column == x
+    ));
 }
 
-// prove and verify an artificial query where
-//     z_i = x_i * x_i
-//     res_i = z_i * z_i
-// where the commitment for x is known
-#[derive(Debug, Serialize)]
-struct DoubleSquareTestProofExecutionPlan {
-    res: [i64; 2],
-    z: [i64; 2],
-}
-impl Default for DoubleSquareTestProofExecutionPlan {
-    fn default() -> Self {
-        Self {
-            res: [81, 625],
-            z: [9, 25],
-        }
-    }
+#[test]
+fn a_recording_accessor_records_the_columns_read_when_proving_and_verifying() {
+    let expr = SquareTestProofExecutionPlan {
+        ..Default::default()
+    };
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(
+        "sxt.test".parse().unwrap(),
+        owned_table([bigint("x", [3, 5]), bigint("y", [7, 11])]),
+        12,
+        (),
+    );
+    let x = ColumnRef::new(
+        "sxt.test".parse().unwrap(),
+        "x".parse().unwrap(),
+        ColumnType::BigInt,
+    );
+
+    let prover_accessor = RecordingAccessor::new(&accessor);
+    let (proof, result) = QueryProof::<InnerProductProof>::new(&expr, &prover_accessor, &());
+    assert_eq!(prover_accessor.accessed_columns(), IndexSet::from_iter([x]));
+    assert!(prover_accessor
+        .accesses()
+        .iter()
+        .all(|access| access.offset == 12));
+
+    let verifier_accessor = RecordingAccessor::new(&accessor);
+    proof
+        .verify(&expr, &verifier_accessor, &result, &())
+        .unwrap();
+    assert_eq!(
+        verifier_accessor.accessed_columns(),
+        ProofExecutionPlan::<RistrettoPoint>::get_column_references(&expr)
+    );
 }
-impl<S: Scalar> ProverEvaluate<S> for DoubleSquareTestProofExecutionPlan {
-    fn result_evaluate<'a>(
-        &self,
-        builder: &mut ResultBuilder<'a>,
-        _alloc: &'a Bump,
-        _accessor: &'a dyn DataAccessor<S>,
-    ) {
-        builder.set_result_indexes(Indexes::Sparse(vec![0, 1]));
-        builder.produce_result_column(self.res);
-    }
 
-    fn prover_evaluate<'a>(
-        &self,
-        builder: &mut ProofBuilder<'a, S>,
-        alloc: &'a Bump,
-        accessor: &'a dyn DataAccessor<S>,
-    ) {
-        let x = accessor.get_column(ColumnRef::new(
-            "sxt.test".parse().unwrap(),
-            "x".parse().unwrap(),
-            ColumnType::BigInt,
-        ));
-        let res: &[_] = alloc.alloc_slice_copy(&self.res);
-        let z: &[_] = alloc.alloc_slice_copy(&self.z);
-        builder.produce_anchored_mle(x.clone());
-        builder.produce_intermediate_mle(z);
+#[test]
+fn verify_fails_if_the_accessor_advertises_a_newer_epoch_of_a_column() {
+    let expr = SquareTestProofExecutionPlan {
+        ..Default::default()
+    };
+    let x = ColumnRef::new(
+        "sxt.test".parse().unwrap(),
+        "x".parse().unwrap(),
+        ColumnType::BigInt,
+    );
+    let mut accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(
+        "sxt.test".parse().unwrap(),
+        owned_table([bigint("x", [3, 5])]),
+        0,
+        (),
+    );
+    accessor.set_column_epoch(x, 1);
+    let (proof, result) = QueryProof::<InnerProductProof>::new(&expr, &accessor, &());
+    assert!(proof.verify(&expr, &accessor, &result, &()).is_ok());
 
-        // poly1
-        builder.produce_sumcheck_subpolynomial(
-            SumcheckSubpolynomialType::Identity,
-            vec![
-                (S::ONE, vec![Box::new(z)]),
-                (-S::ONE, vec![Box::new(x.clone()), Box::new(x)]),
-            ],
-        );
+    let mut newer_accessor = accessor.clone();
+    newer_accessor.set_column_epoch(x, 2);
+    assert!(proof.verify(&expr, &newer_accessor, &result, &()).is_err());
 
-        // poly2
-        builder.produce_sumcheck_subpolynomial(
-            SumcheckSubpolynomialType::Identity,
-            vec![
-                (S::ONE, vec![Box::new(res)]),
-                (-S::ONE, vec![Box::new(z), Box::new(z)]),
-            ],
-        );
+    // a proof of a versioned column does not verify against an unversioned one
+    let unversioned_accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(
+        "sxt.test".parse().unwrap(),
+        owned_table([bigint("x", [3, 5])]),
+        0,
+        (),
+    );
+    assert!(proof
+        .verify(&expr, &unversioned_accessor, &result, &())
+        .is_err());
+}
+
+#[cfg(feature = "zeroize")]
+#[test]
+fn the_witness_data_is_zeroized_after_a_proof_is_created() {
+    let expr = SquareTestProofExecutionPlan {
+        ..Default::default()
+    };
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(
+        "sxt.test".parse().unwrap(),
+        owned_table([bigint("x", [3, 5])]),
+        0,
+        (),
+    );
+    let mut alloc = Bump::new();
+    let (proof, result) = QueryProof::<InnerProductProof>::create_with_bump(
+        &expr,
+        &accessor,
+        &(),
+        &Default::default(),
+        &mut alloc,
+    )
+    .unwrap();
+    let mut len = 0;
+    for chunk in alloc.iter_allocated_chunks() {
+        len += chunk.len();
+        // SAFETY: every byte of the chunk has been written by `zeroize_bump`.
+        assert!(chunk.iter().all(|byte| unsafe { byte.assume_init() } == 0));
+    }
+    assert!(len > 0);
+
+    // the proof does not depend on the zeroized memory
+    let QueryData { table, .. } = proof.verify(&expr, &accessor, &result, &()).unwrap();
+    assert_eq!(table, owned_table([bigint("a1", [9, 25])]));
+}
+
+#[test]
+fn we_can_verify_a_proof_with_an_anchored_commitment_after_prepending_rows() {
+    // the row x = 5 is committed first, at logical offset 0, and the row x = 3 is prepended later
+    let table_ref = "sxt.test".parse().unwrap();
+    let mut offset = SignedOffset::new(100);
+    let mut table_commitment = TableCommitment::<RistrettoPoint>::from_owned_table_with_offset(
+        &owned_table::<Curve25519Scalar>([bigint("x", [5])]),
+        offset.generator_offset(),
+        &(),
+    );
+    let earlier_rows = owned_table::<Curve25519Scalar>([bigint("x", [3])]);
+    table_commitment
+        .try_prepend_rows(earlier_rows.inner_table(), &())
+        .unwrap();
+    offset.try_prepend_rows(earlier_rows.num_rows()).unwrap();
+    assert_eq!(offset.offset(), -1);
+
+    let expr = SquareTestProofExecutionPlan {
+        ..Default::default()
+    };
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(
+        table_ref,
+        owned_table([bigint("x", [3, 5])]),
+        offset.generator_offset(),
+        (),
+    );
+    let (proof, result) = QueryProof::<InnerProductProof>::new(&expr, &accessor, &());
+
+    let commitments = QueryCommitments::from_iter([(table_ref, table_commitment)]);
+    let QueryData { table, .. } = proof.verify(&expr, &commitments, &result, &()).unwrap();
+    assert_eq!(table, owned_table([bigint("a1", [9, 25])]));
+}
+
+#[test]
+fn we_can_verify_a_proof_with_an_anchored_commitment_against_a_commitment_snapshot() {
+    let expr = SquareTestProofExecutionPlan {
+        ..Default::default()
+    };
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(
+        "sxt.test".parse().unwrap(),
+        owned_table([bigint("x", [3, 5])]),
+        0,
+        (),
+    );
+    let (proof, result) = QueryProof::<InnerProductProof>::new(&expr, &accessor, &());
+    let snapshot = CommitmentSnapshot::from_accessor(
+        [ColumnRef::new(
+            "sxt.test".parse().unwrap(),
+            "x".parse().unwrap(),
+            ColumnType::BigInt,
+        )],
+        &accessor,
+    );
+    let snapshot: CommitmentSnapshot<_> =
+        postcard::from_bytes(&postcard::to_allocvec(&snapshot).unwrap()).unwrap();
+    drop(accessor);
+    let QueryData {
+        verification_hash,
+        table,
+    } = proof.verify(&expr, &snapshot, &result, &()).unwrap();
+    assert_ne!(verification_hash, VerificationHash::default());
+    let expected_result = owned_table([bigint("a1", [9, 25])]);
+    assert_eq!(table, expected_result);
+}
+
+#[test]
+fn we_can_verify_a_proof_with_an_accessor_built_from_column_commitments() {
+    let expr = SquareTestProofExecutionPlan {
+        ..Default::default()
+    };
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(
+        "sxt.test".parse().unwrap(),
+        owned_table([bigint("x", [3, 5]), bigint("y", [7, 11])]),
+        3,
+        (),
+    );
+    let (proof, result) = QueryProof::<InnerProductProof>::new(&expr, &accessor, &());
+    let x = ColumnRef::new(
+        "sxt.test".parse().unwrap(),
+        "x".parse().unwrap(),
+        ColumnType::BigInt,
+    );
+    let static_accessor = StaticCommitmentAccessor::from_columns(vec![(
+        x,
+        accessor.get_commitment(x),
+        TableSnapshotMetadata {
+            length: 2,
+            offset: 3,
+        },
+    )]);
+    drop(accessor);
+    assert_eq!(
+        static_accessor.schema(),
+        vec![(
+            "sxt.test".parse().unwrap(),
+            vec![ColumnField::new("x".parse().unwrap(), ColumnType::BigInt)]
+        )]
+    );
+    let table = proof
+        .verify(&expr, &static_accessor, &result, &())
+        .unwrap()
+        .table;
+    assert_eq!(table, owned_table([bigint("a1", [9, 25])]));
+
+    // the commitment alone does not fix the offset of the table
+    let wrong_offset_accessor = StaticCommitmentAccessor::from_columns(vec![(
+        x,
+        static_accessor.get_commitment(x),
+        TableSnapshotMetadata {
+            length: 2,
+            offset: 0,
+        },
+    )]);
+    assert!(proof
+        .verify(&expr, &wrong_offset_accessor, &result, &())
+        .is_err());
+}
+
+#[test]
+fn verify_fails_if_the_result_doesnt_satisfy_an_anchored_equation() {
+    // attempt to prove and verify an artificial query where
+    //     res_i = x_i * x_i
+    // where the commitment for x is known and
+    //     res_i != x_i * x_i
+    // for some i
+    let expr = SquareTestProofExecutionPlan {
+        res: [9, 26],
+        ..Default::default()
+    };
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(
+        "sxt.test".parse().unwrap(),
+        owned_table([bigint("x", [3, 5])]),
+        0,
+        (),
+    );
+    let (proof, result) = QueryProof::<InnerProductProof>::new(&expr, &accessor, &());
+    assert!(proof.verify(&expr, &accessor, &result, &()).is_err());
+}
+
+#[test]
+fn verify_fails_if_the_anchored_commitment_doesnt_match() {
+    // prove and verify an artificial query where
+    //     res_i = x_i * x_i
+    // where the commitment for x is known
+    let expr = SquareTestProofExecutionPlan {
+        anchored_commit_multiplier: 2,
+        ..Default::default()
+    };
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(
+        "sxt.test".parse().unwrap(),
+        owned_table([bigint("x", [3, 5])]),
+        0,
+        (),
+    );
+    let (proof, result) = QueryProof::<InnerProductProof>::new(&expr, &accessor, &());
+    let x = ColumnRef::new(
+        "sxt.test".parse().unwrap(),
+        "x".parse().unwrap(),
+        ColumnType::BigInt,
+    );
+    assert!(matches!(
+        proof.verify(&expr, &accessor, &result, &()),
+        Err(QueryError::ProofError(ProofError::CommitmentMismatch { column })) if column == x
+    ));
+}
+
+#[test]
+fn the_cost_estimate_of_the_square_plan_matches_its_proof() {
+    let expr = SquareTestProofExecutionPlan {
+        ..Default::default()
+    };
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(
+        "sxt.test".parse().unwrap(),
+        owned_table([bigint("x", [3, 5])]),
+        0,
+        (),
+    );
+    let estimate = ProofExecutionPlan::<RistrettoPoint>::estimate_cost(&expr, &accessor);
+    let (proof, _result) = QueryProof::<InnerProductProof>::new(&expr, &accessor, &());
+
+    assert_eq!(estimate.num_commitments, proof.commitments.len());
+    assert_eq!(
+        estimate.num_mle_evaluations,
+        proof.pcs_proof_evaluations.len()
+    );
+    let num_sumcheck_evaluations: usize = proof
+        .sumcheck_proof
+        .round_evaluations()
+        .iter()
+        .map(Vec::len)
+        .sum();
+    assert_eq!(estimate.num_sumcheck_evaluations, num_sumcheck_evaluations);
+
+    // the evaluation proof is not part of the estimate
+    let proof_size = postcard::to_allocvec(&proof).unwrap().len()
+        - postcard::to_allocvec(&proof.evaluation_proof)
+            .unwrap()
+            .len();
+    assert!(estimate.proof_size_bytes.abs_diff(proof_size) <= proof_size / 10);
+
+    assert!(estimate.prover_field_operations > estimate.verifier_field_operations);
+}
+
+#[test]
+fn we_can_merge_the_counts_of_two_square_plans() {
+    let accessor = UnimplementedTestAccessor::new_empty();
+    let count = |expr: &SquareTestProofExecutionPlan| {
+        let mut builder = CountBuilder::new(&[]);
+        ProofExecutionPlan::<RistrettoPoint>::count(expr, &mut builder, &accessor).unwrap();
+        builder.counts().unwrap()
+    };
+    let lhs = count(&SquareTestProofExecutionPlan::default());
+    let rhs = count(&SquareTestProofExecutionPlan {
+        res: [1, 4],
+        ..Default::default()
+    });
+    let expected = ProofCounts {
+        sumcheck_max_multiplicands: 3,
+        result_columns: 2,
+        anchored_mles: 2,
+        intermediate_mles: 0,
+        sumcheck_subpolynomials: 2,
+        post_result_challenges: 0,
+    };
+    let mut merged = lhs;
+    merged.merge(&rhs);
+    assert_eq!(merged, expected);
+    assert_eq!(lhs + rhs, expected);
+    assert_eq!(lhs + ProofCounts::default(), lhs);
+}
+
+// prove and verify an artificial query where
+//     z_i = x_i * x_i
+//     res_i = z_i * z_i
+// where the commitment for x is known
+#[derive(Debug, Serialize)]
+struct DoubleSquareTestProofExecutionPlan {
+    res: [i64; 2],
+    z: [i64; 2],
+}
+impl Default for DoubleSquareTestProofExecutionPlan {
+    fn default() -> Self {
+        Self {
+            res: [81, 625],
+            z: [9, 25],
+        }
+    }
+}
+impl<S: Scalar> ProverEvaluate<S> for DoubleSquareTestProofExecutionPlan {
+    fn result_evaluate<'a>(
+        &self,
+        builder: &mut ResultBuilder<'a>,
+        _alloc: &'a dyn MleAllocator,
+        _accessor: &'a dyn DataAccessor<S>,
+    ) {
+        builder.set_result_indexes(Indexes::Sparse(vec![0, 1]));
+        builder.produce_result_column(self.res);
+    }
+
+    fn prover_evaluate<'a>(
+        &self,
+        builder: &mut ProofBuilder<'a, S>,
+        alloc: &'a dyn MleAllocator,
+        accessor: &'a dyn DataAccessor<S>,
+    ) {
+        let x = accessor.get_column(ColumnRef::new(
+            "sxt.test".parse().unwrap(),
+            "x".parse().unwrap(),
+            ColumnType::BigInt,
+        ));
+        let res: &[_] = alloc.alloc_slice_copy(&self.res);
+        let z: &[_] = alloc.alloc_slice_copy(&self.z);
+        builder.produce_anchored_mle(x.clone());
+        builder.produce_intermediate_mle(z);
+
+        // poly1
+        builder.produce_sumcheck_subpolynomial(
+            SumcheckSubpolynomialType::Identity,
+            vec![
+                (S::ONE, vec![Box::new(z)]),
+                (-S::ONE, vec![Box::new(x.clone()), Box::new(x)]),
+            ],
+        );
+
+        // poly2
+        builder.produce_sumcheck_subpolynomial(
+            SumcheckSubpolynomialType::Identity,
+            vec![
+                (S::ONE, vec![Box::new(res)]),
+                (-S::ONE, vec![Box::new(z), Box::new(z)]),
+            ],
+        );
     }
 }
 impl<C: Commitment> ProofExecutionPlan<C> for DoubleSquareTestProofExecutionPlan {
@@ -473,7 +1160,11 @@ impl<C: Commitment> ProofExecutionPlan<C> for DoubleSquareTestProofExecutionPlan
         vec![ColumnField::new("a1".parse().unwrap(), ColumnType::BigInt)]
     }
     fn get_column_references(&self) -> IndexSet<ColumnRef> {
-        unimplemented!("no real usage for this function yet")
+        IndexSet::from_iter([ColumnRef::new(
+            "sxt.test".parse().unwrap(),
+            "x".parse().unwrap(),
+            ColumnType::BigInt,
+        )])
     }
 }
 
@@ -496,7 +1187,7 @@ fn verify_a_proof_with_an_intermediate_commitment_and_given_offset(offset_genera
         verification_hash,
         table,
     } = proof.verify(&expr, &accessor, &result, &()).unwrap();
-    assert_ne!(verification_hash, [0; 32]);
+    assert_ne!(verification_hash, VerificationHash::default());
     let expected_result = owned_table([bigint("a1", [81, 625])]);
     assert_eq!(table, expected_result);
 
@@ -589,7 +1280,7 @@ impl<S: Scalar> ProverEvaluate<S> for ChallengeTestProofExecutionPlan {
     fn result_evaluate<'a>(
         &self,
         builder: &mut ResultBuilder<'a>,
-        _alloc: &'a Bump,
+        _alloc: &'a dyn MleAllocator,
         _accessor: &'a dyn DataAccessor<S>,
     ) {
         builder.set_result_indexes(Indexes::Sparse(vec![0, 1]));
@@ -600,7 +1291,7 @@ impl<S: Scalar> ProverEvaluate<S> for ChallengeTestProofExecutionPlan {
     fn prover_evaluate<'a>(
         &self,
         builder: &mut ProofBuilder<'a, S>,
-        alloc: &'a Bump,
+        alloc: &'a dyn MleAllocator,
         accessor: &'a dyn DataAccessor<S>,
     ) {
         let x = accessor.get_column(ColumnRef::new(
@@ -664,7 +1355,11 @@ impl<C: Commitment> ProofExecutionPlan<C> for ChallengeTestProofExecutionPlan {
         vec![ColumnField::new("a1".parse().unwrap(), ColumnType::BigInt)]
     }
     fn get_column_references(&self) -> IndexSet<ColumnRef> {
-        unimplemented!("no real usage for this function yet")
+        IndexSet::from_iter([ColumnRef::new(
+            "sxt.test".parse().unwrap(),
+            "x".parse().unwrap(),
+            ColumnType::BigInt,
+        )])
     }
 }
 
@@ -685,7 +1380,7 @@ fn verify_a_proof_with_a_post_result_challenge_and_given_offset(offset_generator
         verification_hash,
         table,
     } = proof.verify(&expr, &accessor, &result, &()).unwrap();
-    assert_ne!(verification_hash, [0; 32]);
+    assert_ne!(verification_hash, VerificationHash::default());
     let expected_result = owned_table([bigint("a1", [9, 25])]);
     assert_eq!(table, expected_result);
 
@@ -708,3 +1403,896 @@ fn we_can_verify_a_proof_with_a_post_result_challenge_and_with_a_zero_offset() {
 fn we_can_verify_a_proof_with_a_post_result_challenge_and_with_a_non_zero_offset() {
     verify_a_proof_with_a_post_result_challenge_and_given_offset(123);
 }
+
+#[cfg(feature = "debug-transcript")]
+#[test]
+fn transcript_logs_diverge_where_the_verifier_is_given_a_mismatched_plan() {
+    use crate::base::proof::{
+        first_divergence, with_transcript_observer, MessageLabel, TranscriptEvent,
+    };
+    use std::{cell::RefCell, rc::Rc};
+
+    let record = |f: &dyn Fn()| {
+        let log = Rc::new(RefCell::new(Vec::<TranscriptEvent>::new()));
+        let observer_log = log.clone();
+        with_transcript_observer(
+            move |event: &TranscriptEvent| observer_log.borrow_mut().push(event.clone()),
+            f,
+        );
+        log.take()
+    };
+
+    let expr = TrivialTestProofExecutionPlan::default();
+    let mismatched_expr = TrivialTestProofExecutionPlan {
+        evaluation: 1,
+        ..Default::default()
+    };
+    let accessor = UnimplementedTestAccessor::new_empty();
+    let (proof, result) = QueryProof::<InnerProductProof>::new(&expr, &accessor, &());
+
+    let prover_log = record(&|| {
+        QueryProof::<InnerProductProof>::new(&expr, &accessor, &());
+    });
+    let honest_verifier_log = record(&|| {
+        assert!(proof.verify(&expr, &accessor, &result, &()).is_ok());
+    });
+    let mismatched_verifier_log = record(&|| {
+        assert!(proof
+            .verify(&mismatched_expr, &accessor, &result, &())
+            .is_err());
+    });
+
+    // The prover and an honest verifier agree on every absorbed message and drawn challenge
+    // up to the point where the verifier stops.
+    assert!(!honest_verifier_log.is_empty());
+    assert_eq!(
+        prover_log[..honest_verifier_log.len()],
+        honest_verifier_log[..]
+    );
+
+    // The serialized plan is the second message absorbed, after the result.
+    let step = first_divergence(&prover_log, &mismatched_verifier_log).unwrap();
+    assert_eq!(step, 1);
+    assert_eq!(prover_log[step].label(), MessageLabel::ProofExecutionPlan);
+    assert_eq!(
+        mismatched_verifier_log[step].label(),
+        MessageLabel::ProofExecutionPlan
+    );
+}
+
+#[cfg(feature = "deterministic")]
+#[test]
+fn the_sumcheck_seed_changes_the_proof_and_binds_the_verifier() {
+    use crate::sql::proof::with_sumcheck_seed;
+
+    let expr = DoubleSquareTestProofExecutionPlan::default();
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(
+        "sxt.test".parse().unwrap(),
+        owned_table([bigint("x", [3, 5])]),
+        0,
+        (),
+    );
+    let prove = |seed: [u8; 32]| {
+        with_sumcheck_seed(seed, || {
+            let (proof, result) = QueryProof::<InnerProductProof>::new(&expr, &accessor, &());
+            (postcard::to_allocvec(&proof).unwrap(), proof, result)
+        })
+    };
+
+    let (bytes, proof, result) = prove([1; 32]);
+    let (same_seed_bytes, _, _) = prove([1; 32]);
+    let (other_seed_bytes, _, _) = prove([2; 32]);
+    let (unseeded_proof, _) = QueryProof::<InnerProductProof>::new(&expr, &accessor, &());
+    assert_eq!(bytes, same_seed_bytes);
+    assert_ne!(bytes, other_seed_bytes);
+    assert_ne!(bytes, postcard::to_allocvec(&unseeded_proof).unwrap());
+
+    // The verifier must be seeded the same way as the prover.
+    assert!(with_sumcheck_seed([1; 32], || proof
+        .verify(&expr, &accessor, &result, &())
+        .is_ok()));
+    assert!(with_sumcheck_seed([2; 32], || proof
+        .verify(&expr, &accessor, &result, &())
+        .is_err()));
+    assert!(proof.verify(&expr, &accessor, &result, &()).is_err());
+
+    // A seed set in the options takes precedence over the installed one.
+    let options = QueryProofOptions {
+        seed: Some([1; 32]),
+        ..Default::default()
+    };
+    assert!(with_sumcheck_seed([2; 32], || proof
+        .verify_with_options(&expr, &accessor, &result, &(), &options)
+        .is_ok()));
+}
+
+#[cfg(feature = "deterministic")]
+#[test]
+fn the_sumcheck_seed_is_restored_if_the_closure_panics() {
+    use super::sumcheck_seed::current_sumcheck_seed;
+    use crate::sql::proof::with_sumcheck_seed;
+
+    with_sumcheck_seed([1; 32], || {
+        let res = std::panic::catch_unwind(|| with_sumcheck_seed([2; 32], || panic!()));
+        assert!(res.is_err());
+        assert_eq!(current_sumcheck_seed(), Some([1; 32]));
+    });
+    assert_eq!(current_sumcheck_seed(), None);
+}
+
+#[test]
+fn verify_fails_if_the_proof_is_replayed_under_a_different_nonce() {
+    let expr = DoubleSquareTestProofExecutionPlan::default();
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(
+        "sxt.test".parse().unwrap(),
+        owned_table([bigint("x", [3, 5])]),
+        0,
+        (),
+    );
+    let (proof, result) =
+        QueryProof::<InnerProductProof>::new_with_nonce(&expr, &accessor, &(), b"nonce A");
+    assert!(proof
+        .verify_with_nonce(&expr, &accessor, &result, &(), b"nonce A")
+        .is_ok());
+    assert!(proof
+        .verify_with_nonce(&expr, &accessor, &result, &(), b"nonce B")
+        .is_err());
+    assert!(proof.verify(&expr, &accessor, &result, &()).is_err());
+
+    // an empty nonce is the same as no nonce
+    let (proof, result) = QueryProof::<InnerProductProof>::new(&expr, &accessor, &());
+    assert!(proof
+        .verify_with_nonce(&expr, &accessor, &result, &(), b"")
+        .is_ok());
+    assert!(proof
+        .verify_with_nonce(&expr, &accessor, &result, &(), b"nonce A")
+        .is_err());
+}
+
+#[test]
+fn a_proof_created_from_a_transcript_seed_only_verifies_under_the_same_seed() {
+    let expr = DoubleSquareTestProofExecutionPlan::default();
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(
+        "sxt.test".parse().unwrap(),
+        owned_table([bigint("x", [3, 5])]),
+        0,
+        (),
+    );
+    let seeded = |seed, nonce| QueryProofOptions {
+        nonce,
+        seed: Some(seed),
+        ..Default::default()
+    };
+    let (proof, result) = QueryProof::<InnerProductProof>::new_with_options(
+        &expr,
+        &accessor,
+        &(),
+        &seeded([1; 32], b""),
+    )
+    .unwrap();
+    let QueryData { table, .. } = proof
+        .verify_with_options(&expr, &accessor, &result, &(), &seeded([1; 32], b""))
+        .unwrap();
+    assert_eq!(table, owned_table([bigint("a1", [9, 25])]));
+    assert!(proof
+        .verify_with_options(&expr, &accessor, &result, &(), &seeded([2; 32], b""))
+        .is_err());
+    assert!(proof.verify(&expr, &accessor, &result, &()).is_err());
+
+    // the seed is absorbed deterministically, and independently of the nonce
+    let (other_proof, _) = QueryProof::<InnerProductProof>::new_with_options(
+        &expr,
+        &accessor,
+        &(),
+        &seeded([1; 32], b""),
+    )
+    .unwrap();
+    assert_eq!(
+        postcard::to_allocvec(&other_proof).unwrap(),
+        postcard::to_allocvec(&proof).unwrap()
+    );
+    let (proof, result) = QueryProof::<InnerProductProof>::new_with_options(
+        &expr,
+        &accessor,
+        &(),
+        &seeded([1; 32], b"nonce"),
+    )
+    .unwrap();
+    assert!(proof
+        .verify_with_options(&expr, &accessor, &result, &(), &seeded([1; 32], b"nonce"))
+        .is_ok());
+    assert!(proof
+        .verify_with_options(&expr, &accessor, &result, &(), &seeded([1; 32], b""))
+        .is_err());
+    assert!(proof
+        .verify_with_nonce(&expr, &accessor, &result, &(), b"nonce")
+        .is_err());
+}
+
+#[test]
+fn we_can_prove_and_verify_under_a_keccak_transcript() {
+    let expr = DoubleSquareTestProofExecutionPlan::default();
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(
+        "sxt.test".parse().unwrap(),
+        owned_table([bigint("x", [3, 5])]),
+        0,
+        (),
+    );
+    let (keccak_proof, keccak_result) =
+        QueryProof::<InnerProductProof, Keccak256Transcript>::new(&expr, &accessor, &());
+    let keccak_data = keccak_proof
+        .verify(&expr, &accessor, &keccak_result, &())
+        .unwrap();
+    let (merlin_proof, merlin_result) = QueryProof::<InnerProductProof>::new(&expr, &accessor, &());
+    let merlin_data = merlin_proof
+        .verify(&expr, &accessor, &merlin_result, &())
+        .unwrap();
+
+    // the same result is proven, but from different challenges
+    assert_eq!(keccak_data.table, merlin_data.table);
+    assert_ne!(
+        keccak_proof.sumcheck_proof.round_evaluations(),
+        merlin_proof.sumcheck_proof.round_evaluations()
+    );
+    assert_ne!(keccak_data.verification_hash, merlin_data.verification_hash);
+
+    // a proof only verifies under the transcript it was created with
+    let reinterpreted = QueryProof::<InnerProductProof> {
+        header: keccak_proof.header,
+        bit_distributions: keccak_proof.bit_distributions,
+        commitments: keccak_proof.commitments,
+        sumcheck_proof: keccak_proof.sumcheck_proof,
+        pcs_proof_evaluations: keccak_proof.pcs_proof_evaluations,
+        evaluation_proof: keccak_proof.evaluation_proof,
+        transcript: PhantomData,
+    };
+    assert!(reinterpreted
+        .verify(&expr, &accessor, &keccak_result, &())
+        .is_err());
+}
+
+#[test]
+fn a_filter_selecting_more_rows_than_allowed_fails_to_prove() {
+    let t = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(
+        t,
+        owned_table([
+            bigint("a", (0..20).map(|i| (i * 7) % 11)),
+            bigint("b", 0..20),
+        ]),
+        0,
+        (),
+    );
+    // 9 rows have a <= 4
+    let ast = filter(
+        cols_result(t, &["b"], &accessor),
+        tab(t),
+        lte(column(t, "a", &accessor), const_bigint(4)),
+    );
+    assert!(matches!(
+        QueryProof::<InnerProductProof>::try_new_with_max_result_rows(&ast, &accessor, &(), 8),
+        Err(ProofError::ResultTooLarge { rows: 9, max: 8 })
+    ));
+
+    let (proof, result) =
+        QueryProof::<InnerProductProof>::try_new_with_max_result_rows(&ast, &accessor, &(), 9)
+            .unwrap();
+    let table = proof.verify(&ast, &accessor, &result, &()).unwrap().table;
+    assert_eq!(table.num_rows(), 9);
+}
+
+#[test]
+fn we_can_prove_and_verify_under_each_sumcheck_strategy() {
+    let t = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(
+        t,
+        owned_table([
+            bigint("a", (0..20).map(|i| (i * 7) % 11)),
+            bigint("b", 0..20),
+        ]),
+        0,
+        (),
+    );
+    let ast = filter(
+        cols_result(t, &["b"], &accessor),
+        tab(t),
+        lte(column(t, "a", &accessor), const_bigint(4)),
+    );
+    let proof_and_size = |strategy| {
+        let (proof, result) = QueryProof::<InnerProductProof>::new_with_sumcheck_strategy(
+            &ast,
+            &accessor,
+            &(),
+            strategy,
+        );
+        assert_eq!(proof.sumcheck_proof.strategy(), strategy);
+        let data = proof.verify(&ast, &accessor, &result, &()).unwrap();
+
+        // the verifier reads the strategy from the streamed proof as well
+        let mut bytes = Vec::new();
+        proof.write_streaming(&mut bytes).unwrap();
+        let streamed = QueryProof::<InnerProductProof>::verify_streaming(
+            &mut Cursor::new(&bytes),
+            &ast,
+            &accessor,
+            &result,
+            &(),
+        )
+        .unwrap();
+        assert_eq!(streamed.table, data.table);
+        let size = postcard::to_allocvec(&proof).unwrap().len();
+        (proof, data, size)
+    };
+    let (single_proof, single_data, single_size) = proof_and_size(SumcheckStrategy::SingleVariable);
+    let (paired_proof, paired_data, paired_size) =
+        proof_and_size(SumcheckStrategy::PairedVariables);
+    assert_eq!(single_data.table, paired_data.table);
+
+    // the table has 5 sumcheck variables, so pairing them leaves a single-variable last round
+    assert_eq!(single_proof.sumcheck_proof.round_evaluations().len(), 5);
+    assert_eq!(paired_proof.sumcheck_proof.round_evaluations().len(), 3);
+    assert!(paired_size > single_size);
+
+    // the default strategy binds a single variable per round
+    let (default_proof, _) = QueryProof::<InnerProductProof>::new(&ast, &accessor, &());
+    assert_eq!(
+        default_proof.sumcheck_proof.strategy(),
+        SumcheckStrategy::SingleVariable
+    );
+}
+
+#[cfg(feature = "tracing-spans")]
+#[test]
+fn we_can_record_a_span_around_each_phase_of_proving_and_verifying() {
+    use std::sync::{Arc, Mutex};
+    use tracing::{
+        span::{Attributes, Id},
+        Subscriber,
+    };
+    use tracing_subscriber::{
+        layer::{Context, SubscriberExt},
+        Layer, Registry,
+    };
+
+    struct SpanRecorder(Arc<Mutex<Vec<&'static str>>>);
+    impl<S: Subscriber> Layer<S> for SpanRecorder {
+        fn on_new_span(&self, attrs: &Attributes<'_>, _id: &Id, _ctx: Context<'_, S>) {
+            let name = attrs.metadata().name();
+            if name.starts_with("QueryProof::new::") || name.starts_with("QueryProof::verify::") {
+                self.0.lock().unwrap().push(name);
+            }
+        }
+    }
+
+    let expr = DoubleSquareTestProofExecutionPlan::default();
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(
+        "sxt.test".parse().unwrap(),
+        owned_table([bigint("x", [3, 5])]),
+        0,
+        (),
+    );
+    let spans = Arc::new(Mutex::new(Vec::new()));
+    let subscriber = Registry::default().with(SpanRecorder(spans.clone()));
+    tracing::subscriber::with_default(subscriber, || {
+        let (proof, result) = QueryProof::<InnerProductProof>::new(&expr, &accessor, &());
+        proof.verify(&expr, &accessor, &result, &()).unwrap();
+    });
+    assert_eq!(
+        *spans.lock().unwrap(),
+        [
+            "QueryProof::new::result_evaluation",
+            "QueryProof::new::commitment",
+            "QueryProof::new::sumcheck",
+            "QueryProof::new::evaluation_proof",
+            "QueryProof::verify::count",
+            "QueryProof::verify::sumcheck",
+            "QueryProof::verify::result_evaluation",
+            "QueryProof::verify::final_checks",
+        ]
+    );
+}
+
+#[test]
+fn we_can_verify_a_streamed_proof_with_the_same_result_as_the_in_memory_proof() {
+    let expr = DoubleSquareTestProofExecutionPlan::default();
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(
+        "sxt.test".parse().unwrap(),
+        owned_table([bigint("x", [3, 5])]),
+        0,
+        (),
+    );
+    let (proof, result) = QueryProof::<InnerProductProof>::new(&expr, &accessor, &());
+    let mut bytes = Vec::new();
+    proof.write_streaming(&mut bytes).unwrap();
+
+    let in_memory = proof.verify(&expr, &accessor, &result, &()).unwrap();
+    let streamed = QueryProof::<InnerProductProof>::verify_streaming(
+        &mut Cursor::new(&bytes),
+        &expr,
+        &accessor,
+        &result,
+        &(),
+    )
+    .unwrap();
+    assert_eq!(streamed.table, in_memory.table);
+    assert_eq!(streamed.verification_hash, in_memory.verification_hash);
+
+    // a truncated or tampered stream fails to verify
+    assert!(QueryProof::<InnerProductProof>::verify_streaming(
+        &mut Cursor::new(&bytes[..bytes.len() - 1]),
+        &expr,
+        &accessor,
+        &result,
+        &(),
+    )
+    .is_err());
+    let mut tampered_proof = proof.clone();
+    tampered_proof.commitments[0] = tampered_proof.commitments[0] * Curve25519Scalar::from(2u64);
+    let mut tampered_bytes = Vec::new();
+    tampered_proof.write_streaming(&mut tampered_bytes).unwrap();
+    assert!(QueryProof::<InnerProductProof>::verify_streaming(
+        &mut Cursor::new(&tampered_bytes),
+        &expr,
+        &accessor,
+        &result,
+        &(),
+    )
+    .is_err());
+}
+
+#[test]
+fn verify_streaming_fails_with_proof_too_large_if_the_proof_exceeds_the_limits() {
+    let expr = DoubleSquareTestProofExecutionPlan::default();
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(
+        "sxt.test".parse().unwrap(),
+        owned_table([bigint("x", [3, 5])]),
+        0,
+        (),
+    );
+    let (proof, result) = QueryProof::<InnerProductProof>::new(&expr, &accessor, &());
+    let verify_streaming = |proof: &QueryProof<InnerProductProof>, size_limits| {
+        let mut bytes = Vec::new();
+        proof.write_streaming(&mut bytes).unwrap();
+        let options = QueryProofOptions {
+            size_limits,
+            ..Default::default()
+        };
+        QueryProof::<InnerProductProof>::verify_streaming_with_options(
+            &mut Cursor::new(&bytes),
+            &expr,
+            &accessor,
+            &result,
+            &(),
+            &options,
+        )
+    };
+    assert!(verify_streaming(&proof, ProofSizeLimits::default()).is_ok());
+
+    // the bounds implied by the query apply to a streamed proof
+    let mut oversized_proof = proof.clone();
+    oversized_proof.commitments = vec![Default::default(); 1000];
+    assert!(matches!(
+        verify_streaming(&oversized_proof, ProofSizeLimits::default()),
+        Err(QueryError::ProofError(ProofError::ProofTooLarge(_)))
+    ));
+    let mut oversized_proof = proof.clone();
+    oversized_proof.pcs_proof_evaluations = vec![Curve25519Scalar::default(); 1000];
+    assert!(matches!(
+        verify_streaming(&oversized_proof, ProofSizeLimits::default()),
+        Err(QueryError::ProofError(ProofError::ProofTooLarge(_)))
+    ));
+
+    // and so do the configured limits
+    assert!(matches!(
+        verify_streaming(
+            &proof,
+            ProofSizeLimits {
+                max_commitments: 0,
+                ..Default::default()
+            }
+        ),
+        Err(QueryError::ProofError(ProofError::ProofTooLarge(_)))
+    ));
+    assert!(matches!(
+        verify_streaming(
+            &proof,
+            ProofSizeLimits {
+                max_frame_length: 1,
+                ..Default::default()
+            }
+        ),
+        Err(QueryError::ProofError(ProofError::ProofTooLarge(_)))
+    ));
+}
+
+#[test]
+fn verify_streaming_rejects_a_frame_length_over_the_limit_before_reading_it() {
+    let expr = DoubleSquareTestProofExecutionPlan::default();
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(
+        "sxt.test".parse().unwrap(),
+        owned_table([bigint("x", [3, 5])]),
+        0,
+        (),
+    );
+    let (_, result) = QueryProof::<InnerProductProof>::new(&expr, &accessor, &());
+    // a length prefix claiming far more bytes than follow
+    let bytes = u64::MAX.to_le_bytes();
+    assert!(matches!(
+        QueryProof::<InnerProductProof>::verify_streaming(
+            &mut Cursor::new(&bytes),
+            &expr,
+            &accessor,
+            &result,
+            &(),
+        ),
+        Err(QueryError::ProofError(ProofError::ProofTooLarge(_)))
+    ));
+}
+
+/// Returns the bytes of `proof`, tagged with the given format version.
+fn proof_bytes_with_version(
+    proof: &QueryProof<InnerProductProof>,
+    major: u16,
+    minor: u16,
+) -> Vec<u8> {
+    let mut bytes = proof.to_bytes();
+    bytes[..2].copy_from_slice(&major.to_le_bytes());
+    bytes[2..4].copy_from_slice(&minor.to_le_bytes());
+    bytes
+}
+
+#[test]
+fn we_can_verify_a_proof_tagged_with_an_older_supported_format_version() {
+    let expr = DoubleSquareTestProofExecutionPlan::default();
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(
+        "sxt.test".parse().unwrap(),
+        owned_table([bigint("x", [3, 5])]),
+        0,
+        (),
+    );
+    let (proof, result) = QueryProof::<InnerProductProof>::new(&expr, &accessor, &());
+    let expected = proof.verify(&expr, &accessor, &result, &()).unwrap();
+    for minor in [
+        MIN_SUPPORTED_QUERY_PROOF_FORMAT_MINOR_VERSION,
+        QUERY_PROOF_FORMAT_MINOR_VERSION,
+    ] {
+        let bytes = proof_bytes_with_version(&proof, QUERY_PROOF_FORMAT_MAJOR_VERSION, minor);
+        let read_proof = QueryProof::<InnerProductProof>::try_from_bytes(&bytes).unwrap();
+        let res = read_proof.verify(&expr, &accessor, &result, &()).unwrap();
+        assert_eq!(res.table, expected.table);
+        assert_eq!(res.verification_hash, expected.verification_hash);
+    }
+}
+
+#[test]
+fn we_cannot_read_a_proof_tagged_with_an_unsupported_format_version() {
+    let expr = DoubleSquareTestProofExecutionPlan::default();
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(
+        "sxt.test".parse().unwrap(),
+        owned_table([bigint("x", [3, 5])]),
+        0,
+        (),
+    );
+    let (proof, _) = QueryProof::<InnerProductProof>::new(&expr, &accessor, &());
+    for (major, minor) in [
+        (
+            QUERY_PROOF_FORMAT_MAJOR_VERSION,
+            QUERY_PROOF_FORMAT_MINOR_VERSION + 1,
+        ),
+        (QUERY_PROOF_FORMAT_MAJOR_VERSION + 1, 0),
+        (
+            QUERY_PROOF_FORMAT_MAJOR_VERSION - 1,
+            QUERY_PROOF_FORMAT_MINOR_VERSION,
+        ),
+    ] {
+        let bytes = proof_bytes_with_version(&proof, major, minor);
+        assert!(matches!(
+            QueryProof::<InnerProductProof>::try_from_bytes(&bytes),
+            Err(ProofError::UnsupportedVersion { major: m, minor: n }) if m == major && n == minor
+        ));
+    }
+    assert!(QueryProof::<InnerProductProof>::try_from_bytes(&[1, 0]).is_err());
+}
+
+/// Type to allow us to prove that `sxt.test.x` is all zeros without returning any column
+#[derive(Debug, Serialize)]
+struct AllZeroTestProofExecutionPlan;
+impl AllZeroTestProofExecutionPlan {
+    fn column() -> ColumnRef {
+        ColumnRef::new(
+            "sxt.test".parse().unwrap(),
+            "x".parse().unwrap(),
+            ColumnType::BigInt,
+        )
+    }
+}
+impl<S: Scalar> ProverEvaluate<S> for AllZeroTestProofExecutionPlan {
+    fn result_evaluate<'a>(
+        &self,
+        _builder: &mut ResultBuilder<'a>,
+        _alloc: &'a dyn MleAllocator,
+        _accessor: &'a dyn DataAccessor<S>,
+    ) {
+    }
+
+    fn prover_evaluate<'a>(
+        &self,
+        builder: &mut ProofBuilder<'a, S>,
+        _alloc: &'a dyn MleAllocator,
+        accessor: &'a dyn DataAccessor<S>,
+    ) {
+        let x = accessor.get_column(Self::column());
+        builder.produce_column_mle(Self::column(), x.clone());
+        builder.produce_sumcheck_subpolynomial(
+            SumcheckSubpolynomialType::Identity,
+            vec![(S::ONE, vec![Box::new(x)])],
+        );
+    }
+}
+impl<C: Commitment> ProofExecutionPlan<C> for AllZeroTestProofExecutionPlan {
+    fn count(
+        &self,
+        builder: &mut CountBuilder,
+        _accessor: &dyn MetadataAccessor,
+    ) -> Result<(), ProofError> {
+        builder.count_result_columns(0);
+        builder.count_anchored_mles(1);
+        builder.count_subpolynomials(1);
+        builder.count_degree(2);
+        Ok(())
+    }
+    fn get_length(&self, accessor: &dyn MetadataAccessor) -> usize {
+        accessor.get_length("sxt.test".parse().unwrap())
+    }
+    fn get_offset(&self, accessor: &dyn MetadataAccessor) -> usize {
+        accessor.get_offset("sxt.test".parse().unwrap())
+    }
+    fn verifier_evaluate(
+        &self,
+        builder: &mut VerificationBuilder<C>,
+        accessor: &dyn CommitmentAccessor<C>,
+        _result: Option<&OwnedTable<C::Scalar>>,
+    ) -> Result<(), ProofError> {
+        let x_eval =
+            builder.consume_column_mle(Self::column(), accessor.get_commitment(Self::column()));
+        let rand_eval = builder.mle_evaluations.random_evaluation;
+        builder.produce_sumcheck_subpolynomial_evaluation(&(rand_eval * x_eval));
+        Ok(())
+    }
+    fn get_column_result_fields(&self) -> Vec<ColumnField> {
+        vec![]
+    }
+    fn get_column_references(&self) -> IndexSet<ColumnRef> {
+        IndexSet::from_iter([Self::column()])
+    }
+}
+
+fn prove_all_zero(
+    x: Vec<i64>,
+) -> (
+    ProvableQueryResult,
+    Result<QueryData<Curve25519Scalar>, QueryError>,
+) {
+    let expr = AllZeroTestProofExecutionPlan;
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(
+        "sxt.test".parse().unwrap(),
+        owned_table([bigint("x", x)]),
+        0,
+        (),
+    );
+    let (proof, result) = QueryProof::<InnerProductProof>::new(&expr, &accessor, &());
+    let data = proof.verify(&expr, &accessor, &result, &());
+    (result, data)
+}
+
+#[test]
+fn we_can_verify_a_plan_declaring_zero_result_columns() {
+    let (result, data) = prove_all_zero(vec![0, 0, 0, 0, 0]);
+    assert_eq!(result.num_columns(), 0);
+    let QueryData {
+        table,
+        verification_hash,
+    } = data.unwrap();
+    assert!(table.is_empty());
+    assert_ne!(verification_hash, VerificationHash::default());
+}
+
+#[test]
+fn a_plan_declaring_zero_result_columns_fails_to_verify_a_false_claim() {
+    let (_, data) = prove_all_zero(vec![0, 0, 3, 0, 0]);
+    assert!(data.is_err());
+}
+
+/// Type to allow us to prove a plan that computes the same intermediate column twice, by
+/// proving that `sxt.test.x` is equal to two separately computed copies of itself
+#[derive(Debug, Serialize)]
+struct DuplicateIntermediateTestProofExecutionPlan {
+    /// Whether `sxt.test.x` is produced as a column MLE, which the copies can be aliases of,
+    /// rather than as a plain anchored MLE
+    column_mle: bool,
+}
+impl<S: Scalar> ProverEvaluate<S> for DuplicateIntermediateTestProofExecutionPlan {
+    fn result_evaluate<'a>(
+        &self,
+        _builder: &mut ResultBuilder<'a>,
+        _alloc: &'a dyn MleAllocator,
+        _accessor: &'a dyn DataAccessor<S>,
+    ) {
+    }
+
+    fn prover_evaluate<'a>(
+        &self,
+        builder: &mut ProofBuilder<'a, S>,
+        alloc: &'a dyn MleAllocator,
+        accessor: &'a dyn DataAccessor<S>,
+    ) {
+        let column = AllZeroTestProofExecutionPlan::column();
+        let x = match accessor.get_column(column) {
+            Column::BigInt(x) => x,
+            _ => panic!("x is a bigint column"),
+        };
+        if self.column_mle {
+            builder.produce_column_mle(column, Column::BigInt(x));
+        } else {
+            builder.produce_anchored_mle(x);
+        }
+        for _ in 0..2 {
+            let copy = alloc.alloc_slice_copy(x) as &[_];
+            builder.produce_intermediate_mle(copy);
+            // copy - x = 0
+            builder.produce_sumcheck_subpolynomial(
+                SumcheckSubpolynomialType::Identity,
+                vec![(S::ONE, vec![Box::new(copy)]), (-S::ONE, vec![Box::new(x)])],
+            );
+        }
+    }
+}
+impl<C: Commitment> ProofExecutionPlan<C> for DuplicateIntermediateTestProofExecutionPlan {
+    fn count(
+        &self,
+        builder: &mut CountBuilder,
+        _accessor: &dyn MetadataAccessor,
+    ) -> Result<(), ProofError> {
+        builder.count_result_columns(0);
+        builder.count_anchored_mles(1);
+        builder.count_intermediate_mles(2);
+        builder.count_subpolynomials(2);
+        builder.count_degree(2);
+        Ok(())
+    }
+    fn get_length(&self, accessor: &dyn MetadataAccessor) -> usize {
+        accessor.get_length("sxt.test".parse().unwrap())
+    }
+    fn get_offset(&self, accessor: &dyn MetadataAccessor) -> usize {
+        accessor.get_offset("sxt.test".parse().unwrap())
+    }
+    fn verifier_evaluate(
+        &self,
+        builder: &mut VerificationBuilder<C>,
+        accessor: &dyn CommitmentAccessor<C>,
+        _result: Option<&OwnedTable<C::Scalar>>,
+    ) -> Result<(), ProofError> {
+        let column = AllZeroTestProofExecutionPlan::column();
+        let x_eval = if self.column_mle {
+            builder.consume_column_mle(column, accessor.get_commitment(column))
+        } else {
+            builder.consume_anchored_mle(accessor.get_commitment(column))
+        };
+        let rand_eval = builder.mle_evaluations.random_evaluation;
+        for _ in 0..2 {
+            let copy_eval = builder.consume_intermediate_mle();
+            // copy - x = 0
+            builder.produce_sumcheck_subpolynomial_evaluation(&(rand_eval * (copy_eval - x_eval)));
+        }
+        Ok(())
+    }
+    fn get_column_result_fields(&self) -> Vec<ColumnField> {
+        vec![]
+    }
+    fn get_column_references(&self) -> IndexSet<ColumnRef> {
+        IndexSet::from_iter([AllZeroTestProofExecutionPlan::column()])
+    }
+}
+
+fn prove_duplicate_intermediate(
+    column_mle: bool,
+) -> (
+    DuplicateIntermediateTestProofExecutionPlan,
+    OwnedTableTestAccessor<InnerProductProof>,
+    QueryProof<InnerProductProof>,
+    ProvableQueryResult,
+) {
+    let expr = DuplicateIntermediateTestProofExecutionPlan { column_mle };
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(
+        "sxt.test".parse().unwrap(),
+        owned_table([bigint("x", [3, 1, 4, 1, 5])]),
+        0,
+        (),
+    );
+    let (proof, result) = QueryProof::<InnerProductProof>::new(&expr, &accessor, &());
+    (expr, accessor, proof, result)
+}
+
+#[test]
+fn identical_intermediate_mles_share_a_single_commitment() {
+    let (expr, accessor, proof, result) = prove_duplicate_intermediate(false);
+    assert_eq!(proof.header.intermediate_mles, 2);
+    assert_eq!(proof.header.intermediate_mle_aliases, vec![(1, 1)]);
+    assert_eq!(proof.commitments.len(), 1);
+    assert_eq!(proof.pcs_proof_evaluations.len(), 2);
+    proof.verify(&expr, &accessor, &result, &()).unwrap();
+
+    let mut buffer = Vec::new();
+    proof.write_streaming(&mut buffer).unwrap();
+    QueryProof::<InnerProductProof>::verify_streaming(
+        &mut Cursor::new(buffer),
+        &expr,
+        &accessor,
+        &result,
+        &(),
+    )
+    .unwrap();
+}
+
+#[test]
+fn intermediate_mles_identical_to_a_column_share_its_commitment() {
+    let (expr, accessor, proof, result) = prove_duplicate_intermediate(true);
+    assert_eq!(proof.header.intermediate_mles, 2);
+    assert_eq!(proof.header.anchored_mles, 1);
+    assert_eq!(proof.header.intermediate_mle_aliases, vec![(0, 0), (1, 0)]);
+    assert!(proof.commitments.is_empty());
+    assert_eq!(proof.pcs_proof_evaluations.len(), 1);
+    proof.verify(&expr, &accessor, &result, &()).unwrap();
+}
+
+#[test]
+fn a_proof_with_invalid_intermediate_mle_aliases_fails_to_verify() {
+    let (expr, accessor, proof, result) = prove_duplicate_intermediate(false);
+    for aliases in [
+        vec![],
+        vec![(0, 1)],
+        vec![(1, 2)],
+        vec![(2, 1)],
+        vec![(1, 1), (1, 1)],
+    ] {
+        let mut proof = proof.clone();
+        proof.header.intermediate_mle_aliases = aliases;
+        assert!(proof.verify(&expr, &accessor, &result, &()).is_err());
+    }
+    let (expr, accessor, proof, result) = prove_duplicate_intermediate(true);
+    for aliases in [vec![(0, 0)], vec![(0, 1), (1, 0)], vec![(1, 0), (0, 0)]] {
+        let mut proof = proof.clone();
+        proof.header.intermediate_mle_aliases = aliases;
+        assert!(proof.verify(&expr, &accessor, &result, &()).is_err());
+    }
+}
+
+#[test]
+fn we_can_resolve_intermediate_mle_aliases() {
+    let mut header = ProofHeader::new(
+        4,
+        &ProofCounts {
+            intermediate_mles: 4,
+            ..Default::default()
+        },
+        b"",
+    );
+    assert_eq!(
+        header.resolve_intermediate_mle_aliases(4).unwrap(),
+        vec![None; 4]
+    );
+    header.intermediate_mle_aliases = vec![(0, 3), (1, 0), (3, 2)];
+    assert_eq!(
+        header.resolve_intermediate_mle_aliases(4).unwrap(),
+        vec![Some(3), Some(0), None, Some(2)]
+    );
+    for aliases in [
+        vec![(3, 2), (1, 0)],
+        vec![(1, 0), (1, 2)],
+        vec![(4, 0)],
+        vec![(0, 0), (1, 0), (2, 0), (3, 0), (3, 1)],
+    ] {
+        header.intermediate_mle_aliases = aliases;
+        assert!(header.resolve_intermediate_mle_aliases(4).is_err());
+    }
+}
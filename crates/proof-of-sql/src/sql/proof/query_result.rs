@@ -1,10 +1,15 @@
+use super::{FromQueryRowError, VerificationHash};
+#[cfg(feature = "arrow")]
+use crate::base::database::OwnedArrowConversionError;
 use crate::base::{
-    database::{OwnedTable, OwnedTableError},
+    database::{OwnedColumn, OwnedTable, OwnedTableError},
     proof::ProofError,
     scalar::Scalar,
 };
 #[cfg(feature = "arrow")]
 use arrow::{error::ArrowError, record_batch::RecordBatch};
+use num_bigint::BigInt;
+use serde_json::{json, Value};
 use thiserror::Error;
 
 /// Verifiable query errors
@@ -21,9 +26,21 @@ pub enum QueryError {
     /// Decoding errors other than overflow and invalid string.
     #[error("Miscellaneous decoding error")]
     MiscellaneousDecodingError,
+    /// A raw scalar in the query result was not in its canonical encoding. This does not mean
+    /// that the verification failed, but the result cannot be decoded unambiguously.
+    #[error("Non-canonical scalar encoding")]
+    NonCanonicalScalar,
     /// Indexes are invalid.
     #[error("Invalid indexes")]
     InvalidIndexes,
+    /// The number of result columns does not match the number of declared result fields.
+    #[error("Result has {actual} columns but {expected} result fields were declared")]
+    ColumnCountMismatch {
+        /// The number of declared result fields.
+        expected: usize,
+        /// The number of columns in the result.
+        actual: usize,
+    },
     /// Miscellaneous evaluation error.
     #[error("Miscellaneous evaluation error")]
     MiscellaneousEvaluationError,
@@ -33,6 +50,13 @@ pub enum QueryError {
     /// The table data was invalid. This should never happen because this should get caught by the verifier before reaching this point.
     #[error(transparent)]
     InvalidTable(#[from] OwnedTableError),
+    /// A row of the result could not be read into the requested type.
+    #[error(transparent)]
+    FromQueryRow(#[from] FromQueryRowError),
+    /// The arrow data could not be converted into a table.
+    #[cfg(feature = "arrow")]
+    #[error(transparent)]
+    ArrowConversionError(#[from] OwnedArrowConversionError),
 }
 
 /// The verified results of a query along with metadata produced by verification
@@ -45,7 +69,7 @@ pub struct QueryData<S: Scalar> {
     pub table: OwnedTable<S>,
     /// Additionally, there is a 32-byte verification hash that is included with this table.
     /// This hash provides evidence that the verification has been run.
-    pub verification_hash: [u8; 32],
+    pub verification_hash: VerificationHash,
 }
 
 impl<S: Scalar> QueryData<S> {
@@ -53,6 +77,73 @@ impl<S: Scalar> QueryData<S> {
     pub fn into_record_batch(self) -> RecordBatch {
         self.try_into().unwrap()
     }
+
+    /// Render the result as pretty-printed JSON, for debugging and for passing it to other
+    /// tools. This is not a serialization format: the JSON can't be read back into a
+    /// [`QueryData`].
+    ///
+    /// The JSON has the name and type of each column under `"schema"`, the rows under `"rows"`,
+    /// each an array of values in the order of the schema, and the hex verification hash under
+    /// `"verification_hash"`. Booleans, strings, and integers of up to 64 bits are rendered as
+    /// JSON values of that kind, and timestamps as their integer value in the unit of their type.
+    /// `DECIMAL`, `DECIMAL75` and `SCALAR` values are rendered as strings of their decimal digits,
+    /// so that they don't lose precision.
+    pub fn to_json(&self) -> String {
+        let columns = self.table.inner_table();
+        let schema: Vec<_> = columns
+            .iter()
+            .map(|(name, column)| {
+                json!({"name": name.as_str(), "type": column.column_type().to_string()})
+            })
+            .collect();
+        let rows: Vec<_> = (0..self.table.num_rows())
+            .map(|i| {
+                Value::Array(
+                    columns
+                        .values()
+                        .map(|column| json_value(column, i))
+                        .collect(),
+                )
+            })
+            .collect();
+        let json = json!({
+            "schema": schema,
+            "rows": rows,
+            "verification_hash": self.verification_hash.to_string(),
+        });
+        serde_json::to_string_pretty(&json).expect("a json value can always be rendered")
+    }
+}
+
+/// The JSON rendering of row `i` of `column`. See [`QueryData::to_json`].
+fn json_value<S: Scalar>(column: &OwnedColumn<S>, i: usize) -> Value {
+    match column {
+        OwnedColumn::Boolean(col) => json!(col[i]),
+        OwnedColumn::SmallInt(col) => json!(col[i]),
+        OwnedColumn::Int(col) => json!(col[i]),
+        OwnedColumn::BigInt(col) | OwnedColumn::TimestampTZ(_, _, col) => json!(col[i]),
+        OwnedColumn::VarChar(col) => json!(col[i]),
+        OwnedColumn::Int128(col) => json!(col[i].to_string()),
+        OwnedColumn::Decimal75(_, scale, col) => json!(decimal_string(col[i].into(), *scale)),
+        OwnedColumn::Scalar(col) => json!(Into::<BigInt>::into(col[i]).to_string()),
+    }
+}
+
+/// The decimal digits of `value * 10^-scale`.
+fn decimal_string(value: BigInt, scale: i8) -> String {
+    if scale <= 0 {
+        return (value * BigInt::from(10).pow(scale.unsigned_abs().into())).to_string();
+    }
+    let scale = scale as usize;
+    let digits = value.magnitude().to_string();
+    let digits = format!("{digits:0>width$}", width = scale + 1);
+    let (integer, fraction) = digits.split_at(digits.len() - scale);
+    let sign = if value.sign() == num_bigint::Sign::Minus {
+        "-"
+    } else {
+        ""
+    };
+    format!("{sign}{integer}.{fraction}")
 }
 
 #[cfg(feature = "arrow")]
@@ -64,5 +155,17 @@ impl<S: Scalar> TryFrom<QueryData<S>> for RecordBatch {
     }
 }
 
+#[cfg(feature = "arrow")]
+impl<S: Scalar> OwnedTable<S> {
+    /// Build a table from an arrow `RecordBatch` so that it can be fed to the prover.
+    ///
+    /// `Boolean`, `Int16`, `Int32`, `Int64`, `Decimal128(38, 0)`, `Decimal256`, `Utf8` and
+    /// `Timestamp` columns are supported. Columns of any other type, and columns containing
+    /// nulls, are rejected.
+    pub fn try_from_record_batch(batch: &RecordBatch) -> Result<Self, QueryError> {
+        Ok(Self::try_from(batch.clone())?)
+    }
+}
+
 /// The result of a query -- either an error or a table.
 pub type QueryResult<S> = Result<QueryData<S>, QueryError>;
@@ -0,0 +1,75 @@
+use super::{QueryData, VerificationHash};
+use crate::base::{
+    database::{owned_table_utility::*, ColumnType, OwnedTable},
+    math::decimal::Precision,
+    scalar::Curve25519Scalar,
+};
+use serde_json::{json, Value};
+
+fn query_data(table: OwnedTable<Curve25519Scalar>) -> QueryData<Curve25519Scalar> {
+    QueryData {
+        table,
+        verification_hash: VerificationHash::from([0xab; 32]),
+    }
+}
+
+#[test]
+fn we_can_round_trip_a_result_through_json() {
+    let table = owned_table([
+        bigint("id", [1_i64, -2]),
+        varchar("name", ["apple", "pear \"bartlett\""]),
+        boolean("ripe", [true, false]),
+    ]);
+    let json: Value = serde_json::from_str(&query_data(table.clone()).to_json()).unwrap();
+    assert_eq!(
+        json["schema"],
+        json!([
+            {"name": "id", "type": "BIGINT"},
+            {"name": "name", "type": "VARCHAR"},
+            {"name": "ripe", "type": "BOOLEAN"},
+        ])
+    );
+    assert_eq!(
+        json["verification_hash"],
+        json!(VerificationHash::from([0xab; 32]).to_string())
+    );
+
+    let rows = json["rows"].as_array().unwrap();
+    let round_tripped = owned_table([
+        bigint("id", rows.iter().map(|row| row[0].as_i64().unwrap())),
+        varchar("name", rows.iter().map(|row| row[1].as_str().unwrap())),
+        boolean("ripe", rows.iter().map(|row| row[2].as_bool().unwrap())),
+    ]);
+    assert_eq!(round_tripped, table);
+}
+
+#[test]
+fn wide_numeric_values_are_rendered_as_json_strings() {
+    let table = owned_table([
+        int128("i", [i128::MAX, -1]),
+        decimal75("d", 10, 2, [12345_i64, -5]),
+        decimal75("e", 10, -2, [3_i64, 0]),
+        scalar("s", [-7_i64, 8]),
+    ]);
+    let json: Value = serde_json::from_str(&query_data(table).to_json()).unwrap();
+    assert_eq!(
+        json["schema"][1]["type"],
+        json!(ColumnType::Decimal75(Precision::new(10).unwrap(), 2).to_string())
+    );
+    assert_eq!(
+        json["rows"],
+        json!([
+            [i128::MAX.to_string(), "123.45", "300", "-7"],
+            ["-1", "-0.05", "0", "8"],
+        ])
+    );
+}
+
+#[test]
+fn an_empty_result_renders_as_json_with_no_rows() {
+    let json: Value =
+        serde_json::from_str(&query_data(owned_table([bigint("a", [0_i64; 0])])).to_json())
+            .unwrap();
+    assert_eq!(json["schema"], json!([{"name": "a", "type": "BIGINT"}]));
+    assert_eq!(json["rows"], json!([]));
+}
@@ -0,0 +1,105 @@
+use crate::base::{
+    database::{ColumnType, OwnedColumn, OwnedTable},
+    scalar::Scalar,
+};
+use proof_of_sql_parser::Identifier;
+use thiserror::Error;
+
+/// Errors that occur when reading a row of a query result into a typed value.
+#[derive(Error, Debug, PartialEq, Eq)]
+pub enum FromQueryRowError {
+    /// The result has no column with the requested name.
+    #[error("Result has no column named {0}")]
+    MissingColumn(String),
+    /// The result column cannot be read as the requested type.
+    #[error("Result column {column} of type {actual} cannot be read as {expected}")]
+    TypeMismatch {
+        /// The name of the column
+        column: String,
+        /// The requested type
+        expected: &'static str,
+        /// The type of the column
+        actual: ColumnType,
+    },
+}
+
+/// A type that a value of a result column can be read as.
+pub trait FromColumnValue<S: Scalar>: Sized {
+    /// Reads the value at `index` of `column`, or returns `None` if the column has another type.
+    fn from_column_value(column: &OwnedColumn<S>, index: usize) -> Option<Self>;
+}
+
+macro_rules! impl_from_column_value {
+    ($type:ty, $($variant:pat => $values:ident),+) => {
+        impl<S: Scalar> FromColumnValue<S> for $type {
+            fn from_column_value(column: &OwnedColumn<S>, index: usize) -> Option<Self> {
+                match column {
+                    $($variant => $values.get(index).cloned(),)+
+                    _ => None,
+                }
+            }
+        }
+    };
+}
+
+impl_from_column_value!(bool, OwnedColumn::Boolean(values) => values);
+impl_from_column_value!(i16, OwnedColumn::SmallInt(values) => values);
+impl_from_column_value!(i32, OwnedColumn::Int(values) => values);
+impl_from_column_value!(
+    i64,
+    OwnedColumn::BigInt(values) => values,
+    OwnedColumn::TimestampTZ(_, _, values) => values
+);
+impl_from_column_value!(i128, OwnedColumn::Int128(values) => values);
+impl_from_column_value!(String, OwnedColumn::VarChar(values) => values);
+
+/// A row of a verified query result, whose values can be read by column name.
+pub struct QueryRow<'a, S: Scalar> {
+    table: &'a OwnedTable<S>,
+    index: usize,
+}
+
+impl<'a, S: Scalar> QueryRow<'a, S> {
+    /// Create a view of the row at `index` of `table`.
+    pub(crate) fn new(table: &'a OwnedTable<S>, index: usize) -> Self {
+        Self { table, index }
+    }
+
+    /// Returns the index of the row in the result.
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Reads the value of the column named `column` in this row as a `T`.
+    pub fn get<T: FromColumnValue<S>>(&self, column: &str) -> Result<T, FromQueryRowError> {
+        let owned_column = Identifier::try_new(column)
+            .ok()
+            .and_then(|identifier| self.table.inner_table().get(&identifier))
+            .ok_or_else(|| FromQueryRowError::MissingColumn(column.to_string()))?;
+        T::from_column_value(owned_column, self.index).ok_or_else(|| {
+            FromQueryRowError::TypeMismatch {
+                column: column.to_string(),
+                expected: core::any::type_name::<T>(),
+                actual: owned_column.column_type(),
+            }
+        })
+    }
+}
+
+/// A type that a row of a query result can be read into.
+///
+/// This is usually derived with `#[derive(FromQueryRow)]`, which reads each field of a struct from
+/// the result column of the same name.
+pub trait FromQueryRow<S: Scalar>: Sized {
+    /// Reads a row of a query result.
+    fn from_query_row(row: &QueryRow<'_, S>) -> Result<Self, FromQueryRowError>;
+}
+
+/// Reads every row of `table` into a `T`.
+pub(crate) fn rows_into<T: FromQueryRow<S>, S: Scalar>(
+    table: &OwnedTable<S>,
+) -> Result<Vec<T>, FromQueryRowError> {
+    (0..table.num_rows())
+        .map(|index| T::from_query_row(&QueryRow::new(table, index)))
+        .collect()
+}
@@ -0,0 +1,83 @@
+use super::{FromQueryRow, FromQueryRowError, QueryError, VerifiableQueryResult};
+use crate::{
+    base::{
+        commitment::InnerProductProof,
+        database::{owned_table_utility::*, ColumnType, OwnedTableTestAccessor},
+    },
+    sql::ast::{test_utility::*, ProofPlan},
+};
+use curve25519_dalek::RistrettoPoint;
+
+#[derive(Debug, PartialEq, FromQueryRow)]
+struct Item {
+    id: i64,
+    name: String,
+}
+
+#[derive(Debug, FromQueryRow)]
+struct MissingField {
+    #[allow(dead_code)]
+    price: i64,
+}
+
+#[derive(Debug, FromQueryRow)]
+struct MistypedField {
+    #[allow(dead_code)]
+    name: i64,
+}
+
+fn accessor_and_filter() -> (
+    OwnedTableTestAccessor<InnerProductProof>,
+    ProofPlan<RistrettoPoint>,
+) {
+    let data = owned_table([
+        bigint("id", [1, 2, 3, 4]),
+        varchar("name", ["apple", "pear", "plum", "fig"]),
+        bigint("stock", [0, 7, 3, 0]),
+    ]);
+    let t = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(t, data, 0, ());
+    let ast = filter(
+        cols_result(t, &["id", "name"], &accessor),
+        tab(t),
+        not(equal(column(t, "stock", &accessor), const_bigint(0))),
+    );
+    (accessor, ast)
+}
+
+#[test]
+fn we_can_read_the_rows_of_a_verified_filter_result_into_structs() {
+    let (accessor, ast) = accessor_and_filter();
+    let res = VerifiableQueryResult::<InnerProductProof>::new(&ast, &accessor, &());
+    let items: Vec<Item> = res.verify_into(&ast, &accessor, &()).unwrap();
+    assert_eq!(
+        items,
+        [
+            Item {
+                id: 2,
+                name: "pear".to_string()
+            },
+            Item {
+                id: 3,
+                name: "plum".to_string()
+            },
+        ]
+    );
+}
+
+#[test]
+fn we_cannot_read_rows_into_structs_that_do_not_match_the_result() {
+    let (accessor, ast) = accessor_and_filter();
+    let res = VerifiableQueryResult::<InnerProductProof>::new(&ast, &accessor, &());
+    assert!(matches!(
+        res.verify_into::<MissingField>(&ast, &accessor, &()),
+        Err(QueryError::FromQueryRow(FromQueryRowError::MissingColumn(column))) if column == "price"
+    ));
+    assert!(matches!(
+        res.verify_into::<MistypedField>(&ast, &accessor, &()),
+        Err(QueryError::FromQueryRow(FromQueryRowError::TypeMismatch {
+            actual: ColumnType::VarChar,
+            ..
+        }))
+    ));
+}
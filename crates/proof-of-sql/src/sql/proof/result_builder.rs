@@ -1,4 +1,5 @@
 use super::{Indexes, ProvableQueryResult, ProvableResultColumn};
+use crate::base::proof::ProofError;
 
 /// Track the result created by a query
 pub struct ResultBuilder<'a> {
@@ -11,6 +12,11 @@ pub struct ResultBuilder<'a> {
     /// the prover after the prover sends the result, but before the prover
     /// send commitments to the intermediate witness columns.
     num_post_result_challenges: usize,
+
+    /// The maximum number of rows the result may have, if any
+    max_result_rows: Option<usize>,
+    /// Set when the result has more rows than allowed
+    result_too_large: Option<ProofError>,
 }
 
 impl<'a> ResultBuilder<'a> {
@@ -21,19 +27,41 @@ impl<'a> ResultBuilder<'a> {
             result_index_vector: Indexes::default(),
             result_columns: Vec::new(),
             num_post_result_challenges: 0,
+            max_result_rows: None,
+            result_too_large: None,
         }
     }
 
+    /// Limit the number of rows the result may have.
+    ///
+    /// See [`ResultBuilder::set_result_indexes`].
+    pub fn set_max_result_rows(&mut self, max_result_rows: usize) {
+        self.max_result_rows = Some(max_result_rows);
+    }
+
     /// Get the length of the table
     pub fn table_length(&self) -> usize {
         self.table_length
     }
 
     /// Set the indexes of the rows select in the result
+    ///
+    /// If more rows are selected than allowed by [`ResultBuilder::set_max_result_rows`], the
+    /// builder records a [`ProofError::ResultTooLarge`], and the query is not proved.
     pub fn set_result_indexes(&mut self, result_index: Indexes) {
+        let rows = result_index.len();
+        if let Some(max) = self.max_result_rows.filter(|&max| rows > max) {
+            self.result_too_large
+                .get_or_insert(ProofError::ResultTooLarge { rows, max });
+        }
         self.result_index_vector = result_index;
     }
 
+    /// Fails if the result has more rows than allowed.
+    pub(super) fn check_result_size(&mut self) -> Result<(), ProofError> {
+        self.result_too_large.take().map_or(Ok(()), Err)
+    }
+
     /// Produce an intermediate result column that will be sent to the verifier.
     pub fn produce_result_column(&mut self, col: impl ProvableResultColumn + 'a) {
         self.result_columns.push(Box::new(col));
@@ -0,0 +1,168 @@
+#[cfg(feature = "prover")]
+use super::ResultBuilder;
+use super::{ProofExecutionPlan, ProvableQueryResult, QueryResult, VerifiableQueryResult};
+#[cfg(feature = "prover")]
+use crate::base::database::DataAccessor;
+use crate::base::{
+    commitment::{Commitment, CommitmentEvaluationProof},
+    database::CommitmentAccessor,
+    proof::ProofError,
+};
+#[cfg(feature = "prover")]
+use bumpalo::Bump;
+use core::ops::Range;
+use serde::{Deserialize, Serialize};
+
+/// A query plan whose result can be split into pages by restricting it to ranges of table rows.
+pub trait PaginatedProofPlan<C: Commitment>: ProofExecutionPlan<C> + Serialize + Sized {
+    /// Restrict the plan to the table rows in `rows`, so that its result only contains the
+    /// results of those rows.
+    ///
+    /// Returns `None` if the plan's result cannot be split this way.
+    fn restrict_to_rows(&self, rows: Range<u64>) -> Option<Self>;
+}
+
+/// The position of the next page in a paginated query result.
+///
+/// Pages are chained together: every page is proven under a nonce derived from the pages before
+/// it, so a page only verifies after the same pages of the same query.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ResultCursor {
+    /// The first table row whose result is in the next page.
+    pub next_row: u64,
+    /// The number of result rows in the pages before the next page, which is where the next
+    /// page starts in the full result.
+    pub start: usize,
+    /// A commitment to every page before the next page.
+    pub chain: [u8; 32],
+}
+
+impl ResultCursor {
+    /// Whether every page of a query over a table with `table_length` rows has been read.
+    pub fn is_complete(&self, table_length: usize) -> bool {
+        self.next_row >= table_length as u64
+    }
+
+    /// The cursor following a page covering `rows` with the given result.
+    fn advance(&self, rows: &Range<u64>, result: &Option<ProvableQueryResult>) -> Self {
+        let mut hasher = blake3::Hasher::new();
+        hasher.update(&self.chain);
+        hasher.update(&rows.start.to_le_bytes());
+        hasher.update(&rows.end.to_le_bytes());
+        hasher.update(&postcard::to_allocvec(result).expect("results are serializable"));
+        Self {
+            next_row: rows.end,
+            start: self.start + result.as_ref().map_or(0, |result| result.indexes().len()),
+            chain: *hasher.finalize().as_bytes(),
+        }
+    }
+}
+
+/// A window `[start, start + page_size)` of a query's result, along with a proof of it.
+///
+/// Each page is the result of the query restricted to a range of table rows, which starts where
+/// the previous page ended. Only the last page of a query may have fewer than `page_size` rows,
+/// so the pages reassemble into the full result.
+#[derive(Clone, Serialize, Deserialize)]
+pub struct VerifiableResultPage<CP: CommitmentEvaluationProof> {
+    /// The table rows whose results are in the page.
+    pub rows: Range<u64>,
+    /// The page's result and its proof.
+    pub result: VerifiableQueryResult<CP>,
+}
+
+impl<CP: CommitmentEvaluationProof> VerifiableResultPage<CP> {
+    /// Prove the page of the result of `expr` that starts at `cursor` and has `page_size` rows,
+    /// or fewer if it is the last page.
+    #[cfg(feature = "prover")]
+    pub fn new(
+        expr: &impl PaginatedProofPlan<CP::Commitment>,
+        accessor: &impl DataAccessor<CP::Scalar>,
+        setup: &CP::ProverPublicSetup<'_>,
+        cursor: &ResultCursor,
+        page_size: usize,
+    ) -> Self {
+        let table_length = expr.get_length(accessor) as u64;
+        assert!(
+            cursor.next_row < table_length,
+            "the result has no more pages"
+        );
+        assert!(page_size > 0, "pages must have at least one row");
+        let num_result_rows = |end: u64| {
+            let restricted = expr
+                .restrict_to_rows(cursor.next_row..end)
+                .expect("the plan cannot be paginated");
+            let alloc = Bump::new();
+            let mut builder = ResultBuilder::new(table_length as usize);
+            restricted.result_evaluate(&mut builder, &alloc, accessor);
+            builder.make_provable_query_result().indexes().len()
+        };
+
+        // find the shortest range of rows whose results fill the page
+        let (mut low, mut high) = (cursor.next_row + 1, table_length);
+        while low < high {
+            let mid = low + (high - low) / 2;
+            if num_result_rows(mid) >= page_size {
+                high = mid;
+            } else {
+                low = mid + 1;
+            }
+        }
+        let rows = cursor.next_row..high;
+
+        let restricted = expr
+            .restrict_to_rows(rows.clone())
+            .expect("the plan cannot be paginated");
+        let result =
+            VerifiableQueryResult::new_with_nonce(&restricted, accessor, setup, &cursor.chain);
+        Self { rows, result }
+    }
+
+    /// The cursor of the page that follows this one.
+    pub fn next_cursor(&self, cursor: &ResultCursor) -> ResultCursor {
+        cursor.advance(&self.rows, &self.result.provable_result)
+    }
+
+    /// Verify that this is the page of the result of `expr` that starts at `cursor` and has
+    /// `page_size` rows, or fewer if it is the last page.
+    ///
+    /// Upon success, this returns the page of the result. The cursor of the next page is then
+    /// given by [`VerifiableResultPage::next_cursor`].
+    ///
+    /// Note: This does NOT transform the result!
+    pub fn verify(
+        &self,
+        expr: &impl PaginatedProofPlan<CP::Commitment>,
+        accessor: &impl CommitmentAccessor<CP::Commitment>,
+        setup: &CP::VerifierPublicSetup<'_>,
+        cursor: &ResultCursor,
+        page_size: usize,
+    ) -> QueryResult<CP::Scalar> {
+        let table_length = expr.get_length(accessor) as u64;
+        if self.rows.start != cursor.next_row
+            || self.rows.start >= self.rows.end
+            || self.rows.end > table_length
+        {
+            Err(ProofError::VerificationError(
+                "page does not cover the rows following the cursor",
+            ))?;
+        }
+        let restricted =
+            expr.restrict_to_rows(self.rows.clone())
+                .ok_or(ProofError::VerificationError(
+                    "query result cannot be paginated",
+                ))?;
+        let data = self
+            .result
+            .verify_with_nonce(&restricted, accessor, setup, &cursor.chain)?;
+        let is_last_page = self.rows.end == table_length;
+        if data.table.num_rows() > page_size
+            || (!is_last_page && data.table.num_rows() != page_size)
+        {
+            Err(ProofError::VerificationError(
+                "page does not have the requested number of rows",
+            ))?;
+        }
+        Ok(data)
+    }
+}
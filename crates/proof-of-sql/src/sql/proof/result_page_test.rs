@@ -0,0 +1,135 @@
+use super::{ResultCursor, VerifiableQueryResult, VerifiableResultPage};
+use crate::{
+    base::{
+        commitment::InnerProductProof,
+        database::{owned_table_utility::*, OwnedTableTestAccessor},
+    },
+    sql::ast::{test_utility::*, ProofPlan},
+};
+use curve25519_dalek::RistrettoPoint;
+
+fn accessor_and_filter() -> (
+    OwnedTableTestAccessor<InnerProductProof>,
+    ProofPlan<RistrettoPoint>,
+) {
+    let data = owned_table([
+        bigint("a", [5_i64, 1, 5, 5, 2, 3, 5, 4, 5, 1, 5, 2]),
+        bigint("b", [0_i64, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11]),
+    ]);
+    let t = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(t, data, 0, ());
+    let ast = filter(
+        cols_result(t, &["a", "b"], &accessor),
+        tab(t),
+        equal(column(t, "a", &accessor), const_bigint(5)),
+    );
+    (accessor, ast)
+}
+
+#[test]
+fn we_can_prove_a_filter_result_in_pages_that_reassemble_into_the_full_result() {
+    let (accessor, ast) = accessor_and_filter();
+    let page_size = 4;
+
+    let first_cursor = ResultCursor::default();
+    let first_page = VerifiableResultPage::<InnerProductProof>::new(
+        &ast,
+        &accessor,
+        &(),
+        &first_cursor,
+        page_size,
+    );
+    let first = first_page
+        .verify(&ast, &accessor, &(), &first_cursor, page_size)
+        .unwrap()
+        .table;
+    assert_eq!(
+        first,
+        owned_table([bigint("a", [5_i64, 5, 5, 5]), bigint("b", [0_i64, 2, 3, 6])])
+    );
+
+    let second_cursor = first_page.next_cursor(&first_cursor);
+    assert_eq!(second_cursor.start, page_size);
+    assert!(!second_cursor.is_complete(12));
+    let second_page = VerifiableResultPage::<InnerProductProof>::new(
+        &ast,
+        &accessor,
+        &(),
+        &second_cursor,
+        page_size,
+    );
+    let second = second_page
+        .verify(&ast, &accessor, &(), &second_cursor, page_size)
+        .unwrap()
+        .table;
+    assert_eq!(
+        second,
+        owned_table([bigint("a", [5_i64, 5]), bigint("b", [8_i64, 10])])
+    );
+    assert!(second_page.next_cursor(&second_cursor).is_complete(12));
+
+    // the pages reassemble into the full result
+    let full = VerifiableQueryResult::<InnerProductProof>::new(&ast, &accessor, &())
+        .verify(&ast, &accessor, &())
+        .unwrap()
+        .table;
+    let reassembled: Vec<i64> = first.inner_table()[1]
+        .i64_iter()
+        .chain(second.inner_table()[1].i64_iter())
+        .copied()
+        .collect();
+    assert_eq!(
+        reassembled,
+        full.inner_table()[1]
+            .i64_iter()
+            .copied()
+            .collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn we_cannot_verify_a_page_out_of_order_or_with_the_wrong_size() {
+    let (accessor, ast) = accessor_and_filter();
+    let page_size = 4;
+    let first_cursor = ResultCursor::default();
+    let first_page = VerifiableResultPage::<InnerProductProof>::new(
+        &ast,
+        &accessor,
+        &(),
+        &first_cursor,
+        page_size,
+    );
+    let second_cursor = first_page.next_cursor(&first_cursor);
+    let second_page = VerifiableResultPage::<InnerProductProof>::new(
+        &ast,
+        &accessor,
+        &(),
+        &second_cursor,
+        page_size,
+    );
+
+    // a page only verifies at the cursor it was created for
+    assert!(second_page
+        .verify(&ast, &accessor, &(), &first_cursor, page_size)
+        .is_err());
+    let mut wrong_chain = second_cursor;
+    wrong_chain.chain = [1; 32];
+    assert!(second_page
+        .verify(&ast, &accessor, &(), &wrong_chain, page_size)
+        .is_err());
+
+    // every page but the last must be full
+    assert!(first_page
+        .verify(&ast, &accessor, &(), &first_cursor, page_size + 1)
+        .is_err());
+    assert!(first_page
+        .verify(&ast, &accessor, &(), &first_cursor, page_size - 1)
+        .is_err());
+
+    // a page cannot cover fewer rows than it claims
+    let mut truncated_page = first_page.clone();
+    truncated_page.rows.end -= 1;
+    assert!(truncated_page
+        .verify(&ast, &accessor, &(), &first_cursor, page_size)
+        .is_err());
+}
@@ -0,0 +1,190 @@
+use super::{CountBuilder, ProofExecutionPlan, VerificationBuilder};
+#[cfg(feature = "prover")]
+use super::{Indexes, ProofBuilder, ProverEvaluate, ResultBuilder, SumcheckSubpolynomialType};
+use crate::base::{
+    commitment::Commitment,
+    database::{
+        owned_table_utility::{bigint, owned_table},
+        ColumnField, ColumnRef, ColumnType, CommitmentAccessor, MetadataAccessor, OwnedTable,
+        TableRef,
+    },
+    proof::ProofError,
+    scalar::Scalar,
+};
+#[cfg(feature = "prover")]
+use crate::base::{database::DataAccessor, MleAllocator};
+use indexmap::IndexSet;
+use serde::Serialize;
+
+/// A synthetic plan whose proof grows along each dimension independently, so that the cost of
+/// proving and verifying can be measured as any one of them grows.
+///
+/// The plan reads the bigint columns `c0`, `c1`, ... of [`ScalingTestProofExecutionPlan::table_ref`],
+/// filled by [`ScalingTestProofExecutionPlan::table`], and returns the square of each of them.
+/// Every subpolynomial proves that one result column is the square of its column, cycling
+/// through the columns, so there can be more or fewer subpolynomials than columns.
+///
+/// Create it with [`generate_scaling_plan`].
+#[derive(Debug, Serialize)]
+pub struct ScalingTestProofExecutionPlan {
+    num_columns: usize,
+    num_subpolynomials: usize,
+    table_length: usize,
+}
+
+/// Build a [`ScalingTestProofExecutionPlan`] over `num_columns` columns of `table_length` rows,
+/// whose proof has `num_subpolynomials` sumcheck subpolynomials.
+///
+/// # Panics
+/// Panics if there are subpolynomials but no columns for them to refer to.
+pub fn generate_scaling_plan(
+    num_columns: usize,
+    num_subpolynomials: usize,
+    table_length: usize,
+) -> ScalingTestProofExecutionPlan {
+    assert!(
+        num_columns > 0 || num_subpolynomials == 0,
+        "subpolynomials need a column to refer to"
+    );
+    ScalingTestProofExecutionPlan {
+        num_columns,
+        num_subpolynomials,
+        table_length,
+    }
+}
+
+impl ScalingTestProofExecutionPlan {
+    /// The table the plan reads.
+    pub fn table_ref(&self) -> TableRef {
+        "sxt.scaling".parse().unwrap()
+    }
+
+    /// The data the plan expects in [`ScalingTestProofExecutionPlan::table_ref`].
+    pub fn table<S: Scalar>(&self) -> OwnedTable<S> {
+        owned_table((0..self.num_columns).map(|j| {
+            bigint(
+                format!("c{j}"),
+                (0..self.table_length).map(|i| Self::value(i, j)),
+            )
+        }))
+    }
+
+    fn value(row: usize, column: usize) -> i64 {
+        (row * (column + 1)) as i64
+    }
+
+    fn column_ref(&self, column: usize) -> ColumnRef {
+        ColumnRef::new(
+            self.table_ref(),
+            format!("c{column}").parse().unwrap(),
+            ColumnType::BigInt,
+        )
+    }
+}
+
+#[cfg(feature = "prover")]
+impl<S: Scalar> ProverEvaluate<S> for ScalingTestProofExecutionPlan {
+    fn result_evaluate<'a>(
+        &self,
+        builder: &mut ResultBuilder<'a>,
+        alloc: &'a dyn MleAllocator,
+        _accessor: &'a dyn DataAccessor<S>,
+    ) {
+        builder.set_result_indexes(Indexes::Dense(0..self.table_length as u64));
+        for j in 0..self.num_columns {
+            let squares = alloc.alloc_slice_fill_with(self.table_length, |i| {
+                Self::value(i, j) * Self::value(i, j)
+            });
+            builder.produce_result_column(squares as &[_]);
+        }
+    }
+
+    fn prover_evaluate<'a>(
+        &self,
+        builder: &mut ProofBuilder<'a, S>,
+        alloc: &'a dyn MleAllocator,
+        accessor: &'a dyn DataAccessor<S>,
+    ) {
+        let columns: Vec<_> = (0..self.num_columns)
+            .map(|j| {
+                let column = accessor.get_column(self.column_ref(j));
+                builder.produce_column_mle(self.column_ref(j), column.clone());
+                column
+            })
+            .collect();
+        let squares: Vec<&[i64]> = (0..self.num_columns)
+            .map(|j| {
+                alloc.alloc_slice_fill_with(self.table_length, |i| {
+                    Self::value(i, j) * Self::value(i, j)
+                }) as &[_]
+            })
+            .collect();
+        for k in 0..self.num_subpolynomials {
+            let j = k % self.num_columns;
+            // squares_j - c_j * c_j = 0
+            builder.produce_sumcheck_subpolynomial(
+                SumcheckSubpolynomialType::Identity,
+                vec![
+                    (S::ONE, vec![Box::new(squares[j])]),
+                    (
+                        -S::ONE,
+                        vec![Box::new(columns[j].clone()), Box::new(columns[j].clone())],
+                    ),
+                ],
+            );
+        }
+    }
+}
+
+impl<C: Commitment> ProofExecutionPlan<C> for ScalingTestProofExecutionPlan {
+    fn count(
+        &self,
+        builder: &mut CountBuilder,
+        _accessor: &dyn MetadataAccessor,
+    ) -> Result<(), ProofError> {
+        builder.count_degree(3);
+        builder.count_result_columns(self.num_columns);
+        builder.count_anchored_mles(self.num_columns);
+        builder.count_subpolynomials(self.num_subpolynomials);
+        Ok(())
+    }
+    fn get_length(&self, _accessor: &dyn MetadataAccessor) -> usize {
+        self.table_length
+    }
+    fn get_offset(&self, accessor: &dyn MetadataAccessor) -> usize {
+        accessor.get_offset(self.table_ref())
+    }
+    fn verifier_evaluate(
+        &self,
+        builder: &mut VerificationBuilder<C>,
+        accessor: &dyn CommitmentAccessor<C>,
+        _result: Option<&OwnedTable<C::Scalar>>,
+    ) -> Result<(), ProofError> {
+        let square_evals: Vec<_> = (0..self.num_columns)
+            .map(|_| builder.consume_result_mle())
+            .collect();
+        let column_evals: Vec<_> = (0..self.num_columns)
+            .map(|j| {
+                let column = self.column_ref(j);
+                builder.consume_column_mle(column, accessor.get_commitment(column))
+            })
+            .collect();
+        let rand_eval = builder.mle_evaluations.random_evaluation;
+        for k in 0..self.num_subpolynomials {
+            let j = k % self.num_columns;
+            // squares_j - c_j * c_j = 0
+            builder.produce_sumcheck_subpolynomial_evaluation(
+                &(rand_eval * (square_evals[j] - column_evals[j] * column_evals[j])),
+            );
+        }
+        Ok(())
+    }
+    fn get_column_result_fields(&self) -> Vec<ColumnField> {
+        (0..self.num_columns)
+            .map(|j| ColumnField::new(format!("c{j}").parse().unwrap(), ColumnType::BigInt))
+            .collect()
+    }
+    fn get_column_references(&self) -> IndexSet<ColumnRef> {
+        (0..self.num_columns).map(|j| self.column_ref(j)).collect()
+    }
+}
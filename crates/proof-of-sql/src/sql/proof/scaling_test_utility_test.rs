@@ -0,0 +1,48 @@
+use super::{generate_scaling_plan, VerifiableQueryResult};
+use crate::base::{
+    commitment::InnerProductProof,
+    database::{owned_table_utility::*, OwnedTableTestAccessor},
+    scalar::Curve25519Scalar,
+};
+
+#[test]
+fn we_can_prove_and_verify_a_small_scaling_plan() {
+    for (num_columns, num_subpolynomials, table_length) in
+        [(1, 1, 1), (2, 1, 4), (2, 5, 4), (3, 3, 7), (3, 0, 5)]
+    {
+        let expr = generate_scaling_plan(num_columns, num_subpolynomials, table_length);
+        let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(
+            expr.table_ref(),
+            expr.table(),
+            0,
+            (),
+        );
+        let res = VerifiableQueryResult::new(&expr, &accessor, &());
+        let table = res.verify(&expr, &accessor, &()).unwrap().table;
+        assert_eq!(table.num_columns(), num_columns);
+        assert_eq!(table.num_rows(), table_length);
+    }
+}
+
+#[test]
+fn the_scaling_plan_returns_the_square_of_each_column() {
+    let expr = generate_scaling_plan(2, 2, 3);
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(
+        expr.table_ref(),
+        expr.table(),
+        0,
+        (),
+    );
+    let res = VerifiableQueryResult::new(&expr, &accessor, &());
+    let table = res.verify(&expr, &accessor, &()).unwrap().table;
+    assert_eq!(
+        table,
+        owned_table::<Curve25519Scalar>([bigint("c0", [0, 1, 4]), bigint("c1", [0, 4, 16])])
+    );
+}
+
+#[test]
+#[should_panic(expected = "subpolynomials need a column to refer to")]
+fn we_cannot_generate_a_scaling_plan_with_subpolynomials_but_no_columns() {
+    generate_scaling_plan(0, 1, 4);
+}
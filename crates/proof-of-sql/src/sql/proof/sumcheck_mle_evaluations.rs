@@ -27,6 +27,8 @@ pub struct SumcheckMleEvaluations<'a, S: Scalar> {
     /// This is used within sumcheck to establish that a given expression
     /// is zero across all entries.
     pub random_evaluation: S,
+    /// The random point generated by sumcheck.
+    pub evaluation_point: &'a [S],
     /// The evaluations (at the random point generated by sumcheck) of the mles that are evaluated by the inner product argument. These are batched together and checked by a single IPA.
     pub pcs_proof_evaluations: &'a [S],
     /// The evaluations (at the random point generated by sumcheck) of the final result table columns.
@@ -44,7 +46,7 @@ impl<'a, S: Scalar> SumcheckMleEvaluations<'a, S> {
     /// - `result_indexes` - the indexes of the entries in the result columns. This can be sparse or dense
     pub fn new(
         table_length: usize,
-        evaluation_point: &[S],
+        evaluation_point: &'a [S],
         sumcheck_random_scalars: &SumcheckRandomScalars<S>,
         pcs_proof_evaluations: &'a [S],
         result_evaluations: &'a [S],
@@ -69,9 +71,19 @@ impl<'a, S: Scalar> SumcheckMleEvaluations<'a, S> {
             num_sumcheck_variables: evaluation_point.len(),
             one_evaluation,
             random_evaluation,
+            evaluation_point,
             pcs_proof_evaluations,
             result_evaluations,
             result_indexes_evaluation,
         }
     }
+
+    /// The evaluation (at the random point generated by sumcheck) of an MLE {x_i} where
+    ///     x_i = 1, if i < length;
+    ///         = 0, otherwise
+    ///
+    /// This is useful for tables that are shorter than `table_length`.
+    pub fn truncated_one_evaluation(&self, length: usize) -> S {
+        compute_truncated_lagrange_basis_sum(length, self.evaluation_point)
+    }
 }
@@ -67,3 +67,26 @@ fn we_can_track_the_evaluation_of_dense_indexes() {
     // Because the range is the entire table, these should be the same.
     assert_eq!(evals.result_indexes_evaluation, Some(evals.one_evaluation));
 }
+#[test]
+fn we_can_evaluate_truncated_ones_for_tables_shorter_than_the_table_length() {
+    let evaluation_point = [Curve25519Scalar::from(3u64), Curve25519Scalar::from(5u64)];
+    let random_scalars = [
+        Curve25519Scalar::from(123u64),
+        Curve25519Scalar::from(456u64),
+    ];
+
+    let sumcheck_random_scalars = SumcheckRandomScalars::new(&random_scalars, 3, 2);
+
+    let evals = SumcheckMleEvaluations::new(
+        3,
+        &evaluation_point,
+        &sumcheck_random_scalars,
+        &[],
+        &[],
+        &Indexes::Sparse(vec![]),
+    );
+    let expected_eval = (Curve25519Scalar::one() - evaluation_point[0])
+        * (Curve25519Scalar::one() - evaluation_point[1]);
+    assert_eq!(evals.truncated_one_evaluation(1), expected_eval);
+    assert_eq!(evals.truncated_one_evaluation(3), evals.one_evaluation);
+}
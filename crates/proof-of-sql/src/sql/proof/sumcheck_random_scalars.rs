@@ -1,4 +1,6 @@
-use crate::base::{polynomial::compute_evaluation_vector, scalar::Scalar};
+#[cfg(feature = "prover")]
+use crate::base::polynomial::compute_evaluation_vector;
+use crate::base::scalar::Scalar;
 
 /// Accessor for the random scalars used to form the sumcheck polynomial of a query proof
 pub struct SumcheckRandomScalars<'a, S: Scalar> {
@@ -17,6 +19,7 @@ impl<'a, S: Scalar> SumcheckRandomScalars<'a, S> {
         }
     }
 
+    #[cfg(feature = "prover")]
     pub fn compute_entrywise_multipliers(&self) -> Vec<S> {
         let mut v = vec![Default::default(); self.table_length];
         compute_evaluation_vector(&mut v, self.entrywise_point);
@@ -0,0 +1,37 @@
+use std::cell::Cell;
+
+thread_local! {
+    static SEED: Cell<Option<[u8; 32]>> = const { Cell::new(None) };
+}
+
+/// Runs `f` with every query proof created or verified on this thread seeded with `seed`.
+///
+/// The seed is used as the [`QueryProofOptions::seed`](super::QueryProofOptions::seed) of every
+/// proof whose options do not set one, so every challenge (including the sumcheck random scalars)
+/// is a function of it. Both the prover and the verifier must run under the same seed. Proofs are
+/// only reproducible for the same query, data and seed; the seed never replaces any transcript
+/// message.
+///
+/// Any previously installed seed is restored afterwards, even if `f` panics.
+pub fn with_sumcheck_seed<R>(seed: [u8; 32], f: impl FnOnce() -> R) -> R {
+    let _guard = SeedGuard {
+        previous: SEED.with(|cell| cell.replace(Some(seed))),
+    };
+    f()
+}
+
+/// Restores the previously installed seed when dropped.
+struct SeedGuard {
+    previous: Option<[u8; 32]>,
+}
+
+impl Drop for SeedGuard {
+    fn drop(&mut self) {
+        SEED.with(|cell| cell.set(self.previous));
+    }
+}
+
+/// Returns the seed installed on this thread, if any.
+pub(super) fn current_sumcheck_seed() -> Option<[u8; 32]> {
+    SEED.with(Cell::get)
+}
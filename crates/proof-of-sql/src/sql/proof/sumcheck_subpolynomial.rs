@@ -1,3 +1,4 @@
+#[cfg(feature = "prover")]
 use super::CompositePolynomialBuilder;
 use crate::base::{polynomial::MultilinearExtension, scalar::Scalar};
 
@@ -39,6 +40,7 @@ impl<'a, S: Scalar> SumcheckSubpolynomial<'a, S> {
     }
 
     /// Combine the subpolynomial into a combined composite polynomial
+    #[cfg(feature = "prover")]
     pub fn compose(
         &self,
         composite_polynomial: &mut CompositePolynomialBuilder<S>,
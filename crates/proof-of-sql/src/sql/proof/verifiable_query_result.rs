@@ -1,9 +1,12 @@
-use super::{ProofExecutionPlan, ProvableQueryResult, QueryData, QueryProof, QueryResult};
+use super::{
+    query_row::rows_into, FromQueryRow, ProofExecutionPlan, ProvableQueryResult, QueryData,
+    QueryError, QueryProof, QueryProofOptions, QueryResult,
+};
+#[cfg(feature = "prover")]
+use crate::base::database::DataAccessor;
 use crate::base::{
     commitment::CommitmentEvaluationProof,
-    database::{
-        ColumnField, ColumnType, CommitmentAccessor, DataAccessor, OwnedColumn, OwnedTable,
-    },
+    database::{ColumnField, ColumnType, CommitmentAccessor, OwnedColumn, OwnedTable},
     proof::ProofError,
     scalar::Scalar,
 };
@@ -78,14 +81,25 @@ impl<CP: CommitmentEvaluationProof> VerifiableQueryResult<CP> {
     ///
     /// This function both computes the result of a query and constructs a proof of the results
     /// validity.
+    #[cfg(feature = "prover")]
     pub fn new(
         expr: &(impl ProofExecutionPlan<CP::Commitment> + Serialize),
         accessor: &impl DataAccessor<CP::Scalar>,
         setup: &CP::ProverPublicSetup<'_>,
     ) -> Self {
-        // a query must have at least one result column; if not, it should
-        // have been rejected at the parsing stage.
+        Self::new_with_nonce(expr, accessor, setup, &[])
+    }
 
+    /// Form a `VerifiableQueryResult` whose proof is bound to an application-supplied `nonce`.
+    ///
+    /// See [`QueryProof::new_with_nonce`].
+    #[cfg(feature = "prover")]
+    pub fn new_with_nonce(
+        expr: &(impl ProofExecutionPlan<CP::Commitment> + Serialize),
+        accessor: &impl DataAccessor<CP::Scalar>,
+        setup: &CP::ProverPublicSetup<'_>,
+        nonce: &[u8],
+    ) -> Self {
         // handle the empty case
         if expr.is_empty(accessor) {
             return VerifiableQueryResult {
@@ -94,7 +108,7 @@ impl<CP: CommitmentEvaluationProof> VerifiableQueryResult<CP> {
             };
         }
 
-        let (proof, res) = QueryProof::new(expr, accessor, setup);
+        let (proof, res) = QueryProof::new_with_nonce(expr, accessor, setup, nonce);
         Self {
             provable_result: Some(res),
             proof: Some(proof),
@@ -114,9 +128,69 @@ impl<CP: CommitmentEvaluationProof> VerifiableQueryResult<CP> {
         accessor: &impl CommitmentAccessor<CP::Commitment>,
         setup: &CP::VerifierPublicSetup<'_>,
     ) -> QueryResult<CP::Scalar> {
-        // a query must have at least one result column; if not, it should
-        // have been rejected at the parsing stage.
+        self.verify_with_nonce(expr, accessor, setup, &[])
+    }
+
+    /// Verify a `VerifiableQueryResult`, and read each row of the verified result into a `T`.
+    ///
+    /// `T` is usually a struct with `#[derive(FromQueryRow)]`, whose fields are read from the
+    /// result columns of the same names.
+    ///
+    /// Note: This does NOT transform the result!
+    pub fn verify_into<T: FromQueryRow<CP::Scalar>>(
+        &self,
+        expr: &(impl ProofExecutionPlan<CP::Commitment> + Serialize),
+        accessor: &impl CommitmentAccessor<CP::Commitment>,
+        setup: &CP::VerifierPublicSetup<'_>,
+    ) -> Result<Vec<T>, QueryError> {
+        let QueryData { table, .. } = self.verify(expr, accessor, setup)?;
+        Ok(rows_into(&table)?)
+    }
 
+    /// Verify a `VerifiableQueryResult` that was created with
+    /// [`VerifiableQueryResult::new_with_nonce`].
+    pub fn verify_with_nonce(
+        &self,
+        expr: &(impl ProofExecutionPlan<CP::Commitment> + Serialize),
+        accessor: &impl CommitmentAccessor<CP::Commitment>,
+        setup: &CP::VerifierPublicSetup<'_>,
+        nonce: &[u8],
+    ) -> QueryResult<CP::Scalar> {
+        let options = QueryProofOptions {
+            nonce,
+            ..Default::default()
+        };
+        self.verify_with_options(expr, accessor, setup, &options)
+    }
+
+    /// Verify a `VerifiableQueryResult`, making the final accept/reject comparisons in constant
+    /// time.
+    ///
+    /// See [`QueryProof::verify_constant_time`] for which parts of verification remain
+    /// data-dependent. Note: This does NOT transform the result!
+    pub fn verify_constant_time(
+        &self,
+        expr: &(impl ProofExecutionPlan<CP::Commitment> + Serialize),
+        accessor: &impl CommitmentAccessor<CP::Commitment>,
+        setup: &CP::VerifierPublicSetup<'_>,
+    ) -> QueryResult<CP::Scalar> {
+        let options = QueryProofOptions {
+            constant_time: true,
+            ..Default::default()
+        };
+        self.verify_with_options(expr, accessor, setup, &options)
+    }
+
+    /// Verify a `VerifiableQueryResult` under the given `options`.
+    ///
+    /// See [`QueryProof::verify_with_options`]. Note: This does NOT transform the result!
+    pub fn verify_with_options(
+        &self,
+        expr: &(impl ProofExecutionPlan<CP::Commitment> + Serialize),
+        accessor: &impl CommitmentAccessor<CP::Commitment>,
+        setup: &CP::VerifierPublicSetup<'_>,
+        options: &QueryProofOptions,
+    ) -> QueryResult<CP::Scalar> {
         // handle the empty case
         if expr.is_empty(accessor) {
             if self.provable_result.is_some() || self.proof.is_some() {
@@ -125,6 +199,12 @@ impl<CP: CommitmentEvaluationProof> VerifiableQueryResult<CP> {
                 ))?;
             }
 
+            if let Some(table) = expr.get_empty_table_result() {
+                return Ok(QueryData {
+                    table,
+                    verification_hash: Default::default(),
+                });
+            }
             let result_fields = expr.get_column_result_fields();
 
             return make_empty_query_result(result_fields);
@@ -136,12 +216,9 @@ impl<CP: CommitmentEvaluationProof> VerifiableQueryResult<CP> {
             ))?;
         }
 
-        self.proof.as_ref().unwrap().verify(
-            expr,
-            accessor,
-            self.provable_result.as_ref().unwrap(),
-            setup,
-        )
+        let proof = self.proof.as_ref().unwrap();
+        let result = self.provable_result.as_ref().unwrap();
+        proof.verify_with_options(expr, accessor, result, setup, options)
     }
 }
 
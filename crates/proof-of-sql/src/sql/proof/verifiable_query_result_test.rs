@@ -12,10 +12,10 @@ use crate::{
         },
         proof::ProofError,
         scalar::Scalar,
+        MleAllocator,
     },
     sql::proof::{QueryData, ResultBuilder},
 };
-use bumpalo::Bump;
 use indexmap::IndexSet;
 use serde::Serialize;
 
@@ -28,14 +28,14 @@ impl<S: Scalar> ProverEvaluate<S> for EmptyTestQueryExpr {
     fn result_evaluate<'a>(
         &self,
         _builder: &mut ResultBuilder<'a>,
-        _alloc: &'a Bump,
+        _alloc: &'a dyn MleAllocator,
         _accessor: &'a dyn DataAccessor<S>,
     ) {
     }
     fn prover_evaluate<'a>(
         &self,
         _builder: &mut ProofBuilder<'a, S>,
-        _alloc: &'a Bump,
+        _alloc: &'a dyn MleAllocator,
         _accessor: &'a dyn DataAccessor<S>,
     ) {
     }
@@ -71,7 +71,7 @@ impl<C: Commitment> ProofExecutionPlan<C> for EmptyTestQueryExpr {
     }
 
     fn get_column_references(&self) -> IndexSet<ColumnRef> {
-        unimplemented!("no real usage for this function yet")
+        IndexSet::new()
     }
 }
 
@@ -1,6 +1,14 @@
 use super::SumcheckMleEvaluations;
-use crate::base::{bit::BitDistribution, commitment::Commitment};
+use crate::base::{
+    bit::BitDistribution,
+    commitment::Commitment,
+    database::{ColumnRef, MetadataAccessor},
+    proof::ProofError,
+    scalar::Scalar,
+};
+use indexmap::IndexMap;
 use num_traits::Zero;
+use subtle::ConstantTimeEq;
 
 /// Track components used to verify a query's proof
 pub struct VerificationBuilder<'a, C: Commitment> {
@@ -10,12 +18,22 @@ pub struct VerificationBuilder<'a, C: Commitment> {
     subpolynomial_multipliers: &'a [C::Scalar],
     inner_product_multipliers: &'a [C::Scalar],
     sumcheck_evaluation: C::Scalar,
+    /// Whether evaluations are checked against their expected values in constant time
+    constant_time: bool,
     bit_distributions: &'a [BitDistribution],
     pcs_proof_commitments: Vec<C>,
+    /// The evaluations of the column MLEs consumed with [`Self::consume_column_mle`]
+    column_evaluations: IndexMap<ColumnRef, C::Scalar>,
     folded_pcs_proof_evaluation: C::Scalar,
     consumed_result_mles: usize,
     consumed_pcs_proof_mles: usize,
     consumed_intermediate_mles: usize,
+    consumed_intermediate_commitments: usize,
+    /// The original of each intermediate MLE that shares the commitment of an earlier MLE,
+    /// indexed among the pcs proof MLEs
+    intermediate_mle_aliases: Vec<Option<usize>>,
+    /// Whether an alias referred to a pcs proof MLE that was not consumed before it
+    invalid_intermediate_mle_alias: bool,
     produced_subpolynomials: usize,
     /// The challenges used in creation of the constraints in the proof.
     /// Specifically, these are the challenges that the verifier sends to
@@ -49,11 +67,16 @@ impl<'a, C: Commitment> VerificationBuilder<'a, C> {
             subpolynomial_multipliers,
             inner_product_multipliers,
             sumcheck_evaluation: C::Scalar::zero(),
+            constant_time: false,
             pcs_proof_commitments: Vec::with_capacity(inner_product_multipliers.len()),
+            column_evaluations: IndexMap::new(),
             folded_pcs_proof_evaluation: C::Scalar::zero(),
             consumed_result_mles: 0,
             consumed_pcs_proof_mles: 0,
             consumed_intermediate_mles: 0,
+            consumed_intermediate_commitments: 0,
+            intermediate_mle_aliases: Vec::new(),
+            invalid_intermediate_mle_alias: false,
             produced_subpolynomials: 0,
             post_result_challenges,
         }
@@ -80,6 +103,59 @@ impl<'a, C: Commitment> VerificationBuilder<'a, C> {
         res
     }
 
+    /// Consume the evaluation of the MLE of a column along with the column's commitment
+    ///
+    /// The MLE of a column is only included in the proof once, so the evaluation is consumed the
+    /// first time the column is referenced and reused afterwards. The column is recorded, so that
+    /// a commitment mismatch can be attributed to it.
+    pub fn consume_column_mle(&mut self, column: ColumnRef, commitment: C) -> C::Scalar {
+        if let Some(evaluation) = self.column_evaluations.get(&column) {
+            return *evaluation;
+        }
+        let evaluation = self.consume_anchored_mle(commitment);
+        self.column_evaluations.insert(column, evaluation);
+        evaluation
+    }
+
+    /// The number of distinct columns whose MLEs have been consumed
+    pub fn num_consumed_column_mles(&self) -> usize {
+        self.column_evaluations.len()
+    }
+
+    /// Check that no column whose MLE has been consumed is longer than the table length of the
+    /// proof
+    ///
+    /// The evaluation proof only covers the first `table_length` rows, so the remaining rows of
+    /// a longer column would silently be ignored.
+    pub fn check_column_lengths(&self, accessor: &dyn MetadataAccessor) -> Result<(), ProofError> {
+        let expected = self.table_length();
+        for &column in self.column_evaluations.keys() {
+            let actual = accessor.get_length(column.table_ref());
+            if actual > expected {
+                return Err(ProofError::LengthMismatch {
+                    column,
+                    expected,
+                    actual,
+                });
+            }
+        }
+        Ok(())
+    }
+
+    /// The column to blame if the pcs proof commitments do not match the MLE evaluations
+    ///
+    /// All the commitments are checked together by a single evaluation proof, so a failure can
+    /// only be attributed to a column when it is the only anchored MLE.
+    /// Intermediate commitments are assumed to be consistent, as they are for an honest prover.
+    pub fn mismatched_column(&self) -> Option<ColumnRef> {
+        let num_anchored_mles =
+            self.pcs_proof_commitments.len() - self.consumed_intermediate_commitments;
+        match (num_anchored_mles, self.column_evaluations.first()) {
+            (1, Some((&column, _))) => Some(column),
+            _ => None,
+        }
+    }
+
     /// Consume a bit distribution that describes which bits are constant
     /// and which bits varying in a column of data
     pub fn consume_bit_distribution(&mut self) -> BitDistribution {
@@ -90,11 +166,43 @@ impl<'a, C: Commitment> VerificationBuilder<'a, C> {
 
     /// Consume the evaluation of an intermediate MLE used in sumcheck
     ///
-    /// An interemdiate MLE is one where the verifier doesn't have access to its commitment
+    /// An interemdiate MLE is one where the verifier doesn't have access to its commitment.
+    /// An MLE that is an alias of an earlier anchored or intermediate MLE has the evaluation of
+    /// the earlier one.
     pub fn consume_intermediate_mle(&mut self) -> C::Scalar {
-        let commitment = self.intermediate_commitments[self.consumed_intermediate_mles];
+        let index = self.consumed_intermediate_mles;
         self.consumed_intermediate_mles += 1;
-        self.consume_anchored_mle(commitment)
+        match self.intermediate_mle_aliases.get(index).copied().flatten() {
+            Some(original) if original < self.consumed_pcs_proof_mles => {
+                self.mle_evaluations.pcs_proof_evaluations[original]
+            }
+            Some(_) => {
+                self.invalid_intermediate_mle_alias = true;
+                C::Scalar::zero()
+            }
+            None => {
+                let commitment =
+                    self.intermediate_commitments[self.consumed_intermediate_commitments];
+                self.consumed_intermediate_commitments += 1;
+                self.consume_anchored_mle(commitment)
+            }
+        }
+    }
+
+    /// Set the original of each intermediate MLE that shares the commitment of an earlier MLE,
+    /// as resolved by [`super::ProofHeader::resolve_intermediate_mle_aliases`]
+    pub(crate) fn set_intermediate_mle_aliases(&mut self, aliases: Vec<Option<usize>>) {
+        self.intermediate_mle_aliases = aliases;
+    }
+
+    /// Check that the original of every consumed alias was consumed before it
+    pub(crate) fn check_intermediate_mle_aliases(&self) -> Result<(), ProofError> {
+        if self.invalid_intermediate_mle_alias {
+            return Err(ProofError::VerificationError(
+                "invalid intermediate mle aliases",
+            ));
+        }
+        Ok(())
     }
 
     /// Consume the evaluation of the MLE for a result column used in sumcheck
@@ -104,6 +212,24 @@ impl<'a, C: Commitment> VerificationBuilder<'a, C> {
         self.mle_evaluations.result_evaluations[index]
     }
 
+    /// Check evaluations against their expected values in constant time.
+    ///
+    /// This only covers the comparisons made through [`Self::evaluations_match`].
+    pub fn set_constant_time(&mut self, constant_time: bool) {
+        self.constant_time = constant_time;
+    }
+
+    /// Whether `evaluation` equals `expected`.
+    ///
+    /// With [`Self::set_constant_time`], the comparison takes the same time whatever the values.
+    pub fn evaluations_match(&self, evaluation: &C::Scalar, expected: &C::Scalar) -> bool {
+        if self.constant_time {
+            scalars_ct_eq(evaluation, expected)
+        } else {
+            evaluation == expected
+        }
+    }
+
     /// Produce the evaluation of a subpolynomial used in sumcheck
     pub fn produce_sumcheck_subpolynomial_evaluation(&mut self, eval: &C::Scalar) {
         self.sumcheck_evaluation +=
@@ -140,7 +266,9 @@ impl<'a, C: Commitment> VerificationBuilder<'a, C> {
     fn completed(&self) -> bool {
         self.bit_distributions.is_empty()
             && self.produced_subpolynomials == self.subpolynomial_multipliers.len()
-            && self.consumed_intermediate_mles == self.intermediate_commitments.len()
+            && self.consumed_intermediate_commitments == self.intermediate_commitments.len()
+            && (self.intermediate_mle_aliases.is_empty()
+                || self.consumed_intermediate_mles == self.intermediate_mle_aliases.len())
             && self.consumed_pcs_proof_mles == self.mle_evaluations.pcs_proof_evaluations.len()
             && self.consumed_result_mles == self.mle_evaluations.result_evaluations.len()
             && self.post_result_challenges.is_empty()
@@ -156,3 +284,20 @@ impl<'a, C: Commitment> VerificationBuilder<'a, C> {
         self.post_result_challenges.pop().unwrap()
     }
 }
+
+/// Compares two scalars with [`ConstantTimeEq`], so that the time taken does not depend on where
+/// they first differ.
+fn scalars_ct_eq<S: Scalar>(a: &S, b: &S) -> bool {
+    #[cfg(test)]
+    CONSTANT_TIME_COMPARISONS.with(|count| count.set(count.get() + 1));
+    let a: [u64; 4] = (*a).into();
+    let b: [u64; 4] = (*b).into();
+    a.ct_eq(&b).into()
+}
+
+#[cfg(test)]
+thread_local! {
+    /// The number of scalars compared in constant time on this thread.
+    pub(super) static CONSTANT_TIME_COMPARISONS: std::cell::Cell<usize> =
+        const { std::cell::Cell::new(0) };
+}
@@ -1,5 +1,8 @@
 use super::{SumcheckMleEvaluations, VerificationBuilder};
-use crate::base::scalar::Curve25519Scalar;
+use crate::base::{
+    database::{ColumnRef, ColumnType},
+    scalar::Curve25519Scalar,
+};
 use curve25519_dalek::ristretto::RistrettoPoint;
 use num_traits::Zero;
 use rand_core::OsRng;
@@ -152,3 +155,109 @@ fn we_can_consume_post_result_challenges_in_proof_builder() {
         builder.consume_post_result_challenge()
     );
 }
+
+#[test]
+fn a_mismatch_is_only_attributed_to_a_column_if_it_is_the_only_anchored_column() {
+    let pcs_proof_evaluations = [Curve25519Scalar::from(1u64); 3];
+    let inner_product_multipliers = [Curve25519Scalar::from(1u64); 3];
+    let a = ColumnRef::new(
+        "sxt.t".parse().unwrap(),
+        "a".parse().unwrap(),
+        ColumnType::BigInt,
+    );
+    let b = ColumnRef::new(
+        "sxt.t".parse().unwrap(),
+        "b".parse().unwrap(),
+        ColumnType::BigInt,
+    );
+    let intermediate_commitments = [RistrettoPoint::default()];
+    let new_builder = || {
+        let mle_evaluations = SumcheckMleEvaluations {
+            table_length: 1,
+            num_sumcheck_variables: 1,
+            pcs_proof_evaluations: &pcs_proof_evaluations,
+            ..Default::default()
+        };
+        VerificationBuilder::<RistrettoPoint>::new(
+            0,
+            mle_evaluations,
+            &[][..],
+            &intermediate_commitments,
+            &[][..],
+            &inner_product_multipliers,
+            Vec::new(),
+        )
+    };
+
+    let mut builder = new_builder();
+    builder.consume_column_mle(a, RistrettoPoint::default());
+    builder.consume_intermediate_mle();
+    builder.consume_column_mle(a, RistrettoPoint::default());
+    assert_eq!(builder.mismatched_column(), Some(a));
+
+    let mut builder = new_builder();
+    builder.consume_column_mle(a, RistrettoPoint::default());
+    builder.consume_intermediate_mle();
+    builder.consume_column_mle(b, RistrettoPoint::default());
+    assert_eq!(builder.mismatched_column(), None);
+
+    let mut builder = new_builder();
+    builder.consume_column_mle(a, RistrettoPoint::default());
+    builder.consume_intermediate_mle();
+    builder.consume_anchored_mle(RistrettoPoint::default());
+    assert_eq!(builder.mismatched_column(), None);
+}
+
+#[test]
+fn the_evaluation_of_a_column_mle_is_only_consumed_once() {
+    let pcs_proof_evaluations = [Curve25519Scalar::from(3u64), Curve25519Scalar::from(5u64)];
+    let mle_evaluations = SumcheckMleEvaluations {
+        table_length: 1,
+        num_sumcheck_variables: 1,
+        pcs_proof_evaluations: &pcs_proof_evaluations,
+        ..Default::default()
+    };
+    let inner_product_multipliers = [
+        Curve25519Scalar::from(10u64),
+        Curve25519Scalar::from(100u64),
+    ];
+    let mut builder = VerificationBuilder::<RistrettoPoint>::new(
+        0,
+        mle_evaluations,
+        &[][..],
+        &[][..],
+        &[][..],
+        &inner_product_multipliers,
+        Vec::new(),
+    );
+    let a = ColumnRef::new(
+        "sxt.t".parse().unwrap(),
+        "a".parse().unwrap(),
+        ColumnType::BigInt,
+    );
+    let b = ColumnRef::new(
+        "sxt.t".parse().unwrap(),
+        "b".parse().unwrap(),
+        ColumnType::BigInt,
+    );
+    let commitment = RistrettoPoint::default();
+    assert_eq!(
+        builder.consume_column_mle(a, commitment),
+        pcs_proof_evaluations[0]
+    );
+    assert_eq!(
+        builder.consume_column_mle(b, commitment),
+        pcs_proof_evaluations[1]
+    );
+    assert_eq!(
+        builder.consume_column_mle(a, commitment),
+        pcs_proof_evaluations[0]
+    );
+    assert_eq!(builder.num_consumed_column_mles(), 2);
+    assert_eq!(builder.pcs_proof_commitments(), &[commitment, commitment]);
+    assert_eq!(
+        builder.folded_pcs_proof_evaluation(),
+        inner_product_multipliers[0] * pcs_proof_evaluations[0]
+            + inner_product_multipliers[1] * pcs_proof_evaluations[1]
+    );
+}
@@ -0,0 +1,93 @@
+use crate::base::proof::{MessageLabel, TranscriptCore};
+use core::{fmt, str::FromStr};
+use subtle::{Choice, ConstantTimeEq};
+use thiserror::Error;
+
+/// The 32-byte hash that a successful verification produces.
+///
+/// This is evidence that verification has been run. Comparisons are constant-time so that
+/// checking a hash against an expected value does not leak how many leading bytes match.
+#[derive(Clone, Copy, Default)]
+pub struct VerificationHash([u8; 32]);
+
+/// Errors from parsing a [`VerificationHash`] from hex.
+#[derive(Error, Debug, PartialEq, Eq)]
+pub enum VerificationHashParseError {
+    /// The string does not have exactly 64 hex digits.
+    #[error("expected 64 hex digits but found {0} characters")]
+    InvalidLength(usize),
+    /// The string contains a character that is not a hex digit.
+    #[error("invalid hex digit")]
+    InvalidHexDigit,
+}
+
+impl VerificationHash {
+    /// Draw the hash from the transcript of a proof that has been fully verified.
+    pub(super) fn from_proof(transcript: &mut impl TranscriptCore) -> Self {
+        let mut bytes = [0u8; 32];
+        transcript.challenge_bytes(MessageLabel::VerificationHash.as_bytes(), &mut bytes);
+        Self(bytes)
+    }
+
+    /// The raw hash bytes.
+    pub fn as_bytes(&self) -> &[u8; 32] {
+        &self.0
+    }
+}
+
+impl ConstantTimeEq for VerificationHash {
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.0.ct_eq(&other.0)
+    }
+}
+
+impl PartialEq for VerificationHash {
+    fn eq(&self, other: &Self) -> bool {
+        self.ct_eq(other).into()
+    }
+}
+
+impl Eq for VerificationHash {}
+
+impl fmt::Display for VerificationHash {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.iter().try_for_each(|byte| write!(f, "{byte:02x}"))
+    }
+}
+
+impl fmt::Debug for VerificationHash {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "VerificationHash({self})")
+    }
+}
+
+impl FromStr for VerificationHash {
+    type Err = VerificationHashParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.len() != 64 {
+            return Err(VerificationHashParseError::InvalidLength(s.len()));
+        }
+        if !s.bytes().all(|c| c.is_ascii_hexdigit()) {
+            return Err(VerificationHashParseError::InvalidHexDigit);
+        }
+        let mut bytes = [0u8; 32];
+        for (byte, digits) in bytes.iter_mut().zip(s.as_bytes().chunks(2)) {
+            let digits = core::str::from_utf8(digits).expect("hex digits are ascii");
+            *byte = u8::from_str_radix(digits, 16).expect("hex digits are valid");
+        }
+        Ok(Self(bytes))
+    }
+}
+
+impl From<[u8; 32]> for VerificationHash {
+    fn from(bytes: [u8; 32]) -> Self {
+        Self(bytes)
+    }
+}
+
+impl From<VerificationHash> for [u8; 32] {
+    fn from(hash: VerificationHash) -> Self {
+        hash.0
+    }
+}
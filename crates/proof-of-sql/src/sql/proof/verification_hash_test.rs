@@ -0,0 +1,61 @@
+use super::{VerificationHash, VerificationHashParseError};
+use crate::base::proof::MessageLabel;
+use merlin::Transcript;
+
+#[test]
+fn we_can_compare_verification_hashes() {
+    let hash = VerificationHash::from([7; 32]);
+    assert_eq!(hash, VerificationHash::from([7; 32]));
+    assert_ne!(hash, VerificationHash::default());
+
+    // a difference in any single byte is detected
+    for i in 0..32 {
+        let mut bytes = [7; 32];
+        bytes[i] = 8;
+        assert_ne!(hash, VerificationHash::from(bytes));
+    }
+}
+
+#[test]
+fn we_can_round_trip_a_verification_hash_through_hex() {
+    let mut bytes = [0; 32];
+    for (i, byte) in bytes.iter_mut().enumerate() {
+        *byte = (i * 9) as u8;
+    }
+    let hash = VerificationHash::from(bytes);
+    let hex = hash.to_string();
+    assert_eq!(hex.len(), 64);
+    assert!(hex.starts_with("00091b"));
+    assert_eq!(hex.parse::<VerificationHash>().unwrap(), hash);
+    assert_eq!(
+        <[u8; 32]>::from(hex.to_uppercase().parse::<VerificationHash>().unwrap()),
+        bytes
+    );
+    assert_eq!(format!("{hash:?}"), format!("VerificationHash({hex})"));
+}
+
+#[test]
+fn we_cannot_parse_a_verification_hash_from_invalid_hex() {
+    assert_eq!(
+        "00".parse::<VerificationHash>(),
+        Err(VerificationHashParseError::InvalidLength(2))
+    );
+    assert_eq!(
+        "+1".repeat(32).parse::<VerificationHash>(),
+        Err(VerificationHashParseError::InvalidHexDigit)
+    );
+    assert_eq!(
+        "0g".repeat(32).parse::<VerificationHash>(),
+        Err(VerificationHashParseError::InvalidHexDigit)
+    );
+}
+
+#[test]
+fn we_can_draw_a_verification_hash_from_a_proof_transcript() {
+    let mut transcript = Transcript::new(b"test");
+    let hash = VerificationHash::from_proof(&mut transcript.clone());
+
+    let mut bytes = [0; 32];
+    transcript.challenge_bytes(MessageLabel::VerificationHash.as_bytes(), &mut bytes);
+    assert_eq!(hash.as_bytes(), &bytes);
+}
@@ -0,0 +1,46 @@
+//! These tests only run without the `prover` feature, and check that the verification path
+//! builds with all of the prover code excluded.
+use super::{ProvableQueryResult, QueryProof, QueryResult, VerifiableQueryResult};
+use crate::{
+    base::{
+        commitment::{CommitmentEvaluationProof, QueryCommitments},
+        database::OwnedTable,
+        scalar::Curve25519Scalar,
+    },
+    proof_primitive::dory::DoryEvaluationProof,
+    sql::ast::ProofPlan,
+};
+use indexmap::IndexMap;
+
+fn verify_query_proof<CP: CommitmentEvaluationProof>(
+    proof: &QueryProof<CP>,
+    expr: &ProofPlan<CP::Commitment>,
+    accessor: &QueryCommitments<CP::Commitment>,
+    result: &ProvableQueryResult,
+    setup: &CP::VerifierPublicSetup<'_>,
+) -> QueryResult<CP::Scalar> {
+    proof.verify(expr, accessor, result, setup)
+}
+
+fn verify_verifiable_query_result<CP: CommitmentEvaluationProof>(
+    verifiable_result: &VerifiableQueryResult<CP>,
+    expr: &ProofPlan<CP::Commitment>,
+    accessor: &QueryCommitments<CP::Commitment>,
+    setup: &CP::VerifierPublicSetup<'_>,
+) -> QueryResult<CP::Scalar> {
+    verifiable_result.verify(expr, accessor, setup)
+}
+
+#[test]
+fn we_can_build_the_verifier_without_the_prover() {
+    let _ = verify_query_proof::<DoryEvaluationProof>;
+    let _ = verify_verifiable_query_result::<DoryEvaluationProof>;
+}
+
+#[test]
+fn we_can_decode_a_result_without_the_prover() {
+    let table = ProvableQueryResult::default()
+        .to_owned_table::<Curve25519Scalar>(&[])
+        .unwrap();
+    assert_eq!(table, OwnedTable::try_new(IndexMap::new()).unwrap());
+}
@@ -1,4 +1,4 @@
-#![cfg(feature = "test")]
+#![cfg(all(feature = "test", feature = "prover"))]
 #[cfg(feature = "blitzar")]
 use blitzar::proof::InnerProductProof;
 #[cfg(feature = "blitzar")]
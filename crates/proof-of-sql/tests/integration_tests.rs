@@ -1,4 +1,4 @@
-#![cfg(feature = "test")]
+#![cfg(all(feature = "test", feature = "prover"))]
 use ark_std::test_rng;
 use curve25519_dalek::RistrettoPoint;
 #[cfg(feature = "blitzar")]
@@ -1,4 +1,4 @@
-#![cfg(feature = "test")]
+#![cfg(all(feature = "test", feature = "prover"))]
 #[cfg(feature = "blitzar")]
 use proof_of_sql::base::commitment::InnerProductProof;
 use proof_of_sql::{